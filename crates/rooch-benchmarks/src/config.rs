@@ -90,6 +90,7 @@ pub struct BenchTxConfig {
     pub btc_rpc_username: Option<String>,
     pub btc_rpc_password: Option<String>,
     pub pprof_output: Option<PProfOutput>, // flamegraph(default)/proto
+    pub parallel_batch_size: Option<usize>, // execute l2 txs in parallel batches when > 1, 0(default)
 }
 
 impl Default for BenchTxConfig {
@@ -102,6 +103,7 @@ impl Default for BenchTxConfig {
             btc_rpc_username: None,
             btc_rpc_password: None,
             pprof_output: Some(PProfOutput::Flamegraph),
+            parallel_batch_size: Some(0),
         }
     }
 }
@@ -129,6 +131,9 @@ impl BenchTxConfig {
         if config.pprof_output.is_some() {
             self.pprof_output = config.pprof_output;
         }
+        if config.parallel_batch_size.is_some() {
+            self.parallel_batch_size = config.parallel_batch_size;
+        }
     }
 
     pub fn load() -> Self {
@@ -167,6 +172,9 @@ impl BenchTxConfig {
         if let Ok(pprof_output) = std::env::var("ROOCH_BENCH_PPROF_OUTPUT") {
             config.pprof_output = Some(pprof_output.parse().unwrap());
         }
+        if let Ok(parallel_batch_size) = std::env::var("ROOCH_BENCH_TX_PARALLEL_BATCH_SIZE") {
+            config.parallel_batch_size = Some(parallel_batch_size.parse().unwrap());
+        }
         config
     }
 }
@@ -25,6 +25,7 @@ pub fn tx_exec_benchmark(c: &mut Criterion) {
     let mut test_transaction_builder = TestTransactionBuilder::new(kp);
 
     let tx_type = config.tx_type.clone().unwrap();
+    let parallel_batch_size = config.parallel_batch_size.unwrap_or_default();
     let (bench_id, tx_cnt) = match tx_type {
         BtcBlock => ("btc_block", 100),
         BtcTx => ("btc_tx", 5), // The tx_cnt is the block count
@@ -88,6 +89,37 @@ pub fn tx_exec_benchmark(c: &mut Criterion) {
                 }
             }
         }
+        _ if parallel_batch_size > 1 => {
+            // One tx per sender in a batch, so the txs of a batch do not conflict on the sender account
+            let mut builders = (0..parallel_batch_size)
+                .map(|_| TestTransactionBuilder::new(RoochKeyPair::generate_secp256k1()))
+                .collect::<Vec<_>>();
+            if tx_type.clone() == TransferLargeObject {
+                for builder in builders.iter_mut() {
+                    builder.update_sequence_number(0);
+                    let publish_action = builder
+                        .new_publish_examples("large_objects", Some("rooch_examples".to_owned()))
+                        .unwrap();
+                    let tx = builder.build_and_sign(publish_action).unwrap();
+                    binding_test.execute(tx).unwrap();
+                }
+            }
+            let seq_num = if tx_type.clone() == TransferLargeObject {
+                1u64
+            } else {
+                0u64
+            };
+            for n in 0..tx_cnt {
+                let builder = &mut builders[n % parallel_batch_size];
+                let tx = create_l2_tx(
+                    builder,
+                    seq_num + (n / parallel_batch_size) as u64,
+                    tx_type.clone(),
+                )
+                .unwrap();
+                transactions.push(LedgerTxData::L2Tx(tx));
+            }
+        }
         _ => {
             let mut seq_num = 0u64;
             if tx_type.clone() == TransferLargeObject {
@@ -112,11 +144,39 @@ pub fn tx_exec_benchmark(c: &mut Criterion) {
             }
         }
     }
+    let mut group = c.benchmark_group("bench_tx_exec");
+    group.sampling_mode(SamplingMode::Flat);
+    if parallel_batch_size > 1 && matches!(tx_type, Empty | Transfer | TransferLargeObject) {
+        let batches = transactions
+            .chunks(parallel_batch_size)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .map(|tx| match tx {
+                        LedgerTxData::L2Tx(tx) => tx.clone(),
+                        _ => unreachable!("only l2 txs can be executed in parallel"),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        group.sample_size(batches.len().max(10));
+        let mut batches_iter = batches.into_iter();
+        let bench_id = format!("{}_parallel_{}", bench_id, parallel_batch_size);
+        group.bench_function(bench_id, |b| {
+            b.iter(|| {
+                let txs = match batches_iter.next() {
+                    Some(txs) => txs,
+                    None => return,
+                };
+                binding_test.execute_batch(txs).unwrap();
+            });
+        });
+        group.finish();
+        return;
+    }
     let sample_size = transactions.len();
     let mut transactions_iter = transactions.into_iter();
-    let mut group = c.benchmark_group("bench_tx_exec");
     group.sample_size(sample_size);
-    group.sampling_mode(SamplingMode::Flat);
     group.bench_function(bench_id, |b| {
         b.iter(|| {
             let tx = match transactions_iter.next() {
@@ -0,0 +1,55 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The follower mode runs a read-only node which applies the store changes of the leader node
+/// without re-executing the transactions, and forwards the write RPCs to the leader node.
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, Parser)]
+#[serde(deny_unknown_fields)]
+pub struct FollowerConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        long,
+        help = "The RPC url of the leader node to follow, the node runs in read-only mode and the store must be synced from the same genesis"
+    )]
+    pub leader_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "follower-sync-interval",
+        long,
+        requires = "leader_url",
+        help = "The interval in seconds to pull the committed transactions from the leader node"
+    )]
+    pub interval: Option<u64>,
+}
+
+impl FollowerConfig {
+    pub fn is_follower(&self) -> bool {
+        self.leader_url.is_some()
+    }
+}
+
+impl Config for FollowerConfig {}
+
+impl std::fmt::Display for FollowerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err(|_e| std::fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for FollowerConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        let deserialized: FollowerConfig = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
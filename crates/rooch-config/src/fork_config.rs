@@ -0,0 +1,55 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use clap::Parser;
+use moveos_types::h256::H256;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The fork mode runs a local node on top of the state of a remote node,
+/// the remote state is loaded lazily and cached in the local store.
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, Parser)]
+#[serde(deny_unknown_fields)]
+pub struct ForkConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        long,
+        help = "The RPC url of the remote node to fork, the store must be empty when the node is forked for the first time"
+    )]
+    pub fork_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        long,
+        requires = "fork_url",
+        help = "The state root of the remote node to fork from, default is the latest state root of the remote node"
+    )]
+    pub fork_state_root: Option<H256>,
+}
+
+impl ForkConfig {
+    pub fn is_fork(&self) -> bool {
+        self.fork_url.is_some()
+    }
+}
+
+impl Config for ForkConfig {}
+
+impl std::fmt::Display for ForkConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err(|_e| std::fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for ForkConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        let deserialized: ForkConfig = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
@@ -2,8 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::da_config::DAConfig;
+use crate::follower_config::FollowerConfig;
+use crate::fork_config::ForkConfig;
 use crate::proposer_config::ProposerConfig;
+use crate::pruner_config::PrunerConfig;
 use crate::store_config::StoreConfig;
+use crate::tx_pool_config::TxPoolConfig;
 use anyhow::Result;
 use clap::Parser;
 use moveos_config::{temp_dir, DataDirPath};
@@ -22,10 +26,14 @@ use std::{fmt::Debug, path::Path, path::PathBuf};
 
 pub mod config;
 pub mod da_config;
+pub mod follower_config;
+pub mod fork_config;
 pub mod proposer_config;
+pub mod pruner_config;
 pub mod server_config;
 pub mod settings;
 pub mod store_config;
+pub mod tx_pool_config;
 
 pub const ROOCH_DIR: &str = ".rooch";
 pub const ROOCH_CONFIR_DIR: &str = "rooch_config";
@@ -152,6 +160,18 @@ pub struct RoochOpt {
     #[clap(flatten)]
     pub proposer: ProposerConfig,
 
+    #[clap(flatten)]
+    pub pruner: PrunerConfig,
+
+    #[clap(flatten)]
+    pub tx_pool: TxPoolConfig,
+
+    #[clap(flatten)]
+    pub fork: ForkConfig,
+
+    #[clap(flatten)]
+    pub follower: FollowerConfig,
+
     #[clap(long, default_value_t, value_enum)]
     pub service_status: ServiceStatus,
 
@@ -207,6 +227,10 @@ impl RoochOpt {
             proposer_account: None,
             da: DAConfig::default(),
             proposer: ProposerConfig::default(),
+            pruner: PrunerConfig::default(),
+            tx_pool: TxPoolConfig::default(),
+            fork: ForkConfig::default(),
+            follower: FollowerConfig::default(),
             service_status: ServiceStatus::default(),
             traffic_per_second: None,
             traffic_burst_size: None,
@@ -335,6 +359,14 @@ impl RoochOpt {
     pub fn da_config(&self) -> &DAConfig {
         &self.da
     }
+
+    pub fn fork_config(&self) -> &ForkConfig {
+        &self.fork
+    }
+
+    pub fn follower_config(&self) -> &FollowerConfig {
+        &self.follower
+    }
 }
 
 #[derive(Debug, Clone)]
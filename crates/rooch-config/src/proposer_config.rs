@@ -23,6 +23,13 @@ pub struct ProposerConfig {
         help = "The proposer check avail block to propose interval"
     )]
     pub interval: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "proposer-submit-to-chain",
+        long,
+        help = "Submit the proposed blocks to the on-chain State Commitment Chain by the proposer account, default is false"
+    )]
+    pub submit_to_chain: Option<bool>,
 }
 
 impl Config for ProposerConfig {}
@@ -0,0 +1,91 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub const DEFAULT_PRUNER_RETAIN_RECENT: u64 = 100_000;
+pub const DEFAULT_PRUNER_BATCH_SIZE: u64 = 1000;
+
+/// The state pruner deletes the historical state nodes, archive nodes should keep it disabled,
+/// RPC nodes only need the recent states and the states at the DA checkpoints.
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, Parser)]
+#[serde(deny_unknown_fields)]
+pub struct PrunerConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "pruner-enable",
+        long,
+        help = "Enable the state pruner to delete the historical state nodes, default is false (archive node)"
+    )]
+    pub enable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "pruner-retain-recent",
+        long,
+        help = "The number of recent tx states to retain, default is 100000"
+    )]
+    pub retain_recent: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "pruner-retain-checkpoints",
+        long,
+        help = "Retain the states at the DA checkpoints (the end of the submitted blocks), and only prune the states before the last submitted block, default is true"
+    )]
+    pub retain_checkpoints: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "pruner-batch-size",
+        long,
+        help = "The max number of tx states to index or prune in a batch, default is 1000"
+    )]
+    pub batch_size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "pruner-interval",
+        long,
+        help = "The pruner interval in seconds"
+    )]
+    pub interval: Option<u64>,
+}
+
+impl PrunerConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.enable.unwrap_or(false)
+    }
+
+    pub fn retain_recent(&self) -> u64 {
+        self.retain_recent.unwrap_or(DEFAULT_PRUNER_RETAIN_RECENT)
+    }
+
+    pub fn retain_checkpoints(&self) -> bool {
+        self.retain_checkpoints.unwrap_or(true)
+    }
+
+    pub fn batch_size(&self) -> u64 {
+        self.batch_size.unwrap_or(DEFAULT_PRUNER_BATCH_SIZE)
+    }
+}
+
+impl Config for PrunerConfig {}
+
+impl std::fmt::Display for PrunerConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err(|_e| std::fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for PrunerConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        let deserialized: PrunerConfig = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
@@ -7,3 +7,11 @@
 pub const ROOCH_BATCH_INTERVAL: u64 = 1000 * 60 * 15;
 // 5 seconds, check avail block to propose interval
 pub const PROPOSER_CHECK_INTERVAL: u64 = 5;
+// 10 seconds, check new batches in DA to sync interval (sync mode only)
+pub const DA_SYNC_INTERVAL: u64 = 10;
+// 1 second, pull the committed transactions from the leader node interval (follower mode only)
+pub const FOLLOWER_SYNC_INTERVAL: u64 = 1;
+// 10 seconds, index the state node logs and prune the stale state nodes interval
+pub const PRUNER_INTERVAL: u64 = 10;
+// 10 seconds, remove the expired transactions from the tx pool interval
+pub const TX_POOL_GC_INTERVAL: u64 = 10;
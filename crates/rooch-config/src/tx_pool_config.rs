@@ -0,0 +1,75 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Config;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub const DEFAULT_TX_POOL_MAX_SIZE: usize = 10_000;
+pub const DEFAULT_TX_POOL_MAX_PER_SENDER: usize = 64;
+pub const DEFAULT_TX_POOL_TTL: u64 = 600;
+
+/// The tx pool holds the L2 transactions before they are sequenced,
+/// the transactions with future sequence numbers are queued until the gaps are filled.
+#[derive(Clone, Default, Debug, Deserialize, PartialEq, Serialize, Parser)]
+#[serde(deny_unknown_fields)]
+pub struct TxPoolConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "tx-pool-max-size",
+        long,
+        help = "The max number of transactions in the tx pool, default is 10000"
+    )]
+    pub max_size: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "tx-pool-max-per-sender",
+        long,
+        help = "The max number of pending transactions of a sender in the tx pool, default is 64"
+    )]
+    pub max_per_sender: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(
+        name = "tx-pool-ttl",
+        long,
+        help = "The time in seconds a transaction can stay in the tx pool before it is expired, default is 600"
+    )]
+    pub ttl: Option<u64>,
+}
+
+impl TxPoolConfig {
+    pub fn max_size(&self) -> usize {
+        self.max_size.unwrap_or(DEFAULT_TX_POOL_MAX_SIZE)
+    }
+
+    pub fn max_per_sender(&self) -> usize {
+        self.max_per_sender
+            .unwrap_or(DEFAULT_TX_POOL_MAX_PER_SENDER)
+    }
+
+    pub fn ttl(&self) -> u64 {
+        self.ttl.unwrap_or(DEFAULT_TX_POOL_TTL)
+    }
+}
+
+impl Config for TxPoolConfig {}
+
+impl std::fmt::Display for TxPoolConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err(|_e| std::fmt::Error)?
+        )
+    }
+}
+
+impl FromStr for TxPoolConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+        let deserialized: TxPoolConfig = serde_json::from_str(s)?;
+        Ok(deserialized)
+    }
+}
@@ -12,6 +12,8 @@ use async_trait::async_trait;
 use coerce::actor::context::ActorContext;
 use coerce::actor::message::Handler;
 use coerce::actor::Actor;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
 use moveos_types::h256::H256;
 use rooch_config::da_config::{DAConfig, DEFAULT_DA_BACKGROUND_SUBMIT_INTERVAL};
 use rooch_store::da_store::DAMetaStore;
@@ -44,6 +46,7 @@ impl DAServerActor {
         da_config: DAConfig,
         sequencer_key: RoochKeyPair,
         rooch_store: RoochStore,
+        moveos_store: MoveOSStore,
         genesis_namespace: String,
         shutdown_rx: broadcast::Receiver<()>,
     ) -> anyhow::Result<Self> {
@@ -81,6 +84,7 @@ impl DAServerActor {
         if submit_threshold != 0 {
             Self::run_background_submitter(
                 rooch_store,
+                moveos_store,
                 sequencer_key,
                 backends,
                 submit_threshold,
@@ -178,6 +182,7 @@ impl DAServerActor {
     // This prevents blocking other actor handlers and maintains the actor's responsiveness.
     fn run_background_submitter(
         rooch_store: RoochStore,
+        moveos_store: MoveOSStore,
         sequencer_key: RoochKeyPair,
         backends: Vec<Arc<dyn DABackend>>,
         submit_threshold: usize,
@@ -192,6 +197,7 @@ impl DAServerActor {
                 submitter: Submitter {
                     sequencer_key: sequencer_key.copy(),
                     rooch_store: rooch_store.clone(),
+                    moveos_store,
                     backends: backends.clone(),
                     submit_threshold,
                 },
@@ -280,6 +286,7 @@ impl Handler<RevertTransactionMessage> for DAServerActor {
 pub(crate) struct Submitter {
     sequencer_key: RoochKeyPair,
     rooch_store: RoochStore,
+    moveos_store: MoveOSStore,
 
     backends: Vec<Arc<dyn DABackend>>,
     submit_threshold: usize,
@@ -295,14 +302,21 @@ impl Submitter {
         let tx_order_start = block_range.tx_order_start;
         let tx_order_end = block_range.tx_order_end;
 
+        // the state root after executing the batch is signed with the batch meta for the sync mode nodes
+        let state_root_opt = self.get_state_root(&tx_list)?;
+
         // create batch
-        let batch = DABatch::new(
+        let mut batch = DABatch::new(
             block_number,
             tx_order_start,
             tx_order_end,
             &tx_list,
             &self.sequencer_key,
         )?;
+        // without the state root, the batch is submitted as an unsigned ChunkV0 rather than stalling the submitter
+        if let Some(state_root) = state_root_opt {
+            batch.sign_state_root(state_root, &self.sequencer_key);
+        }
         let batch_meta = batch.meta.clone();
         let meta_signature = batch.meta_signature.clone();
         let batch_hash = batch.get_hash();
@@ -328,6 +342,28 @@ impl Submitter {
         })
     }
 
+    // get the state root after executing the last tx of the batch,
+    // None if the last tx has no execution info (e.g., the known anomalies or not executed yet)
+    fn get_state_root(&self, tx_list: &[LedgerTransaction]) -> anyhow::Result<Option<H256>> {
+        let mut last_tx = tx_list
+            .last()
+            .cloned()
+            .ok_or_else(|| anyhow!("empty tx list"))?;
+        let tx_order = last_tx.sequence_info.tx_order;
+        let tx_hash = last_tx.tx_hash();
+        match self.moveos_store.get_tx_execution_info(tx_hash)? {
+            Some(execution_info) => Ok(Some(execution_info.state_root)),
+            None => {
+                tracing::warn!(
+                    "fail to get execution info by tx_hash: {:?}, tx_order: {}, submit the batch without signed state root",
+                    tx_hash,
+                    tx_order
+                );
+                Ok(None)
+            }
+        }
+    }
+
     async fn submit_batch_to_backends(&self, batch: DABatch) -> anyhow::Result<()> {
         let backends = self.backends.clone();
         let submit_threshold = self.submit_threshold;
@@ -516,8 +552,45 @@ fn pair_tx_order_hash(
 
 #[cfg(test)]
 mod tests {
-    use crate::actor::server::{pair_tx_order_hash, BackgroundSubmitter};
+    use crate::actor::server::{pair_tx_order_hash, BackgroundSubmitter, Submitter};
+    use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+    use moveos_store::MoveOSStore;
     use moveos_types::h256::H256;
+    use moveos_types::transaction::TransactionExecutionInfo;
+    use rooch_store::RoochStore;
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::test_utils::random_ledger_transaction_with_order;
+
+    #[test]
+    fn test_submitter_get_state_root() {
+        let (rooch_store, _rooch_store_dir) = RoochStore::mock_rooch_store().unwrap();
+        let (moveos_store, _moveos_store_dir) = MoveOSStore::mock_moveos_store().unwrap();
+        let sequencer_key = RoochKeyPair::generate_secp256k1();
+        let tx_list = (1..=3)
+            .map(|tx_order| random_ledger_transaction_with_order(tx_order, &sequencer_key))
+            .collect::<Vec<_>>();
+        let submitter = Submitter {
+            sequencer_key,
+            rooch_store,
+            moveos_store: moveos_store.clone(),
+            backends: vec![],
+            submit_threshold: 0,
+        };
+
+        assert!(submitter.get_state_root(&[]).is_err());
+        // the last tx has no execution info, the batch is submitted without signed state root
+        assert_eq!(submitter.get_state_root(&tx_list).unwrap(), None);
+
+        let mut execution_info = TransactionExecutionInfo::random();
+        execution_info.tx_hash = tx_list.last().unwrap().clone().tx_hash();
+        moveos_store
+            .save_tx_execution_info(execution_info.clone())
+            .unwrap();
+        assert_eq!(
+            submitter.get_state_root(&tx_list).unwrap(),
+            Some(execution_info.state_root)
+        );
+    }
 
     #[test]
     fn test_background_submitter_adjust_cursor() {
@@ -6,7 +6,8 @@ use anyhow::anyhow;
 use async_trait::async_trait;
 use rooch_config::da_config::{DABackendConfig, DABackendConfigType};
 use rooch_types::da::batch::DABatch;
-use std::collections::HashMap;
+use rooch_types::da::segment::{Segment, SegmentID};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 
 pub mod openda;
@@ -27,6 +28,14 @@ pub trait DABackend: Sync + Send {
     async fn submit_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<()>;
     fn get_identifier(&self) -> String;
     fn get_adapter_stats(&self) -> AdapterSubmitStat;
+
+    /// Lists chunks could be retrieved from backend: chunk_id -> segment_numbers (ascending).
+    async fn list_chunks(&self) -> anyhow::Result<BTreeMap<u128, Vec<u64>>>;
+    /// Gets segment by segment_id, returns None if it's not found in backend.
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Box<dyn Segment>>>;
+    /// Gets all segments of the chunk and reassembles them into batches.
+    /// Returns None if any segment of the chunk is missing.
+    async fn get_batches(&self, chunk_id: u128) -> anyhow::Result<Option<Vec<DABatch>>>;
 }
 
 pub struct DABackends {
@@ -76,6 +85,26 @@ impl DABackends {
         Ok(this)
     }
 
+    /// Gets batches of chunk from backends by priority,
+    /// falls back to the next backend if the chunk is missing or broken in the current one.
+    pub async fn get_batches(&self, chunk_id: u128) -> anyhow::Result<Option<Vec<DABatch>>> {
+        for backend in &self.backends {
+            match backend.get_batches(chunk_id).await {
+                Ok(Some(batches)) => return Ok(Some(batches)),
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        "failed to get chunk: {} from {:?}, error: {:?}",
+                        chunk_id,
+                        backend.get_identifier(),
+                        e,
+                    );
+                }
+            }
+        }
+        Ok(None)
+    }
+
     // sort backends by their priority
     fn sort_backends(&mut self) {
         let priority_map: HashMap<&str, usize> = BACKENDS_PRIORITY
@@ -20,6 +20,7 @@ use tokio::sync::RwLock;
 
 const DEFAULT_MAX_SEGMENT_SIZE: u64 = 8 * 1024 * 1024;
 pub(crate) const DEFAULT_MAX_RETRY_TIMES: usize = 3;
+const DEFAULT_SCAN_START_HEIGHT: u64 = 1;
 
 /// OpenDAAdapter connecting to OpenDA-compatible backends
 #[async_trait]
@@ -30,6 +31,80 @@ pub(crate) trait OpenDAAdapter: Sync + Send {
         segment_bytes: &[u8],
         is_last_segment: bool,
     ) -> anyhow::Result<()>;
+
+    /// Reads back the raw bytes of a submitted segment.
+    /// Returns `None` if the segment can't be found in backend.
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>>;
+
+    /// Lists all segment ids which could be retrieved from backend, in ascending order.
+    async fn list_segments(&self) -> anyhow::Result<Vec<SegmentID>>;
+}
+
+/// SegmentHeightIndex locates segments for backends which could only be queried by height
+/// (e.g., Celestia, Avail): it maps segment_id to the height which includes it.
+///
+/// Heights are learned from submissions of this node and from scanning backend,
+/// `next_height` is the next height that has not been scanned yet.
+pub(crate) struct SegmentHeightIndex {
+    inner: RwLock<SegmentHeightIndexInner>,
+}
+
+struct SegmentHeightIndexInner {
+    heights: HashMap<SegmentID, u64>,
+    next_height: u64,
+}
+
+impl SegmentHeightIndex {
+    pub(crate) fn new(start_height: u64) -> Self {
+        Self {
+            inner: RwLock::new(SegmentHeightIndexInner {
+                heights: HashMap::new(),
+                next_height: start_height,
+            }),
+        }
+    }
+
+    pub(crate) async fn insert(&self, segment_id: SegmentID, height: u64) {
+        let mut inner = self.inner.write().await;
+        inner.heights.insert(segment_id, height);
+    }
+
+    pub(crate) async fn get(&self, segment_id: &SegmentID) -> Option<u64> {
+        let inner = self.inner.read().await;
+        inner.heights.get(segment_id).copied()
+    }
+
+    pub(crate) async fn next_height(&self) -> u64 {
+        self.inner.read().await.next_height
+    }
+
+    /// Records segments found at `height` and moves the scan cursor after it.
+    pub(crate) async fn mark_scanned(&self, height: u64, segment_ids: Vec<SegmentID>) {
+        let mut inner = self.inner.write().await;
+        for segment_id in segment_ids {
+            inner.heights.insert(segment_id, height);
+        }
+        if height >= inner.next_height {
+            inner.next_height = height + 1;
+        }
+    }
+
+    pub(crate) async fn segment_ids(&self) -> Vec<SegmentID> {
+        let inner = self.inner.read().await;
+        let mut segment_ids = inner.heights.keys().copied().collect::<Vec<_>>();
+        segment_ids.sort();
+        segment_ids
+    }
+}
+
+/// Parses scan start height from scheme config, default is 1.
+pub(crate) fn parse_start_height(scheme_config: &HashMap<String, String>) -> anyhow::Result<u64> {
+    match scheme_config.get("start_height") {
+        Some(height) => height
+            .parse::<u64>()
+            .map_err(|e| anyhow!("invalid start_height: {}, error: {:?}", height, e)),
+        None => Ok(DEFAULT_SCAN_START_HEIGHT),
+    }
 }
 
 #[derive(Clone)]
@@ -168,12 +243,14 @@ impl OpenDAAdapterConfig {
             }
             OpenDAScheme::Celestia => {
                 let namespace = WrappedNamespace::from_string(&self.namespace.clone())?;
+                let start_height = parse_start_height(&scheme_config)?;
                 Box::new(
                     CelestiaAdapter::new(
                         namespace.into_inner(),
                         &scheme_config["endpoint"],
                         scheme_config.get("auth_token").map(|s| s.as_str()),
                         max_retries,
+                        start_height,
                         stats,
                     )
                     .await?,
@@ -355,6 +432,47 @@ mod tests {
         assert_eq!(map_config.get("default_storage_class").unwrap(), "STANDARD");
     }
 
+    #[tokio::test]
+    async fn test_segment_height_index() {
+        let index = SegmentHeightIndex::new(10);
+        assert_eq!(index.next_height().await, 10);
+
+        let segment_id1 = SegmentID {
+            chunk_id: 2,
+            segment_number: 0,
+        };
+        let segment_id2 = SegmentID {
+            chunk_id: 1,
+            segment_number: 1,
+        };
+        index.insert(segment_id1, 5).await;
+        assert_eq!(index.get(&segment_id1).await, Some(5));
+        // inserting submitted segment doesn't move scan cursor
+        assert_eq!(index.next_height().await, 10);
+
+        index.mark_scanned(12, vec![segment_id2]).await;
+        assert_eq!(index.get(&segment_id2).await, Some(12));
+        assert_eq!(index.next_height().await, 13);
+        // scanning an old height doesn't move scan cursor back
+        index.mark_scanned(11, vec![]).await;
+        assert_eq!(index.next_height().await, 13);
+
+        assert_eq!(index.segment_ids().await, vec![segment_id2, segment_id1]);
+    }
+
+    #[test]
+    fn test_parse_start_height() {
+        let mut scheme_config = HashMap::new();
+        assert_eq!(
+            parse_start_height(&scheme_config).unwrap(),
+            DEFAULT_SCAN_START_HEIGHT
+        );
+        scheme_config.insert("start_height".to_string(), "1024".to_string());
+        assert_eq!(parse_start_height(&scheme_config).unwrap(), 1024);
+        scheme_config.insert("start_height".to_string(), "abc".to_string());
+        assert!(parse_start_height(&scheme_config).is_err());
+    }
+
     #[tokio::test]
     async fn test_adapter_submit_stats() {
         let stats = AdapterSubmitStat::new();
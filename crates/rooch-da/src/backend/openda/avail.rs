@@ -1,13 +1,15 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::backend::openda::adapter::{AdapterSubmitStat, OpenDAAdapter};
+use crate::backend::openda::adapter::{
+    parse_start_height, AdapterSubmitStat, OpenDAAdapter, SegmentHeightIndex,
+};
 use anyhow::anyhow;
 use async_trait::async_trait;
 use base64::engine::general_purpose;
 use base64::Engine;
 use reqwest::{Client, StatusCode};
-use rooch_types::da::segment::SegmentID;
+use rooch_types::da::segment::{segment_from_bytes, SegmentID};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::HashMap;
@@ -21,6 +23,8 @@ const MAX_BACKOFF_DELAY: Duration = Duration::from_secs(30);
 
 const MIN_BACKOFF_DELAY: Duration = Duration::from_millis(3000);
 const SUBMIT_API_PATH: &str = "v2/submit";
+const STATUS_API_PATH: &str = "v2/status";
+const BLOCK_DATA_API_PATH: &str = "v2/blocks";
 
 const TURBO_MIN_BACKOFF_DELAY: Duration = Duration::from_millis(500);
 const TURBO_SUBMIT_API_PATH: &str = "v1/submit_raw_data";
@@ -31,14 +35,79 @@ pub struct AvailFusionAdapter {
     stats: AdapterSubmitStat,
     turbo_client: Option<AvailTurboClient>,
     light_client: Option<AvailLightClient>,
+    height_index: SegmentHeightIndex,
 }
 
 impl AvailFusionAdapter {
-    async fn submit(&self, segment_id: SegmentID, segment_bytes: &[u8]) -> anyhow::Result<()> {
+    // Turbo only returns submission_id, all reads go through the light client.
+    fn reader(&self) -> anyhow::Result<&AvailLightClient> {
+        self.light_client
+            .as_ref()
+            .ok_or_else(|| anyhow!("light_endpoint must be provided for reading from Avail"))
+    }
+
+    async fn get_segments_at(
+        &self,
+        block_number: u64,
+    ) -> anyhow::Result<Vec<(SegmentID, Vec<u8>)>> {
+        let data_list = self.reader()?.get_block_data(block_number).await?;
+        let mut segments = Vec::with_capacity(data_list.len());
+        for data in data_list {
+            match segment_from_bytes(&data) {
+                Ok(segment) => segments.push((segment.get_id(), data)),
+                Err(e) => {
+                    tracing::warn!(
+                        "Skip invalid segment in Avail block: {}: {:?}",
+                        block_number,
+                        e
+                    );
+                }
+            }
+        }
+        Ok(segments)
+    }
+
+    // scan blocks which haven't been scanned until the latest block,
+    // stop early if `target` is found.
+    async fn scan(&self, target: Option<SegmentID>) -> anyhow::Result<Option<Vec<u8>>> {
+        let latest = self.reader()?.get_latest_block_number().await?;
+        let mut block_number = self.height_index.next_height().await;
+        while block_number <= latest {
+            let segments = self.get_segments_at(block_number).await?;
+            let segment_ids = segments.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+            self.height_index
+                .mark_scanned(block_number, segment_ids)
+                .await;
+            if let Some(target) = target {
+                if let Some((_, bytes)) = segments.into_iter().find(|(id, _)| *id == target) {
+                    return Ok(Some(bytes));
+                }
+            }
+            block_number += 1;
+        }
+        Ok(None)
+    }
+
+    async fn get(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        if let Some(block_number) = self.height_index.get(&segment_id).await {
+            let segments = self.get_segments_at(block_number).await?;
+            if let Some((_, bytes)) = segments.into_iter().find(|(id, _)| *id == segment_id) {
+                return Ok(Some(bytes));
+            }
+        }
+        self.scan(Some(segment_id)).await
+    }
+
+    // returns the block number which includes the segment if it's known (light client only)
+    async fn submit(
+        &self,
+        segment_id: SegmentID,
+        segment_bytes: &[u8],
+    ) -> anyhow::Result<Option<u64>> {
         match &self.turbo_client {
             Some(turbo_client) => {
                 match turbo_client.submit_segment(segment_id, segment_bytes).await {
-                    Ok(result) => return Ok(result), // No fallback needed
+                    Ok(_) => return Ok(None), // No fallback needed, turbo only returns submission_id
                     Err(error) => {
                         tracing::warn!(
                             "Failed to submit segment to Avail Turbo: {}, trying light_client if available",
@@ -57,6 +126,7 @@ impl AvailFusionAdapter {
             light_client
                 .submit_segment(segment_id, segment_bytes) // Takes ownership here
                 .await
+                .map(Some)
         } else {
             Err(anyhow!("Both turbo and light clients are not available"))
         }
@@ -72,7 +142,10 @@ impl OpenDAAdapter for AvailFusionAdapter {
         is_last_segment: bool,
     ) -> anyhow::Result<()> {
         match self.submit(segment_id, segment_bytes).await {
-            Ok(_) => {
+            Ok(block_number) => {
+                if let Some(block_number) = block_number {
+                    self.height_index.insert(segment_id, block_number).await;
+                }
                 self.stats
                     .add_done_segment(segment_id, is_last_segment)
                     .await;
@@ -81,6 +154,15 @@ impl OpenDAAdapter for AvailFusionAdapter {
             Err(error) => Err(error),
         }
     }
+
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        self.get(segment_id).await
+    }
+
+    async fn list_segments(&self) -> anyhow::Result<Vec<SegmentID>> {
+        self.scan(None).await?;
+        Ok(self.height_index.segment_ids().await)
+    }
 }
 
 pub struct AvailFusionClientConfig {
@@ -88,6 +170,7 @@ pub struct AvailFusionClientConfig {
     pub turbo_api_key: Option<String>,
     pub light_endpoint: Option<String>,
     pub max_retries: usize,
+    pub start_height: u64,
 }
 
 impl AvailFusionClientConfig {
@@ -105,12 +188,14 @@ impl AvailFusionClientConfig {
         if turbo_endpoint.is_some() && turbo_api_key.is_none() {
             return Err(anyhow!("turbo_api_key must be provided"));
         }
+        let start_height = parse_start_height(&scheme_config)?;
 
         Ok(AvailFusionClientConfig {
             turbo_endpoint,
             turbo_api_key,
             light_endpoint,
             max_retries,
+            start_height,
         })
     }
 
@@ -134,6 +219,7 @@ impl AvailFusionClientConfig {
             stats,
             turbo_client,
             light_client,
+            height_index: SegmentHeightIndex::new(self.start_height),
         })
     }
 }
@@ -260,6 +346,26 @@ impl AvailLightClient {
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AvailLightClientStatusResponse {
+    blocks: AvailLightClientStatusBlocks,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AvailLightClientStatusBlocks {
+    latest: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AvailLightClientBlockDataResponse {
+    data_transactions: Vec<AvailLightClientDataTransaction>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AvailLightClientDataTransaction {
+    data: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AvailLightClientSubmitResponse {
     block_number: u32,
@@ -269,11 +375,12 @@ pub struct AvailLightClientSubmitResponse {
 }
 
 impl AvailLightClient {
+    // returns the block number which includes the segment
     async fn submit_segment(
         &self,
         segment_id: SegmentID,
         segment_bytes: &[u8],
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<u64> {
         let submit_url = format!("{}/{}", self.endpoint, SUBMIT_API_PATH);
         let data = general_purpose::STANDARD.encode(segment_bytes);
         let max_attempts = self.max_retries + 1; // max_attempts = max_retries + first attempt
@@ -300,7 +407,7 @@ impl AvailLightClient {
                         submit_response.hash,
                         submit_response.index,
                     );
-                    return Ok(());
+                    return Ok(submit_response.block_number as u64);
                 }
                 StatusCode::NOT_FOUND => {
                     return Err(anyhow!(
@@ -331,3 +438,70 @@ impl AvailLightClient {
         }
     }
 }
+
+impl AvailLightClient {
+    async fn get_latest_block_number(&self) -> anyhow::Result<u64> {
+        let status_url = format!("{}/{}", self.endpoint, STATUS_API_PATH);
+        let response = self.http_client.get(&status_url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Failed to get status from Avail: {}",
+                response.status()
+            ));
+        }
+        let status: AvailLightClientStatusResponse = response.json().await?;
+        Ok(status.blocks.latest)
+    }
+
+    // returns the raw data of all data transactions in block for the app_id of light client
+    async fn get_block_data(&self, block_number: u64) -> anyhow::Result<Vec<Vec<u8>>> {
+        let data_url = format!(
+            "{}/{}/{}/data?fields=data",
+            self.endpoint, BLOCK_DATA_API_PATH, block_number
+        );
+        let max_attempts = self.max_retries + 1; // max_attempts = max_retries + first attempt
+        let mut attempts = 0;
+        let mut retry_delay = MIN_BACKOFF_DELAY;
+
+        loop {
+            attempts += 1;
+            let response = self.http_client.get(&data_url).send().await?;
+            match response.status() {
+                StatusCode::OK => {
+                    let block_data: AvailLightClientBlockDataResponse = response.json().await?;
+                    let mut data_list = Vec::with_capacity(block_data.data_transactions.len());
+                    for tx in block_data.data_transactions {
+                        data_list.push(general_purpose::STANDARD.decode(tx.data)?);
+                    }
+                    return Ok(data_list);
+                }
+                StatusCode::NOT_FOUND => {
+                    return Err(anyhow!(
+                        "Block: {} not found or app mode not active for Avail.",
+                        block_number
+                    ))
+                }
+                _ => {
+                    if attempts < max_attempts {
+                        tracing::warn!(
+                            "Failed to get block: {} data from Avail: {}, attempts: {}，retrying after {}ms",
+                            block_number,
+                            response.status(),
+                            attempts,
+                            retry_delay.as_millis(),
+                        );
+                        sleep(retry_delay).await;
+                        retry_delay = std::cmp::min(retry_delay * 2, MAX_BACKOFF_DELAY);
+                    } else {
+                        return Err(anyhow!(
+                            "Failed to get block: {} data from Avail: {} after {} attempts",
+                            block_number,
+                            response.status(),
+                            attempts,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,14 +1,14 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::backend::openda::adapter::{AdapterSubmitStat, OpenDAAdapter};
+use crate::backend::openda::adapter::{AdapterSubmitStat, OpenDAAdapter, SegmentHeightIndex};
 use anyhow::anyhow;
 use async_trait::async_trait;
-use celestia_rpc::{BlobClient, Client};
+use celestia_rpc::{BlobClient, Client, HeaderClient};
 use celestia_types::blob::SubmitOptions;
 use celestia_types::nmt::Namespace;
 use celestia_types::Blob;
-use rooch_types::da::segment::SegmentID;
+use rooch_types::da::segment::{segment_from_bytes, SegmentID};
 use std::fmt::Display;
 use std::time::Duration;
 use tokio::time::sleep;
@@ -25,6 +25,7 @@ pub(crate) struct CelestiaAdapter {
     namespace: Namespace,
     client: Client,
     max_retries: usize,
+    height_index: SegmentHeightIndex,
 }
 
 impl CelestiaAdapter {
@@ -33,6 +34,7 @@ impl CelestiaAdapter {
         endpoint: &str,
         auth_token: Option<&str>,
         max_retries: usize,
+        start_height: u64,
         stats: AdapterSubmitStat,
     ) -> anyhow::Result<Self> {
         let celestia_client = Client::new(endpoint, auth_token).await?;
@@ -41,9 +43,72 @@ impl CelestiaAdapter {
             namespace,
             client: celestia_client,
             max_retries,
+            height_index: SegmentHeightIndex::new(start_height),
         })
     }
 
+    // get all segments in namespace at height
+    async fn get_segments_at(&self, height: u64) -> anyhow::Result<Vec<(SegmentID, Vec<u8>)>> {
+        let blobs = match self.client.blob_get_all(height, &[self.namespace]).await {
+            Ok(blobs) => blobs,
+            Err(e) => {
+                // no blob in namespace at this height
+                if e.to_string().contains("blob: not found") {
+                    return Ok(vec![]);
+                }
+                return Err(anyhow!(
+                    "Failed to get blobs from Celestia at height: {}: {:?}",
+                    height,
+                    e
+                ));
+            }
+        };
+        let mut segments = Vec::with_capacity(blobs.len());
+        for blob in blobs {
+            match segment_from_bytes(&blob.data) {
+                Ok(segment) => segments.push((segment.get_id(), blob.data)),
+                Err(e) => {
+                    tracing::warn!(
+                        "Skip invalid segment in Celestia namespace at height: {}: {:?}",
+                        height,
+                        e
+                    );
+                }
+            }
+        }
+        Ok(segments)
+    }
+
+    // scan heights which haven't been scanned until network head,
+    // stop early if `target` is found.
+    async fn scan(&self, target: Option<SegmentID>) -> anyhow::Result<Option<Vec<u8>>> {
+        let head = self.client.header_network_head().await?;
+        let head_height = head.height().value();
+        let mut height = self.height_index.next_height().await;
+        while height <= head_height {
+            let segments = self.get_segments_at(height).await?;
+            let segment_ids = segments.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+            self.height_index.mark_scanned(height, segment_ids).await;
+            if let Some(target) = target {
+                if let Some((_, bytes)) = segments.into_iter().find(|(id, _)| *id == target) {
+                    return Ok(Some(bytes));
+                }
+            }
+            height += 1;
+        }
+        Ok(None)
+    }
+
+    async fn get(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        if let Some(height) = self.height_index.get(&segment_id).await {
+            let segments = self.get_segments_at(height).await?;
+            if let Some((_, bytes)) = segments.into_iter().find(|(id, _)| *id == segment_id) {
+                return Ok(Some(bytes));
+            }
+        }
+        self.scan(Some(segment_id)).await
+    }
+
     async fn submit(&self, segment_id: SegmentID, segment_bytes: &[u8]) -> anyhow::Result<()> {
         let blob = Blob::new(self.namespace, segment_bytes.to_vec())?;
         let max_attempts = self.max_retries + 1; // max_attempts = max_retries + first attempt
@@ -64,6 +129,7 @@ impl CelestiaAdapter {
                         blob.commitment,
                         height,
                     );
+                    self.height_index.insert(segment_id, height).await;
                     return Ok(());
                 }
                 Err(e) => {
@@ -109,6 +175,15 @@ impl OpenDAAdapter for CelestiaAdapter {
             Err(error) => Err(error),
         }
     }
+
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        self.get(segment_id).await
+    }
+
+    async fn list_segments(&self) -> anyhow::Result<Vec<SegmentID>> {
+        self.scan(None).await?;
+        Ok(self.height_index.segment_ids().await)
+    }
 }
 
 pub(crate) struct WrappedNamespace(Namespace);
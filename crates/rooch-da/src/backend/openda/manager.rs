@@ -4,10 +4,13 @@
 use crate::backend::openda::adapter::{AdapterSubmitStat, OpenDAAdapter, OpenDAAdapterConfig};
 use crate::backend::openda::derive_identifier;
 use crate::backend::DABackend;
+use anyhow::anyhow;
 use async_trait::async_trait;
 use rooch_config::da_config::DABackendOpenDAConfig;
 use rooch_types::da::batch::DABatch;
-use rooch_types::da::chunk::{Chunk, ChunkV0};
+use rooch_types::da::chunk::{chunk_from_batch, chunk_from_segments, Chunk};
+use rooch_types::da::segment::{segment_from_bytes, Segment, SegmentID};
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 /// manage OpenDA backends while integrating specific adapter logic
@@ -39,7 +42,7 @@ impl OpenDABackendManager {
 #[async_trait]
 impl DABackend for OpenDABackendManager {
     async fn submit_batch(&self, batch: Arc<DABatch>) -> anyhow::Result<()> {
-        let chunk: ChunkV0 = (*batch).clone().into();
+        let chunk = chunk_from_batch((*batch).clone());
 
         let max_segment_size = self.adapter_config.max_segment_size;
 
@@ -77,4 +80,175 @@ impl DABackend for OpenDABackendManager {
     fn get_adapter_stats(&self) -> AdapterSubmitStat {
         self.adapter_stats.clone()
     }
+
+    async fn list_chunks(&self) -> anyhow::Result<BTreeMap<u128, Vec<u64>>> {
+        let segment_ids = self.adapter.list_segments().await?;
+        let mut chunks: BTreeMap<u128, Vec<u64>> = BTreeMap::new();
+        for segment_id in segment_ids {
+            chunks
+                .entry(segment_id.chunk_id)
+                .or_default()
+                .push(segment_id.segment_number);
+        }
+        for segment_numbers in chunks.values_mut() {
+            segment_numbers.sort();
+            segment_numbers.dedup();
+        }
+        Ok(chunks)
+    }
+
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Box<dyn Segment>>> {
+        match self.adapter.get_segment(segment_id).await? {
+            Some(bytes) => {
+                let segment = segment_from_bytes(&bytes)?;
+                if segment.get_id() != segment_id {
+                    return Err(anyhow!(
+                        "segment_id mismatch from {:?}, exp: {}, act: {}",
+                        self.get_identifier(),
+                        segment_id,
+                        segment.get_id()
+                    ));
+                }
+                Ok(Some(segment))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn get_batches(&self, chunk_id: u128) -> anyhow::Result<Option<Vec<DABatch>>> {
+        let mut segments = Vec::new();
+        for segment_number in 0.. {
+            let segment_id = SegmentID {
+                chunk_id,
+                segment_number,
+            };
+            match self.get_segment(segment_id).await? {
+                Some(segment) => {
+                    let is_last = segment.is_last();
+                    segments.push(segment);
+                    if is_last {
+                        break;
+                    }
+                }
+                None => return Ok(None),
+            }
+        }
+
+        let chunk = chunk_from_segments(segments)?;
+        let batches = chunk.get_batches();
+        for batch in &batches {
+            batch.verify(true)?;
+        }
+        Ok(Some(batches))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos_types::h256::H256;
+    use rooch_config::da_config::OpenDAScheme;
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::test_utils::random_ledger_transaction_with_order;
+    use std::collections::HashMap;
+
+    const TEST_NAMESPACE: &str = "test_namespace";
+
+    fn random_batch(block_number: u128, tx_order_start: u64, tx_order_end: u64) -> DABatch {
+        let keypair = RoochKeyPair::generate_secp256k1();
+        let tx_list = (tx_order_start..=tx_order_end)
+            .map(|tx_order| random_ledger_transaction_with_order(tx_order, &keypair))
+            .collect::<Vec<_>>();
+        DABatch::new(
+            block_number,
+            tx_order_start,
+            tx_order_end,
+            &tx_list,
+            &keypair,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_fs_backend_round_trip() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root = tmp_dir.path().to_path_buf();
+        std::fs::create_dir_all(root.join(TEST_NAMESPACE)).unwrap();
+        let open_da_config = DABackendOpenDAConfig {
+            scheme: OpenDAScheme::Fs,
+            config: HashMap::from([("root".to_string(), root.to_string_lossy().to_string())]),
+            namespace: Some(TEST_NAMESPACE.to_string()),
+            // split chunks into multiple segments
+            max_segment_size: Some(512),
+            max_retries: None,
+        };
+        let backend = OpenDABackendManager::new(&open_da_config).await.unwrap();
+
+        let batch_v0 = random_batch(0, 1, 16);
+        let mut batch_v1 = random_batch(1, 17, 32);
+        let keypair = RoochKeyPair::generate_secp256k1();
+        batch_v1.sign_state_root(H256::random(), &keypair);
+        backend
+            .submit_batch(Arc::new(batch_v0.clone()))
+            .await
+            .unwrap();
+        backend
+            .submit_batch(Arc::new(batch_v1.clone()))
+            .await
+            .unwrap();
+
+        let chunks = backend.list_chunks().await.unwrap();
+        assert_eq!(chunks.keys().copied().collect::<Vec<_>>(), vec![0, 1]);
+        for segment_numbers in chunks.values() {
+            assert!(segment_numbers.len() > 1);
+            assert_eq!(
+                segment_numbers,
+                &(0..segment_numbers.len() as u64).collect::<Vec<_>>()
+            );
+        }
+
+        let segment_id = SegmentID {
+            chunk_id: 1,
+            segment_number: 0,
+        };
+        let segment = backend.get_segment(segment_id).await.unwrap().unwrap();
+        assert_eq!(segment.get_id(), segment_id);
+        assert!(!segment.is_last());
+        let missing_segment_id = SegmentID {
+            chunk_id: 2,
+            segment_number: 0,
+        };
+        assert!(backend
+            .get_segment(missing_segment_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        assert_eq!(
+            backend.get_batches(0).await.unwrap(),
+            Some(vec![batch_v0.clone()])
+        );
+        assert_eq!(
+            backend.get_batches(1).await.unwrap(),
+            Some(vec![batch_v1.clone()])
+        );
+        assert_eq!(backend.get_batches(2).await.unwrap(), None);
+
+        // the chunk is incomplete if the last segment is missing
+        let last_segment_id = SegmentID {
+            chunk_id: 0,
+            segment_number: *chunks[&0].last().unwrap(),
+        };
+        std::fs::remove_file(root.join(TEST_NAMESPACE).join(last_segment_id.to_string())).unwrap();
+        assert_eq!(backend.get_batches(0).await.unwrap(), None);
+
+        // the broken segment is rejected
+        std::fs::write(
+            root.join(TEST_NAMESPACE).join(segment_id.to_string()),
+            b"broken segment",
+        )
+        .unwrap();
+        assert!(backend.get_segment(segment_id).await.is_err());
+        assert!(backend.get_batches(1).await.is_err());
+    }
 }
@@ -4,7 +4,7 @@
 use crate::backend::openda::adapter::{AdapterSubmitStat, OpenDAAdapter};
 use async_trait::async_trait;
 use opendal::layers::{LoggingLayer, RetryLayer};
-use opendal::{Operator, Scheme};
+use opendal::{EntryMode, ErrorKind, Operator, Scheme};
 use rooch_config::da_config::OpenDAScheme;
 use rooch_types::da::segment::SegmentID;
 use std::collections::HashMap;
@@ -46,6 +46,27 @@ impl OpenDalAdapter {
         w.close().await?;
         Ok(())
     }
+
+    async fn get(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        let path = segment_id.to_string();
+        match self.operator.read(&path).await {
+            Ok(buf) => Ok(Some(buf.to_vec())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // segments are stored flat under root, named by segment_id
+    async fn list(&self) -> anyhow::Result<Vec<SegmentID>> {
+        let entries = self.operator.list("/").await?;
+        let mut segment_ids = entries
+            .iter()
+            .filter(|entry| entry.metadata().mode() == EntryMode::FILE)
+            .filter_map(|entry| entry.name().parse::<SegmentID>().ok())
+            .collect::<Vec<_>>();
+        segment_ids.sort();
+        Ok(segment_ids)
+    }
 }
 
 #[async_trait]
@@ -66,4 +87,12 @@ impl OpenDAAdapter for OpenDalAdapter {
             Err(error) => Err(error),
         }
     }
+
+    async fn get_segment(&self, segment_id: SegmentID) -> anyhow::Result<Option<Vec<u8>>> {
+        self.get(segment_id).await
+    }
+
+    async fn list_segments(&self) -> anyhow::Result<Vec<SegmentID>> {
+        self.list().await
+    }
 }
@@ -0,0 +1,287 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::RoochDB;
+use anyhow::{ensure, format_err, Result};
+use moveos_config::store_config::RocksdbConfig;
+use moveos_store::config_store::ConfigDBStore;
+use moveos_types::startup_info::StartupInfo;
+use prometheus::Registry;
+use raw_store::metrics::DBMetrics;
+use raw_store::rocks::RocksDB;
+use raw_store::StoreInstance;
+use rooch_config::store_config::{DEFAULT_DB_INDEXER_SUBDIR, DEFAULT_DB_STORE_SUBDIR};
+use rooch_store::meta_store::MetaDBStore;
+use rooch_types::backup::BackupManifest;
+use rooch_types::sequencer::SequencerInfo;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl RoochDB {
+    /// Take a RocksDB checkpoint of the moveos and rooch stores into the store dir of `backup_dir`,
+    /// it should be called between the tx executions, so the checkpoint never contains a half
+    /// processed tx. The checkpoint hard links the RocksDB files, so it is cheap.
+    pub fn checkpoint_store(&self, backup_dir: &Path) -> Result<()> {
+        ensure!(
+            !backup_dir.exists(),
+            "Backup dir {:?} already exists",
+            backup_dir
+        );
+        std::fs::create_dir_all(backup_dir)?;
+        self.rooch_store
+            .store_instance
+            .create_checkpoint(&backup_dir.join(DEFAULT_DB_STORE_SUBDIR))
+    }
+}
+
+/// Write the manifest of the backup in `backup_dir`, after the store checkpoint is taken and the
+/// `indexer_tables` are copied into the indexer dir. The layout of the backup dir is the same as
+/// the rooch db dir. The indexer must be backed up before any tx is executed after the
+/// checkpoint, otherwise the restored indexer is ahead of the store and fails to index the txs
+/// re-sequenced at the same orders.
+pub fn write_backup_manifest(
+    chain_id: u64,
+    backup_dir: &Path,
+    indexer_tables: Vec<String>,
+) -> Result<BackupManifest> {
+    // Read the meta from the checkpoint rather than the live store, so it matches the backup
+    let (sequencer_info, startup_info) =
+        read_store_meta(&backup_dir.join(DEFAULT_DB_STORE_SUBDIR))?;
+    let created_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
+    let manifest = BackupManifest {
+        chain_id,
+        created_at,
+        sequencer_order: sequencer_info.last_order,
+        state_root: startup_info.state_root,
+        state_size: startup_info.size,
+        indexer_tables,
+    };
+    manifest.save(backup_dir)?;
+    Ok(manifest)
+}
+
+/// Validate the backup in `backup_dir` with its manifest, return the manifest if the backup is
+/// complete and the store meta matches the manifest.
+pub fn validate_backup(backup_dir: &Path, chain_id: u64) -> Result<BackupManifest> {
+    let manifest = BackupManifest::load(backup_dir)?;
+    ensure!(
+        manifest.chain_id == chain_id,
+        "Backup chain id {} mismatch with {}",
+        manifest.chain_id,
+        chain_id
+    );
+    let indexer_dir = backup_dir.join(DEFAULT_DB_INDEXER_SUBDIR);
+    for table in &manifest.indexer_tables {
+        ensure!(
+            indexer_dir.join(table).is_file(),
+            "Backup indexer table {} not found in {:?}",
+            table,
+            indexer_dir
+        );
+    }
+
+    let (sequencer_info, startup_info) =
+        read_store_meta(&backup_dir.join(DEFAULT_DB_STORE_SUBDIR))?;
+    ensure!(
+        sequencer_info.last_order == manifest.sequencer_order,
+        "Backup sequencer order {} mismatch with manifest {}",
+        sequencer_info.last_order,
+        manifest.sequencer_order
+    );
+    ensure!(
+        startup_info.state_root == manifest.state_root && startup_info.size == manifest.state_size,
+        "Backup state root {:?}(size: {}) mismatch with manifest {:?}(size: {})",
+        startup_info.state_root,
+        startup_info.size,
+        manifest.state_root,
+        manifest.state_size
+    );
+    Ok(manifest)
+}
+
+/// Restore the validated backup in `backup_dir` into the rooch db dir, the node must be stopped
+/// and the store and indexer dirs in the rooch db dir must be empty.
+pub fn restore_backup(
+    backup_dir: &Path,
+    rooch_db_dir: &Path,
+    chain_id: u64,
+) -> Result<BackupManifest> {
+    let manifest = validate_backup(backup_dir, chain_id)?;
+    let store_dir = rooch_db_dir.join(DEFAULT_DB_STORE_SUBDIR);
+    let indexer_dir = rooch_db_dir.join(DEFAULT_DB_INDEXER_SUBDIR);
+    ensure_empty_dir(&store_dir)?;
+    ensure_empty_dir(&indexer_dir)?;
+
+    copy_dir_all(&backup_dir.join(DEFAULT_DB_STORE_SUBDIR), &store_dir)?;
+    std::fs::create_dir_all(&indexer_dir)?;
+    let backup_indexer_dir = backup_dir.join(DEFAULT_DB_INDEXER_SUBDIR);
+    for table in &manifest.indexer_tables {
+        std::fs::copy(backup_indexer_dir.join(table), indexer_dir.join(table))?;
+    }
+    Ok(manifest)
+}
+
+fn read_store_meta(store_dir: &Path) -> Result<(SequencerInfo, StartupInfo)> {
+    ensure!(store_dir.is_dir(), "Store dir {:?} not found", store_dir);
+    let db = RocksDB::open_with_cfs(
+        store_dir,
+        RoochDB::column_families(),
+        true,
+        RocksdbConfig::default(),
+    )?;
+    let registry = Registry::new();
+    let mut instance =
+        StoreInstance::new_db_instance(db, DBMetrics::get_or_init(&registry).clone());
+    let sequencer_info = MetaDBStore::new(instance.clone()).get_sequencer_info();
+    let startup_info = ConfigDBStore::new(instance.clone()).get_startup_info();
+    instance.cancel_metrics_task()?;
+
+    let sequencer_info = sequencer_info?
+        .ok_or_else(|| format_err!("Sequencer info not found in {:?}", store_dir))?;
+    let startup_info =
+        startup_info?.ok_or_else(|| format_err!("Startup info not found in {:?}", store_dir))?;
+    Ok((sequencer_info, startup_info))
+}
+
+fn ensure_empty_dir(dir: &Path) -> Result<()> {
+    if dir.exists() {
+        ensure!(
+            std::fs::read_dir(dir)?.next().is_none(),
+            "Dir {:?} is not empty",
+            dir
+        );
+    }
+    Ok(())
+}
+
+fn copy_dir_all(src: &Path, dst: &Path) -> Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &dst.join(entry.file_name()))?;
+        } else {
+            std::fs::copy(entry.path(), dst.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use accumulator::accumulator_info::AccumulatorInfo;
+    use moveos_types::h256::H256;
+    use rooch_config::RoochOpt;
+    use rooch_types::rooch_network::BuiltinChainID;
+
+    fn save_store_meta(rooch_db: &RoochDB, last_order: u64, state_root: H256) -> Result<()> {
+        rooch_db
+            .rooch_store
+            .get_meta_store()
+            .save_sequencer_info_unsafe(SequencerInfo::new(
+                last_order,
+                AccumulatorInfo::new(H256::random(), vec![], last_order + 1, last_order + 1),
+            ))?;
+        rooch_db
+            .moveos_store
+            .config_store
+            .save_startup_info(StartupInfo::new(state_root, last_order + 1))
+    }
+
+    fn backup(rooch_db: &RoochDB, chain_id: u64, backup_dir: &Path) -> Result<BackupManifest> {
+        rooch_db.checkpoint_store(backup_dir)?;
+        let indexer_tables = rooch_db
+            .indexer_store
+            .backup_sqlite(&backup_dir.join(DEFAULT_DB_INDEXER_SUBDIR))?;
+        write_backup_manifest(chain_id, backup_dir, indexer_tables)
+    }
+
+    #[test]
+    fn test_backup_and_restore() -> Result<()> {
+        let chain_id: u64 = BuiltinChainID::Local.chain_id().id();
+        let opt = RoochOpt::new_with_temp_store()?;
+        let rooch_db = RoochDB::init_with_mock_metrics_for_test(opt.store_config())?;
+        let state_root = H256::random();
+        save_store_meta(&rooch_db, 10, state_root)?;
+
+        let backup_dir = opt.store_config().get_backup_dir().join("test");
+        let manifest = backup(&rooch_db, chain_id, &backup_dir)?;
+        assert_eq!(manifest.chain_id, chain_id);
+        assert_eq!(manifest.sequencer_order, 10);
+        assert_eq!(manifest.state_root, state_root);
+        assert_eq!(manifest.state_size, 11);
+        assert!(!manifest.indexer_tables.is_empty());
+
+        // The writes after the checkpoint are not in the backup
+        save_store_meta(&rooch_db, 11, H256::random())?;
+        assert_eq!(validate_backup(&backup_dir, chain_id)?, manifest);
+        assert!(backup(&rooch_db, chain_id, &backup_dir).is_err());
+
+        let restore_opt = RoochOpt::new_with_temp_store()?;
+        let restore_db_dir = restore_opt.store_config().get_rooch_db_dir();
+        assert_eq!(
+            restore_backup(&backup_dir, &restore_db_dir, chain_id)?,
+            manifest
+        );
+        for table in &manifest.indexer_tables {
+            assert!(restore_opt
+                .store_config()
+                .get_indexer_dir()
+                .join(table)
+                .is_file());
+        }
+        let restored_db = RoochDB::init_with_mock_metrics_for_test(restore_opt.store_config())?;
+        let sequencer_info = restored_db
+            .rooch_store
+            .get_meta_store()
+            .get_sequencer_info()?
+            .unwrap();
+        assert_eq!(sequencer_info.last_order, 10);
+        assert_eq!(
+            restored_db.moveos_store.config_store.get_startup_info()?,
+            Some(StartupInfo::new(state_root, 11))
+        );
+
+        // The restored store dir is not empty anymore
+        assert!(restore_backup(&backup_dir, &restore_db_dir, chain_id).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_backup() -> Result<()> {
+        let chain_id: u64 = BuiltinChainID::Local.chain_id().id();
+        let opt = RoochOpt::new_with_temp_store()?;
+        let rooch_db = RoochDB::init_with_mock_metrics_for_test(opt.store_config())?;
+        save_store_meta(&rooch_db, 5, H256::random())?;
+        let backup_dir = opt.store_config().get_backup_dir().join("test");
+        let manifest = backup(&rooch_db, chain_id, &backup_dir)?;
+
+        assert!(validate_backup(&backup_dir, chain_id + 1).is_err());
+
+        let mut tampered = manifest.clone();
+        tampered.state_root = H256::random();
+        tampered.save(&backup_dir)?;
+        assert!(validate_backup(&backup_dir, chain_id).is_err());
+
+        let mut tampered = manifest.clone();
+        tampered.sequencer_order += 1;
+        tampered.save(&backup_dir)?;
+        assert!(validate_backup(&backup_dir, chain_id).is_err());
+
+        manifest.save(&backup_dir)?;
+        assert_eq!(validate_backup(&backup_dir, chain_id)?, manifest);
+        let table = &manifest.indexer_tables[0];
+        std::fs::remove_file(backup_dir.join(DEFAULT_DB_INDEXER_SUBDIR).join(table))?;
+        assert!(validate_backup(&backup_dir, chain_id).is_err());
+
+        let restore_opt = RoochOpt::new_with_temp_store()?;
+        assert!(restore_backup(
+            &backup_dir,
+            &restore_opt.store_config().get_rooch_db_dir(),
+            chain_id
+        )
+        .is_err());
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ use moveos_common::utils::to_bytes;
 use moveos_store::config_store::STARTUP_INFO_KEY;
 use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
 use moveos_store::{
-    MoveOSStore, CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME,
+    MoveOSStore, CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME, STATE_NODE_LOG_COLUMN_FAMILY_NAME,
     TRANSACTION_EXECUTION_INFO_COLUMN_FAMILY_NAME,
 };
 use moveos_types::access_path::AccessPath;
@@ -22,7 +22,7 @@ use prometheus::Registry;
 use raw_store::metrics::DBMetrics;
 use raw_store::rocks::batch::WriteBatch;
 use raw_store::traits::DBStore;
-use raw_store::{rocks::RocksDB, StoreInstance};
+use raw_store::{rocks::RocksDB, ColumnFamilyName, StoreInstance};
 use rooch_config::store_config::StoreConfig;
 use rooch_indexer::store::traits::IndexerStoreTrait;
 use rooch_indexer::{indexer_reader::IndexerReader, list_field_indexer_keys, IndexerStore};
@@ -43,6 +43,8 @@ use rooch_types::indexer::state::{
 use rooch_types::sequencer::SequencerInfo;
 use tracing::error;
 
+pub mod backup;
+
 #[derive(Clone)]
 pub struct RoochDB {
     pub moveos_store: MoveOSStore,
@@ -62,11 +64,10 @@ impl RoochDB {
         instance: StoreInstance,
         registry: &Registry,
     ) -> Result<Self> {
-        let indexer_dir = config.get_indexer_dir();
         let moveos_store = MoveOSStore::new_with_instance(instance.clone(), registry)?;
         let rooch_store = RoochStore::new_with_instance(instance.clone(), registry)?;
-        let indexer_store = IndexerStore::new(indexer_dir.clone(), registry)?;
-        let indexer_reader = IndexerReader::new(indexer_dir, registry)?;
+        let indexer_store = IndexerStore::new_with_store_config(config, registry)?;
+        let indexer_reader = IndexerReader::new_with_store_config(config, registry)?;
 
         Ok(Self {
             moveos_store,
@@ -81,6 +82,19 @@ impl RoochDB {
         registry: &Registry,
     ) -> Result<StoreInstance> {
         let store_dir = config.get_store_dir();
+        let column_families = Self::column_families();
+
+        let db_metrics = DBMetrics::get_or_init(registry).clone();
+        let instance = StoreInstance::new_db_instance(
+            RocksDB::new(store_dir, column_families, config.rocksdb_config())?,
+            db_metrics,
+        );
+
+        Ok(instance)
+    }
+
+    /// The column families of the moveos store and rooch store, they share one RocksDB
+    pub fn column_families() -> Vec<ColumnFamilyName> {
         let mut column_families = moveos_store::StoreMeta::get_column_family_names().to_vec();
         column_families.append(&mut rooch_store::StoreMeta::get_column_family_names().to_vec());
         //ensure no duplicate column families
@@ -92,14 +106,7 @@ impl RoochDB {
                 }
             });
         }
-
-        let db_metrics = DBMetrics::get_or_init(registry).clone();
-        let instance = StoreInstance::new_db_instance(
-            RocksDB::new(store_dir, column_families, config.rocksdb_config())?,
-            db_metrics,
-        );
-
-        Ok(instance)
+        column_families
     }
 
     pub fn init_with_mock_metrics_for_test(config: &StoreConfig) -> Result<Self> {
@@ -137,6 +144,86 @@ impl RoochDB {
         Ok(())
     }
 
+    /// revert the sequenced but not executed txs in one step, they must be the last txs in order.
+    /// the sequencer info is rolled back to the tx before them,
+    /// the startup info is kept since the state is not changed by these txs.
+    pub fn revert_unexecuted_txs(&self, txs: Vec<(u64, H256)>) -> Result<()> {
+        let (first_tx_order, _) = *txs
+            .first()
+            .ok_or_else(|| anyhow!("revert txs failed: no tx to revert"))?;
+        let last_sequencer_info = self
+            .rooch_store
+            .get_meta_store()
+            .get_sequencer_info()?
+            .ok_or_else(|| anyhow!("Load sequencer info failed. database is inconsistent"))?;
+        // ensure txs are the last txs in order and not executed
+        for (i, (tx_order, tx_hash)) in txs.iter().enumerate() {
+            if *tx_order != first_tx_order + i as u64 {
+                return Err(anyhow!(
+                    "revert txs failed: tx_order {} is not continuous",
+                    tx_order
+                ));
+            }
+            if self.moveos_store.get_tx_execution_info(*tx_hash)?.is_some() {
+                return Err(anyhow!(
+                    "revert txs failed: tx {:?} has been executed",
+                    tx_hash
+                ));
+            }
+        }
+        let last_tx_order = first_tx_order + txs.len() as u64 - 1;
+        if last_tx_order != last_sequencer_info.last_order {
+            return Err(anyhow!(
+                "revert txs failed: tx_order {} is not last tx_order {}",
+                last_tx_order,
+                last_sequencer_info.last_order
+            ));
+        }
+        if first_tx_order == 0 {
+            return Err(anyhow!("revert txs failed: genesis tx can not be reverted"));
+        }
+
+        let previous_tx_order = first_tx_order - 1;
+        let previous_ledger_tx = self
+            .rooch_store
+            .transaction_store
+            .get_tx_by_order(previous_tx_order)?
+            .ok_or_else(|| {
+                anyhow!(
+                    "revert txs failed: ledger tx(previous) not found for tx_order {}. database is inconsistent",
+                    previous_tx_order
+                )
+            })?;
+        let previous_sequencer_info = SequencerInfo::new(
+            previous_tx_order,
+            previous_ledger_tx.sequence_info.tx_accumulator_info(),
+        );
+
+        let inner_store = &self.rooch_store.store_instance;
+        let mut write_batch = WriteBatch::new();
+        let mut cf_names = vec![];
+        for (tx_order, tx_hash) in &txs {
+            write_batch.delete(to_bytes(tx_hash)?)?; // tx_hash:tx
+            write_batch.delete(to_bytes(tx_order)?)?; // tx_order:tx_hash
+            cf_names.push(TRANSACTION_COLUMN_FAMILY_NAME);
+            cf_names.push(TX_SEQUENCE_INFO_MAPPING_COLUMN_FAMILY_NAME);
+        }
+        write_batch.put(
+            to_bytes(SEQUENCER_INFO_KEY)?,
+            to_bytes(&previous_sequencer_info)?,
+        )?;
+        cf_names.push(META_SEQUENCER_INFO_COLUMN_FAMILY_NAME);
+        inner_store.write_batch_across_cfs(cf_names, write_batch, true)?;
+
+        tracing::info!(
+            "revert unexecuted txs succeed: tx_order {}..={}, previous_tx_order: {}",
+            first_tx_order,
+            last_tx_order,
+            previous_tx_order,
+        );
+        Ok(())
+    }
+
     /// revert tx unsafe with these operations:
     /// 1. remove the tx (atomic)
     /// 2. revert indexer
@@ -246,6 +333,7 @@ impl RoochDB {
         update_startup: bool,
     ) -> Result<()> {
         let state_change_set_ext_opt = self.rooch_store.get_state_change_set(tx_order)?;
+        let execution_info_opt = self.moveos_store.get_tx_execution_info(tx_hash)?;
 
         let inner_store = &self.rooch_store.store_instance;
         let mut write_batch = WriteBatch::new();
@@ -260,6 +348,15 @@ impl RoochDB {
             TRANSACTION_EXECUTION_INFO_COLUMN_FAMILY_NAME,
             STATE_CHANGE_SET_COLUMN_FAMILY_NAME,
         ];
+        // the state pruner must not index the tx until it is removed
+        let index_guard = self.moveos_store.get_prune_store().lock_state_index();
+        if let Some(execution_info) = execution_info_opt {
+            // revert the state node refcounts if the tx has been indexed by the state pruner
+            self.moveos_store
+                .unindex_state_node_log(tx_order, execution_info.state_root)?;
+            write_batch.delete(to_bytes(&execution_info.state_root)?)?; // state_root:state_node_log
+            cf_names.push(STATE_NODE_LOG_COLUMN_FAMILY_NAME);
+        }
 
         // save sequencer info and startup info for setup with previous tx values
         if update_startup {
@@ -285,6 +382,7 @@ impl RoochDB {
         }
 
         inner_store.write_batch_across_cfs(cf_names, write_batch, true)?;
+        drop(index_guard);
 
         // revert the indexer
         self.revert_indexer(tx_order, state_change_set_ext_opt)
@@ -1,7 +1,12 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use moveos_types::transaction::TransactionExecutionInfo;
+use rooch_types::indexer::event::IndexerEvent;
+use rooch_types::indexer::state::IndexerObjectState;
+use rooch_types::indexer::transaction::IndexerTransaction;
 use rooch_types::service_status::ServiceStatus;
+use rooch_types::transaction::LedgerTransaction;
 
 #[derive(Default, Clone, Debug)]
 pub struct GasUpgradeEvent {}
@@ -10,3 +15,14 @@ pub struct GasUpgradeEvent {}
 pub struct ServiceStatusEvent {
     pub status: ServiceStatus,
 }
+
+/// The transaction and its events and object changes are indexed
+#[derive(Clone, Debug)]
+pub struct TransactionIndexedEvent {
+    pub ledger_transaction: LedgerTransaction,
+    pub execution_info: TransactionExecutionInfo,
+    pub indexer_transaction: IndexerTransaction,
+    pub events: Vec<IndexerEvent>,
+    /// The new and updated object states
+    pub object_states: Vec<IndexerObjectState>,
+}
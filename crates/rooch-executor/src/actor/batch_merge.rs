@@ -0,0 +1,120 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use move_core_types::effects::Op;
+use move_core_types::language_storage::TypeTag;
+use move_core_types::u256::U256;
+use moveos_object_runtime::read_write_set::ReadSet;
+use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
+use moveos_types::moveos_std::timestamp::Timestamp;
+use moveos_types::state::{MoveState, MoveStructType, ObjectChange, StateChangeSet};
+use moveos_types::state_resolver::StateResolver;
+use rooch_types::framework::coin_store::CoinStore;
+use rooch_types::framework::gas_coin::RGas;
+use std::collections::BTreeSet;
+
+/// Merge the writes of the system pre_execute and post_execute functions in a speculative change set
+/// on top of the latest state, so the transactions of a batch do not conflict on the global objects
+/// written by every transaction.
+///
+/// * The `Timestamp` is updated to the sequenced tx timestamp before the action, the update is kept
+///   if it is not behind the latest timestamp, which is the same as executing the transactions in order.
+/// * The transaction fee pool, gas revenue and storage deposit pool stores are embedded `CoinStore<RGas>`,
+///   the system functions only deposit to them, or withdraw the amount deposited by the same transaction,
+///   so the balance change of the speculative execution is applied to the latest balance.
+///
+/// Returns false if any of the `conflicts` can not be merged, then the transaction must be re-executed.
+pub(crate) fn merge_system_writes(
+    change_set: &mut StateChangeSet,
+    read_set: &ReadSet,
+    conflicts: &BTreeSet<ObjectID>,
+    base: &impl StateResolver,
+    latest: &impl StateResolver,
+) -> Result<bool> {
+    for object_id in conflicts {
+        if object_id.parent() != Some(ObjectID::root()) {
+            return Ok(false);
+        }
+        let change = match change_set.changes.get_mut(&object_id.field_key()) {
+            Some(change) => change,
+            // The transaction only reads the object, it depends on the stale value
+            None => return Ok(false),
+        };
+        let merged = if *object_id == Timestamp::object_id() {
+            merge_timestamp(change, latest)?
+        } else if is_embedded_gas_coin_store(&change.metadata)
+            && !read_set.is_read_by_action(object_id)
+        {
+            merge_gas_coin_store(change, base, latest)?
+        } else {
+            false
+        };
+        if !merged {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+fn is_embedded_gas_coin_store(metadata: &ObjectMeta) -> bool {
+    metadata.is_embeded()
+        && matches!(&metadata.object_type, TypeTag::Struct(struct_tag) if **struct_tag == CoinStore::<RGas>::struct_tag())
+}
+
+fn merge_timestamp(change: &ObjectChange, latest: &impl StateResolver) -> Result<bool> {
+    let timestamp = match &change.value {
+        Some(Op::Modify(bytes)) if change.fields.is_empty() => Timestamp::from_bytes(bytes)?,
+        _ => return Ok(false),
+    };
+    let latest_timestamp = match latest.get_object(&Timestamp::object_id())? {
+        Some(state) => state.value_as::<Timestamp>()?,
+        None => return Ok(false),
+    };
+    Ok(timestamp.milliseconds >= latest_timestamp.milliseconds)
+}
+
+fn merge_gas_coin_store(
+    change: &mut ObjectChange,
+    base: &impl StateResolver,
+    latest: &impl StateResolver,
+) -> Result<bool> {
+    let speculative = match &change.value {
+        Some(Op::Modify(bytes)) if change.fields.is_empty() => {
+            CoinStore::<RGas>::from_bytes(bytes)?
+        }
+        _ => return Ok(false),
+    };
+    let object_id = &change.metadata.id;
+    let (base, latest) = match (base.get_object(object_id)?, latest.get_object(object_id)?) {
+        (Some(base), Some(latest)) => (
+            base.value_as::<CoinStore<RGas>>()?,
+            latest.value_as::<CoinStore<RGas>>()?,
+        ),
+        _ => return Ok(false),
+    };
+    if speculative.frozen() != base.frozen() || latest.frozen() != base.frozen() {
+        return Ok(false);
+    }
+    let merged_balance = if speculative.balance() >= base.balance() {
+        latest
+            .balance()
+            .checked_add(speculative.balance() - base.balance())
+    } else if speculative.balance() == U256::zero() {
+        // The withdrawal may be capped by the stale balance, such as the storage rebate
+        None
+    } else {
+        latest
+            .balance()
+            .checked_sub(base.balance() - speculative.balance())
+    };
+    match merged_balance {
+        Some(balance) => {
+            change.value = Some(Op::Modify(
+                CoinStore::<RGas>::new(balance, speculative.frozen()).to_bytes(),
+            ));
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
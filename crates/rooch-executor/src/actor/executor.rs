@@ -1,32 +1,42 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use super::batch_merge::merge_system_writes;
+use super::fee_block::FeeBlockTracker;
 use super::messages::{
-    ConvertL2TransactionData, DryRunTransactionMessage, DryRunTransactionResult,
+    ApplyChangeSetMessage, ConvertL2TransactionData, DryRunTransactionMessage,
+    DryRunTransactionResult, ExecuteTransactionBatchMessage, ExecuteTransactionBatchResult,
     ExecuteTransactionMessage, ExecuteTransactionResult, GetRootMessage, SaveStateChangeSetMessage,
     ValidateL1BlockMessage, ValidateL1TxMessage, ValidateL2TxMessage,
 };
 use crate::metrics::ExecutorMetrics;
-use anyhow::Result;
+use anyhow::{bail, ensure, Result};
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
 use function_name::named;
+use move_core_types::account_address::AccountAddress;
 use move_core_types::vm_status::VMStatus;
 use moveos::moveos::{MoveOS, MoveOSConfig};
 use moveos::vm::vm_status_explainer::explain_vm_status;
 use moveos_eventbus::bus::EventData;
+use moveos_object_runtime::read_write_set::WriteSet;
 use moveos_store::MoveOSStore;
 use moveos_types::function_return_value::FunctionResult;
 use moveos_types::module_binding::MoveFunctionCaller;
 use moveos_types::move_std::option::MoveOption;
+use moveos_types::moveos_std::event::Event;
 use moveos_types::moveos_std::object::ObjectMeta;
-use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::moveos_std::tx_context::{GasPaymentAccount, TxContext};
 use moveos_types::moveos_std::tx_meta::TxMeta;
-use moveos_types::state::{ObjectState, StateChangeSetExt};
+use moveos_types::state::{ObjectState, StateChangeSet, StateChangeSetExt};
 use moveos_types::state_resolver::RootObjectResolver;
-use moveos_types::transaction::{FunctionCall, MoveOSTransaction, VerifiedMoveAction};
+use moveos_types::transaction::{
+    FunctionCall, MoveOSTransaction, RawTransactionOutput, TransactionExecutionInfo,
+    VerifiedMoveAction,
+};
 use moveos_types::transaction::{MoveAction, VerifiedMoveOSTransaction};
 use prometheus::Registry;
+use rayon::prelude::*;
 use rooch_event::actor::{EventActor, EventActorSubscribeMessage, GasUpgradeMessage};
 use rooch_event::event::GasUpgradeEvent;
 use rooch_genesis::FrameworksGasParameters;
@@ -39,12 +49,13 @@ use rooch_types::framework::auth_validator::{
     AuthValidatorCaller, BuiltinAuthValidator, TxValidateResult,
 };
 use rooch_types::framework::ethereum::EthereumModule;
+use rooch_types::framework::transaction_fee::{BaseFee, FeeBlockGasUsed};
 use rooch_types::framework::transaction_validator::TransactionValidator;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
 use rooch_types::multichain_id::RoochMultiChainID;
 use rooch_types::transaction::authenticator::AUTH_PAYLOAD_SIZE;
 use rooch_types::transaction::{
-    AuthenticatorInfo, L1Block, L1BlockWithBody, L1Transaction, RoochTransaction,
+    Authenticator, AuthenticatorInfo, L1Block, L1BlockWithBody, L1Transaction, RoochTransaction,
     RoochTransactionData,
 };
 use std::str::FromStr;
@@ -57,6 +68,7 @@ pub struct ExecutorActor {
     rooch_store: RoochStore,
     metrics: Arc<ExecutorMetrics>,
     event_actor: Option<LocalActorRef<EventActor>>,
+    fee_block_tracker: FeeBlockTracker,
 }
 
 type ValidateAuthenticatorResult = Result<TxValidateResult, VMStatus>;
@@ -87,6 +99,7 @@ impl ExecutorActor {
             rooch_store,
             metrics: Arc::new(ExecutorMetrics::new(registry)),
             event_actor,
+            fee_block_tracker: FeeBlockTracker::default(),
         })
     }
 
@@ -117,25 +130,218 @@ impl ExecutorActor {
     }
 
     #[named]
-    pub fn execute(&mut self, tx: VerifiedMoveOSTransaction) -> Result<ExecuteTransactionResult> {
+    pub fn execute(
+        &mut self,
+        mut tx: VerifiedMoveOSTransaction,
+    ) -> Result<ExecuteTransactionResult> {
         let fn_name = function_name!();
         let _timer = self
             .metrics
             .executor_execute_tx_latency_seconds
             .with_label_values(&[fn_name])
             .start_timer();
-        let tx_hash = tx.ctx.tx_hash();
-        let size = tx.ctx.tx_size;
+        self.add_fee_block_gas_used(&mut tx.ctx)?;
+        let ctx = tx.ctx.clone();
         let (raw_output, _) = self.moveos.execute_only(tx)?;
+        self.handle_raw_output(fn_name, &ctx, raw_output)
+    }
+
+    /// Add the gas used in the current fee block to the tx context, the base fee is adjusted by it
+    /// when the transaction begins a new fee block.
+    pub fn add_fee_block_gas_used(&mut self, ctx: &mut TxContext) -> Result<()> {
+        let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
+        if let Some(fee_block_gas_used) = self.fee_block_tracker.gas_used_before(
+            &resolver,
+            ctx,
+            &self.moveos_store,
+            &self.rooch_store,
+        )? {
+            ctx.add(fee_block_gas_used)?;
+        }
+        Ok(())
+    }
+
+    /// Execute a sequenced batch of transactions optimistically in parallel,
+    /// the results are the same as executing the transactions one by one in order.
+    ///
+    /// All the transactions are speculatively executed on top of the current root in parallel,
+    /// then committed in order. A speculative output is committed if the objects read and written by
+    /// the transaction are not written by the previously committed transactions of the batch,
+    /// or the conflicting objects are only written by the system functions and can be merged, see `merge_system_writes`.
+    /// Otherwise the transaction is re-executed on top of the latest root.
+    ///
+    /// If the L2 transactions are given, every transaction after the first one is validated again
+    /// on top of the root committed by the previous transactions before it is committed.
+    #[named]
+    pub fn execute_batch(
+        &mut self,
+        txs: Vec<VerifiedMoveOSTransaction>,
+        l2_txs: Option<Vec<RoochTransaction>>,
+    ) -> ExecuteTransactionBatchResult {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .executor_execute_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut batch_result = ExecuteTransactionBatchResult::default();
+        if let Err(error) = self.execute_batch_inner(fn_name, txs, l2_txs, &mut batch_result) {
+            batch_result.error = Some(error);
+        }
+        batch_result
+    }
+
+    fn execute_batch_inner(
+        &mut self,
+        fn_name: &str,
+        txs: Vec<VerifiedMoveOSTransaction>,
+        l2_txs: Option<Vec<RoochTransaction>>,
+        batch_result: &mut ExecuteTransactionBatchResult,
+    ) -> Result<()> {
+        if let Some(l2_txs) = &l2_txs {
+            ensure!(
+                l2_txs.len() == txs.len(),
+                "The number of L2 transactions {} does not match the number of transactions {}",
+                l2_txs.len(),
+                txs.len()
+            );
+        }
+        let base_root = self.root.clone();
+        // The gas used in the fee block before the later transactions is unknown before the previous
+        // transactions are committed, so the speculative execution uses the one before the first transaction.
+        // It only affects the transaction which begins a new fee block and writes the `BaseFee`.
+        let speculative_fee_block_gas_used = match txs.first() {
+            Some(tx) => {
+                let mut ctx = tx.ctx.clone();
+                self.add_fee_block_gas_used(&mut ctx)?;
+                ctx.get::<FeeBlockGasUsed>()?
+            }
+            None => None,
+        };
+        let moveos = &self.moveos;
+        let speculative_results = txs
+            .par_iter()
+            .map(|tx| {
+                let mut tx = tx.clone();
+                tx.root = base_root.clone();
+                if let Some(fee_block_gas_used) = speculative_fee_block_gas_used {
+                    tx.ctx.add(fee_block_gas_used)?;
+                }
+                moveos.execute_with_read_set(tx)
+            })
+            .collect::<Vec<_>>();
+
+        let mut committed_write_set = WriteSet::default();
+        // The module or gas schedule upgrade invalidates the speculative outputs after it
+        let mut upgraded = false;
+        let base_fee_id = BaseFee::base_fee_object_id();
+        for (index, (mut tx, speculative_result)) in
+            txs.into_iter().zip(speculative_results).enumerate()
+        {
+            let tx_hash = tx.ctx.tx_hash();
+            // The transactions are validated on top of the root before the batch,
+            // the previous transactions of the batch may invalidate them.
+            if let Some(l2_tx) = l2_txs.as_ref().and_then(|l2_txs| l2_txs.get(index)) {
+                if index > 0 {
+                    self.validate_l2_tx(l2_tx.clone())?;
+                }
+            }
+            self.add_fee_block_gas_used(&mut tx.ctx)?;
+            let fee_block_gas_used = tx.ctx.get::<FeeBlockGasUsed>()?;
+            let speculative_output = match speculative_result {
+                Ok((mut raw_output, _, read_set)) if !upgraded => {
+                    let write_set = WriteSet::from_change_set(&raw_output.changeset);
+                    let fee_block_changed = fee_block_gas_used != speculative_fee_block_gas_used
+                        && write_set.objects().contains(&base_fee_id);
+                    let conflicts =
+                        read_set
+                            .conflicting_objects(&committed_write_set)
+                            .map(|mut conflicts| {
+                                conflicts
+                                    .extend(write_set.conflicting_objects(&committed_write_set));
+                                conflicts
+                            });
+                    let mergeable = match conflicts {
+                        Some(conflicts) if !fee_block_changed => {
+                            conflicts.is_empty()
+                                || merge_system_writes(
+                                    &mut raw_output.changeset,
+                                    &read_set,
+                                    &conflicts,
+                                    &RootObjectResolver::new(base_root.clone(), &self.moveos_store),
+                                    &RootObjectResolver::new(self.root.clone(), &self.moveos_store),
+                                )?
+                        }
+                        _ => false,
+                    };
+                    if mergeable {
+                        Some((raw_output, write_set))
+                    } else {
+                        None
+                    }
+                }
+                // The speculative execution error may be caused by the stale state, re-execute it
+                _ => None,
+            };
+            let (raw_output, write_set) = match speculative_output {
+                Some((mut raw_output, write_set)) => {
+                    rebase_change_set(&mut raw_output.changeset, &base_root, &self.root)?;
+                    (raw_output, write_set)
+                }
+                None => {
+                    tracing::debug!(
+                        "executor re-execute tx in batch, tx_hash: {:?}, upgraded: {}",
+                        tx_hash,
+                        upgraded
+                    );
+                    self.metrics.executor_parallel_reexecuted_tx_count.inc();
+                    batch_result.reexecuted_count += 1;
+                    tx.root = self.root.clone();
+                    let (raw_output, _) = self.moveos.execute_only(tx.clone())?;
+                    let write_set = WriteSet::from_change_set(&raw_output.changeset);
+                    (raw_output, write_set)
+                }
+            };
+            committed_write_set.merge(write_set);
+            self.metrics.executor_parallel_executed_tx_count.inc();
+
+            let is_upgrade = raw_output.is_upgrade;
+            let is_gas_upgrade = raw_output.is_gas_upgrade;
+            batch_result
+                .results
+                .push(self.handle_raw_output(fn_name, &tx.ctx, raw_output)?);
+            if is_upgrade {
+                self.moveos.flush_module_cache(is_upgrade)?;
+            }
+            // The following transactions of the batch must be executed with the new gas parameters
+            if is_gas_upgrade {
+                self.reload_moveos()?;
+            }
+            upgraded |= is_upgrade || is_gas_upgrade;
+        }
+        Ok(())
+    }
+
+    fn handle_raw_output(
+        &mut self,
+        fn_name: &str,
+        ctx: &TxContext,
+        raw_output: RawTransactionOutput,
+    ) -> Result<ExecuteTransactionResult> {
         let is_gas_upgrade = raw_output.is_gas_upgrade;
 
-        let (output, execution_info) = self.moveos_store.handle_tx_output(tx_hash, raw_output)?;
+        let (output, execution_info) = self
+            .moveos_store
+            .handle_tx_output(ctx.tx_hash(), raw_output)?;
 
         self.root = execution_info.root_metadata();
+        let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
+        self.fee_block_tracker
+            .on_executed(&resolver, ctx, output.gas_used)?;
         self.metrics
             .executor_execute_tx_bytes
             .with_label_values(&[fn_name])
-            .observe(size as f64);
+            .observe(ctx.tx_size as f64);
 
         if is_gas_upgrade {
             if let Some(event_actor) = self.event_actor.clone() {
@@ -149,6 +355,20 @@ impl ExecutorActor {
         })
     }
 
+    fn reload_moveos(&mut self) -> Result<()> {
+        let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
+        let gas_parameters = FrameworksGasParameters::load_from_chain(&resolver)?;
+
+        self.moveos = MoveOS::new(
+            self.moveos_store.clone(),
+            gas_parameters.all_natives(),
+            MoveOSConfig::default(),
+            system_pre_execute_functions(),
+            system_post_execute_functions(),
+        )?;
+        Ok(())
+    }
+
     #[named]
     pub fn dry_run(&mut self, tx: VerifiedMoveOSTransaction) -> Result<DryRunTransactionResult> {
         let fn_name = function_name!();
@@ -284,8 +504,34 @@ impl ExecutorActor {
         tracing::debug!("executor validate_l2_tx: {:?}, sender: {}", tx_hash, sender);
 
         let authenticator = tx.authenticator_info();
+        let sponsor = tx.data.sponsor;
+        let sponsor_authenticator = tx.sponsor_authenticator().cloned();
+        if sponsor.is_some() != sponsor_authenticator.is_some() {
+            bail!(
+                "The sponsor {:?} of the transaction {:?} does not match the sponsor authenticator",
+                sponsor,
+                tx_hash
+            );
+        }
+        // The sponsor in the transaction data is added to the context as the gas payment account
         let mut moveos_tx: MoveOSTransaction = tx.into_moveos_transaction(self.root.clone());
         let tx_size = moveos_tx.ctx.tx_size;
+        // The sponsor must be validated before the sender, the gas balance is checked on the sponsor
+        if let Some(sponsor_authenticator) = sponsor_authenticator {
+            match self.validate_sponsor(&moveos_tx.ctx, sponsor_authenticator)? {
+                Ok(_) => {}
+                Err(e) => {
+                    let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
+                    let status_view = explain_vm_status(&resolver, e.clone())?;
+                    tracing::warn!(
+                        "transaction validate sponsor vm error, tx_hash: {:?}, error:{:?}",
+                        tx_hash,
+                        status_view,
+                    );
+                    return Err(e.into());
+                }
+            }
+        }
         let tx_result = self.validate_authenticator(&moveos_tx.ctx, authenticator);
         let result = match tx_result {
             Ok(vm_result) => match vm_result {
@@ -378,6 +624,24 @@ impl ExecutorActor {
         Ok(vm_result)
     }
 
+    #[named]
+    pub fn validate_sponsor(
+        &self,
+        ctx: &TxContext,
+        sponsor_authenticator: Authenticator,
+    ) -> Result<Result<AccountAddress, VMStatus>> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .executor_validate_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let tx_validator = self.as_module_binding::<TransactionValidator>();
+        Ok(tx_validator
+            .validate_sponsor(ctx, sponsor_authenticator)?
+            .into_result())
+    }
+
     pub fn convert_to_verified_tx_for_dry_run(
         &self,
         tx_data: RoochTransactionData,
@@ -398,6 +662,9 @@ impl ExecutorActor {
 
         let tx_metadata = TxMeta::new_from_move_action(&tx_data.action);
         tx_ctx.add(tx_metadata).unwrap();
+        if let Some(sponsor) = tx_data.sponsor {
+            tx_ctx.add(GasPaymentAccount::new(sponsor.into()))?;
+        }
 
         let mut bitcoin_address = BitcoinAddress::from_str("18cBEMRxXHqzWWCxZNtU91F5sbUNKhL5PX")?;
 
@@ -425,6 +692,7 @@ impl ExecutorActor {
                 module_bundle,
                 init_function_modules: vec![],
             },
+            MoveAction::MultiCall(call) => VerifiedMoveAction::MultiCall { call },
         };
 
         Ok(VerifiedMoveOSTransaction::new(
@@ -439,6 +707,30 @@ impl ExecutorActor {
         self.moveos.flush_module_cache(is_upgrade)
     }
 
+    /// Apply the store changes of a transaction committed by another node without re-executing it.
+    #[named]
+    pub fn apply_change_set(
+        &mut self,
+        execution_info: TransactionExecutionInfo,
+        state_change_set: StateChangeSet,
+        events: Vec<Event>,
+    ) -> Result<StateChangeSet> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .executor_execute_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let state_change_set = self.moveos_store.apply_tx_change_set(
+            self.root.clone(),
+            execution_info,
+            state_change_set,
+            events,
+        )?;
+        self.root = state_change_set.root_metadata();
+        Ok(state_change_set)
+    }
+
     pub fn save_state_change_set(
         &mut self,
         tx_order: u64,
@@ -503,6 +795,28 @@ impl Handler<ExecuteTransactionMessage> for ExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<ExecuteTransactionBatchMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteTransactionBatchMessage,
+        _ctx: &mut ActorContext,
+    ) -> ExecuteTransactionBatchResult {
+        self.execute_batch(msg.txs, msg.l2_txs)
+    }
+}
+
+#[async_trait]
+impl Handler<ApplyChangeSetMessage> for ExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: ApplyChangeSetMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<StateChangeSet> {
+        self.apply_change_set(msg.execution_info, msg.state_change_set, msg.events)
+    }
+}
+
 #[async_trait]
 impl Handler<GetRootMessage> for ExecutorActor {
     async fn handle(
@@ -525,6 +839,27 @@ impl Handler<SaveStateChangeSetMessage> for ExecutorActor {
     }
 }
 
+/// Move the speculative change set from the base root on top of the latest root,
+/// only the root object is shared by the non-conflicting transactions.
+fn rebase_change_set(
+    change_set: &mut StateChangeSet,
+    base_root: &ObjectMeta,
+    root: &ObjectMeta,
+) -> Result<()> {
+    let global_size = (root.size + change_set.global_size)
+        .checked_sub(base_root.size)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Invalid global size {} of the change set, base root size: {}",
+                change_set.global_size,
+                base_root.size
+            )
+        })?;
+    change_set.state_root = root.state_root();
+    change_set.global_size = global_size;
+    Ok(())
+}
+
 impl MoveFunctionCaller for ExecutorActor {
     fn call_function(&self, ctx: &TxContext, call: FunctionCall) -> Result<FunctionResult> {
         Ok(self
@@ -538,17 +873,7 @@ impl Handler<EventData> for ExecutorActor {
     async fn handle(&mut self, message: EventData, _ctx: &mut ActorContext) -> Result<()> {
         if let Ok(_gas_upgrade_msg) = message.data.downcast::<GasUpgradeEvent>() {
             tracing::info!("ExecutorActor: Reload the MoveOS instance...");
-
-            let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
-            let gas_parameters = FrameworksGasParameters::load_from_chain(&resolver)?;
-
-            self.moveos = MoveOS::new(
-                self.moveos_store.clone(),
-                gas_parameters.all_natives(),
-                MoveOSConfig::default(),
-                system_pre_execute_functions(),
-                system_post_execute_functions(),
-            )?;
+            self.reload_moveos()?;
         }
         Ok(())
     }
@@ -0,0 +1,146 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, Result};
+use moveos_store::transaction_store::TransactionStore;
+use moveos_store::MoveOSStore;
+use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::state_resolver::StateResolver;
+use rooch_store::RoochStore;
+use rooch_types::framework::transaction_fee::{BaseFee, FeeBlockGasUsed};
+use rooch_types::transaction::{LedgerTxData, TransactionSequenceInfo};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FeeBlockState {
+    /// The order of the first transaction in the fee block.
+    start_tx_order: u64,
+    /// The order of the next transaction to execute.
+    next_tx_order: u64,
+    /// The gas used by the L2 transactions in [start_tx_order, next_tx_order).
+    gas_used: u64,
+}
+
+/// Sum the gas used by the L2 transactions in the current fee block of the base fee market.
+///
+/// The `BaseFee` only records the first transaction of the fee block, the executor provides the gas used
+/// in the fee block to the transaction context as `FeeBlockGasUsed`, so the transactions do not write
+/// the `BaseFee` except the first one of every fee block.
+/// The sum is kept in memory while the transactions are executed in order, and it is rebuilt from
+/// the store after restart or revert, so the tx context is the same for every node executing the transaction.
+#[derive(Debug, Default)]
+pub struct FeeBlockTracker {
+    state: Option<FeeBlockState>,
+}
+
+impl FeeBlockTracker {
+    /// Returns the gas used in the current fee block before the transaction.
+    /// Returns None if the transaction is not a sequenced L2 transaction, or the base fee market is not created.
+    pub fn gas_used_before(
+        &mut self,
+        resolver: &impl StateResolver,
+        ctx: &TxContext,
+        moveos_store: &MoveOSStore,
+        rooch_store: &RoochStore,
+    ) -> Result<Option<FeeBlockGasUsed>> {
+        if ctx.is_system_call() {
+            return Ok(None);
+        }
+        let tx_order = match ctx.get::<TransactionSequenceInfo>()? {
+            Some(sequence_info) => sequence_info.tx_order,
+            None => return Ok(None),
+        };
+        let start_tx_order = match get_base_fee(resolver)? {
+            Some(base_fee) => base_fee.fee_block_start_tx_order,
+            None => return Ok(None),
+        };
+        let state = match self.state {
+            Some(state)
+                if state.start_tx_order == start_tx_order && state.next_tx_order == tx_order =>
+            {
+                state
+            }
+            _ => {
+                let state = Self::rebuild(start_tx_order, tx_order, moveos_store, rooch_store)?;
+                self.state = Some(state);
+                state
+            }
+        };
+        Ok(Some(FeeBlockGasUsed::new(state.gas_used)))
+    }
+
+    /// Record the gas used by the executed transaction, the `resolver` is on the state root after the transaction.
+    pub fn on_executed(
+        &mut self,
+        resolver: &impl StateResolver,
+        ctx: &TxContext,
+        gas_used: u64,
+    ) -> Result<()> {
+        let tx_order = match ctx.get::<TransactionSequenceInfo>()? {
+            Some(sequence_info) => sequence_info.tx_order,
+            None => return Ok(()),
+        };
+        let start_tx_order = match get_base_fee(resolver)? {
+            Some(base_fee) => base_fee.fee_block_start_tx_order,
+            None => {
+                self.state = None;
+                return Ok(());
+            }
+        };
+        let gas_used = if ctx.is_system_call() { 0 } else { gas_used };
+        self.state = match self.state {
+            Some(state)
+                if state.start_tx_order == start_tx_order && state.next_tx_order == tx_order =>
+            {
+                Some(FeeBlockState {
+                    start_tx_order,
+                    next_tx_order: tx_order + 1,
+                    gas_used: state.gas_used.saturating_add(gas_used),
+                })
+            }
+            // The transaction begins a new fee block
+            _ if start_tx_order == tx_order => Some(FeeBlockState {
+                start_tx_order,
+                next_tx_order: tx_order + 1,
+                gas_used,
+            }),
+            _ => None,
+        };
+        Ok(())
+    }
+
+    fn rebuild(
+        start_tx_order: u64,
+        tx_order: u64,
+        moveos_store: &MoveOSStore,
+        rooch_store: &RoochStore,
+    ) -> Result<FeeBlockState> {
+        let mut gas_used = 0u64;
+        for order in start_tx_order..tx_order {
+            let mut ledger_tx = rooch_store
+                .get_transaction_store()
+                .get_tx_by_order(order)?
+                .ok_or_else(|| anyhow!("The tx with order {} should exists", order))?;
+            if !matches!(ledger_tx.data, LedgerTxData::L2Tx(_)) {
+                continue;
+            }
+            let tx_hash = ledger_tx.tx_hash();
+            let execution_info = moveos_store
+                .get_transaction_store()
+                .get_tx_execution_info(tx_hash)?
+                .ok_or_else(|| anyhow!("Execution info not found for tx {:?}", tx_hash))?;
+            gas_used = gas_used.saturating_add(execution_info.gas_used);
+        }
+        Ok(FeeBlockState {
+            start_tx_order,
+            next_tx_order: tx_order,
+            gas_used,
+        })
+    }
+}
+
+fn get_base_fee(resolver: &impl StateResolver) -> Result<Option<BaseFee>> {
+    resolver
+        .get_object(&BaseFee::base_fee_object_id())?
+        .map(|state| Ok(state.into_object::<BaseFee>()?.value))
+        .transpose()
+}
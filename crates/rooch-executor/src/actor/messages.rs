@@ -5,18 +5,22 @@ use anyhow::Result;
 use coerce::actor::message::Message;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
+use moveos_gas_profiling::log::TransactionGasLog;
 use moveos_types::access_path::AccessPath;
 use moveos_types::function_return_value::AnnotatedFunctionResult;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{AnnotatedEvent, Event, EventID};
 use moveos_types::moveos_std::object::ObjectMeta;
-use moveos_types::state::{AnnotatedState, FieldKey, ObjectState, StateChangeSetExt};
+use moveos_types::state::{
+    AnnotatedState, FieldKey, ObjectState, StateChangeSet, StateChangeSetExt,
+};
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::transaction::TransactionOutput;
 use moveos_types::transaction::VerifiedMoveOSTransaction;
 use moveos_types::transaction::{FunctionCall, RawTransactionOutput, VMErrorInfo};
 use rooch_types::address::MultiChainAddress;
+use rooch_types::proof::StateProof;
 use rooch_types::transaction::{
     L1BlockWithBody, L1Transaction, RoochTransaction, RoochTransactionData,
 };
@@ -64,6 +68,41 @@ impl Message for ExecuteTransactionMessage {
     type Result = Result<ExecuteTransactionResult>;
 }
 
+/// Execute a sequenced batch of transactions in parallel, the results are in the same order as the transactions.
+/// If the L2 transactions of the batch are given, they are validated again before they are committed.
+#[derive(Debug)]
+pub struct ExecuteTransactionBatchMessage {
+    pub txs: Vec<VerifiedMoveOSTransaction>,
+    pub l2_txs: Option<Vec<RoochTransaction>>,
+}
+
+impl Message for ExecuteTransactionBatchMessage {
+    type Result = ExecuteTransactionBatchResult;
+}
+
+/// The results of the executed transactions of a batch, in the same order as the transactions.
+/// If a transaction fails, the `error` is set, and the transactions after it are not executed.
+#[derive(Debug, Default)]
+pub struct ExecuteTransactionBatchResult {
+    pub results: Vec<ExecuteTransactionResult>,
+    /// The count of the executed transactions whose speculative output is discarded because of the conflicts.
+    pub reexecuted_count: usize,
+    pub error: Option<anyhow::Error>,
+}
+
+/// Apply the store changes of a transaction committed by another node without re-executing it,
+/// returns the applied state change set.
+#[derive(Debug)]
+pub struct ApplyChangeSetMessage {
+    pub execution_info: TransactionExecutionInfo,
+    pub state_change_set: StateChangeSet,
+    pub events: Vec<Event>,
+}
+
+impl Message for ApplyChangeSetMessage {
+    type Result = Result<StateChangeSet>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExecuteViewFunctionMessage {
     pub call: FunctionCall,
@@ -83,6 +122,25 @@ impl Message for StatesMessage {
     type Result = Result<Vec<Option<ObjectState>>>;
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetStateProofsMessage {
+    pub state_root: Option<H256>,
+    pub access_path: AccessPath,
+}
+
+impl Message for GetStateProofsMessage {
+    type Result = Result<Vec<StateProof>>;
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetStateNodesMessage {
+    pub node_hashes: Vec<H256>,
+}
+
+impl Message for GetStateNodesMessage {
+    type Result = Result<Vec<Option<Vec<u8>>>>;
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResolveMessage {
     pub address: MultiChainAddress,
@@ -243,3 +301,15 @@ pub struct DryRunTransactionResult {
     pub raw_output: RawTransactionOutput,
     pub vm_error_info: Option<VMErrorInfo>,
 }
+
+#[derive(Debug)]
+pub struct ReplayTransactionResult {
+    pub tx_order: u64,
+    /// The state root before the transaction, the transaction is replayed on it
+    pub pre_state_root: H256,
+    /// The execution info recorded when the transaction was executed
+    pub execution_info: TransactionExecutionInfo,
+    pub raw_output: RawTransactionOutput,
+    pub vm_error_info: Option<VMErrorInfo>,
+    pub gas_log: Option<TransactionGasLog>,
+}
@@ -1,6 +1,8 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod batch_merge;
 pub mod executor;
+pub mod fee_block;
 pub mod messages;
 pub mod reader_executor;
@@ -4,7 +4,8 @@
 use super::messages::{
     AnnotatedStatesMessage, CheckStateChangeSetsMessage, ExecuteViewFunctionMessage,
     GetAnnotatedEventsByEventHandleMessage, GetAnnotatedEventsByEventIDsMessage,
-    GetEventsByEventHandleMessage, GetStateChangeSetsMessage, RefreshStateMessage, StatesMessage,
+    GetEventsByEventHandleMessage, GetStateChangeSetsMessage, GetStateNodesMessage,
+    GetStateProofsMessage, RefreshStateMessage, StatesMessage,
 };
 use crate::actor::messages::{
     GetEventsByEventIDsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
@@ -33,6 +34,7 @@ use rooch_event::event::GasUpgradeEvent;
 use rooch_genesis::FrameworksGasParameters;
 use rooch_store::RoochStore;
 use rooch_types::framework::{system_post_execute_functions, system_pre_execute_functions};
+use rooch_types::proof::{FieldProof, StateProof};
 
 pub struct ReaderExecutorActor {
     root: ObjectMeta,
@@ -156,6 +158,46 @@ impl Handler<StatesMessage> for ReaderExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<GetStateProofsMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: GetStateProofsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<StateProof>, anyhow::Error> {
+        let state_root = msg.state_root.unwrap_or(self.root.state_root());
+        let state_store = self.moveos_store.get_state_store();
+        let query = msg.access_path.into_state_query().into_fields_query()?;
+        query
+            .into_iter()
+            .map(|(object_id, field_key)| {
+                let mut proofs = vec![];
+                let mut current_state_root = state_root;
+                for key in StateProof::field_key_path(&object_id, field_key) {
+                    let (state, proof) =
+                        state_store.get_field_at_with_proof(current_state_root, key)?;
+                    let next_state_root = state.as_ref().map(|state| state.state_root());
+                    proofs.push(FieldProof {
+                        field_key: key,
+                        state,
+                        proof,
+                    });
+                    match next_state_root {
+                        Some(next_state_root) => current_state_root = next_state_root,
+                        None => break,
+                    }
+                }
+                Ok(StateProof {
+                    state_root,
+                    object_id,
+                    field_key,
+                    proofs,
+                })
+            })
+            .collect()
+    }
+}
+
 #[async_trait]
 impl Handler<AnnotatedStatesMessage> for ReaderExecutorActor {
     async fn handle(
@@ -301,6 +343,19 @@ impl Handler<GetEventsByEventIDsMessage> for ReaderExecutorActor {
     }
 }
 
+#[async_trait]
+impl Handler<GetStateNodesMessage> for ReaderExecutorActor {
+    async fn handle(
+        &mut self,
+        msg: GetStateNodesMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<Option<Vec<u8>>>> {
+        self.moveos_store
+            .get_state_node_store()
+            .get_nodes(&msg.node_hashes)
+    }
+}
+
 #[async_trait]
 impl Handler<GetTxExecutionInfosByHashMessage> for ReaderExecutorActor {
     async fn handle(
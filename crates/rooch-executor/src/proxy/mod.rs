@@ -3,10 +3,11 @@
 
 use crate::actor::messages::{
     CheckStateChangeSetsMessage, ConvertL2TransactionData, DryRunTransactionResult,
-    GetAnnotatedEventsByEventIDsMessage, GetEventsByEventHandleMessage, GetEventsByEventIDsMessage,
-    GetStateChangeSetsMessage, GetTxExecutionInfosByHashMessage, ListAnnotatedStatesMessage,
-    ListStatesMessage, RefreshStateMessage, SaveStateChangeSetMessage, ValidateL1BlockMessage,
-    ValidateL1TxMessage,
+    ExecuteTransactionBatchResult, GetAnnotatedEventsByEventIDsMessage,
+    GetEventsByEventHandleMessage, GetEventsByEventIDsMessage, GetStateChangeSetsMessage,
+    GetStateNodesMessage, GetStateProofsMessage, GetTxExecutionInfosByHashMessage,
+    ListAnnotatedStatesMessage, ListStatesMessage, RefreshStateMessage, SaveStateChangeSetMessage,
+    ValidateL1BlockMessage, ValidateL1TxMessage,
 };
 use crate::actor::reader_executor::ReaderExecutorActor;
 use crate::actor::{
@@ -27,7 +28,7 @@ use moveos_types::moveos_std::account::Account;
 use moveos_types::moveos_std::event::{Event, EventID};
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::moveos_std::tx_context::TxContext;
-use moveos_types::state::{FieldKey, StateChangeSetExt};
+use moveos_types::state::{FieldKey, StateChangeSet, StateChangeSetExt};
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::FunctionCall;
 use moveos_types::transaction::TransactionExecutionInfo;
@@ -39,6 +40,8 @@ use moveos_types::{
 };
 use rooch_types::bitcoin::network::BitcoinNetwork;
 use rooch_types::framework::chain_id::ChainID;
+use rooch_types::framework::transaction_fee::BaseFee;
+use rooch_types::proof::StateProof;
 use rooch_types::transaction::{
     L1BlockWithBody, L1Transaction, RoochTransaction, RoochTransactionData,
 };
@@ -97,6 +100,49 @@ impl ExecutorProxy {
         Ok((result.output, result.transaction_info))
     }
 
+    /// Execute a sequenced batch of transactions in parallel,
+    /// the transactions after the failed one are not executed, see `ExecuteTransactionBatchResult`.
+    pub async fn execute_transaction_batch(
+        &self,
+        txs: Vec<VerifiedMoveOSTransaction>,
+    ) -> Result<ExecuteTransactionBatchResult> {
+        Ok(self
+            .actor
+            .send(crate::actor::messages::ExecuteTransactionBatchMessage { txs, l2_txs: None })
+            .await?)
+    }
+
+    /// Execute a batch of L2 transactions, every transaction is validated again
+    /// on top of the state committed by the previous transactions of the batch.
+    pub async fn execute_l2_transaction_batch(
+        &self,
+        txs: Vec<VerifiedMoveOSTransaction>,
+        l2_txs: Vec<RoochTransaction>,
+    ) -> Result<ExecuteTransactionBatchResult> {
+        Ok(self
+            .actor
+            .send(crate::actor::messages::ExecuteTransactionBatchMessage {
+                txs,
+                l2_txs: Some(l2_txs),
+            })
+            .await?)
+    }
+
+    pub async fn apply_change_set(
+        &self,
+        execution_info: TransactionExecutionInfo,
+        state_change_set: StateChangeSet,
+        events: Vec<Event>,
+    ) -> Result<StateChangeSet> {
+        self.actor
+            .send(crate::actor::messages::ApplyChangeSetMessage {
+                execution_info,
+                state_change_set,
+                events,
+            })
+            .await?
+    }
+
     pub async fn dry_run_transaction(
         &self,
         tx: VerifiedMoveOSTransaction,
@@ -130,6 +176,26 @@ impl ExecutorProxy {
             .await?
     }
 
+    /// Get the states and their proofs in the state tree at the given state_root(the latest if None).
+    pub async fn get_state_proofs(
+        &self,
+        access_path: AccessPath,
+        state_root: Option<H256>,
+    ) -> Result<Vec<StateProof>> {
+        self.reader_actor
+            .send(GetStateProofsMessage {
+                state_root,
+                access_path,
+            })
+            .await?
+    }
+
+    pub async fn get_state_nodes(&self, node_hashes: Vec<H256>) -> Result<Vec<Option<Vec<u8>>>> {
+        self.reader_actor
+            .send(GetStateNodesMessage { node_hashes })
+            .await?
+    }
+
     pub async fn get_annotated_states(
         &self,
         access_path: AccessPath,
@@ -306,6 +372,21 @@ impl ExecutorProxy {
             .and_then(|state| Ok(state.into_object::<BitcoinNetwork>()?.value))
     }
 
+    /// Get the current base fee per gas unit, the initial base fee is returned
+    /// if no transaction has updated the base fee yet.
+    pub async fn base_fee(&self) -> Result<u64> {
+        Ok(self
+            .get_states(AccessPath::object(BaseFee::base_fee_object_id()), None)
+            .await?
+            .pop()
+            .flatten()
+            .map(|state| state.into_object::<BaseFee>())
+            .transpose()?
+            .map_or(BaseFee::INITIAL_BASE_FEE, |base_fee| {
+                base_fee.value.base_fee
+            }))
+    }
+
     //TODO provide a trait to abstract the async state reader, elemiate the duplicated code bwteen RpcService and Client
     pub async fn get_sequence_number(&self, address: AccountAddress) -> Result<u64> {
         Ok(self
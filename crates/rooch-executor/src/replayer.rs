@@ -0,0 +1,122 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::executor::ExecutorActor;
+use crate::actor::messages::ReplayTransactionResult;
+use anyhow::{bail, ensure, Result};
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use prometheus::Registry;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::transaction::LedgerTxData;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// The default max number of the transactions replayed at the same time
+pub const DEFAULT_MAX_CONCURRENT_REPLAYS: usize = 4;
+
+/// TransactionReplayer re-executes the transactions on the blocking workers,
+/// so the replay does not block the executor actors.
+/// Every replay loads a new MoveOS on the state root before the transaction,
+/// the number of the concurrent replays is limited.
+#[derive(Clone)]
+pub struct TransactionReplayer {
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+    permits: Arc<Semaphore>,
+}
+
+impl TransactionReplayer {
+    pub fn new(
+        moveos_store: MoveOSStore,
+        rooch_store: RoochStore,
+        max_concurrent_replays: usize,
+    ) -> Self {
+        Self {
+            moveos_store,
+            rooch_store,
+            permits: Arc::new(Semaphore::new(max_concurrent_replays)),
+        }
+    }
+
+    /// Re-execute the transaction on the state root before it, the state is not changed.
+    pub async fn replay_transaction(
+        &self,
+        tx_hash: H256,
+        trace: bool,
+    ) -> Result<Option<ReplayTransactionResult>> {
+        let permit = self.permits.clone().acquire_owned().await?;
+        let replayer = self.clone();
+        tokio::task::spawn_blocking(move || {
+            // The permit is released after the replay is finished, even if the caller is gone.
+            let _permit = permit;
+            replayer.replay(tx_hash, trace)
+        })
+        .await?
+    }
+
+    fn replay(&self, tx_hash: H256, trace: bool) -> Result<Option<ReplayTransactionResult>> {
+        let ledger_tx = match self.rooch_store.get_transaction_by_hash(tx_hash)? {
+            Some(ledger_tx) => ledger_tx,
+            None => return Ok(None),
+        };
+        let tx_order = ledger_tx.sequence_info.tx_order;
+        ensure!(tx_order > 0, "The genesis transaction can not be replayed");
+        let execution_info = self
+            .moveos_store
+            .get_transaction_store()
+            .get_tx_execution_info(tx_hash)?
+            .ok_or_else(|| anyhow::anyhow!("Execution info not found for tx {:?}", tx_hash))?;
+        let pre_tx_hash = self
+            .rooch_store
+            .get_tx_hashes(vec![tx_order - 1])?
+            .pop()
+            .flatten()
+            .ok_or_else(|| anyhow::anyhow!("Tx hash not found for tx_order {}", tx_order - 1))?;
+        let pre_root = self
+            .moveos_store
+            .get_transaction_store()
+            .get_tx_execution_info(pre_tx_hash)?
+            .ok_or_else(|| anyhow::anyhow!("Execution info not found for tx {:?}", pre_tx_hash))?
+            .root_metadata();
+
+        // Use a new executor on the pre state root, so the modules and the gas parameters are
+        // loaded from the state before the transaction, not from the cache of the latest state.
+        let mut executor = ExecutorActor::new(
+            pre_root.clone(),
+            self.moveos_store.clone(),
+            self.rooch_store.clone(),
+            &Registry::new(),
+            None,
+        )?;
+        let mut moveos_tx = match ledger_tx.data {
+            LedgerTxData::L1Block(_) => {
+                bail!("Replaying L1 block is not supported, the block body is not stored")
+            }
+            LedgerTxData::L1Tx(l1_tx) => executor.validate_l1_tx(l1_tx)?,
+            LedgerTxData::L2Tx(l2_tx) => executor.validate_l2_tx(l2_tx)?,
+        };
+        moveos_tx.ctx.add(ledger_tx.sequence_info)?;
+        executor.add_fee_block_gas_used(&mut moveos_tx.ctx)?;
+
+        let (raw_output, vm_error_info, gas_log) = if trace {
+            let (raw_output, vm_error_info, gas_log) =
+                executor.moveos().execute_with_gas_profiler(moveos_tx)?;
+            (raw_output, vm_error_info, Some(gas_log))
+        } else {
+            let (raw_output, vm_error_info) = executor.moveos().execute_only(moveos_tx)?;
+            (raw_output, vm_error_info, None)
+        };
+
+        Ok(Some(ReplayTransactionResult {
+            tx_order,
+            pre_state_root: pre_root.state_root(),
+            execution_info,
+            raw_output,
+            vm_error_info,
+            gas_log,
+        }))
+    }
+}
@@ -23,10 +23,15 @@ use moveos_types::transaction::{FunctionCall, MoveAction, VerifiedMoveOSTransact
 use rooch_config::RoochOpt;
 use rooch_db::RoochDB;
 use rooch_executor::actor::reader_executor::ReaderExecutorActor;
-use rooch_executor::actor::{executor::ExecutorActor, messages::ExecuteTransactionResult};
+use rooch_executor::actor::{
+    executor::ExecutorActor,
+    messages::{ExecuteTransactionBatchResult, ExecuteTransactionResult},
+};
 use rooch_genesis::RoochGenesis;
-use rooch_types::address::BitcoinAddress;
+use rooch_types::address::{BitcoinAddress, RoochAddress};
 use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::coin_store::CoinStore;
 use rooch_types::framework::gas_coin::RGas;
 use rooch_types::framework::transfer::TransferModule;
 use rooch_types::rooch_network::{BuiltinChainID, RoochNetwork};
@@ -148,16 +153,19 @@ impl RustBindingTest {
         &self.rooch_db
     }
 
+    pub fn rooch_dao_address(&self) -> RoochAddress {
+        self.network
+            .genesis_config
+            .rooch_dao
+            .multisign_bitcoin_address
+            .to_rooch_address()
+    }
+
     pub fn get_rgas(&mut self, addr: AccountAddress, amount: U256) -> Result<()> {
         // transfer RGas from rooch dao account to addr
         let function_call =
             TransferModule::create_transfer_coin_action(RGas::struct_tag(), addr, amount);
-        let sender = self
-            .network
-            .genesis_config
-            .rooch_dao
-            .multisign_bitcoin_address
-            .to_rooch_address();
+        let sender = self.rooch_dao_address();
         let sequence_number = self.get_account_sequence_number(sender.into())?;
         let tx_data = RoochTransactionData::new(
             sender,
@@ -175,6 +183,16 @@ impl RustBindingTest {
         Ok(())
     }
 
+    pub fn rgas_balance(&self, addr: RoochAddress) -> Result<U256> {
+        let coin_store_id =
+            AccountCoinStoreModule::account_coin_store_id(addr.into(), RGas::struct_tag());
+        let balance = match self.resolver().get_object(&coin_store_id)? {
+            Some(object) => object.value_as::<CoinStore<RGas>>()?.balance(),
+            None => U256::zero(),
+        };
+        Ok(balance)
+    }
+
     //TODO let the module bundle to execute the function
     pub fn execute(&mut self, tx: RoochTransaction) -> Result<ExecuteTransactionResult> {
         let execute_result = self.execute_as_result(tx)?;
@@ -290,6 +308,28 @@ impl RustBindingTest {
         Ok(result)
     }
 
+    /// Execute a batch of transactions with the parallel executor, the results are in the order of the txs
+    pub fn execute_batch(
+        &mut self,
+        txs: Vec<RoochTransaction>,
+    ) -> Result<ExecuteTransactionBatchResult> {
+        let verified_txs = txs
+            .iter()
+            .map(|tx| self.executor.validate_l2_tx(tx.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        let mut batch_result = self.executor.execute_batch(verified_txs, Some(txs));
+        for result in &batch_result.results {
+            self.root = result.transaction_info.root_metadata();
+            self.events.extend(result.output.events.clone());
+        }
+        self.reader_executor
+            .refresh_state(self.root.clone(), false)?;
+        if let Some(error) = batch_result.error.take() {
+            return Err(error);
+        }
+        Ok(batch_result)
+    }
+
     /// Directly apply a change set to the state and update root
     pub fn apply_changes(&mut self, changes: Vec<ObjectChange>) -> Result<()> {
         let mut change_set = StateChangeSet::new(self.root.state_root(), self.root.size);
@@ -12,7 +12,15 @@ mod chain_id_test;
 mod check_tx_size;
 mod empty_tests;
 mod ethereum_test;
+mod multi_call_tests;
 mod multisign_account_tests;
 mod ord_test;
+mod parallel_execution_tests;
+mod replay_tests;
 mod session_validator_tests;
+mod sponsored_transaction_tests;
+mod state_commitment_chain_test;
+mod storage_deposit_tests;
+mod transaction_validator_tests;
 mod view_function_gas;
+mod webauthn_validator_tests;
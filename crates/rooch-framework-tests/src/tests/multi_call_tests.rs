@@ -0,0 +1,167 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::u256::U256;
+use move_core_types::value::MoveValue;
+use moveos_types::move_types::FunctionId;
+use moveos_types::state::{MoveStructType, MoveType};
+use moveos_types::state_resolver::StateResolver;
+use moveos_types::transaction::{CallArgument, ChainedCall, MoveAction};
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::address::RoochAddress;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::coin_store::CoinStoreInfo;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::str::FromStr;
+
+fn faucet_call(amount: U256) -> ChainedCall {
+    ChainedCall::new(
+        FunctionId::from_str("0x3::gas_coin::faucet_entry").unwrap(),
+        vec![],
+        vec![CallArgument::pure(
+            MoveValue::U256(amount).simple_serialize().unwrap(),
+        )],
+    )
+}
+
+fn withdraw_call(amount: U256) -> ChainedCall {
+    ChainedCall::new(
+        FunctionId::from_str("0x3::account_coin_store::withdraw").unwrap(),
+        vec![RGas::type_tag()],
+        vec![CallArgument::pure(
+            MoveValue::U256(amount).simple_serialize().unwrap(),
+        )],
+    )
+}
+
+fn deposit_call(recipient: RoochAddress, coin: CallArgument) -> ChainedCall {
+    ChainedCall::new(
+        FunctionId::from_str("0x3::account_coin_store::deposit").unwrap(),
+        vec![RGas::type_tag()],
+        vec![
+            CallArgument::pure(
+                MoveValue::Address(recipient.into())
+                    .simple_serialize()
+                    .unwrap(),
+            ),
+            coin,
+        ],
+    )
+}
+
+#[tokio::test]
+async fn test_multi_call() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses()[0];
+    let recipient = keystore.addresses()[1];
+    let amount = U256::from(100u64);
+
+    // The coin returned by the withdraw call is passed to the deposit call
+    let action = MoveAction::new_multi_call(vec![
+        faucet_call(amount),
+        withdraw_call(amount),
+        deposit_call(recipient, CallArgument::result(1, 0)),
+    ]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+
+    let coin_store_id =
+        AccountCoinStoreModule::account_coin_store_id(recipient.into(), RGas::struct_tag());
+    let coin_store = binding_test
+        .resolver()
+        .get_object(&coin_store_id)
+        .unwrap()
+        .unwrap();
+    let coin_store = CoinStoreInfo::try_from(coin_store).unwrap();
+    assert_eq!(coin_store.balance(), amount);
+}
+
+#[tokio::test]
+async fn test_multi_call_invalid_result() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses()[0];
+    let recipient = keystore.addresses()[1];
+    let amount = U256::from(100u64);
+
+    // The coin without drop ability must be used
+    let action = MoveAction::new_multi_call(vec![faucet_call(amount), withdraw_call(amount)]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    assert!(binding_test.execute(tx).is_err());
+
+    // The coin can not be used twice
+    let action = MoveAction::new_multi_call(vec![
+        faucet_call(amount),
+        withdraw_call(amount),
+        deposit_call(recipient, CallArgument::result(1, 0)),
+        deposit_call(recipient, CallArgument::result(1, 0)),
+    ]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    assert!(binding_test.execute(tx).is_err());
+
+    // The result can only refer to the previous calls
+    let action = MoveAction::new_multi_call(vec![
+        faucet_call(amount),
+        deposit_call(recipient, CallArgument::result(2, 0)),
+        withdraw_call(amount),
+    ]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    assert!(binding_test.execute(tx).is_err());
+}
+
+#[tokio::test]
+async fn test_multi_call_private_generics_function() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses()[0];
+    let victim = keystore.addresses()[1];
+    let amount = U256::from(100u64);
+    binding_test.get_rgas(victim.into(), amount).unwrap();
+
+    // The private_generics function can only be called by the module of the type argument,
+    // so it is rejected when the transaction is validated.
+    let action = MoveAction::new_multi_call(vec![ChainedCall::new(
+        FunctionId::from_str("0x3::account_coin_store::transfer_extend").unwrap(),
+        vec![RGas::type_tag()],
+        vec![
+            CallArgument::pure(
+                MoveValue::Address(victim.into())
+                    .simple_serialize()
+                    .unwrap(),
+            ),
+            CallArgument::pure(
+                MoveValue::Address(sender.into())
+                    .simple_serialize()
+                    .unwrap(),
+            ),
+            CallArgument::pure(MoveValue::U256(amount).simple_serialize().unwrap()),
+        ],
+    )]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    assert!(binding_test.execute_as_result(tx).is_err());
+
+    let coin_store_id =
+        AccountCoinStoreModule::account_coin_store_id(victim.into(), RGas::struct_tag());
+    let coin_store = binding_test
+        .resolver()
+        .get_object(&coin_store_id)
+        .unwrap()
+        .unwrap();
+    let coin_store = CoinStoreInfo::try_from(coin_store).unwrap();
+    assert_eq!(coin_store.balance(), amount);
+}
@@ -2,13 +2,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
 use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::transaction::MoveAction;
 use rand::Rng;
 use rooch_types::{
+    address::MultiChainAddress,
     bitcoin::multisign_account::{self, MultisignAccountModule},
     crypto::RoochKeyPair,
+    framework::{
+        address_mapping::{AddressMappingModule, RoochToBitcoinAddressMapping},
+        empty::Empty,
+    },
     genesis_config::G_TEST_CONFIG,
-    transaction::RoochTransactionData,
+    transaction::{
+        authenticator::{Authenticator, BitcoinAuthenticator},
+        RoochTransaction, RoochTransactionData,
+    },
 };
 
 #[tokio::test]
@@ -139,3 +150,164 @@ async fn test_multisign_account_random() {
             .join(",")
     );
 }
+
+#[tokio::test]
+async fn test_multisign_account_update_participants() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let kp1 = RoochKeyPair::generate_secp256k1();
+    let kp2 = RoochKeyPair::generate_secp256k1();
+    let kp3 = RoochKeyPair::generate_secp256k1();
+    let kp4 = RoochKeyPair::generate_secp256k1();
+
+    let u1 = kp1.public().bitcoin_address().unwrap().to_rooch_address();
+    let u2 = kp2.public().bitcoin_address().unwrap().to_rooch_address();
+    let u3 = kp3.public().bitcoin_address().unwrap().to_rooch_address();
+    let u4 = kp4.public().bitcoin_address().unwrap().to_rooch_address();
+
+    let pk1 = kp1.bitcoin_public_key().unwrap().to_bytes();
+    let pk2 = kp2.bitcoin_public_key().unwrap().to_bytes();
+    let pk3 = kp3.bitcoin_public_key().unwrap().to_bytes();
+    let pk4 = kp4.bitcoin_public_key().unwrap().to_bytes();
+
+    let pubkeys = vec![pk1.clone(), pk2.clone(), pk3.clone()];
+    let old_bitcoin_address =
+        multisign_account::generate_multisign_address(2, pubkeys.clone()).unwrap();
+    let multisign_address = old_bitcoin_address.to_rooch_address();
+
+    let action = MultisignAccountModule::initialize_multisig_account_action(2, pubkeys);
+    let tx_data = RoochTransactionData::new_for_test(u1, 0, action);
+    binding_test.execute(tx_data.sign(&kp1)).unwrap();
+
+    // u1 proposes to replace kp3 with kp4, the proposer approves the proposal automatically
+    let action = MultisignAccountModule::propose_participants_update_action(
+        multisign_address.into(),
+        vec![pk4.clone()],
+        vec![pk3.clone()],
+        2,
+    );
+    let tx_data = RoochTransactionData::new_for_test(u1, 1, action);
+    binding_test.execute(tx_data.sign(&kp1)).unwrap();
+
+    let new_bitcoin_address = multisign_account::generate_multisign_address(
+        2,
+        vec![pk1.clone(), pk2.clone(), pk4.clone()],
+    )
+    .unwrap();
+    {
+        let account_module = binding_test.as_module_binding::<MultisignAccountModule>();
+        let proposals = account_module.proposals(multisign_address.into()).unwrap();
+        assert_eq!(proposals.len(), 1);
+        let proposal = &proposals[0];
+        assert_eq!(proposal.status, multisign_account::PROPOSAL_STATUS_PENDING);
+        assert_eq!(proposal.approvals, vec![AccountAddress::from(u1)]);
+        assert_eq!(proposal.new_bitcoin_address, new_bitcoin_address);
+        // The participants are not changed before the proposal is approved
+        assert!(account_module
+            .is_participant(multisign_address.into(), u3.into())
+            .unwrap());
+    }
+
+    // u2 approves the proposal, the approvals reach the threshold and the proposal is executed
+    let action =
+        MultisignAccountModule::approve_participants_update_action(multisign_address.into(), 0);
+    let tx_data = RoochTransactionData::new_for_test(u2, 0, action);
+    binding_test.execute(tx_data.sign(&kp2)).unwrap();
+
+    let account_module = binding_test.as_module_binding::<MultisignAccountModule>();
+    // The executed proposal is pruned
+    let proposals = account_module.proposals(multisign_address.into()).unwrap();
+    assert!(proposals.is_empty());
+    assert_eq!(
+        account_module.threshold(multisign_address.into()).unwrap(),
+        2
+    );
+    assert!(account_module
+        .is_participant(multisign_address.into(), u4.into())
+        .unwrap());
+    assert!(!account_module
+        .is_participant(multisign_address.into(), u3.into())
+        .unwrap());
+
+    let records = account_module
+        .migration_records(multisign_address.into())
+        .unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].from_bitcoin_address, old_bitcoin_address);
+    assert_eq!(records[0].to_bitcoin_address, new_bitcoin_address);
+
+    // The multisign account is rebound to the new BitcoinAddress
+    assert_eq!(
+        RoochToBitcoinAddressMapping::resolve_bitcoin_address(
+            &binding_test.resolver(),
+            multisign_address.into()
+        )
+        .unwrap(),
+        Some(new_bitcoin_address.clone())
+    );
+    // The new BitcoinAddress resolves to the multisign account, not the address derived from it
+    let address_mapping = binding_test.as_module_binding::<AddressMappingModule>();
+    assert_eq!(
+        address_mapping
+            .resolve(MultiChainAddress::from(new_bitcoin_address.clone()))
+            .unwrap(),
+        Some(multisign_address.into())
+    );
+    assert_ne!(new_bitcoin_address.to_rooch_address(), multisign_address);
+
+    // The multisign account can not be initialized again at the new BitcoinAddress
+    let action = MultisignAccountModule::initialize_multisig_account_action(
+        2,
+        vec![pk1.clone(), pk2.clone(), pk4.clone()],
+    );
+    let tx_data = RoochTransactionData::new_for_test(u1, 2, action);
+    assert!(binding_test.execute(tx_data.sign(&kp1)).is_err());
+    assert!(!binding_test
+        .as_module_binding::<MultisignAccountModule>()
+        .is_multisign_account(new_bitcoin_address.to_rooch_address().into())
+        .unwrap());
+
+    // The new participants can sign and execute a transaction of the multisign account
+    binding_test
+        .get_rgas(multisign_address.into(), U256::from(1000_000_000_000u64))
+        .unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(multisign_address, 0, action);
+    let removed_participant_tx = RoochTransaction::new(
+        tx_data.clone(),
+        Authenticator::bitcoin_multisign(vec![
+            BitcoinAuthenticator::sign(&kp2, &tx_data),
+            BitcoinAuthenticator::sign(&kp3, &tx_data),
+        ])
+        .unwrap(),
+    );
+    assert!(binding_test
+        .execute_as_result(removed_participant_tx)
+        .is_err());
+    let tx = RoochTransaction::new(
+        tx_data.clone(),
+        Authenticator::bitcoin_multisign(vec![
+            BitcoinAuthenticator::sign(&kp1, &tx_data),
+            BitcoinAuthenticator::sign(&kp4, &tx_data),
+        ])
+        .unwrap(),
+    );
+    binding_test.execute(tx).unwrap();
+    assert_eq!(
+        binding_test
+            .get_account_sequence_number(multisign_address.into())
+            .unwrap(),
+        1
+    );
+
+    // The removed participant can not propose anymore
+    let action = MultisignAccountModule::propose_participants_update_action(
+        multisign_address.into(),
+        vec![pk3],
+        vec![],
+        2,
+    );
+    let tx_data = RoochTransactionData::new_for_test(u3, 0, action);
+    assert!(binding_test.execute(tx_data.sign(&kp3)).is_err());
+}
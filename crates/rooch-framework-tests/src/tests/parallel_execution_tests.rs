@@ -0,0 +1,94 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::transaction::MoveAction;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::transaction_fee::TransactionFeeModule;
+use rooch_types::transaction::RoochTransactionData;
+
+/// The total RGas held by the gas revenue stores of the sequencer and the system, and the storage deposit pool
+fn fee_pools_balance(
+    binding_test: &binding_test::RustBindingTest,
+    sequencer: AccountAddress,
+) -> U256 {
+    let transaction_fee = binding_test.as_module_binding::<TransactionFeeModule>();
+    transaction_fee.gas_revenue_balance(sequencer).unwrap()
+        + transaction_fee
+            .gas_revenue_balance(ROOCH_FRAMEWORK_ADDRESS)
+            .unwrap()
+        + transaction_fee.storage_deposit_pool_balance().unwrap()
+}
+
+#[tokio::test]
+async fn test_execute_batch() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let sequencer = binding_test.sequencer;
+
+    let kps = (0..4)
+        .map(|_| RoochKeyPair::generate_secp256k1())
+        .collect::<Vec<_>>();
+    let senders = kps
+        .iter()
+        .map(|kp| kp.public().bitcoin_address().unwrap().to_rooch_address())
+        .collect::<Vec<_>>();
+
+    for sequence_number in 0..2u64 {
+        let senders_balance = senders
+            .iter()
+            .map(|sender| binding_test.rgas_balance(*sender).unwrap())
+            .fold(U256::zero(), |total, balance| total + balance);
+        let fee_pools_balance_before = fee_pools_balance(&binding_test, sequencer);
+
+        let txs = kps
+            .iter()
+            .zip(senders.iter())
+            .map(|(kp, sender)| {
+                let action =
+                    MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+                RoochTransactionData::new_for_test(*sender, sequence_number, action).sign(kp)
+            })
+            .collect::<Vec<_>>();
+        let batch_result = binding_test.execute_batch(txs).unwrap();
+        let results = &batch_result.results;
+        assert_eq!(results.len(), kps.len());
+        for result in results {
+            assert_eq!(result.transaction_info.status, KeptVMStatus::Executed);
+        }
+        assert_eq!(
+            binding_test.root().state_root(),
+            results.last().unwrap().transaction_info.state_root
+        );
+        if sequence_number > 0 {
+            // The txs of the existing accounts only conflict on the fee pools written by the system functions,
+            // so all the speculative outputs are merged and committed without re-execution.
+            assert_eq!(batch_result.reexecuted_count, 0);
+            // The merged fee pools receive the gas paid by every sender.
+            let paid_gas = senders
+                .iter()
+                .map(|sender| binding_test.rgas_balance(*sender).unwrap())
+                .fold(senders_balance, |total, balance| total - balance);
+            assert!(paid_gas > U256::zero());
+            assert_eq!(
+                fee_pools_balance(&binding_test, sequencer) - fee_pools_balance_before,
+                paid_gas
+            );
+        }
+    }
+
+    for sender in senders {
+        assert_eq!(
+            binding_test
+                .get_account_sequence_number(sender.into())
+                .unwrap(),
+            2
+        );
+    }
+}
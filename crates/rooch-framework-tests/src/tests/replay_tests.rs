@@ -0,0 +1,85 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::h256::H256;
+use moveos_types::transaction::MoveAction;
+use rooch_executor::replayer::TransactionReplayer;
+use rooch_types::framework::empty::Empty;
+use rooch_types::sequencer::SequencerInfo;
+use rooch_types::transaction::{LedgerTransaction, RoochTransactionData, TransactionSequenceInfo};
+
+#[tokio::test]
+async fn test_replay_transaction() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let sender = binding_test.sequencer;
+    let sequence_number = binding_test.get_account_sequence_number(sender).unwrap();
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let mut tx = RoochTransactionData::new_for_test(sender.into(), sequence_number, action)
+        .sign(binding_test.sequencer_kp());
+    let tx_hash = tx.tx_hash();
+
+    // Execute and save the transaction as the pipeline processor does
+    let mut sequence_info = TransactionSequenceInfo::random();
+    sequence_info.tx_order = 1;
+    let mut verified_tx = binding_test.executor.validate_l2_tx(tx.clone()).unwrap();
+    verified_tx.ctx.add(sequence_info.clone()).unwrap();
+    let result = binding_test.execute_verified_tx(verified_tx).unwrap();
+    let rooch_store = binding_test.rooch_db().rooch_store.clone();
+    let sequencer_info = SequencerInfo::new(1, sequence_info.tx_accumulator_info());
+    rooch_store
+        .save_sequenced_tx(
+            tx_hash,
+            LedgerTransaction::new_l2_tx(tx, sequence_info),
+            sequencer_info,
+            None,
+            true,
+        )
+        .unwrap();
+
+    let replayer = TransactionReplayer::new(
+        binding_test.rooch_db().moveos_store.clone(),
+        rooch_store.clone(),
+        1,
+    );
+    // The replays wait for the permit one by one
+    let (replayed, traced) = tokio::join!(
+        replayer.replay_transaction(tx_hash, false),
+        replayer.replay_transaction(tx_hash, true)
+    );
+    let replayed = replayed.unwrap().unwrap();
+    let traced = traced.unwrap().unwrap();
+    for replay in [&replayed, &traced] {
+        assert_eq!(replay.tx_order, 1);
+        assert_eq!(replay.execution_info.tx_hash, tx_hash);
+        assert_eq!(replay.raw_output.status, KeptVMStatus::Executed);
+        assert_eq!(replay.raw_output.gas_used, result.output.gas_used);
+        assert_eq!(replay.raw_output.events.len(), result.output.events.len());
+    }
+    assert!(replayed.gas_log.is_none());
+    let gas_log = traced.gas_log.unwrap();
+    assert!(!gas_log.exec_io.call_graph.events.is_empty());
+
+    // The state is not changed by the replay
+    assert_eq!(
+        binding_test.get_account_sequence_number(sender).unwrap(),
+        sequence_number + 1
+    );
+
+    assert!(replayer
+        .replay_transaction(H256::random(), false)
+        .await
+        .unwrap()
+        .is_none());
+    let genesis_tx_hash = rooch_store
+        .transaction_store
+        .get_tx_hashes(vec![0])
+        .unwrap()[0]
+        .unwrap();
+    assert!(replayer
+        .replay_transaction(genesis_tx_hash, false)
+        .await
+        .is_err());
+}
@@ -0,0 +1,102 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::u256::U256;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::empty::Empty;
+use rooch_types::transaction::authenticator::BitcoinAuthenticator;
+use rooch_types::transaction::rooch::{RoochTransaction, RoochTransactionData};
+
+#[tokio::test]
+async fn test_sponsor_pays_gas() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(2);
+    let sender = keystore.addresses()[0];
+    let sponsor = keystore.addresses()[1];
+    let sponsor_kp = keystore.get_key_pair(&sponsor, None).unwrap();
+    let sponsor_rgas = U256::from(1000_000_000_000u64);
+    binding_test.get_rgas(sponsor.into(), sponsor_rgas).unwrap();
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action).with_sponsor(sponsor);
+    let sponsor_authenticator = BitcoinAuthenticator::sign_sponsor(&sponsor_kp, &tx_data);
+    let tx = keystore
+        .sign_transaction(&sender, tx_data, None)
+        .unwrap()
+        .with_sponsor_authenticator(sponsor_authenticator.into());
+    let result = binding_test.execute(tx).unwrap();
+    assert!(result.transaction_info.gas_used > 0);
+
+    // The sender only holds the RGas from the local faucet, the gas is paid by the sponsor
+    assert_eq!(
+        binding_test.rgas_balance(sender).unwrap(),
+        U256::from(1000_000_000_000u64)
+    );
+    assert!(binding_test.rgas_balance(sponsor).unwrap() < sponsor_rgas);
+}
+
+#[tokio::test]
+async fn test_reject_stripped_or_replaced_sponsor() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(3);
+    let sender = keystore.addresses()[0];
+    let sponsor = keystore.addresses()[1];
+    let other = keystore.addresses()[2];
+    let sponsor_kp = keystore.get_key_pair(&sponsor, None).unwrap();
+    let other_kp = keystore.get_key_pair(&other, None).unwrap();
+    binding_test
+        .get_rgas(sponsor.into(), U256::from(1000_000_000_000u64))
+        .unwrap();
+    binding_test
+        .get_rgas(other.into(), U256::from(1000_000_000_000u64))
+        .unwrap();
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action).with_sponsor(sponsor);
+    let signed_tx = || -> RoochTransaction {
+        keystore
+            .sign_transaction(&sender, tx_data.clone(), None)
+            .unwrap()
+    };
+
+    // The sponsor authenticator is stripped
+    assert!(binding_test.execute_as_result(signed_tx()).is_err());
+
+    // The sponsor authenticator is replaced by another account
+    let other_authenticator = BitcoinAuthenticator::sign_sponsor(&other_kp, &tx_data);
+    let tx = signed_tx().with_sponsor_authenticator(other_authenticator.into());
+    assert!(binding_test.execute_as_result(tx).is_err());
+
+    // The sponsor signature is not domain separated from the sender signature
+    let sponsor_tx_signature = BitcoinAuthenticator::sign(&sponsor_kp, &tx_data);
+    let tx = signed_tx().with_sponsor_authenticator(sponsor_tx_signature.into());
+    assert!(binding_test.execute_as_result(tx).is_err());
+    let sender_authenticator = signed_tx().authenticator;
+    let tx = signed_tx().with_sponsor_authenticator(sender_authenticator);
+    assert!(binding_test.execute_as_result(tx).is_err());
+
+    // The sponsor is replaced in the transaction data, the sender signature is invalid
+    let sponsor_authenticator = BitcoinAuthenticator::sign_sponsor(&sponsor_kp, &tx_data);
+    let sender_authenticator = signed_tx().authenticator;
+    let tx = RoochTransaction::new(tx_data.clone().with_sponsor(other), sender_authenticator)
+        .with_sponsor_authenticator(sponsor_authenticator.clone().into());
+    assert!(binding_test.execute_as_result(tx).is_err());
+
+    // The sponsor is stripped from the transaction data, the sender signature is invalid
+    let mut stripped_tx_data = tx_data.clone();
+    stripped_tx_data.sponsor = None;
+    let sender_authenticator = signed_tx().authenticator;
+    let tx = RoochTransaction::new(stripped_tx_data, sender_authenticator)
+        .with_sponsor_authenticator(sponsor_authenticator.clone().into());
+    assert!(binding_test.execute_as_result(tx).is_err());
+
+    let tx = signed_tx().with_sponsor_authenticator(sponsor_authenticator.into());
+    binding_test.execute(tx).unwrap();
+}
@@ -0,0 +1,130 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::{AbortLocation, KeptVMStatus};
+use moveos_types::h256::H256;
+use moveos_types::module_binding::{ModuleBinding, MoveFunctionCaller};
+use moveos_types::transaction::{FunctionCall, MoveAction};
+use rooch_types::block::Block;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::state_commitment_chain::StateCommitmentChainModule;
+use rooch_types::transaction::RoochTransactionData;
+
+fn assert_scc_abort(status: KeptVMStatus, expect_code: u64) {
+    match status {
+        KeptVMStatus::MoveAbort(AbortLocation::Module(module_id), code) => {
+            assert_eq!(module_id, StateCommitmentChainModule::module_id());
+            assert_eq!(code, expect_code);
+        }
+        status => panic!("expect move abort in the SCC module, got {:?}", status),
+    }
+}
+
+fn commit_block_call(block: &Block) -> FunctionCall {
+    match StateCommitmentChainModule::create_commit_block_action(block) {
+        MoveAction::Function(function_call) => function_call,
+        _ => unreachable!("commit block action should be a function call"),
+    }
+}
+
+#[tokio::test]
+async fn test_commit_block_by_proposer() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    // the sequencer is the proposer before set by the admin
+    let scc_module = binding_test.as_module_binding::<StateCommitmentChainModule>();
+    assert_eq!(scc_module.proposer().unwrap(), binding_test.sequencer);
+    assert_eq!(scc_module.last_block_number().unwrap(), None);
+    assert_eq!(scc_module.get_block(0).unwrap(), None);
+
+    let block = Block::new(
+        0,
+        1,
+        H256::random(),
+        H256::zero(),
+        H256::random(),
+        H256::random(),
+    );
+    let result = binding_test
+        .execute_function_call_via_sequencer(commit_block_call(&block))
+        .unwrap();
+    assert_eq!(result.transaction_info.status, KeptVMStatus::Executed);
+
+    let scc_module = binding_test.as_module_binding::<StateCommitmentChainModule>();
+    assert_eq!(scc_module.proposer().unwrap(), binding_test.sequencer);
+    assert_eq!(scc_module.last_block_number().unwrap(), Some(0));
+    let committed_block = scc_module.get_block(0).unwrap().unwrap();
+    assert_eq!(committed_block.block_number, block.block_number);
+    assert_eq!(committed_block.batch_size, block.batch_size);
+    assert_eq!(committed_block.batch_hash, block.batch_hash.0.to_vec());
+    assert_eq!(
+        committed_block.prev_tx_accumulator_root,
+        block.prev_tx_accumulator_root.0.to_vec()
+    );
+    assert_eq!(
+        committed_block.tx_accumulator_root,
+        block.tx_accumulator_root.0.to_vec()
+    );
+    assert_eq!(committed_block.state_root, block.state_root.0.to_vec());
+
+    let next_block = Block::new(
+        1,
+        1,
+        H256::random(),
+        block.tx_accumulator_root,
+        H256::random(),
+        H256::random(),
+    );
+
+    // only the proposer can commit blocks
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().rooch_address().unwrap();
+    binding_test
+        .get_rgas(sender.into(), U256::from(1000_000_000_000u64))
+        .unwrap();
+    let tx_data = RoochTransactionData::new_for_test(
+        sender,
+        0,
+        StateCommitmentChainModule::create_commit_block_action(&next_block),
+    );
+    let result = binding_test.execute_as_result(tx_data.sign(&kp)).unwrap();
+    // ErrorNotProposer = 1
+    assert_scc_abort(result.transaction_info.status, 1);
+
+    // blocks must be committed one by one
+    let skipped_block = Block::new(
+        2,
+        1,
+        H256::random(),
+        block.tx_accumulator_root,
+        H256::random(),
+        H256::random(),
+    );
+    let result = binding_test
+        .execute_function_call_via_sequencer(commit_block_call(&skipped_block))
+        .unwrap();
+    // ErrorInvalidBlockNumber = 2
+    assert_scc_abort(result.transaction_info.status, 2);
+
+    let result = binding_test
+        .execute_function_call_via_sequencer(commit_block_call(&next_block))
+        .unwrap();
+    assert_eq!(result.transaction_info.status, KeptVMStatus::Executed);
+
+    let scc_module = binding_test.as_module_binding::<StateCommitmentChainModule>();
+    assert_eq!(scc_module.last_block_number().unwrap(), Some(1));
+    assert_eq!(scc_module.get_block(2).unwrap(), None);
+    let committed_block = scc_module.get_block(1).unwrap().unwrap();
+    assert_eq!(
+        committed_block.prev_tx_accumulator_root,
+        block.tx_accumulator_root.0.to_vec()
+    );
+    assert_eq!(
+        committed_block.tx_accumulator_root,
+        next_block.tx_accumulator_root.0.to_vec()
+    );
+    assert_eq!(committed_block.state_root, next_block.state_root.0.to_vec());
+}
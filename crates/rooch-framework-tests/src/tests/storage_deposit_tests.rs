@@ -0,0 +1,152 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
+use move_core_types::u256::U256;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::moveos_std::storage_deposit::StorageDepositRecord;
+use moveos_types::state::{MoveState, MoveStructType, StateChangeSet};
+use moveos_types::state_resolver::StateResolver;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
+use rooch_types::framework::gas_coin::RGas;
+use rooch_types::framework::session_key::{SessionKeyModule, SessionScope};
+use rooch_types::framework::transaction_fee::{BaseFee, TransactionFeeModule};
+use rooch_types::transaction::rooch::RoochTransactionData;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// The storage deposit records created, updated(`Some`) or removed(`None`) in the change set
+fn record_changes(changeset: &StateChangeSet) -> BTreeMap<ObjectID, Option<u64>> {
+    changeset
+        .changes
+        .values()
+        .filter(|change| StorageDepositRecord::type_tag_match(&change.metadata.object_type))
+        .filter_map(|change| {
+            let deposit = match change.value.as_ref()? {
+                Op::New(bytes) | Op::Modify(bytes) => {
+                    Some(StorageDepositRecord::from_bytes(bytes).unwrap().deposit)
+                }
+                Op::Delete => None,
+            };
+            Some((change.metadata.id.clone(), deposit))
+        })
+        .collect()
+}
+
+fn gas_revenue(binding_test: &binding_test::RustBindingTest, sequencer: AccountAddress) -> U256 {
+    let fee_module = binding_test.as_module_binding::<TransactionFeeModule>();
+    fee_module.gas_revenue_balance(sequencer).unwrap()
+        + fee_module
+            .gas_revenue_balance(ROOCH_FRAMEWORK_ADDRESS)
+            .unwrap()
+}
+
+fn storage_deposit_pool(binding_test: &binding_test::RustBindingTest) -> U256 {
+    binding_test
+        .as_module_binding::<TransactionFeeModule>()
+        .storage_deposit_pool_balance()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_storage_deposit_and_rebate() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let sequencer = binding_test.sequencer;
+
+    let mut keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let session_auth_key = keystore.generate_session_key(&sender, None).unwrap();
+
+    // Create a session key, the new objects are charged the storage deposit
+    let pool_before = storage_deposit_pool(&binding_test);
+    let revenue_before = gas_revenue(&binding_test, sequencer);
+    let action = SessionKeyModule::create_session_key_action(
+        FromStr::from_str("test").unwrap(),
+        FromStr::from_str("https:://test.rooch.network").unwrap(),
+        session_auth_key.as_ref().to_vec(),
+        SessionScope::new(ROOCH_FRAMEWORK_ADDRESS, "*", "*").unwrap(),
+        100,
+    );
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let result = binding_test.execute(tx).unwrap();
+    assert_eq!(result.transaction_info.status, KeptVMStatus::Executed);
+
+    let created_records = record_changes(&result.output.changeset);
+    assert!(!created_records.is_empty());
+    let deposit: u64 = created_records
+        .values()
+        .map(|deposit| deposit.expect("the record should be created"))
+        .sum();
+    assert!(deposit > 0);
+    assert_eq!(
+        storage_deposit_pool(&binding_test),
+        pool_before + U256::from(deposit)
+    );
+    // The storage deposit is included in the gas used, but it is not distributed as fee
+    let gas_price = BaseFee::INITIAL_BASE_FEE;
+    assert_eq!(
+        gas_revenue(&binding_test, sequencer),
+        revenue_before + U256::from((result.output.gas_used - deposit) * gas_price)
+    );
+
+    // Remove the session key, the deposit of the removed object is refunded
+    let pool_before = storage_deposit_pool(&binding_test);
+    let action = SessionKeyModule::remove_session_key_action(session_auth_key.as_ref().to_vec());
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    let result = binding_test.execute(tx).unwrap();
+    assert_eq!(result.transaction_info.status, KeptVMStatus::Executed);
+
+    let removed_records = record_changes(&result.output.changeset);
+    assert_eq!(removed_records.len(), 1);
+    let (record_id, removed) = removed_records.into_iter().next().unwrap();
+    assert!(removed.is_none(), "the record should be removed");
+    let rebate = created_records
+        .get(&record_id)
+        .cloned()
+        .flatten()
+        .expect("the removed record should be created by the first transaction");
+    assert_eq!(
+        storage_deposit_pool(&binding_test),
+        pool_before - U256::from(rebate)
+    );
+}
+
+#[tokio::test]
+async fn test_legacy_object_exempt_from_storage_deposit() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    // The RGas is transferred from the coin store of rooch dao which is created in genesis,
+    // the genesis object has no storage deposit record, so it is exempt from the deposit.
+    let receiver = AccountAddress::random();
+    binding_test.get_rgas(receiver, U256::from(1u64)).unwrap();
+
+    let resolver = binding_test.resolver();
+    let dao_coin_store = AccountCoinStoreModule::account_coin_store_id(
+        binding_test.rooch_dao_address().into(),
+        RGas::struct_tag(),
+    );
+    assert!(resolver
+        .get_object(&StorageDepositRecord::record_id(&dao_coin_store))
+        .unwrap()
+        .is_none());
+
+    // The coin store of the receiver is created by the transaction, it is charged the deposit
+    let receiver_coin_store =
+        AccountCoinStoreModule::account_coin_store_id(receiver, RGas::struct_tag());
+    let record = resolver
+        .get_object(&StorageDepositRecord::record_id(&receiver_coin_store))
+        .unwrap()
+        .expect("the record should be created");
+    assert!(record.value_as::<StorageDepositRecord>().unwrap().deposit > 0);
+}
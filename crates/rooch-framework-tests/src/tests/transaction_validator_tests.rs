@@ -0,0 +1,120 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use moveos_types::h256::H256;
+use moveos_types::state_resolver::StateResolver;
+use moveos_types::transaction::MoveAction;
+use rooch_key::keystore::account_keystore::AccountKeystore;
+use rooch_key::keystore::memory_keystore::InMemKeystore;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::transaction_fee::BaseFee;
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::TransactionSequenceInfo;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::binding_test;
+
+#[tokio::test]
+async fn test_validate_expiration_timestamp() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+
+    // The transaction is expired from the timestamp 0
+    let tx_data =
+        RoochTransactionData::new_for_test(sender, 0, action.clone()).with_expiration_timestamp(0);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    assert!(binding_test.execute_as_result(tx).is_err());
+
+    let tx_data =
+        RoochTransactionData::new_for_test(sender, 0, action).with_expiration_timestamp(u64::MAX);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+}
+
+#[tokio::test]
+async fn test_validate_gas_price() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+
+    // The gas price is lower than the initial base fee
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action.clone()).with_gas_price(0);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    assert!(binding_test.execute_as_result(tx).is_err());
+
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action).with_gas_price(2);
+    let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+    binding_test.execute(tx).unwrap();
+}
+
+#[tokio::test]
+async fn test_base_fee_updated_once_per_fee_block() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let keystore = InMemKeystore::new_insecure_for_tests(1);
+    let sender = keystore.addresses()[0];
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let base_fee_id = BaseFee::base_fee_object_id();
+    let start_timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+
+    // (tx_order, tx_timestamp, begins a new fee block)
+    let fee_block_interval = 10000;
+    let cases = [
+        (1, start_timestamp, true),
+        (2, start_timestamp + 1, false),
+        (3, start_timestamp + 2, false),
+        (4, start_timestamp + fee_block_interval, true),
+        (5, start_timestamp + fee_block_interval + 1, false),
+    ];
+    for (sequence_number, (tx_order, tx_timestamp, new_fee_block)) in cases.into_iter().enumerate()
+    {
+        let tx_data =
+            RoochTransactionData::new_for_test(sender, sequence_number as u64, action.clone());
+        let tx = keystore.sign_transaction(&sender, tx_data, None).unwrap();
+        let mut verified_tx = binding_test.executor.validate_l2_tx(tx).unwrap();
+        verified_tx
+            .ctx
+            .add(TransactionSequenceInfo {
+                tx_order,
+                tx_order_signature: vec![],
+                tx_accumulator_root: H256::zero(),
+                tx_timestamp,
+                tx_accumulator_frozen_subtree_roots: vec![],
+                tx_accumulator_num_leaves: 0,
+                tx_accumulator_num_nodes: 0,
+            })
+            .unwrap();
+        let result = binding_test.execute_verified_tx(verified_tx).unwrap();
+
+        let base_fee_written = result
+            .output
+            .changeset
+            .changes
+            .values()
+            .any(|change| change.metadata.id == base_fee_id);
+        assert_eq!(base_fee_written, new_fee_block, "tx_order: {}", tx_order);
+
+        let base_fee = binding_test
+            .resolver()
+            .get_object(&base_fee_id)
+            .unwrap()
+            .unwrap()
+            .into_object::<BaseFee>()
+            .unwrap()
+            .value;
+        if new_fee_block {
+            assert_eq!(base_fee.fee_block_start_tx_order, tx_order);
+            assert_eq!(base_fee.fee_block_start_timestamp, tx_timestamp);
+            // The gas used in the last fee block is far below the target
+            assert_eq!(base_fee.base_fee, BaseFee::INITIAL_BASE_FEE);
+        }
+    }
+}
@@ -0,0 +1,228 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::binding_test;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use fastcrypto::{
+    hash::{HashFunction, Sha256},
+    secp256r1::Secp256r1KeyPair,
+    traits::{KeyPair, Signer, ToFromBytes},
+};
+use moveos_types::h256::H256;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::transaction::{MoveAction, MoveOSTransaction};
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
+use rooch_types::framework::auth_payload::WebauthnAuthPayload;
+use rooch_types::framework::auth_validator::BuiltinAuthValidator;
+use rooch_types::framework::empty::Empty;
+use rooch_types::framework::webauthn_validator::{WebauthnValidator, WebauthnValidatorModule};
+use rooch_types::transaction::rooch::RoochTransactionData;
+use rooch_types::transaction::{Authenticator, RoochTransaction};
+
+const RP_ID: &str = "rooch.network";
+
+/// Simulate the passkey authenticator, sign the assertion of the `rp_id` requested by the
+/// `origin`, whose challenge is the tx_hash.
+fn sign_assertion_with_origin(
+    passkey: &Secp256r1KeyPair,
+    tx_hash: H256,
+    rp_id: &str,
+    origin: &str,
+) -> WebauthnAuthPayload {
+    let client_data_json = serde_json::json!({
+        "type": "webauthn.get",
+        "challenge": URL_SAFE_NO_PAD.encode(tx_hash.as_bytes()),
+        "origin": origin,
+        "crossOrigin": false,
+    })
+    .to_string()
+    .into_bytes();
+
+    // rpIdHash || flags(UP | UV) || signCount
+    let mut authenticator_data = Sha256::digest(rp_id.as_bytes()).digest.to_vec();
+    authenticator_data.push(0x05);
+    authenticator_data.extend_from_slice(&1u32.to_be_bytes());
+
+    let mut message = authenticator_data.clone();
+    message.extend_from_slice(&Sha256::digest(&client_data_json).digest);
+    let signature = passkey.sign(&message);
+
+    WebauthnAuthPayload::new(
+        signature.as_bytes().to_vec(),
+        passkey.public().as_bytes().to_vec(),
+        authenticator_data,
+        client_data_json,
+    )
+}
+
+fn sign_assertion(passkey: &Secp256r1KeyPair, tx_hash: H256) -> WebauthnAuthPayload {
+    sign_assertion_with_origin(passkey, tx_hash, RP_ID, "https://rooch.network")
+}
+
+fn rp_id() -> MoveString {
+    MoveString::from(RP_ID)
+}
+
+#[tokio::test]
+async fn test_validate() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+    let root = binding_test.root().clone();
+
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().bitcoin_address().unwrap().to_rooch_address();
+
+    let passkey = Secp256r1KeyPair::generate(&mut rand::thread_rng());
+    let public_key = passkey.public().as_bytes().to_vec();
+
+    //Add the passkey to the account via the bitcoin key
+    let action = WebauthnValidatorModule::add_passkey_action(public_key.clone(), rp_id());
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action);
+    let tx = tx_data.sign(&kp);
+    binding_test.execute(tx).unwrap();
+
+    let validator_caller = binding_test.as_module_binding::<WebauthnValidatorModule>();
+    assert!(validator_caller
+        .has_passkey(sender.into(), public_key)
+        .unwrap());
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let payload = sign_assertion(&passkey, tx_data.tx_hash());
+    let tx = RoochTransaction::new(tx_data, Authenticator::webauthn(payload));
+
+    let auth_info = tx.authenticator_info();
+
+    //Test the validate function
+    {
+        let move_tx: MoveOSTransaction = tx.clone().into_moveos_transaction(root);
+
+        let validator_caller = binding_test.as_module_binding::<WebauthnValidatorModule>();
+        let result = validator_caller.validate(&move_tx.ctx, auth_info.authenticator.payload);
+        assert!(result.is_ok());
+    }
+
+    //Execute the transaction signed by the passkey
+    binding_test.execute(tx).unwrap();
+}
+
+#[tokio::test]
+async fn test_validate_failed() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().bitcoin_address().unwrap().to_rooch_address();
+
+    let passkey = Secp256r1KeyPair::generate(&mut rand::thread_rng());
+    let action =
+        WebauthnValidatorModule::add_passkey_action(passkey.public().as_bytes().to_vec(), rp_id());
+    let tx = RoochTransactionData::new_for_test(sender, 0, action).sign(&kp);
+    binding_test.execute(tx).unwrap();
+
+    //The assertion is signed for another transaction
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let other_tx_data = RoochTransactionData::new_for_test(sender, 2, action.clone());
+    let payload = sign_assertion(&passkey, other_tx_data.tx_hash());
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action.clone());
+    let tx = RoochTransaction::new(tx_data, Authenticator::webauthn(payload));
+    assert!(binding_test.execute(tx).is_err());
+
+    //The passkey is not added to the sender
+    let other_passkey = Secp256r1KeyPair::generate(&mut rand::thread_rng());
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action.clone());
+    let payload = sign_assertion(&other_passkey, tx_data.tx_hash());
+    let tx = RoochTransaction::new(tx_data, Authenticator::webauthn(payload));
+    assert!(binding_test.execute(tx).is_err());
+
+    //The assertion is signed for another rp id than the passkey is added for
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action.clone());
+    let payload = sign_assertion_with_origin(
+        &passkey,
+        tx_data.tx_hash(),
+        "app.rooch.network",
+        "https://app.rooch.network",
+    );
+    let tx = RoochTransaction::new(tx_data, Authenticator::webauthn(payload));
+    assert!(binding_test.execute(tx).is_err());
+
+    //The assertion is requested by an origin of another rp id
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let payload =
+        sign_assertion_with_origin(&passkey, tx_data.tx_hash(), RP_ID, "https://evil.network");
+    let tx = RoochTransaction::new(tx_data, Authenticator::webauthn(payload));
+    assert!(binding_test.execute(tx).is_err());
+}
+
+#[tokio::test]
+async fn test_passkey_account() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    //Onboard with the passkey only, the account is derived from the passkey
+    let passkey = Secp256r1KeyPair::generate(&mut rand::thread_rng());
+    let public_key = passkey.public().as_bytes().to_vec();
+    let sender = WebauthnValidator::passkey_address(RP_ID, &public_key).unwrap();
+
+    let action = MoveAction::new_function_call(Empty::empty_function_id(), vec![], vec![]);
+    let tx_data = RoochTransactionData::new_for_test(sender, 0, action.clone());
+    let payload = sign_assertion_with_origin(
+        &passkey,
+        tx_data.tx_hash(),
+        RP_ID,
+        "https://app.rooch.network",
+    );
+    let tx = RoochTransaction::new(tx_data, Authenticator::webauthn(payload));
+    binding_test.execute(tx).unwrap();
+
+    let bitcoin_address = RoochToBitcoinAddressMapping::resolve_bitcoin_address(
+        &binding_test.resolver(),
+        sender.into(),
+    )
+    .unwrap();
+    assert_eq!(
+        bitcoin_address,
+        Some(WebauthnValidator::passkey_bitcoin_address(RP_ID, &public_key).unwrap())
+    );
+    assert_eq!(
+        binding_test
+            .get_account_sequence_number(sender.into())
+            .unwrap(),
+        1
+    );
+
+    //The passkey of another rp id controls another account
+    let tx_data = RoochTransactionData::new_for_test(sender, 1, action);
+    let payload = sign_assertion_with_origin(
+        &passkey,
+        tx_data.tx_hash(),
+        "app.rooch.network",
+        "https://app.rooch.network",
+    );
+    let tx = RoochTransaction::new(tx_data, Authenticator::webauthn(payload));
+    assert!(binding_test.execute(tx).is_err());
+}
+
+#[tokio::test]
+async fn test_init_for_upgrade() {
+    let mut binding_test = binding_test::RustBindingTest::new().unwrap();
+
+    let kp = RoochKeyPair::generate_secp256k1();
+    let sender = kp.public().bitcoin_address().unwrap().to_rooch_address();
+
+    //The validator is registered in genesis, so the upgrade init is a no-op on the new chain
+    let tx = RoochTransactionData::new_for_test(
+        sender,
+        0,
+        WebauthnValidatorModule::init_for_upgrade_action(),
+    )
+    .sign(&kp);
+    binding_test.execute(tx).unwrap();
+
+    //The id 3 is reserved for the Ethereum validator
+    assert_eq!(WebauthnValidator::auth_validator_id(), 4);
+    assert_eq!(BuiltinAuthValidator::Ethereum.flag(), 3);
+    assert_eq!(
+        BuiltinAuthValidator::Webauthn.auth_validator().id,
+        WebauthnValidator::auth_validator_id()
+    );
+}
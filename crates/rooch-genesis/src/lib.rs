@@ -10,6 +10,7 @@ use include_dir::{include_dir, Dir};
 use move_core_types::gas_algebra::{InternalGas, InternalGasPerArg};
 use move_core_types::value::MoveTypeLayout;
 use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use move_resource_viewer::MoveValueAnnotator;
 use move_vm_runtime::native_functions::NativeFunction;
 use moveos::gas::table::VMGasParameters;
 use moveos::moveos::{MoveOS, MoveOSConfig};
@@ -502,6 +503,11 @@ impl RoochGenesis {
         rooch_db.indexer_store.persist_transactions(transactions)?;
 
         // 2. update indexer event
+        let resolver = state_resolver::RootObjectResolver::new(
+            genesis_raw_output.changeset.root_metadata(),
+            &rooch_db.moveos_store,
+        );
+        let annotator = MoveValueAnnotator::new(&resolver);
         let events: Vec<_> = output
             .events
             .into_iter()
@@ -511,6 +517,7 @@ impl RoochGenesis {
                     ledger_tx.clone(),
                     self.genesis_moveos_tx().ctx,
                 )
+                .with_decoded_event_data(&annotator)
             })
             .collect();
         rooch_db.indexer_store.persist_events(events)?;
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS utxos;
\ No newline at end of file
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS inscriptions;
\ No newline at end of file
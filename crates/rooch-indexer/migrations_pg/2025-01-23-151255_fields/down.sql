@@ -0,0 +1 @@
+DROP TABLE IF EXISTS fields;
\ No newline at end of file
@@ -0,0 +1,2 @@
+-- The event data decoded to JSON, for filtering events by field values
+ALTER TABLE events ADD COLUMN event_data TEXT;
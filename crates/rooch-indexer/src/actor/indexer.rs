@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::actor::messages::{
-    IndexerApplyObjectStatesMessage, IndexerDeleteAnyObjectStatesMessage, IndexerEventsMessage,
+    IndexerApplyObjectStatesMessage, IndexerBackupMessage, IndexerCommittedTransactionMessage,
+    IndexerDeleteAnyObjectStatesMessage, IndexerEventsMessage,
     IndexerPersistOrUpdateAnyObjectStatesMessage, IndexerRevertMessage, IndexerStatesMessage,
     IndexerTransactionMessage, UpdateIndexerMessage,
 };
@@ -11,11 +12,15 @@ use crate::{list_field_indexer_keys, IndexerStore};
 use anyhow::Result;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
+use move_resource_viewer::MoveValueAnnotator;
 use moveos_store::MoveOSStore;
+use moveos_types::moveos_std::event::Event;
 use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
+use moveos_types::state::StateChangeSet;
 use moveos_types::state_resolver::RootObjectResolver;
-use moveos_types::transaction::MoveAction;
-use rooch_event::actor::EventActor;
+use moveos_types::transaction::{MoveAction, TransactionExecutionInfo};
+use rooch_event::actor::{EventActor, TransactionIndexedMessage};
+use rooch_event::event::TransactionIndexedEvent;
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::field::{handle_field_change, IndexerFieldChanges};
 use rooch_types::indexer::state::{
@@ -23,12 +28,13 @@ use rooch_types::indexer::state::{
     IndexerObjectStatesIndexGenerator, ObjectStateType,
 };
 use rooch_types::indexer::transaction::IndexerTransaction;
+use rooch_types::transaction::LedgerTransaction;
 
 pub struct IndexerActor {
     root: ObjectMeta,
     indexer_store: IndexerStore,
     moveos_store: MoveOSStore,
-    _event_actor: Option<LocalActorRef<EventActor>>,
+    event_actor: Option<LocalActorRef<EventActor>>,
 }
 
 impl IndexerActor {
@@ -42,7 +48,7 @@ impl IndexerActor {
             root,
             indexer_store,
             moveos_store,
-            _event_actor: event_actor,
+            event_actor,
         })
     }
 
@@ -51,46 +57,37 @@ impl IndexerActor {
         let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
         list_field_indexer_keys(&resolver)
     }
-}
-
-impl Actor for IndexerActor {}
 
-#[async_trait]
-impl Handler<UpdateIndexerMessage> for IndexerActor {
-    async fn handle(&mut self, msg: UpdateIndexerMessage, _ctx: &mut ActorContext) -> Result<()> {
-        let UpdateIndexerMessage {
-            ledger_transaction,
-            execution_info,
-            moveos_tx,
-            events,
-            state_change_set,
-        } = msg;
-        self.root = state_change_set.root_metadata();
+    /// Index the transaction, its events and object changes, then notify the subscribers.
+    fn index_transaction(
+        &mut self,
+        ledger_transaction: LedgerTransaction,
+        execution_info: TransactionExecutionInfo,
+        indexer_transaction: IndexerTransaction,
+        events: Vec<Event>,
+        state_change_set: StateChangeSet,
+    ) -> Result<()> {
         let tx_order = ledger_transaction.sequence_info.tx_order;
 
         // 1. update indexer transaction
-        let move_action = MoveAction::from(moveos_tx.action);
-        let indexer_transaction = IndexerTransaction::new(
-            ledger_transaction.clone(),
-            execution_info.clone(),
-            move_action,
-            moveos_tx.ctx.clone(),
-        )?;
-        let transactions = vec![indexer_transaction];
+        let transactions = vec![indexer_transaction.clone()];
         self.indexer_store.persist_transactions(transactions)?;
 
         // 2. update indexer event
+        let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
+        let annotator = MoveValueAnnotator::new(&resolver);
         let events: Vec<_> = events
             .into_iter()
             .map(|event| {
-                IndexerEvent::new(
-                    event.clone(),
+                IndexerEvent::new_with_sender(
+                    event,
                     ledger_transaction.clone(),
-                    moveos_tx.ctx.clone(),
+                    indexer_transaction.sender,
                 )
+                .with_decoded_event_data(&annotator)
             })
             .collect();
-        self.indexer_store.persist_events(events)?;
+        self.indexer_store.persist_events(events.clone())?;
 
         // 3. update indexer full object state, including object_states, utxos and inscriptions
         // indexer object state index generator
@@ -105,6 +102,7 @@ impl Handler<UpdateIndexerMessage> for IndexerActor {
                 object_change,
             )?;
         }
+        let object_states = indexer_object_state_change_set.changed_object_states();
         self.indexer_store
             .apply_object_states(indexer_object_state_change_set)?;
 
@@ -121,10 +119,79 @@ impl Handler<UpdateIndexerMessage> for IndexerActor {
         }
         self.indexer_store.apply_fields(field_changes)?;
 
+        // 5. notify the subscribers after the transaction is indexed
+        if let Some(event_actor) = &self.event_actor {
+            let _ = event_actor.notify(TransactionIndexedMessage {
+                event: TransactionIndexedEvent {
+                    ledger_transaction,
+                    execution_info,
+                    indexer_transaction,
+                    events,
+                    object_states,
+                },
+            });
+        }
+
         Ok(())
     }
 }
 
+impl Actor for IndexerActor {}
+
+#[async_trait]
+impl Handler<UpdateIndexerMessage> for IndexerActor {
+    async fn handle(&mut self, msg: UpdateIndexerMessage, _ctx: &mut ActorContext) -> Result<()> {
+        let UpdateIndexerMessage {
+            ledger_transaction,
+            execution_info,
+            moveos_tx,
+            events,
+            state_change_set,
+        } = msg;
+        self.root = state_change_set.root_metadata();
+
+        let move_action = MoveAction::from(moveos_tx.action);
+        let indexer_transaction = IndexerTransaction::new(
+            ledger_transaction.clone(),
+            execution_info.clone(),
+            move_action,
+            moveos_tx.ctx.clone(),
+        )?;
+        self.index_transaction(
+            ledger_transaction,
+            execution_info,
+            indexer_transaction,
+            events,
+            state_change_set,
+        )
+    }
+}
+
+#[async_trait]
+impl Handler<IndexerCommittedTransactionMessage> for IndexerActor {
+    async fn handle(
+        &mut self,
+        msg: IndexerCommittedTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        let IndexerCommittedTransactionMessage {
+            ledger_transaction,
+            execution_info,
+            indexer_transaction,
+            events,
+            state_change_set,
+        } = msg;
+        self.root = state_change_set.root_metadata();
+        self.index_transaction(
+            ledger_transaction,
+            execution_info,
+            indexer_transaction,
+            events,
+            state_change_set,
+        )
+    }
+}
+
 #[async_trait]
 impl Handler<IndexerStatesMessage> for IndexerActor {
     async fn handle(&mut self, msg: IndexerStatesMessage, _ctx: &mut ActorContext) -> Result<()> {
@@ -189,9 +256,14 @@ impl Handler<IndexerEventsMessage> for IndexerActor {
             tx_context,
         } = msg;
 
+        let resolver = RootObjectResolver::new(self.root.clone(), &self.moveos_store);
+        let annotator = MoveValueAnnotator::new(&resolver);
         let events: Vec<_> = events
             .into_iter()
-            .map(|event| IndexerEvent::new(event, ledger_transaction.clone(), tx_context.clone()))
+            .map(|event| {
+                IndexerEvent::new(event, ledger_transaction.clone(), tx_context.clone())
+                    .with_decoded_event_data(&annotator)
+            })
             .collect();
         self.indexer_store.persist_events(events)?;
         Ok(())
@@ -217,6 +289,9 @@ impl Handler<IndexerPersistOrUpdateAnyObjectStatesMessage> for IndexerActor {
             ObjectStateType::Inscription => self
                 .indexer_store
                 .persist_or_update_object_state_inscriptions(states)?,
+            ObjectStateType::RuneBalances => self
+                .indexer_store
+                .persist_or_update_object_state_rune_balances(states)?,
         }
         Ok(())
     }
@@ -241,6 +316,9 @@ impl Handler<IndexerDeleteAnyObjectStatesMessage> for IndexerActor {
             ObjectStateType::Inscription => self
                 .indexer_store
                 .delete_object_state_inscriptions(state_pks)?,
+            ObjectStateType::RuneBalances => self
+                .indexer_store
+                .delete_object_state_rune_balances(state_pks)?,
         }
         Ok(())
     }
@@ -302,3 +380,18 @@ impl Handler<IndexerRevertMessage> for IndexerActor {
         Ok(())
     }
 }
+
+#[async_trait]
+impl Handler<IndexerBackupMessage> for IndexerActor {
+    async fn handle(
+        &mut self,
+        msg: IndexerBackupMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<String>> {
+        if self.indexer_store.pg_store.is_some() {
+            tracing::warn!("The indexer uses PostgreSQL, skip the indexer backup");
+            return Ok(vec![]);
+        }
+        self.indexer_store.backup_sqlite(&msg.backup_dir)
+    }
+}
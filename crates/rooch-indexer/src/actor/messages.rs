@@ -18,6 +18,7 @@ use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
 use rooch_types::transaction::LedgerTransaction;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 /// Indexer write Message
 #[derive(Debug, Clone)]
@@ -33,6 +34,20 @@ impl Message for UpdateIndexerMessage {
     type Result = Result<()>;
 }
 
+/// Index a transaction committed by another node, such as the leader node of the follower node
+#[derive(Debug, Clone)]
+pub struct IndexerCommittedTransactionMessage {
+    pub ledger_transaction: LedgerTransaction,
+    pub execution_info: TransactionExecutionInfo,
+    pub indexer_transaction: IndexerTransaction,
+    pub events: Vec<Event>,
+    pub state_change_set: StateChangeSet,
+}
+
+impl Message for IndexerCommittedTransactionMessage {
+    type Result = Result<()>;
+}
+
 /// Indexer Transaction write Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct IndexerTransactionMessage {
@@ -163,6 +178,16 @@ impl Message for IndexerRevertMessage {
     type Result = Result<()>;
 }
 
+/// Backup the SQLite indexer tables into `backup_dir`, return the backed up table names
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IndexerBackupMessage {
+    pub backup_dir: PathBuf,
+}
+
+impl Message for IndexerBackupMessage {
+    type Result = Result<Vec<String>>;
+}
+
 /// Query Indexer Fields Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct QueryIndexerFieldsMessage {
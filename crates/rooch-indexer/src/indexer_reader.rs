@@ -10,21 +10,26 @@ use crate::models::transactions::StoredTransaction;
 use crate::schema::{events, transactions};
 use crate::utils::escape_sql_string;
 use crate::{
-    IndexerResult, IndexerStoreMeta, IndexerTableName, SqliteConnectionConfig,
+    get_pg_pool_connection, new_pg_connection_pool, IndexerResult, IndexerStoreMeta,
+    IndexerTableName, PgConnectionPool, PgPoolConnection, SqliteConnectionConfig,
     SqliteConnectionPoolConfig, SqlitePoolConnection, DEFAULT_BUSY_TIMEOUT,
     INDEXER_EVENTS_TABLE_NAME, INDEXER_FIELDS_TABLE_NAME, INDEXER_OBJECT_STATES_TABLE_NAME,
-    INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME, INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
-    INDEXER_TRANSACTIONS_TABLE_NAME,
+    INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME, INDEXER_OBJECT_STATE_RUNE_BALANCES_TABLE_NAME,
+    INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME, INDEXER_TRANSACTIONS_TABLE_NAME,
 };
 use anyhow::{anyhow, Result};
+use diesel::pg::Pg;
+use diesel::sqlite::Sqlite;
 use diesel::{
-    r2d2::ConnectionManager, Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection,
+    r2d2::ConnectionManager, Connection, ExpressionMethods, PgConnection, QueryDsl,
+    QueryableByName, RunQueryDsl, SqliteConnection,
 };
 use function_name::named;
 use move_core_types::language_storage::StructTag;
 use moveos_types::moveos_std::object::ObjectID;
 use prometheus::Registry;
-use rooch_types::indexer::event::{EventFilter, IndexerEvent, IndexerEventID};
+use rooch_config::store_config::StoreConfig;
+use rooch_types::indexer::event::{EventFieldFilter, EventFilter, IndexerEvent, IndexerEventID};
 use rooch_types::indexer::field::{FieldFilter, IndexerField};
 use rooch_types::indexer::state::{IndexerStateID, ObjectStateFilter, ObjectStateType};
 use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
@@ -48,6 +53,7 @@ pub const EVENT_HANDLE_ID_STR: &str = "event_handle_id";
 pub const EVENT_INDEX_STR: &str = "event_index";
 pub const EVENT_SEQ_STR: &str = "event_seq";
 pub const EVENT_TYPE_STR: &str = "event_type";
+pub const EVENT_DATA_STR: &str = "event_data";
 
 pub const STATE_OBJECT_ID_STR: &str = "id";
 pub const STATE_INDEX_STR: &str = "state_index";
@@ -136,9 +142,66 @@ impl InnerIndexerReader {
     }
 }
 
+#[derive(Clone)]
+pub struct PgInnerIndexerReader {
+    pub(crate) pool: PgConnectionPool,
+}
+
+impl PgInnerIndexerReader {
+    pub fn new(db_url: &str) -> Result<Self> {
+        let pool = new_pg_connection_pool(db_url, true)?;
+        Ok(Self { pool })
+    }
+
+    pub fn get_connection(&self) -> Result<PgPoolConnection, IndexerError> {
+        get_pg_pool_connection(&self.pool)
+    }
+
+    pub fn run_query<T, E, F>(&self, query: F) -> Result<T, IndexerError>
+    where
+        F: FnOnce(&mut PgConnection) -> Result<T, E>,
+        E: From<diesel::result::Error> + std::error::Error,
+    {
+        let mut connection = self.get_connection()?;
+        connection
+            .deref_mut()
+            .transaction(query)
+            .map_err(|e| IndexerError::PgReadError(e.to_string()))
+    }
+
+    pub fn run_query_with_timeout<T, E, F>(&self, query: F) -> Result<T, IndexerError>
+    where
+        F: FnOnce(&mut PgConnection) -> Result<T, E> + Send + 'static,
+        E: From<diesel::result::Error> + std::error::Error + Send,
+        T: Send + 'static,
+    {
+        // default query time out in second
+        let timeout_duration = Duration::from_secs(DEFAULT_QUERY_TIMEOUT);
+        let mut connection = self.get_connection()?;
+
+        tokio::task::block_in_place(|| {
+            Handle::current().block_on(async move {
+                timeout(
+                    timeout_duration,
+                    tokio::task::spawn_blocking(move || {
+                        connection
+                            .deref_mut()
+                            .transaction(query)
+                            .map_err(|e| IndexerError::PgReadError(e.to_string()))
+                    }),
+                )
+                .await
+                .map_err(|e| IndexerError::PgAsyncReadError(e.to_string()))??
+            })
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct IndexerReader {
     pub(crate) inner_indexer_reader_mapping: HashMap<String, InnerIndexerReader>,
+    /// The PostgreSQL reader, if set, all the indexer tables are read from it instead of SQLite
+    pub(crate) pg_reader: Option<PgInnerIndexerReader>,
     metrics: Arc<IndexerReaderMetrics>,
 }
 
@@ -170,10 +233,28 @@ impl IndexerReader {
 
         Ok(IndexerReader {
             inner_indexer_reader_mapping,
+            pg_reader: None,
             metrics: Arc::new(IndexerReaderMetrics::new(registry)),
         })
     }
 
+    pub fn new_postgres(db_url: &str, registry: &Registry) -> Result<Self> {
+        Ok(IndexerReader {
+            inner_indexer_reader_mapping: HashMap::new(),
+            pg_reader: Some(PgInnerIndexerReader::new(db_url)?),
+            metrics: Arc::new(IndexerReaderMetrics::new(registry)),
+        })
+    }
+
+    /// Open the indexer reader with the backend selected by the store config,
+    /// PostgreSQL if the indexer db url is set, otherwise SQLite in the indexer dir.
+    pub fn new_with_store_config(config: &StoreConfig, registry: &Registry) -> Result<Self> {
+        match config.get_indexer_db_url() {
+            Some(db_url) => Self::new_postgres(db_url.as_str(), registry),
+            None => Self::new(config.get_indexer_dir(), registry),
+        }
+    }
+
     pub fn get_inner_indexer_reader(&self, table_name: &str) -> Result<InnerIndexerReader> {
         Ok(self
             .inner_indexer_reader_mapping
@@ -182,6 +263,21 @@ impl IndexerReader {
             .clone())
     }
 
+    /// Load the rows of a raw SQL query from the table, the query must be valid for both SQLite and PostgreSQL.
+    /// So string literals are single quoted, PostgreSQL treats the double quoted as identifiers.
+    fn load_with_timeout<T>(&self, table_name: &str, query: String) -> IndexerResult<Vec<T>>
+    where
+        T: QueryableByName<Sqlite> + QueryableByName<Pg> + Send + 'static,
+    {
+        match &self.pg_reader {
+            Some(pg_reader) => pg_reader
+                .run_query_with_timeout(move |conn| diesel::sql_query(query).load::<T>(conn)),
+            None => self
+                .get_inner_indexer_reader(table_name)?
+                .run_query_with_timeout(move |conn| diesel::sql_query(query).load::<T>(conn)),
+        }
+    }
+
     #[named]
     pub fn query_transactions_with_filter(
         &self,
@@ -200,14 +296,16 @@ impl IndexerReader {
         let tx_order = if let Some(cursor) = cursor {
             cursor as i64
         } else if descending_order {
-            let max_tx_order: i64 = self
-                .get_inner_indexer_reader(INDEXER_TRANSACTIONS_TABLE_NAME)?
-                .run_query_with_timeout(|conn| {
-                    transactions::dsl::transactions
-                        .select(transactions::tx_order)
-                        .order_by(transactions::tx_order.desc())
-                        .first::<i64>(conn)
-                })?;
+            let max_tx_order_query = transactions::dsl::transactions
+                .select(transactions::tx_order)
+                .order_by(transactions::tx_order.desc());
+            let max_tx_order: i64 = match &self.pg_reader {
+                Some(pg_reader) => pg_reader
+                    .run_query_with_timeout(move |conn| max_tx_order_query.first::<i64>(conn))?,
+                None => self
+                    .get_inner_indexer_reader(INDEXER_TRANSACTIONS_TABLE_NAME)?
+                    .run_query_with_timeout(move |conn| max_tx_order_query.first::<i64>(conn))?,
+            };
             max_tx_order + 1
         } else {
             -1
@@ -215,12 +313,12 @@ impl IndexerReader {
 
         let main_where_clause = match filter {
             TransactionFilter::Sender(sender) => {
-                format!("{TX_SENDER_STR} = \"{}\"", sender.to_hex_literal())
+                format!("{TX_SENDER_STR} = '{}'", sender.to_hex_literal())
             }
             TransactionFilter::TxHashes(tx_hashes) => {
                 let in_tx_hash_str: String = tx_hashes
                     .iter()
-                    .map(|tx_hash| format!("\"{:?}\"", tx_hash))
+                    .map(|tx_hash| format!("'{:?}'", tx_hash))
                     .collect::<Vec<String>>()
                     .join(",");
                 format!("{TX_HASH_STR} in ({})", in_tx_hash_str)
@@ -267,11 +365,8 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query transactions: {}", query);
-        let stored_transactions = self
-            .get_inner_indexer_reader(INDEXER_TRANSACTIONS_TABLE_NAME)?
-            .run_query_with_timeout(|conn| {
-                diesel::sql_query(query).load::<StoredTransaction>(conn)
-            })?;
+        let stored_transactions: Vec<StoredTransaction> =
+            self.load_with_timeout(INDEXER_TRANSACTIONS_TABLE_NAME, query)?;
 
         let result = stored_transactions
             .into_iter()
@@ -307,14 +402,19 @@ impl IndexerReader {
             } = cursor;
             (tx_order as i64, event_index as i64)
         } else if descending_order {
-            let (max_tx_order, event_index): (i64, i64) = self
-                .get_inner_indexer_reader(INDEXER_EVENTS_TABLE_NAME)?
-                .run_query_with_timeout(|conn| {
-                    events::dsl::events
-                        .select((events::tx_order, events::event_index))
-                        .order_by((events::tx_order.desc(), events::event_index.desc()))
-                        .first::<(i64, i64)>(conn)
-                })?;
+            let max_event_id_query = events::dsl::events
+                .select((events::tx_order, events::event_index))
+                .order_by((events::tx_order.desc(), events::event_index.desc()));
+            let (max_tx_order, event_index): (i64, i64) = match &self.pg_reader {
+                Some(pg_reader) => pg_reader.run_query_with_timeout(move |conn| {
+                    max_event_id_query.first::<(i64, i64)>(conn)
+                })?,
+                None => self
+                    .get_inner_indexer_reader(INDEXER_EVENTS_TABLE_NAME)?
+                    .run_query_with_timeout(move |conn| {
+                        max_event_id_query.first::<(i64, i64)>(conn)
+                    })?,
+            };
             (max_tx_order, event_index + 1)
         } else {
             (-1, 0)
@@ -323,20 +423,20 @@ impl IndexerReader {
         let main_where_clause = match filter {
             EventFilter::EventTypeWithSender { event_type, sender } => {
                 format!(
-                    "{TX_SENDER_STR} = \"{}\" AND {EVENT_TYPE_STR} = \"{}\"",
+                    "{TX_SENDER_STR} = '{}' AND {EVENT_TYPE_STR} = '{}'",
                     sender.to_hex_literal(),
                     event_type
                 )
             }
             EventFilter::EventType(event_type) => {
-                format!("{EVENT_TYPE_STR} = \"{}\"", event_type)
+                format!("{EVENT_TYPE_STR} = '{}'", event_type)
             }
             EventFilter::Sender(sender) => {
-                format!("{TX_SENDER_STR} = \"{}\"", sender.to_hex_literal())
+                format!("{TX_SENDER_STR} = '{}'", sender.to_hex_literal())
             }
             EventFilter::TxHash(tx_hash) => {
                 let tx_hash_str = format!("{:?}", tx_hash);
-                format!("{TX_HASH_STR} = \"{}\"", tx_hash_str)
+                format!("{TX_HASH_STR} = '{}'", tx_hash_str)
             }
             EventFilter::TimeRange {
                 start_time,
@@ -356,6 +456,19 @@ impl IndexerReader {
                     from_order, to_order
                 )
             }
+            EventFilter::EventTypeWithFieldFilters {
+                event_type,
+                field_filters,
+            } => {
+                let mut clauses = vec![format!("{EVENT_TYPE_STR} = '{}'", event_type)];
+                for field_filter in field_filters {
+                    clauses.push(event_field_filter_query(
+                        &field_filter,
+                        self.pg_reader.is_some(),
+                    )?);
+                }
+                clauses.join(" AND ")
+            }
         };
 
         let cursor_clause = if descending_order {
@@ -386,9 +499,8 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query events: {}", query);
-        let stored_events = self
-            .get_inner_indexer_reader(INDEXER_EVENTS_TABLE_NAME)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredEvent>(conn))?;
+        let stored_events: Vec<StoredEvent> =
+            self.load_with_timeout(INDEXER_EVENTS_TABLE_NAME, query)?;
 
         let result = stored_events
             .into_iter()
@@ -448,14 +560,14 @@ impl IndexerReader {
                             object_type_query(&object_type)
                         };
                         format!(
-                            "{STATE_OWNER_STR} = \"{}\" AND {}",
+                            "{STATE_OWNER_STR} = '{}' AND {}",
                             owner.to_hex_literal(),
                             object_query
                         )
                     }
                     _ => {
                         // Ignore object_type param for utxo and inscription query
-                        format!("{STATE_OWNER_STR} = \"{}\"", owner.to_hex_literal(),)
+                        format!("{STATE_OWNER_STR} = '{}'", owner.to_hex_literal(),)
                     }
                 }
             }
@@ -468,12 +580,12 @@ impl IndexerReader {
             }
 
             ObjectStateFilter::Owner(owner) => {
-                format!("{STATE_OWNER_STR} = \"{}\"", owner.to_hex_literal())
+                format!("{STATE_OWNER_STR} = '{}'", owner.to_hex_literal())
             }
             ObjectStateFilter::ObjectId(object_ids) => {
                 let object_ids_str = object_ids
                     .into_iter()
-                    .map(|obj_id| format!("\"{}\"", obj_id))
+                    .map(|obj_id| format!("'{}'", obj_id))
                     .collect::<Vec<_>>()
                     .join(",");
                 format!("{OBJECT_ID_STR} IN ({object_ids_str})")
@@ -510,11 +622,8 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query object states: {}", query);
-        let stored_object_state_infos = self
-            .get_inner_indexer_reader(table_name)?
-            .run_query_with_timeout(|conn| {
-                diesel::sql_query(query).load::<StoredObjectStateInfo>(conn)
-            })?;
+        let stored_object_state_infos: Vec<StoredObjectStateInfo> =
+            self.load_with_timeout(table_name, query)?;
 
         tracing::debug!("Query object states time elapsed: {:?}", start.elapsed());
         Ok(stored_object_state_infos)
@@ -569,9 +678,7 @@ impl IndexerReader {
         );
 
         tracing::debug!("query last indexer state id: {}", query);
-        let stored_state_ids = self
-            .get_inner_indexer_reader(table_name)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredStateID>(conn))?;
+        let stored_state_ids: Vec<StoredStateID> = self.load_with_timeout(table_name, query)?;
 
         let last_state_id = if stored_state_ids.is_empty() {
             None
@@ -590,7 +697,7 @@ impl IndexerReader {
         state_type: ObjectStateType,
     ) -> IndexerResult<Option<u64>> {
         let table_name = get_table_name_by_state_type(state_type);
-        let where_clause = format!("{TX_ORDER_STR} = \"{}\"", tx_order as i64);
+        let where_clause = format!("{TX_ORDER_STR} = {}", tx_order as i64);
         let order_clause = format!("{TX_ORDER_STR} DESC, {STATE_INDEX_STR} DESC");
         let query = format!(
             "
@@ -603,9 +710,7 @@ impl IndexerReader {
         );
 
         tracing::debug!("query last state index by tx order: {}", query);
-        let stored_state_ids = self
-            .get_inner_indexer_reader(table_name)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredStateID>(conn))?;
+        let stored_state_ids: Vec<StoredStateID> = self.load_with_timeout(table_name, query)?;
         let last_state_index = if stored_state_ids.is_empty() {
             None
         } else {
@@ -635,7 +740,7 @@ impl IndexerReader {
             FieldFilter::ObjectId(object_ids) => {
                 let object_ids_str = object_ids
                     .into_iter()
-                    .map(|obj_id| format!("\"{}\"", obj_id))
+                    .map(|obj_id| format!("'{}'", obj_id))
                     .collect::<Vec<_>>()
                     .join(",");
                 format!("{PARENT_OBJECT_ID_STR} IN ({object_ids_str})")
@@ -649,7 +754,7 @@ impl IndexerReader {
         };
         let mut start_limit = (page_of - 1) * (limit as u64);
         start_limit = start_limit.saturating_sub(1);
-        let page_clause = format!("{}, {}", start_limit, limit);
+        let page_clause = format!("{} OFFSET {}", limit, start_limit);
         let query = format!(
             "
                 SELECT * FROM fields \
@@ -661,9 +766,8 @@ impl IndexerReader {
         );
 
         tracing::debug!("Query fields: {}", query);
-        let stored_fields = self
-            .get_inner_indexer_reader(INDEXER_FIELDS_TABLE_NAME)?
-            .run_query_with_timeout(|conn| diesel::sql_query(query).load::<StoredField>(conn))?;
+        let stored_fields: Vec<StoredField> =
+            self.load_with_timeout(INDEXER_FIELDS_TABLE_NAME, query)?;
 
         let result = stored_fields
             .into_iter()
@@ -678,11 +782,95 @@ impl IndexerReader {
     }
 }
 
+/// The decimal digits of U256::MAX
+const U256_MAX_DIGITS: usize = 78;
+
+fn event_field_filter_query(
+    field_filter: &EventFieldFilter,
+    is_postgres: bool,
+) -> IndexerResult<String> {
+    let segments = field_filter
+        .path_segments()
+        .map_err(|e| IndexerError::InvalidArgumentError(e.to_string()))?;
+    // The scalar values in the decoded event data are strings, so the field value is a text
+    let field_value = if is_postgres {
+        format!("({EVENT_DATA_STR}::jsonb #>> '{{{}}}')", segments.join(","))
+    } else {
+        let json_path = segments
+            .iter()
+            .map(|segment| {
+                if segment.chars().all(|c| c.is_ascii_digit()) {
+                    format!("[{}]", segment)
+                } else {
+                    format!(".{}", segment)
+                }
+            })
+            .collect::<String>();
+        format!("json_extract({EVENT_DATA_STR}, '${}')", json_path)
+    };
+
+    let query = match field_filter {
+        EventFieldFilter::Eq { value, .. } => {
+            format!("{} = '{}'", field_value, escape_sql_string(value.clone()))
+        }
+        EventFieldFilter::Range { from, to, .. } => {
+            if is_postgres {
+                // Only cast the integer strings, PostgreSQL fails the cast on the other strings
+                let numeric_value = format!(
+                    "(CASE WHEN {0} ~ '^[0-9]+$' THEN CAST({0} AS NUMERIC) END)",
+                    field_value
+                );
+                let mut clauses = vec![format!("{} IS NOT NULL", numeric_value)];
+                if let Some(from) = from {
+                    clauses.push(format!("{} >= {}", numeric_value, from));
+                }
+                if let Some(to) = to {
+                    clauses.push(format!("{} < {}", numeric_value, to));
+                }
+                format!("({})", clauses.join(" AND "))
+            } else {
+                // SQLite NUMERIC is a 64-bit integer or a double, it is inexact above 2^63,
+                // so compare the integer strings left-padded with zeros to the width of U256::MAX
+                let integer_value = format!("ltrim({}, '0')", field_value);
+                let padded_value = format!(
+                    "substr('{}' || {}, -{})",
+                    "0".repeat(U256_MAX_DIGITS),
+                    integer_value,
+                    U256_MAX_DIGITS
+                );
+                let mut clauses = vec![
+                    format!("{0} <> '' AND {0} NOT GLOB '*[^0-9]*'", field_value),
+                    format!("length({}) <= {}", integer_value, U256_MAX_DIGITS),
+                ];
+                if let Some(from) = from {
+                    clauses.push(format!(
+                        "{} >= '{:0>width$}'",
+                        padded_value,
+                        from.to_string(),
+                        width = U256_MAX_DIGITS
+                    ));
+                }
+                if let Some(to) = to {
+                    clauses.push(format!(
+                        "{} < '{:0>width$}'",
+                        padded_value,
+                        to.to_string(),
+                        width = U256_MAX_DIGITS
+                    ));
+                }
+                format!("({})", clauses.join(" AND "))
+            }
+        }
+    };
+    Ok(query)
+}
+
 fn get_table_name_by_state_type(state_type: ObjectStateType) -> IndexerTableName {
     match state_type {
         ObjectStateType::ObjectState => INDEXER_OBJECT_STATES_TABLE_NAME,
         ObjectStateType::UTXO => INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
         ObjectStateType::Inscription => INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
+        ObjectStateType::RuneBalances => INDEXER_OBJECT_STATE_RUNE_BALANCES_TABLE_NAME,
     }
 }
 fn object_type_query(object_type: &StructTag) -> String {
@@ -692,11 +880,11 @@ fn object_type_query(object_type: &StructTag) -> String {
         let (first_bound, second_bound, upper_bound) =
             optimize_object_type_like_query(object_type_str.as_str());
         format!(
-            "({STATE_OBJECT_TYPE_STR} = \"{}\" OR ({STATE_OBJECT_TYPE_STR} >= \"{}\" AND {STATE_OBJECT_TYPE_STR} < \"{}\" AND {STATE_OBJECT_TYPE_STR} < \"{}\"))",
+            "({STATE_OBJECT_TYPE_STR} = '{}' OR ({STATE_OBJECT_TYPE_STR} >= '{}' AND {STATE_OBJECT_TYPE_STR} < '{}' AND {STATE_OBJECT_TYPE_STR} < '{}'))",
             object_type_str, first_bound, second_bound, upper_bound
         )
     } else {
-        format!("{STATE_OBJECT_TYPE_STR} = \"{}\"", object_type_str)
+        format!("{STATE_OBJECT_TYPE_STR} = '{}'", object_type_str)
     }
 }
 
@@ -707,11 +895,11 @@ fn not_object_type_query(object_type: &StructTag) -> String {
         let (first_bound, second_bound, upper_bound) =
             optimize_object_type_like_query(object_type_str.as_str());
         format!(
-            "({STATE_OBJECT_TYPE_STR} != \"{}\" AND ({STATE_OBJECT_TYPE_STR} < \"{}\" OR {STATE_OBJECT_TYPE_STR} >= \"{}\" OR {STATE_OBJECT_TYPE_STR} >= \"{}\"))",
+            "({STATE_OBJECT_TYPE_STR} != '{}' AND ({STATE_OBJECT_TYPE_STR} < '{}' OR {STATE_OBJECT_TYPE_STR} >= '{}' OR {STATE_OBJECT_TYPE_STR} >= '{}'))",
             object_type_str, first_bound, second_bound, upper_bound
         )
     } else {
-        format!("{STATE_OBJECT_TYPE_STR} != \"{}\"", object_type_str)
+        format!("{STATE_OBJECT_TYPE_STR} != '{}'", object_type_str)
     }
 }
 
@@ -2,11 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::store::metrics::IndexerDBMetrics;
+use crate::store::pg_store::PgIndexerStore;
 use crate::store::sqlite_store::SqliteIndexerStore;
 use crate::store::traits::IndexerStoreTrait;
-use crate::utils::create_all_tables_if_not_exists;
+use crate::utils::{
+    create_all_pg_tables_if_not_exists, create_all_tables_if_not_exists, escape_sql_string,
+};
 use anyhow::Result;
 use diesel::connection::SimpleConnection;
+use diesel::pg::PgConnection;
 use diesel::r2d2::ConnectionManager;
 use diesel::sqlite::SqliteConnection;
 use diesel::ConnectionError::BadConnection;
@@ -17,6 +21,7 @@ use moveos_types::moveos_std::object::{is_dynamic_field_type, DynamicField, Obje
 use moveos_types::state_resolver::{RootObjectResolver, StateResolver};
 use once_cell::sync::Lazy;
 use prometheus::Registry;
+use rooch_config::store_config::StoreConfig;
 use rooch_types::framework::indexer::{FieldIndexerData, IndexerModule};
 use rooch_types::indexer::event::IndexerEvent;
 use rooch_types::indexer::field::{IndexerField, IndexerFieldChanges};
@@ -26,7 +31,7 @@ use rooch_types::indexer::state::{
 use rooch_types::indexer::transaction::IndexerTransaction;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::string::ToString;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
@@ -52,6 +57,7 @@ pub const INDEXER_EVENTS_TABLE_NAME: IndexerTableName = "events";
 pub const INDEXER_OBJECT_STATES_TABLE_NAME: IndexerTableName = "object_states";
 pub const INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME: IndexerTableName = "utxos";
 pub const INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME: IndexerTableName = "inscriptions";
+pub const INDEXER_OBJECT_STATE_RUNE_BALANCES_TABLE_NAME: IndexerTableName = "rune_balances";
 pub const INDEXER_TRANSACTIONS_TABLE_NAME: IndexerTableName = "transactions";
 pub const INDEXER_FIELDS_TABLE_NAME: IndexerTableName = "fields";
 
@@ -62,6 +68,7 @@ static INDEXER_VEC_TABLE_NAME: Lazy<Vec<IndexerTableName>> = Lazy::new(|| {
         INDEXER_OBJECT_STATES_TABLE_NAME,
         INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
         INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
+        INDEXER_OBJECT_STATE_RUNE_BALANCES_TABLE_NAME,
         INDEXER_TRANSACTIONS_TABLE_NAME,
         INDEXER_FIELDS_TABLE_NAME,
     ]
@@ -78,10 +85,14 @@ impl IndexerStoreMeta {
 
 pub type SqliteConnectionPool = diesel::r2d2::Pool<ConnectionManager<SqliteConnection>>;
 pub type SqlitePoolConnection = diesel::r2d2::PooledConnection<ConnectionManager<SqliteConnection>>;
+pub type PgConnectionPool = diesel::r2d2::Pool<ConnectionManager<PgConnection>>;
+pub type PgPoolConnection = diesel::r2d2::PooledConnection<ConnectionManager<PgConnection>>;
 
 #[derive(Clone)]
 pub struct IndexerStore {
     pub sqlite_store_mapping: HashMap<String, SqliteIndexerStore>,
+    /// The PostgreSQL store, if set, all the indexer tables are written to it instead of SQLite
+    pub pg_store: Option<PgIndexerStore>,
 }
 
 impl IndexerStore {
@@ -109,11 +120,32 @@ impl IndexerStore {
 
         let store = Self {
             sqlite_store_mapping,
+            pg_store: None,
+        };
+        store.create_all_tables_if_not_exists()?;
+        Ok(store)
+    }
+
+    pub fn new_postgres(db_url: &str, registry: &Registry) -> Result<Self> {
+        let db_metrics = IndexerDBMetrics::get_or_init(registry).clone();
+        let pg_cp = new_pg_connection_pool(db_url, false)?;
+        let store = Self {
+            sqlite_store_mapping: HashMap::new(),
+            pg_store: Some(PgIndexerStore::new(pg_cp, db_metrics)),
         };
         store.create_all_tables_if_not_exists()?;
         Ok(store)
     }
 
+    /// Open the indexer store with the backend selected by the store config,
+    /// PostgreSQL if the indexer db url is set, otherwise SQLite in the indexer dir.
+    pub fn new_with_store_config(config: &StoreConfig, registry: &Registry) -> Result<Self> {
+        match config.get_indexer_db_url() {
+            Some(db_url) => Self::new_postgres(db_url.as_str(), registry),
+            None => Self::new(config.get_indexer_dir(), registry),
+        }
+    }
+
     pub fn get_sqlite_store(&self, table_name: &str) -> Result<SqliteIndexerStore> {
         Ok(self
             .sqlite_store_mapping
@@ -122,7 +154,42 @@ impl IndexerStore {
             .clone())
     }
 
+    /// Backup every SQLite indexer table into a file with the table name in `backup_dir`,
+    /// `VACUUM INTO` makes a consistent copy while the tables are being written.
+    /// Return the backed up table names, the PostgreSQL backend should be backed up by pg_dump.
+    pub fn backup_sqlite(&self, backup_dir: &Path) -> Result<Vec<String>> {
+        if self.pg_store.is_some() {
+            return Err(anyhow::anyhow!(
+                "The indexer uses PostgreSQL, please backup it with pg_dump"
+            ));
+        }
+        if !backup_dir.exists() {
+            std::fs::create_dir_all(backup_dir)?;
+        }
+        let mut tables = Vec::with_capacity(self.sqlite_store_mapping.len());
+        for (table, store) in &self.sqlite_store_mapping {
+            let backup_path = backup_dir.join(table);
+            let backup_path = backup_path
+                .to_str()
+                .ok_or(anyhow::anyhow!("Invalid indexer backup path"))?;
+            let mut connection = get_sqlite_pool_connection(&store.connection_pool)?;
+            connection
+                .batch_execute(&format!(
+                    "VACUUM INTO '{}'",
+                    escape_sql_string(backup_path.to_string())
+                ))
+                .map_err(|e| anyhow::anyhow!("Failed to backup indexer table {}: {}", table, e))?;
+            tables.push(table.clone());
+        }
+        tables.sort();
+        Ok(tables)
+    }
+
     fn create_all_tables_if_not_exists(&self) -> Result<()> {
+        if let Some(pg_store) = &self.pg_store {
+            let mut connection = get_pg_pool_connection(&pg_store.connection_pool)?;
+            create_all_pg_tables_if_not_exists(&mut connection)?;
+        }
         for (k, v) in &self.sqlite_store_mapping {
             let mut connection = get_sqlite_pool_connection(&v.connection_pool)?;
             create_all_tables_if_not_exists(&mut connection, k.clone())?;
@@ -153,18 +220,19 @@ impl IndexerStoreTrait for IndexerStore {
     ) -> Result<(), IndexerError> {
         self.update_object_states_by_state_type(
             object_state_change_set.object_states,
-            INDEXER_OBJECT_STATES_TABLE_NAME,
             ObjectStateType::ObjectState,
         )?;
         self.update_object_states_by_state_type(
             object_state_change_set.object_state_utxos,
-            INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME,
             ObjectStateType::UTXO,
         )?;
         self.update_object_states_by_state_type(
             object_state_change_set.object_state_inscriptions,
-            INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME,
             ObjectStateType::Inscription,
+        )?;
+        self.update_object_states_by_state_type(
+            object_state_change_set.object_state_rune_balances,
+            ObjectStateType::RuneBalances,
         )
     }
 
@@ -172,11 +240,17 @@ impl IndexerStoreTrait for IndexerStore {
         &self,
         states: Vec<IndexerObjectState>,
     ) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.persist_or_update_object_states(states);
+        }
         self.get_sqlite_store(INDEXER_OBJECT_STATES_TABLE_NAME)?
             .persist_or_update_object_states(states)
     }
 
     fn delete_object_states(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.delete_object_states(state_pks);
+        }
         self.get_sqlite_store(INDEXER_OBJECT_STATES_TABLE_NAME)?
             .delete_object_states(state_pks)
     }
@@ -185,11 +259,17 @@ impl IndexerStoreTrait for IndexerStore {
         &self,
         states: Vec<IndexerObjectState>,
     ) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.persist_or_update_object_state_utxos(states);
+        }
         self.get_sqlite_store(INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME)?
             .persist_or_update_object_state_utxos(states)
     }
 
     fn delete_object_state_utxos(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.delete_object_state_utxos(state_pks);
+        }
         self.get_sqlite_store(INDEXER_OBJECT_STATE_UTXOS_TABLE_NAME)?
             .delete_object_state_utxos(state_pks)
     }
@@ -198,49 +278,98 @@ impl IndexerStoreTrait for IndexerStore {
         &self,
         states: Vec<IndexerObjectState>,
     ) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.persist_or_update_object_state_inscriptions(states);
+        }
         self.get_sqlite_store(INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME)?
             .persist_or_update_object_state_inscriptions(states)
     }
 
     fn delete_object_state_inscriptions(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.delete_object_state_inscriptions(state_pks);
+        }
         self.get_sqlite_store(INDEXER_OBJECT_STATE_INSCRIPTIONS_TABLE_NAME)?
             .delete_object_state_inscriptions(state_pks)
     }
 
+    fn persist_or_update_object_state_rune_balances(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.persist_or_update_object_state_rune_balances(states);
+        }
+        self.get_sqlite_store(INDEXER_OBJECT_STATE_RUNE_BALANCES_TABLE_NAME)?
+            .persist_or_update_object_state_rune_balances(states)
+    }
+
+    fn delete_object_state_rune_balances(
+        &self,
+        state_pks: Vec<String>,
+    ) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.delete_object_state_rune_balances(state_pks);
+        }
+        self.get_sqlite_store(INDEXER_OBJECT_STATE_RUNE_BALANCES_TABLE_NAME)?
+            .delete_object_state_rune_balances(state_pks)
+    }
+
     fn persist_transactions(
         &self,
         transactions: Vec<IndexerTransaction>,
     ) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.persist_transactions(transactions);
+        }
         self.get_sqlite_store(INDEXER_TRANSACTIONS_TABLE_NAME)?
             .persist_transactions(transactions)
     }
 
     fn persist_events(&self, events: Vec<IndexerEvent>) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.persist_events(events);
+        }
         self.get_sqlite_store(INDEXER_EVENTS_TABLE_NAME)?
             .persist_events(events)
     }
 
     fn delete_transactions(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.delete_transactions(tx_orders);
+        }
         self.get_sqlite_store(INDEXER_TRANSACTIONS_TABLE_NAME)?
             .delete_transactions(tx_orders)
     }
 
     fn delete_events(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.delete_events(tx_orders);
+        }
         self.get_sqlite_store(INDEXER_EVENTS_TABLE_NAME)?
             .delete_events(tx_orders)
     }
 
     fn persist_or_update_fields(&self, fields: Vec<IndexerField>) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.persist_or_update_fields(fields);
+        }
         self.get_sqlite_store(INDEXER_FIELDS_TABLE_NAME)?
             .persist_or_update_fields(fields)
     }
 
     fn delete_fields(&self, table_pks: Vec<String>) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.delete_fields(table_pks);
+        }
         self.get_sqlite_store(INDEXER_FIELDS_TABLE_NAME)?
             .delete_fields(table_pks)
     }
 
     fn delete_fields_by_parent_id(&self, ids: Vec<String>) -> Result<(), IndexerError> {
+        if let Some(pg_store) = &self.pg_store {
+            return pg_store.delete_fields_by_parent_id(ids);
+        }
         self.get_sqlite_store(INDEXER_FIELDS_TABLE_NAME)?
             .delete_fields_by_parent_id(ids)
     }
@@ -258,29 +387,26 @@ impl IndexerStore {
     fn update_object_states_by_state_type(
         &self,
         mut object_state_change: IndexerObjectStateChanges,
-        table_name: &str,
         state_type: ObjectStateType,
     ) -> Result<(), IndexerError> {
         let mut object_states_new_and_update = object_state_change.new_object_states;
         object_states_new_and_update.append(&mut object_state_change.update_object_states);
         match state_type {
             ObjectStateType::ObjectState => {
-                self.get_sqlite_store(table_name)?
-                    .persist_or_update_object_states(object_states_new_and_update)?;
-                self.get_sqlite_store(table_name)?
-                    .delete_object_states(object_state_change.remove_object_states)
+                self.persist_or_update_object_states(object_states_new_and_update)?;
+                self.delete_object_states(object_state_change.remove_object_states)
             }
             ObjectStateType::UTXO => {
-                self.get_sqlite_store(table_name)?
-                    .persist_or_update_object_state_utxos(object_states_new_and_update)?;
-                self.get_sqlite_store(table_name)?
-                    .delete_object_state_utxos(object_state_change.remove_object_states)
+                self.persist_or_update_object_state_utxos(object_states_new_and_update)?;
+                self.delete_object_state_utxos(object_state_change.remove_object_states)
             }
             ObjectStateType::Inscription => {
-                self.get_sqlite_store(table_name)?
-                    .persist_or_update_object_state_inscriptions(object_states_new_and_update)?;
-                self.get_sqlite_store(table_name)?
-                    .delete_object_state_inscriptions(object_state_change.remove_object_states)
+                self.persist_or_update_object_state_inscriptions(object_states_new_and_update)?;
+                self.delete_object_state_inscriptions(object_state_change.remove_object_states)
+            }
+            ObjectStateType::RuneBalances => {
+                self.persist_or_update_object_state_rune_balances(object_states_new_and_update)?;
+                self.delete_object_state_rune_balances(object_state_change.remove_object_states)
             }
         }
     }
@@ -432,6 +558,51 @@ pub fn get_sqlite_pool_connection(
     })
 }
 
+pub fn new_pg_connection_pool(
+    db_url: &str,
+    read_only: bool,
+) -> Result<PgConnectionPool, IndexerError> {
+    // The PostgreSQL pool shares the pool size and connection timeout settings with SQLite
+    let pool_config = SqliteConnectionPoolConfig::pool_config(read_only);
+    let manager = ConnectionManager::<PgConnection>::new(db_url);
+
+    diesel::r2d2::Pool::builder()
+        .max_size(pool_config.pool_size)
+        .connection_timeout(pool_config.connection_timeout)
+        .connection_customizer(Box::new(PgConnectionConfig { read_only }))
+        .build(manager)
+        .map_err(|e| {
+            IndexerError::PgConnectionPoolInitError(format!(
+                "Failed to initialize connection pool with error: {:?}",
+                e
+            ))
+        })
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PgConnectionConfig {
+    read_only: bool,
+}
+
+impl diesel::r2d2::CustomizeConnection<PgConnection, diesel::r2d2::Error> for PgConnectionConfig {
+    fn on_acquire(&self, conn: &mut PgConnection) -> std::result::Result<(), diesel::r2d2::Error> {
+        if self.read_only {
+            conn.batch_execute("SET SESSION CHARACTERISTICS AS TRANSACTION READ ONLY;")
+                .map_err(diesel::r2d2::Error::QueryError)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn get_pg_pool_connection(pool: &PgConnectionPool) -> Result<PgPoolConnection, IndexerError> {
+    pool.get().map_err(|e| {
+        IndexerError::PgPoolConnectionError(format!(
+            "Failed to get connection from PostgreSQL connection pool with error: {:?}",
+            e
+        ))
+    })
+}
+
 pub fn list_field_indexer_keys(
     resolver: &RootObjectResolver<MoveOSStore>,
 ) -> Result<Vec<ObjectID>> {
@@ -39,6 +39,9 @@ pub struct StoredEvent {
 
     #[diesel(sql_type = diesel::sql_types::BigInt)]
     pub created_at: i64,
+    /// the event data decoded to JSON
+    #[diesel(sql_type = diesel::sql_types::Nullable<diesel::sql_types::Text>)]
+    pub event_data: Option<String>,
 }
 
 impl From<IndexerEvent> for StoredEvent {
@@ -53,6 +56,7 @@ impl From<IndexerEvent> for StoredEvent {
             tx_order: event.indexer_event_id.tx_order as i64,
             sender: event.sender.to_hex_literal(),
             created_at: event.created_at as i64,
+            event_data: event.decoded_event_data.map(|v| v.to_string()),
         }
     }
 }
@@ -63,12 +67,18 @@ impl StoredEvent {
         let sender = AccountAddress::from_str(self.sender.as_str())?;
         let tx_hash = H256::from_str(self.tx_hash.as_str())?;
         let event_type = StructTag::from_str(self.event_type.as_str())?;
+        let decoded_event_data = self
+            .event_data
+            .as_ref()
+            .map(|v| serde_json::from_str(v.as_str()))
+            .transpose()?;
 
         let indexer_event = IndexerEvent {
             indexer_event_id: IndexerEventID::new(self.tx_order as u64, self.event_index as u64),
             event_id: EventID::new(event_handle_id, self.event_seq as u64),
             event_type,
             event_data: None,
+            decoded_event_data,
             tx_hash,
             sender,
             created_at: self.created_at as u64,
@@ -4,6 +4,7 @@
 pub mod events;
 pub mod fields;
 pub mod inscriptions;
+pub mod rune_balances;
 pub mod states;
 pub mod transactions;
 pub mod utxos;
@@ -3,7 +3,8 @@
 
 use crate::actor::indexer::IndexerActor;
 use crate::actor::messages::{
-    IndexerApplyObjectStatesMessage, IndexerDeleteAnyObjectStatesMessage, IndexerEventsMessage,
+    IndexerApplyObjectStatesMessage, IndexerBackupMessage, IndexerCommittedTransactionMessage,
+    IndexerDeleteAnyObjectStatesMessage, IndexerEventsMessage,
     IndexerPersistOrUpdateAnyObjectStatesMessage, IndexerRevertMessage, IndexerStatesMessage,
     IndexerTransactionMessage, QueryIndexerEventsMessage, QueryIndexerFieldsMessage,
     QueryIndexerObjectIdsMessage, QueryIndexerTransactionsMessage,
@@ -26,6 +27,7 @@ use rooch_types::indexer::state::{
 use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
 use rooch_types::transaction::LedgerTransaction;
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 #[derive(Clone)]
 pub struct IndexerProxy {
@@ -61,6 +63,26 @@ impl IndexerProxy {
         Ok(())
     }
 
+    pub async fn index_committed_transaction(
+        &self,
+        ledger_transaction: LedgerTransaction,
+        execution_info: TransactionExecutionInfo,
+        indexer_transaction: IndexerTransaction,
+        events: Vec<Event>,
+        state_change_set: StateChangeSet,
+    ) -> Result<()> {
+        self.actor
+            .notify(IndexerCommittedTransactionMessage {
+                ledger_transaction,
+                execution_info,
+                indexer_transaction,
+                events,
+                state_change_set,
+            })
+            .await?;
+        Ok(())
+    }
+
     pub async fn indexer_states(
         &self,
         root: ObjectMeta,
@@ -231,6 +253,11 @@ impl IndexerProxy {
         Ok(())
     }
 
+    /// Backup the indexer by the indexer actor, so the backup runs outside the pipeline processor
+    pub async fn backup(&self, backup_dir: PathBuf) -> Result<Vec<String>> {
+        self.actor.send(IndexerBackupMessage { backup_dir }).await?
+    }
+
     pub async fn query_fields(
         &self,
         filter: FieldFilter,
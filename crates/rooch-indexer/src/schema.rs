@@ -13,6 +13,7 @@ diesel::table! {
         tx_order -> BigInt,
         sender -> Text,
         created_at -> BigInt,
+        event_data -> Nullable<Text>,
     }
 }
 
@@ -50,6 +51,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    rune_balances (id) {
+        id -> Text,
+        owner -> Text,
+        tx_order -> BigInt,
+        state_index -> BigInt,
+        created_at -> BigInt,
+        updated_at -> BigInt,
+    }
+}
+
 diesel::table! {
     transactions (tx_order) {
         tx_order -> BigInt,
@@ -81,6 +93,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     object_states,
     utxos,
     inscriptions,
+    rune_balances,
     transactions,
     fields,
 );
@@ -0,0 +1,503 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::errors::{Context, IndexerError};
+use anyhow::Result;
+use diesel::upsert::excluded;
+use diesel::QueryDsl;
+use diesel::{ExpressionMethods, RunQueryDsl};
+use function_name::named;
+use rooch_types::indexer::event::IndexerEvent;
+use rooch_types::indexer::field::IndexerField;
+use rooch_types::indexer::state::IndexerObjectState;
+use rooch_types::indexer::transaction::IndexerTransaction;
+use std::sync::Arc;
+
+use crate::models::events::StoredEvent;
+use crate::models::fields::StoredField;
+use crate::models::inscriptions::StoredInscription;
+use crate::models::rune_balances::StoredRuneBalances;
+use crate::models::states::StoredObjectState;
+use crate::models::transactions::StoredTransaction;
+use crate::models::utxos::StoredUTXO;
+use crate::schema::{
+    events, fields, inscriptions, object_states, rune_balances, transactions, utxos,
+};
+use crate::store::metrics::IndexerDBMetrics;
+use crate::{get_pg_pool_connection, PgConnectionPool};
+
+/// PostgreSQL limits a statement to 65535 bind parameters, so batch writes are split into chunks.
+const PG_WRITE_CHUNK_SIZE: usize = 5000;
+
+/// The indexer store backed by a single PostgreSQL database, all the indexer tables live in it.
+/// Unlike SQLite, PostgreSQL binds the values as parameters, so the values are not escaped.
+#[derive(Clone)]
+pub struct PgIndexerStore {
+    pub(crate) connection_pool: PgConnectionPool,
+    db_metrics: Arc<IndexerDBMetrics>,
+}
+
+impl PgIndexerStore {
+    pub fn new(connection_pool: PgConnectionPool, db_metrics: Arc<IndexerDBMetrics>) -> Self {
+        Self {
+            connection_pool,
+            db_metrics,
+        }
+    }
+
+    #[named]
+    pub fn persist_or_update_object_states(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let states = states
+            .into_iter()
+            .map(StoredObjectState::from)
+            .collect::<Vec<_>>();
+
+        for chunk in states.chunks(PG_WRITE_CHUNK_SIZE) {
+            diesel::insert_into(object_states::table)
+                .values(chunk)
+                .on_conflict(object_states::id)
+                .do_update()
+                .set((
+                    object_states::owner.eq(excluded(object_states::owner)),
+                    object_states::tx_order.eq(excluded(object_states::tx_order)),
+                    object_states::state_index.eq(excluded(object_states::state_index)),
+                    object_states::updated_at.eq(excluded(object_states::updated_at)),
+                ))
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+                .context("Failed to write or update object states to PostgreSQL")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn persist_or_update_object_state_utxos(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let states = states.into_iter().map(StoredUTXO::from).collect::<Vec<_>>();
+
+        for chunk in states.chunks(PG_WRITE_CHUNK_SIZE) {
+            diesel::insert_into(utxos::table)
+                .values(chunk)
+                .on_conflict(utxos::id)
+                .do_update()
+                .set((
+                    utxos::owner.eq(excluded(utxos::owner)),
+                    utxos::tx_order.eq(excluded(utxos::tx_order)),
+                    utxos::state_index.eq(excluded(utxos::state_index)),
+                    utxos::updated_at.eq(excluded(utxos::updated_at)),
+                ))
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+                .context("Failed to write or update object state utxos to PostgreSQL")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn persist_or_update_object_state_inscriptions(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let states = states
+            .into_iter()
+            .map(StoredInscription::from)
+            .collect::<Vec<_>>();
+
+        for chunk in states.chunks(PG_WRITE_CHUNK_SIZE) {
+            diesel::insert_into(inscriptions::table)
+                .values(chunk)
+                .on_conflict(inscriptions::id)
+                .do_update()
+                .set((
+                    inscriptions::owner.eq(excluded(inscriptions::owner)),
+                    inscriptions::tx_order.eq(excluded(inscriptions::tx_order)),
+                    inscriptions::state_index.eq(excluded(inscriptions::state_index)),
+                    inscriptions::updated_at.eq(excluded(inscriptions::updated_at)),
+                ))
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+                .context("Failed to write or update object state inscriptions to PostgreSQL")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn persist_or_update_object_state_rune_balances(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError> {
+        if states.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let states = states
+            .into_iter()
+            .map(StoredRuneBalances::from)
+            .collect::<Vec<_>>();
+
+        for chunk in states.chunks(PG_WRITE_CHUNK_SIZE) {
+            diesel::insert_into(rune_balances::table)
+                .values(chunk)
+                .on_conflict(rune_balances::id)
+                .do_update()
+                .set((
+                    rune_balances::owner.eq(excluded(rune_balances::owner)),
+                    rune_balances::tx_order.eq(excluded(rune_balances::tx_order)),
+                    rune_balances::state_index.eq(excluded(rune_balances::state_index)),
+                    rune_balances::updated_at.eq(excluded(rune_balances::updated_at)),
+                ))
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+                .context("Failed to write or update object state rune balances to PostgreSQL")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn delete_object_states(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(object_states::table.filter(object_states::id.eq_any(state_pks.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete object states to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn delete_object_state_utxos(&self, state_pks: Vec<String>) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(utxos::table.filter(utxos::id.eq_any(state_pks.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete object state utxos to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn delete_object_state_inscriptions(
+        &self,
+        state_pks: Vec<String>,
+    ) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(inscriptions::table.filter(inscriptions::id.eq_any(state_pks.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete object state inscriptions to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn delete_object_state_rune_balances(
+        &self,
+        state_pks: Vec<String>,
+    ) -> Result<(), IndexerError> {
+        if state_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(rune_balances::table.filter(rune_balances::id.eq_any(state_pks.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete object state rune balances to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn persist_transactions(
+        &self,
+        transactions: Vec<IndexerTransaction>,
+    ) -> Result<(), IndexerError> {
+        if transactions.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let transactions = transactions
+            .into_iter()
+            .map(StoredTransaction::from)
+            .collect::<Vec<_>>();
+
+        for chunk in transactions.chunks(PG_WRITE_CHUNK_SIZE) {
+            diesel::insert_into(transactions::table)
+                .values(chunk)
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+                .context("Failed to write transactions to PostgreSQL")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn delete_transactions(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        if tx_orders.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        let tx_orders: Vec<_> = tx_orders.into_iter().map(|v| v as i64).collect();
+        diesel::delete(
+            transactions::table.filter(transactions::tx_order.eq_any(tx_orders.as_slice())),
+        )
+        .execute(&mut connection)
+        .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+        .context("Failed to delete transactions to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn persist_events(&self, events: Vec<IndexerEvent>) -> Result<(), IndexerError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let events = events
+            .into_iter()
+            .map(StoredEvent::from)
+            .collect::<Vec<_>>();
+
+        for chunk in events.chunks(PG_WRITE_CHUNK_SIZE) {
+            diesel::insert_into(events::table)
+                .values(chunk)
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+                .context("Failed to write events to PostgreSQL")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn delete_events(&self, tx_orders: Vec<u64>) -> Result<(), IndexerError> {
+        if tx_orders.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        let tx_orders: Vec<_> = tx_orders.into_iter().map(|v| v as i64).collect();
+        diesel::delete(events::table.filter(events::tx_order.eq_any(tx_orders.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete events to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn persist_or_update_fields(&self, fields: Vec<IndexerField>) -> Result<(), IndexerError> {
+        if fields.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+        let fields = fields
+            .into_iter()
+            .map(StoredField::from)
+            .collect::<Vec<_>>();
+
+        for chunk in fields.chunks(PG_WRITE_CHUNK_SIZE) {
+            diesel::insert_into(fields::table)
+                .values(chunk)
+                .on_conflict(fields::id)
+                .do_update()
+                .set((
+                    fields::parent_id.eq(excluded(fields::parent_id)),
+                    fields::field_key.eq(excluded(fields::field_key)),
+                    fields::sort_key.eq(excluded(fields::sort_key)),
+                    fields::updated_at.eq(excluded(fields::updated_at)),
+                ))
+                .execute(&mut connection)
+                .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+                .context("Failed to write or update fields to PostgreSQL")?;
+        }
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn delete_fields(&self, field_pks: Vec<String>) -> Result<(), IndexerError> {
+        if field_pks.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(fields::table.filter(fields::id.eq_any(field_pks.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete fields to PostgreSQL")?;
+
+        Ok(())
+    }
+
+    #[named]
+    pub fn delete_fields_by_parent_id(&self, ids: Vec<String>) -> Result<(), IndexerError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let fn_name = function_name!();
+        let _timer = self
+            .db_metrics
+            .indexer_store_metrics
+            .indexer_persist_or_update_or_delete_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+        let mut connection = get_pg_pool_connection(&self.connection_pool)?;
+
+        diesel::delete(fields::table.filter(fields::parent_id.eq_any(ids.as_slice())))
+            .execute(&mut connection)
+            .map_err(|e| IndexerError::PgWriteError(e.to_string()))
+            .context("Failed to delete fields to PostgreSQL")?;
+
+        Ok(())
+    }
+}
@@ -35,6 +35,14 @@ pub trait IndexerStoreTrait: Send + Sync {
 
     fn delete_object_state_inscriptions(&self, state_pks: Vec<String>) -> Result<(), IndexerError>;
 
+    fn persist_or_update_object_state_rune_balances(
+        &self,
+        states: Vec<IndexerObjectState>,
+    ) -> Result<(), IndexerError>;
+
+    fn delete_object_state_rune_balances(&self, state_pks: Vec<String>)
+        -> Result<(), IndexerError>;
+
     fn persist_transactions(
         &self,
         transactions: Vec<IndexerTransaction>,
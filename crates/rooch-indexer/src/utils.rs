@@ -1,13 +1,14 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::SqlitePoolConnection;
+use crate::{PgPoolConnection, SqlitePoolConnection};
 use anyhow::anyhow;
 use diesel::{RunQueryDsl, SqliteConnection};
 use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
 use tracing::{debug, info};
 
 const MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations");
+const PG_MIGRATIONS: EmbeddedMigrations = embed_migrations!("migrations_pg");
 
 /// creates all the tables by applying all migrations.
 pub fn create_all_tables_if_not_exists(
@@ -50,6 +51,18 @@ pub fn create_all_tables_if_not_exists(
     Ok(())
 }
 
+/// creates all the tables in the PostgreSQL db by applying all PostgreSQL migrations.
+pub fn create_all_pg_tables_if_not_exists(
+    conn: &mut PgPoolConnection,
+) -> Result<(), anyhow::Error> {
+    debug!("Indexer creates all tables in the PostgreSQL db ...");
+    // The harness creates the __diesel_schema_migrations table if not exist
+    conn.run_pending_migrations(PG_MIGRATIONS)
+        .map_err(|e| anyhow!("Failed to run PostgreSQL migrations {e}"))?;
+    debug!("Indexer creates all PostgreSQL tables complete.");
+    Ok(())
+}
+
 /// Resets the db by reverting all migrations and reapplying them.
 ///
 /// If `drop_all` is set to `true`, the function will drop all tables in the db before
@@ -101,6 +114,6 @@ pub fn drop_all_tables(conn: &mut SqliteConnection) -> Result<(), diesel::result
 }
 
 pub fn escape_sql_string(value: String) -> String {
-    // In SQLite, replace single quotes with two single quotes
+    // In SQLite and PostgreSQL, replace single quotes with two single quotes
     value.replace(['\''], "''")
 }
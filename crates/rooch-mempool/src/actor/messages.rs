@@ -0,0 +1,49 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::pool::PooledTransaction;
+use anyhow::Result;
+use coerce::actor::{message::Message, scheduler::timer::TimerTick};
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::{ExecuteTransactionResponse, RoochTransaction};
+use tokio::sync::oneshot;
+
+pub type ExecuteTransactionReceiver = oneshot::Receiver<Result<ExecuteTransactionResponse>>;
+
+/// Submit a L2 transaction to the tx pool, the receiver gets the execution result
+/// after the transaction is sequenced and executed.
+#[derive(Clone)]
+pub struct SubmitTransactionMessage {
+    pub tx: RoochTransaction,
+}
+
+impl Message for SubmitTransactionMessage {
+    type Result = Result<ExecuteTransactionReceiver>;
+}
+
+/// Sequence and execute the ready transactions in the tx pool
+#[derive(Clone)]
+pub struct ProcessReadyTransactionsMessage {}
+
+impl Message for ProcessReadyTransactionsMessage {
+    type Result = ();
+}
+
+#[derive(Clone)]
+pub struct GetPendingTransactionsMessage {
+    pub sender: Option<RoochAddress>,
+}
+
+impl Message for GetPendingTransactionsMessage {
+    type Result = Result<Vec<(PooledTransaction, bool)>>;
+}
+
+/// Timer tick to remove the expired transactions from the tx pool
+#[derive(Clone)]
+pub struct TxPoolGcTick {}
+
+impl Message for TxPoolGcTick {
+    type Result = ();
+}
+
+impl TimerTick for TxPoolGcTick {}
@@ -0,0 +1,234 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::messages::{
+    ExecuteTransactionReceiver, GetPendingTransactionsMessage, ProcessReadyTransactionsMessage,
+    SubmitTransactionMessage, TxPoolGcTick,
+};
+use crate::pool::{PooledTransaction, TxPool};
+use anyhow::Result;
+use async_trait::async_trait;
+use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRef};
+use moveos_types::h256::H256;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::{ExecuteTransactionResponse, RoochTransaction};
+use std::collections::HashMap;
+use std::time::SystemTime;
+use tokio::sync::oneshot;
+use tracing::{debug, info};
+
+/// The max number of ready transactions sent to the pipeline processor in one batch
+const MAX_READY_BATCH_SIZE: usize = 32;
+
+type ExecuteTransactionSender = oneshot::Sender<Result<ExecuteTransactionResponse>>;
+
+/// TxPoolActor sits between the RPC service and the pipeline processor,
+/// it queues the L2 transactions with future sequence numbers until the gaps are filled,
+/// and sends the ready transactions to the pipeline processor by the fee priority.
+pub struct TxPoolActor {
+    pool: TxPool,
+    executor: ExecutorProxy,
+    processor: PipelineProcessorProxy,
+    responders: HashMap<H256, ExecuteTransactionSender>,
+}
+
+impl TxPoolActor {
+    pub fn new(pool: TxPool, executor: ExecutorProxy, processor: PipelineProcessorProxy) -> Self {
+        Self {
+            pool,
+            executor,
+            processor,
+            responders: HashMap::new(),
+        }
+    }
+
+    fn now_milliseconds() -> u64 {
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .expect("system time should be after the unix epoch")
+            .as_millis() as u64
+    }
+
+    fn respond(&mut self, tx_hash: H256, result: Result<ExecuteTransactionResponse>) {
+        if let Some(responder) = self.responders.remove(&tx_hash) {
+            // the submitter may not wait for the result
+            let _ = responder.send(result);
+        }
+    }
+
+    pub async fn submit(
+        &mut self,
+        mut tx: RoochTransaction,
+    ) -> Result<(ExecuteTransactionReceiver, bool)> {
+        let (sender, receiver) = oneshot::channel();
+        let tx_hash = tx.tx_hash();
+        let account_sequence_number = self
+            .executor
+            .get_sequence_number(tx.sender().into())
+            .await?;
+        for stale in self.pool.remove_stale(tx.sender(), account_sequence_number) {
+            self.respond(
+                stale.tx_hash,
+                Err(anyhow::anyhow!(
+                    "Transaction {:?} is dropped from the tx pool, the sequence number {} of {} is stale, the account sequence number is {}",
+                    stale.tx_hash,
+                    stale.sequence_number(),
+                    stale.sender(),
+                    account_sequence_number
+                )),
+            );
+        }
+        // The stale transaction is not queued, it is sent to the pipeline processor directly,
+        // and the validator rejects it with the sequence number error.
+        // It is executed in a separate task to not block the following submissions.
+        if tx.sequence_number() < account_sequence_number {
+            let processor = self.processor.clone();
+            tokio::spawn(async move {
+                let result = processor.execute_l2_tx(tx).await;
+                let _ = sender.send(result);
+            });
+            return Ok((receiver, false));
+        }
+        let ptx = PooledTransaction::new(tx, Self::now_milliseconds());
+        if let Some(replaced) = self.pool.add(ptx, account_sequence_number)? {
+            self.respond(
+                replaced.tx_hash,
+                Err(anyhow::anyhow!(
+                    "Transaction {:?} is replaced by {:?} with a higher priority in the tx pool",
+                    replaced.tx_hash,
+                    tx_hash
+                )),
+            );
+        }
+        self.responders.insert(tx_hash, sender);
+        Ok((receiver, true))
+    }
+
+    pub async fn process_ready_transactions(&mut self) {
+        loop {
+            // The ready transactions are from different senders,
+            // the pipeline processor executes them in a parallel batch.
+            let mut ptxs = vec![];
+            while ptxs.len() < MAX_READY_BATCH_SIZE {
+                match self.pool.pop_ready() {
+                    Some(ptx) => ptxs.push(ptx),
+                    None => break,
+                }
+            }
+            if ptxs.is_empty() {
+                break;
+            }
+            let txs = ptxs.iter().map(|ptx| ptx.tx.clone()).collect::<Vec<_>>();
+            let results = match self.processor.execute_l2_tx_batch(txs).await {
+                Ok(results) => results,
+                Err(e) => {
+                    let error = format!("{:?}", e);
+                    ptxs.iter()
+                        .map(|_| Err(anyhow::anyhow!("{}", error)))
+                        .collect()
+                }
+            };
+            for (ptx, result) in ptxs.into_iter().zip(results) {
+                let sender = ptx.sender();
+                let sequence_number = ptx.sequence_number();
+                if result.is_ok() {
+                    self.pool.mark_sequenced(sender, sequence_number);
+                } else {
+                    debug!(
+                        "Transaction {:?} from tx pool failed, sender: {}, sequence number: {}",
+                        ptx.tx_hash, sender, sequence_number
+                    );
+                    for dropped in self.pool.mark_failed(sender) {
+                        self.respond(
+                            dropped.tx_hash,
+                            Err(anyhow::anyhow!(
+                                "Transaction {:?} is dropped from the tx pool, the transaction with sequence number {} of {} failed",
+                                dropped.tx_hash,
+                                sequence_number,
+                                sender
+                            )),
+                        );
+                    }
+                }
+                self.respond(ptx.tx_hash, result);
+            }
+        }
+    }
+
+    pub fn remove_expired(&mut self) {
+        let expired = self.pool.remove_expired(Self::now_milliseconds());
+        if !expired.is_empty() {
+            info!(
+                "Removed {} expired transactions from tx pool",
+                expired.len()
+            );
+        }
+        for ptx in expired {
+            self.respond(
+                ptx.tx_hash,
+                Err(anyhow::anyhow!(
+                    "Transaction {:?} is expired in the tx pool, the sequence number {} of {} is not reached",
+                    ptx.tx_hash,
+                    ptx.sequence_number(),
+                    ptx.sender()
+                )),
+            );
+        }
+    }
+
+    pub fn pending_transactions(
+        &self,
+        sender: Option<RoochAddress>,
+    ) -> Vec<(PooledTransaction, bool)> {
+        self.pool.pending_transactions(sender)
+    }
+}
+
+impl Actor for TxPoolActor {}
+
+#[async_trait]
+impl Handler<SubmitTransactionMessage> for TxPoolActor {
+    async fn handle(
+        &mut self,
+        msg: SubmitTransactionMessage,
+        ctx: &mut ActorContext,
+    ) -> Result<ExecuteTransactionReceiver> {
+        let (receiver, queued) = self.submit(msg.tx).await?;
+        if queued {
+            // The ready transactions are processed after the messages already in the mailbox,
+            // so the burst of transactions are ordered by the priority together.
+            let local_actor_ref: LocalActorRef<Self> = ctx.actor_ref();
+            local_actor_ref
+                .notify(ProcessReadyTransactionsMessage {})
+                .map_err(|e| anyhow::anyhow!("Notify tx pool to process error: {:?}", e))?;
+        }
+        Ok(receiver)
+    }
+}
+
+#[async_trait]
+impl Handler<ProcessReadyTransactionsMessage> for TxPoolActor {
+    async fn handle(&mut self, _msg: ProcessReadyTransactionsMessage, _ctx: &mut ActorContext) {
+        self.process_ready_transactions().await
+    }
+}
+
+#[async_trait]
+impl Handler<GetPendingTransactionsMessage> for TxPoolActor {
+    async fn handle(
+        &mut self,
+        msg: GetPendingTransactionsMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<(PooledTransaction, bool)>> {
+        Ok(self.pending_transactions(msg.sender))
+    }
+}
+
+#[async_trait]
+impl Handler<TxPoolGcTick> for TxPoolActor {
+    async fn handle(&mut self, _msg: TxPoolGcTick, _ctx: &mut ActorContext) {
+        self.remove_expired()
+    }
+}
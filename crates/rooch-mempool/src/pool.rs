@@ -0,0 +1,499 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use moveos_types::h256::H256;
+use rooch_config::tx_pool_config::TxPoolConfig;
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::RoochTransaction;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+#[derive(Clone, Debug)]
+pub struct PooledTransaction {
+    pub tx: RoochTransaction,
+    pub tx_hash: H256,
+    /// The fee priority of the transaction, the ready transaction with higher priority is sequenced first.
+    /// It is the gas price the transaction bids, the transaction without gas price has the lowest priority.
+    pub priority: u64,
+    /// The timestamp in milliseconds when the transaction is received by the pool
+    pub received_at: u64,
+}
+
+impl PooledTransaction {
+    pub fn new(mut tx: RoochTransaction, received_at: u64) -> Self {
+        let tx_hash = tx.tx_hash();
+        let priority = tx.gas_price().unwrap_or_default();
+        Self {
+            tx,
+            tx_hash,
+            priority,
+            received_at,
+        }
+    }
+
+    pub fn sender(&self) -> RoochAddress {
+        self.tx.sender()
+    }
+
+    pub fn sequence_number(&self) -> u64 {
+        self.tx.sequence_number()
+    }
+}
+
+/// The pending transactions of a sender, ordered by the sequence number.
+#[derive(Debug, Default)]
+struct SenderQueue {
+    /// The sequence number of the next transaction to sequence
+    next_sequence_number: u64,
+    /// Whether the transaction with the next sequence number is popped and being sequenced
+    sequencing: bool,
+    txs: BTreeMap<u64, PooledTransaction>,
+}
+
+impl SenderQueue {
+    fn ready(&self) -> Option<&PooledTransaction> {
+        if self.sequencing {
+            return None;
+        }
+        self.txs.get(&self.next_sequence_number)
+    }
+}
+
+/// The key of a ready transaction in the ready index, the greatest key is sequenced first.
+type ReadyKey = (u64, Reverse<u64>, RoochAddress);
+
+fn ready_key(ptx: &PooledTransaction) -> ReadyKey {
+    (ptx.priority, Reverse(ptx.received_at), ptx.sender())
+}
+
+/// TxPool queues the transactions per sender by the sequence number,
+/// a transaction is ready when its sequence number is the next sequence number of the sender,
+/// and the ready transactions of all the senders are sequenced by the fee priority.
+#[derive(Debug)]
+pub struct TxPool {
+    max_size: usize,
+    max_per_sender: usize,
+    /// The time to live in milliseconds
+    ttl: u64,
+    senders: HashMap<RoochAddress, SenderQueue>,
+    /// The ready transaction of each sender indexed by the priority
+    ready: BTreeSet<ReadyKey>,
+    size: usize,
+}
+
+impl TxPool {
+    pub fn new(config: &TxPoolConfig) -> Self {
+        Self {
+            max_size: config.max_size(),
+            max_per_sender: config.max_per_sender(),
+            ttl: config.ttl() * 1000,
+            senders: HashMap::new(),
+            ready: BTreeSet::new(),
+            size: 0,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn contains(&self, sender: &RoochAddress, tx_hash: &H256) -> bool {
+        self.senders
+            .get(sender)
+            .map(|queue| queue.txs.values().any(|ptx| &ptx.tx_hash == tx_hash))
+            .unwrap_or(false)
+    }
+
+    /// Add the transaction to the pool, the `account_sequence_number` is the current sequence number
+    /// of the sender account on chain, the stale transactions of the sender should be removed by
+    /// `remove_stale` before.
+    /// The transaction with the same sender and sequence number is replaced if the new one has a higher priority,
+    /// and the replaced transaction is returned.
+    pub fn add(
+        &mut self,
+        ptx: PooledTransaction,
+        account_sequence_number: u64,
+    ) -> Result<Option<PooledTransaction>> {
+        let sender = ptx.sender();
+        let sequence_number = ptx.sequence_number();
+        if self.contains(&sender, &ptx.tx_hash) {
+            bail!("Transaction {:?} is already in the tx pool", ptx.tx_hash);
+        }
+        let queue = self.senders.get(&sender);
+        let next_sequence_number = queue
+            .map(|queue| queue.next_sequence_number.max(account_sequence_number))
+            .unwrap_or(account_sequence_number);
+        if sequence_number < next_sequence_number {
+            bail!(
+                "Transaction sequence number {} is too old, the next sequence number of {} is {}",
+                sequence_number,
+                sender,
+                next_sequence_number
+            );
+        }
+        let sequencing = queue.map(|queue| queue.sequencing).unwrap_or(false);
+        if sequencing && sequence_number == next_sequence_number {
+            bail!(
+                "Transaction with sequence number {} of {} is being sequenced",
+                sequence_number,
+                sender
+            );
+        }
+        let is_ready = !sequencing && sequence_number == next_sequence_number;
+        match queue.and_then(|queue| queue.txs.get(&sequence_number)) {
+            Some(existing) => {
+                if ptx.priority <= existing.priority {
+                    bail!(
+                        "Transaction with sequence number {} of {} is already in the tx pool, the replacement must have a higher priority than {}",
+                        sequence_number,
+                        sender,
+                        existing.priority
+                    );
+                }
+                if is_ready {
+                    self.ready.remove(&ready_key(existing));
+                }
+            }
+            None => {
+                let pending = queue.map(|queue| queue.txs.len()).unwrap_or_default();
+                if sequence_number >= next_sequence_number + self.max_per_sender as u64
+                    || pending >= self.max_per_sender
+                {
+                    bail!(
+                        "Too many pending transactions of {} in the tx pool, the max is {}",
+                        sender,
+                        self.max_per_sender
+                    );
+                }
+                if self.size >= self.max_size {
+                    bail!("The tx pool is full, the max size is {}", self.max_size);
+                }
+            }
+        }
+
+        // The sender queue is only created after the transaction is accepted
+        if is_ready {
+            self.ready.insert(ready_key(&ptx));
+        }
+        let queue = self.senders.entry(sender).or_default();
+        queue.next_sequence_number = next_sequence_number;
+        let replaced = queue.txs.insert(sequence_number, ptx);
+        if replaced.is_none() {
+            self.size += 1;
+        }
+        Ok(replaced)
+    }
+
+    /// Remove the transactions of the sender with the sequence number below the account sequence number
+    /// on chain, they can never be sequenced since the account sequence number is advanced by other ways.
+    /// The sender's next sequence number is advanced, and the removed transactions are returned.
+    pub fn remove_stale(
+        &mut self,
+        sender: RoochAddress,
+        account_sequence_number: u64,
+    ) -> Vec<PooledTransaction> {
+        let queue = match self.senders.get_mut(&sender) {
+            Some(queue) if queue.next_sequence_number < account_sequence_number => queue,
+            _ => return vec![],
+        };
+        if let Some(ptx) = queue.ready() {
+            self.ready.remove(&ready_key(ptx));
+        }
+        queue.next_sequence_number = account_sequence_number;
+        let pending = queue.txs.split_off(&account_sequence_number);
+        let stale = std::mem::replace(&mut queue.txs, pending)
+            .into_values()
+            .collect::<Vec<_>>();
+        if let Some(ptx) = queue.ready() {
+            self.ready.insert(ready_key(ptx));
+        }
+        // keep the sender being sequenced until it is marked
+        if !queue.sequencing && queue.txs.is_empty() {
+            self.senders.remove(&sender);
+        }
+        self.size -= stale.len();
+        stale
+    }
+
+    /// Pop the ready transaction with the highest priority, the earlier received one goes first
+    /// if the priorities are the same.
+    /// The sender's next sequence number is not advanced until `mark_sequenced` is called,
+    /// so the following transactions of the sender are not ready meanwhile.
+    pub fn pop_ready(&mut self) -> Option<PooledTransaction> {
+        let (_, _, sender) = self.ready.pop_last()?;
+        let queue = self.senders.get_mut(&sender)?;
+        let ptx = queue.txs.remove(&queue.next_sequence_number)?;
+        queue.sequencing = true;
+        self.size -= 1;
+        Some(ptx)
+    }
+
+    /// Advance the sender's next sequence number after the transaction is sequenced.
+    pub fn mark_sequenced(&mut self, sender: RoochAddress, sequence_number: u64) {
+        if let Some(queue) = self.senders.get_mut(&sender) {
+            queue.sequencing = false;
+            queue.next_sequence_number = queue.next_sequence_number.max(sequence_number + 1);
+            if let Some(ptx) = queue.ready() {
+                self.ready.insert(ready_key(ptx));
+            }
+            if queue.txs.is_empty() {
+                self.senders.remove(&sender);
+            }
+        }
+    }
+
+    /// Release the sender after the transaction failed to be sequenced.
+    /// The sender's sequence number is not advanced on chain, so the following transactions
+    /// of the sender can not be sequenced, they are removed from the pool and returned.
+    pub fn mark_failed(&mut self, sender: RoochAddress) -> Vec<PooledTransaction> {
+        let queue = match self.senders.remove(&sender) {
+            Some(queue) => queue,
+            None => return vec![],
+        };
+        if let Some(ptx) = queue.ready() {
+            self.ready.remove(&ready_key(ptx));
+        }
+        self.size -= queue.txs.len();
+        queue.txs.into_values().collect()
+    }
+
+    /// Remove the transactions which have been in the pool longer than the ttl.
+    pub fn remove_expired(&mut self, now: u64) -> Vec<PooledTransaction> {
+        let ttl = self.ttl;
+        let ready = &mut self.ready;
+        let mut expired = vec![];
+        self.senders.retain(|_, queue| {
+            let expired_sequence_numbers = queue
+                .txs
+                .iter()
+                .filter(|(_, ptx)| ptx.received_at.saturating_add(ttl) <= now)
+                .map(|(sequence_number, _)| *sequence_number)
+                .collect::<Vec<_>>();
+            if let Some(ptx) = queue.ready() {
+                if expired_sequence_numbers.contains(&ptx.sequence_number()) {
+                    ready.remove(&ready_key(ptx));
+                }
+            }
+            for sequence_number in expired_sequence_numbers {
+                if let Some(ptx) = queue.txs.remove(&sequence_number) {
+                    expired.push(ptx);
+                }
+            }
+            // keep the sender being sequenced until it is marked
+            queue.sequencing || !queue.txs.is_empty()
+        });
+        self.size -= expired.len();
+        expired
+    }
+
+    /// Get the pending transactions, and whether each transaction is ready to be sequenced.
+    pub fn pending_transactions(
+        &self,
+        sender: Option<RoochAddress>,
+    ) -> Vec<(PooledTransaction, bool)> {
+        self.senders
+            .iter()
+            .filter(|(address, _)| sender.map(|sender| sender == **address).unwrap_or(true))
+            .flat_map(|(_, queue)| {
+                queue.txs.values().map(|ptx| {
+                    (
+                        ptx.clone(),
+                        !queue.sequencing && ptx.sequence_number() == queue.next_sequence_number,
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos_types::transaction::MoveAction;
+    use rooch_types::address::RoochSupportedAddress;
+    use rooch_types::transaction::RoochTransactionData;
+
+    fn pooled_tx(
+        sender: RoochAddress,
+        sequence_number: u64,
+        gas_price: u64,
+        received_at: u64,
+    ) -> PooledTransaction {
+        let tx_data = RoochTransactionData::new_for_test(
+            sender,
+            sequence_number,
+            MoveAction::ModuleBundle(vec![]),
+        )
+        .with_gas_price(gas_price);
+        let tx = RoochTransaction::new(tx_data, RoochTransaction::mock().authenticator);
+        PooledTransaction::new(tx, received_at)
+    }
+
+    #[test]
+    fn test_future_sequence_number_is_queued() {
+        let mut pool = TxPool::new(&TxPoolConfig::default());
+        let sender = RoochAddress::random();
+        pool.add(pooled_tx(sender, 2, 100, 0), 0).unwrap();
+        pool.add(pooled_tx(sender, 1, 100, 0), 0).unwrap();
+        assert!(pool.pop_ready().is_none());
+
+        pool.add(pooled_tx(sender, 0, 100, 0), 0).unwrap();
+        for sequence_number in 0..3 {
+            let ptx = pool.pop_ready().unwrap();
+            assert_eq!(ptx.sequence_number(), sequence_number);
+            // the next one is not ready until the popped one is sequenced
+            assert!(pool.pop_ready().is_none());
+            pool.mark_sequenced(sender, sequence_number);
+        }
+        assert_eq!(pool.size(), 0);
+        assert!(pool.add(pooled_tx(sender, 1, 100, 0), 3).is_err());
+    }
+
+    #[test]
+    fn test_ready_transactions_ordered_by_priority() {
+        let mut pool = TxPool::new(&TxPoolConfig::default());
+        let (alice, bob, carol) = (
+            RoochAddress::random(),
+            RoochAddress::random(),
+            RoochAddress::random(),
+        );
+        pool.add(pooled_tx(alice, 0, 100, 0), 0).unwrap();
+        pool.add(pooled_tx(bob, 0, 200, 1), 0).unwrap();
+        pool.add(pooled_tx(carol, 0, 200, 2), 0).unwrap();
+        assert_eq!(pool.pop_ready().unwrap().sender(), bob);
+        assert_eq!(pool.pop_ready().unwrap().sender(), carol);
+        assert_eq!(pool.pop_ready().unwrap().sender(), alice);
+
+        // the replacement must have a higher priority
+        pool.add(pooled_tx(alice, 1, 100, 0), 1).unwrap();
+        assert!(pool.add(pooled_tx(alice, 1, 100, 1), 1).is_err());
+        let replaced = pool.add(pooled_tx(alice, 1, 300, 1), 1).unwrap();
+        assert_eq!(replaced.unwrap().priority, 100);
+        assert_eq!(pool.size(), 1);
+    }
+
+    #[test]
+    fn test_limits_and_expiration() {
+        let config = TxPoolConfig {
+            max_size: Some(3),
+            max_per_sender: Some(2),
+            ttl: Some(10),
+        };
+        let mut pool = TxPool::new(&config);
+        let (alice, bob) = (RoochAddress::random(), RoochAddress::random());
+        pool.add(pooled_tx(alice, 0, 100, 0), 0).unwrap();
+        assert!(pool.add(pooled_tx(alice, 2, 100, 0), 0).is_err());
+        pool.add(pooled_tx(alice, 1, 100, 0), 0).unwrap();
+        pool.add(pooled_tx(bob, 0, 100, 5_000), 0).unwrap();
+        assert!(pool.add(pooled_tx(bob, 1, 100, 5_000), 0).is_err());
+
+        let expired = pool.remove_expired(10_000);
+        assert_eq!(expired.len(), 2);
+        assert_eq!(pool.size(), 1);
+        assert_eq!(pool.pending_transactions(Some(bob)).len(), 1);
+        assert!(pool.pending_transactions(Some(alice)).is_empty());
+    }
+
+    #[test]
+    fn test_failed_transaction_releases_sender() {
+        let mut pool = TxPool::new(&TxPoolConfig::default());
+        let (alice, bob) = (RoochAddress::random(), RoochAddress::random());
+        pool.add(pooled_tx(alice, 0, 100, 0), 0).unwrap();
+        pool.add(pooled_tx(alice, 1, 100, 0), 0).unwrap();
+        pool.add(pooled_tx(bob, 0, 50, 0), 0).unwrap();
+
+        let ptx = pool.pop_ready().unwrap();
+        assert_eq!(ptx.sender(), alice);
+        // the transaction being sequenced can not be submitted again
+        assert!(pool.add(pooled_tx(alice, 0, 200, 1), 0).is_err());
+
+        let dropped = pool.mark_failed(alice);
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(dropped[0].sequence_number(), 1);
+        assert_eq!(pool.size(), 1);
+        assert!(pool.pending_transactions(Some(alice)).is_empty());
+
+        // the sender can resubmit from the sequence number on chain
+        pool.add(pooled_tx(alice, 0, 200, 1), 0).unwrap();
+        assert_eq!(pool.pop_ready().unwrap().sender(), alice);
+        assert_eq!(pool.pop_ready().unwrap().sender(), bob);
+        assert!(pool.pop_ready().is_none());
+    }
+
+    #[test]
+    fn test_ready_index_follows_replacement_and_expiration() {
+        let config = TxPoolConfig {
+            max_size: None,
+            max_per_sender: None,
+            ttl: Some(10),
+        };
+        let mut pool = TxPool::new(&config);
+        let (alice, bob) = (RoochAddress::random(), RoochAddress::random());
+        pool.add(pooled_tx(alice, 0, 100, 0), 0).unwrap();
+        pool.add(pooled_tx(bob, 0, 200, 5_000), 0).unwrap();
+        // the replacement of the ready transaction updates its priority
+        pool.add(pooled_tx(alice, 0, 300, 5_000), 0).unwrap();
+        assert_eq!(pool.pop_ready().unwrap().priority, 300);
+        pool.mark_sequenced(alice, 0);
+
+        pool.add(pooled_tx(alice, 1, 500, 0), 1).unwrap();
+        let expired = pool.remove_expired(10_000);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(pool.pop_ready().unwrap().sender(), bob);
+        assert!(pool.pop_ready().is_none());
+    }
+
+    #[test]
+    fn test_rejected_transaction_not_queued() {
+        let config = TxPoolConfig {
+            max_size: None,
+            max_per_sender: Some(2),
+            ttl: None,
+        };
+        let mut pool = TxPool::new(&config);
+        let sender = RoochAddress::random();
+        assert!(pool.add(pooled_tx(sender, 5, 100, 0), 0).is_err());
+        assert!(pool.add(pooled_tx(sender, 0, 100, 0), 1).is_err());
+        assert!(!pool.senders.contains_key(&sender));
+        assert_eq!(pool.size(), 0);
+    }
+
+    #[test]
+    fn test_remove_stale_transactions() {
+        let mut pool = TxPool::new(&TxPoolConfig::default());
+        let (alice, bob) = (RoochAddress::random(), RoochAddress::random());
+        pool.add(pooled_tx(alice, 0, 100, 0), 0).unwrap();
+        pool.add(pooled_tx(alice, 1, 100, 0), 0).unwrap();
+        pool.add(pooled_tx(alice, 3, 100, 0), 0).unwrap();
+        pool.add(pooled_tx(bob, 0, 50, 0), 0).unwrap();
+        assert!(pool.remove_stale(alice, 0).is_empty());
+
+        // alice's account sequence number is advanced to 3 by other ways
+        let stale = pool.remove_stale(alice, 3);
+        assert_eq!(
+            stale
+                .iter()
+                .map(|ptx| ptx.sequence_number())
+                .collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        assert_eq!(pool.size(), 2);
+        let pending = pool.pending_transactions(Some(alice));
+        assert_eq!(pending.len(), 1);
+        assert!(pending[0].1);
+
+        let ptx = pool.pop_ready().unwrap();
+        assert_eq!((ptx.sender(), ptx.sequence_number()), (alice, 3));
+        pool.mark_sequenced(alice, 3);
+        assert_eq!(pool.pop_ready().unwrap().sender(), bob);
+        pool.mark_sequenced(bob, 0);
+        assert!(pool.pop_ready().is_none());
+        assert_eq!(pool.size(), 0);
+
+        pool.add(pooled_tx(bob, 2, 50, 0), 1).unwrap();
+        assert_eq!(pool.remove_stale(bob, 5).len(), 1);
+        assert!(pool.pending_transactions(Some(bob)).is_empty());
+        assert!(pool.pop_ready().is_none());
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::actor::messages::{
+    ExecuteTransactionReceiver, GetPendingTransactionsMessage, SubmitTransactionMessage,
+};
+use crate::actor::tx_pool::TxPoolActor;
+use crate::pool::PooledTransaction;
+use anyhow::Result;
+use coerce::actor::ActorRef;
+use rooch_types::address::RoochAddress;
+use rooch_types::transaction::{ExecuteTransactionResponse, RoochTransaction};
+
+#[derive(Clone)]
+pub struct TxPoolProxy {
+    pub actor: ActorRef<TxPoolActor>,
+}
+
+impl TxPoolProxy {
+    pub fn new(actor: ActorRef<TxPoolActor>) -> Self {
+        Self { actor }
+    }
+
+    /// Submit the transaction to the tx pool without waiting for the execution.
+    pub async fn submit_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionReceiver> {
+        self.actor.send(SubmitTransactionMessage { tx }).await?
+    }
+
+    /// Submit the transaction to the tx pool and wait for the execution result.
+    pub async fn execute_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponse> {
+        let receiver = self.submit_tx(tx).await?;
+        receiver.await.map_err(|_| {
+            anyhow::anyhow!("The tx pool dropped the transaction without execution result")
+        })?
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        sender: Option<RoochAddress>,
+    ) -> Result<Vec<(PooledTransaction, bool)>> {
+        self.actor
+            .send(GetPendingTransactionsMessage { sender })
+            .await?
+    }
+}
+
+impl From<ActorRef<TxPoolActor>> for TxPoolProxy {
+    fn from(actor: ActorRef<TxPoolActor>) -> Self {
+        Self::new(actor)
+    }
+}
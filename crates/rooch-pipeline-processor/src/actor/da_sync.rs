@@ -0,0 +1,172 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::messages::DASyncTick;
+use crate::actor::TxAnomalies;
+use crate::proxy::PipelineProcessorProxy;
+use anyhow::Result;
+use async_trait::async_trait;
+use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
+use rooch_da::backend::DABackends;
+use rooch_sequencer::proxy::SequencerProxy;
+use rooch_store::da_store::DAMetaStore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+use rooch_types::address::BitcoinAddress;
+use rooch_types::da::batch::DABatch;
+
+/// DASyncActor follows the DA stream in sync mode:
+/// fetches batches chunk by chunk from DA backends, verifies them, and replays the transactions in tx_order.
+///
+/// Each batch is verified by:
+/// 1. meta signature must be signed by the sequencer in genesis config
+/// 2. tx_list must match the tx_list_hash in meta, and tx_order must be continuous
+/// 3. tx accumulator root of each transaction must be the same as the one after local replay
+/// 4. state root after local replay must be the one signed by the sequencer with the meta (ChunkV1 only)
+pub struct DASyncActor {
+    backends: DABackends,
+    sequencer_address: BitcoinAddress,
+    rooch_store: RoochStore,
+    moveos_store: MoveOSStore,
+    sequencer: SequencerProxy,
+    processor: PipelineProcessorProxy,
+    tx_anomalies: Option<TxAnomalies>,
+}
+
+impl DASyncActor {
+    pub fn new(
+        backends: DABackends,
+        sequencer_address: BitcoinAddress,
+        rooch_store: RoochStore,
+        moveos_store: MoveOSStore,
+        sequencer: SequencerProxy,
+        processor: PipelineProcessorProxy,
+        tx_anomalies: Option<TxAnomalies>,
+    ) -> Self {
+        Self {
+            backends,
+            sequencer_address,
+            rooch_store,
+            moveos_store,
+            sequencer,
+            processor,
+            tx_anomalies,
+        }
+    }
+
+    /// Syncs chunks until the next chunk is not found in DA backends, returns the number of synced chunks.
+    pub async fn sync(&mut self) -> Result<usize> {
+        let mut synced = 0;
+        loop {
+            let next_block_number = match self.rooch_store.get_last_block_number()? {
+                Some(last_block_number) => last_block_number + 1,
+                None => 0,
+            };
+            let batches = match self.backends.get_batches(next_block_number).await? {
+                Some(batches) => batches,
+                None => return Ok(synced),
+            };
+            for batch in batches {
+                self.sync_batch(next_block_number, batch).await?;
+            }
+            synced += 1;
+        }
+    }
+
+    async fn sync_batch(&mut self, block_number: u128, batch: DABatch) -> Result<()> {
+        let block_range = batch.meta.block_range.clone();
+        if block_range.block_number != block_number {
+            return Err(anyhow::anyhow!(
+                "block number mismatch in chunk: exp: {}, act: {}",
+                block_number,
+                block_range.block_number
+            ));
+        }
+        batch.verify_sequencer(&self.sequencer_address)?;
+
+        // txs may be partially replayed before restarting, skip them
+        let last_order = self.sequencer.get_sequencer_order().await?;
+        for mut tx in batch.get_tx_list()? {
+            if tx.sequence_info.tx_order <= last_order {
+                continue;
+            }
+            let tx_hash = tx.tx_hash();
+            let (verify_accumulator, bypass_execution) = match &self.tx_anomalies {
+                Some(tx_anomalies) => (
+                    !tx_anomalies.is_dup_hash(&tx_hash),
+                    tx_anomalies.is_no_execution_info(&tx_hash),
+                ),
+                None => (true, false),
+            };
+            self.processor
+                .replay_ledger_tx(tx, verify_accumulator, bypass_execution)
+                .await?;
+        }
+        self.verify_state_root(&batch)?;
+
+        self.rooch_store
+            .append_submitting_block(block_range.tx_order_start, block_range.tx_order_end)?;
+        self.rooch_store.set_submitting_block_done(
+            block_number,
+            block_range.tx_order_start,
+            block_range.tx_order_end,
+            batch.get_hash(),
+        )?;
+        tracing::info!(
+            "[DASync] block: {} synced, tx_order: [{}, {}]",
+            block_number,
+            block_range.tx_order_start,
+            block_range.tx_order_end
+        );
+        Ok(())
+    }
+
+    // the block is not marked as synced if the state root mismatches, so the sync stops at this block
+    fn verify_state_root(&self, batch: &DABatch) -> Result<()> {
+        let block_range = &batch.meta.block_range;
+        let tx_hash = self
+            .rooch_store
+            .get_tx_hashes(vec![block_range.tx_order_end])?
+            .pop()
+            .flatten()
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "tx hash of tx_order {} not found after replay",
+                    block_range.tx_order_end
+                )
+            })?;
+        let execution_info = match self.moveos_store.get_tx_execution_info(tx_hash)? {
+            Some(execution_info) => execution_info,
+            None => {
+                // the known anomalies without execution info
+                tracing::warn!(
+                    "[DASync] block: {} state root is not verified, no execution info of tx_order: {}",
+                    block_range.block_number,
+                    block_range.tx_order_end
+                );
+                return Ok(());
+            }
+        };
+        if !batch.verify_state_root(&self.sequencer_address, execution_info.state_root)? {
+            tracing::debug!(
+                "[DASync] block: {} state root is not verified, no signed state root in the chunk",
+                block_range.block_number
+            );
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Actor for DASyncActor {}
+
+#[async_trait]
+impl Handler<DASyncTick> for DASyncActor {
+    async fn handle(&mut self, _message: DASyncTick, _ctx: &mut ActorContext) {
+        if let Err(e) = self.sync().await {
+            tracing::error!("[DASync] sync from DA error: {:?}", e);
+        }
+    }
+}
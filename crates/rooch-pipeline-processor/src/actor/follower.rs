@@ -0,0 +1,75 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::messages::FollowerSyncTick;
+use crate::proxy::PipelineProcessorProxy;
+use anyhow::Result;
+use async_trait::async_trait;
+use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use rooch_rpc_client::Client;
+use rooch_sequencer::proxy::SequencerProxy;
+
+const FOLLOWER_SYNC_BATCH_SIZE: u64 = 100;
+
+/// FollowerActor follows the leader node in follower mode:
+/// pulls the committed transactions from the leader after the local last tx_order,
+/// and applies their store changes in tx_order without re-executing.
+///
+/// Each transaction is verified by:
+/// 1. tx accumulator root must be the same as the one after local append
+/// 2. state root, global size and event root must be the same as the leader's execution info after local apply
+pub struct FollowerActor {
+    leader: Client,
+    sequencer: SequencerProxy,
+    processor: PipelineProcessorProxy,
+}
+
+impl FollowerActor {
+    pub fn new(
+        leader: Client,
+        sequencer: SequencerProxy,
+        processor: PipelineProcessorProxy,
+    ) -> Self {
+        Self {
+            leader,
+            sequencer,
+            processor,
+        }
+    }
+
+    /// Syncs until the leader has no more committed transactions, returns the number of synced transactions.
+    pub async fn sync(&mut self) -> Result<usize> {
+        let mut synced = 0;
+        loop {
+            let last_order = self.sequencer.get_sequencer_order().await?;
+            let committed_txs = self
+                .leader
+                .rooch
+                .get_committed_transactions(Some(last_order), Some(FOLLOWER_SYNC_BATCH_SIZE))
+                .await?;
+            if committed_txs.is_empty() {
+                return Ok(synced);
+            }
+            for committed_tx in committed_txs {
+                self.processor.apply_committed_tx(committed_tx).await?;
+                synced += 1;
+            }
+            tracing::debug!(
+                "[Follower] synced to tx_order: {}",
+                self.sequencer.get_sequencer_order().await?
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl Actor for FollowerActor {}
+
+#[async_trait]
+impl Handler<FollowerSyncTick> for FollowerActor {
+    async fn handle(&mut self, _message: FollowerSyncTick, _ctx: &mut ActorContext) {
+        if let Err(e) = self.sync().await {
+            tracing::error!("[Follower] sync from leader error: {:?}", e);
+        }
+    }
+}
@@ -2,11 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::Result;
-use coerce::actor::message::Message;
+use coerce::actor::{message::Message, scheduler::timer::TimerTick};
 use rooch_types::{
     service_status::ServiceStatus,
-    transaction::{ExecuteTransactionResponse, L1BlockWithBody, L1Transaction, RoochTransaction},
+    transaction::{
+        CommittedTransaction, ExecuteTransactionResponse, L1BlockWithBody, L1Transaction,
+        LedgerTransaction, RoochTransaction,
+    },
 };
+use std::path::PathBuf;
 
 #[derive(Clone)]
 pub struct ExecuteL2TxMessage {
@@ -17,6 +21,16 @@ impl Message for ExecuteL2TxMessage {
     type Result = Result<ExecuteTransactionResponse>;
 }
 
+/// Execute a batch of L2 transactions in parallel, the results are in the same order as the transactions.
+#[derive(Clone)]
+pub struct ExecuteL2TxBatchMessage {
+    pub txs: Vec<RoochTransaction>,
+}
+
+impl Message for ExecuteL2TxBatchMessage {
+    type Result = Vec<Result<ExecuteTransactionResponse>>;
+}
+
 #[derive(Clone)]
 pub struct ExecuteL1BlockMessage {
     pub tx: L1BlockWithBody,
@@ -35,9 +49,65 @@ impl Message for ExecuteL1TxMessage {
     type Result = Result<ExecuteTransactionResponse>;
 }
 
+/// Replay a LedgerTransaction sequenced by the sequencer, used in sync mode
+#[derive(Clone)]
+pub struct ReplayLedgerTxMessage {
+    pub tx: LedgerTransaction,
+    pub verify_accumulator: bool,
+    /// Only append the tx to ledger without execution, for known anomalies
+    pub bypass_execution: bool,
+}
+
+impl Message for ReplayLedgerTxMessage {
+    type Result = Result<Option<ExecuteTransactionResponse>>;
+}
+
+/// Apply a transaction committed by the leader node, used in read-only (follower) mode
+#[derive(Clone)]
+pub struct ApplyCommittedTxMessage {
+    pub tx: CommittedTransaction,
+}
+
+impl Message for ApplyCommittedTxMessage {
+    type Result = Result<()>;
+}
+
 #[derive(Clone)]
 pub struct GetServiceStatusMessage {}
 
 impl Message for GetServiceStatusMessage {
     type Result = Result<ServiceStatus>;
 }
+
+/// Take the RocksDB checkpoint and the indexer backup between the tx executions, so the backup
+/// never contains a half processed tx, and the indexer is at the same tx as the store.
+/// Returns the backed up indexer tables.
+#[derive(Clone)]
+pub struct BackupDBMessage {
+    pub backup_dir: PathBuf,
+    pub indexer_backup_dir: PathBuf,
+}
+
+impl Message for BackupDBMessage {
+    type Result = Result<Vec<String>>;
+}
+
+/// Timer tick to sync batches from DA, used in sync mode
+#[derive(Clone)]
+pub struct DASyncTick {}
+
+impl Message for DASyncTick {
+    type Result = ();
+}
+
+impl TimerTick for DASyncTick {}
+
+/// Timer tick to pull the committed transactions from the leader node, used in follower mode
+#[derive(Clone)]
+pub struct FollowerSyncTick {}
+
+impl Message for FollowerSyncTick {
+    type Result = ();
+}
+
+impl TimerTick for FollowerSyncTick {}
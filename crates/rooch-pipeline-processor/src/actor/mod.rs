@@ -9,6 +9,8 @@ use std::fs::File;
 use std::io::Write;
 use std::path::Path;
 
+pub mod da_sync;
+pub mod follower;
 pub mod messages;
 pub mod processor;
 
@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::messages::{
-    ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, GetServiceStatusMessage,
+    ApplyCommittedTxMessage, BackupDBMessage, ExecuteL1BlockMessage, ExecuteL1TxMessage,
+    ExecuteL2TxBatchMessage, ExecuteL2TxMessage, GetServiceStatusMessage, ReplayLedgerTxMessage,
 };
 use crate::metrics::PipelineProcessorMetrics;
 use anyhow::{Error, Result};
@@ -13,13 +14,17 @@ use coerce::actor::{context::ActorContext, message::Handler, Actor, LocalActorRe
 use function_name::named;
 use moveos::moveos::VMPanicError;
 use moveos_types::h256::H256;
+use moveos_types::moveos_std::module_store::ModuleStore;
 use moveos_types::state::StateChangeSetExt;
-use moveos_types::transaction::VerifiedMoveOSTransaction;
+use moveos_types::transaction::{
+    TransactionExecutionInfo, TransactionOutput, VerifiedMoveOSTransaction,
+};
 use prometheus::Registry;
 use rooch_da::actor::messages::{AppendTransactionMessage, RevertTransactionMessage};
 use rooch_da::proxy::DAServerProxy;
 use rooch_db::RoochDB;
 use rooch_event::actor::{EventActor, UpdateServiceStatusMessage};
+use rooch_executor::actor::messages::ExecuteTransactionBatchResult;
 use rooch_executor::proxy::ExecutorProxy;
 use rooch_indexer::proxy::IndexerProxy;
 use rooch_sequencer::proxy::SequencerProxy;
@@ -27,8 +32,8 @@ use rooch_types::bitcoin::types::Block as BitcoinBlock;
 use rooch_types::{
     service_status::ServiceStatus,
     transaction::{
-        ExecuteTransactionResponse, L1BlockWithBody, L1Transaction, LedgerTransaction,
-        LedgerTxData, RoochTransaction,
+        CommittedTransaction, ExecuteTransactionResponse, L1Block, L1BlockWithBody, L1Transaction,
+        LedgerTransaction, LedgerTxData, RoochTransaction,
     },
 };
 use std::io;
@@ -120,25 +125,8 @@ impl PipelineProcessorActor {
             match &ledger_tx.data {
                 LedgerTxData::L1Block(block) => {
                     debug!("process_sequenced_tx_on_startup l1_block_tx: {:?}", block);
-                    match &self.bitcoin_client_proxy {
-                        Some(bitcoin_client_proxy) => {
-                            let block_hash_vec = block.block_hash.clone();
-                            let block_hash =
-                                bitcoin::block::BlockHash::from_slice(&block_hash_vec)?;
-                            let btc_block = bitcoin_client_proxy.get_block(block_hash).await?;
-                            let block_body = BitcoinBlock::from(btc_block);
-                            self.execute_l1_block(L1BlockWithBody::new(
-                                block.clone(),
-                                block_body.encode(),
-                            ))
-                            .await?;
-                        }
-                        None => {
-                            return Err(anyhow::anyhow!(
-                                "The bitcoin client proxy should be initialized before processing the sequenced l1_block_tx(block: {:?}) on startup", block
-                            ));
-                        }
-                    }
+                    let l1_block_with_body = self.get_l1_block_with_body(block).await?;
+                    self.execute_l1_block(l1_block_with_body).await?;
                 }
                 LedgerTxData::L1Tx(l1_tx) => {
                     debug!("process_sequenced_tx_on_startup l1_tx: {:?}", l1_tx);
@@ -163,11 +151,189 @@ impl PipelineProcessorActor {
         Ok(())
     }
 
+    async fn get_l1_block_with_body(&self, block: &L1Block) -> Result<L1BlockWithBody> {
+        match &self.bitcoin_client_proxy {
+            Some(bitcoin_client_proxy) => {
+                let block_hash_vec = block.block_hash.clone();
+                let block_hash = bitcoin::block::BlockHash::from_slice(&block_hash_vec)?;
+                let btc_block = bitcoin_client_proxy.get_block(block_hash).await?;
+                let block_body = BitcoinBlock::from(btc_block);
+                Ok(L1BlockWithBody::new(block.clone(), block_body.encode()))
+            }
+            None => Err(anyhow::anyhow!(
+                "The bitcoin client proxy should be initialized before processing the sequenced l1_block_tx(block: {:?})", block
+            )),
+        }
+    }
+
+    /// Replay a LedgerTransaction sequenced by the sequencer (e.g., from DA) in sync mode:
+    /// validate it, append it to local ledger by the sequencer, then execute it.
+    #[named]
+    pub async fn replay_ledger_tx(
+        &mut self,
+        mut ledger_tx: LedgerTransaction,
+        verify_accumulator: bool,
+        bypass_execution: bool,
+    ) -> Result<Option<ExecuteTransactionResponse>> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .pipeline_processor_execution_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+
+        let tx_order = ledger_tx.sequence_info.tx_order;
+        let tx_hash = ledger_tx.tx_hash();
+        if bypass_execution {
+            self.sequencer
+                .replay_transaction(ledger_tx, verify_accumulator)
+                .await?;
+            return Ok(None);
+        }
+
+        let (moveos_tx, tx_type) = match &ledger_tx.data {
+            LedgerTxData::L1Block(block) => {
+                let l1_block_with_body = self.get_l1_block_with_body(block).await?;
+                (
+                    self.executor.validate_l1_block(l1_block_with_body).await?,
+                    "L1Block",
+                )
+            }
+            LedgerTxData::L1Tx(l1_tx) => {
+                (self.executor.validate_l1_tx(l1_tx.clone()).await?, "L1Tx")
+            }
+            LedgerTxData::L2Tx(l2_tx) => {
+                (self.executor.validate_l2_tx(l2_tx.clone()).await?, "L2Tx")
+            }
+        };
+        self.sequencer
+            .replay_transaction(ledger_tx.clone(), verify_accumulator)
+            .await?;
+
+        match self.execute_tx(ledger_tx, moveos_tx).await {
+            Ok(v) => Ok(Some(v)),
+            Err(err) => {
+                tracing::error!(
+                    "Replay {} failed, tx_order: {}, tx_hash: {:?}, error: {:?}",
+                    tx_type,
+                    tx_order,
+                    tx_hash,
+                    err
+                );
+                // revert the replayed tx, it will be replayed again in the next round
+                if let Err(e) = self.rooch_db.revert_tx(tx_hash) {
+                    tracing::error!(
+                        "Revert replayed tx failed, set service to Maintenance mode. error: {:?}",
+                        e,
+                    );
+                    self.update_service_status(ServiceStatus::Maintenance).await;
+                }
+                Err(err)
+            }
+        }
+    }
+
+    /// Apply a transaction committed by the leader node in read-only (follower) mode:
+    /// append it to local ledger by the sequencer, then apply its store changes without re-executing.
+    #[named]
+    pub async fn apply_committed_tx(&mut self, committed_tx: CommittedTransaction) -> Result<()> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .pipeline_processor_execution_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+
+        let CommittedTransaction {
+            transaction: mut ledger_tx,
+            execution_info,
+            state_change_set,
+            events,
+            indexer_transaction,
+        } = committed_tx;
+        let tx_order = ledger_tx.sequence_info.tx_order;
+        let tx_hash = ledger_tx.tx_hash();
+        self.sequencer
+            .replay_transaction(ledger_tx.clone(), true)
+            .await?;
+
+        let is_upgrade = state_change_set
+            .state_change_set
+            .changes
+            .values()
+            .any(|change| change.metadata.id == ModuleStore::object_id());
+        let changeset = match self
+            .executor
+            .apply_change_set(
+                execution_info.clone(),
+                state_change_set.state_change_set,
+                events.clone(),
+            )
+            .await
+        {
+            Ok(changeset) => changeset,
+            Err(err) => {
+                tracing::error!(
+                    "Apply committed tx failed, tx_order: {}, tx_hash: {:?}, error: {:?}",
+                    tx_order,
+                    tx_hash,
+                    err
+                );
+                // revert the applied tx, it will be pulled from the leader again in the next round
+                if let Err(e) = self.rooch_db.revert_tx(tx_hash) {
+                    tracing::error!(
+                        "Revert applied tx failed, set service to Maintenance mode. error: {:?}",
+                        e,
+                    );
+                    self.update_service_status(ServiceStatus::Maintenance).await;
+                }
+                return Err(err);
+            }
+        };
+
+        // Sync the latest state root from writer executor to reader executor
+        self.executor
+            .refresh_state(execution_info.root_metadata(), is_upgrade)
+            .await?;
+        let state_change_set_ext =
+            StateChangeSetExt::new(changeset.clone(), state_change_set.sequence_number);
+        self.executor
+            .save_state_change_set(tx_order, state_change_set_ext)
+            .await?;
+
+        //The index_committed_transaction is a notification call, do not block the current task
+        let result = self
+            .indexer
+            .index_committed_transaction(
+                ledger_tx,
+                execution_info,
+                indexer_transaction,
+                events,
+                changeset,
+            )
+            .await;
+        if let Err(error) = result {
+            tracing::error!("Update indexer error: {}", error);
+        }
+        Ok(())
+    }
+
     // sequence tx and public tx to DA
     async fn sequence_and_public_tx(&mut self, tx_data: LedgerTxData) -> Result<LedgerTransaction> {
+        let ledger_tx = self.sequence_tx(tx_data).await?;
+        match self.public_tx(&ledger_tx).await {
+            Ok(_) => Ok(ledger_tx),
+            Err(err) => {
+                self.rooch_db.revert_tx(ledger_tx.tx_hash())?;
+                Err(err)
+            }
+        }
+    }
+
+    async fn sequence_tx(&mut self, tx_data: LedgerTxData) -> Result<LedgerTransaction> {
         let ledger_tx_ret = self.sequencer.sequence_transaction(tx_data).await;
-        let mut ledger_tx = match ledger_tx_ret {
-            Ok(v) => v,
+        match ledger_tx_ret {
+            Ok(v) => Ok(v),
             Err(err) => {
                 if let Some(_io_err) = err.downcast_ref::<io::Error>() {
                     tracing::error!(
@@ -177,37 +343,52 @@ impl PipelineProcessorActor {
                     );
                     self.update_service_status(ServiceStatus::Maintenance).await;
                 }
-                return Err(err);
+                Err(err)
             }
-        };
+        }
+    }
 
+    // public tx to DA, revert it for DA if failed
+    async fn public_tx(&mut self, ledger_tx: &LedgerTransaction) -> Result<()> {
         let tx_order = ledger_tx.sequence_info.tx_order;
         let public_ret = self
             .da_server
             .append_tx(AppendTransactionMessage {
-                tx_order: ledger_tx.sequence_info.tx_order,
+                tx_order,
                 tx_timestamp: ledger_tx.sequence_info.tx_timestamp,
             })
             .await;
-        match public_ret {
-            Ok(_) => Ok(ledger_tx),
-            Err(err) => {
-                tracing::error!(
-                    "Public transaction(tx_order: {}) failed, revert tx soon. error: {:?}",
-                    tx_order,
-                    err
-                );
-                // try to revert this tx for DA, error may cause by network or other reasons
-                let ret = self
-                    .da_server
-                    .revert_tx(RevertTransactionMessage { tx_order })
-                    .await; // if revert public failed, only pause runtime DA state, easy to monitor and restart service will fix it
-                if let Err(e) = ret {
-                    tracing::error!("Revert public tx failed, error: {:?}", e);
-                }
-                self.rooch_db.revert_tx(ledger_tx.tx_hash())?;
-                Err(err)
+        if let Err(err) = public_ret {
+            tracing::error!(
+                "Public transaction(tx_order: {}) failed, revert tx soon. error: {:?}",
+                tx_order,
+                err
+            );
+            // try to revert this tx for DA, error may cause by network or other reasons
+            let ret = self
+                .da_server
+                .revert_tx(RevertTransactionMessage { tx_order })
+                .await; // if revert public failed, only pause runtime DA state, easy to monitor and restart service will fix it
+            if let Err(e) = ret {
+                tracing::error!("Revert public tx failed, error: {:?}", e);
             }
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    // revert the sequenced but not executed txs of a batch in one step,
+    // they are not public to DA yet.
+    async fn revert_unexecuted_txs(&mut self, txs: Vec<(u64, H256)>) {
+        if txs.is_empty() {
+            return;
+        }
+        if let Err(e) = self.rooch_db.revert_unexecuted_txs(txs) {
+            tracing::error!(
+                "Revert unexecuted txs failed, set service to Maintenance mode and pause the relayer. error: {:?}",
+                e,
+            );
+            self.update_service_status(ServiceStatus::Maintenance).await;
         }
     }
 
@@ -414,7 +595,26 @@ impl PipelineProcessorActor {
         // Then execute
         let size = moveos_tx.ctx.tx_size;
         let (output, execution_info) = self.executor.execute_transaction(moveos_tx.clone()).await?;
+        let response = self
+            .handle_executed_tx(tx, moveos_tx, output, execution_info)
+            .await?;
+
+        self.metrics
+            .pipeline_processor_execution_tx_bytes
+            .with_label_values(&[fn_name])
+            .observe(size as f64);
+
+        Ok(response)
+    }
 
+    /// Sync the state of the executed transaction to the reader executor, the state change set store and the indexer.
+    async fn handle_executed_tx(
+        &mut self,
+        tx: LedgerTransaction,
+        moveos_tx: VerifiedMoveOSTransaction,
+        output: TransactionOutput,
+        execution_info: TransactionExecutionInfo,
+    ) -> Result<ExecuteTransactionResponse> {
         let root = execution_info.root_metadata();
         // Sync the latest state root from writer executor to reader executor
         self.executor
@@ -448,11 +648,6 @@ impl PipelineProcessorActor {
             };
         };
 
-        self.metrics
-            .pipeline_processor_execution_tx_bytes
-            .with_label_values(&[fn_name])
-            .observe(size as f64);
-
         Ok(ExecuteTransactionResponse {
             sequence_info,
             execution_info,
@@ -460,6 +655,150 @@ impl PipelineProcessorActor {
         })
     }
 
+    /// Execute a batch of L2 transactions, the transactions are sequenced in order,
+    /// then executed in parallel by the executor. The results are in the same order as the transactions.
+    ///
+    /// The batch should not contain more than one transaction of the same sender.
+    /// The transactions are validated before they are sequenced, and validated again by the executor
+    /// on top of the state committed by the previous transactions of the batch.
+    /// A transaction is public to DA only after it is executed. If a transaction fails to execute,
+    /// it and the transactions sequenced after it are reverted in one step.
+    #[named]
+    pub async fn execute_l2_tx_batch(
+        &mut self,
+        txs: Vec<RoochTransaction>,
+    ) -> Vec<Result<ExecuteTransactionResponse>> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .pipeline_processor_execution_tx_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+
+        let mut results = txs.iter().map(|_| None).collect::<Vec<_>>();
+        let mut sequenced = Vec::with_capacity(txs.len());
+        for (index, tx) in txs.into_iter().enumerate() {
+            let tx_hash = tx.tx_hash();
+            let mut moveos_tx = match self.executor.validate_l2_tx(tx.clone()).await {
+                Ok(moveos_tx) => moveos_tx,
+                Err(err) => {
+                    results[index] = Some(Err(err));
+                    continue;
+                }
+            };
+            let ledger_tx = match self.sequence_tx(LedgerTxData::L2Tx(tx.clone())).await {
+                Ok(ledger_tx) => ledger_tx,
+                Err(err) => {
+                    results[index] = Some(Err(err));
+                    continue;
+                }
+            };
+            // Add sequence info to tx context, let the Move contract can get the sequence info
+            if let Err(err) = moveos_tx.ctx.add(ledger_tx.sequence_info.clone()) {
+                self.revert_unexecuted_txs(vec![(ledger_tx.sequence_info.tx_order, tx_hash)])
+                    .await;
+                results[index] = Some(Err(err));
+                continue;
+            }
+            sequenced.push((index, tx_hash, tx, ledger_tx, moveos_tx));
+        }
+
+        let (moveos_txs, l2_txs): (Vec<_>, Vec<_>) = sequenced
+            .iter()
+            .map(|(_, _, tx, _, moveos_tx)| (moveos_tx.clone(), tx.clone()))
+            .unzip();
+        let batch_result = match self
+            .executor
+            .execute_l2_transaction_batch(moveos_txs, l2_txs)
+            .await
+        {
+            Ok(batch_result) => batch_result,
+            Err(err) => ExecuteTransactionBatchResult {
+                error: Some(err),
+                ..Default::default()
+            },
+        };
+        let ExecuteTransactionBatchResult {
+            results: executed,
+            error,
+            ..
+        } = batch_result;
+        let mut sequenced = sequenced.into_iter();
+        for (result, (index, _, _, ledger_tx, moveos_tx)) in
+            executed.into_iter().zip(&mut sequenced)
+        {
+            if let Err(err) = self.public_tx(&ledger_tx).await {
+                // the tx has been executed, it can not be reverted without reverting the state
+                tracing::error!(
+                    "Public executed transaction(tx_order: {}) failed, set service to Maintenance mode and pause the relayer. error: {:?}",
+                    ledger_tx.sequence_info.tx_order,
+                    err
+                );
+                self.update_service_status(ServiceStatus::Maintenance).await;
+            }
+            let size = moveos_tx.ctx.tx_size;
+            let gas_used = result.output.gas_used;
+            results[index] = Some(
+                self.handle_executed_tx(
+                    ledger_tx,
+                    moveos_tx,
+                    result.output,
+                    result.transaction_info,
+                )
+                .await,
+            );
+            self.metrics
+                .pipeline_processor_l2_tx_gas_used
+                .inc_by(gas_used);
+            self.metrics
+                .pipeline_processor_execution_tx_bytes
+                .with_label_values(&[fn_name])
+                .observe(size as f64);
+        }
+
+        // Revert the failed transaction and the transactions sequenced after it,
+        // the sequencer is rolled back to the last executed transaction.
+        let failed = sequenced.collect::<Vec<_>>();
+        if let Some((_, failed_tx_hash, failed_tx, failed_ledger_tx, _)) = failed.first() {
+            let failed_tx_hash = *failed_tx_hash;
+            let error =
+                error.unwrap_or_else(|| anyhow::anyhow!("The transaction batch is not executed"));
+            let l2_tx_bcs_bytes = bcs::to_bytes(failed_tx).unwrap_or_default();
+            tracing::error!(
+                "Execute L2Tx in batch failed, revert it and the {} txs after it. error: {:?}, tx_order: {}, tx_hash: {:?}, tx_info: {}",
+                failed.len() - 1,
+                error,
+                failed_ledger_tx.sequence_info.tx_order,
+                failed_tx_hash,
+                hex::encode(l2_tx_bcs_bytes)
+            );
+            let reverted = failed
+                .iter()
+                .map(|(_, tx_hash, _, ledger_tx, _)| (ledger_tx.sequence_info.tx_order, *tx_hash))
+                .collect::<Vec<_>>();
+            self.revert_unexecuted_txs(reverted).await;
+
+            let error_message = format!("{:?}", error);
+            let mut error = Some(error);
+            for (index, ..) in failed {
+                let err = match error.take() {
+                    Some(err) => err,
+                    None => anyhow::anyhow!(
+                        "Transaction is not executed because the transaction {:?} before it in the batch failed: {}",
+                        failed_tx_hash,
+                        error_message
+                    ),
+                };
+                results[index] = Some(Err(err));
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|result| result.expect("every transaction of the batch should have a result"))
+            .collect()
+    }
+
     async fn update_service_status(&mut self, status: ServiceStatus) {
         self.service_status = status;
         if let Some(event_actor) = self.event_actor.clone() {
@@ -484,6 +823,17 @@ impl Handler<ExecuteL2TxMessage> for PipelineProcessorActor {
     }
 }
 
+#[async_trait]
+impl Handler<ExecuteL2TxBatchMessage> for PipelineProcessorActor {
+    async fn handle(
+        &mut self,
+        msg: ExecuteL2TxBatchMessage,
+        _ctx: &mut ActorContext,
+    ) -> Vec<Result<ExecuteTransactionResponse>> {
+        self.execute_l2_tx_batch(msg.txs).await
+    }
+}
+
 #[async_trait]
 impl Handler<ExecuteL1BlockMessage> for PipelineProcessorActor {
     async fn handle(
@@ -506,6 +856,29 @@ impl Handler<ExecuteL1TxMessage> for PipelineProcessorActor {
     }
 }
 
+#[async_trait]
+impl Handler<ReplayLedgerTxMessage> for PipelineProcessorActor {
+    async fn handle(
+        &mut self,
+        msg: ReplayLedgerTxMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<ExecuteTransactionResponse>> {
+        self.replay_ledger_tx(msg.tx, msg.verify_accumulator, msg.bypass_execution)
+            .await
+    }
+}
+
+#[async_trait]
+impl Handler<ApplyCommittedTxMessage> for PipelineProcessorActor {
+    async fn handle(
+        &mut self,
+        msg: ApplyCommittedTxMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        self.apply_committed_tx(msg.tx).await
+    }
+}
+
 #[async_trait]
 impl Handler<GetServiceStatusMessage> for PipelineProcessorActor {
     async fn handle(
@@ -517,6 +890,24 @@ impl Handler<GetServiceStatusMessage> for PipelineProcessorActor {
     }
 }
 
+#[async_trait]
+impl Handler<BackupDBMessage> for PipelineProcessorActor {
+    async fn handle(
+        &mut self,
+        msg: BackupDBMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Vec<String>> {
+        self.rooch_db.checkpoint_store(&msg.backup_dir)?;
+        info!("Checkpoint RoochDB store to {:?}", msg.backup_dir);
+        // The indexer is updated by notifications in the order of the executed txs, the backup
+        // message is handled after them. No tx is executed until the backup is done, so the
+        // indexer backup is neither ahead of nor behind the store checkpoint.
+        let indexer_tables = self.indexer.backup(msg.indexer_backup_dir.clone()).await?;
+        info!("Backup RoochDB indexer to {:?}", msg.indexer_backup_dir);
+        Ok(indexer_tables)
+    }
+}
+
 pub fn is_vm_panic_error(error: &Error) -> bool {
     if let Some(vm_error) = error.downcast_ref::<VMPanicError>() {
         match vm_error {
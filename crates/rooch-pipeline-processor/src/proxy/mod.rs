@@ -3,7 +3,9 @@
 
 use crate::actor::{
     messages::{
-        ExecuteL1BlockMessage, ExecuteL1TxMessage, ExecuteL2TxMessage, GetServiceStatusMessage,
+        ApplyCommittedTxMessage, BackupDBMessage, ExecuteL1BlockMessage, ExecuteL1TxMessage,
+        ExecuteL2TxBatchMessage, ExecuteL2TxMessage, GetServiceStatusMessage,
+        ReplayLedgerTxMessage,
     },
     processor::PipelineProcessorActor,
 };
@@ -12,9 +14,11 @@ use coerce::actor::ActorRef;
 use rooch_types::{
     service_status::ServiceStatus,
     transaction::{
-        rooch::RoochTransaction, ExecuteTransactionResponse, L1BlockWithBody, L1Transaction,
+        rooch::RoochTransaction, CommittedTransaction, ExecuteTransactionResponse, L1BlockWithBody,
+        L1Transaction, LedgerTransaction,
     },
 };
+use std::path::PathBuf;
 
 #[derive(Clone)]
 pub struct PipelineProcessorProxy {
@@ -30,6 +34,13 @@ impl PipelineProcessorProxy {
         self.actor.send(ExecuteL2TxMessage { tx }).await?
     }
 
+    pub async fn execute_l2_tx_batch(
+        &self,
+        txs: Vec<RoochTransaction>,
+    ) -> Result<Vec<Result<ExecuteTransactionResponse>>> {
+        Ok(self.actor.send(ExecuteL2TxBatchMessage { txs }).await?)
+    }
+
     pub async fn execute_l1_block(
         &self,
         tx: L1BlockWithBody,
@@ -41,9 +52,41 @@ impl PipelineProcessorProxy {
         self.actor.send(ExecuteL1TxMessage { tx }).await?
     }
 
+    pub async fn replay_ledger_tx(
+        &self,
+        tx: LedgerTransaction,
+        verify_accumulator: bool,
+        bypass_execution: bool,
+    ) -> Result<Option<ExecuteTransactionResponse>> {
+        self.actor
+            .send(ReplayLedgerTxMessage {
+                tx,
+                verify_accumulator,
+                bypass_execution,
+            })
+            .await?
+    }
+
+    pub async fn apply_committed_tx(&self, tx: CommittedTransaction) -> Result<()> {
+        self.actor.send(ApplyCommittedTxMessage { tx }).await?
+    }
+
     pub async fn get_service_status(&self) -> Result<ServiceStatus> {
         self.actor.send(GetServiceStatusMessage {}).await?
     }
+
+    pub async fn backup_db(
+        &self,
+        backup_dir: PathBuf,
+        indexer_backup_dir: PathBuf,
+    ) -> Result<Vec<String>> {
+        self.actor
+            .send(BackupDBMessage {
+                backup_dir,
+                indexer_backup_dir,
+            })
+            .await?
+    }
 }
 
 impl From<ActorRef<PipelineProcessorActor>> for PipelineProcessorProxy {
@@ -3,12 +3,15 @@
 
 use super::messages::ProposeBlock;
 use crate::metrics::ProposerMetrics;
+use crate::scc::committer::{check_proposer, BlockCommitter, ChainBlockCommitter};
 use crate::scc::StateCommitmentChain;
 use async_trait::async_trait;
 use coerce::actor::{context::ActorContext, message::Handler, Actor};
 use moveos_store::MoveOSStore;
 use prometheus::Registry;
 use rooch_config::proposer_config::ProposerConfig;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
 use rooch_store::proposer_store::ProposerStore;
 use rooch_store::RoochStore;
 use rooch_types::crypto::RoochKeyPair;
@@ -17,9 +20,10 @@ use std::sync::Arc;
 const PROPOSE_BLOCK_FN_NAME: &str = "propose_block";
 
 pub struct ProposerActor {
-    proposer_key: RoochKeyPair,
     scc: StateCommitmentChain,
     metrics: Arc<ProposerMetrics>,
+    // commit the proposed blocks to the on-chain SCC if submit_to_chain is enabled
+    committer: Option<Box<dyn BlockCommitter>>,
 }
 
 impl ProposerActor {
@@ -29,6 +33,8 @@ impl ProposerActor {
         rooch_store: RoochStore,
         registry: &Registry,
         config: ProposerConfig,
+        executor: ExecutorProxy,
+        processor: PipelineProcessorProxy,
     ) -> anyhow::Result<Self> {
         let init_offset = config.init_offset;
         let last_proposed = rooch_store.get_last_proposed()?;
@@ -44,13 +50,32 @@ impl ProposerActor {
         };
 
         let scc = StateCommitmentChain::new(rooch_store, moveos_store)?;
+        let committer: Option<Box<dyn BlockCommitter>> = if config.submit_to_chain.unwrap_or(false)
+        {
+            Some(Box::new(ChainBlockCommitter::new(
+                proposer_key,
+                executor,
+                processor,
+            )))
+        } else {
+            None
+        };
 
         Ok(Self {
-            proposer_key,
             scc,
             metrics: Arc::new(ProposerMetrics::new(registry)),
+            committer,
         })
     }
+
+    /// Check the proposer is allowed to commit blocks by the on-chain SCC before starting,
+    /// nothing to check if the blocks are not submitted to chain.
+    pub async fn check_proposer(&self) -> anyhow::Result<()> {
+        match &self.committer {
+            Some(committer) => check_proposer(committer.as_ref()).await,
+            None => Ok(()),
+        }
+    }
 }
 
 impl Actor for ProposerActor {}
@@ -69,8 +94,6 @@ impl Handler<ProposeBlock> for ProposerActor {
             Ok(block) => {
                 match block {
                     Some(block) => {
-                        // TODO submit to the on-chain SCC contract use the proposer key
-                        let _proposer_key = &self.proposer_key;
                         let ret = self.scc.set_last_proposed(block.block_number);
                         match ret {
                             Ok(_) => {
@@ -98,5 +121,11 @@ impl Handler<ProposeBlock> for ProposerActor {
                 tracing::error!("[ProposeBlock] error: {:?}", e);
             }
         }
+
+        if let Some(committer) = &self.committer {
+            if let Err(e) = self.scc.commit_blocks(committer.as_ref()).await {
+                tracing::error!("[CommitBlock] error: {:?}", e);
+            }
+        }
     }
 }
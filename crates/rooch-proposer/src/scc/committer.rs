@@ -0,0 +1,98 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use move_core_types::vm_status::KeptVMStatus;
+use moveos_types::module_binding::MoveFunctionCaller;
+use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
+use rooch_executor::proxy::ExecutorProxy;
+use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
+use rooch_types::address::RoochAddress;
+use rooch_types::block::Block;
+use rooch_types::crypto::RoochKeyPair;
+use rooch_types::framework::state_commitment_chain::StateCommitmentChainModule;
+use rooch_types::transaction::RoochTransactionData;
+
+/// Commit the proposed blocks to the on-chain State Commitment Chain
+#[async_trait]
+pub trait BlockCommitter: Send + Sync {
+    /// The account which sends the commit transactions
+    fn sender(&self) -> anyhow::Result<RoochAddress>;
+    /// The account allowed to commit blocks by the on-chain SCC
+    async fn get_onchain_proposer(&self) -> anyhow::Result<RoochAddress>;
+    async fn commit_block(&self, block: &Block) -> anyhow::Result<()>;
+}
+
+/// Commit blocks by executing transactions signed by the proposer key
+pub struct ChainBlockCommitter {
+    proposer_key: RoochKeyPair,
+    executor: ExecutorProxy,
+    processor: PipelineProcessorProxy,
+}
+
+impl ChainBlockCommitter {
+    pub fn new(
+        proposer_key: RoochKeyPair,
+        executor: ExecutorProxy,
+        processor: PipelineProcessorProxy,
+    ) -> Self {
+        Self {
+            proposer_key,
+            executor,
+            processor,
+        }
+    }
+}
+
+#[async_trait]
+impl BlockCommitter for ChainBlockCommitter {
+    fn sender(&self) -> anyhow::Result<RoochAddress> {
+        self.proposer_key.public().rooch_address()
+    }
+
+    async fn get_onchain_proposer(&self) -> anyhow::Result<RoochAddress> {
+        let scc_module = self
+            .executor
+            .as_module_binding::<StateCommitmentChainModule>();
+        Ok(scc_module.proposer()?.into())
+    }
+
+    async fn commit_block(&self, block: &Block) -> anyhow::Result<()> {
+        let sender = self.sender()?;
+        let sequence_number = self.executor.get_sequence_number(sender.into()).await?;
+        let chain_id = self.executor.chain_id().await?.id;
+        let action = StateCommitmentChainModule::create_commit_block_action(block);
+        let tx_data = RoochTransactionData::new(
+            sender,
+            sequence_number,
+            chain_id,
+            GasScheduleConfig::CLI_DEFAULT_MAX_GAS_AMOUNT,
+            action,
+        );
+        let tx = tx_data.sign(&self.proposer_key);
+        let result = self.processor.execute_l2_tx(tx).await?;
+        match result.execution_info.status {
+            KeptVMStatus::Executed => Ok(()),
+            status => Err(anyhow::anyhow!(
+                "commit block: {} to SCC failed, status: {:?}",
+                block.block_number,
+                status
+            )),
+        }
+    }
+}
+
+/// Check the committer is allowed to commit blocks by the on-chain SCC,
+/// otherwise every commit transaction would abort.
+pub async fn check_proposer(committer: &dyn BlockCommitter) -> anyhow::Result<()> {
+    let sender = committer.sender()?;
+    let onchain_proposer = committer.get_onchain_proposer().await?;
+    if sender != onchain_proposer {
+        return Err(anyhow::anyhow!(
+            "proposer: {} is not the on-chain SCC proposer: {}, please set it by `state_commitment_chain::set_proposer` or disable proposer-submit-to-chain",
+            sender,
+            onchain_proposer
+        ));
+    }
+    Ok(())
+}
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::scc::committer::BlockCommitter;
 use moveos_store::transaction_store::TransactionStore;
 use moveos_store::MoveOSStore;
 use moveos_types::h256::H256;
@@ -11,6 +12,8 @@ use rooch_types::block::Block;
 use rooch_types::da::batch::BlockSubmitState;
 use rooch_types::transaction::LedgerTransaction;
 
+pub mod committer;
+
 /// State Commitment Chain(SCC) is a chain of transaction state root
 /// This SCC is a mirror of the on-chain SCC
 pub struct StateCommitmentChain {
@@ -71,8 +74,8 @@ impl StateCommitmentChain {
         Ok(())
     }
 
-    #[allow(dead_code)]
-    fn get_block(&self, block_number: u128) -> anyhow::Result<Block> {
+    /// Get the proposed block by block number
+    pub fn get_block(&self, block_number: u128) -> anyhow::Result<Block> {
         match self.last_proposed_block_number {
             Some(last_proposed) => {
                 if block_number > last_proposed {
@@ -124,13 +127,11 @@ impl StateCommitmentChain {
         Ok((tx_accumulator_root, tx_state_root))
     }
 
-    #[allow(dead_code)]
     fn get_accumulator_root(&self, tx_order: u64) -> anyhow::Result<H256> {
         let ledger_tx = get_ledger_tx(self.rooch_store.clone(), tx_order)?;
         Ok(ledger_tx.sequence_info.tx_accumulator_root)
     }
 
-    #[allow(dead_code)]
     fn get_prev_accumulator_root(&self, block_number: u128) -> anyhow::Result<H256> {
         if block_number == 0 {
             return Ok(H256::zero());
@@ -174,6 +175,40 @@ impl StateCommitmentChain {
         self.rooch_store.set_last_proposed(block_number)
     }
 
+    pub fn get_last_proposed(&self) -> Option<u128> {
+        self.last_proposed_block_number
+    }
+
+    pub fn get_last_committed(&self) -> anyhow::Result<Option<u128>> {
+        self.rooch_store.get_last_committed()
+    }
+
+    pub fn set_last_committed(&self, block_number: u128) -> anyhow::Result<()> {
+        self.rooch_store.set_last_committed(block_number)
+    }
+
+    /// Commit proposed but not committed blocks to the on-chain SCC one by one,
+    /// stop at the first failure and retry in the next round.
+    pub async fn commit_blocks(&self, committer: &dyn BlockCommitter) -> anyhow::Result<()> {
+        let last_proposed = match self.get_last_proposed() {
+            Some(last_proposed) => last_proposed,
+            None => return Ok(()),
+        };
+        // start from the last proposed block if nothing has been committed,
+        // the on-chain SCC accepts any block number as the first one
+        let next_commit_block_number = match self.get_last_committed()? {
+            Some(last_committed) => last_committed + 1,
+            None => last_proposed,
+        };
+        for block_number in next_commit_block_number..=last_proposed {
+            let block = self.get_block(block_number)?;
+            committer.commit_block(&block).await?;
+            self.set_last_committed(block_number)?;
+            tracing::info!("[CommitBlock] done. block_number: {}", block_number);
+        }
+        Ok(())
+    }
+
     /// Trigger the proposer to propose a new block
     pub async fn propose_block(&mut self) -> anyhow::Result<Option<Block>> {
         let last_proposed = self.rooch_store.get_last_proposed()?;
@@ -213,3 +248,182 @@ fn get_ledger_tx(rooch_store: RoochStore, tx_order: u64) -> anyhow::Result<Ledge
     }
     Ok(tx_opt.unwrap())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scc::committer::check_proposer;
+    use async_trait::async_trait;
+    use move_core_types::vm_status::KeptVMStatus;
+    use moveos_types::transaction::TransactionExecutionInfo;
+    use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::sequencer::SequencerInfo;
+    use rooch_types::test_utils::{random_accumulator_info, random_ledger_transaction_with_order};
+    use std::sync::Mutex;
+
+    const TXS_PER_BLOCK: u64 = 2;
+
+    struct MockBlockCommitter {
+        sender: RoochAddress,
+        onchain_proposer: RoochAddress,
+        fail_block_number: Mutex<Option<u128>>,
+        committed_blocks: Mutex<Vec<Block>>,
+    }
+
+    impl MockBlockCommitter {
+        fn new(sender: RoochAddress, onchain_proposer: RoochAddress) -> Self {
+            Self {
+                sender,
+                onchain_proposer,
+                fail_block_number: Mutex::new(None),
+                committed_blocks: Mutex::new(vec![]),
+            }
+        }
+
+        fn committed_block_numbers(&self) -> Vec<u128> {
+            self.committed_blocks
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|block| block.block_number)
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl BlockCommitter for MockBlockCommitter {
+        fn sender(&self) -> anyhow::Result<RoochAddress> {
+            Ok(self.sender)
+        }
+
+        async fn get_onchain_proposer(&self) -> anyhow::Result<RoochAddress> {
+            Ok(self.onchain_proposer)
+        }
+
+        async fn commit_block(&self, block: &Block) -> anyhow::Result<()> {
+            if *self.fail_block_number.lock().unwrap() == Some(block.block_number) {
+                return Err(anyhow::anyhow!(
+                    "commit block: {} failed",
+                    block.block_number
+                ));
+            }
+            self.committed_blocks.lock().unwrap().push(block.clone());
+            Ok(())
+        }
+    }
+
+    // appends a DA submitted block with executed transactions
+    fn append_block(rooch_store: &RoochStore, moveos_store: &MoveOSStore, keypair: &RoochKeyPair) {
+        let tx_order_start = match rooch_store.get_last_block_number().unwrap() {
+            Some(block_number) => {
+                rooch_store
+                    .get_block_state(block_number)
+                    .unwrap()
+                    .block_range
+                    .tx_order_end
+                    + 1
+            }
+            None => 1,
+        };
+        let tx_order_end = tx_order_start + TXS_PER_BLOCK - 1;
+        for tx_order in tx_order_start..=tx_order_end {
+            let mut tx = random_ledger_transaction_with_order(tx_order, keypair);
+            let tx_hash = tx.data.tx_hash();
+            rooch_store
+                .save_sequenced_tx(
+                    tx_hash,
+                    tx,
+                    SequencerInfo::new(tx_order, random_accumulator_info()),
+                    None,
+                    true,
+                )
+                .unwrap();
+            moveos_store
+                .save_tx_execution_info(TransactionExecutionInfo::new(
+                    tx_hash,
+                    H256::random(),
+                    0,
+                    H256::random(),
+                    0,
+                    KeptVMStatus::Executed,
+                ))
+                .unwrap();
+        }
+        let block_number = rooch_store
+            .append_submitting_block(tx_order_start, tx_order_end)
+            .unwrap();
+        rooch_store
+            .set_submitting_block_done(block_number, tx_order_start, tx_order_end, H256::random())
+            .unwrap();
+        rooch_store
+            .set_background_submit_block_cursor(block_number)
+            .unwrap();
+    }
+
+    async fn propose_blocks(scc: &mut StateCommitmentChain) {
+        while let Some(block) = scc.propose_block().await.unwrap() {
+            scc.set_last_proposed(block.block_number).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_commit_blocks() {
+        let (rooch_store, _rooch_dir) = RoochStore::mock_rooch_store().unwrap();
+        let (moveos_store, _moveos_dir) = MoveOSStore::mock_moveos_store().unwrap();
+        let keypair = RoochKeyPair::generate_secp256k1();
+        let proposer = RoochAddress::random();
+        let committer = MockBlockCommitter::new(proposer, proposer);
+        let mut scc = StateCommitmentChain::new(rooch_store.clone(), moveos_store.clone()).unwrap();
+
+        // nothing to commit before any block is proposed
+        scc.commit_blocks(&committer).await.unwrap();
+        assert!(committer.committed_block_numbers().is_empty());
+
+        // start from the last proposed block if nothing has been committed
+        append_block(&rooch_store, &moveos_store, &keypair);
+        append_block(&rooch_store, &moveos_store, &keypair);
+        propose_blocks(&mut scc).await;
+        scc.commit_blocks(&committer).await.unwrap();
+        assert_eq!(committer.committed_block_numbers(), vec![1]);
+        assert_eq!(scc.get_last_committed().unwrap(), Some(1));
+
+        // stop at the first failure and retry in the next round
+        for _ in 0..3 {
+            append_block(&rooch_store, &moveos_store, &keypair);
+        }
+        propose_blocks(&mut scc).await;
+        *committer.fail_block_number.lock().unwrap() = Some(3);
+        assert!(scc.commit_blocks(&committer).await.is_err());
+        assert_eq!(committer.committed_block_numbers(), vec![1, 2]);
+        assert_eq!(scc.get_last_committed().unwrap(), Some(2));
+
+        *committer.fail_block_number.lock().unwrap() = None;
+        scc.commit_blocks(&committer).await.unwrap();
+        assert_eq!(committer.committed_block_numbers(), vec![1, 2, 3, 4]);
+        assert_eq!(scc.get_last_committed().unwrap(), Some(4));
+
+        // the committed blocks are chained by the tx accumulator root
+        let committed_blocks = committer.committed_blocks.lock().unwrap();
+        for blocks in committed_blocks.windows(2) {
+            assert_eq!(
+                blocks[1].prev_tx_accumulator_root,
+                blocks[0].tx_accumulator_root
+            );
+        }
+        for block in committed_blocks.iter() {
+            assert_eq!(block.batch_size, TXS_PER_BLOCK);
+            assert_eq!(block, &scc.get_block(block.block_number).unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_proposer() {
+        let proposer = RoochAddress::random();
+        let committer = MockBlockCommitter::new(proposer, proposer);
+        check_proposer(&committer).await.unwrap();
+
+        let committer = MockBlockCommitter::new(RoochAddress::random(), proposer);
+        assert!(check_proposer(&committer).await.is_err());
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::messages::PruneTick;
+use crate::pruner::StatePruner;
+use async_trait::async_trait;
+use coerce::actor::{context::ActorContext, message::Handler, Actor};
+use tracing::{error, info};
+
+pub struct PrunerActor {
+    pruner: StatePruner,
+}
+
+impl PrunerActor {
+    pub fn new(pruner: StatePruner) -> Self {
+        Self { pruner }
+    }
+}
+
+impl Actor for PrunerActor {}
+
+#[async_trait]
+impl Handler<PruneTick> for PrunerActor {
+    async fn handle(&mut self, _message: PruneTick, _ctx: &mut ActorContext) {
+        match self.pruner.run_once() {
+            Ok(stats) => {
+                if !stats.is_idle() {
+                    info!(
+                        "State pruner indexed {} versions with {} stale nodes, pruned {} versions, deleted {} nodes, retained {} nodes",
+                        stats.indexed_versions,
+                        stats.stale_nodes,
+                        stats.prune_stats.pruned_versions,
+                        stats.prune_stats.deleted_nodes,
+                        stats.prune_stats.retained_nodes
+                    );
+                }
+            }
+            Err(e) => {
+                error!("State pruner error: {:?}", e);
+            }
+        }
+    }
+}
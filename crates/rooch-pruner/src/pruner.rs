@@ -0,0 +1,239 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use moveos_store::prune_store::StatePruneStats;
+use moveos_store::transaction_store::TransactionStore as TxExecutionInfoStore;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use rooch_config::pruner_config::PrunerConfig;
+use rooch_store::da_store::DAMetaStore;
+use rooch_store::meta_store::MetaStore;
+use rooch_store::transaction_store::TransactionStore;
+use rooch_store::RoochStore;
+
+#[derive(Clone, Debug, Default)]
+pub struct PruneRoundStats {
+    pub indexed_versions: u64,
+    pub stale_nodes: u64,
+    pub prune_stats: StatePruneStats,
+}
+
+impl PruneRoundStats {
+    pub fn is_idle(&self) -> bool {
+        self.indexed_versions == 0 && self.prune_stats.pruned_versions == 0
+    }
+}
+
+/// The state pruner indexes the state node logs of the executed transactions,
+/// and deletes the stale state nodes of the versions out of the retention.
+///
+/// The version of the state is the tx order. The states of the recent `retain_recent` versions
+/// are retained, and if `retain_checkpoints` is enabled, the states at the end of every DA block
+/// are retained too, the versions after the last submitted block are not pruned.
+pub struct StatePruner {
+    moveos_store: MoveOSStore,
+    rooch_store: RoochStore,
+    retain_recent: u64,
+    retain_checkpoints: bool,
+    batch_size: u64,
+}
+
+impl StatePruner {
+    pub fn new(
+        moveos_store: MoveOSStore,
+        rooch_store: RoochStore,
+        config: &PrunerConfig,
+    ) -> Result<Self> {
+        ensure!(
+            config.retain_recent() >= 1,
+            "The pruner must retain at least one recent state"
+        );
+        ensure!(
+            config.batch_size() >= 1,
+            "The pruner batch size must be greater than 0"
+        );
+        Ok(Self {
+            moveos_store,
+            rooch_store,
+            retain_recent: config.retain_recent(),
+            retain_checkpoints: config.retain_checkpoints(),
+            batch_size: config.batch_size(),
+        })
+    }
+
+    /// Index the node logs of a batch of versions, then prune a batch of versions
+    /// if all the executed versions are indexed.
+    /// The state writes are only blocked when the prune batch is committed.
+    pub fn run_once(&self) -> Result<PruneRoundStats> {
+        let mut stats = PruneRoundStats::default();
+        let prune_store = self.moveos_store.get_prune_store();
+        let _index_guard = prune_store.lock_state_index();
+        let prune_info = prune_store.get_prune_info()?.unwrap_or_default();
+
+        let start = prune_info.next_index_version();
+        let versions = self.get_versions(start, start + self.batch_size - 1)?;
+        let last_indexed_version = match versions.last() {
+            Some((version, _)) => Some(*version),
+            None => prune_info.last_indexed_version,
+        };
+        if !versions.is_empty() {
+            stats.stale_nodes = self.moveos_store.index_state_node_logs(&versions)?;
+            stats.indexed_versions = versions.len() as u64;
+        }
+        // the executed versions are not all indexed, a node may be added again in them
+        if versions.len() as u64 == self.batch_size {
+            return Ok(stats);
+        }
+
+        let target_version = match self.get_target_version(last_indexed_version)? {
+            Some(target_version) => target_version,
+            None => return Ok(stats),
+        };
+        let start = prune_info.next_prune_version();
+        if start > target_version {
+            return Ok(stats);
+        }
+        let end = target_version.min(start + self.batch_size - 1);
+        let versions = self.get_versions(start, end)?;
+        ensure!(
+            versions.len() as u64 == end - start + 1,
+            "The execution info of the versions [{}, {}] to prune is incomplete",
+            start,
+            end
+        );
+        let plan = match self
+            .moveos_store
+            .plan_state_prune(&versions, |alive_since, stale_since| {
+                self.is_retained(alive_since, stale_since)
+            })? {
+            Some(plan) => plan,
+            None => return Ok(stats),
+        };
+
+        // Block the tx output writes, the deleted nodes must not be written again meanwhile,
+        // the nodes written by the versions executed after indexing are kept.
+        let _write_guard = prune_store.lock_state_writes();
+        let next_index_version = last_indexed_version.map(|v| v + 1).unwrap_or(0);
+        let unindexed_versions =
+            self.get_versions(next_index_version, next_index_version + self.batch_size - 1)?;
+        if unindexed_versions.len() as u64 == self.batch_size {
+            return Ok(stats);
+        }
+        stats.prune_stats = self
+            .moveos_store
+            .commit_state_prune(plan, &unindexed_versions)?;
+        Ok(stats)
+    }
+
+    /// Initialize the state pruning of a store without the node logs since genesis,
+    /// the existing nodes are kept and the states written after the last executed version are pruned.
+    /// It scans all the state nodes, so it is only run offline. Returns the number of the pinned nodes,
+    /// or None if the state pruning is already initialized or the genesis node log exists.
+    pub fn init_baseline_if_needed(&self) -> Result<Option<u64>> {
+        let prune_store = self.moveos_store.get_prune_store();
+        if prune_store.get_prune_info()?.is_some() {
+            return Ok(None);
+        }
+        if let Some((_, genesis_state_root)) = self.get_versions(0, 0)?.first() {
+            if prune_store.get_node_log(*genesis_state_root)?.is_some() {
+                return Ok(None);
+            }
+        }
+        let last_order = self
+            .rooch_store
+            .get_sequencer_info()?
+            .map(|sequencer_info| sequencer_info.last_order)
+            .unwrap_or(0);
+        let mut base_version = last_order;
+        while self.get_versions(base_version, base_version)?.is_empty() {
+            base_version = match base_version.checked_sub(1) {
+                Some(version) => version,
+                None => return Ok(None),
+            };
+        }
+        let _index_guard = prune_store.lock_state_index();
+        let _write_guard = prune_store.lock_state_writes();
+        let pinned_nodes = self.moveos_store.init_state_prune_baseline(base_version)?;
+        Ok(Some(pinned_nodes))
+    }
+
+    /// Get the (version, state_root) of the executed versions in [start, end], stop at the first
+    /// version which is not executed.
+    fn get_versions(&self, start: u64, end: u64) -> Result<Vec<(u64, H256)>> {
+        let tx_orders = (start..=end).collect::<Vec<_>>();
+        let tx_hashes = self.rooch_store.get_tx_hashes(tx_orders.clone())?;
+        let tx_hashes = tx_hashes
+            .into_iter()
+            .map_while(|tx_hash| tx_hash)
+            .collect::<Vec<_>>();
+        let execution_infos = self.moveos_store.multi_get_tx_execution_infos(tx_hashes)?;
+        Ok(tx_orders
+            .into_iter()
+            .zip(execution_infos)
+            .map_while(|(tx_order, execution_info)| {
+                execution_info.map(|execution_info| (tx_order, execution_info.state_root))
+            })
+            .collect())
+    }
+
+    fn get_target_version(&self, last_indexed_version: Option<u64>) -> Result<Option<u64>> {
+        let target_version = match last_indexed_version
+            .and_then(|version| version.checked_sub(self.retain_recent))
+        {
+            Some(target_version) => target_version,
+            None => return Ok(None),
+        };
+        if !self.retain_checkpoints {
+            return Ok(Some(target_version));
+        }
+        // only prune the versions before the last submitted block
+        let last_submitted_block = match self.rooch_store.get_background_submit_block_cursor()? {
+            Some(block_number) => block_number,
+            None => return Ok(None),
+        };
+        let block = self.rooch_store.get_block_state(last_submitted_block)?;
+        Ok(Some(target_version.min(block.block_range.tx_order_end)))
+    }
+
+    /// Whether there is a checkpoint in [alive_since, stale_since)
+    fn is_retained(&self, alive_since: u64, stale_since: u64) -> Result<bool> {
+        if !self.retain_checkpoints {
+            return Ok(false);
+        }
+        match self.find_block_end(alive_since)? {
+            Some(tx_order_end) => Ok(tx_order_end < stale_since),
+            None => Ok(false),
+        }
+    }
+
+    /// Find the tx_order_end of the first block which ends at or after the `tx_order`
+    fn find_block_end(&self, tx_order: u64) -> Result<Option<u64>> {
+        let last_block_number = match self.rooch_store.get_last_block_number()? {
+            Some(block_number) => block_number,
+            None => return Ok(None),
+        };
+        let (mut low, mut high) = (0u128, last_block_number);
+        let mut found = None;
+        while low <= high {
+            let mid = low + (high - low) / 2;
+            let tx_order_end = match self.rooch_store.try_get_block_state(mid)? {
+                Some(block) => block.block_range.tx_order_end,
+                None => {
+                    low = mid + 1;
+                    continue;
+                }
+            };
+            if tx_order_end >= tx_order {
+                found = Some(tx_order_end);
+                if mid == 0 {
+                    break;
+                }
+                high = mid - 1;
+            } else {
+                low = mid + 1;
+            }
+        }
+        Ok(found)
+    }
+}
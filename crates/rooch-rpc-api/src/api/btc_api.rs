@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::jsonrpc_types::btc::ord::InscriptionFilterView;
+use crate::jsonrpc_types::btc::runes::RuneBalancesFilterView;
 use crate::jsonrpc_types::btc::utxo::UTXOFilterView;
 use crate::jsonrpc_types::{
-    BytesView, IndexerStateIDView, InscriptionPageView, StrView, UTXOPageView,
+    BytesView, IndexerStateIDView, InscriptionPageView, RuneBalancesPageView, StrView, UTXOPageView,
 };
 use crate::RpcResult;
 use jsonrpsee::proc_macros::rpc;
@@ -36,6 +37,17 @@ pub trait BtcAPI {
         descending_order: Option<bool>,
     ) -> RpcResult<InscriptionPageView>;
 
+    /// Query the Rune balances of UTXOs via global index by RuneBalances filter
+    #[method(name = "queryRuneBalances")]
+    async fn query_rune_balances(
+        &self,
+        filter: RuneBalancesFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerStateIDView>,
+        limit: Option<StrView<u64>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<RuneBalancesPageView>;
+
     /// Broadcast a Bitcoin transaction
     #[method(name = "broadcastTX")]
     async fn broadcast_tx(
@@ -3,20 +3,27 @@
 
 use crate::jsonrpc_types::account_view::BalanceInfoView;
 use crate::jsonrpc_types::address::UnitedAddressView;
-use crate::jsonrpc_types::event_view::{EventFilterView, IndexerEventIDView};
+use crate::jsonrpc_types::backup_view::BackupManifestView;
+use crate::jsonrpc_types::event_view::{EventFilterView, IndexerEventIDView, IndexerEventView};
 use crate::jsonrpc_types::field_view::FieldFilterView;
+use crate::jsonrpc_types::proof_view::{StateProofView, TransactionProofView};
 use crate::jsonrpc_types::repair_view::{RepairIndexerParamsView, RepairIndexerTypeView};
-use crate::jsonrpc_types::transaction_view::{TransactionFilterView, TransactionWithInfoView};
+use crate::jsonrpc_types::trace_view::ReplayTransactionResponseView;
+use crate::jsonrpc_types::transaction_view::{
+    PendingTransactionView, TransactionFilterView, TransactionWithInfoView,
+};
 use crate::jsonrpc_types::{
     AccessPathView, AnnotatedFunctionResultView, BalanceInfoPageView, BytesView, EventOptions,
     EventPageView, ExecuteTransactionResponseView, FieldKeyView, FieldPageView, FunctionCallView,
-    H256View, IndexerEventPageView, IndexerObjectStatePageView, IndexerStateIDView, ModuleABIView,
-    ObjectIDVecView, ObjectIDView, ObjectStateFilterView, ObjectStateView, QueryOptions,
-    RoochAddressView, StateChangeSetPageView, StateOptions, StatePageView, StrView, StructTagView,
-    SyncStateFilterView, TransactionWithInfoPageView, TxOptions,
+    H256View, IndexerEventPageView, IndexerObjectStatePageView, IndexerObjectStateView,
+    IndexerStateIDView, ModuleABIView, ObjectIDVecView, ObjectIDView, ObjectStateFilterView,
+    ObjectStateView, QueryOptions, RoochAddressView, StateChangeSetPageView, StateOptions,
+    StatePageView, StrView, StructTagView, SyncStateFilterView, TransactionWithInfoPageView,
+    TxOptions,
 };
 use crate::jsonrpc_types::{DryRunTransactionResponseView, Status};
 use crate::RpcResult;
+use jsonrpsee::core::SubscriptionResult;
 use jsonrpsee::proc_macros::rpc;
 use moveos_types::{access_path::AccessPath, state::FieldKey};
 use rooch_open_rpc_macros::open_rpc;
@@ -42,9 +49,40 @@ pub trait RoochAPI {
         tx_option: Option<TxOptions>,
     ) -> RpcResult<ExecuteTransactionResponseView>;
 
+    /// Get the transactions waiting in the tx pool, the transactions with future sequence numbers
+    /// are queued until the transactions with the previous sequence numbers arrive.
+    /// If the sender is provided, only the transactions of the sender are returned.
+    #[method(name = "getPendingTransactions")]
+    async fn get_pending_transactions(
+        &self,
+        sender: Option<UnitedAddressView>,
+    ) -> RpcResult<Vec<PendingTransactionView>>;
+
+    /// Estimate the gas price for a new transaction, it is the current base fee per gas unit.
+    /// The transaction with a gas price lower than the base fee is rejected, and the ready transactions
+    /// with higher gas prices are sequenced first by the tx pool.
+    #[method(name = "estimateGasPrice")]
+    async fn estimate_gas_price(&self) -> RpcResult<StrView<u64>>;
+
     #[method(name = "dryRunRawTransaction")]
     async fn dry_run(&self, tx_bcs_hex: BytesView) -> RpcResult<DryRunTransactionResponseView>;
 
+    /// Re-execute the executed transaction on the state root before it, and return the output.
+    /// The state is not changed, the recorded execution info is returned for comparison.
+    #[method(name = "replayTransaction")]
+    async fn replay_transaction(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<ReplayTransactionResponseView>>;
+
+    /// Replay the executed transaction like `replayTransaction`, and return the call tree of the
+    /// execution with the arguments and the gas used of every function call.
+    #[method(name = "traceTransaction")]
+    async fn trace_transaction(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<ReplayTransactionResponseView>>;
+
     /// Execute a read-only function call
     /// The function do not change the state of Application
     #[method(name = "executeViewFunction")]
@@ -81,6 +119,24 @@ pub trait RoochAPI {
         state_option: Option<StateOptions>,
     ) -> RpcResult<Vec<Option<ObjectStateView>>>;
 
+    /// Get the Merkle proofs of the states by access_path.
+    /// If the state_root is None, the proofs are generated with the latest state root.
+    /// The proofs can be verified with `StateProof::verify`, even if the state does not exist.
+    #[method(name = "getStateProof")]
+    async fn get_state_proof(
+        &self,
+        access_path: AccessPathView,
+        state_root: Option<H256View>,
+    ) -> RpcResult<Vec<StateProofView>>;
+
+    /// Get the raw state tree nodes by the node hashes, None means the node does not exist.
+    /// It is used by the forked node to load the remote state lazily.
+    #[method(name = "getStateNodes")]
+    async fn get_state_nodes(
+        &self,
+        node_hashes: Vec<H256View>,
+    ) -> RpcResult<Vec<Option<BytesView>>>;
+
     /// Get Object Fields via ObjectID and field keys.
     #[method(name = "getFieldStates")]
     async fn get_field_states(
@@ -135,6 +191,17 @@ pub trait RoochAPI {
         descending_order: Option<bool>,
     ) -> RpcResult<TransactionWithInfoPageView>;
 
+    /// Get the Merkle proof of the transaction inclusion in the tx accumulator.
+    /// If the accumulator_tx_order is provided, the proof is generated with the tx accumulator root
+    /// after the transaction at accumulator_tx_order, such as the last transaction of a proposed block.
+    /// Otherwise, the proof is generated with the transaction's own tx accumulator root.
+    #[method(name = "getTransactionProof")]
+    async fn get_transaction_proof(
+        &self,
+        tx_order: StrView<u64>,
+        accumulator_tx_order: Option<StrView<u64>>,
+    ) -> RpcResult<Option<TransactionProofView>>;
+
     /// get account balance by RoochAddress and CoinType
     #[method(name = "getBalance")]
     async fn get_balance(
@@ -222,6 +289,16 @@ pub trait RoochAPI {
         query_option: Option<QueryOptions>,
     ) -> RpcResult<StateChangeSetPageView>;
 
+    /// Get the committed transactions with their store changes in bcs format, ordered by tx_order.
+    /// It is used by the follower node to apply the store changes of the leader node without re-executing.
+    #[method(name = "getCommittedTransactions")]
+    async fn get_committed_transactions(
+        &self,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<u64>>,
+    ) -> RpcResult<Vec<BytesView>>;
+
     /// Get the chain and service status
     #[method(name = "status")]
     async fn status(&self) -> RpcResult<Status>;
@@ -234,4 +311,39 @@ pub trait RoochAPI {
         limit: Option<StrView<u64>>,
         query_option: Option<QueryOptions>,
     ) -> RpcResult<Vec<u64>>;
+
+    /// Backup the node databases online into the backup dir of the node, with an optional name,
+    /// default to the current timestamp. It is an admin method, only served over HTTP to the
+    /// clients on the local host of the node
+    #[method(name = "backupDB")]
+    async fn backup_db(&self, name: Option<String>) -> RpcResult<BackupManifestView>;
+
+    /// Subscribe to the events of the newly indexed transactions by event filter
+    #[subscription(
+        name = "subscribeEvents",
+        unsubscribe = "unsubscribeEvents",
+        item = IndexerEventView
+    )]
+    async fn subscribe_events(&self, filter: EventFilterView) -> SubscriptionResult;
+
+    /// Subscribe to the newly indexed transactions by transaction filter
+    #[subscription(
+        name = "subscribeTransactions",
+        unsubscribe = "unsubscribeTransactions",
+        item = TransactionWithInfoView
+    )]
+    async fn subscribe_transactions(&self, filter: TransactionFilterView) -> SubscriptionResult;
+
+    /// Subscribe to the created or updated object states of the newly indexed transactions by state filter,
+    /// the object states are the latest states when they are pushed.
+    #[subscription(
+        name = "subscribeObjectStates",
+        unsubscribe = "unsubscribeObjectStates",
+        item = IndexerObjectStateView
+    )]
+    async fn subscribe_object_states(
+        &self,
+        filter: ObjectStateFilterView,
+        query_option: Option<QueryOptions>,
+    ) -> SubscriptionResult;
 }
@@ -0,0 +1,37 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{H256View, StrView};
+use rooch_types::backup::BackupManifest;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct BackupManifestView {
+    pub chain_id: StrView<u64>,
+    /// The timestamp in milliseconds when the backup was created
+    pub created_at: StrView<u64>,
+    /// The last tx order sequenced in the backup
+    pub sequencer_order: StrView<u64>,
+    /// The latest state root in the backup
+    pub state_root: H256View,
+    pub state_size: StrView<u64>,
+    /// The backed up indexer SQLite tables, empty if the indexer uses PostgreSQL
+    pub indexer_tables: Vec<String>,
+    /// The backup dir on the node
+    pub backup_dir: String,
+}
+
+impl BackupManifestView {
+    pub fn new(manifest: BackupManifest, backup_dir: String) -> Self {
+        Self {
+            chain_id: manifest.chain_id.into(),
+            created_at: manifest.created_at.into(),
+            sequencer_order: manifest.sequencer_order.into(),
+            state_root: manifest.state_root.into(),
+            state_size: manifest.state_size.into(),
+            indexer_tables: manifest.indexer_tables,
+            backup_dir,
+        }
+    }
+}
@@ -5,13 +5,14 @@ use crate::jsonrpc_types::{
     AnnotatedMoveStructView, H256View, HumanReadableDisplay, RoochAddressView, StrView,
     StructTagView, UnitedAddressView,
 };
+use move_core_types::u256::U256;
 use moveos_types::moveos_std::{
     event::{AnnotatedEvent, Event, EventID, TransactionEvent},
     object::ObjectID,
 };
 use rooch_types::address::RoochAddress;
 use rooch_types::indexer::event::{
-    AnnotatedIndexerEvent, EventFilter, IndexerEvent, IndexerEventID,
+    AnnotatedIndexerEvent, EventFieldFilter, EventFilter, IndexerEvent, IndexerEventID,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -217,6 +218,40 @@ pub enum EventFilterView {
         /// right endpoint of transaction order, exclusive
         to_order: StrView<u64>,
     },
+    /// Query by event type and the decoded event data, all the field filters must match
+    EventTypeWithFieldFilters {
+        event_type: StructTagView,
+        field_filters: Vec<EventFieldFilterView>,
+    },
+}
+
+/// The filter on a field of the decoded event data.
+/// The path is the dot separated field names from the event struct, such as `coin.value`,
+/// a vector element is selected by its index, such as `amounts.0`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventFieldFilterView {
+    /// The field value equals the value, numbers, bools and addresses are compared in string form
+    Eq { path: String, value: String },
+    /// The integer field value in [from, to) interval, the interval is unbounded if an endpoint is omitted
+    Range {
+        path: String,
+        from: Option<StrView<U256>>,
+        to: Option<StrView<U256>>,
+    },
+}
+
+impl From<EventFieldFilterView> for EventFieldFilter {
+    fn from(field_filter: EventFieldFilterView) -> Self {
+        match field_filter {
+            EventFieldFilterView::Eq { path, value } => Self::Eq { path, value },
+            EventFieldFilterView::Range { path, from, to } => Self::Range {
+                path,
+                from: from.map(|v| v.0),
+                to: to.map(|v| v.0),
+            },
+        }
+    }
 }
 
 impl From<EventFilterView> for EventFilter {
@@ -245,6 +280,13 @@ impl From<EventFilterView> for EventFilter {
                 from_order: from_order.0,
                 to_order: to_order.0,
             },
+            EventFilterView::EventTypeWithFieldFilters {
+                event_type,
+                field_filters,
+            } => Self::EventTypeWithFieldFilters {
+                event_type: event_type.into(),
+                field_filters: field_filters.into_iter().map(Into::into).collect(),
+            },
         }
     }
 }
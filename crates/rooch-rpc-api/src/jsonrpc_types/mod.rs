@@ -24,9 +24,12 @@ pub mod json_to_table_display;
 pub mod transaction_view;
 
 pub mod address;
+pub mod backup_view;
 pub mod btc;
 pub mod field_view;
+pub mod proof_view;
 pub mod repair_view;
+pub mod trace_view;
 
 pub use self::rooch_types::*;
 pub use address::*;
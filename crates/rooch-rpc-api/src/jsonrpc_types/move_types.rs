@@ -18,7 +18,7 @@ use moveos_types::transaction::MoveAction;
 use moveos_types::{
     access_path::AccessPath,
     move_types::FunctionId,
-    transaction::{FunctionCall, ScriptCall},
+    transaction::{CallArgument, ChainedCall, FunctionCall, ScriptCall},
 };
 use moveos_types::{
     move_std::{ascii::MoveAsciiString, string::MoveString},
@@ -392,6 +392,70 @@ impl From<FunctionCallView> for FunctionCall {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CallArgumentView {
+    Pure(BytesView),
+    Result { call_index: u16, result_index: u16 },
+}
+
+impl From<CallArgument> for CallArgumentView {
+    fn from(origin: CallArgument) -> Self {
+        match origin {
+            CallArgument::Pure(arg) => Self::Pure(StrView(arg)),
+            CallArgument::Result {
+                call_index,
+                result_index,
+            } => Self::Result {
+                call_index,
+                result_index,
+            },
+        }
+    }
+}
+
+impl From<CallArgumentView> for CallArgument {
+    fn from(value: CallArgumentView) -> Self {
+        match value {
+            CallArgumentView::Pure(arg) => Self::Pure(arg.into()),
+            CallArgumentView::Result {
+                call_index,
+                result_index,
+            } => Self::Result {
+                call_index,
+                result_index,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct ChainedCallView {
+    pub function_id: FunctionIdView,
+    pub ty_args: Vec<TypeTagView>,
+    pub args: Vec<CallArgumentView>,
+}
+
+impl From<ChainedCall> for ChainedCallView {
+    fn from(origin: ChainedCall) -> Self {
+        Self {
+            function_id: StrView(origin.function_id),
+            ty_args: origin.ty_args.into_iter().map(StrView).collect(),
+            args: origin.args.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ChainedCallView> for ChainedCall {
+    fn from(value: ChainedCallView) -> Self {
+        Self {
+            function_id: value.function_id.into(),
+            ty_args: value.ty_args.into_iter().map(Into::into).collect(),
+            args: value.args.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MoveActionView {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -400,6 +464,8 @@ pub struct MoveActionView {
     pub script_call: Option<ScriptCallView>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub module_bundle: Option<Vec<BytesView>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_call: Option<Vec<ChainedCallView>>,
 }
 
 impl From<MoveAction> for MoveActionView {
@@ -409,16 +475,25 @@ impl From<MoveAction> for MoveActionView {
                 script_call: Some(script.into()),
                 function_call: None,
                 module_bundle: None,
+                multi_call: None,
             },
             MoveAction::Function(fun) => Self {
                 script_call: None,
                 function_call: Some(fun.into()),
                 module_bundle: None,
+                multi_call: None,
             },
             MoveAction::ModuleBundle(module) => Self {
                 script_call: None,
                 function_call: None,
                 module_bundle: Some(module.into_iter().map(StrView).collect()),
+                multi_call: None,
+            },
+            MoveAction::MultiCall(multi_call) => Self {
+                script_call: None,
+                function_call: None,
+                module_bundle: None,
+                multi_call: Some(multi_call.calls.into_iter().map(Into::into).collect()),
             },
         }
     }
@@ -432,6 +507,8 @@ impl From<MoveActionView> for MoveAction {
             MoveAction::Function(function_call.into())
         } else if let Some(module_bundle) = action.module_bundle {
             MoveAction::ModuleBundle(module_bundle.into_iter().map(StrView::into).collect())
+        } else if let Some(multi_call) = action.multi_call {
+            MoveAction::new_multi_call(multi_call.into_iter().map(Into::into).collect())
         } else {
             panic!("Invalid MoveActionView")
         }
@@ -444,6 +521,7 @@ pub enum MoveActionTypeView {
     ScriptCall,
     FunctionCall,
     ModuleBundle,
+    MultiCall,
 }
 
 impl From<MoveAction> for MoveActionTypeView {
@@ -452,6 +530,7 @@ impl From<MoveAction> for MoveActionTypeView {
             MoveAction::Script(_) => Self::ScriptCall,
             MoveAction::Function(_) => Self::FunctionCall,
             MoveAction::ModuleBundle(_) => Self::ModuleBundle,
+            MoveAction::MultiCall(_) => Self::MultiCall,
         }
     }
 }
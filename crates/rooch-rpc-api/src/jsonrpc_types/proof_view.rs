@@ -0,0 +1,137 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::{FieldKeyView, H256View, ObjectIDView, ObjectStateView, StrView};
+use accumulator::proof::AccumulatorProof;
+use rooch_types::proof::{FieldProof, StateProof, TransactionProof};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use smt::SparseMerkleProof;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SparseMerkleLeafView {
+    pub key: H256View,
+    pub value_hash: H256View,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SparseMerkleProofView {
+    /// The leaf in the proof, None means the key's subtree is empty
+    pub leaf: Option<SparseMerkleLeafView>,
+    pub siblings: Vec<H256View>,
+}
+
+impl From<SparseMerkleProof> for SparseMerkleProofView {
+    fn from(proof: SparseMerkleProof) -> Self {
+        Self {
+            leaf: proof.leaf().map(|(key, value_hash)| SparseMerkleLeafView {
+                key: key.into(),
+                value_hash: value_hash.into(),
+            }),
+            siblings: proof.siblings().iter().map(|s| (*s).into()).collect(),
+        }
+    }
+}
+
+impl From<SparseMerkleProofView> for SparseMerkleProof {
+    fn from(view: SparseMerkleProofView) -> Self {
+        SparseMerkleProof::new(
+            view.leaf.map(|leaf| (leaf.key.0, leaf.value_hash.0)),
+            view.siblings.into_iter().map(|s| s.0).collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FieldProofView {
+    pub field_key: FieldKeyView,
+    /// None means the field does not exist, and the proof is a non-inclusion proof
+    pub state: Option<ObjectStateView>,
+    pub proof: SparseMerkleProofView,
+}
+
+impl From<FieldProof> for FieldProofView {
+    fn from(proof: FieldProof) -> Self {
+        Self {
+            field_key: proof.field_key.into(),
+            state: proof.state.map(Into::into),
+            proof: proof.proof.into(),
+        }
+    }
+}
+
+impl From<FieldProofView> for FieldProof {
+    fn from(view: FieldProofView) -> Self {
+        Self {
+            field_key: view.field_key.0,
+            state: view.state.map(Into::into),
+            proof: view.proof.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StateProofView {
+    pub state_root: H256View,
+    pub object_id: ObjectIDView,
+    pub field_key: FieldKeyView,
+    /// The field proofs from the global state tree to the field
+    pub proofs: Vec<FieldProofView>,
+}
+
+impl From<StateProof> for StateProofView {
+    fn from(proof: StateProof) -> Self {
+        Self {
+            state_root: proof.state_root.into(),
+            object_id: proof.object_id.into(),
+            field_key: proof.field_key.into(),
+            proofs: proof.proofs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<StateProofView> for StateProof {
+    fn from(view: StateProofView) -> Self {
+        Self {
+            state_root: view.state_root.0,
+            object_id: view.object_id.0,
+            field_key: view.field_key.0,
+            proofs: view.proofs.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TransactionProofView {
+    pub tx_order: StrView<u64>,
+    pub tx_hash: H256View,
+    /// The tx accumulator root the proof is generated with
+    pub accumulator_root: H256View,
+    pub leaf_index: StrView<u64>,
+    /// The accumulator siblings, ordered from the bottom level to the root level
+    pub siblings: Vec<H256View>,
+}
+
+impl From<TransactionProof> for TransactionProofView {
+    fn from(proof: TransactionProof) -> Self {
+        Self {
+            tx_order: proof.tx_order.into(),
+            tx_hash: proof.tx_hash.into(),
+            accumulator_root: proof.accumulator_root.into(),
+            leaf_index: proof.leaf_index.into(),
+            siblings: proof.proof.siblings.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<TransactionProofView> for TransactionProof {
+    fn from(view: TransactionProofView) -> Self {
+        Self {
+            tx_order: view.tx_order.0,
+            tx_hash: view.tx_hash.0,
+            accumulator_root: view.accumulator_root.0,
+            leaf_index: view.leaf_index.0,
+            proof: AccumulatorProof::new(view.siblings.into_iter().map(|s| s.0).collect()),
+        }
+    }
+}
@@ -5,6 +5,7 @@ use super::event_view::IndexerEventIDView;
 use super::{HumanReadableDisplay, IndexerStateIDView, StateChangeSetWithTxOrderView};
 use crate::jsonrpc_types::account_view::BalanceInfoView;
 use crate::jsonrpc_types::btc::ord::InscriptionStateView;
+use crate::jsonrpc_types::btc::runes::RuneBalancesStateView;
 use crate::jsonrpc_types::btc::utxo::UTXOStateView;
 use crate::jsonrpc_types::event_view::{EventView, IndexerEventView};
 use crate::jsonrpc_types::field_view::IndexerFieldView;
@@ -30,6 +31,7 @@ pub type IndexerObjectStatePageView = PageView<IndexerObjectStateView, IndexerSt
 
 pub type UTXOPageView = PageView<UTXOStateView, IndexerStateIDView>;
 pub type InscriptionPageView = PageView<InscriptionStateView, IndexerStateIDView>;
+pub type RuneBalancesPageView = PageView<RuneBalancesStateView, IndexerStateIDView>;
 pub type StateChangeSetPageView = PageView<StateChangeSetWithTxOrderView, StrView<u64>>;
 
 pub type FieldPageView = PageView<IndexerFieldView, StrView<u64>>;
@@ -79,6 +81,8 @@ pub struct TransactionView {
     pub raw: BytesView,
     pub chain_id: StrView<u64>,
     pub max_gas_amount: StrView<u64>,
+    pub expiration_timestamp: Option<StrView<u64>>,
+    pub gas_price: Option<StrView<u64>>,
 }
 
 impl TransactionView {
@@ -95,6 +99,8 @@ impl TransactionView {
             raw: transaction.encode().into(),
             chain_id: transaction.chain_id().into(),
             max_gas_amount: transaction.max_gas_amount().into(),
+            expiration_timestamp: transaction.expiration_timestamp().map(Into::into),
+            gas_price: transaction.gas_price().map(Into::into),
         }
     }
 }
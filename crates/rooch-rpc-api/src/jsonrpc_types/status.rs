@@ -128,6 +128,8 @@ impl From<ObjectState> for RootStateView {
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RoochStatus {
+    /// The genesis hash of the chain, the nodes of the same chain must have the same genesis
+    pub genesis_hash: StrView<H256>,
     pub sequencer_info: SequencerInfoView,
     pub da_info: DAInfoView,
     pub root_state: RootStateView,
@@ -3,3 +3,4 @@
 
 mod changeset_tests;
 mod str_view_tests;
+mod trace_view_tests;
@@ -0,0 +1,81 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::trace_view::CallFrameView;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::gas_algebra::InternalGas;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{ModuleId, TypeTag};
+use moveos_gas_profiling::log::{CallFrame, ExecutionGasEvent};
+use moveos_types::addresses::MOVEOS_STD_ADDRESS;
+
+fn call_native(address: AccountAddress, module: &str, name: &str, cost: u64) -> ExecutionGasEvent {
+    ExecutionGasEvent::CallNative {
+        module_id: ModuleId::new(address, Identifier::new(module).unwrap()),
+        fn_name: Identifier::new(name).unwrap(),
+        ty_args: vec![TypeTag::U64],
+        args: vec![format!("{}_arg", name)],
+        return_values: vec![],
+        cost: InternalGas::new(cost),
+    }
+}
+
+fn function_frame(name: &str) -> CallFrame {
+    CallFrame::new_function(
+        ModuleId::new(AccountAddress::ONE, Identifier::new("test").unwrap()),
+        Identifier::new(name).unwrap(),
+        vec![],
+    )
+}
+
+#[test]
+fn test_call_frame_reads_writes_and_events() {
+    let mut inner = function_frame("inner");
+    inner.events.push(call_native(
+        MOVEOS_STD_ADDRESS,
+        "object",
+        "native_add_field",
+        10,
+    ));
+    let mut root = function_frame("outer");
+    root.events.push(call_native(
+        MOVEOS_STD_ADDRESS,
+        "object",
+        "native_borrow_field",
+        1,
+    ));
+    root.events.push(call_native(
+        MOVEOS_STD_ADDRESS,
+        "object",
+        "native_borrow_mut_object",
+        2,
+    ));
+    root.events
+        .push(call_native(MOVEOS_STD_ADDRESS, "event", "native_emit", 3));
+    // the natives of other modules are not the state access
+    root.events.push(call_native(
+        AccountAddress::ONE,
+        "object",
+        "native_borrow_field",
+        4,
+    ));
+    root.events.push(ExecutionGasEvent::Call(inner));
+
+    let view = CallFrameView::from(root);
+    assert_eq!(view.gas_used.0, 20);
+    assert_eq!(view.calls.len(), 5);
+    assert_eq!(view.reads.len(), 1);
+    assert_eq!(view.reads[0].args, vec!["native_borrow_field_arg"]);
+    assert_eq!(view.writes.len(), 1);
+    assert_eq!(view.writes[0].args, vec!["native_borrow_mut_object_arg"]);
+    assert_eq!(view.events.len(), 1);
+    assert_eq!(view.events[0].event_data, "native_emit_arg");
+    assert!(view.events[0].event_type.is_some());
+
+    // the effects of the nested call are recorded in the nested frame
+    let inner = &view.calls[4];
+    assert!(!inner.is_native);
+    assert!(inner.reads.is_empty());
+    assert_eq!(inner.writes.len(), 1);
+    assert_eq!(inner.gas_used.0, 10);
+}
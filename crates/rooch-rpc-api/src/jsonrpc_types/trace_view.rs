@@ -0,0 +1,209 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jsonrpc_types::event_view::TransactionEventView;
+use crate::jsonrpc_types::{
+    FunctionIdView, H256View, RawTransactionOutputView, StateChangeSetView, StrView,
+    TransactionExecutionInfoView, TypeTagView,
+};
+use move_core_types::identifier::IdentStr;
+use move_core_types::language_storage::{ModuleId, TypeTag};
+use moveos_gas_profiling::log::{CallFrame, ExecutionGasEvent, FrameName};
+use moveos_types::addresses::MOVEOS_STD_ADDRESS;
+use moveos_types::move_types::FunctionId;
+use moveos_types::transaction::VMErrorInfo;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The object natives which read the state
+const STATE_READ_NATIVES: &[&str] = &[
+    "native_object_owner",
+    "native_object_size",
+    "native_object_flag",
+    "native_object_created_at",
+    "native_object_updated_at",
+    "native_borrow_object",
+    "native_borrow_field",
+    "native_contains_field",
+    "native_contains_field_with_value_type",
+    "native_list_field_keys",
+];
+
+/// The object natives which write the state
+const STATE_WRITE_NATIVES: &[&str] = &[
+    "native_transfer_object",
+    "native_to_shared_object",
+    "native_to_frozen_object",
+    "native_borrow_mut_object",
+    "native_take_object",
+    "native_add_field",
+    "native_borrow_mut_field",
+    "native_remove_field",
+];
+
+enum FrameEffect {
+    Read,
+    Write,
+    Event,
+}
+
+impl FrameEffect {
+    /// The state access and the event emission are done by the natives of `moveos_std`
+    fn of_native(module_id: &ModuleId, fn_name: &IdentStr) -> Option<Self> {
+        if module_id.address() != &MOVEOS_STD_ADDRESS {
+            return None;
+        }
+        let fn_name = fn_name.as_str();
+        match module_id.name().as_str() {
+            "object" if STATE_READ_NATIVES.contains(&fn_name) => Some(Self::Read),
+            "object" if STATE_WRITE_NATIVES.contains(&fn_name) => Some(Self::Write),
+            "event" if fn_name == "native_emit" => Some(Self::Event),
+            _ => None,
+        }
+    }
+}
+
+/// A state read or write of the frame, done by the object native function
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StateAccessView {
+    pub function: FunctionIdView,
+    pub ty_args: Vec<TypeTagView>,
+    /// The rendered arguments, the object id and the field key
+    pub args: Vec<String>,
+}
+
+/// An event emitted by the frame
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FrameEventView {
+    pub event_type: Option<TypeTagView>,
+    pub event_data: String,
+}
+
+/// A function call frame of the transaction execution
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CallFrameView {
+    /// The called function, None for the root frame of the script or module publishing transaction
+    pub function: Option<FunctionIdView>,
+    pub ty_args: Vec<TypeTagView>,
+    pub args: Vec<String>,
+    /// The return values are only recorded for the native function
+    pub return_values: Vec<String>,
+    pub is_native: bool,
+    /// The gas used by the frame, including the nested calls
+    pub gas_used: StrView<u64>,
+    /// The states read by the frame, excluding the nested calls
+    pub reads: Vec<StateAccessView>,
+    /// The states written by the frame, excluding the nested calls
+    pub writes: Vec<StateAccessView>,
+    /// The events emitted by the frame, excluding the nested calls
+    pub events: Vec<FrameEventView>,
+    pub calls: Vec<CallFrameView>,
+}
+
+impl CallFrameView {
+    fn new_native(
+        module_id: ModuleId,
+        fn_name: &IdentStr,
+        ty_args: Vec<TypeTag>,
+        args: Vec<String>,
+        return_values: Vec<String>,
+        gas_used: u64,
+    ) -> Self {
+        Self {
+            function: Some(FunctionId::new(module_id, fn_name.to_owned()).into()),
+            ty_args: ty_args.into_iter().map(Into::into).collect(),
+            args,
+            return_values,
+            is_native: true,
+            gas_used: gas_used.into(),
+            reads: vec![],
+            writes: vec![],
+            events: vec![],
+            calls: vec![],
+        }
+    }
+}
+
+impl From<CallFrame> for CallFrameView {
+    fn from(frame: CallFrame) -> Self {
+        let gas_used = u64::from(frame.total_cost());
+        let (function, ty_args) = match frame.name {
+            FrameName::Function {
+                module_id,
+                name,
+                ty_args,
+            } => (Some(FunctionId::new(module_id, name)), ty_args),
+            FrameName::Script | FrameName::ModuleBundle | FrameName::MultiCall => (None, vec![]),
+        };
+        let (mut reads, mut writes, mut events, mut calls) = (vec![], vec![], vec![], vec![]);
+        for event in frame.events {
+            match event {
+                ExecutionGasEvent::Call(frame) => calls.push(frame.into()),
+                ExecutionGasEvent::CallNative {
+                    module_id,
+                    fn_name,
+                    ty_args,
+                    args,
+                    return_values,
+                    cost,
+                } => {
+                    match FrameEffect::of_native(&module_id, &fn_name) {
+                        Some(FrameEffect::Event) => events.push(FrameEventView {
+                            event_type: ty_args.first().cloned().map(Into::into),
+                            event_data: args.first().cloned().unwrap_or_default(),
+                        }),
+                        Some(effect) => {
+                            let access = StateAccessView {
+                                function: FunctionId::new(module_id.clone(), fn_name.clone())
+                                    .into(),
+                                ty_args: ty_args.iter().cloned().map(Into::into).collect(),
+                                args: args.clone(),
+                            };
+                            match effect {
+                                FrameEffect::Read => reads.push(access),
+                                _ => writes.push(access),
+                            }
+                        }
+                        None => {}
+                    }
+                    calls.push(CallFrameView::new_native(
+                        module_id,
+                        &fn_name,
+                        ty_args,
+                        args,
+                        return_values,
+                        u64::from(cost),
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Self {
+            function: function.map(Into::into),
+            ty_args: ty_args.into_iter().map(Into::into).collect(),
+            args: frame.args,
+            return_values: vec![],
+            is_native: false,
+            gas_used: gas_used.into(),
+            reads,
+            writes,
+            events,
+            calls,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayTransactionResponseView {
+    pub tx_order: StrView<u64>,
+    /// The state root before the transaction, the transaction is replayed on it
+    pub pre_state_root: H256View,
+    /// The execution info recorded when the transaction was executed
+    pub execution_info: TransactionExecutionInfoView,
+    pub raw_output: RawTransactionOutputView,
+    pub changeset: StateChangeSetView,
+    pub events: Vec<TransactionEventView>,
+    pub vm_error_info: Option<VMErrorInfo>,
+    /// The call tree of the execution, only returned by the trace
+    pub trace: Option<CallFrameView>,
+}
@@ -115,6 +115,20 @@ impl LedgerTransactionView {
     }
 }
 
+/// The transaction waiting in the tx pool to be sequenced
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PendingTransactionView {
+    pub tx_hash: H256View,
+    pub transaction: TransactionView,
+    /// The fee priority of the transaction in the tx pool
+    pub priority: StrView<u64>,
+    /// The timestamp in milliseconds when the transaction is received by the tx pool
+    pub received_at: StrView<u64>,
+    /// Whether the transaction is ready to be sequenced, otherwise it waits for the
+    /// transactions with the previous sequence numbers
+    pub ready: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TransactionWithInfoView {
     pub transaction: LedgerTransactionView,
@@ -21,6 +21,7 @@ use moveos_types::{
     moveos_std::tx_context::TxContext, transaction::FunctionCall,
 };
 use rooch_client::RoochRpcClient;
+use smt::NodeReader;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::runtime::Handle;
@@ -231,6 +232,24 @@ impl StatelessResolver for ClientResolver {
     }
 }
 
+/// The ClientResolver reads the state tree nodes from the remote node,
+/// it is used by the forked node to load the nodes missing locally.
+impl NodeReader for ClientResolver {
+    fn get(&self, hash: &H256) -> Result<Option<Vec<u8>>> {
+        tokio::task::block_in_place(|| {
+            Handle::current().block_on(async {
+                Ok(self
+                    .client
+                    .rooch
+                    .get_state_nodes(vec![*hash])
+                    .await?
+                    .pop()
+                    .flatten())
+            })
+        })
+    }
+}
+
 impl StateResolver for ClientResolver {
     fn root(&self) -> &ObjectMeta {
         &self.root
@@ -13,12 +13,14 @@ use moveos_types::state::{FieldKey, MoveStructState};
 use moveos_types::{access_path::AccessPath, state::ObjectState, transaction::FunctionCall};
 use rooch_rpc_api::api::btc_api::BtcAPIClient;
 use rooch_rpc_api::api::rooch_api::RoochAPIClient;
+use rooch_rpc_api::jsonrpc_types::backup_view::BackupManifestView;
 use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionObjectView};
+use rooch_rpc_api::jsonrpc_types::btc::runes::RuneBalancesFilterView;
 use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOObjectView};
 use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView, transaction_view::TransactionWithInfoView, InscriptionPageView,
-    Status, UTXOPageView,
+    RuneBalancesPageView, Status, UTXOPageView,
 };
 use rooch_rpc_api::jsonrpc_types::{
     AccessPathView, AnnotatedFunctionResultView, BalanceInfoPageView, BytesView, EventOptions,
@@ -34,6 +36,8 @@ use rooch_types::address::BitcoinAddress;
 use rooch_types::bitcoin::multisign_account::MultisignAccountInfo;
 use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
 use rooch_types::indexer::state::IndexerStateID;
+use rooch_types::proof::{StateProof, TransactionProof};
+use rooch_types::transaction::CommittedTransaction;
 use rooch_types::{address::RoochAddress, transaction::rooch::RoochTransaction};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -67,6 +71,21 @@ impl RoochRpcClient {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    pub async fn send_raw_transaction(&self, tx_payload: Vec<u8>) -> Result<H256> {
+        Ok(self.http.send_raw_transaction(tx_payload.into()).await?.0)
+    }
+
+    pub async fn execute_raw_transaction(
+        &self,
+        tx_payload: Vec<u8>,
+        tx_option: Option<TxOptions>,
+    ) -> Result<ExecuteTransactionResponseView> {
+        Ok(self
+            .http
+            .execute_raw_transaction(tx_payload.into(), tx_option)
+            .await?)
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
@@ -118,6 +137,96 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn get_state_nodes(&self, node_hashes: Vec<H256>) -> Result<Vec<Option<Vec<u8>>>> {
+        Ok(self
+            .http
+            .get_state_nodes(node_hashes.into_iter().map(Into::into).collect())
+            .await?
+            .into_iter()
+            .map(|node| node.map(|bytes| bytes.0))
+            .collect())
+    }
+
+    /// Get the committed transactions after the exclusive cursor, used by the follower node
+    pub async fn get_committed_transactions(
+        &self,
+        cursor: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<CommittedTransaction>> {
+        self.http
+            .get_committed_transactions(cursor.map(Into::into), limit.map(Into::into))
+            .await?
+            .into_iter()
+            .map(|bytes| bcs::from_bytes(&bytes.0).map_err(Into::into))
+            .collect()
+    }
+
+    pub async fn get_state_proofs(
+        &self,
+        access_path: AccessPath,
+        state_root: Option<H256>,
+    ) -> Result<Vec<StateProof>> {
+        let proofs = self
+            .http
+            .get_state_proof(access_path.into(), state_root.map(Into::into))
+            .await?;
+        Ok(proofs.into_iter().map(StateProof::from).collect())
+    }
+
+    /// Get the states by access_path, and verify them with the trusted state root.
+    pub async fn get_verified_states(
+        &self,
+        access_path: AccessPath,
+        state_root: H256,
+    ) -> Result<Vec<Option<ObjectState>>> {
+        // the proofs must be for the fields of the access path, in the same order
+        let fields = access_path.clone().into_state_query().into_fields_query()?;
+        let proofs = self.get_state_proofs(access_path, Some(state_root)).await?;
+        if proofs.len() != fields.len() {
+            return Err(anyhow::anyhow!(
+                "State proofs length mismatch, expected: {}, actual: {}",
+                fields.len(),
+                proofs.len()
+            ));
+        }
+        proofs
+            .iter()
+            .zip(fields)
+            .map(|(proof, (object_id, field_key))| proof.verify(state_root, &object_id, field_key))
+            .collect()
+    }
+
+    pub async fn get_transaction_proof(
+        &self,
+        tx_order: u64,
+        accumulator_tx_order: Option<u64>,
+    ) -> Result<Option<TransactionProof>> {
+        let proof = self
+            .http
+            .get_transaction_proof(tx_order.into(), accumulator_tx_order.map(Into::into))
+            .await?;
+        Ok(proof.map(TransactionProof::from))
+    }
+
+    /// Verify the transaction with tx_hash at tx_order is included in the tx accumulator with the trusted root,
+    /// the root is the tx accumulator root after the transaction at accumulator_tx_order, returns the verified proof.
+    pub async fn verify_transaction_inclusion(
+        &self,
+        tx_order: u64,
+        tx_hash: H256,
+        accumulator_tx_order: Option<u64>,
+        accumulator_root: H256,
+    ) -> Result<TransactionProof> {
+        let proof = self
+            .get_transaction_proof(tx_order, accumulator_tx_order)
+            .await?
+            .ok_or_else(|| {
+                anyhow::anyhow!("Transaction proof not found, tx_order: {}", tx_order)
+            })?;
+        proof.verify(accumulator_root, tx_order, tx_hash)?;
+        Ok(proof)
+    }
+
     pub async fn get_transactions_by_order(
         &self,
         cursor: Option<u64>,
@@ -367,6 +476,24 @@ impl RoochRpcClient {
             .await?)
     }
 
+    pub async fn query_rune_balances(
+        &self,
+        filter: RuneBalancesFilterView,
+        cursor: Option<IndexerStateID>,
+        limit: Option<u64>,
+        descending_order: Option<bool>,
+    ) -> Result<RuneBalancesPageView> {
+        Ok(self
+            .http
+            .query_rune_balances(
+                filter,
+                cursor.map(Into::into),
+                limit.map(Into::into),
+                descending_order,
+            )
+            .await?)
+    }
+
     pub async fn get_resource<T: MoveStructState>(
         &self,
         account: RoochAddress,
@@ -430,4 +557,8 @@ impl RoochRpcClient {
     pub async fn status(&self) -> Result<Status> {
         Ok(self.http.status().await?)
     }
+
+    pub async fn backup_db(&self, name: Option<String>) -> Result<BackupManifestView> {
+        Ok(self.http.backup_db(name).await?)
+    }
 }
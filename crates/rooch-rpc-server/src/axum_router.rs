@@ -22,6 +22,14 @@ pub const MAX_RESPONSE_SIZE: u32 = 2 << 30;
 pub const NOT_SUPPORTED_CODE: i32 = 32005;
 pub const NOT_SUPPORTED_MSG: &str = "Requests are not supported by this server";
 
+pub const ADMIN_ONLY_CODE: i32 = 32006;
+pub const ADMIN_ONLY_MSG: &str =
+    "The method is only served over HTTP to the clients on the local host of this server";
+
+/// The admin methods are only served over HTTP to the clients on the local host,
+/// the requests forwarded by a proxy are rejected even if the proxy runs on the local host.
+pub const ADMIN_METHODS: &[&str] = &["rooch_backupDB"];
+
 #[derive(Debug, Clone)]
 pub(crate) struct CallData<'a> {
     methods: &'a Methods,
@@ -120,13 +128,30 @@ pub async fn json_rpc_handler(
     ok_response(response.to_result())
 }
 
+fn is_admin_method(method: &str) -> bool {
+    ADMIN_METHODS.contains(&method)
+}
+
+fn is_local_client(client_addr: SocketAddr, headers: &HeaderMap) -> bool {
+    client_addr.ip().is_loopback()
+        && ["forwarded", "x-forwarded-for", "x-real-ip"]
+            .iter()
+            .all(|header| !headers.contains_key(*header))
+}
+
 async fn process_raw_request(
     service: &JsonRpcService,
     raw_request: &str,
-    _: SocketAddr,
-    _: HeaderMap,
+    client_addr: SocketAddr,
+    headers: HeaderMap,
 ) -> MethodResponse {
     if let Ok(request) = serde_json::from_str::<Request>(raw_request) {
+        if is_admin_method(request.method.as_ref()) && !is_local_client(client_addr, &headers) {
+            return MethodResponse::error(
+                request.id,
+                ErrorObject::borrowed(ADMIN_ONLY_CODE, ADMIN_ONLY_MSG, None),
+            );
+        }
         let response: MethodResponse = process_request(request, service.call_data()).await;
 
         response
@@ -312,6 +337,12 @@ pub mod ws {
         sink: &MethodSink,
     ) -> Option<MethodResponse> {
         if let Ok(request) = serde_json::from_str::<Request>(raw_request) {
+            if is_admin_method(request.method.as_ref()) {
+                return Some(MethodResponse::error(
+                    request.id,
+                    ErrorObject::borrowed(ADMIN_ONLY_CODE, ADMIN_ONLY_MSG, None),
+                ));
+            }
             process_request(request, service.ws_call_data(bounded_subscriptions, sink)).await
         } else if let Ok(_batch) = serde_json::from_str::<Vec<&RawValue>>(raw_request) {
             Some(MethodResponse::error(
@@ -0,0 +1,114 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use moveos_types::fork_info::ForkInfo;
+use moveos_types::startup_info::StartupInfo;
+use rooch_config::fork_config::ForkConfig;
+use rooch_db::RoochDB;
+use rooch_rpc_client::{ClientBuilder, ClientResolver};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// The fork of a remote node, the remote state is loaded lazily by the state node store,
+/// and the local transactions are applied on top of the fork point.
+pub(crate) struct Fork {
+    pub info: ForkInfo,
+    /// Whether the store is forked for the first time
+    pub is_new: bool,
+}
+
+impl Fork {
+    /// Load the fork from the store, or fork the remote node if the fork url is given.
+    /// The fork url is saved in the store, so the forked node can be restarted without it.
+    pub async fn load_or_init(
+        fork_config: &ForkConfig,
+        chain_id: u64,
+        rooch_db: &RoochDB,
+    ) -> Result<Option<Self>> {
+        let saved_fork_info = rooch_db.moveos_store.get_config_store().get_fork_info()?;
+        let fork_url = match (&fork_config.fork_url, &saved_fork_info) {
+            (Some(fork_url), _) => fork_url.clone(),
+            (None, Some(fork_info)) => fork_info.fork_url.clone(),
+            (None, None) => return Ok(None),
+        };
+
+        let client = ClientBuilder::default().build(&fork_url).await?;
+        let remote_chain_id = client.rooch.get_chain_id().await?;
+        ensure!(
+            remote_chain_id == chain_id,
+            "The chain id {} of the remote node is not equal to the chain id {} of the local node",
+            remote_chain_id,
+            chain_id
+        );
+
+        let fork = match saved_fork_info {
+            Some(fork_info) => {
+                if let Some(fork_state_root) = fork_config.fork_state_root {
+                    ensure!(
+                        fork_state_root == fork_info.state_root,
+                        "The store is forked from the state root {:?}, can not fork from {:?}, please use a new data dir",
+                        fork_info.state_root,
+                        fork_state_root
+                    );
+                }
+                Fork {
+                    info: ForkInfo::new(fork_url, fork_info.state_root, fork_info.size),
+                    is_new: false,
+                }
+            }
+            None => {
+                ensure!(
+                    rooch_db.latest_root()?.is_none(),
+                    "The store is not empty, please use a new data dir to fork the remote node"
+                );
+                let root_state = client.rooch.status().await?.rooch_status.root_state;
+                let (state_root, size) = match fork_config.fork_state_root {
+                    Some(fork_state_root) if fork_state_root != root_state.state_root.0 => {
+                        warn!(
+                            "The size of the historical state root {:?} is unknown, the global size counts from 0",
+                            fork_state_root
+                        );
+                        (fork_state_root, 0)
+                    }
+                    _ => (root_state.state_root.0, root_state.size.0),
+                };
+                ensure!(
+                    client
+                        .rooch
+                        .get_state_nodes(vec![state_root])
+                        .await?
+                        .pop()
+                        .flatten()
+                        .is_some(),
+                    "The state root {:?} is not found in the remote node, it may be pruned",
+                    state_root
+                );
+                Fork {
+                    info: ForkInfo::new(fork_url, state_root, size),
+                    is_new: true,
+                }
+            }
+        };
+
+        let resolver = ClientResolver::new(client, fork.info.root_metadata());
+        rooch_db
+            .moveos_store
+            .get_state_node_store()
+            .set_remote_node_reader(Arc::new(resolver))?;
+        info!("Fork the remote node: {}", fork.info);
+        Ok(Some(fork))
+    }
+
+    /// Start the local state from the fork point after the genesis is initialized,
+    /// the local genesis only initializes the ledger and the indexer.
+    pub fn init_state(&self, rooch_db: &RoochDB) -> Result<()> {
+        let config_store = rooch_db.moveos_store.get_config_store();
+        if self.is_new {
+            config_store
+                .save_startup_info(StartupInfo::new(self.info.state_root, self.info.size))?;
+        }
+        // the fork url may be changed
+        config_store.save_fork_info(self.info.clone())
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! The GraphQL query service over the indexer and the state.
+//!
+//! The schema is served by the same axum router as the JSON-RPC service at `/graphql`,
+//! the resolvers reuse the `RpcService` and the JSON-RPC view types, so one query can fetch
+//! an account with its balances, objects and transactions in a single round trip.
+
+use crate::service::aggregate_service::AggregateService;
+use crate::service::rpc_service::RpcService;
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{EmptyMutation, EmptySubscription, Schema, SchemaBuilder};
+use axum::{Extension, Json};
+use query::QueryRoot;
+use types::TransactionLoader;
+
+pub mod query;
+pub mod types;
+
+pub const GRAPHQL_PATH: &str = "/graphql";
+
+/// The max nesting depth of a query, it bounds the fan out of the nested resolvers.
+pub const MAX_QUERY_DEPTH: usize = 8;
+/// The max complexity of a query, each field counts 1 by default,
+/// and the items of a page count `limit` times, see `types::page_complexity`.
+pub const MAX_QUERY_COMPLEXITY: usize = 4096;
+
+pub type RoochSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+fn schema_builder() -> SchemaBuilder<QueryRoot, EmptyMutation, EmptySubscription> {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+}
+
+pub fn build_schema(rpc_service: RpcService, aggregate_service: AggregateService) -> RoochSchema {
+    schema_builder()
+        .data(DataLoader::new(
+            TransactionLoader::new(aggregate_service.clone()),
+            tokio::spawn,
+        ))
+        .data(rpc_service)
+        .data(aggregate_service)
+        .finish()
+}
+
+pub async fn graphql_handler(
+    Extension(schema): Extension<RoochSchema>,
+    Json(request): Json<async_graphql::Request>,
+) -> Json<async_graphql::Response> {
+    Json(schema.execute(request).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn execute(query: &str) -> async_graphql::Response {
+        schema_builder().finish().execute(query).await
+    }
+
+    fn is_too_complex(response: &async_graphql::Response) -> bool {
+        response
+            .errors
+            .iter()
+            .any(|error| error.message.contains("too complex"))
+    }
+
+    #[tokio::test]
+    async fn test_page_limit_counts_in_complexity() {
+        let small_page = r#"{ transactions(filter: {tx_order_range: {from_order: "0", to_order: "10"}}, limit: 10) { data { txOrder } hasNextPage } }"#;
+        assert!(!is_too_complex(&execute(small_page).await));
+
+        // 200 transactions with 200 events each
+        let nested_pages = r#"{
+            transactions(filter: {tx_order_range: {from_order: "0", to_order: "10"}}, limit: 200) {
+                data { events(limit: 200) { data { txHash } } }
+            }
+        }"#;
+        assert!(is_too_complex(&execute(nested_pages).await));
+
+        // the default limit is counted if the limit is not given
+        let default_nested_pages = r#"{
+            account(address: "0x1") {
+                objects { data { fields { data { fieldKey } } } }
+            }
+        }"#;
+        assert!(is_too_complex(&execute(default_nested_pages).await));
+    }
+
+    #[tokio::test]
+    async fn test_query_depth_is_limited() {
+        let deep_query = r#"{
+            account(address: "0x1") {
+                objects(limit: 1) { data { ownerAccount { objects(limit: 1) { data {
+                    ownerAccount { objects(limit: 1) { data { id } } }
+                } } } } }
+            }
+        }"#;
+        let response = execute(deep_query).await;
+        assert!(response
+            .errors
+            .iter()
+            .any(|error| error.message.contains("nested too deep")));
+    }
+}
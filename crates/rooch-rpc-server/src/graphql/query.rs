@@ -0,0 +1,205 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::graphql::types::{
+    page_complexity, query_events, query_fields, query_inscriptions, query_object_states,
+    query_transactions, query_utxos, Account, Event, Field, Inscription, ObjectState, Page,
+    Transaction, TransactionLoader, UTXO,
+};
+use async_graphql::dataloader::DataLoader;
+use async_graphql::{Context, Json, Object, Result};
+use moveos_types::h256::H256;
+use rooch_rpc_api::jsonrpc_types::btc::ord::InscriptionFilterView;
+use rooch_rpc_api::jsonrpc_types::btc::utxo::UTXOFilterView;
+use rooch_rpc_api::jsonrpc_types::event_view::{EventFilterView, IndexerEventIDView};
+use rooch_rpc_api::jsonrpc_types::field_view::FieldFilterView;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionFilterView;
+use rooch_rpc_api::jsonrpc_types::{
+    IndexerStateIDView, ObjectStateFilterView, QueryOptions, StrView, UnitedAddressView,
+};
+use rooch_types::indexer::state::ObjectStateType;
+use std::str::FromStr;
+
+/// The root of the GraphQL queries, the filters and the cursors are the same as the JSON-RPC `query*` methods.
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Get an account by rooch address, bitcoin address or nostr public key
+    async fn account(&self, address: String) -> Result<Account> {
+        Ok(Account::new(UnitedAddressView::from_str(&address)?))
+    }
+
+    async fn transaction(&self, ctx: &Context<'_>, tx_hash: String) -> Result<Option<Transaction>> {
+        let tx_hash = H256::from_str(&tx_hash)?;
+        let tx = ctx
+            .data::<DataLoader<TransactionLoader>>()?
+            .load_one(tx_hash)
+            .await?
+            .map(Transaction);
+        Ok(tx)
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        filter: Json<TransactionFilterView>,
+        cursor: Option<Json<StrView<u64>>>,
+        limit: Option<u64>,
+        options: Option<Json<QueryOptions>>,
+    ) -> Result<Page<Transaction, StrView<u64>>> {
+        let query_option = options.map(|o| o.0).unwrap_or_default();
+        query_transactions(
+            ctx,
+            filter.0.into(),
+            cursor.map(|c| c.0 .0),
+            limit,
+            query_option.descending,
+        )
+        .await
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        filter: Json<EventFilterView>,
+        cursor: Option<Json<IndexerEventIDView>>,
+        limit: Option<u64>,
+        options: Option<Json<QueryOptions>>,
+    ) -> Result<Page<Event, IndexerEventIDView>> {
+        query_events(
+            ctx,
+            filter.0.into(),
+            cursor.map(|c| c.0),
+            limit,
+            options.map(|o| o.0).unwrap_or_default(),
+        )
+        .await
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn object_states(
+        &self,
+        ctx: &Context<'_>,
+        filter: Json<ObjectStateFilterView>,
+        cursor: Option<Json<IndexerStateIDView>>,
+        limit: Option<u64>,
+        options: Option<Json<QueryOptions>>,
+    ) -> Result<Page<ObjectState, IndexerStateIDView>> {
+        let query_option = options.map(|o| o.0).unwrap_or_default();
+        let filter =
+            ObjectStateFilterView::try_into_object_state_filter(filter.0, query_option.clone())?;
+        let (data, next_cursor, has_next_page) = query_object_states(
+            ctx,
+            filter,
+            cursor.map(|c| c.0),
+            limit,
+            query_option,
+            ObjectStateType::ObjectState,
+        )
+        .await?;
+        Ok(Page {
+            data: data.into_iter().map(ObjectState).collect(),
+            next_cursor: next_cursor.map(Json),
+            has_next_page,
+        })
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn fields(
+        &self,
+        ctx: &Context<'_>,
+        filter: Json<FieldFilterView>,
+        page: Option<u64>,
+        limit: Option<u64>,
+        options: Option<Json<QueryOptions>>,
+    ) -> Result<Page<Field, StrView<u64>>> {
+        query_fields(
+            ctx,
+            filter.0.into(),
+            page,
+            limit,
+            options.map(|o| o.0).unwrap_or_default(),
+        )
+        .await
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn utxos(
+        &self,
+        ctx: &Context<'_>,
+        filter: Json<UTXOFilterView>,
+        cursor: Option<Json<IndexerStateIDView>>,
+        limit: Option<u64>,
+        descending: Option<bool>,
+    ) -> Result<Page<UTXO, IndexerStateIDView>> {
+        query_utxos(
+            ctx,
+            filter.0,
+            cursor.map(|c| c.0),
+            limit,
+            descending.unwrap_or(true),
+        )
+        .await
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn inscriptions(
+        &self,
+        ctx: &Context<'_>,
+        filter: Json<InscriptionFilterView>,
+        cursor: Option<Json<IndexerStateIDView>>,
+        limit: Option<u64>,
+        descending: Option<bool>,
+    ) -> Result<Page<Inscription, IndexerStateIDView>> {
+        query_inscriptions(
+            ctx,
+            filter.0,
+            cursor.map(|c| c.0),
+            limit,
+            descending.unwrap_or(true),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::{EmptyMutation, EmptySubscription, Schema, Value};
+    use rooch_types::address::{RoochAddress, RoochSupportedAddress};
+
+    async fn execute(query: &str) -> async_graphql::Response {
+        Schema::new(QueryRoot, EmptyMutation, EmptySubscription)
+            .execute(query)
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_account() {
+        let address = RoochAddress::random();
+        let response = execute(&format!(
+            r#"{{ account(address: "{}") {{ address bitcoinAddress }} }}"#,
+            address.to_hex_literal()
+        ))
+        .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        assert_eq!(data["account"]["address"], address.to_string());
+        assert_eq!(data["account"]["bitcoinAddress"], serde_json::Value::Null);
+
+        let response = execute(r#"{ account(address: "invalid") { address } }"#).await;
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(response.data, Value::Null);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_with_invalid_hash() {
+        let response = execute(r#"{ transaction(txHash: "0x1234") { txOrder } }"#).await;
+        assert_eq!(response.errors.len(), 1);
+        // the hash is checked before loading the transaction
+        assert!(!response.errors[0].message.contains("TransactionLoader"));
+    }
+}
@@ -0,0 +1,699 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::service::aggregate_service::AggregateService;
+use crate::service::rpc_service::RpcService;
+use async_graphql::dataloader::{DataLoader, Loader};
+use async_graphql::{Context, Json, Object, OutputType, Result, SimpleObject};
+use moveos_types::h256::H256;
+use rooch_rpc_api::api::{DEFAULT_RESULT_LIMIT_USIZE, MAX_RESULT_LIMIT_USIZE};
+use rooch_rpc_api::jsonrpc_types::account_view::BalanceInfoView;
+use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionStateView};
+use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOStateView};
+use rooch_rpc_api::jsonrpc_types::event_view::{IndexerEventIDView, IndexerEventView};
+use rooch_rpc_api::jsonrpc_types::field_view::IndexerFieldView;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionWithInfoView;
+use rooch_rpc_api::jsonrpc_types::{
+    IndexerObjectStateView, IndexerStateIDView, QueryOptions, StrView, UnitedAddressView,
+};
+use rooch_types::indexer::event::EventFilter;
+use rooch_types::indexer::field::FieldFilter;
+use rooch_types::indexer::state::{IndexerStateID, ObjectStateFilter, ObjectStateType};
+use rooch_types::indexer::transaction::TransactionFilter;
+use serde::Serialize;
+use std::cmp::{max, min};
+use std::collections::HashMap;
+
+/// A page of the query result, the `next_cursor` is the same cursor as the JSON-RPC `query*` methods.
+#[derive(SimpleObject)]
+#[graphql(concrete(name = "TransactionPage", params(Transaction, StrView<u64>)))]
+#[graphql(concrete(name = "EventPage", params(Event, IndexerEventIDView)))]
+#[graphql(concrete(name = "ObjectStatePage", params(ObjectState, IndexerStateIDView)))]
+#[graphql(concrete(name = "FieldPage", params(Field, StrView<u64>)))]
+#[graphql(concrete(name = "UTXOPage", params(UTXO, IndexerStateIDView)))]
+#[graphql(concrete(name = "InscriptionPage", params(Inscription, IndexerStateIDView)))]
+#[graphql(concrete(name = "BalancePage", params(Balance, IndexerStateIDView)))]
+pub struct Page<T: OutputType, C: Serialize + Send + Sync> {
+    pub data: Vec<T>,
+    pub next_cursor: Option<Json<C>>,
+    pub has_next_page: bool,
+}
+
+pub(crate) fn limit_of(limit: Option<u64>) -> usize {
+    min(
+        limit
+            .map(|l| l as usize)
+            .unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+        MAX_RESULT_LIMIT_USIZE,
+    )
+}
+
+/// The complexity of a page field, the items of the page count `limit` times.
+pub(crate) fn page_complexity(limit: Option<u64>, child_complexity: usize) -> usize {
+    limit_of(limit).saturating_mul(child_complexity)
+}
+
+/// TransactionLoader loads the transactions of the nested fields by hash in one batch,
+/// so the transactions of a page of events are not fetched one by one.
+pub struct TransactionLoader {
+    aggregate_service: AggregateService,
+}
+
+impl TransactionLoader {
+    pub fn new(aggregate_service: AggregateService) -> Self {
+        Self { aggregate_service }
+    }
+}
+
+impl Loader<H256> for TransactionLoader {
+    type Value = TransactionWithInfoView;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[H256]) -> Result<HashMap<H256, Self::Value>, Self::Error> {
+        let (tx_hashes, txs): (Vec<_>, Vec<_>) = keys
+            .iter()
+            .cloned()
+            .zip(
+                self.aggregate_service
+                    .get_transaction_with_info(keys.to_vec())
+                    .await?,
+            )
+            .filter_map(|(tx_hash, tx)| tx.map(|tx| (tx_hash, tx)))
+            .unzip();
+        let txs = self.aggregate_service.transactions_to_view(txs).await?;
+        Ok(tx_hashes.into_iter().zip(txs).collect())
+    }
+}
+
+pub(crate) async fn query_transactions(
+    ctx: &Context<'_>,
+    filter: TransactionFilter,
+    cursor: Option<u64>,
+    limit: Option<u64>,
+    descending_order: bool,
+) -> Result<Page<Transaction, StrView<u64>>> {
+    let rpc_service = ctx.data::<RpcService>()?;
+    let aggregate_service = ctx.data::<AggregateService>()?;
+    let limit_of = limit_of(limit);
+
+    let txs = rpc_service
+        .query_transactions(filter, cursor, limit_of + 1, descending_order)
+        .await?;
+    let mut data = aggregate_service.build_transaction_with_infos(txs).await?;
+
+    let has_next_page = data.len() > limit_of;
+    data.truncate(limit_of);
+
+    let data = aggregate_service.transactions_to_view(data).await?;
+    let next_cursor = data
+        .last()
+        .map_or(cursor, |t| Some(t.transaction.sequence_info.tx_order.0));
+
+    Ok(Page {
+        data: data.into_iter().map(Transaction).collect(),
+        next_cursor: next_cursor.map(|c| Json(StrView(c))),
+        has_next_page,
+    })
+}
+
+pub(crate) async fn query_events(
+    ctx: &Context<'_>,
+    filter: EventFilter,
+    cursor: Option<IndexerEventIDView>,
+    limit: Option<u64>,
+    query_option: QueryOptions,
+) -> Result<Page<Event, IndexerEventIDView>> {
+    let rpc_service = ctx.data::<RpcService>()?;
+    let limit_of = limit_of(limit);
+    let descending_order = query_option.descending;
+
+    let mut data = if query_option.decode {
+        rpc_service
+            .query_annotated_events(
+                filter,
+                cursor.clone().map(Into::into),
+                limit_of + 1,
+                descending_order,
+            )
+            .await?
+            .into_iter()
+            .map(IndexerEventView::from)
+            .collect::<Vec<_>>()
+    } else {
+        rpc_service
+            .query_events(
+                filter,
+                cursor.clone().map(Into::into),
+                limit_of + 1,
+                descending_order,
+            )
+            .await?
+            .into_iter()
+            .map(IndexerEventView::from)
+            .collect::<Vec<_>>()
+    };
+
+    let has_next_page = data.len() > limit_of;
+    data.truncate(limit_of);
+    let next_cursor = data
+        .last()
+        .map_or(cursor, |e| Some(e.indexer_event_id.clone()));
+
+    Ok(Page {
+        data: data.into_iter().map(Event).collect(),
+        next_cursor: next_cursor.map(Json),
+        has_next_page,
+    })
+}
+
+pub(crate) async fn query_object_states(
+    ctx: &Context<'_>,
+    filter: ObjectStateFilter,
+    cursor: Option<IndexerStateIDView>,
+    limit: Option<u64>,
+    query_option: QueryOptions,
+    state_type: ObjectStateType,
+) -> Result<(
+    Vec<IndexerObjectStateView>,
+    Option<IndexerStateIDView>,
+    bool,
+)> {
+    let rpc_service = ctx.data::<RpcService>()?;
+    let limit_of = limit_of(limit);
+
+    let mut object_states = rpc_service
+        .query_object_states(
+            filter,
+            cursor.clone().map(Into::into),
+            limit_of + 1,
+            query_option.descending,
+            query_option.decode,
+            query_option.show_display,
+            state_type,
+        )
+        .await?;
+
+    let has_next_page = object_states.len() > limit_of;
+    object_states.truncate(limit_of);
+    let next_cursor = object_states
+        .last()
+        .map_or(cursor, |t| Some(t.indexer_id.clone()));
+
+    Ok((object_states, next_cursor, has_next_page))
+}
+
+pub(crate) async fn query_fields(
+    ctx: &Context<'_>,
+    filter: FieldFilter,
+    page: Option<u64>,
+    limit: Option<u64>,
+    query_option: QueryOptions,
+) -> Result<Page<Field, StrView<u64>>> {
+    let rpc_service = ctx.data::<RpcService>()?;
+    let page_of = max(page.unwrap_or(1), 1u64);
+    let limit_of = limit_of(limit);
+
+    let (fields, mut fields_view) = rpc_service
+        .query_fields(
+            filter,
+            page_of,
+            limit_of + 1,
+            query_option.descending,
+            query_option.decode,
+        )
+        .await?;
+
+    let has_next_page = fields.len() > limit_of;
+    // Solve the pagation consistency problem after indexer data filtering
+    if fields_view.len() >= fields.len() {
+        fields_view.truncate(limit_of);
+    }
+    let next_page = if has_next_page {
+        page_of
+            .checked_add(1)
+            .ok_or("next page value is overflow")?
+    } else {
+        page_of
+    };
+
+    Ok(Page {
+        data: fields_view.into_iter().map(Field).collect(),
+        next_cursor: Some(Json(StrView(next_page))),
+        has_next_page,
+    })
+}
+
+pub struct Transaction(pub TransactionWithInfoView);
+
+#[Object]
+impl Transaction {
+    async fn tx_order(&self) -> String {
+        self.0.transaction.sequence_info.tx_order.to_string()
+    }
+
+    async fn tx_hash(&self) -> Option<String> {
+        self.0
+            .execution_info
+            .as_ref()
+            .map(|info| info.tx_hash.to_string())
+    }
+
+    async fn tx_timestamp(&self) -> String {
+        self.0.transaction.sequence_info.tx_timestamp.to_string()
+    }
+
+    /// The transaction with the execution info, the same as `rooch_getTransactionsByHash`
+    async fn data(&self) -> Json<TransactionWithInfoView> {
+        Json(self.0.clone())
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    /// The events emitted by the transaction
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<Json<IndexerEventIDView>>,
+        limit: Option<u64>,
+        options: Option<Json<QueryOptions>>,
+    ) -> Result<Page<Event, IndexerEventIDView>> {
+        let Some(execution_info) = &self.0.execution_info else {
+            return Ok(Page {
+                data: vec![],
+                next_cursor: None,
+                has_next_page: false,
+            });
+        };
+        query_events(
+            ctx,
+            EventFilter::TxHash(execution_info.tx_hash.0),
+            cursor.map(|c| c.0),
+            limit,
+            options.map(|o| o.0).unwrap_or_default(),
+        )
+        .await
+    }
+}
+
+pub struct Event(pub IndexerEventView);
+
+#[Object]
+impl Event {
+    async fn indexer_event_id(&self) -> Json<IndexerEventIDView> {
+        Json(self.0.indexer_event_id.clone())
+    }
+
+    async fn event_type(&self) -> String {
+        self.0.event_type.to_string()
+    }
+
+    async fn sender(&self) -> String {
+        self.0.sender.to_string()
+    }
+
+    async fn tx_hash(&self) -> String {
+        self.0.tx_hash.to_string()
+    }
+
+    async fn created_at(&self) -> String {
+        self.0.created_at.to_string()
+    }
+
+    /// The indexed event, the same as `rooch_queryEvents`
+    async fn data(&self) -> Json<IndexerEventView> {
+        Json(self.0.clone())
+    }
+
+    /// The transaction which emitted the event, the transactions of the events are loaded in one batch
+    async fn transaction(&self, ctx: &Context<'_>) -> Result<Option<Transaction>> {
+        let tx = ctx
+            .data::<DataLoader<TransactionLoader>>()?
+            .load_one(self.0.tx_hash.0)
+            .await?
+            .map(Transaction);
+        Ok(tx)
+    }
+}
+
+pub struct ObjectState(pub IndexerObjectStateView);
+
+#[Object]
+impl ObjectState {
+    async fn id(&self) -> String {
+        self.0.metadata.id.to_string()
+    }
+
+    async fn object_type(&self) -> String {
+        self.0.metadata.object_type.to_string()
+    }
+
+    async fn owner(&self) -> String {
+        self.0.metadata.owner.to_string()
+    }
+
+    async fn owner_bitcoin_address(&self) -> Option<&str> {
+        self.0.metadata.owner_bitcoin_address.as_deref()
+    }
+
+    /// The indexed object state, the same as `rooch_queryObjectStates`
+    async fn data(&self) -> Json<IndexerObjectStateView> {
+        Json(self.0.clone())
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    /// The dynamic fields of the object, paged by the page number
+    async fn fields(
+        &self,
+        ctx: &Context<'_>,
+        page: Option<u64>,
+        limit: Option<u64>,
+        options: Option<Json<QueryOptions>>,
+    ) -> Result<Page<Field, StrView<u64>>> {
+        query_fields(
+            ctx,
+            FieldFilter::ObjectId(vec![self.0.metadata.id.clone()]),
+            page,
+            limit,
+            options.map(|o| o.0).unwrap_or_default(),
+        )
+        .await
+    }
+
+    /// The account which owns the object
+    async fn owner_account(&self) -> Account {
+        Account::new(self.0.metadata.owner.clone().into())
+    }
+}
+
+pub struct Field(pub IndexerFieldView);
+
+#[Object]
+impl Field {
+    async fn id(&self) -> String {
+        self.0.state.metadata.id.to_string()
+    }
+
+    async fn field_key(&self) -> String {
+        self.0.field_key.to_string()
+    }
+
+    /// The indexed field, the same as `rooch_queryFields`
+    async fn data(&self) -> Json<IndexerFieldView> {
+        Json(self.0.clone())
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+pub struct UTXO(pub UTXOStateView);
+
+#[Object]
+impl UTXO {
+    async fn id(&self) -> String {
+        self.0.metadata.id.to_string()
+    }
+
+    async fn owner(&self) -> String {
+        self.0.metadata.owner.to_string()
+    }
+
+    async fn bitcoin_txid(&self) -> String {
+        self.0.value.bitcoin_txid.to_string()
+    }
+
+    async fn vout(&self) -> u32 {
+        self.0.value.vout
+    }
+
+    async fn value(&self) -> String {
+        self.0.value.value.to_string()
+    }
+
+    /// The indexed UTXO, the same as `btc_queryUTXOs`
+    async fn data(&self) -> Json<UTXOStateView> {
+        Json(self.0.clone())
+    }
+}
+
+pub struct Inscription(pub InscriptionStateView);
+
+#[Object]
+impl Inscription {
+    async fn id(&self) -> String {
+        self.0.metadata.id.to_string()
+    }
+
+    async fn owner(&self) -> String {
+        self.0.metadata.owner.to_string()
+    }
+
+    /// The indexed inscription, the same as `btc_queryInscriptions`
+    async fn data(&self) -> Json<InscriptionStateView> {
+        Json(self.0.clone())
+    }
+}
+
+pub struct Balance(pub BalanceInfoView);
+
+#[Object]
+impl Balance {
+    async fn coin_type(&self) -> String {
+        self.0.coin_info.coin_type.to_string()
+    }
+
+    async fn balance(&self) -> String {
+        self.0.balance.to_string()
+    }
+
+    /// The balance with the coin info, the same as `rooch_getBalances`
+    async fn data(&self) -> Json<BalanceInfoView> {
+        Json(self.0.clone())
+    }
+}
+
+/// An account, the entry of the nested queries by owner or sender
+pub struct Account {
+    address: UnitedAddressView,
+}
+
+impl Account {
+    pub fn new(address: UnitedAddressView) -> Self {
+        Self { address }
+    }
+}
+
+#[Object]
+impl Account {
+    async fn address(&self) -> String {
+        self.address.0.rooch_address.to_string()
+    }
+
+    async fn bitcoin_address(&self) -> Option<String> {
+        self.address
+            .0
+            .bitcoin_address
+            .as_ref()
+            .map(|addr| addr.to_string())
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn balances(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<Json<IndexerStateIDView>>,
+        limit: Option<u64>,
+    ) -> Result<Page<Balance, IndexerStateIDView>> {
+        let aggregate_service = ctx.data::<AggregateService>()?;
+        let limit_of = limit_of(limit);
+        let cursor: Option<IndexerStateID> = cursor.map(|c| c.0.into());
+
+        let mut data = aggregate_service
+            .get_balances(self.address.clone().into(), cursor.clone(), limit_of + 1)
+            .await?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data
+            .last()
+            .cloned()
+            .map_or(cursor, |(key, _balance_info)| key);
+
+        Ok(Page {
+            data: data
+                .into_iter()
+                .map(|(_, balance_info)| Balance(balance_info))
+                .collect(),
+            next_cursor: next_cursor.map(|c| Json(c.into())),
+            has_next_page,
+        })
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn objects(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<Json<IndexerStateIDView>>,
+        limit: Option<u64>,
+        options: Option<Json<QueryOptions>>,
+    ) -> Result<Page<ObjectState, IndexerStateIDView>> {
+        let (data, next_cursor, has_next_page) = query_object_states(
+            ctx,
+            ObjectStateFilter::Owner(self.address.clone().into()),
+            cursor.map(|c| c.0),
+            limit,
+            options.map(|o| o.0).unwrap_or_default(),
+            ObjectStateType::ObjectState,
+        )
+        .await?;
+        Ok(Page {
+            data: data.into_iter().map(ObjectState).collect(),
+            next_cursor: next_cursor.map(Json),
+            has_next_page,
+        })
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    /// The transactions sent by the account
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<Json<StrView<u64>>>,
+        limit: Option<u64>,
+        descending: Option<bool>,
+    ) -> Result<Page<Transaction, StrView<u64>>> {
+        query_transactions(
+            ctx,
+            TransactionFilter::Sender(self.address.0.rooch_address.into()),
+            cursor.map(|c| c.0 .0),
+            limit,
+            descending.unwrap_or(true),
+        )
+        .await
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn utxos(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<Json<IndexerStateIDView>>,
+        limit: Option<u64>,
+        descending: Option<bool>,
+    ) -> Result<Page<UTXO, IndexerStateIDView>> {
+        query_utxos(
+            ctx,
+            UTXOFilterView::Owner(self.address.clone()),
+            cursor.map(|c| c.0),
+            limit,
+            descending.unwrap_or(true),
+        )
+        .await
+    }
+
+    #[graphql(complexity = "page_complexity(limit, child_complexity)")]
+    async fn inscriptions(
+        &self,
+        ctx: &Context<'_>,
+        cursor: Option<Json<IndexerStateIDView>>,
+        limit: Option<u64>,
+        descending: Option<bool>,
+    ) -> Result<Page<Inscription, IndexerStateIDView>> {
+        query_inscriptions(
+            ctx,
+            InscriptionFilterView::Owner(self.address.clone()),
+            cursor.map(|c| c.0),
+            limit,
+            descending.unwrap_or(true),
+        )
+        .await
+    }
+}
+
+pub(crate) async fn query_utxos(
+    ctx: &Context<'_>,
+    filter: UTXOFilterView,
+    cursor: Option<IndexerStateIDView>,
+    limit: Option<u64>,
+    descending_order: bool,
+) -> Result<Page<UTXO, IndexerStateIDView>> {
+    let (data, next_cursor, has_next_page) = query_object_states(
+        ctx,
+        UTXOFilterView::into_global_state_filter(filter)?,
+        cursor,
+        limit,
+        QueryOptions::default().descending(descending_order),
+        ObjectStateType::UTXO,
+    )
+    .await?;
+    let data = data
+        .into_iter()
+        .map(|state| UTXOStateView::try_from(state).map(UTXO))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(Page {
+        data,
+        next_cursor: next_cursor.map(Json),
+        has_next_page,
+    })
+}
+
+pub(crate) async fn query_inscriptions(
+    ctx: &Context<'_>,
+    filter: InscriptionFilterView,
+    cursor: Option<IndexerStateIDView>,
+    limit: Option<u64>,
+    descending_order: bool,
+) -> Result<Page<Inscription, IndexerStateIDView>> {
+    let (data, next_cursor, has_next_page) = query_object_states(
+        ctx,
+        InscriptionFilterView::into_global_state_filter(filter)?,
+        cursor,
+        limit,
+        QueryOptions::default().descending(descending_order),
+        ObjectStateType::Inscription,
+    )
+    .await?;
+    let data = data
+        .into_iter()
+        .map(|state| InscriptionStateView::try_from(state).map(Inscription))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(Page {
+        data,
+        next_cursor: next_cursor.map(Json),
+        has_next_page,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphql::query::QueryRoot;
+    use async_graphql::{EmptyMutation, EmptySubscription, Schema};
+
+    #[test]
+    fn test_limit_and_page_complexity() {
+        assert_eq!(limit_of(None), DEFAULT_RESULT_LIMIT_USIZE);
+        assert_eq!(limit_of(Some(1)), 1);
+        assert_eq!(limit_of(Some(u64::MAX)), MAX_RESULT_LIMIT_USIZE);
+
+        assert_eq!(page_complexity(Some(10), 3), 30);
+        assert_eq!(page_complexity(None, 2), DEFAULT_RESULT_LIMIT_USIZE * 2);
+        assert_eq!(
+            page_complexity(Some(u64::MAX), 2),
+            MAX_RESULT_LIMIT_USIZE * 2
+        );
+        assert_eq!(page_complexity(Some(2), usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn test_page_types() {
+        let sdl = Schema::new(QueryRoot, EmptyMutation, EmptySubscription).sdl();
+        for page in [
+            "TransactionPage",
+            "EventPage",
+            "ObjectStatePage",
+            "FieldPage",
+            "UTXOPage",
+            "InscriptionPage",
+            "BalancePage",
+        ] {
+            assert!(sdl.contains(&format!("type {} {{", page)), "{}", page);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::fork::Fork;
 use crate::metrics_server::{init_metrics, start_basic_prometheus_server};
 use crate::server::btc_server::BtcServer;
 use crate::server::rooch_server::RoochServer;
@@ -20,33 +21,52 @@ use moveos_eventbus::bus::EventBus;
 use raw_store::errors::RawStoreError;
 use rooch_config::da_config::derive_namespace_from_genesis;
 use rooch_config::server_config::ServerConfig;
-use rooch_config::settings::PROPOSER_CHECK_INTERVAL;
+use rooch_config::settings::{
+    DA_SYNC_INTERVAL, FOLLOWER_SYNC_INTERVAL, PROPOSER_CHECK_INTERVAL, PRUNER_INTERVAL,
+    TX_POOL_GC_INTERVAL,
+};
 use rooch_config::{RoochOpt, ServerOpt};
 use rooch_da::actor::server::DAServerActor;
+use rooch_da::backend::DABackends;
 use rooch_da::proxy::DAServerProxy;
 use rooch_db::RoochDB;
 use rooch_event::actor::EventActor;
+use rooch_event::event::TransactionIndexedEvent;
 use rooch_executor::actor::executor::ExecutorActor;
 use rooch_executor::actor::reader_executor::ReaderExecutorActor;
 use rooch_executor::proxy::ExecutorProxy;
+use rooch_executor::replayer::{TransactionReplayer, DEFAULT_MAX_CONCURRENT_REPLAYS};
 use rooch_genesis::RoochGenesis;
 use rooch_indexer::actor::indexer::IndexerActor;
 use rooch_indexer::actor::reader_indexer::IndexerReaderActor;
 use rooch_indexer::proxy::IndexerProxy;
+use rooch_mempool::actor::messages::TxPoolGcTick;
+use rooch_mempool::actor::tx_pool::TxPoolActor;
+use rooch_mempool::pool::TxPool;
+use rooch_mempool::proxy::TxPoolProxy;
+use rooch_pipeline_processor::actor::da_sync::DASyncActor;
+use rooch_pipeline_processor::actor::follower::FollowerActor;
+use rooch_pipeline_processor::actor::load_tx_anomalies;
+use rooch_pipeline_processor::actor::messages::{DASyncTick, FollowerSyncTick};
 use rooch_pipeline_processor::actor::processor::PipelineProcessorActor;
 use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
 use rooch_proposer::actor::messages::ProposeBlock;
 use rooch_proposer::actor::proposer::ProposerActor;
+use rooch_pruner::actor::messages::PruneTick;
+use rooch_pruner::actor::pruner::PrunerActor;
+use rooch_pruner::pruner::StatePruner;
 use rooch_relayer::actor::messages::RelayTick;
 use rooch_relayer::actor::relayer::RelayerActor;
 use rooch_rpc_api::api::RoochRpcModule;
 use rooch_rpc_api::RpcError;
+use rooch_rpc_client::ClientBuilder;
 use rooch_sequencer::actor::sequencer::SequencerActor;
 use rooch_sequencer::proxy::SequencerProxy;
 use rooch_store::da_store::DAMetaStore;
 use rooch_types::address::RoochAddress;
 use rooch_types::error::{GenesisError, RoochError};
 use rooch_types::rooch_network::BuiltinChainID;
+use rooch_types::service_status::ServiceStatus;
 use rooch_types::service_type::ServiceType;
 use serde_json::json;
 use std::fmt::Debug;
@@ -64,6 +84,8 @@ use tower_http::trace::TraceLayer;
 use tracing::{error, info};
 
 mod axum_router;
+mod fork;
+pub mod graphql;
 pub mod metrics_server;
 pub mod server;
 pub mod service;
@@ -71,6 +93,9 @@ pub mod service;
 /// This exit code means is that the server failed to start and required human intervention.
 static R_EXIT_CODE_NEED_HELP: i32 = 120;
 
+/// The buffered indexed transactions for the RPC subscriptions, the lagged subscriptions are closed.
+const SUBSCRIPTION_CHANNEL_CAPACITY: usize = 1024;
+
 pub struct ServerHandle {
     shutdown_tx: Sender<()>,
     timers: Vec<Timer>,
@@ -202,6 +227,7 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
 
     // Init store
     let store_config = opt.store_config();
+    let backup_dir = store_config.get_backup_dir();
 
     let rooch_db = RoochDB::init(store_config, &prometheus_registry)?;
     let (rooch_store, moveos_store, indexer_store, indexer_reader) = (
@@ -222,16 +248,50 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     let sequencer_account = sequencer_keypair.public().rooch_address()?;
     let sequencer_bitcoin_address = sequencer_keypair.public().bitcoin_address()?;
 
-    let service_status = opt.service_status;
-
     let mut network = opt.network();
+    let fork = Fork::load_or_init(opt.fork_config(), network.chain_id.id(), &rooch_db).await?;
+
+    // The follower node applies the store changes of the leader node, it always runs in read-only mode
+    let follower_config = opt.follower_config();
+    let leader = match &follower_config.leader_url {
+        Some(leader_url) => {
+            ensure!(
+                opt.service_status.is_active() || opt.service_status.is_read_only_mode(),
+                "The follower node runs in read-only mode, service status {:?} is not supported",
+                opt.service_status
+            );
+            ensure!(
+                fork.is_none(),
+                "The forked node can not follow a leader node"
+            );
+            let client = ClientBuilder::default().build(leader_url).await?;
+            let leader_chain_id = client.rooch.get_chain_id().await?;
+            ensure!(
+                leader_chain_id == network.chain_id.id(),
+                "The chain id {} of the leader node is not equal to the chain id {} of the local node",
+                leader_chain_id,
+                network.chain_id.id()
+            );
+            Some(client)
+        }
+        None => None,
+    };
+    let service_status = if leader.is_some() {
+        ServiceStatus::ReadOnlyMode
+    } else {
+        opt.service_status
+    };
+
     if network.chain_id == BuiltinChainID::Local.chain_id() {
         // local chain use current active account as sequencer account
         let rooch_dao_bitcoin_address = network.mock_genesis_account(&sequencer_keypair)?;
         let rooch_dao_address = rooch_dao_bitcoin_address.to_rooch_address();
         println!("Rooch DAO address: {:?}", rooch_dao_address);
         println!("Rooch DAO Bitcoin address: {}", rooch_dao_bitcoin_address);
-    } else {
+    } else if !service_status.is_sync_mode() && fork.is_none() && leader.is_none() {
+        // sync mode and follower mode only replay transactions signed by the sequencer in genesis config,
+        // the local sequencer key is not used,
+        // and the forked node sequences the local transactions by the local sequencer key
         ensure!(
             network.genesis_config.sequencer_account == sequencer_bitcoin_address,
             "Sequencer({:?}) in genesis config is not equal to sequencer({:?}) in cli config",
@@ -241,6 +301,19 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     }
 
     let genesis = RoochGenesis::load_or_init(network.clone(), &rooch_db)?;
+    if let Some(leader) = &leader {
+        // The follower applies the store changes of the leader on top of the local genesis state
+        let leader_genesis_hash = leader.rooch.status().await?.rooch_status.genesis_hash.0;
+        ensure!(
+            leader_genesis_hash == genesis.genesis_hash(),
+            "The genesis hash {:?} of the leader node is not equal to the genesis hash {:?} of the local node",
+            leader_genesis_hash,
+            genesis.genesis_hash()
+        );
+    }
+    if let Some(fork) = &fork {
+        fork.init_state(&rooch_db)?;
+    }
 
     let root = rooch_db
         .latest_root()?
@@ -252,6 +325,19 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     );
 
     let event_bus = EventBus::new();
+    // Forward the indexed transactions to the RPC subscriptions
+    let (indexed_tx_sender, _) =
+        broadcast::channel::<TransactionIndexedEvent>(SUBSCRIPTION_CHANNEL_CAPACITY);
+    let subscription_sender = indexed_tx_sender.clone();
+    event_bus.callback_subscribe::<TransactionIndexedEvent, _>(
+        "rpc_subscription",
+        move |event| {
+            if let Ok(event) = event.downcast::<TransactionIndexedEvent>() {
+                // No subscriber is not an error
+                let _ = subscription_sender.send(*event);
+            }
+        },
+    )?;
     let event_actor = EventActor::new(event_bus.clone());
     let event_actor_ref = event_actor
         .into_actor(Some("EventActor"), &actor_system)
@@ -284,6 +370,11 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         executor_actor_ref.clone().into(),
         read_executor_ref.clone().into(),
     );
+    let replayer = TransactionReplayer::new(
+        moveos_store.clone(),
+        rooch_store.clone(),
+        DEFAULT_MAX_CONCURRENT_REPLAYS,
+    );
 
     // Init sequencer
     info!("RPC Server sequencer address: {:?}", sequencer_account);
@@ -301,16 +392,29 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     // Init DA
     let genesis_hash = genesis.genesis_hash();
     let genesis_namespace = derive_namespace_from_genesis(genesis_hash);
-    let last_tx_order = sequencer_proxy.get_sequencer_order().await?;
-    let (da_issues, da_fixed) = rooch_store.try_repair_da_meta(last_tx_order, false)?;
-    info!("DA meta issues: {:?}, fixed: {:?}", da_issues, da_fixed);
-    let da_config = opt.da_config().clone();
+    let mut da_config = opt.da_config().clone();
+    if service_status.is_sync_mode() {
+        // DA meta is rebuilt by the synced chunks, and there is nothing to submit in sync mode
+        da_config.da_backend = None;
+    } else if leader.is_some() {
+        // The transactions are submitted to DA by the leader node
+        da_config.da_backend = None;
+    } else {
+        if fork.is_some() {
+            // The local transactions of the forked node must not be submitted to the DA of the remote network
+            da_config.da_backend = None;
+        }
+        let last_tx_order = sequencer_proxy.get_sequencer_order().await?;
+        let (da_issues, da_fixed) = rooch_store.try_repair_da_meta(last_tx_order, false)?;
+        info!("DA meta issues: {:?}, fixed: {:?}", da_issues, da_fixed);
+    }
     let da_proxy = DAServerProxy::new(
         DAServerActor::new(
             da_config,
             sequencer_keypair.copy(),
             rooch_store.clone(),
-            genesis_namespace,
+            moveos_store.clone(),
+            genesis_namespace.clone(),
             shutdown_tx.subscribe(),
         )
         .await?
@@ -319,34 +423,11 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         .into(),
     );
 
-    // Init proposer
-    let proposer_keypair = server_opt.proposer_keypair.unwrap();
-    let proposer_account: RoochAddress = proposer_keypair.public().rooch_address()?;
-    info!("RPC Server proposer address: {:?}", proposer_account);
-    let proposer = ProposerActor::new(
-        proposer_keypair,
-        moveos_store.clone(),
-        rooch_store,
-        &prometheus_registry,
-        opt.proposer.clone(),
-    )?
-    .into_actor(Some("Proposer"), &actor_system)
-    .await?;
-    let block_propose_duration_in_seconds: u64 =
-        opt.proposer.interval.unwrap_or(PROPOSER_CHECK_INTERVAL);
-    let mut timers = vec![];
-    let proposer_timer = Timer::start(
-        proposer,
-        Duration::from_secs(block_propose_duration_in_seconds),
-        ProposeBlock {},
-    );
-    timers.push(proposer_timer);
-
     // Init indexer
     let indexer_executor = IndexerActor::new(
         root,
         indexer_store,
-        moveos_store,
+        moveos_store.clone(),
         Some(event_actor_ref.clone()),
     )?
     .into_actor(Some("Indexer"), &actor_system)
@@ -364,7 +445,10 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
             btc_rpc_password: config.btc_rpc_password.clone(),
             local_block_store_dir: Some(config.btc_reorg_aware_block_store_dir.clone()), // this client will be used for startup processing, may need reorg blocks
         });
-    let bitcoin_client_proxy = if service_status.is_active() && bitcoin_client_config.is_some() {
+    // L1 blocks must be fetched from bitcoin for executing when replaying in sync mode
+    let bitcoin_client_proxy = if (service_status.is_active() || service_status.is_sync_mode())
+        && bitcoin_client_config.is_some()
+    {
         let bitcoin_client = bitcoin_client_config.unwrap().build()?;
         let bitcoin_client_actor_ref = bitcoin_client
             .into_actor(Some("bitcoin_client_for_rpc_service"), &actor_system)
@@ -396,6 +480,98 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         .await?;
     let processor_proxy = PipelineProcessorProxy::new(processor_actor.into());
 
+    let mut timers = vec![];
+    // Init proposer
+    let proposer_keypair = server_opt.proposer_keypair.unwrap();
+    let proposer_account: RoochAddress = proposer_keypair.public().rooch_address()?;
+    info!("RPC Server proposer address: {:?}", proposer_account);
+    let proposer = ProposerActor::new(
+        proposer_keypair,
+        moveos_store.clone(),
+        rooch_store.clone(),
+        &prometheus_registry,
+        opt.proposer.clone(),
+        executor_proxy.clone(),
+        processor_proxy.clone(),
+    )?;
+    // blocks are proposed by the sequencer node, not in sync mode or follower mode
+    let is_proposing = !service_status.is_sync_mode() && leader.is_none();
+    if is_proposing {
+        proposer.check_proposer().await?;
+    }
+    let proposer = proposer.into_actor(Some("Proposer"), &actor_system).await?;
+    let block_propose_duration_in_seconds: u64 =
+        opt.proposer.interval.unwrap_or(PROPOSER_CHECK_INTERVAL);
+    if is_proposing {
+        let proposer_timer = Timer::start(
+            proposer,
+            Duration::from_secs(block_propose_duration_in_seconds),
+            ProposeBlock {},
+        );
+        timers.push(proposer_timer);
+    }
+
+    // archive nodes keep all the historical states, the pruner is disabled by default
+    if opt.pruner.is_enabled() {
+        let pruner = StatePruner::new(moveos_store.clone(), rooch_store.clone(), &opt.pruner)?;
+        info!("RPC Server state pruner is enabled, config: {}", opt.pruner);
+        let pruner_actor = PrunerActor::new(pruner)
+            .into_actor(Some("Pruner"), &actor_system)
+            .await?;
+        let pruner_timer = Timer::start(
+            pruner_actor,
+            Duration::from_secs(opt.pruner.interval.unwrap_or(PRUNER_INTERVAL)),
+            PruneTick {},
+        );
+        timers.push(pruner_timer);
+    }
+
+    if service_status.is_sync_mode() {
+        let da_backend_config = opt.da_config().da_backend.clone().ok_or_else(|| {
+            anyhow::anyhow!("DA backend config is required in sync mode, please set it by --da")
+        })?;
+        let backends =
+            DABackends::initialize(Some(da_backend_config), genesis_namespace.clone()).await?;
+        let tx_anomalies = load_tx_anomalies(genesis_namespace)?;
+        info!(
+            "RPC Server is in sync mode, syncing from DA, sequencer: {}",
+            network.genesis_config.sequencer_account
+        );
+        let da_sync = DASyncActor::new(
+            backends,
+            network.genesis_config.sequencer_account.clone(),
+            rooch_store,
+            moveos_store,
+            sequencer_proxy.clone(),
+            processor_proxy.clone(),
+            tx_anomalies,
+        )
+        .into_actor(Some("DASync"), &actor_system)
+        .await?;
+        let da_sync_timer = Timer::start(
+            da_sync,
+            Duration::from_secs(DA_SYNC_INTERVAL),
+            DASyncTick {},
+        );
+        timers.push(da_sync_timer);
+    }
+
+    if let Some(leader) = leader.clone() {
+        info!(
+            "RPC Server is in follower mode, following the leader node: {}",
+            follower_config.leader_url.clone().unwrap_or_default()
+        );
+        let follower = FollowerActor::new(leader, sequencer_proxy.clone(), processor_proxy.clone())
+            .into_actor(Some("Follower"), &actor_system)
+            .await?;
+        let follower_timer = Timer::start(
+            follower,
+            Duration::from_secs(follower_config.interval.unwrap_or(FOLLOWER_SYNC_INTERVAL)),
+            FollowerSyncTick {},
+        );
+        timers.push(follower_timer);
+    }
+
     let ethereum_relayer_config = opt.ethereum_relayer_config();
 
     if service_status.is_active()
@@ -420,15 +596,35 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         timers.push(relayer_timer);
     }
 
+    // Init tx pool, the L2 transactions from RPC are sequenced via the tx pool
+    let tx_pool_actor = TxPoolActor::new(
+        TxPool::new(&opt.tx_pool),
+        executor_proxy.clone(),
+        processor_proxy.clone(),
+    )
+    .into_actor(Some("TxPool"), &actor_system)
+    .await?;
+    let tx_pool_timer = Timer::start(
+        tx_pool_actor.clone(),
+        Duration::from_secs(TX_POOL_GC_INTERVAL),
+        TxPoolGcTick {},
+    );
+    timers.push(tx_pool_timer);
+    let tx_pool_proxy = TxPoolProxy::new(tx_pool_actor.into());
+
     let rpc_service = RpcService::new(
         network.chain_id.id,
         network.genesis_config.bitcoin_network,
+        genesis_hash,
         executor_proxy,
+        replayer,
         sequencer_proxy,
         indexer_proxy,
         processor_proxy,
+        tx_pool_proxy,
         bitcoin_client_proxy,
         da_proxy,
+        backup_dir,
     );
     let aggregate_service = AggregateService::new(rpc_service.clone());
 
@@ -515,6 +711,8 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
     rpc_module_builder.register_module(RoochServer::new(
         rpc_service.clone(),
         aggregate_service.clone(),
+        indexed_tx_sender,
+        leader,
     ))?;
     rpc_module_builder.register_module(BtcServer::new(rpc_service.clone()).await?)?;
     rpc_module_builder
@@ -540,6 +738,10 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
                     "/",
                     axum::routing::post(crate::axum_router::json_rpc_handler),
                 )
+                .route(
+                    graphql::GRAPHQL_PATH,
+                    axum::routing::post(graphql::graphql_handler),
+                )
                 .route(
                     "/",
                     axum::routing::get(crate::axum_router::ws::ws_json_rpc_upgrade),
@@ -550,7 +752,12 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
                 );
         }
         ServiceType::Http => {
-            router = router.route("/", axum::routing::post(axum_router::json_rpc_handler));
+            router = router
+                .route("/", axum::routing::post(axum_router::json_rpc_handler))
+                .route(
+                    graphql::GRAPHQL_PATH,
+                    axum::routing::post(graphql::graphql_handler),
+                );
         }
         ServiceType::WebSocket => {
             router = router
@@ -565,7 +772,11 @@ pub async fn run_start_server(opt: RoochOpt, server_opt: ServerOpt) -> Result<Se
         }
     }
 
-    let app = router.with_state(ser).layer(middleware);
+    let graphql_schema = graphql::build_schema(rpc_service, aggregate_service);
+    let app = router
+        .with_state(ser)
+        .layer(axum::Extension(graphql_schema))
+        .layer(middleware);
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     let addr = listener.local_addr()?;
@@ -8,9 +8,10 @@ use jsonrpsee::{core::async_trait, RpcModule};
 use rooch_rpc_api::api::btc_api::BtcAPIServer;
 use rooch_rpc_api::api::{RoochRpcModule, DEFAULT_RESULT_LIMIT_USIZE, MAX_RESULT_LIMIT_USIZE};
 use rooch_rpc_api::jsonrpc_types::btc::ord::{InscriptionFilterView, InscriptionStateView};
+use rooch_rpc_api::jsonrpc_types::btc::runes::{RuneBalancesFilterView, RuneBalancesStateView};
 use rooch_rpc_api::jsonrpc_types::btc::utxo::{UTXOFilterView, UTXOStateView};
 use rooch_rpc_api::jsonrpc_types::{
-    BytesView, IndexerStateIDView, InscriptionPageView, StrView, UTXOPageView,
+    BytesView, IndexerStateIDView, InscriptionPageView, RuneBalancesPageView, StrView, UTXOPageView,
 };
 use rooch_rpc_api::RpcResult;
 use rooch_types::indexer::state::ObjectStateType;
@@ -116,6 +117,50 @@ impl BtcAPIServer for BtcServer {
         })
     }
 
+    async fn query_rune_balances(
+        &self,
+        filter: RuneBalancesFilterView,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<IndexerStateIDView>,
+        limit: Option<StrView<u64>>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<RuneBalancesPageView> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT_USIZE),
+            MAX_RESULT_LIMIT_USIZE,
+        );
+        let descending_order = descending_order.unwrap_or(true);
+
+        let global_state_filter = RuneBalancesFilterView::into_global_state_filter(filter)?;
+        let object_states = self
+            .rpc_service
+            .query_object_states(
+                global_state_filter,
+                cursor.map(Into::into),
+                limit_of + 1,
+                descending_order,
+                false,
+                false,
+                ObjectStateType::RuneBalances,
+            )
+            .await?;
+
+        let mut data = object_states
+            .into_iter()
+            .map(RuneBalancesStateView::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let has_next_page = data.len() > limit_of;
+        data.truncate(limit_of);
+        let next_cursor = data.last().cloned().map_or(cursor, |t| Some(t.indexer_id));
+
+        Ok(RuneBalancesPageView {
+            data,
+            next_cursor,
+            has_next_page,
+        })
+    }
+
     async fn broadcast_tx(
         &self,
         hex: BytesView,
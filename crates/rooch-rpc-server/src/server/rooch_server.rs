@@ -4,7 +4,10 @@
 use crate::service::aggregate_service::AggregateService;
 use crate::service::rpc_service::RpcService;
 use anyhow::Result;
-use jsonrpsee::{core::async_trait, RpcModule};
+use jsonrpsee::core::SubscriptionResult;
+use jsonrpsee::{
+    core::async_trait, PendingSubscriptionSink, RpcModule, SubscriptionMessage, SubscriptionSink,
+};
 use move_core_types::{
     account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
 };
@@ -15,22 +18,27 @@ use moveos_types::{
     moveos_std::{move_module::MoveModule, object::ObjectID},
     state::{AnnotatedState, FieldKey},
 };
-use rooch_rpc_api::api::MAX_INTERNAL_LIMIT_USIZE;
+use rooch_event::event::TransactionIndexedEvent;
+use rooch_rpc_api::api::{MAX_INTERNAL_LIMIT_USIZE, MAX_STATE_NODES_PER_QUERY};
 use rooch_rpc_api::jsonrpc_types::field_view::FieldFilterView;
+use rooch_rpc_api::jsonrpc_types::proof_view::{StateProofView, TransactionProofView};
 use rooch_rpc_api::jsonrpc_types::{
     account_view::BalanceInfoView,
     event_view::{EventFilterView, EventView, IndexerEventIDView, IndexerEventView},
-    transaction_view::{TransactionFilterView, TransactionWithInfoView},
+    transaction_view::{PendingTransactionView, TransactionFilterView, TransactionWithInfoView},
     AccessPathView, BalanceInfoPageView, DryRunTransactionResponseView, EventOptions,
     EventPageView, ExecuteTransactionResponseView, FieldPageView, FunctionCallView, H256View,
     IndexerEventPageView, IndexerObjectStatePageView, IndexerStateIDView, ModuleABIView,
     ObjectIDVecView, ObjectStateFilterView, ObjectStateView, QueryOptions,
     RawTransactionOutputView, RoochAddressView, StateChangeSetPageView,
     StateChangeSetWithTxOrderView, StateKVView, StateOptions, StatePageView, StrView,
-    StructTagView, SyncStateFilterView, TransactionWithInfoPageView, TxOptions, UnitedAddressView,
+    StructTagView, SyncStateFilterView, TransactionView, TransactionWithInfoPageView, TxOptions,
+    UnitedAddressView,
 };
 use rooch_rpc_api::jsonrpc_types::{
+    backup_view::BackupManifestView,
     repair_view::{RepairIndexerParamsView, RepairIndexerTypeView},
+    trace_view::ReplayTransactionResponseView,
     Status,
 };
 use rooch_rpc_api::{
@@ -42,56 +50,70 @@ use rooch_rpc_api::{
     jsonrpc_types::BytesView,
     RpcError, RpcResult,
 };
+use rooch_rpc_client::Client;
+use rooch_types::indexer::event::EventFilter;
 use rooch_types::indexer::state::{IndexerStateID, ObjectStateType};
+use rooch_types::indexer::transaction::TransactionFilter;
+use rooch_types::indexer::Filter;
 use rooch_types::transaction::{RoochTransaction, RoochTransactionData, TransactionWithInfo};
+use serde::Serialize;
 use std::cmp::{max, min};
+use std::future::Future;
 use std::str::FromStr;
-use tracing::{debug, info};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, info, warn};
 
+#[derive(Clone)]
 pub struct RoochServer {
     rpc_service: RpcService,
     aggregate_service: AggregateService,
+    indexed_tx_sender: broadcast::Sender<TransactionIndexedEvent>,
+    /// The leader node client in follower mode, the write requests are forwarded to the leader
+    leader: Option<Client>,
 }
 
 impl RoochServer {
-    pub fn new(rpc_service: RpcService, aggregate_service: AggregateService) -> Self {
+    pub fn new(
+        rpc_service: RpcService,
+        aggregate_service: AggregateService,
+        indexed_tx_sender: broadcast::Sender<TransactionIndexedEvent>,
+        leader: Option<Client>,
+    ) -> Self {
         Self {
             rpc_service,
             aggregate_service,
+            indexed_tx_sender,
+            leader,
         }
     }
 
-    async fn transactions_to_view(
+    async fn replay_transaction_to_view(
         &self,
-        data: Vec<TransactionWithInfo>,
-    ) -> Result<Vec<TransactionWithInfoView>> {
-        let rooch_addresses = data
-            .iter()
-            .filter_map(|tx| tx.transaction.sender())
-            .collect::<Vec<_>>();
-        let address_mapping = self
-            .rpc_service
-            .get_bitcoin_addresses(rooch_addresses)
-            .await?;
-        let bitcoin_network = self.rpc_service.get_bitcoin_network();
-        let data = data
-            .into_iter()
-            .map(|tx| {
-                let sender_bitcoin_address = match tx.transaction.sender() {
-                    Some(rooch_address) => address_mapping
-                        .get(&rooch_address)
-                        .map(|addr| addr.clone().map(|a| a.format(bitcoin_network))),
-                    None => None,
-                }
-                .flatten()
-                .transpose()?;
-                Ok(TransactionWithInfoView::new_from_transaction_with_info(
-                    tx,
-                    sender_bitcoin_address,
-                ))
-            })
-            .collect::<Result<Vec<_>>>()?;
-        Ok(data)
+        tx_hash: H256,
+        trace: bool,
+    ) -> Result<Option<ReplayTransactionResponseView>> {
+        let result = match self.rpc_service.replay_tx(tx_hash, trace).await? {
+            Some(result) => result,
+            None => return Ok(None),
+        };
+        let raw_output = result.raw_output;
+        Ok(Some(ReplayTransactionResponseView {
+            tx_order: result.tx_order.into(),
+            pre_state_root: result.pre_state_root.into(),
+            execution_info: result.execution_info.into(),
+            raw_output: RawTransactionOutputView {
+                status: raw_output.status.into(),
+                gas_used: raw_output.gas_used.into(),
+                is_upgrade: raw_output.is_upgrade,
+            },
+            changeset: raw_output.changeset.into(),
+            events: raw_output.events.into_iter().map(Into::into).collect(),
+            vm_error_info: result.vm_error_info,
+            trace: result
+                .gas_log
+                .map(|gas_log| gas_log.exec_io.call_graph.into()),
+        }))
     }
 }
 
@@ -104,6 +126,10 @@ impl RoochAPIServer for RoochServer {
 
     async fn send_raw_transaction(&self, payload: BytesView) -> RpcResult<H256View> {
         debug!("send_raw_transaction payload: {:?}", payload);
+        if let Some(leader) = &self.leader {
+            let hash = leader.rooch.send_raw_transaction(payload.0).await?;
+            return Ok(hash.into());
+        }
         let mut tx = bcs::from_bytes::<RoochTransaction>(&payload.0)?;
         info!(
             "send_raw_transaction tx sender:{:?}, hash:{:?}",
@@ -121,6 +147,12 @@ impl RoochAPIServer for RoochServer {
         payload: BytesView,
         tx_options: Option<TxOptions>,
     ) -> RpcResult<ExecuteTransactionResponseView> {
+        if let Some(leader) = &self.leader {
+            return Ok(leader
+                .rooch
+                .execute_raw_transaction(payload.0, tx_options)
+                .await?);
+        }
         let tx_options = tx_options.unwrap_or_default();
         let tx = bcs::from_bytes::<RoochTransaction>(&payload.0)?;
         let tx_response = self.rpc_service.execute_tx(tx).await?;
@@ -168,6 +200,54 @@ impl RoochAPIServer for RoochServer {
         Ok(result)
     }
 
+    async fn estimate_gas_price(&self) -> RpcResult<StrView<u64>> {
+        let base_fee = self.rpc_service.get_base_fee().await?;
+        Ok(StrView(base_fee))
+    }
+
+    async fn get_pending_transactions(
+        &self,
+        sender: Option<UnitedAddressView>,
+    ) -> RpcResult<Vec<PendingTransactionView>> {
+        let pending_txs = self
+            .rpc_service
+            .get_pending_transactions(sender.map(Into::into))
+            .await?;
+
+        let bitcoin_network = self.rpc_service.get_bitcoin_network();
+        let rooch_addresses = pending_txs
+            .iter()
+            .map(|(ptx, _)| ptx.sender())
+            .collect::<Vec<_>>();
+        let address_mapping = self
+            .rpc_service
+            .get_bitcoin_addresses(rooch_addresses)
+            .await?;
+
+        let data = pending_txs
+            .into_iter()
+            .map(|(ptx, ready)| {
+                let sender_bitcoin_address = address_mapping
+                    .get(&ptx.sender())
+                    .cloned()
+                    .flatten()
+                    .map(|addr| addr.format(bitcoin_network))
+                    .transpose()?;
+                Ok(PendingTransactionView {
+                    tx_hash: ptx.tx_hash.into(),
+                    transaction: TransactionView::new_from_rooch_transaction(
+                        ptx.tx,
+                        sender_bitcoin_address,
+                    ),
+                    priority: ptx.priority.into(),
+                    received_at: ptx.received_at.into(),
+                    ready,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(data)
+    }
+
     async fn dry_run(&self, payload: BytesView) -> RpcResult<DryRunTransactionResponseView> {
         let tx = bcs::from_bytes::<RoochTransactionData>(&payload.0)?;
         let tx_result = self.rpc_service.dry_run_tx(tx).await?;
@@ -184,6 +264,24 @@ impl RoochAPIServer for RoochServer {
         Ok(tx_response)
     }
 
+    async fn replay_transaction(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<ReplayTransactionResponseView>> {
+        Ok(self
+            .replay_transaction_to_view(tx_hash.into(), false)
+            .await?)
+    }
+
+    async fn trace_transaction(
+        &self,
+        tx_hash: H256View,
+    ) -> RpcResult<Option<ReplayTransactionResponseView>> {
+        Ok(self
+            .replay_transaction_to_view(tx_hash.into(), true)
+            .await?)
+    }
+
     async fn execute_view_function(
         &self,
         function_call: FunctionCallView,
@@ -325,6 +423,44 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_state_proof(
+        &self,
+        access_path: AccessPathView,
+        state_root: Option<H256View>,
+    ) -> RpcResult<Vec<StateProofView>> {
+        access_path.0.validate_max_object_ids()?;
+        let proofs = self
+            .rpc_service
+            .get_state_proofs(access_path.into(), state_root.map(|h256_view| h256_view.0))
+            .await?;
+        Ok(proofs.into_iter().map(StateProofView::from).collect())
+    }
+
+    async fn get_state_nodes(
+        &self,
+        node_hashes: Vec<H256View>,
+    ) -> RpcResult<Vec<Option<BytesView>>> {
+        if node_hashes.len() > MAX_STATE_NODES_PER_QUERY {
+            return Err(RpcError::UnexpectedError(format!(
+                "Too many state nodes requested. Maximum allowed: {}",
+                MAX_STATE_NODES_PER_QUERY
+            )));
+        }
+        let nodes = self
+            .rpc_service
+            .get_state_nodes(
+                node_hashes
+                    .into_iter()
+                    .map(|h256_view| h256_view.0)
+                    .collect(),
+            )
+            .await?;
+        Ok(nodes
+            .into_iter()
+            .map(|node| node.map(BytesView::from))
+            .collect())
+    }
+
     async fn get_object_states(
         &self,
         object_ids: ObjectIDVecView,
@@ -498,6 +634,18 @@ impl RoochAPIServer for RoochServer {
         Ok(data)
     }
 
+    async fn get_transaction_proof(
+        &self,
+        tx_order: StrView<u64>,
+        accumulator_tx_order: Option<StrView<u64>>,
+    ) -> RpcResult<Option<TransactionProofView>> {
+        let proof = self
+            .rpc_service
+            .get_transaction_proof(tx_order.0, accumulator_tx_order.map(|v| v.0))
+            .await?;
+        Ok(proof.map(TransactionProofView::from))
+    }
+
     async fn get_transactions_by_order(
         &self,
         cursor: Option<StrView<u64>>,
@@ -563,7 +711,7 @@ impl RoochAPIServer for RoochServer {
             .flatten()
             .collect::<Vec<_>>();
 
-        let data = self.transactions_to_view(data).await?;
+        let data = self.aggregate_service.transactions_to_view(data).await?;
 
         Ok(TransactionWithInfoPageView {
             data,
@@ -676,7 +824,7 @@ impl RoochAPIServer for RoochServer {
         let has_next_page = data.len() > limit_of;
         data.truncate(limit_of);
 
-        let data = self.transactions_to_view(data).await?;
+        let data = self.aggregate_service.transactions_to_view(data).await?;
 
         let next_cursor = data
             .last()
@@ -907,6 +1055,35 @@ impl RoochAPIServer for RoochServer {
         })
     }
 
+    async fn get_committed_transactions(
+        &self,
+        // exclusive cursor if `Some`, otherwise start from the beginning
+        cursor: Option<StrView<u64>>,
+        limit: Option<StrView<u64>>,
+    ) -> RpcResult<Vec<BytesView>> {
+        let limit_of = min(
+            limit.map(Into::into).unwrap_or(DEFAULT_RESULT_LIMIT),
+            MAX_RESULT_LIMIT,
+        );
+        let start = cursor.map(|v| v.0 + 1).unwrap_or(0);
+        let last_sequencer_order = self.rpc_service.get_sequencer_order().await?;
+        let end = min(
+            start.saturating_add(limit_of),
+            last_sequencer_order.saturating_add(1),
+        );
+        if start >= end {
+            return Ok(vec![]);
+        }
+        let committed_txs = self
+            .rpc_service
+            .get_committed_transactions(start, end)
+            .await?;
+        Ok(committed_txs
+            .iter()
+            .map(|tx| bcs::to_bytes(tx).map(BytesView::from))
+            .collect::<Result<Vec<_>, _>>()?)
+    }
+
     async fn status(&self) -> RpcResult<Status> {
         let status = self.rpc_service.status().await?;
         Ok(status)
@@ -953,6 +1130,147 @@ impl RoochAPIServer for RoochServer {
 
         Ok(data)
     }
+
+    async fn backup_db(&self, name: Option<String>) -> RpcResult<BackupManifestView> {
+        let (manifest, backup_dir) = self.rpc_service.backup_db(name).await?;
+        Ok(BackupManifestView::new(
+            manifest,
+            backup_dir.to_string_lossy().to_string(),
+        ))
+    }
+
+    async fn subscribe_events(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: EventFilterView,
+    ) -> SubscriptionResult {
+        let filter: EventFilter = filter.into();
+        let receiver = self.indexed_tx_sender.subscribe();
+        let sink = pending.accept().await?;
+        tokio::spawn(pipe_indexed_transactions(sink, receiver, move |event| {
+            let events = event
+                .events
+                .into_iter()
+                .filter(|e| filter.matches(e))
+                .map(IndexerEventView::from)
+                .collect::<Vec<_>>();
+            async move { Ok(events) }
+        }));
+        Ok(())
+    }
+
+    async fn subscribe_transactions(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: TransactionFilterView,
+    ) -> SubscriptionResult {
+        let filter: TransactionFilter = filter.into();
+        let receiver = self.indexed_tx_sender.subscribe();
+        let sink = pending.accept().await?;
+        let server = self.clone();
+        tokio::spawn(pipe_indexed_transactions(sink, receiver, move |event| {
+            let matched = filter.matches(&event.indexer_transaction);
+            let server = server.clone();
+            async move {
+                if !matched {
+                    return Ok(vec![]);
+                }
+                let tx = TransactionWithInfo::new(event.ledger_transaction, event.execution_info)?;
+                server
+                    .aggregate_service
+                    .transactions_to_view(vec![tx])
+                    .await
+            }
+        }));
+        Ok(())
+    }
+
+    async fn subscribe_object_states(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: ObjectStateFilterView,
+        query_option: Option<QueryOptions>,
+    ) -> SubscriptionResult {
+        let query_option = query_option.unwrap_or_default();
+        let filter =
+            ObjectStateFilterView::try_into_object_state_filter(filter, query_option.clone())?;
+        let receiver = self.indexed_tx_sender.subscribe();
+        let sink = pending.accept().await?;
+        let rpc_service = self.rpc_service.clone();
+        tokio::spawn(pipe_indexed_transactions(sink, receiver, move |event| {
+            let indexer_ids = event
+                .object_states
+                .into_iter()
+                .filter(|state| filter.matches_changed(state))
+                .map(|state| (state.metadata.id.clone(), state.indexer_state_id()))
+                .collect::<Vec<_>>();
+            let rpc_service = rpc_service.clone();
+            let (decode, show_display) = (query_option.decode, query_option.show_display);
+            async move {
+                if indexer_ids.is_empty() {
+                    return Ok(vec![]);
+                }
+                rpc_service
+                    .object_states_to_view(indexer_ids, decode, show_display)
+                    .await
+            }
+        }));
+        Ok(())
+    }
+}
+
+/// Push the items of the newly indexed transactions to the subscription sink,
+/// until the subscription is closed or the subscriber lags behind.
+async fn pipe_indexed_transactions<T, F, Fut>(
+    sink: SubscriptionSink,
+    mut receiver: broadcast::Receiver<TransactionIndexedEvent>,
+    mut to_items: F,
+) where
+    T: Serialize,
+    F: FnMut(TransactionIndexedEvent) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    loop {
+        let event = tokio::select! {
+            _ = sink.closed() => break,
+            event = receiver.recv() => event,
+        };
+        let event = match event {
+            Ok(event) => event,
+            Err(RecvError::Lagged(skipped)) => {
+                warn!(
+                    "Subscription {:?} lagged behind, {} transactions skipped, close it",
+                    sink.subscription_id(),
+                    skipped
+                );
+                break;
+            }
+            Err(RecvError::Closed) => break,
+        };
+        let items = match to_items(event).await {
+            Ok(items) => items,
+            Err(e) => {
+                warn!(
+                    "Subscription {:?} failed to build items: {:?}",
+                    sink.subscription_id(),
+                    e
+                );
+                continue;
+            }
+        };
+        for item in items {
+            let message = match SubscriptionMessage::from_json(&item) {
+                Ok(message) => message,
+                Err(e) => {
+                    warn!("Subscription failed to serialize item: {:?}", e);
+                    continue;
+                }
+            };
+            if sink.send(message).await.is_err() {
+                return;
+            }
+        }
+    }
 }
 
 impl RoochRpcModule for RoochServer {
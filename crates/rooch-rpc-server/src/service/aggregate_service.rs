@@ -10,6 +10,7 @@ use moveos_types::h256::H256;
 use moveos_types::moveos_std::object::ObjectID;
 use moveos_types::state::PlaceholderStruct;
 use rooch_rpc_api::jsonrpc_types::account_view::BalanceInfoView;
+use rooch_rpc_api::jsonrpc_types::transaction_view::TransactionWithInfoView;
 use rooch_rpc_api::jsonrpc_types::CoinInfoView;
 use rooch_types::address::RoochAddress;
 use rooch_types::framework::account_coin_store::AccountCoinStoreModule;
@@ -231,4 +232,37 @@ impl AggregateService {
             .collect::<Result<Vec<_>>>()?;
         Ok(data)
     }
+
+    pub async fn transactions_to_view(
+        &self,
+        data: Vec<TransactionWithInfo>,
+    ) -> Result<Vec<TransactionWithInfoView>> {
+        let rooch_addresses = data
+            .iter()
+            .filter_map(|tx| tx.transaction.sender())
+            .collect::<Vec<_>>();
+        let address_mapping = self
+            .rpc_service
+            .get_bitcoin_addresses(rooch_addresses)
+            .await?;
+        let bitcoin_network = self.rpc_service.get_bitcoin_network();
+        let data = data
+            .into_iter()
+            .map(|tx| {
+                let sender_bitcoin_address = match tx.transaction.sender() {
+                    Some(rooch_address) => address_mapping
+                        .get(&rooch_address)
+                        .map(|addr| addr.clone().map(|a| a.format(bitcoin_network))),
+                    None => None,
+                }
+                .flatten()
+                .transpose()?;
+                Ok(TransactionWithInfoView::new_from_transaction_with_info(
+                    tx,
+                    sender_bitcoin_address,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(data)
+    }
 }
@@ -17,10 +17,15 @@ use moveos_types::moveos_std::object::{ObjectID, MAX_OBJECT_IDS_PER_QUERY};
 use moveos_types::state::{AnnotatedState, FieldKey, ObjectState, StateChangeSet};
 use moveos_types::state_resolver::{AnnotatedStateKV, StateKV};
 use moveos_types::transaction::{FunctionCall, TransactionExecutionInfo};
+use rooch_config::store_config::DEFAULT_DB_INDEXER_SUBDIR;
 use rooch_da::proxy::DAServerProxy;
-use rooch_executor::actor::messages::DryRunTransactionResult;
+use rooch_db::backup::write_backup_manifest;
+use rooch_executor::actor::messages::{DryRunTransactionResult, ReplayTransactionResult};
 use rooch_executor::proxy::ExecutorProxy;
+use rooch_executor::replayer::TransactionReplayer;
 use rooch_indexer::proxy::IndexerProxy;
+use rooch_mempool::pool::PooledTransaction;
+use rooch_mempool::proxy::TxPoolProxy;
 use rooch_pipeline_processor::proxy::PipelineProcessorProxy;
 use rooch_rpc_api::jsonrpc_types::field_view::IndexerFieldView;
 use rooch_rpc_api::jsonrpc_types::{
@@ -28,6 +33,7 @@ use rooch_rpc_api::jsonrpc_types::{
 };
 use rooch_sequencer::proxy::SequencerProxy;
 use rooch_types::address::{BitcoinAddress, RoochAddress};
+use rooch_types::backup::BackupManifest;
 use rooch_types::bitcoin::pending_block::PendingBlockModule;
 use rooch_types::bitcoin::BitcoinModule;
 use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
@@ -37,15 +43,19 @@ use rooch_types::indexer::event::{
 use rooch_types::indexer::field::{FieldFilter, IndexerField};
 use rooch_types::indexer::state::{
     IndexerObjectState, IndexerStateID, ObjectStateFilter, ObjectStateType, INSCRIPTION_TYPE_TAG,
-    UTXO_TYPE_TAG,
+    RUNE_BALANCES_TYPE_TAG, UTXO_TYPE_TAG,
 };
 use rooch_types::indexer::transaction::{IndexerTransaction, TransactionFilter};
+use rooch_types::proof::{StateProof, TransactionProof};
 use rooch_types::repair::{RepairIndexerParams, RepairIndexerType};
 use rooch_types::state::{StateChangeSetWithTxOrder, SyncStateFilter};
 use rooch_types::transaction::{
-    ExecuteTransactionResponse, LedgerTransaction, RoochTransaction, RoochTransactionData,
+    CommittedTransaction, ExecuteTransactionResponse, LedgerTransaction, RoochTransaction,
+    RoochTransactionData,
 };
 use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// RpcService is the implementation of the RPC service.
 /// It is the glue between the RPC server(EthAPIServer,RoochApiServer) and the rooch's actors.
@@ -54,34 +64,46 @@ use std::collections::{BTreeMap, HashMap};
 pub struct RpcService {
     chain_id: u64,
     bitcoin_network: u8,
+    genesis_hash: H256,
     pub(crate) executor: ExecutorProxy,
+    replayer: TransactionReplayer,
     pub(crate) sequencer: SequencerProxy,
     pub(crate) indexer: IndexerProxy,
     pub(crate) pipeline_processor: PipelineProcessorProxy,
+    pub(crate) tx_pool: TxPoolProxy,
     pub(crate) bitcoin_client: Option<BitcoinClientProxy>,
     pub(crate) da_server: DAServerProxy,
+    backup_dir: PathBuf,
 }
 
 impl RpcService {
     pub fn new(
         chain_id: u64,
         bitcoin_network: u8,
+        genesis_hash: H256,
         executor: ExecutorProxy,
+        replayer: TransactionReplayer,
         sequencer: SequencerProxy,
         indexer: IndexerProxy,
         pipeline_processor: PipelineProcessorProxy,
+        tx_pool: TxPoolProxy,
         bitcoin_client: Option<BitcoinClientProxy>,
         da_server: DAServerProxy,
+        backup_dir: PathBuf,
     ) -> Self {
         Self {
             chain_id,
             bitcoin_network,
+            genesis_hash,
             executor,
+            replayer,
             sequencer,
             indexer,
             pipeline_processor,
+            tx_pool,
             bitcoin_client,
             da_server,
+            backup_dir,
         }
     }
 }
@@ -95,14 +117,25 @@ impl RpcService {
         self.bitcoin_network
     }
 
+    /// Submit the transaction to the tx pool, do not wait for the execution
     pub async fn queue_tx(&self, tx: RoochTransaction) -> Result<()> {
-        //TODO implement queue tx and do not wait to execute
-        let _ = self.execute_tx(tx).await?;
+        let _ = self.tx_pool.submit_tx(tx).await?;
         Ok(())
     }
 
     pub async fn execute_tx(&self, tx: RoochTransaction) -> Result<ExecuteTransactionResponse> {
-        self.pipeline_processor.execute_l2_tx(tx).await
+        self.tx_pool.execute_tx(tx).await
+    }
+
+    pub async fn get_pending_transactions(
+        &self,
+        sender: Option<RoochAddress>,
+    ) -> Result<Vec<(PooledTransaction, bool)>> {
+        self.tx_pool.get_pending_transactions(sender).await
+    }
+
+    pub async fn get_base_fee(&self) -> Result<u64> {
+        self.executor.base_fee().await
     }
 
     pub async fn dry_run_tx(&self, tx: RoochTransactionData) -> Result<DryRunTransactionResult> {
@@ -110,6 +143,14 @@ impl RpcService {
         self.executor.dry_run_transaction(verified_tx).await
     }
 
+    pub async fn replay_tx(
+        &self,
+        tx_hash: H256,
+        trace: bool,
+    ) -> Result<Option<ReplayTransactionResult>> {
+        self.replayer.replay_transaction(tx_hash, trace).await
+    }
+
     pub async fn execute_view_function(
         &self,
         function_call: FunctionCall,
@@ -131,6 +172,20 @@ impl RpcService {
         self.executor.get_states(access_path, state_root).await
     }
 
+    pub async fn get_state_proofs(
+        &self,
+        access_path: AccessPath,
+        state_root: Option<H256>,
+    ) -> Result<Vec<StateProof>> {
+        self.executor
+            .get_state_proofs(access_path, state_root)
+            .await
+    }
+
+    pub async fn get_state_nodes(&self, node_hashes: Vec<H256>) -> Result<Vec<Option<Vec<u8>>>> {
+        self.executor.get_state_nodes(node_hashes).await
+    }
+
     pub async fn exists_module(&self, module_id: ModuleId) -> Result<bool> {
         let mut resp = self
             .get_states(AccessPath::module(&module_id), None)
@@ -253,6 +308,16 @@ impl RpcService {
         Ok(resp)
     }
 
+    pub async fn get_transaction_proof(
+        &self,
+        tx_order: u64,
+        accumulator_tx_order: Option<u64>,
+    ) -> Result<Option<TransactionProof>> {
+        self.sequencer
+            .get_transaction_proof(tx_order, accumulator_tx_order)
+            .await
+    }
+
     pub async fn get_sequencer_order(&self) -> Result<u64> {
         let resp = self.sequencer.get_sequencer_order().await?;
         Ok(resp)
@@ -389,6 +454,17 @@ impl RpcService {
                     .await?
             }
         };
+        self.object_states_to_view(indexer_ids, decode, show_display)
+            .await
+    }
+
+    /// Get the latest states of the indexed objects, the objects not found in the state are skipped
+    pub async fn object_states_to_view(
+        &self,
+        indexer_ids: Vec<(ObjectID, IndexerStateID)>,
+        decode: bool,
+        show_display: bool,
+    ) -> Result<Vec<IndexerObjectStateView>> {
         let object_ids = indexer_ids.iter().map(|m| m.0.clone()).collect::<Vec<_>>();
 
         let access_path = AccessPath::objects(object_ids.clone());
@@ -609,6 +685,7 @@ impl RpcService {
                             ObjectStateType::ObjectState,
                             ObjectStateType::UTXO,
                             ObjectStateType::Inscription,
+                            ObjectStateType::RuneBalances,
                         ] {
                             self.repair_indexer_object_states(
                                 states.clone(),
@@ -666,6 +743,12 @@ impl RpcService {
                                 object_states_mapping.insert(state.metadata.id.clone(), state);
                             }
                         }
+                        ObjectStateType::RuneBalances => {
+                            if type_tag_match(&state.metadata.object_type, &RUNE_BALANCES_TYPE_TAG)
+                            {
+                                object_states_mapping.insert(state.metadata.id.clone(), state);
+                            }
+                        }
                     },
                     None => remove_object_ids.push(object_ids[idx].clone()),
                 }
@@ -801,12 +884,156 @@ impl RpcService {
         Ok(result)
     }
 
+    /// Get the committed transactions with their store changes in [from_order, to_order),
+    /// stop at the first transaction which is not fully committed, e.g., the indexer is delayed.
+    pub async fn get_committed_transactions(
+        &self,
+        from_order: u64,
+        to_order: u64,
+    ) -> Result<Vec<CommittedTransaction>> {
+        const EVENTS_PAGE_SIZE: usize = 1000;
+
+        let tx_orders = (from_order..to_order).collect::<Vec<_>>();
+        let tx_hashes = self.get_tx_hashes(tx_orders.clone()).await?;
+        let tx_hashes = tx_hashes.into_iter().map_while(|h| h).collect::<Vec<_>>();
+        let transactions = self.get_transactions_by_hash(tx_hashes.clone()).await?;
+        let execution_infos = self
+            .get_transaction_execution_infos_by_hash(tx_hashes)
+            .await?;
+        let state_change_sets = self.executor.get_state_change_sets(tx_orders).await?;
+        let indexer_transactions = self
+            .indexer
+            .query_transactions(
+                TransactionFilter::TxOrderRange {
+                    from_order,
+                    to_order,
+                },
+                None,
+                (to_order - from_order) as usize,
+                false,
+            )
+            .await?
+            .into_iter()
+            .map(|tx| (tx.tx_order, tx))
+            .collect::<HashMap<_, _>>();
+
+        let mut event_ids_by_order: BTreeMap<u64, Vec<EventID>> = BTreeMap::new();
+        let mut cursor = None;
+        loop {
+            let indexer_events = self
+                .indexer
+                .query_events(
+                    EventFilter::TxOrderRange {
+                        from_order,
+                        to_order,
+                    },
+                    cursor,
+                    EVENTS_PAGE_SIZE,
+                    false,
+                )
+                .await?;
+            cursor = indexer_events.last().map(|e| e.indexer_event_id);
+            let is_last_page = indexer_events.len() < EVENTS_PAGE_SIZE;
+            for event in indexer_events {
+                event_ids_by_order
+                    .entry(event.indexer_event_id.tx_order)
+                    .or_default()
+                    .push(event.event_id);
+            }
+            if is_last_page {
+                break;
+            }
+        }
+
+        let mut result = vec![];
+        for (((tx_order, transaction), execution_info), state_change_set) in (from_order..to_order)
+            .zip(transactions)
+            .zip(execution_infos)
+            .zip(state_change_sets)
+        {
+            let (
+                Some(transaction),
+                Some(execution_info),
+                Some(state_change_set),
+                Some(indexer_transaction),
+            ) = (
+                transaction,
+                execution_info,
+                state_change_set,
+                indexer_transactions.get(&tx_order).cloned(),
+            )
+            else {
+                break;
+            };
+            let event_ids = event_ids_by_order.remove(&tx_order).unwrap_or_default();
+            let events = self.get_events_by_event_ids(event_ids).await?;
+            let Some(events) = events.into_iter().collect::<Option<Vec<_>>>() else {
+                break;
+            };
+            result.push(CommittedTransaction {
+                transaction,
+                execution_info,
+                state_change_set,
+                events,
+                indexer_transaction,
+            });
+        }
+        Ok(result)
+    }
+
     pub async fn check_state_change_sets(&self, tx_orders: Vec<u64>) -> Result<Vec<u64>> {
         let result = self.executor.check_state_change_sets(tx_orders).await?;
 
         Ok(result)
     }
 
+    /// Backup the RoochDB into the `name` subdir of the node backup dir, the name defaults to
+    /// the current timestamp in milliseconds.
+    pub async fn backup_db(&self, name: Option<String>) -> Result<(BackupManifest, PathBuf)> {
+        let name = match name {
+            Some(name) => {
+                if name.is_empty()
+                    || !name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                {
+                    return Err(format_err!(
+                        "Invalid backup name {}, only alphanumeric, '-' and '_' are allowed",
+                        name
+                    ));
+                }
+                name
+            }
+            None => SystemTime::now()
+                .duration_since(UNIX_EPOCH)?
+                .as_millis()
+                .to_string(),
+        };
+        let backup_dir = self.backup_dir.join(name);
+        // The pipeline processor is paused until both the store and the indexer are backed up,
+        // so the indexer backup is at the same tx as the store checkpoint.
+        let indexer_tables = self
+            .pipeline_processor
+            .backup_db(
+                backup_dir.clone(),
+                backup_dir.join(DEFAULT_DB_INDEXER_SUBDIR),
+            )
+            .await?;
+        let chain_id = self.chain_id;
+        let manifest_dir = backup_dir.clone();
+        let manifest = tokio::task::spawn_blocking(move || {
+            write_backup_manifest(chain_id, &manifest_dir, indexer_tables)
+        })
+        .await??;
+        tracing::info!(
+            "Backup RoochDB to {:?}, sequencer order: {}, state root: {:?}",
+            backup_dir,
+            manifest.sequencer_order,
+            manifest.state_root
+        );
+        Ok((manifest, backup_dir))
+    }
+
     pub async fn status(&self) -> Result<Status> {
         let service_status = self.pipeline_processor.get_service_status().await?;
         let sequencer_info = self.sequencer.get_sequencer_info().await?;
@@ -814,6 +1041,7 @@ impl RpcService {
         let da_server_status = self.da_server.get_status().await?;
 
         let rooch_status = RoochStatus {
+            genesis_hash: self.genesis_hash.into(),
             sequencer_info: sequencer_info.into(),
             root_state: root_state.into(),
             da_info: da_server_status.into(),
@@ -8,7 +8,8 @@ use std::time::SystemTime;
 
 use crate::messages::{
     GetSequencerInfoMessage, GetSequencerOrderMessage, GetTransactionByHashMessage,
-    GetTransactionsByHashMessage, GetTxHashsMessage, TransactionSequenceMessage,
+    GetTransactionProofMessage, GetTransactionsByHashMessage, GetTxHashsMessage,
+    ReplayTransactionMessage, TransactionSequenceMessage,
 };
 use crate::metrics::SequencerMetrics;
 use accumulator::{Accumulator, MerkleAccumulator};
@@ -24,6 +25,7 @@ use rooch_event::event::ServiceStatusEvent;
 use rooch_store::transaction_store::TransactionStore;
 use rooch_store::RoochStore;
 use rooch_types::crypto::RoochKeyPair;
+use rooch_types::proof::TransactionProof;
 use rooch_types::sequencer::SequencerInfo;
 use rooch_types::service_status::ServiceStatus;
 use rooch_types::transaction::{LedgerTransaction, LedgerTxData};
@@ -101,6 +103,11 @@ impl SequencerActor {
             ServiceStatus::ReadOnlyMode => {
                 return Err(anyhow::anyhow!("The service is in read-only mode"));
             }
+            ServiceStatus::SyncMode => {
+                return Err(anyhow::anyhow!(
+                    "The service is in sync mode, transactions are only replayed from DA"
+                ));
+            }
             ServiceStatus::DateImportMode => {
                 if !tx_data.is_l1_block() && !tx_data.is_l1_tx() {
                     return Err(anyhow::anyhow!(
@@ -130,6 +137,12 @@ impl SequencerActor {
             .get_meta_store()
             .get_sequencer_info()?
             .ok_or_else(|| anyhow::anyhow!("Load sequencer info failed"))?;
+        // the txs after the last order in db have been reverted, fork the accumulator back
+        if self.tx_accumulator.get_info() != sequencer_info_db.last_accumulator_info {
+            self.tx_accumulator = self
+                .tx_accumulator
+                .fork(Some(sequencer_info_db.last_accumulator_info.clone()));
+        }
         self.last_sequencer_info = sequencer_info_db;
 
         Ok(self.last_sequencer_info.last_order + 1)
@@ -198,6 +211,135 @@ impl SequencerActor {
 
         Ok(tx)
     }
+
+    /// Replay a transaction which has been sequenced by another sequencer (e.g., from DA) in sync mode.
+    /// The tx_order must be the next order of local ledger,
+    /// and the tx accumulator root after appending must be equal to the one in sequence info
+    /// if `verify_accumulator` is true.
+    #[named]
+    pub fn replay(&mut self, mut tx: LedgerTransaction, verify_accumulator: bool) -> Result<()> {
+        let fn_name = function_name!();
+        let _timer = self
+            .metrics
+            .sequencer_sequence_latency_seconds
+            .with_label_values(&[fn_name])
+            .start_timer();
+
+        // Sync mode replays from DA, read-only mode replays from the leader (follower node)
+        if !self.service_status.is_sync_mode() && !self.service_status.is_read_only_mode() {
+            return Err(anyhow::anyhow!(
+                "Replay transaction is only allowed in sync mode or read-only mode"
+            ));
+        }
+
+        let tx_order = tx.sequence_info.tx_order;
+        let exp_tx_order = self.get_next_tx_order()?;
+        if tx_order != exp_tx_order {
+            return Err(anyhow::anyhow!(
+                "Replay tx order is not continuous, expect: {}, actual: {}",
+                exp_tx_order,
+                tx_order
+            ));
+        }
+        // the last sequencer info may be reverted in db, keep accumulator consistent with it
+        let last_accumulator_info = self.last_sequencer_info.last_accumulator_info.clone();
+        if self.tx_accumulator.get_info() != last_accumulator_info {
+            self.tx_accumulator = self
+                .tx_accumulator
+                .fork(Some(last_accumulator_info.clone()));
+        }
+
+        let tx_hash = tx.tx_hash();
+        let _tx_accumulator_root = self.tx_accumulator.append(vec![tx_hash].as_slice())?;
+        let tx_accumulator_unsaved_nodes = self.tx_accumulator.pop_unsaved_nodes();
+        let tx_accumulator_info = self.tx_accumulator.get_info();
+        if verify_accumulator
+            && tx_accumulator_info.accumulator_root != tx.sequence_info.tx_accumulator_root
+        {
+            self.tx_accumulator = self.tx_accumulator.fork(Some(last_accumulator_info));
+            return Err(anyhow::anyhow!(
+                "Replay tx accumulator root mismatch, tx_order: {}, expect: {:?}, actual: {:?}",
+                tx_order,
+                tx.sequence_info.tx_accumulator_root,
+                tx_accumulator_info.accumulator_root
+            ));
+        }
+
+        let sequencer_info = SequencerInfo::new(tx_order, tx_accumulator_info);
+        let save_ret = self.rooch_store.save_sequenced_tx(
+            tx_hash,
+            tx,
+            sequencer_info.clone(),
+            tx_accumulator_unsaved_nodes,
+            true,
+        );
+        if let Err(e) = save_ret {
+            self.tx_accumulator = self.tx_accumulator.fork(Some(last_accumulator_info));
+            self.service_status = ServiceStatus::Maintenance;
+            tracing::error!(
+                "Failed to save replayed tx, tx_order: {}, error: {:?}, set sequencer to Maintenance mode.",
+                tx_order,
+                e
+            );
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("Save replayed tx failed: {:?}", e),
+            )
+            .into());
+        }
+        self.tx_accumulator.clear_after_save();
+        info!(
+            "sequencer replayed tx_hash: {:?} tx_order: {:?}",
+            tx_hash, tx_order
+        );
+        self.last_sequencer_info = sequencer_info;
+
+        Ok(())
+    }
+
+    /// Get the proof of the tx in the tx accumulator,
+    /// with the accumulator root after the tx with `accumulator_tx_order` appended.
+    pub fn get_transaction_proof(
+        &self,
+        tx_order: u64,
+        accumulator_tx_order: Option<u64>,
+    ) -> Result<Option<TransactionProof>> {
+        let accumulator_tx_order = accumulator_tx_order.unwrap_or(tx_order);
+        if accumulator_tx_order < tx_order {
+            return Err(anyhow::anyhow!(
+                "accumulator_tx_order: {} must be >= tx_order: {}",
+                accumulator_tx_order,
+                tx_order
+            ));
+        }
+        let transaction_store = self.rooch_store.get_transaction_store();
+        let mut tx = match transaction_store.get_tx_by_order(tx_order)? {
+            Some(tx) => tx,
+            None => return Ok(None),
+        };
+        let accumulator_info = match transaction_store.get_tx_by_order(accumulator_tx_order)? {
+            Some(accumulator_tx) => accumulator_tx.sequence_info.tx_accumulator_info(),
+            None => return Ok(None),
+        };
+        let accumulator_root = accumulator_info.accumulator_root;
+        let tx_accumulator = MerkleAccumulator::new_with_info(
+            accumulator_info,
+            self.rooch_store.get_transaction_accumulator_store(),
+        );
+        // leaf index is the number of leaves before the tx appended
+        let leaf_index = tx.sequence_info.tx_accumulator_num_leaves - 1;
+        let proof = match tx_accumulator.get_proof(leaf_index)? {
+            Some(proof) => proof,
+            None => return Ok(None),
+        };
+        Ok(Some(TransactionProof {
+            tx_order,
+            tx_hash: tx.tx_hash(),
+            accumulator_root,
+            leaf_index,
+            proof,
+        }))
+    }
 }
 
 #[async_trait]
@@ -221,6 +363,17 @@ impl Handler<TransactionSequenceMessage> for SequencerActor {
     }
 }
 
+#[async_trait]
+impl Handler<ReplayTransactionMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: ReplayTransactionMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<()> {
+        self.replay(msg.tx, msg.verify_accumulator)
+    }
+}
+
 #[async_trait]
 impl Handler<GetTransactionByHashMessage> for SequencerActor {
     async fn handle(
@@ -279,6 +432,17 @@ impl Handler<EventData> for SequencerActor {
     }
 }
 
+#[async_trait]
+impl Handler<GetTransactionProofMessage> for SequencerActor {
+    async fn handle(
+        &mut self,
+        msg: GetTransactionProofMessage,
+        _ctx: &mut ActorContext,
+    ) -> Result<Option<TransactionProof>> {
+        self.get_transaction_proof(msg.tx_order, msg.accumulator_tx_order)
+    }
+}
+
 #[async_trait]
 impl Handler<GetSequencerInfoMessage> for SequencerActor {
     async fn handle(
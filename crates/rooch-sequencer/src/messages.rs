@@ -5,6 +5,7 @@ use anyhow::Result;
 use coerce::actor::message::Message;
 use moveos_types::h256::H256;
 use rooch_types::{
+    proof::TransactionProof,
     sequencer::SequencerInfo,
     transaction::{LedgerTransaction, LedgerTxData},
 };
@@ -20,6 +21,18 @@ impl Message for TransactionSequenceMessage {
     type Result = Result<LedgerTransaction>;
 }
 
+/// Replay Transaction Message, for transactions sequenced by another sequencer (e.g., from DA)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReplayTransactionMessage {
+    pub tx: LedgerTransaction,
+    /// Whether to verify tx accumulator root, it's false for known anomalies (e.g., duplicated tx hash)
+    pub verify_accumulator: bool,
+}
+
+impl Message for ReplayTransactionMessage {
+    type Result = Result<()>;
+}
+
 /// Get Transaction By Hash Message
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetTransactionByHashMessage {
@@ -62,3 +75,15 @@ pub struct GetSequencerInfoMessage {}
 impl Message for GetSequencerInfoMessage {
     type Result = Result<SequencerInfo>;
 }
+
+/// Get Transaction Proof Message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetTransactionProofMessage {
+    pub tx_order: u64,
+    /// Generate the proof with the accumulator root after this tx appended, default is the tx_order itself
+    pub accumulator_tx_order: Option<u64>,
+}
+
+impl Message for GetTransactionProofMessage {
+    type Result = Result<Option<TransactionProof>>;
+}
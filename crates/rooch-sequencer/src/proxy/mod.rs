@@ -3,12 +3,14 @@
 
 use crate::messages::{
     GetSequencerInfoMessage, GetSequencerOrderMessage, GetTransactionByHashMessage,
-    GetTransactionsByHashMessage, GetTxHashsMessage,
+    GetTransactionProofMessage, GetTransactionsByHashMessage, GetTxHashsMessage,
+    ReplayTransactionMessage,
 };
 use crate::{actor::sequencer::SequencerActor, messages::TransactionSequenceMessage};
 use anyhow::Result;
 use coerce::actor::ActorRef;
 use moveos_types::h256::H256;
+use rooch_types::proof::TransactionProof;
 use rooch_types::sequencer::SequencerInfo;
 use rooch_types::transaction::{LedgerTransaction, LedgerTxData};
 
@@ -26,6 +28,19 @@ impl SequencerProxy {
         self.actor.send(TransactionSequenceMessage { tx }).await?
     }
 
+    pub async fn replay_transaction(
+        &self,
+        tx: LedgerTransaction,
+        verify_accumulator: bool,
+    ) -> Result<()> {
+        self.actor
+            .send(ReplayTransactionMessage {
+                tx,
+                verify_accumulator,
+            })
+            .await?
+    }
+
     pub async fn get_transaction_by_hash(&self, hash: H256) -> Result<Option<LedgerTransaction>> {
         self.actor
             .send(GetTransactionByHashMessage { hash })
@@ -52,4 +67,17 @@ impl SequencerProxy {
     pub async fn get_sequencer_info(&self) -> Result<SequencerInfo> {
         self.actor.send(GetSequencerInfoMessage {}).await?
     }
+
+    pub async fn get_transaction_proof(
+        &self,
+        tx_order: u64,
+        accumulator_tx_order: Option<u64>,
+    ) -> Result<Option<TransactionProof>> {
+        self.actor
+            .send(GetTransactionProofMessage {
+                tx_order,
+                accumulator_tx_order,
+            })
+            .await?
+    }
 }
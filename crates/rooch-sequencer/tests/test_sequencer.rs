@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use accumulator::inmemory::InMemoryAccumulator;
 use anyhow::Result;
 use coerce::actor::{system::ActorSystem, IntoActor};
 use metrics::RegistryService;
@@ -137,3 +138,131 @@ async fn test_sequencer_concurrent() -> Result<()> {
 
     Ok(())
 }
+
+// A failed tx followed by more txs in a batch is sequenced but not executed,
+// they must be reverted in one step without the execution info of their predecessors.
+#[tokio::test]
+async fn test_sequencer_revert_unexecuted_txs() -> Result<()> {
+    let opt = RoochOpt::new_with_temp_store()?;
+    let registry = prometheus::Registry::new();
+    let rooch_db = init_rooch_db(&opt, &registry)?;
+    let mut sequencer = SequencerActor::new(
+        RoochKeyPair::generate_secp256k1(),
+        rooch_db.rooch_store.clone(),
+        ServiceStatus::Active,
+        &registry,
+        None,
+    )?;
+    let genesis_tx = rooch_db
+        .rooch_store
+        .transaction_store
+        .get_tx_by_order(0)?
+        .unwrap();
+    assert_eq!(sequencer.last_order(), 0);
+
+    // the failed tx and two txs after it
+    let mut unexecuted = vec![];
+    for _ in 0..3 {
+        let ledger_tx = sequencer.sequence(LedgerTxData::L2Tx(RoochTransaction::mock()))?;
+        unexecuted.push((ledger_tx.sequence_info.tx_order, ledger_tx.tx_hash()));
+    }
+    assert_eq!(
+        unexecuted
+            .iter()
+            .map(|(order, _)| *order)
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    // the previous tx of the last one is not executed
+    assert!(rooch_db.revert_tx(unexecuted[2].1).is_err());
+    // not the last txs
+    assert!(rooch_db
+        .revert_unexecuted_txs(unexecuted[..2].to_vec())
+        .is_err());
+    // not continuous
+    assert!(rooch_db
+        .revert_unexecuted_txs(vec![unexecuted[0], unexecuted[2]])
+        .is_err());
+    // the genesis tx is executed
+    assert!(rooch_db
+        .revert_unexecuted_txs(vec![(0, genesis_tx.tx_hash())])
+        .is_err());
+
+    rooch_db.revert_unexecuted_txs(unexecuted.clone())?;
+    for (tx_order, tx_hash) in &unexecuted {
+        assert!(rooch_db
+            .rooch_store
+            .transaction_store
+            .get_tx_by_order(*tx_order)?
+            .is_none());
+        assert!(rooch_db
+            .rooch_store
+            .transaction_store
+            .get_transaction_by_hash(*tx_hash)?
+            .is_none());
+    }
+
+    // the sequencer and the accumulator are rolled back to the genesis tx
+    let ledger_tx = sequencer.sequence(LedgerTxData::L2Tx(RoochTransaction::mock()))?;
+    assert_eq!(ledger_tx.sequence_info.tx_order, 1);
+    let accumulator =
+        InMemoryAccumulator::from_leaves(&[genesis_tx.tx_hash(), ledger_tx.tx_hash()]);
+    let accumulator_info = ledger_tx.sequence_info.tx_accumulator_info();
+    assert_eq!(accumulator_info.num_leaves, 2);
+    assert_eq!(accumulator_info.accumulator_root, accumulator.root_hash());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sequencer_replay() -> Result<()> {
+    let leader_opt = RoochOpt::new_with_temp_store()?;
+    let leader_registry = prometheus::Registry::new();
+    let leader_db = init_rooch_db(&leader_opt, &leader_registry)?;
+    let mut leader = SequencerActor::new(
+        RoochKeyPair::generate_secp256k1(),
+        leader_db.rooch_store,
+        ServiceStatus::Active,
+        &leader_registry,
+        None,
+    )?;
+    let mut ledger_txs = vec![];
+    for _ in 0..5 {
+        let tx_data = LedgerTxData::L2Tx(RoochTransaction::mock());
+        ledger_txs.push(leader.sequence(tx_data)?);
+    }
+
+    let replica_opt = RoochOpt::new_with_temp_store()?;
+    let replica_registry = prometheus::Registry::new();
+    let replica_db = init_rooch_db_with_instance(
+        &replica_opt,
+        RoochDB::generate_store_instance(replica_opt.store_config(), &replica_registry)?,
+        &replica_registry,
+    )?;
+    let mut replica = SequencerActor::new(
+        RoochKeyPair::generate_secp256k1(),
+        replica_db.rooch_store,
+        ServiceStatus::SyncMode,
+        &replica_registry,
+        None,
+    )?;
+    assert_eq!(replica.last_order(), leader.last_order() - 5);
+
+    // only replay is allowed in sync mode
+    let tx_data = LedgerTxData::L2Tx(RoochTransaction::mock());
+    assert!(replica.sequence(tx_data).is_err());
+
+    // tx order must be continuous
+    assert!(replica.replay(ledger_txs[1].clone(), true).is_err());
+
+    // tx accumulator root must be equal to leader's
+    let mut tampered_tx = ledger_txs[0].clone();
+    tampered_tx.data = LedgerTxData::L2Tx(RoochTransaction::mock());
+    assert!(replica.replay(tampered_tx, true).is_err());
+
+    for ledger_tx in ledger_txs {
+        replica.replay(ledger_tx, true)?;
+    }
+    assert_eq!(replica.last_order(), leader.last_order());
+    Ok(())
+}
@@ -418,4 +418,16 @@ impl ProposerStore for RoochStore {
     fn clear_last_proposed(&self) -> Result<()> {
         self.get_proposer_store().clear_last_proposed()
     }
+
+    fn get_last_committed(&self) -> Result<Option<u128>> {
+        self.get_proposer_store().get_last_committed()
+    }
+
+    fn set_last_committed(&self, block_number: u128) -> Result<()> {
+        self.get_proposer_store().set_last_committed(block_number)
+    }
+
+    fn clear_last_committed(&self) -> Result<()> {
+        self.get_proposer_store().clear_last_committed()
+    }
 }
@@ -5,6 +5,7 @@ use crate::PROPOSER_LAST_BLOCK_COLUMN_FAMILY_NAME;
 use raw_store::{derive_store, CodecKVStore};
 
 pub const PROPOSER_LAST_BLOCK_KEY: &str = "proposer_last_block";
+pub const PROPOSER_LAST_COMMITTED_BLOCK_KEY: &str = "proposer_last_committed_block";
 
 derive_store!(
     ProposerLastBlockStore,
@@ -17,6 +18,10 @@ pub trait ProposerStore {
     fn get_last_proposed(&self) -> anyhow::Result<Option<u128>>;
     fn set_last_proposed(&self, block_number: u128) -> anyhow::Result<()>;
     fn clear_last_proposed(&self) -> anyhow::Result<()>;
+    // last block which has been committed to the on-chain SCC
+    fn get_last_committed(&self) -> anyhow::Result<Option<u128>>;
+    fn set_last_committed(&self, block_number: u128) -> anyhow::Result<()>;
+    fn clear_last_committed(&self) -> anyhow::Result<()>;
 }
 
 #[derive(Clone)]
@@ -47,4 +52,19 @@ impl ProposerStore for ProposerDBStore {
         self.last_block_store
             .remove(PROPOSER_LAST_BLOCK_KEY.to_string())
     }
+
+    fn get_last_committed(&self) -> anyhow::Result<Option<u128>> {
+        self.last_block_store
+            .kv_get(PROPOSER_LAST_COMMITTED_BLOCK_KEY.to_string())
+    }
+
+    fn set_last_committed(&self, block_number: u128) -> anyhow::Result<()> {
+        self.last_block_store
+            .put_sync(PROPOSER_LAST_COMMITTED_BLOCK_KEY.to_string(), block_number)
+    }
+
+    fn clear_last_committed(&self) -> anyhow::Result<()> {
+        self.last_block_store
+            .remove(PROPOSER_LAST_COMMITTED_BLOCK_KEY.to_string())
+    }
 }
@@ -0,0 +1,43 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{ensure, Result};
+use moveos_types::h256::H256;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub const BACKUP_MANIFEST_FILE: &str = "manifest.json";
+
+/// The manifest of a RoochDB backup, written in the backup dir beside the store and indexer dirs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub chain_id: u64,
+    /// The timestamp in milliseconds when the backup was created
+    pub created_at: u64,
+    /// The last tx order sequenced in the backed up store
+    pub sequencer_order: u64,
+    /// The latest state root in the backed up store
+    pub state_root: H256,
+    pub state_size: u64,
+    /// The backed up indexer SQLite tables, empty if the indexer uses PostgreSQL
+    pub indexer_tables: Vec<String>,
+}
+
+impl BackupManifest {
+    pub fn load(backup_dir: &Path) -> Result<Self> {
+        let manifest_path = backup_dir.join(BACKUP_MANIFEST_FILE);
+        ensure!(
+            manifest_path.is_file(),
+            "Backup manifest {:?} not found",
+            manifest_path
+        );
+        let manifest = serde_json::from_slice(&std::fs::read(manifest_path)?)?;
+        Ok(manifest)
+    }
+
+    pub fn save(&self, backup_dir: &Path) -> Result<()> {
+        let manifest_path = backup_dir.join(BACKUP_MANIFEST_FILE);
+        std::fs::write(manifest_path, serde_json::to_vec_pretty(self)?)?;
+        Ok(())
+    }
+}
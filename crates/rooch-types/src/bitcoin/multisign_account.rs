@@ -93,6 +93,82 @@ impl MoveStructState for ParticipantInfo {
     }
 }
 
+pub const PROPOSAL_STATUS_PENDING: u8 = 0;
+pub const PROPOSAL_STATUS_APPROVED: u8 = 1;
+pub const PROPOSAL_STATUS_REJECTED: u8 = 2;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipantsProposal {
+    pub proposal_id: u64,
+    pub proposer: AccountAddress,
+    /// The multisign account BitcoinAddress when the proposal is created
+    pub base_bitcoin_address: BitcoinAddress,
+    /// The public keys of the participants after the proposal is executed
+    pub participant_public_keys: Vec<Vec<u8>>,
+    /// The threshold after the proposal is executed
+    pub threshold: u64,
+    /// The BitcoinAddress derived from the new participants and threshold
+    pub new_bitcoin_address: BitcoinAddress,
+    pub approvals: Vec<AccountAddress>,
+    pub rejections: Vec<AccountAddress>,
+    pub status: u8,
+    pub created_at: u64,
+}
+
+impl MoveStructType for ParticipantsProposal {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("ParticipantsProposal");
+}
+
+impl MoveStructState for ParticipantsProposal {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            u64::type_layout(),
+            AccountAddress::type_layout(),
+            BitcoinAddress::type_layout(),
+            Vec::<Vec<u8>>::type_layout(),
+            u64::type_layout(),
+            BitcoinAddress::type_layout(),
+            Vec::<AccountAddress>::type_layout(),
+            Vec::<AccountAddress>::type_layout(),
+            u8::type_layout(),
+            u64::type_layout(),
+        ])
+    }
+}
+
+/// The record of a participants change, the Bitcoin UTXOs of `from_bitcoin_address`
+/// still need to be transferred to `to_bitcoin_address` by the previous participants.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationRecord {
+    pub proposal_id: u64,
+    pub from_bitcoin_address: BitcoinAddress,
+    pub from_threshold: u64,
+    pub from_participant_public_keys: Vec<Vec<u8>>,
+    pub to_bitcoin_address: BitcoinAddress,
+    pub migrated_at: u64,
+}
+
+impl MoveStructType for MigrationRecord {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("MigrationRecord");
+}
+
+impl MoveStructState for MigrationRecord {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            u64::type_layout(),
+            BitcoinAddress::type_layout(),
+            u64::type_layout(),
+            Vec::<Vec<u8>>::type_layout(),
+            BitcoinAddress::type_layout(),
+            u64::type_layout(),
+        ])
+    }
+}
+
 pub fn generate_multisign_address(
     threshold: usize,
     public_keys: Vec<Vec<u8>>,
@@ -237,6 +313,14 @@ impl<'a> MultisignAccountModule<'a> {
         ident_str!("is_multisign_account");
     const PARTICIPANTS_FUNCTION_NAME: &'static IdentStr = ident_str!("participants");
     const THRESHOLD_FUNCTION_NAME: &'static IdentStr = ident_str!("threshold");
+    const PROPOSE_PARTICIPANTS_UPDATE_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("propose_participants_update_entry");
+    const APPROVE_PARTICIPANTS_UPDATE_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("approve_participants_update_entry");
+    const REJECT_PARTICIPANTS_UPDATE_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("reject_participants_update_entry");
+    const PROPOSALS_FUNCTION_NAME: &'static IdentStr = ident_str!("proposals");
+    const MIGRATION_RECORDS_FUNCTION_NAME: &'static IdentStr = ident_str!("migration_records");
 
     pub fn initialize_multisig_account_action(
         threshold: u64,
@@ -352,6 +436,98 @@ impl<'a> MultisignAccountModule<'a> {
             })?;
         Ok(participants)
     }
+
+    pub fn propose_participants_update_action(
+        multisign_address: AccountAddress,
+        add_public_keys: Vec<Vec<u8>>,
+        remove_public_keys: Vec<Vec<u8>>,
+        threshold: u64,
+    ) -> MoveAction {
+        Self::create_move_action(
+            Self::PROPOSE_PARTICIPANTS_UPDATE_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                multisign_address.to_move_value(),
+                add_public_keys.to_move_value(),
+                remove_public_keys.to_move_value(),
+                threshold.to_move_value(),
+            ],
+        )
+    }
+
+    pub fn approve_participants_update_action(
+        multisign_address: AccountAddress,
+        proposal_id: u64,
+    ) -> MoveAction {
+        Self::create_move_action(
+            Self::APPROVE_PARTICIPANTS_UPDATE_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                multisign_address.to_move_value(),
+                proposal_id.to_move_value(),
+            ],
+        )
+    }
+
+    pub fn reject_participants_update_action(
+        multisign_address: AccountAddress,
+        proposal_id: u64,
+    ) -> MoveAction {
+        Self::create_move_action(
+            Self::REJECT_PARTICIPANTS_UPDATE_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![
+                multisign_address.to_move_value(),
+                proposal_id.to_move_value(),
+            ],
+        )
+    }
+
+    pub fn proposals(
+        &self,
+        multisign_address: AccountAddress,
+    ) -> Result<Vec<ParticipantsProposal>> {
+        let function_call = Self::create_function_call(
+            Self::PROPOSALS_FUNCTION_NAME,
+            vec![],
+            vec![multisign_address.to_move_value()],
+        );
+
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let proposals = self
+            .caller
+            .call_function(&ctx, function_call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<Vec<ParticipantsProposal>>(&value.value)
+                    .expect("should be a valid vector of ParticipantsProposal")
+            })?;
+        Ok(proposals)
+    }
+
+    pub fn migration_records(
+        &self,
+        multisign_address: AccountAddress,
+    ) -> Result<Vec<MigrationRecord>> {
+        let function_call = Self::create_function_call(
+            Self::MIGRATION_RECORDS_FUNCTION_NAME,
+            vec![],
+            vec![multisign_address.to_move_value()],
+        );
+
+        let ctx = TxContext::new_readonly_ctx(AccountAddress::ZERO);
+        let records = self
+            .caller
+            .call_function(&ctx, function_call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<Vec<MigrationRecord>>(&value.value)
+                    .expect("should be a valid vector of MigrationRecord")
+            })?;
+        Ok(records)
+    }
 }
 
 impl<'a> ModuleBinding<'a> for MultisignAccountModule<'a> {
@@ -0,0 +1,618 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::types::OutPoint;
+use crate::addresses::BITCOIN_MOVE_ADDRESS;
+use anyhow::{bail, ensure, Result};
+use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
+use moveos_types::{
+    h256::H256,
+    move_std::option::MoveOption,
+    moveos_std::object::{self, ObjectID, ObjectMeta},
+    state::{MoveState, MoveStructState, MoveStructType, MoveType, ObjectState},
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub const MODULE_NAME: &IdentStr = ident_str!("runes");
+
+/// The spacer between the letters of a rune name
+pub const SPACER: char = '•';
+pub const MAX_DIVISIBILITY: u8 = 38;
+pub const MAX_SPACERS: u32 = 0b00000111_11111111_11111111_11111111;
+
+#[derive(Debug, Default, PartialEq, Clone, Copy, Hash, Eq, PartialOrd, Ord)]
+pub struct RuneId {
+    pub block: u64,
+    pub tx: u32,
+}
+
+impl RuneId {
+    pub fn new(block: u64, tx: u32) -> Self {
+        Self { block, tx }
+    }
+
+    /// Apply the delta encoding of the edicts, return None if overflow
+    pub fn next(self, block: u128, tx: u128) -> Option<Self> {
+        let block = u64::try_from(block).ok()?;
+        let tx = u32::try_from(tx).ok()?;
+        let next_block = self.block.checked_add(block)?;
+        let next_tx = if block == 0 {
+            self.tx.checked_add(tx)?
+        } else {
+            tx
+        };
+        Some(Self::new(next_block, next_tx))
+    }
+
+    pub fn object_id(&self) -> ObjectID {
+        derive_rune_entry_id(self)
+    }
+}
+
+impl Display for RuneId {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}:{}", self.block, self.tx)
+    }
+}
+
+impl FromStr for RuneId {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let Some((block, tx)) = s.split_once(':') else {
+            bail!("Invalid RuneId: {}, should be `block:tx`", s);
+        };
+        Ok(Self::new(block.parse()?, tx.parse()?))
+    }
+}
+
+impl Serialize for RuneId {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            #[derive(Serialize)]
+            struct Value {
+                block: u64,
+                tx: u32,
+            }
+            Value {
+                block: self.block,
+                tx: self.tx,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RuneId {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            Self::from_str(&s).map_err(serde::de::Error::custom)
+        } else {
+            #[derive(Deserialize)]
+            struct Value {
+                block: u64,
+                tx: u32,
+            }
+            let value = Value::deserialize(deserializer)?;
+            Ok(RuneId::new(value.block, value.tx))
+        }
+    }
+}
+
+impl MoveStructType for RuneId {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RuneId");
+}
+
+impl MoveStructState for RuneId {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![u64::type_layout(), u32::type_layout()])
+    }
+}
+
+/// The rune name, encoded as a modified base-26 integer
+#[derive(Debug, Default, PartialEq, Clone, Copy, Hash, Eq, PartialOrd, Ord)]
+pub struct Rune(pub u128);
+
+impl Rune {
+    /// The commitment of the rune name in the etching transaction's tapscript
+    pub fn commitment(self) -> Vec<u8> {
+        let bytes = self.0.to_le_bytes();
+        let mut end = bytes.len();
+        while end > 0 && bytes[end - 1] == 0 {
+            end -= 1;
+        }
+        bytes[..end].to_vec()
+    }
+}
+
+impl Display for Rune {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let mut n = self.0;
+        if n == u128::MAX {
+            return write!(f, "BCGDENLQRQWDSLRUGSNLBTMFIJAV");
+        }
+
+        n += 1;
+        let mut symbol = String::new();
+        while n > 0 {
+            symbol.push(
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZ"
+                    .chars()
+                    .nth(((n - 1) % 26) as usize)
+                    .unwrap(),
+            );
+            n = (n - 1) / 26;
+        }
+
+        for c in symbol.chars().rev() {
+            write!(f, "{c}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Rune {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut x = 0u128;
+        for (i, c) in s.chars().enumerate() {
+            if i > 0 {
+                x = x
+                    .checked_add(1)
+                    .ok_or_else(|| anyhow::anyhow!("Rune name overflow: {}", s))?;
+            }
+            x = x
+                .checked_mul(26)
+                .ok_or_else(|| anyhow::anyhow!("Rune name overflow: {}", s))?;
+            match c {
+                'A'..='Z' => {
+                    x = x
+                        .checked_add(c as u128 - 'A' as u128)
+                        .ok_or_else(|| anyhow::anyhow!("Rune name overflow: {}", s))?;
+                }
+                _ => bail!("Invalid character `{}` in rune name: {}", c, s),
+            }
+        }
+        Ok(Rune(x))
+    }
+}
+
+/// The rune name with the spacers, such as `UNCOMMON•GOODS`
+#[derive(Debug, Default, PartialEq, Clone, Copy, Hash, Eq)]
+pub struct SpacedRune {
+    pub rune: Rune,
+    pub spacers: u32,
+}
+
+impl SpacedRune {
+    pub fn new(rune: Rune, spacers: u32) -> Self {
+        Self { rune, spacers }
+    }
+}
+
+impl Display for SpacedRune {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let rune = self.rune.to_string();
+
+        for (i, c) in rune.chars().enumerate() {
+            write!(f, "{c}")?;
+
+            if i < rune.len() - 1 && self.spacers & 1 << i != 0 {
+                write!(f, "{SPACER}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for SpacedRune {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut rune = String::new();
+        let mut spacers = 0u32;
+
+        for c in s.chars() {
+            match c {
+                'A'..='Z' => rune.push(c),
+                '.' | '•' => {
+                    ensure!(!rune.is_empty(), "Leading spacer in rune name: {}", s);
+                    let flag = 1 << (rune.len() - 1);
+                    ensure!(spacers & flag == 0, "Double spacer in rune name: {}", s);
+                    spacers |= flag;
+                }
+                _ => bail!("Invalid character `{}` in rune name: {}", c, s),
+            }
+        }
+
+        ensure!(
+            32 - spacers.leading_zeros() < rune.len().try_into().unwrap_or(u32::MAX),
+            "Trailing spacer in rune name: {}",
+            s
+        );
+
+        Ok(SpacedRune {
+            rune: rune.parse()?,
+            spacers,
+        })
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Terms {
+    pub amount: MoveOption<u128>,
+    pub cap: MoveOption<u128>,
+    pub height_start: MoveOption<u64>,
+    pub height_end: MoveOption<u64>,
+    pub offset_start: MoveOption<u64>,
+    pub offset_end: MoveOption<u64>,
+}
+
+impl MoveStructType for Terms {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Terms");
+}
+
+impl MoveStructState for Terms {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            MoveOption::<u128>::type_layout(),
+            MoveOption::<u128>::type_layout(),
+            MoveOption::<u64>::type_layout(),
+            MoveOption::<u64>::type_layout(),
+            MoveOption::<u64>::type_layout(),
+            MoveOption::<u64>::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Etching {
+    pub divisibility: MoveOption<u8>,
+    pub premine: MoveOption<u128>,
+    pub rune: MoveOption<u128>,
+    pub spacers: MoveOption<u32>,
+    /// The unicode code point of the currency symbol
+    pub symbol: MoveOption<u32>,
+    pub terms: MoveOption<Terms>,
+    pub turbo: bool,
+}
+
+impl Etching {
+    /// The max supply of the etching, return None if overflow
+    pub fn supply(&self) -> Option<u128> {
+        let premine = self.premine.as_ref().copied().unwrap_or_default();
+        let (cap, amount) = match self.terms.as_ref() {
+            Some(terms) => (
+                terms.cap.as_ref().copied().unwrap_or_default(),
+                terms.amount.as_ref().copied().unwrap_or_default(),
+            ),
+            None => (0, 0),
+        };
+        premine.checked_add(cap.checked_mul(amount)?)
+    }
+}
+
+impl MoveStructType for Etching {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Etching");
+}
+
+impl MoveStructState for Etching {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            MoveOption::<u8>::type_layout(),
+            MoveOption::<u128>::type_layout(),
+            MoveOption::<u128>::type_layout(),
+            MoveOption::<u32>::type_layout(),
+            MoveOption::<u32>::type_layout(),
+            MoveOption::<Terms>::type_layout(),
+            bool::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct Edict {
+    pub id: RuneId,
+    pub amount: u128,
+    pub output: u32,
+}
+
+impl MoveStructType for Edict {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Edict");
+}
+
+impl MoveStructState for Edict {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            RuneId::type_layout(),
+            u128::type_layout(),
+            u32::type_layout(),
+        ])
+    }
+}
+
+/// The reasons why a runestone is a cenotaph, the value is the `flaw` code in Move
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum Flaw {
+    EdictOutput = 1,
+    EdictRuneId = 2,
+    InvalidScript = 3,
+    Opcode = 4,
+    SupplyOverflow = 5,
+    TrailingIntegers = 6,
+    TruncatedField = 7,
+    UnrecognizedEvenTag = 8,
+    UnrecognizedFlag = 9,
+    Varint = 10,
+}
+
+/// The deciphered runestone of a transaction.
+/// If the `flaw` is some, the runestone is a cenotaph, only the `etching.rune` and `mint` are kept,
+/// and all the runes of the transaction inputs are burned.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Runestone {
+    pub edicts: Vec<Edict>,
+    pub etching: MoveOption<Etching>,
+    pub mint: MoveOption<RuneId>,
+    pub pointer: MoveOption<u32>,
+    pub flaw: MoveOption<u8>,
+}
+
+impl Runestone {
+    pub fn cenotaph(flaw: Flaw, rune: Option<u128>, mint: Option<RuneId>) -> Self {
+        Self {
+            edicts: vec![],
+            etching: rune
+                .map(|rune| Etching {
+                    rune: Some(rune).into(),
+                    ..Default::default()
+                })
+                .into(),
+            mint: mint.into(),
+            pointer: None.into(),
+            flaw: Some(flaw as u8).into(),
+        }
+    }
+
+    pub fn is_cenotaph(&self) -> bool {
+        self.flaw.as_ref().is_some()
+    }
+}
+
+impl MoveStructType for Runestone {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Runestone");
+}
+
+impl MoveStructState for Runestone {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            Vec::<Edict>::type_layout(),
+            MoveOption::<Etching>::type_layout(),
+            MoveOption::<RuneId>::type_layout(),
+            MoveOption::<u32>::type_layout(),
+            MoveOption::<u8>::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct RuneEntry {
+    pub id: RuneId,
+    pub rune: u128,
+    pub spacers: u32,
+    pub divisibility: u8,
+    pub symbol: MoveOption<u32>,
+    pub premine: u128,
+    pub terms: MoveOption<Terms>,
+    pub mints: u128,
+    pub burned: u128,
+    pub number: u64,
+    /// The txid of the etching transaction
+    pub etching: AccountAddress,
+    pub turbo: bool,
+    pub timestamp: u64,
+}
+
+impl RuneEntry {
+    pub fn spaced_rune(&self) -> SpacedRune {
+        SpacedRune::new(Rune(self.rune), self.spacers)
+    }
+
+    pub fn symbol(&self) -> Option<char> {
+        self.symbol.as_ref().and_then(|s| char::from_u32(*s))
+    }
+}
+
+impl MoveStructType for RuneEntry {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RuneEntry");
+}
+
+impl MoveStructState for RuneEntry {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            RuneId::type_layout(),
+            u128::type_layout(),
+            u32::type_layout(),
+            u8::type_layout(),
+            MoveOption::<u32>::type_layout(),
+            u128::type_layout(),
+            MoveOption::<Terms>::type_layout(),
+            u128::type_layout(),
+            u128::type_layout(),
+            u64::type_layout(),
+            AccountAddress::type_layout(),
+            bool::type_layout(),
+            u64::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct RuneBalance {
+    pub id: RuneId,
+    pub amount: u128,
+}
+
+impl MoveStructType for RuneBalance {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RuneBalance");
+}
+
+impl MoveStructState for RuneBalance {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            RuneId::type_layout(),
+            u128::type_layout(),
+        ])
+    }
+}
+
+/// The rune balances of a UTXO, the object is sealed to the UTXO and owned by the UTXO owner
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct RuneBalances {
+    pub outpoint: OutPoint,
+    pub balances: Vec<RuneBalance>,
+}
+
+impl RuneBalances {
+    pub fn object_id(&self) -> ObjectID {
+        derive_rune_balances_id(&self.outpoint)
+    }
+}
+
+impl MoveStructType for RuneBalances {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RuneBalances");
+}
+
+impl MoveStructState for RuneBalances {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            OutPoint::type_layout(),
+            Vec::<RuneBalance>::type_layout(),
+        ])
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuneStore {
+    /// The number of etched runes, the rune -> RuneId mapping is stored in the fields of the store object
+    pub runes: u64,
+}
+
+impl RuneStore {
+    pub fn object_id() -> ObjectID {
+        object::named_object_id(&Self::struct_tag())
+    }
+
+    /// The RuneStore with the runes imported at genesis, the fields are in the `state_root`
+    pub fn genesis_with_state_root(state_root: H256, size: u64, store: RuneStore) -> ObjectState {
+        let id = Self::object_id();
+        let mut metadata = ObjectMeta::genesis_meta(id, Self::type_tag());
+        metadata.state_root = Some(state_root);
+        metadata.size = size;
+        metadata.to_shared();
+        ObjectState::new_with_struct(metadata, store)
+            .expect("Create RuneStore Object should success")
+    }
+}
+
+impl MoveStructType for RuneStore {
+    const ADDRESS: AccountAddress = BITCOIN_MOVE_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("RuneStore");
+}
+
+impl MoveStructState for RuneStore {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![u64::type_layout()])
+    }
+}
+
+pub fn derive_rune_entry_id(rune_id: &RuneId) -> ObjectID {
+    object::custom_object_id_with_parent::<RuneId, RuneEntry>(RuneStore::object_id(), rune_id)
+}
+
+pub fn derive_rune_balances_id(outpoint: &OutPoint) -> ObjectID {
+    object::custom_object_id_with_parent::<OutPoint, RuneBalances>(RuneStore::object_id(), outpoint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rune_name() {
+        for (n, name) in [
+            (0, "A"),
+            (25, "Z"),
+            (26, "AA"),
+            (701, "ZZ"),
+            (702, "AAA"),
+            (u128::MAX, "BCGDENLQRQWDSLRUGSNLBTMFIJAV"),
+        ] {
+            assert_eq!(Rune(n).to_string(), name);
+            assert_eq!(Rune::from_str(name).unwrap(), Rune(n));
+        }
+        assert!(Rune::from_str("BCGDENLQRQWDSLRUGSNLBTMFIJAW").is_err());
+        assert!(Rune::from_str("a").is_err());
+    }
+
+    #[test]
+    fn test_spaced_rune() {
+        let spaced_rune = SpacedRune::from_str("UNCOMMON•GOODS").unwrap();
+        assert_eq!(spaced_rune.spacers, 0b1000_0000);
+        assert_eq!(spaced_rune.to_string(), "UNCOMMON•GOODS");
+        assert_eq!(SpacedRune::from_str("A.B.C").unwrap().to_string(), "A•B•C");
+        assert!(SpacedRune::from_str("•A").is_err());
+        assert!(SpacedRune::from_str("A•").is_err());
+        assert!(SpacedRune::from_str("A••B").is_err());
+    }
+
+    #[test]
+    fn test_rune_id() {
+        let rune_id = RuneId::from_str("840000:1").unwrap();
+        assert_eq!(rune_id, RuneId::new(840000, 1));
+        assert_eq!(rune_id.to_string(), "840000:1");
+        assert_eq!(rune_id.next(0, 1), Some(RuneId::new(840000, 2)));
+        assert_eq!(rune_id.next(1, 1), Some(RuneId::new(840001, 1)));
+        assert_eq!(rune_id.next(0, u32::MAX as u128), None);
+        assert!(RuneId::from_str("840000").is_err());
+    }
+
+    #[test]
+    fn test_commitment() {
+        assert_eq!(Rune(0).commitment(), Vec::<u8>::new());
+        assert_eq!(Rune(1).commitment(), vec![1]);
+        assert_eq!(Rune(256).commitment(), vec![0, 1]);
+    }
+}
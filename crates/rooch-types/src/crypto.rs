@@ -597,12 +597,18 @@ impl Signature {
     /// This is useful for the MultiSig to construct the bitmap in [struct MultiPublicKey].
     pub fn to_public_key(&self) -> Result<PublicKey, RoochError> {
         let bytes = self.public_key_bytes();
-        // Process Rooch signature by default
-        Ok(PublicKey::Ed25519(
-            (&Ed25519PublicKey::from_bytes(bytes)
-                .map_err(|_| RoochError::KeyConversionError("Cannot parse pk".to_owned()))?)
-                .into(),
-        ))
+        match self.scheme() {
+            SignatureScheme::Ed25519 => Ok(PublicKey::Ed25519(
+                (&Ed25519PublicKey::from_bytes(bytes)
+                    .map_err(|_| RoochError::KeyConversionError("Cannot parse pk".to_owned()))?)
+                    .into(),
+            )),
+            SignatureScheme::Secp256k1 => Ok(PublicKey::Secp256k1(
+                (&Secp256k1PublicKey::from_bytes(bytes)
+                    .map_err(|_| RoochError::KeyConversionError("Cannot parse pk".to_owned()))?)
+                    .into(),
+            )),
+        }
     }
 }
 
@@ -859,4 +865,32 @@ mod tests {
         let signature = kp.sign_secure(&value);
         assert!(signature.verify_secure(&value).is_ok());
     }
+
+    #[test]
+    fn test_signature_to_public_key() {
+        let message = b"hello world";
+        for kp in [
+            RoochKeyPair::generate_secp256k1(),
+            RoochKeyPair::generate_ed25519(),
+        ] {
+            let signature = Signature::from_bytes(kp.sign(message).as_ref()).unwrap();
+            assert_eq!(signature.to_public_key().unwrap(), kp.public());
+        }
+
+        let secp256k1_kp = RoochKeyPair::generate_secp256k1();
+        let ed25519_kp = RoochKeyPair::generate_ed25519();
+        let secp256k1_pk = Signature::sign(message, &secp256k1_kp)
+            .to_public_key()
+            .unwrap();
+        assert!(matches!(secp256k1_pk, PublicKey::Secp256k1(_)));
+        assert_eq!(
+            secp256k1_pk.bitcoin_address().unwrap(),
+            secp256k1_kp.public().bitcoin_address().unwrap()
+        );
+        let ed25519_pk = Signature::sign(message, &ed25519_kp)
+            .to_public_key()
+            .unwrap();
+        assert!(matches!(ed25519_pk, PublicKey::Ed25519(_)));
+        assert_ne!(secp256k1_pk, ed25519_pk);
+    }
 }
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::address::BitcoinAddress;
 use crate::crypto::{RoochKeyPair, RoochSignature, Signature};
 use crate::transaction::LedgerTransaction;
 use fastcrypto::traits::ToFromBytes;
@@ -94,6 +95,23 @@ impl DABatchMeta {
     }
 }
 
+impl DABatchMeta {
+    /// The hash signed by the sequencer for the state root after executing the batch
+    pub fn state_root_hash(&self, state_root: &H256) -> H256 {
+        let bytes = bcs::to_bytes(&(self, state_root)).expect("encode batch_meta should success");
+        sha2_256_of(&bytes)
+    }
+}
+
+/// The state root after executing the transactions of the batch,
+/// signed by the sequencer together with the batch meta.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DABatchStateRoot {
+    pub state_root: H256,
+    /// signature of DABatchMeta::state_root_hash(state_root), signed by sequencer
+    pub signature: Vec<u8>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct SignedDABatchMeta {
     pub meta: DABatchMeta,
@@ -109,6 +127,9 @@ pub struct DABatch {
     pub meta_signature: Vec<u8>,
     /// encoded Vec<LedgerTransaction>
     pub tx_list_bytes: Vec<u8>,
+    /// The signed state root, carried by ChunkV1 only
+    #[serde(skip)]
+    pub state_root: Option<DABatchStateRoot>,
 }
 
 impl DABatch {
@@ -135,9 +156,22 @@ impl DABatch {
             meta: batch_meta,
             meta_signature,
             tx_list_bytes,
+            state_root: None,
         })
     }
 
+    /// Sign the state root after executing the transactions of the batch
+    pub fn sign_state_root(&mut self, state_root: H256, sequencer_key: &RoochKeyPair) {
+        let state_root_hash = self.meta.state_root_hash(&state_root);
+        let signature = Signature::sign(&state_root_hash.0, sequencer_key)
+            .as_ref()
+            .to_vec();
+        self.state_root = Some(DABatchStateRoot {
+            state_root,
+            signature,
+        });
+    }
+
     pub fn get_hash(&self) -> H256 {
         let meta_bytes = bcs::to_bytes(&self.meta).expect("encode batch_meta should success");
         sha2_256_of(&meta_bytes)
@@ -163,6 +197,56 @@ impl DABatch {
         Ok(())
     }
 
+    /// Verify the batch is signed by the sequencer, helpful when syncing batches from untrusted DA backends.
+    pub fn verify_sequencer(&self, sequencer: &BitcoinAddress) -> anyhow::Result<()> {
+        let meta_signature = Signature::from_bytes(&self.meta_signature)?;
+        let signer = meta_signature.to_public_key()?.bitcoin_address()?;
+        if &signer != sequencer {
+            return Err(anyhow::anyhow!(
+                "batch meta signer mismatch: exp: {}, act: {}",
+                sequencer,
+                signer
+            ));
+        }
+        Ok(())
+    }
+
+    /// Verify the state root after executing the batch locally is the one signed by the sequencer.
+    /// Returns false if there is no signed state root in the batch (e.g., unpacked from ChunkV0).
+    pub fn verify_state_root(
+        &self,
+        sequencer: &BitcoinAddress,
+        state_root: H256,
+    ) -> anyhow::Result<bool> {
+        let signed_state_root = match &self.state_root {
+            Some(signed_state_root) => signed_state_root,
+            None => return Ok(false),
+        };
+        let signature = Signature::from_bytes(&signed_state_root.signature)?;
+        signature.verify(
+            self.meta
+                .state_root_hash(&signed_state_root.state_root)
+                .as_bytes(),
+        )?;
+        let signer = signature.to_public_key()?.bitcoin_address()?;
+        if &signer != sequencer {
+            return Err(anyhow::anyhow!(
+                "batch state root signer mismatch: exp: {}, act: {}",
+                sequencer,
+                signer
+            ));
+        }
+        if signed_state_root.state_root != state_root {
+            return Err(anyhow::anyhow!(
+                "state root mismatch for block {}: exp: {:?}, act: {:?}",
+                self.meta.block_range.block_number,
+                signed_state_root.state_root,
+                state_root
+            ));
+        }
+        Ok(true)
+    }
+
     fn verify_order_and_signature(&self) -> anyhow::Result<()> {
         let tx_list = self.get_tx_list()?;
         verify_tx_order(
@@ -218,3 +302,68 @@ fn verify_tx_order(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_ledger_transaction_with_order;
+
+    #[test]
+    fn test_verify_sequencer() {
+        let keypair = RoochKeyPair::generate_secp256k1();
+        let other_keypair = RoochKeyPair::generate_secp256k1();
+
+        let tx_list = (0..4)
+            .map(|i| random_ledger_transaction_with_order(i as u64 + 1, &keypair))
+            .collect::<Vec<_>>();
+        let batch = DABatch::new(1, 1, 4, &tx_list, &keypair).expect("create batch should success");
+
+        let sequencer = keypair.public().bitcoin_address().unwrap();
+        assert!(batch.verify_sequencer(&sequencer).is_ok());
+        let other = other_keypair.public().bitcoin_address().unwrap();
+        assert!(batch.verify_sequencer(&other).is_err());
+    }
+
+    #[test]
+    fn test_verify_state_root() {
+        let keypair = RoochKeyPair::generate_secp256k1();
+        let other_keypair = RoochKeyPair::generate_secp256k1();
+        let sequencer = keypair.public().bitcoin_address().unwrap();
+
+        let tx_list = (0..4)
+            .map(|i| random_ledger_transaction_with_order(i as u64 + 1, &keypair))
+            .collect::<Vec<_>>();
+        let mut batch =
+            DABatch::new(1, 1, 4, &tx_list, &keypair).expect("create batch should success");
+        let state_root = H256::random();
+        // no signed state root
+        assert!(!batch.verify_state_root(&sequencer, state_root).unwrap());
+
+        batch.sign_state_root(state_root, &keypair);
+        assert!(batch.verify_state_root(&sequencer, state_root).unwrap());
+        assert!(batch.verify_state_root(&sequencer, H256::random()).is_err());
+
+        // signed by other key
+        let mut other_signed_batch = batch.clone();
+        other_signed_batch.sign_state_root(state_root, &other_keypair);
+        assert!(other_signed_batch
+            .verify_state_root(&sequencer, state_root)
+            .is_err());
+
+        // the signed state root is tampered
+        let mut tampered_batch = batch.clone();
+        let tampered_state_root = H256::random();
+        tampered_batch.state_root.as_mut().unwrap().state_root = tampered_state_root;
+        assert!(tampered_batch
+            .verify_state_root(&sequencer, tampered_state_root)
+            .is_err());
+
+        // the state root is signed with the meta of another batch
+        let mut other_batch =
+            DABatch::new(2, 1, 4, &tx_list, &keypair).expect("create batch should success");
+        other_batch.state_root = batch.state_root.clone();
+        assert!(other_batch
+            .verify_state_root(&sequencer, state_root)
+            .is_err());
+    }
+}
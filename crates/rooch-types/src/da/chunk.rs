@@ -1,9 +1,10 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::da::batch::DABatch;
+use crate::da::batch::{DABatch, DABatchStateRoot};
 use crate::da::segment::{Segment, SegmentID, SegmentV0};
 use lz4::EncoderBuilder;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::io;
 
@@ -11,12 +12,15 @@ use std::io;
 pub enum ChunkVersion {
     V0,
     Unknown(u8),
+    // appended after Unknown to keep the bcs encoding of the existing versions
+    V1,
 }
 
 impl From<u8> for ChunkVersion {
     fn from(num: u8) -> Self {
         match num {
             0 => ChunkVersion::V0,
+            1 => ChunkVersion::V1,
             // ...
             _ => Self::Unknown(num),
         }
@@ -27,6 +31,7 @@ impl From<ChunkVersion> for u8 {
     fn from(version: ChunkVersion) -> Self {
         match version {
             ChunkVersion::V0 => 0,
+            ChunkVersion::V1 => 1,
             ChunkVersion::Unknown(num) => num,
         }
     }
@@ -59,17 +64,65 @@ impl From<DABatch> for ChunkV0 {
 
 impl ChunkV0 {
     fn to_bytes(&self) -> Vec<u8> {
-        let mut compressed_bytes = Vec::new();
+        compress_chunk(self)
+    }
+}
 
-        {
-            let mut encoder = EncoderBuilder::new().build(&mut compressed_bytes).unwrap();
-            bcs::serialize_into(&mut encoder, self).unwrap();
-            let (_output, result) = encoder.finish();
-            result.unwrap();
-        }
+// compress the bcs encoded chunk by lz4
+fn compress_chunk<T: Serialize>(chunk: &T) -> Vec<u8> {
+    let mut compressed_bytes = Vec::new();
 
-        compressed_bytes
+    {
+        let mut encoder = EncoderBuilder::new().build(&mut compressed_bytes).unwrap();
+        bcs::serialize_into(&mut encoder, chunk).unwrap();
+        let (_output, result) = encoder.finish();
+        result.unwrap();
     }
+
+    compressed_bytes
+}
+
+// split the compressed chunk into segments, the segment layout is the same for all chunk versions
+fn split_chunk(
+    version: ChunkVersion,
+    chunk_id: u128,
+    bytes: Vec<u8>,
+    max_segment_size: usize,
+) -> Vec<Box<dyn Segment>> {
+    let segments_data = bytes.chunks(max_segment_size);
+    let segments_count = segments_data.len();
+
+    segments_data
+        .enumerate()
+        .map(|(i, data)| {
+            Box::new(SegmentV0 {
+                version: version.clone(),
+                id: SegmentID {
+                    chunk_id,
+                    segment_number: i as u64,
+                },
+                is_last: i == segments_count - 1, // extra info overhead is much smaller than max_block_size - max_segment_size
+                data_len: data.len() as u64,
+                // *_checksum will be filled in to_bytes method of Segment
+                data_checksum: 0,
+                checksum: 0,
+                data: data.to_vec(),
+            }) as Box<dyn Segment>
+        })
+        .collect::<Vec<_>>()
+}
+
+// decompress the chunk from the segments
+fn decompress_chunk<T: DeserializeOwned>(segments: Vec<Box<dyn Segment>>) -> anyhow::Result<T> {
+    let bytes = segments
+        .iter()
+        .flat_map(|segment| segment.get_data())
+        .collect::<Vec<_>>();
+
+    let decoder = lz4::Decoder::new(&bytes[..])?;
+    let mut decompressed_reader = io::BufReader::new(decoder);
+    let chunk: T = bcs::from_reader(&mut decompressed_reader)?;
+    Ok(chunk)
 }
 
 impl Chunk for ChunkV0 {
@@ -78,28 +131,12 @@ impl Chunk for ChunkV0 {
     }
 
     fn to_segments(&self, max_segment_size: usize) -> Vec<Box<dyn Segment>> {
-        let bytes = self.to_bytes();
-        let segments_data = bytes.chunks(max_segment_size);
-        let segments_count = segments_data.len();
-
-        let chunk_id = self.get_chunk_id();
-        segments_data
-            .enumerate()
-            .map(|(i, data)| {
-                Box::new(SegmentV0 {
-                    id: SegmentID {
-                        chunk_id,
-                        segment_number: i as u64,
-                    },
-                    is_last: i == segments_count - 1, // extra info overhead is much smaller than max_block_size - max_segment_size
-                    data_len: data.len() as u64,
-                    // *_checksum will be filled in to_bytes method of Segment
-                    data_checksum: 0,
-                    checksum: 0,
-                    data: data.to_vec(),
-                }) as Box<dyn Segment>
-            })
-            .collect::<Vec<_>>()
+        split_chunk(
+            ChunkVersion::V0,
+            self.get_chunk_id(),
+            self.to_bytes(),
+            max_segment_size,
+        )
     }
 
     fn get_batches(&self) -> Vec<DABatch> {
@@ -153,6 +190,7 @@ pub fn chunk_from_segments(segments: Vec<Box<dyn Segment>>) -> anyhow::Result<Bo
 
     match version {
         ChunkVersion::V0 => Ok(Box::new(ChunkV0::from_segments(segments)?)),
+        ChunkVersion::V1 => Ok(Box::new(ChunkV1::from_segments(segments)?)),
         // ...
         ChunkVersion::Unknown(_) => Err(anyhow::anyhow!("unsupported segment version")),
     }
@@ -160,15 +198,80 @@ pub fn chunk_from_segments(segments: Vec<Box<dyn Segment>>) -> anyhow::Result<Bo
 
 impl ChunkV0 {
     pub fn from_segments(segments: Vec<Box<dyn Segment>>) -> anyhow::Result<Self> {
-        let bytes = segments
-            .iter()
-            .flat_map(|segment| segment.get_data())
-            .collect::<Vec<_>>();
+        decompress_chunk(segments)
+    }
+}
+
+// ChunkV1:
+// 1. same as ChunkV0, each chunk maps to a batch (block number is chunk_id), compressed by lz4
+// 2. with the state root after executing the batch, signed by the sequencer
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct ChunkV1 {
+    pub version: ChunkVersion,
+    pub batch: DABatch,
+    pub state_root: DABatchStateRoot,
+}
+
+impl TryFrom<DABatch> for ChunkV1 {
+    type Error = anyhow::Error;
+
+    fn try_from(mut batch: DABatch) -> anyhow::Result<Self> {
+        let state_root = batch.state_root.take().ok_or_else(|| {
+            anyhow::anyhow!(
+                "batch of block {} has no signed state root",
+                batch.meta.block_range.block_number
+            )
+        })?;
+        Ok(Self {
+            version: ChunkVersion::V1,
+            batch,
+            state_root,
+        })
+    }
+}
+
+impl ChunkV1 {
+    fn to_bytes(&self) -> Vec<u8> {
+        compress_chunk(self)
+    }
+
+    pub fn from_segments(segments: Vec<Box<dyn Segment>>) -> anyhow::Result<Self> {
+        decompress_chunk(segments)
+    }
+}
+
+impl Chunk for ChunkV1 {
+    fn get_version(&self) -> ChunkVersion {
+        ChunkVersion::V1
+    }
+
+    fn to_segments(&self, max_segment_size: usize) -> Vec<Box<dyn Segment>> {
+        split_chunk(
+            ChunkVersion::V1,
+            self.get_chunk_id(),
+            self.to_bytes(),
+            max_segment_size,
+        )
+    }
+
+    fn get_batches(&self) -> Vec<DABatch> {
+        let mut batch = self.batch.clone();
+        batch.state_root = Some(self.state_root.clone());
+        vec![batch]
+    }
+
+    /// using batch.meta.block_number as chunk_id
+    fn get_chunk_id(&self) -> u128 {
+        self.batch.meta.block_range.block_number
+    }
+}
 
-        let decoder = lz4::Decoder::new(&bytes[..])?;
-        let mut decompressed_reader = io::BufReader::new(decoder);
-        let chunk: ChunkV0 = bcs::from_reader(&mut decompressed_reader)?;
-        Ok(chunk)
+/// Make the chunk of the batch, ChunkV1 if the batch has the signed state root, otherwise ChunkV0
+pub fn chunk_from_batch(batch: DABatch) -> Box<dyn Chunk> {
+    if batch.state_root.is_some() {
+        Box::new(ChunkV1::try_from(batch).expect("batch should have state root"))
+    } else {
+        Box::new(ChunkV0::from(batch))
     }
 }
 
@@ -177,6 +280,7 @@ mod tests {
     use super::*;
     use crate::crypto::RoochKeyPair;
     use crate::test_utils::random_ledger_transaction_with_order;
+    use moveos_types::h256::H256;
 
     #[test]
     fn test_chunk_v0() {
@@ -200,4 +304,38 @@ mod tests {
         // after serialization and deserialization, tx_hash will be dropped.
         assert!(act_batch.verify(true).is_ok())
     }
+
+    #[test]
+    fn test_chunk_v1() {
+        let keypair = RoochKeyPair::generate_secp256k1();
+        let sequencer = keypair.public().bitcoin_address().unwrap();
+
+        let tx_list = (0..128)
+            .map(|i| random_ledger_transaction_with_order(i as u64 + 1, &keypair))
+            .collect::<Vec<_>>();
+        let mut batch =
+            DABatch::new(123, 1, 128, &tx_list, &keypair).expect("create batch should success");
+        assert!(ChunkV1::try_from(batch.clone()).is_err());
+        assert_eq!(
+            chunk_from_batch(batch.clone()).get_version(),
+            ChunkVersion::V0
+        );
+
+        let state_root = H256::random();
+        batch.sign_state_root(state_root, &keypair);
+        let chunk = chunk_from_batch(batch.clone());
+        assert_eq!(chunk.get_version(), ChunkVersion::V1);
+        let segments = chunk.to_segments(1023);
+        assert!(segments
+            .iter()
+            .all(|segment| segment.get_version() == ChunkVersion::V1));
+
+        let chunk = chunk_from_segments(segments).unwrap();
+        assert_eq!(chunk.get_chunk_id(), 123);
+        let batches = chunk.get_batches();
+        let act_batch = batches.first().unwrap();
+        assert_eq!(act_batch, &batch);
+        assert!(act_batch.verify(true).is_ok());
+        assert!(act_batch.verify_state_root(&sequencer, state_root).unwrap());
+    }
 }
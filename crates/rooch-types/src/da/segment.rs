@@ -19,8 +19,10 @@ pub trait Segment: fmt::Debug + Send {
 pub const SEGMENT_V0_DATA_OFFSET: usize = 50;
 pub const SEGMENT_V0_CHECKSUM_OFFSET: usize = 42;
 
+// SegmentV0 is the segment layout of all chunk versions, the version byte is the chunk version
 #[derive(Serialize, Debug, PartialEq, Clone)]
 pub struct SegmentV0 {
+    pub version: ChunkVersion,
     pub id: SegmentID,
     pub is_last: bool,      // is last segment in chunk
     pub data_len: u64,      // length of data
@@ -42,6 +44,7 @@ impl SegmentV0 {
             ));
         }
 
+        let version = ChunkVersion::from(bytes[0]);
         let chunk_id = u128::from_le_bytes(bytes[1..17].try_into()?);
         let segment_number = u64::from_le_bytes(bytes[17..25].try_into()?);
         let is_last = bytes[25] != 0;
@@ -71,6 +74,7 @@ impl SegmentV0 {
         }
 
         Ok(Self {
+            version,
             id: SegmentID {
                 chunk_id,
                 segment_number,
@@ -87,7 +91,7 @@ impl SegmentV0 {
 impl Segment for SegmentV0 {
     fn to_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(SEGMENT_V0_DATA_OFFSET + self.data_len as usize);
-        bytes.push(ChunkVersion::V0.into()); // version
+        bytes.push(self.version.clone().into()); // version
         bytes.extend_from_slice(&self.id.chunk_id.to_le_bytes());
         bytes.extend_from_slice(&self.id.segment_number.to_le_bytes());
         bytes.push(self.is_last as u8);
@@ -101,7 +105,7 @@ impl Segment for SegmentV0 {
     }
 
     fn get_version(&self) -> ChunkVersion {
-        ChunkVersion::V0
+        self.version.clone()
     }
 
     fn get_id(&self) -> SegmentID {
@@ -119,7 +123,7 @@ impl Segment for SegmentV0 {
 
 pub fn get_data_offset(version: ChunkVersion) -> usize {
     match version {
-        ChunkVersion::V0 => SEGMENT_V0_DATA_OFFSET,
+        ChunkVersion::V0 | ChunkVersion::V1 => SEGMENT_V0_DATA_OFFSET,
         ChunkVersion::Unknown(_) => panic!("unsupported segment version"),
     }
 }
@@ -128,13 +132,13 @@ pub fn segment_from_bytes(bytes: &[u8]) -> anyhow::Result<Box<dyn Segment>> {
     let version = bytes[0];
 
     match ChunkVersion::from(version) {
-        ChunkVersion::V0 => Ok(Box::new(SegmentV0::from_bytes(bytes)?)),
+        ChunkVersion::V0 | ChunkVersion::V1 => Ok(Box::new(SegmentV0::from_bytes(bytes)?)),
         // ...
         ChunkVersion::Unknown(_) => Err(anyhow::anyhow!("unsupported segment version")),
     }
 }
 
-#[derive(Serialize, Debug, PartialEq, Clone, Copy)]
+#[derive(Serialize, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
 pub struct SegmentID {
     // chunk id represents the sequential order of extents within a stream, commencing from 0 and incrementing successively.
     pub chunk_id: u128,
@@ -188,6 +192,7 @@ mod tests {
     #[test]
     fn test_segment_trait() {
         let mut segment_v0 = SegmentV0 {
+            version: ChunkVersion::V0,
             id: SegmentID {
                 chunk_id: 1234567890,
                 segment_number: 12345678,
@@ -199,7 +204,11 @@ mod tests {
             data: vec![1, 2, 3, 4, 5],
         };
 
-        let segments: Vec<Box<dyn Segment>> = vec![Box::new(segment_v0.clone())];
+        let mut segment_v1 = segment_v0.clone();
+        segment_v1.version = ChunkVersion::V1;
+
+        let segments: Vec<Box<dyn Segment>> =
+            vec![Box::new(segment_v0.clone()), Box::new(segment_v1.clone())];
 
         for segment in segments {
             let bytes = segment.to_bytes();
@@ -213,6 +222,13 @@ mod tests {
                     segment_v0.data_checksum = recovered_segment.data_checksum;
                     assert_eq!(&segment_v0, &recovered_segment)
                 }
+                ChunkVersion::V1 => {
+                    let recovered_segment =
+                        SegmentV0::from_bytes(&bytes).expect("successful deserialization");
+                    segment_v1.checksum = recovered_segment.checksum;
+                    segment_v1.data_checksum = recovered_segment.data_checksum;
+                    assert_eq!(&segment_v1, &recovered_segment)
+                }
 
                 _ => panic!("unsupported segment version"),
             };
@@ -44,9 +44,22 @@ impl SignData {
         message_prefix: Vec<u8>,
         message_info_without_tx_hash: Vec<u8>,
         tx_data: &RoochTransactionData,
+    ) -> Self {
+        Self::new_with_hash(
+            message_prefix,
+            message_info_without_tx_hash,
+            tx_data.tx_hash(),
+        )
+    }
+
+    /// Sign the `hash` instead of the tx hash, eg. the sponsor hash of the transaction.
+    pub fn new_with_hash(
+        message_prefix: Vec<u8>,
+        message_info_without_tx_hash: Vec<u8>,
+        hash: H256,
     ) -> Self {
         let message_info = {
-            let tx_hash_hex = hex::encode(tx_data.tx_hash().as_bytes()).into_bytes();
+            let tx_hash_hex = hex::encode(hash.as_bytes()).into_bytes();
             let mut message_info = message_info_without_tx_hash;
             message_info.extend_from_slice(&tx_hash_hex);
             message_info
@@ -279,6 +292,57 @@ impl MoveStructState for MultisignAuthPayload {
     }
 }
 
+/// The auth payload of a WebAuthn (passkey) assertion, the signature is a secp256r1 signature
+/// over `authenticator_data || sha256(client_data_json)`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebauthnAuthPayload {
+    pub signature: Vec<u8>,
+    pub public_key: Vec<u8>,
+    pub authenticator_data: Vec<u8>,
+    pub client_data_json: Vec<u8>,
+}
+
+impl WebauthnAuthPayload {
+    pub fn new(
+        signature: Vec<u8>,
+        public_key: Vec<u8>,
+        authenticator_data: Vec<u8>,
+        client_data_json: Vec<u8>,
+    ) -> Self {
+        Self {
+            signature,
+            public_key,
+            authenticator_data,
+            client_data_json,
+        }
+    }
+}
+
+impl MoveStructType for WebauthnAuthPayload {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WebauthnAuthPayload");
+}
+
+impl MoveStructState for WebauthnAuthPayload {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+        ])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,6 +49,7 @@ pub enum BuiltinAuthValidator {
     Bitcoin,
     BitcoinMultisign,
     Ethereum,
+    Webauthn,
 }
 
 impl BuiltinAuthValidator {
@@ -56,6 +57,7 @@ impl BuiltinAuthValidator {
     const BITCOIN_FLAG: u8 = 0x01;
     const BITCOIN_MULTISIGN: u8 = 0x02;
     const ETHEREUM_FLAG: u8 = 0x03;
+    const WEBAUTHN_FLAG: u8 = 0x04;
 
     pub fn flag(&self) -> u8 {
         match self {
@@ -63,6 +65,7 @@ impl BuiltinAuthValidator {
             BuiltinAuthValidator::Bitcoin => Self::BITCOIN_FLAG,
             BuiltinAuthValidator::BitcoinMultisign => Self::BITCOIN_MULTISIGN,
             BuiltinAuthValidator::Ethereum => Self::ETHEREUM_FLAG,
+            BuiltinAuthValidator::Webauthn => Self::WEBAUTHN_FLAG,
         }
     }
 
@@ -79,6 +82,7 @@ impl BuiltinAuthValidator {
             Self::BITCOIN_FLAG => Ok(BuiltinAuthValidator::Bitcoin),
             Self::BITCOIN_MULTISIGN => Ok(BuiltinAuthValidator::BitcoinMultisign),
             Self::ETHEREUM_FLAG => Ok(BuiltinAuthValidator::Ethereum),
+            Self::WEBAUTHN_FLAG => Ok(BuiltinAuthValidator::Webauthn),
             _ => Err(RoochError::KeyConversionError(
                 "Invalid key auth validator".to_owned(),
             )),
@@ -108,6 +112,11 @@ impl BuiltinAuthValidator {
                 module_address: ROOCH_NURSERY_ADDRESS,
                 module_name: MoveString::from_str("ethereum_validator").expect("Should be valid"),
             },
+            BuiltinAuthValidator::Webauthn => AuthValidator {
+                id: self.flag().into(),
+                module_address: ROOCH_FRAMEWORK_ADDRESS,
+                module_name: MoveString::from_str("webauthn_validator").expect("Should be valid"),
+            },
         }
     }
 }
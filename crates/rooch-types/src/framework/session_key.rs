@@ -159,6 +159,8 @@ impl SessionKey {
             MoveAction::Script(_) => false,
             MoveAction::ModuleBundle(_) => false,
             MoveAction::Function(function) => self.is_scope_match(function),
+            // The session key can not be used to execute a multi-call, the same as the session_key::in_session_scope
+            MoveAction::MultiCall(_) => false,
         }
     }
 }
@@ -194,6 +196,8 @@ impl<'a> SessionKeyModule<'a> {
         ident_str!("create_session_key_entry");
     pub const GET_SESSION_KEYS_HANDLE_FUNCTION_NAME: &'static IdentStr =
         ident_str!("get_session_keys_handle");
+    pub const REMOVE_SESSION_KEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_session_key_entry");
 
     pub fn get_session_key(
         &self,
@@ -272,6 +276,14 @@ impl<'a> SessionKeyModule<'a> {
             ],
         )
     }
+
+    pub fn remove_session_key_action(authentication_key: Vec<u8>) -> MoveAction {
+        Self::create_move_action(
+            Self::REMOVE_SESSION_KEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(authentication_key)],
+        )
+    }
 }
 
 impl<'a> ModuleBinding<'a> for SessionKeyModule<'a> {
@@ -0,0 +1,158 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use crate::block::Block;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::option::MoveOption,
+    moveos_std::tx_context::TxContext,
+    state::{MoveStructState, MoveStructType},
+    transaction::{FunctionCall, MoveAction},
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("state_commitment_chain");
+
+/// The block committed to the SCC, a mirror of the Move `state_commitment_chain::Block`
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CommittedBlock {
+    pub block_number: u128,
+    pub batch_size: u64,
+    pub batch_hash: Vec<u8>,
+    pub prev_tx_accumulator_root: Vec<u8>,
+    pub tx_accumulator_root: Vec<u8>,
+    pub state_root: Vec<u8>,
+    pub commit_timestamp: u64,
+}
+
+impl MoveStructType for CommittedBlock {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("Block");
+}
+
+impl MoveStructState for CommittedBlock {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U128,
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::Vector(Box::new(
+                move_core_types::value::MoveTypeLayout::U8,
+            )),
+            move_core_types::value::MoveTypeLayout::U64,
+        ])
+    }
+}
+
+/// Rust bindings for RoochFramework state_commitment_chain module
+pub struct StateCommitmentChainModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> StateCommitmentChainModule<'a> {
+    pub const COMMIT_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("commit_block");
+    pub const PROPOSER_FUNCTION_NAME: &'static IdentStr = ident_str!("proposer");
+    pub const LAST_BLOCK_NUMBER_FUNCTION_NAME: &'static IdentStr = ident_str!("last_block_number");
+    pub const GET_BLOCK_FUNCTION_NAME: &'static IdentStr = ident_str!("get_block");
+
+    /// The account allowed to commit blocks to the SCC
+    pub fn proposer(&self) -> Result<AccountAddress> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::PROPOSER_FUNCTION_NAME),
+            vec![],
+            vec![],
+        );
+        let ctx = TxContext::zero();
+        let proposer = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<AccountAddress>(&value.value).expect("should be a valid address")
+            })?;
+        Ok(proposer)
+    }
+
+    /// The last committed block number, None if no block has been committed
+    pub fn last_block_number(&self) -> Result<Option<u128>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::LAST_BLOCK_NUMBER_FUNCTION_NAME),
+            vec![],
+            vec![],
+        );
+        let ctx = TxContext::zero();
+        let last_block_number =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<MoveOption<u128>>(&value.value)
+                        .expect("should be a valid MoveOption<u128>")
+                        .into()
+                })?;
+        Ok(last_block_number)
+    }
+
+    pub fn get_block(&self, block_number: u128) -> Result<Option<CommittedBlock>> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GET_BLOCK_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::U128(block_number).simple_serialize().unwrap()],
+        );
+        let ctx = TxContext::zero();
+        let block = self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<MoveOption<CommittedBlock>>(&value.value)
+                    .expect("should be a valid MoveOption<CommittedBlock>")
+                    .into()
+            })?;
+        Ok(block)
+    }
+
+    pub fn create_commit_block_action(block: &Block) -> MoveAction {
+        Self::create_move_action(
+            Self::COMMIT_BLOCK_FUNCTION_NAME,
+            vec![],
+            vec![
+                MoveValue::U128(block.block_number),
+                MoveValue::U64(block.batch_size),
+                MoveValue::vector_u8(block.batch_hash.0.to_vec()),
+                MoveValue::vector_u8(block.prev_tx_accumulator_root.0.to_vec()),
+                MoveValue::vector_u8(block.tx_accumulator_root.0.to_vec()),
+                MoveValue::vector_u8(block.state_root.0.to_vec()),
+            ],
+        )
+    }
+}
+
+impl<'a> ModuleBinding<'a> for StateCommitmentChainModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
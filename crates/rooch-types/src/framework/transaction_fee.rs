@@ -0,0 +1,137 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, u256::U256, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    moveos_std::{
+        object::{self, ObjectID},
+        tx_context::TxContext,
+    },
+    state::{MoveStructState, MoveStructType},
+    transaction::FunctionCall,
+};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("transaction_fee");
+
+/// The base fee market in the transaction_fee module.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BaseFee {
+    /// The base fee per gas unit.
+    pub base_fee: u64,
+    /// The start timestamp of the current fee block, in milliseconds.
+    pub fee_block_start_timestamp: u64,
+    /// The order of the first transaction in the current fee block.
+    pub fee_block_start_tx_order: u64,
+}
+
+impl BaseFee {
+    /// The base fee before the first transaction creates the BaseFee object.
+    pub const INITIAL_BASE_FEE: u64 = 1;
+
+    pub fn base_fee_object_id() -> ObjectID {
+        object::named_object_id(&Self::struct_tag())
+    }
+}
+
+impl MoveStructType for BaseFee {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("BaseFee");
+}
+
+impl MoveStructState for BaseFee {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U64,
+        ])
+    }
+}
+
+/// The gas used by the L2 transactions in the current fee block before the current transaction,
+/// the executor adds it to the tx context.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FeeBlockGasUsed {
+    pub gas_used: u64,
+}
+
+impl FeeBlockGasUsed {
+    pub fn new(gas_used: u64) -> Self {
+        Self { gas_used }
+    }
+}
+
+impl MoveStructType for FeeBlockGasUsed {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("FeeBlockGasUsed");
+}
+
+impl MoveStructState for FeeBlockGasUsed {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U64,
+        ])
+    }
+}
+
+/// Rust bindings for RoochFramework transaction_fee module
+pub struct TransactionFeeModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> TransactionFeeModule<'a> {
+    pub const GAS_REVENUE_BALANCE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("gas_revenue_balance");
+    pub const STORAGE_DEPOSIT_POOL_BALANCE_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("storage_deposit_pool_balance");
+
+    pub fn gas_revenue_balance(&self, addr: AccountAddress) -> Result<U256> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::GAS_REVENUE_BALANCE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::Address(addr).simple_serialize().unwrap()],
+        );
+        self.call_u256(call)
+    }
+
+    pub fn storage_deposit_pool_balance(&self) -> Result<U256> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::STORAGE_DEPOSIT_POOL_BALANCE_FUNCTION_NAME),
+            vec![],
+            vec![],
+        );
+        self.call_u256(call)
+    }
+
+    fn call_u256(&self, call: FunctionCall) -> Result<U256> {
+        let ctx = TxContext::random_for_testing_only();
+        Ok(self
+            .caller
+            .call_function(&ctx, call)?
+            .into_result()
+            .map(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                bcs::from_bytes::<U256>(&value.value).expect("should be a valid u256")
+            })?)
+    }
+}
+
+impl<'a> ModuleBinding<'a> for TransactionFeeModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
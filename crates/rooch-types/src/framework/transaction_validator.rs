@@ -16,7 +16,7 @@ use moveos_types::{
 
 use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use crate::framework::auth_validator::TxValidateResult;
-use crate::transaction::AuthenticatorInfo;
+use crate::transaction::{Authenticator, AuthenticatorInfo};
 
 /// Rust bindings for RoochFramework transaction_validator module
 pub struct TransactionValidator<'a> {
@@ -25,6 +25,7 @@ pub struct TransactionValidator<'a> {
 
 impl<'a> TransactionValidator<'a> {
     pub const VALIDATE_FUNCTION_NAME: &'static IdentStr = ident_str!("validate");
+    pub const VALIDATE_SPONSOR_FUNCTION_NAME: &'static IdentStr = ident_str!("validate_sponsor");
     pub const PRE_EXECUTE_FUNCTION_NAME: &'static IdentStr = ident_str!("pre_execute");
     pub const POST_EXECUTE_FUNCTION_NAME: &'static IdentStr = ident_str!("post_execute");
 
@@ -57,6 +58,35 @@ impl<'a> TransactionValidator<'a> {
         Ok(function_result)
     }
 
+    /// Validate the sponsor's authenticator, return the sponsor address who pays the gas.
+    pub fn validate_sponsor(
+        &self,
+        ctx: &TxContext,
+        authenticator: Authenticator,
+    ) -> Result<DecodedFunctionResult<AccountAddress>> {
+        let validate_sponsor_call = FunctionCall::new(
+            Self::function_id(Self::VALIDATE_SPONSOR_FUNCTION_NAME),
+            vec![],
+            vec![
+                MoveValue::U64(authenticator.auth_validator_id)
+                    .simple_serialize()
+                    .unwrap(),
+                MoveValue::vector_u8(authenticator.payload)
+                    .simple_serialize()
+                    .unwrap(),
+            ],
+        );
+        let function_result = self
+            .caller
+            .call_function(ctx, validate_sponsor_call)?
+            .decode(|mut values| {
+                let value = values.pop().expect("should have one return value");
+                let result = bcs::from_bytes::<AccountAddress>(&value.value)?;
+                Ok(result)
+            })?;
+        Ok(function_result)
+    }
+
     pub fn pre_execute_function_id() -> FunctionId {
         Self::function_id(Self::PRE_EXECUTE_FUNCTION_NAME)
     }
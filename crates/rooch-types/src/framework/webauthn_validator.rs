@@ -0,0 +1,155 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use super::auth_validator::BuiltinAuthValidator;
+use crate::address::{BitcoinAddress, RoochAddress};
+use crate::addresses::ROOCH_FRAMEWORK_ADDRESS;
+use anyhow::Result;
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::key::Secp256k1;
+use bitcoin::{TapNodeHash, XOnlyPublicKey};
+use move_core_types::{
+    account_address::AccountAddress, ident_str, identifier::IdentStr, value::MoveValue,
+};
+use moveos_types::{
+    module_binding::{ModuleBinding, MoveFunctionCaller},
+    move_std::string::MoveString,
+    moveos_std::tx_context::TxContext,
+    state::{MoveState, MoveStructType},
+    transaction::{FunctionCall, MoveAction},
+};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("webauthn_validator");
+
+/// The BIP341 NUMS point, the key path of the passkey account Bitcoin address is unspendable.
+const NUMS_INTERNAL_PUBKEY: [u8; 32] = [
+    0x50, 0x92, 0x9b, 0x74, 0xc1, 0xa0, 0x49, 0x54, 0xb7, 0x8b, 0x4b, 0x60, 0x35, 0xe9, 0x7a, 0x5e,
+    0x07, 0x8a, 0x5a, 0x0f, 0x28, 0xec, 0x96, 0xd5, 0x47, 0xbf, 0xee, 0x9a, 0xce, 0x80, 0x3a, 0xc0,
+];
+/// The domain of the passkey account script, it must be the same as the `PASSKEY_ACCOUNT_DOMAIN` in Move.
+pub const PASSKEY_ACCOUNT_DOMAIN: &[u8] = b"ROOCH_PASSKEY::";
+
+/// WebAuthn (passkey) Auth Validator
+pub struct WebauthnValidator {}
+
+impl WebauthnValidator {
+    pub fn auth_validator_id() -> u64 {
+        BuiltinAuthValidator::Webauthn.flag().into()
+    }
+
+    /// The Bitcoin address of the account controlled by the passkey of the `rp_id`, so a user can
+    /// onboard with a passkey without a Bitcoin wallet.
+    pub fn passkey_bitcoin_address(rp_id: &str, public_key: &[u8]) -> Result<BitcoinAddress> {
+        let mut data = PASSKEY_ACCOUNT_DOMAIN.to_vec();
+        data.extend_from_slice(sha256::Hash::hash(rp_id.as_bytes()).as_byte_array());
+        data.extend_from_slice(public_key);
+        let merkle_root = TapNodeHash::from_byte_array(sha256::Hash::hash(&data).to_byte_array());
+        let internal_key = XOnlyPublicKey::from_slice(&NUMS_INTERNAL_PUBKEY)?;
+        let address = bitcoin::Address::p2tr(
+            &Secp256k1::verification_only(),
+            internal_key,
+            Some(merkle_root),
+            bitcoin::Network::Bitcoin,
+        );
+        Ok(address.into())
+    }
+
+    pub fn passkey_address(rp_id: &str, public_key: &[u8]) -> Result<RoochAddress> {
+        Ok(Self::passkey_bitcoin_address(rp_id, public_key)?.to_rooch_address())
+    }
+}
+
+impl MoveStructType for WebauthnValidator {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("WebauthnValidator");
+}
+
+/// Rust bindings for RoochFramework webauthn_validator module
+pub struct WebauthnValidatorModule<'a> {
+    caller: &'a dyn MoveFunctionCaller,
+}
+
+impl<'a> WebauthnValidatorModule<'a> {
+    pub const VALIDATE_FUNCTION_NAME: &'static IdentStr = ident_str!("validate");
+    pub const HAS_PASSKEY_FUNCTION_NAME: &'static IdentStr = ident_str!("has_passkey");
+    pub const ADD_PASSKEY_ENTRY_FUNCTION_NAME: &'static IdentStr = ident_str!("add_passkey_entry");
+    pub const REMOVE_PASSKEY_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("remove_passkey_entry");
+    pub const INIT_FOR_UPGRADE_ENTRY_FUNCTION_NAME: &'static IdentStr =
+        ident_str!("init_for_upgrade_entry");
+
+    pub fn validate(&self, ctx: &TxContext, payload: Vec<u8>) -> Result<()> {
+        let auth_validator_call = FunctionCall::new(
+            Self::function_id(Self::VALIDATE_FUNCTION_NAME),
+            vec![],
+            vec![MoveValue::vector_u8(payload).simple_serialize().unwrap()],
+        );
+        self.caller
+            .call_function(ctx, auth_validator_call)?
+            .into_result()
+            .map(|values| {
+                debug_assert!(values.is_empty(), "should not have return values");
+            })?;
+        Ok(())
+    }
+
+    pub fn has_passkey(
+        &self,
+        account_address: AccountAddress,
+        public_key: Vec<u8>,
+    ) -> Result<bool> {
+        let call = FunctionCall::new(
+            Self::function_id(Self::HAS_PASSKEY_FUNCTION_NAME),
+            vec![],
+            vec![
+                MoveValue::Address(account_address)
+                    .simple_serialize()
+                    .unwrap(),
+                MoveValue::vector_u8(public_key).simple_serialize().unwrap(),
+            ],
+        );
+        let ctx = TxContext::new_readonly_ctx(account_address);
+        let has_passkey =
+            self.caller
+                .call_function(&ctx, call)?
+                .into_result()
+                .map(|mut values| {
+                    let value = values.pop().expect("should have one return value");
+                    bcs::from_bytes::<bool>(&value.value).expect("should be a valid bool")
+                })?;
+        Ok(has_passkey)
+    }
+
+    pub fn add_passkey_action(public_key: Vec<u8>, rp_id: MoveString) -> MoveAction {
+        Self::create_move_action(
+            Self::ADD_PASSKEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(public_key), rp_id.to_move_value()],
+        )
+    }
+
+    pub fn remove_passkey_action(public_key: Vec<u8>) -> MoveAction {
+        Self::create_move_action(
+            Self::REMOVE_PASSKEY_ENTRY_FUNCTION_NAME,
+            vec![],
+            vec![MoveValue::vector_u8(public_key)],
+        )
+    }
+
+    pub fn init_for_upgrade_action() -> MoveAction {
+        Self::create_move_action(Self::INIT_FOR_UPGRADE_ENTRY_FUNCTION_NAME, vec![], vec![])
+    }
+}
+
+impl<'a> ModuleBinding<'a> for WebauthnValidatorModule<'a> {
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const MODULE_ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+
+    fn new(caller: &'a impl MoveFunctionCaller) -> Self
+    where
+        Self: Sized,
+    {
+        Self { caller }
+    }
+}
@@ -3,16 +3,23 @@
 
 use crate::indexer::Filter;
 use crate::transaction::LedgerTransaction;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::StructTag;
-use move_resource_viewer::AnnotatedMoveStruct;
+use move_core_types::resolver::MoveResolver;
+use move_core_types::u256::U256;
+use move_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue, MoveValueAnnotator};
 use moveos_types::h256::H256;
+use moveos_types::move_std::ascii::MoveAsciiString;
+use moveos_types::move_std::string::MoveString;
 use moveos_types::move_types::struct_tag_match;
 use moveos_types::moveos_std::event::{Event, EventID};
 use moveos_types::moveos_std::tx_context::TxContext;
+use moveos_types::state::MoveStructType;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::str::FromStr;
 
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct IndexerEvent {
@@ -24,6 +31,8 @@ pub struct IndexerEvent {
     pub event_type: StructTag,
     /// The data payload of the event
     pub event_data: Option<Vec<u8>>,
+    /// The event data decoded to JSON, see `annotated_move_struct_to_json`
+    pub decoded_event_data: Option<Value>,
 
     /// the hash of this transaction.
     pub tx_hash: H256,
@@ -35,7 +44,15 @@ pub struct IndexerEvent {
 }
 
 impl IndexerEvent {
-    pub fn new(event: Event, mut ledger_transaction: LedgerTransaction, ctx: TxContext) -> Self {
+    pub fn new(event: Event, ledger_transaction: LedgerTransaction, ctx: TxContext) -> Self {
+        Self::new_with_sender(event, ledger_transaction, ctx.sender)
+    }
+
+    pub fn new_with_sender(
+        event: Event,
+        mut ledger_transaction: LedgerTransaction,
+        sender: AccountAddress,
+    ) -> Self {
         IndexerEvent {
             indexer_event_id: IndexerEventID::new(
                 ledger_transaction.sequence_info.tx_order,
@@ -45,12 +62,69 @@ impl IndexerEvent {
 
             event_type: event.event_type,
             event_data: Some(event.event_data),
+            decoded_event_data: None,
             tx_hash: ledger_transaction.tx_hash(),
-            sender: ctx.sender,
+            sender,
 
             created_at: ledger_transaction.sequence_info.tx_timestamp,
         }
     }
+
+    /// Decode the event data with the annotator, the event is still indexed if the decoding fails.
+    pub fn with_decoded_event_data<T: MoveResolver + ?Sized>(
+        mut self,
+        annotator: &MoveValueAnnotator<T>,
+    ) -> Self {
+        if let Some(event_data) = &self.event_data {
+            match annotator.view_resource(&self.event_type, event_data) {
+                Ok(annotated) => {
+                    self.decoded_event_data = Some(annotated_move_struct_to_json(&annotated))
+                }
+                Err(e) => {
+                    tracing::warn!("Decode event data of {} failed: {:?}", self.event_type, e)
+                }
+            }
+        }
+        self
+    }
+}
+
+/// Convert the annotated Move struct to a JSON object of the field values.
+/// The scalar values are rendered as strings, so they compare the same in SQLite and PostgreSQL
+/// and the integers larger than 2^53 keep their precision.
+pub fn annotated_move_struct_to_json(move_struct: &AnnotatedMoveStruct) -> Value {
+    if move_struct.type_ == MoveString::struct_tag()
+        || move_struct.type_ == MoveAsciiString::struct_tag()
+    {
+        if let Some((_, AnnotatedMoveValue::Bytes(bytes))) = move_struct.value.first() {
+            return Value::String(String::from_utf8_lossy(bytes).to_string());
+        }
+    }
+    Value::Object(
+        move_struct
+            .value
+            .iter()
+            .map(|(name, value)| (name.to_string(), annotated_move_value_to_json(value)))
+            .collect::<Map<_, _>>(),
+    )
+}
+
+fn annotated_move_value_to_json(value: &AnnotatedMoveValue) -> Value {
+    match value {
+        AnnotatedMoveValue::U8(v) => Value::String(v.to_string()),
+        AnnotatedMoveValue::U16(v) => Value::String(v.to_string()),
+        AnnotatedMoveValue::U32(v) => Value::String(v.to_string()),
+        AnnotatedMoveValue::U64(v) => Value::String(v.to_string()),
+        AnnotatedMoveValue::U128(v) => Value::String(v.to_string()),
+        AnnotatedMoveValue::U256(v) => Value::String(v.to_string()),
+        AnnotatedMoveValue::Bool(v) => Value::String(v.to_string()),
+        AnnotatedMoveValue::Address(v) => Value::String(v.to_hex_literal()),
+        AnnotatedMoveValue::Bytes(v) => Value::String(format!("0x{}", hex::encode(v))),
+        AnnotatedMoveValue::Vector(_, v) => {
+            Value::Array(v.iter().map(annotated_move_value_to_json).collect())
+        }
+        AnnotatedMoveValue::Struct(v) => annotated_move_struct_to_json(v),
+    }
 }
 
 #[derive(
@@ -108,6 +182,11 @@ pub enum EventFilter {
         /// right endpoint of transaction order, exclusive
         to_order: u64,
     },
+    /// Query by event type and the decoded event data, all the field filters must match
+    EventTypeWithFieldFilters {
+        event_type: StructTag,
+        field_filters: Vec<EventFieldFilter>,
+    },
 }
 
 impl EventFilter {
@@ -130,6 +209,83 @@ impl EventFilter {
                 *from_order <= item.indexer_event_id.tx_order
                     && item.indexer_event_id.tx_order < *to_order
             }
+            EventFilter::EventTypeWithFieldFilters {
+                event_type,
+                field_filters,
+            } => {
+                if !struct_tag_match(&item.event_type, event_type) {
+                    return Ok(false);
+                }
+                let decoded_event_data = match &item.decoded_event_data {
+                    Some(decoded_event_data) => decoded_event_data,
+                    None => return Ok(false),
+                };
+                for field_filter in field_filters {
+                    if !field_filter.try_matches(decoded_event_data)? {
+                        return Ok(false);
+                    }
+                }
+                true
+            }
+        })
+    }
+}
+
+/// The filter on a field of the decoded event data.
+/// The path is the dot separated field names from the event struct, such as `coin.value`,
+/// a vector element is selected by its index, such as `amounts.0`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EventFieldFilter {
+    /// The field value equals the value, numbers, bools and addresses are compared in string form
+    Eq { path: String, value: String },
+    /// The integer field value in [from, to) interval, the interval is unbounded if an endpoint is None
+    Range {
+        path: String,
+        from: Option<U256>,
+        to: Option<U256>,
+    },
+}
+
+impl EventFieldFilter {
+    pub fn path(&self) -> &str {
+        match self {
+            EventFieldFilter::Eq { path, .. } => path,
+            EventFieldFilter::Range { path, .. } => path,
+        }
+    }
+
+    /// Split the path to the field names or vector indexes,
+    /// only ASCII alphanumeric and `_` are allowed, so the path is safe to be put in the SQL.
+    pub fn path_segments(&self) -> Result<Vec<&str>> {
+        let segments = self.path().split('.').collect::<Vec<_>>();
+        for segment in &segments {
+            if segment.is_empty()
+                || !segment
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                bail!("Invalid event field path: {}", self.path());
+            }
+        }
+        Ok(segments)
+    }
+
+    pub fn try_matches(&self, decoded_event_data: &Value) -> Result<bool> {
+        let pointer = format!("/{}", self.path_segments()?.join("/"));
+        let field_value = match decoded_event_data.pointer(&pointer).and_then(Value::as_str) {
+            Some(field_value) => field_value,
+            None => return Ok(false),
+        };
+        Ok(match self {
+            EventFieldFilter::Eq { value, .. } => field_value == value,
+            EventFieldFilter::Range { from, to, .. } => match U256::from_str(field_value) {
+                Ok(field_value) => {
+                    from.map_or(true, |from| from <= field_value)
+                        && to.map_or(true, |to| field_value < to)
+                }
+                Err(_) => false,
+            },
         })
     }
 }
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::bitcoin::ord::Inscription;
+use crate::bitcoin::runes::RuneBalances;
 use crate::bitcoin::utxo::UTXO;
 use crate::indexer::Filter;
 use anyhow::Result;
@@ -22,6 +23,8 @@ pub static UTXO_TYPE_TAG: Lazy<TypeTag> = Lazy::new(UTXO::type_tag);
 
 pub static INSCRIPTION_TYPE_TAG: Lazy<TypeTag> = Lazy::new(Inscription::type_tag);
 
+pub static RUNE_BALANCES_TYPE_TAG: Lazy<TypeTag> = Lazy::new(RuneBalances::type_tag);
+
 /// Index all Object state, include child object
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexerObjectState {
@@ -56,9 +59,10 @@ impl IndexerObjectState {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ObjectStateType {
-    ObjectState, //all object states exclude utxo and inscription
+    ObjectState, //all object states exclude utxo, inscription and rune balances
     UTXO,
     Inscription,
+    RuneBalances,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -66,6 +70,7 @@ pub struct IndexerObjectStateChangeSet {
     pub object_states: IndexerObjectStateChanges,
     pub object_state_utxos: IndexerObjectStateChanges,
     pub object_state_inscriptions: IndexerObjectStateChanges,
+    pub object_state_rune_balances: IndexerObjectStateChanges,
 }
 
 impl IndexerObjectStateChangeSet {
@@ -76,6 +81,10 @@ impl IndexerObjectStateChangeSet {
             self.object_state_inscriptions
                 .update_object_states
                 .push(state)
+        } else if type_tag_match(&state.metadata.object_type, &RUNE_BALANCES_TYPE_TAG) {
+            self.object_state_rune_balances
+                .update_object_states
+                .push(state)
         } else {
             self.object_states.update_object_states.push(state)
         }
@@ -86,11 +95,25 @@ impl IndexerObjectStateChangeSet {
             self.object_state_utxos.new_object_states.push(state)
         } else if type_tag_match(&state.metadata.object_type, &INSCRIPTION_TYPE_TAG) {
             self.object_state_inscriptions.new_object_states.push(state)
+        } else if type_tag_match(&state.metadata.object_type, &RUNE_BALANCES_TYPE_TAG) {
+            self.object_state_rune_balances
+                .new_object_states
+                .push(state)
         } else {
             self.object_states.new_object_states.push(state)
         }
     }
 
+    /// The new and updated object states, excluding the UTXOs, Inscriptions and RuneBalances
+    pub fn changed_object_states(&self) -> Vec<IndexerObjectState> {
+        self.object_states
+            .new_object_states
+            .iter()
+            .chain(self.object_states.update_object_states.iter())
+            .cloned()
+            .collect()
+    }
+
     pub fn remove_object_states(&mut self, object_id: ObjectID, object_type: &TypeTag) {
         if type_tag_match(object_type, &UTXO_TYPE_TAG) {
             self.object_state_utxos
@@ -100,6 +123,10 @@ impl IndexerObjectStateChangeSet {
             self.object_state_inscriptions
                 .remove_object_states
                 .push(object_id.to_string())
+        } else if type_tag_match(object_type, &RUNE_BALANCES_TYPE_TAG) {
+            self.object_state_rune_balances
+                .remove_object_states
+                .push(object_id.to_string())
         } else {
             self.object_states
                 .remove_object_states
@@ -120,6 +147,7 @@ pub struct IndexerObjectStatesIndexGenerator {
     pub object_states_index_generator: u64,
     pub object_state_utxos_index_generator: u64,
     pub object_state_inscriptions_generator: u64,
+    pub object_state_rune_balances_generator: u64,
 }
 
 impl IndexerObjectStatesIndexGenerator {
@@ -128,6 +156,8 @@ impl IndexerObjectStatesIndexGenerator {
             self.object_state_utxos_index_generator += 1;
         } else if type_tag_match(object_type, &INSCRIPTION_TYPE_TAG) {
             self.object_state_inscriptions_generator += 1;
+        } else if type_tag_match(object_type, &RUNE_BALANCES_TYPE_TAG) {
+            self.object_state_rune_balances_generator += 1;
         } else {
             self.object_states_index_generator += 1;
         }
@@ -138,6 +168,8 @@ impl IndexerObjectStatesIndexGenerator {
             self.object_state_utxos_index_generator
         } else if type_tag_match(object_type, &INSCRIPTION_TYPE_TAG) {
             self.object_state_inscriptions_generator
+        } else if type_tag_match(object_type, &RUNE_BALANCES_TYPE_TAG) {
+            self.object_state_rune_balances_generator
         } else {
             self.object_states_index_generator
         }
@@ -375,6 +407,17 @@ impl ObjectStateFilter {
     }
 }
 
+impl ObjectStateFilter {
+    /// Match the changed object states pushed to the subscribers,
+    /// unlike `matches`, the `ObjectId` filter matches any of the object ids.
+    pub fn matches_changed(&self, item: &IndexerObjectState) -> bool {
+        match self {
+            ObjectStateFilter::ObjectId(object_ids) => object_ids.contains(&item.metadata.id),
+            _ => self.matches(item),
+        }
+    }
+}
+
 impl Filter<IndexerObjectState> for ObjectStateFilter {
     fn matches(&self, item: &IndexerObjectState) -> bool {
         self.try_matches(item).unwrap_or_default()
@@ -387,3 +430,47 @@ pub struct IndexerStateChangeSet {
     pub state_change_set: StateChangeSet,
     pub created_at: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moveos_types::moveos_std::object::SYSTEM_OWNER_ADDRESS;
+
+    fn object_state_for_test(id: ObjectID) -> IndexerObjectState {
+        IndexerObjectState::new(ObjectMeta::genesis_meta(id, UTXO::type_tag()), 1, 0)
+    }
+
+    #[test]
+    fn test_object_id_filter_matches() {
+        let object_id = ObjectID::random();
+        let other_object_id = ObjectID::random();
+        let state = object_state_for_test(object_id.clone());
+
+        assert!(ObjectStateFilter::ObjectId(vec![object_id.clone()]).matches(&state));
+        assert!(!ObjectStateFilter::ObjectId(vec![other_object_id.clone()]).matches(&state));
+        assert!(!ObjectStateFilter::ObjectId(vec![]).matches(&state));
+        // the object id filter matches the exact object id only
+        assert!(
+            !ObjectStateFilter::ObjectId(vec![object_id.clone(), other_object_id.clone()])
+                .matches(&state)
+        );
+        assert!(!ObjectStateFilter::ObjectId(vec![other_object_id, object_id]).matches(&state));
+    }
+
+    #[test]
+    fn test_object_id_filter_matches_changed() {
+        let object_id = ObjectID::random();
+        let other_object_id = ObjectID::random();
+        let state = object_state_for_test(object_id.clone());
+
+        assert!(ObjectStateFilter::ObjectId(vec![object_id.clone()]).matches_changed(&state));
+        assert!(
+            ObjectStateFilter::ObjectId(vec![other_object_id.clone(), object_id.clone()])
+                .matches_changed(&state)
+        );
+        assert!(!ObjectStateFilter::ObjectId(vec![other_object_id]).matches_changed(&state));
+        assert!(!ObjectStateFilter::ObjectId(vec![]).matches_changed(&state));
+        assert!(ObjectStateFilter::Owner(SYSTEM_OWNER_ADDRESS).matches_changed(&state));
+        assert!(!ObjectStateFilter::Owner(AccountAddress::random()).matches_changed(&state));
+    }
+}
@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::indexer::Filter;
 use crate::transaction::{LedgerTransaction, LedgerTxData};
 use anyhow::Result;
 use move_core_types::account_address::AccountAddress;
@@ -9,7 +10,7 @@ use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::transaction::{MoveAction, TransactionExecutionInfo};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexerTransaction {
     // The hash of this transaction.
     pub tx_hash: H256,
@@ -87,3 +88,26 @@ pub enum TransactionFilter {
         to_order: u64,
     },
 }
+
+impl TransactionFilter {
+    fn try_matches(&self, item: &IndexerTransaction) -> Result<bool> {
+        Ok(match self {
+            TransactionFilter::Sender(sender) => sender == &item.sender,
+            TransactionFilter::TxHashes(tx_hashes) => tx_hashes.contains(&item.tx_hash),
+            TransactionFilter::TimeRange {
+                start_time,
+                end_time,
+            } => *start_time <= item.created_at && item.created_at < *end_time,
+            TransactionFilter::TxOrderRange {
+                from_order,
+                to_order,
+            } => *from_order <= item.tx_order && item.tx_order < *to_order,
+        })
+    }
+}
+
+impl Filter<IndexerTransaction> for TransactionFilter {
+    fn matches(&self, item: &IndexerTransaction) -> bool {
+        self.try_matches(item).unwrap_or_default()
+    }
+}
@@ -4,6 +4,7 @@
 pub mod address;
 pub mod addresses;
 pub mod authentication_key;
+pub mod backup;
 pub mod bitcoin;
 pub mod block;
 pub mod coin_type;
@@ -18,6 +19,7 @@ pub mod into_address;
 pub mod key_struct;
 pub mod multichain_id;
 pub mod nursery;
+pub mod proof;
 pub mod repair;
 pub mod rooch_key;
 pub mod rooch_network;
@@ -0,0 +1,434 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use accumulator::proof::AccumulatorProof;
+use anyhow::{ensure, Result};
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::state::{FieldKey, ObjectState};
+use serde::{Deserialize, Serialize};
+use smt::SparseMerkleProof;
+
+/// Proof of a field in the object which state root is the parent's
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FieldProof {
+    pub field_key: FieldKey,
+    /// None means the field does not exist, and the proof is a non-inclusion proof
+    pub state: Option<ObjectState>,
+    pub proof: SparseMerkleProof,
+}
+
+/// Proof of a state(field of an object) in the global state tree.
+/// It's a chain of field proofs from the global state root to the field:
+/// the first one is in the global state tree, the next one is in the state tree of the previous object, and so on.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StateProof {
+    pub state_root: H256,
+    pub object_id: ObjectID,
+    pub field_key: FieldKey,
+    pub proofs: Vec<FieldProof>,
+}
+
+impl StateProof {
+    /// Returns the field keys from the global state tree to the field.
+    pub fn field_key_path(object_id: &ObjectID, field_key: FieldKey) -> Vec<FieldKey> {
+        let mut path = vec![field_key];
+        let mut object_id = object_id.clone();
+        while !object_id.is_root() {
+            path.push(object_id.field_key());
+            object_id = object_id
+                .parent()
+                .expect("ObjectID parent should not be None");
+        }
+        path.reverse();
+        path
+    }
+
+    /// The state of the field, None if the field does not exist.
+    pub fn state(&self) -> Option<&ObjectState> {
+        self.proofs.last().and_then(|proof| proof.state.as_ref())
+    }
+
+    /// Verify the proof is for the expected field of the object with the expected global state root,
+    /// returns the state of the field.
+    pub fn verify(
+        &self,
+        expected_state_root: H256,
+        expected_object_id: &ObjectID,
+        expected_field_key: FieldKey,
+    ) -> Result<Option<ObjectState>> {
+        ensure!(
+            self.state_root == expected_state_root,
+            "State root mismatch, expected: {:?}, actual: {:?}",
+            expected_state_root,
+            self.state_root
+        );
+        ensure!(
+            &self.object_id == expected_object_id && self.field_key == expected_field_key,
+            "State key mismatch, expected: ({}, {}), actual: ({}, {})",
+            expected_object_id,
+            expected_field_key,
+            self.object_id,
+            self.field_key
+        );
+        let path = Self::field_key_path(&self.object_id, self.field_key);
+        // proofs stop at the first non-existent object in the path
+        ensure!(
+            !self.proofs.is_empty() && self.proofs.len() <= path.len(),
+            "Invalid proofs length: {}, path length: {}",
+            self.proofs.len(),
+            path.len()
+        );
+        let mut state_root = self.state_root;
+        for (i, field_proof) in self.proofs.iter().enumerate() {
+            let field_key = path[i];
+            ensure!(
+                field_key == field_proof.field_key,
+                "Field key mismatch, expected: {}, actual: {}",
+                field_key,
+                field_proof.field_key
+            );
+            field_proof
+                .proof
+                .verify(state_root, field_key, field_proof.state.clone())?;
+            match &field_proof.state {
+                Some(state) => state_root = state.state_root(),
+                None => {
+                    ensure!(
+                        i == self.proofs.len() - 1,
+                        "Non-inclusion proof must be the last one"
+                    );
+                    return Ok(None);
+                }
+            }
+        }
+        ensure!(
+            self.proofs.len() == path.len(),
+            "Incomplete proofs, length: {}, path length: {}",
+            self.proofs.len(),
+            path.len()
+        );
+        Ok(self.state().cloned())
+    }
+}
+
+/// Proof of a transaction in the tx accumulator
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TransactionProof {
+    pub tx_order: u64,
+    pub tx_hash: H256,
+    /// The accumulator root the proof is generated with
+    pub accumulator_root: H256,
+    pub leaf_index: u64,
+    pub proof: AccumulatorProof,
+}
+
+impl TransactionProof {
+    /// Verify the proof is for the expected transaction with the expected tx accumulator root.
+    /// The leaf index of a transaction in the tx accumulator is its tx_order.
+    pub fn verify(
+        &self,
+        expected_accumulator_root: H256,
+        expected_tx_order: u64,
+        expected_tx_hash: H256,
+    ) -> Result<()> {
+        ensure!(
+            self.accumulator_root == expected_accumulator_root,
+            "Accumulator root mismatch, expected: {:?}, actual: {:?}",
+            expected_accumulator_root,
+            self.accumulator_root
+        );
+        ensure!(
+            self.tx_order == expected_tx_order && self.leaf_index == expected_tx_order,
+            "Transaction order mismatch, expected: {}, actual tx_order: {}, leaf_index: {}",
+            expected_tx_order,
+            self.tx_order,
+            self.leaf_index
+        );
+        ensure!(
+            self.tx_hash == expected_tx_hash,
+            "Transaction hash mismatch, expected: {:?}, actual: {:?}",
+            expected_tx_hash,
+            self.tx_hash
+        );
+        self.proof
+            .verify(self.accumulator_root, self.tx_hash, self.leaf_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use accumulator::inmemory::InMemoryAccumulator;
+    use move_core_types::language_storage::TypeTag;
+    use moveos_types::moveos_std::object::{ObjectMeta, GENESIS_STATE_ROOT};
+    use smt::{InMemoryNodeStore, SMTree};
+
+    type StateTree = SMTree<FieldKey, ObjectState, InMemoryNodeStore>;
+
+    fn new_object_state(id: ObjectID, value: u64) -> ObjectState {
+        ObjectState::new(
+            ObjectMeta::genesis_meta(id, TypeTag::U64),
+            bcs::to_bytes(&value).unwrap(),
+        )
+    }
+
+    fn put_state(
+        smt: &StateTree,
+        node_store: &InMemoryNodeStore,
+        state_root: H256,
+        field_key: FieldKey,
+        state: ObjectState,
+    ) -> H256 {
+        let change_set = smt.put(state_root, field_key, state).unwrap();
+        node_store.write_nodes(change_set.nodes).unwrap();
+        change_set.state_root
+    }
+
+    // generates the proof the same way as the reader executor
+    fn get_state_proof(
+        smt: &StateTree,
+        state_root: H256,
+        object_id: ObjectID,
+        field_key: FieldKey,
+    ) -> StateProof {
+        let mut proofs = vec![];
+        let mut current_state_root = state_root;
+        for key in StateProof::field_key_path(&object_id, field_key) {
+            let (state, proof) = smt.get_with_proof(current_state_root, key).unwrap();
+            let next_state_root = state.as_ref().map(|state| state.state_root());
+            proofs.push(FieldProof {
+                field_key: key,
+                state,
+                proof,
+            });
+            match next_state_root {
+                Some(next_state_root) => current_state_root = next_state_root,
+                None => break,
+            }
+        }
+        StateProof {
+            state_root,
+            object_id,
+            field_key,
+            proofs,
+        }
+    }
+
+    // builds a global state tree with a parent object which has a child object field
+    fn new_state_tree() -> (StateTree, H256, ObjectID, ObjectState) {
+        let node_store = InMemoryNodeStore::default();
+        let registry = prometheus::Registry::new();
+        let smt = StateTree::new(node_store.clone(), &registry);
+
+        let parent_id = ObjectID::random();
+        let child_id = parent_id.child_id(FieldKey::random());
+        let child_state = new_object_state(child_id.clone(), 2);
+        let parent_state_root = put_state(
+            &smt,
+            &node_store,
+            *GENESIS_STATE_ROOT,
+            child_id.field_key(),
+            child_state.clone(),
+        );
+        let mut parent_state = new_object_state(parent_id.clone(), 1);
+        parent_state.update_state_root(parent_state_root);
+        let state_root = put_state(
+            &smt,
+            &node_store,
+            *GENESIS_STATE_ROOT,
+            parent_id.field_key(),
+            parent_state,
+        );
+        (smt, state_root, child_id, child_state)
+    }
+
+    #[test]
+    fn test_state_proof_inclusion() {
+        let (smt, state_root, child_id, child_state) = new_state_tree();
+        let parent_id = child_id.parent().unwrap();
+        let field_key = child_id.field_key();
+        let proof = get_state_proof(&smt, state_root, parent_id.clone(), field_key);
+        assert_eq!(proof.proofs.len(), 2);
+        assert_eq!(proof.state(), Some(&child_state));
+        assert_eq!(
+            proof.verify(state_root, &parent_id, field_key).unwrap(),
+            Some(child_state)
+        );
+        assert!(proof.verify(H256::random(), &parent_id, field_key).is_err());
+    }
+
+    #[test]
+    fn test_state_proof_non_inclusion() {
+        let (smt, state_root, child_id, _) = new_state_tree();
+
+        // the field does not exist in the parent object
+        let parent_id = child_id.parent().unwrap();
+        let field_key = FieldKey::random();
+        let proof = get_state_proof(&smt, state_root, parent_id.clone(), field_key);
+        assert_eq!(proof.proofs.len(), 2);
+        assert_eq!(
+            proof.verify(state_root, &parent_id, field_key).unwrap(),
+            None
+        );
+
+        // the proofs stop at the non-existent parent object
+        let object_id = ObjectID::random();
+        let proof = get_state_proof(&smt, state_root, object_id.clone(), field_key);
+        assert_eq!(proof.proofs.len(), 1);
+        assert_eq!(
+            proof.verify(state_root, &object_id, field_key).unwrap(),
+            None
+        );
+        assert!(proof.verify(H256::random(), &object_id, field_key).is_err());
+    }
+
+    #[test]
+    fn test_tampered_state_proof() {
+        let (smt, state_root, child_id, _) = new_state_tree();
+        let parent_id = child_id.parent().unwrap();
+        let field_key = child_id.field_key();
+        let proof = get_state_proof(&smt, state_root, parent_id.clone(), field_key);
+
+        // the state is changed
+        let mut tampered_proof = proof.clone();
+        tampered_proof.proofs[1].state = Some(new_object_state(child_id.clone(), 3));
+        assert!(tampered_proof
+            .verify(state_root, &parent_id, field_key)
+            .is_err());
+
+        // claims the existing field does not exist
+        let mut tampered_proof = proof.clone();
+        tampered_proof.proofs[1].state = None;
+        assert!(tampered_proof
+            .verify(state_root, &parent_id, field_key)
+            .is_err());
+
+        // the field key is not in the path
+        let mut tampered_proof = proof.clone();
+        tampered_proof.field_key = FieldKey::random();
+        assert!(tampered_proof
+            .verify(state_root, &parent_id, tampered_proof.field_key)
+            .is_err());
+
+        // the proof of the child object is dropped
+        let mut tampered_proof = proof.clone();
+        tampered_proof.proofs.pop();
+        assert!(tampered_proof
+            .verify(state_root, &parent_id, field_key)
+            .is_err());
+
+        let mut tampered_proof = proof.clone();
+        tampered_proof.proofs.clear();
+        assert!(tampered_proof
+            .verify(state_root, &parent_id, field_key)
+            .is_err());
+
+        // the non-inclusion proof is not the last one
+        let mut tampered_proof =
+            get_state_proof(&smt, state_root, ObjectID::random(), FieldKey::random());
+        tampered_proof.proofs.push(proof.proofs[1].clone());
+        assert!(tampered_proof
+            .verify(
+                state_root,
+                &tampered_proof.object_id,
+                tampered_proof.field_key
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_state_proof_of_other_key() {
+        let (smt, state_root, child_id, _) = new_state_tree();
+        let parent_id = child_id.parent().unwrap();
+        // a valid proof of the existing field
+        let proof = get_state_proof(&smt, state_root, parent_id.clone(), child_id.field_key());
+        assert!(proof
+            .verify(state_root, &parent_id, child_id.field_key())
+            .is_ok());
+
+        // is not the proof of another field of the object
+        assert!(proof
+            .verify(state_root, &parent_id, FieldKey::random())
+            .is_err());
+        // nor the proof of the field of another object
+        assert!(proof
+            .verify(state_root, &ObjectID::random(), child_id.field_key())
+            .is_err());
+        // nor the proof of the parent object itself
+        let root_id = parent_id.parent().unwrap();
+        assert!(proof
+            .verify(state_root, &root_id, parent_id.field_key())
+            .is_err());
+    }
+
+    #[test]
+    fn test_transaction_proof() {
+        let leaves = (0..10).map(|_| H256::random()).collect::<Vec<_>>();
+        let accumulator_root = InMemoryAccumulator::from_leaves(&leaves).root_hash();
+        let leaf_index = 3;
+        let proof = TransactionProof {
+            tx_order: leaf_index,
+            tx_hash: leaves[leaf_index as usize],
+            accumulator_root,
+            leaf_index,
+            proof: InMemoryAccumulator::get_proof_from_leaves(&leaves, leaf_index).unwrap(),
+        };
+        let tx_hash = leaves[leaf_index as usize];
+        proof.verify(accumulator_root, leaf_index, tx_hash).unwrap();
+        assert!(proof.verify(H256::random(), leaf_index, tx_hash).is_err());
+
+        let mut invalid_proof = proof.clone();
+        invalid_proof.tx_hash = H256::random();
+        assert!(invalid_proof
+            .verify(accumulator_root, leaf_index, invalid_proof.tx_hash)
+            .is_err());
+    }
+
+    #[test]
+    fn test_transaction_proof_of_other_tx() {
+        let leaves = (0..10).map(|_| H256::random()).collect::<Vec<_>>();
+        let accumulator_root = InMemoryAccumulator::from_leaves(&leaves).root_hash();
+        // a valid proof of the tx at order 3
+        let other_order = 3;
+        let other_proof = TransactionProof {
+            tx_order: other_order,
+            tx_hash: leaves[other_order as usize],
+            accumulator_root,
+            leaf_index: other_order,
+            proof: InMemoryAccumulator::get_proof_from_leaves(&leaves, other_order).unwrap(),
+        };
+        other_proof
+            .verify(accumulator_root, other_order, leaves[other_order as usize])
+            .unwrap();
+
+        // is not the proof of the tx at order 5
+        let tx_order = 5;
+        let tx_hash = leaves[tx_order as usize];
+        assert!(other_proof
+            .verify(accumulator_root, tx_order, tx_hash)
+            .is_err());
+
+        // claims the requested tx_order
+        let mut invalid_proof = other_proof.clone();
+        invalid_proof.tx_order = tx_order;
+        assert!(invalid_proof
+            .verify(accumulator_root, tx_order, tx_hash)
+            .is_err());
+
+        // claims the requested tx_order and tx_hash
+        invalid_proof.tx_hash = tx_hash;
+        assert!(invalid_proof
+            .verify(accumulator_root, tx_order, tx_hash)
+            .is_err());
+
+        // the leaf index is not the tx_order
+        let mut invalid_proof = other_proof.clone();
+        invalid_proof.tx_order = tx_order;
+        invalid_proof.tx_hash = tx_hash;
+        invalid_proof.leaf_index = tx_order;
+        assert!(invalid_proof
+            .verify(accumulator_root, tx_order, tx_hash)
+            .is_err());
+    }
+}
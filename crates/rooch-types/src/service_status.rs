@@ -18,6 +18,8 @@ pub enum ServiceStatus {
     ReadOnlyMode,
     /// The service is in date import mode.
     DateImportMode,
+    /// The service is in sync mode, replaying transactions from DA.
+    SyncMode,
 }
 
 impl ServiceStatus {
@@ -36,4 +38,8 @@ impl ServiceStatus {
     pub fn is_date_import_mode(&self) -> bool {
         matches!(self, ServiceStatus::DateImportMode)
     }
+
+    pub fn is_sync_mode(&self) -> bool {
+        matches!(self, ServiceStatus::SyncMode)
+    }
 }
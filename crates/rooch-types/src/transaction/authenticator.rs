@@ -22,7 +22,10 @@ use std::{fmt, str::FromStr};
 use crate::{
     crypto::{RoochKeyPair, Signature, SignatureScheme},
     framework::{
-        auth_payload::{AuthPayload, MultisignAuthPayload, SignData},
+        auth_payload::{
+            AuthPayload, MultisignAuthPayload, SignData, WebauthnAuthPayload, MESSAGE_INFO,
+            MESSAGE_INFO_PREFIX,
+        },
         auth_validator::BuiltinAuthValidator,
     },
     rooch_network::{BuiltinChainID, RoochNetwork},
@@ -115,8 +118,21 @@ impl BitcoinAuthenticator {
     }
 
     pub fn sign(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Self {
+        Self::sign_data(kp, tx_data, SignData::new_with_default(tx_data))
+    }
+
+    /// Sign the sponsor hash of the transaction as the sponsor who pays the gas.
+    pub fn sign_sponsor(kp: &RoochKeyPair, tx_data: &RoochTransactionData) -> Self {
+        let sign_data = SignData::new_with_hash(
+            MESSAGE_INFO_PREFIX.to_vec(),
+            MESSAGE_INFO.to_vec(),
+            tx_data.sponsor_hash(),
+        );
+        Self::sign_data(kp, tx_data, sign_data)
+    }
+
+    fn sign_data(kp: &RoochKeyPair, tx_data: &RoochTransactionData, sign_data: SignData) -> Self {
         assert_eq!(kp.public().scheme(), SignatureScheme::Secp256k1);
-        let sign_data = SignData::new_with_default(tx_data);
         let data_hash = sign_data.data_hash();
         let signature = kp.sign(data_hash.as_bytes());
         let bitcoin_address = kp
@@ -176,6 +192,69 @@ impl BitcoinMultisignAuthenticator {
     }
 }
 
+/// The WebAuthn (passkey) authenticator, the assertion is signed by the authenticator device,
+/// so the authenticator is constructed from the assertion instead of a `RoochKeyPair`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WebauthnAuthenticator {
+    pub payload: WebauthnAuthPayload,
+}
+
+impl WebauthnAuthenticator {
+    pub fn new(payload: WebauthnAuthPayload) -> Self {
+        Self { payload }
+    }
+}
+
+impl BuiltinAuthenticator for WebauthnAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        BuiltinAuthValidator::Webauthn.flag().into()
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(&self.payload).expect("Serialize WebauthnAuthenticator should success")
+    }
+}
+
+/// The authenticators of a sponsored transaction, the sponsor pays the gas of the transaction.
+/// It is serialized as the authenticator of the `RoochTransaction` with the `SPONSORED_AUTH_VALIDATOR_ID`,
+/// so the transactions without sponsor keep the same bytes.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SponsoredAuthenticator {
+    pub sender: Authenticator,
+    pub sponsor: Authenticator,
+}
+
+impl SponsoredAuthenticator {
+    /// It is not a registered auth validator id, only used to mark the sponsored authenticator.
+    pub const SPONSORED_AUTH_VALIDATOR_ID: u64 = u64::MAX;
+
+    pub fn new(sender: Authenticator, sponsor: Authenticator) -> Self {
+        Self { sender, sponsor }
+    }
+
+    pub fn is_sponsored(authenticator: &Authenticator) -> bool {
+        authenticator.auth_validator_id == Self::SPONSORED_AUTH_VALIDATOR_ID
+    }
+
+    pub fn from_authenticator(authenticator: &Authenticator) -> Result<Self> {
+        if !Self::is_sponsored(authenticator) {
+            return Err(anyhow::anyhow!(
+                "Invalid sponsored authenticator validator id: {}",
+                authenticator.auth_validator_id
+            ));
+        }
+        bcs::from_bytes(&authenticator.payload).map_err(Into::into)
+    }
+}
+
+impl BuiltinAuthenticator for SponsoredAuthenticator {
+    fn auth_validator_id(&self) -> u64 {
+        Self::SPONSORED_AUTH_VALIDATOR_ID
+    }
+    fn payload(&self) -> Vec<u8> {
+        bcs::to_bytes(self).expect("Serialize SponsoredAuthenticator should success")
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct Authenticator {
     pub auth_validator_id: u64,
@@ -218,6 +297,11 @@ impl Authenticator {
         BitcoinMultisignAuthenticator::build_multisig_authenticator(authenticators).map(Into::into)
     }
 
+    /// Create a WebAuthn authenticator for RoochTransaction from the passkey assertion
+    pub fn webauthn(payload: WebauthnAuthPayload) -> Self {
+        WebauthnAuthenticator::new(payload).into()
+    }
+
     /// Create a custom authenticator
     pub fn new(auth_validator_id: u64, payload: Vec<u8>) -> Self {
         Self {
@@ -7,7 +7,8 @@ use framework_types::addresses::ROOCH_FRAMEWORK_ADDRESS;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::ident_str;
 use move_core_types::identifier::IdentStr;
-use moveos_types::state::{MoveState, MoveStructState, MoveStructType};
+use moveos_types::moveos_std::event::Event;
+use moveos_types::state::{MoveState, MoveStructState, MoveStructType, StateChangeSetExt};
 use moveos_types::transaction::TransactionExecutionInfo;
 use moveos_types::{h256::H256, transaction::TransactionOutput};
 use serde::{Deserialize, Serialize};
@@ -16,6 +17,7 @@ pub mod authenticator;
 mod ledger_transaction;
 pub mod rooch;
 
+use crate::indexer::transaction::IndexerTransaction;
 use crate::test_utils::random_accumulator_info;
 pub use authenticator::Authenticator;
 pub use ledger_transaction::{
@@ -144,6 +146,64 @@ impl MoveStructState for TransactionSequenceInfo {
     }
 }
 
+/// The expiration in the transaction data extension, it is added to the tx context
+/// and checked by the transaction validator.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionExpiration {
+    /// The transaction is expired from this timestamp, in millisecond.
+    pub expiration_timestamp: u64,
+}
+
+impl TransactionExpiration {
+    pub fn new(expiration_timestamp: u64) -> Self {
+        Self {
+            expiration_timestamp,
+        }
+    }
+}
+
+impl MoveStructType for TransactionExpiration {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("transaction");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TransactionExpiration");
+}
+
+impl MoveStructState for TransactionExpiration {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U64,
+        ])
+    }
+}
+
+/// The gas price in the transaction data extension, it is added to the tx context
+/// and the transaction fee is calculated by it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionGasPrice {
+    /// The gas price per gas unit the sender bids.
+    pub gas_price: u64,
+}
+
+impl TransactionGasPrice {
+    pub fn new(gas_price: u64) -> Self {
+        Self { gas_price }
+    }
+}
+
+impl MoveStructType for TransactionGasPrice {
+    const ADDRESS: AccountAddress = ROOCH_FRAMEWORK_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = ident_str!("transaction");
+    const STRUCT_NAME: &'static IdentStr = ident_str!("TransactionGasPrice");
+}
+
+impl MoveStructState for TransactionGasPrice {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U64,
+        ])
+    }
+}
+
 /// Transaction with sequence info and execution info.
 #[derive(Debug, Clone)]
 pub struct TransactionWithInfo {
@@ -163,6 +223,18 @@ impl TransactionWithInfo {
     }
 }
 
+/// The committed transaction with all its store changes, the follower nodes apply the store changes
+/// of the leader node without re-executing the transaction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommittedTransaction {
+    pub transaction: LedgerTransaction,
+    pub execution_info: TransactionExecutionInfo,
+    /// The state change set after applied, the state roots in it are the state roots after the changes
+    pub state_change_set: StateChangeSetExt,
+    pub events: Vec<Event>,
+    pub indexer_transaction: IndexerTransaction,
+}
+
 #[derive(Debug, Clone)]
 pub struct ExecuteTransactionResponse {
     pub sequence_info: TransactionSequenceInfo,
@@ -172,12 +244,15 @@ pub struct ExecuteTransactionResponse {
 
 #[cfg(test)]
 mod tests {
-    use super::rooch::RoochTransaction;
+    use super::rooch::{RoochTransaction, RoochTransactionData};
+    use crate::address::{RoochAddress, RoochSupportedAddress};
     use crate::test_utils::random_accumulator_info;
     use crate::transaction::TransactionSequenceInfo;
     use ethers::types::H256;
     use moveos_types::state::MoveState;
     use moveos_types::test_utils::random_bytes;
+    use moveos_types::transaction::{ChainedCall, MoveAction};
+    use serde::Serialize;
 
     fn test_serialize_deserialize_roundtrip(tx: RoochTransaction) {
         let bytes = tx.encode();
@@ -191,6 +266,145 @@ mod tests {
         test_serialize_deserialize_roundtrip(tx)
     }
 
+    #[test]
+    fn test_serialize_deserialize_with_expiration() {
+        let mut tx = RoochTransaction::mock();
+        let v1_hash = tx.data.tx_hash();
+        tx.data = tx.data.with_expiration_timestamp(1_000);
+        assert_ne!(tx.data.tx_hash(), v1_hash);
+        test_serialize_deserialize_roundtrip(tx)
+    }
+
+    #[test]
+    fn test_serialize_deserialize_with_gas_price() {
+        let mut tx = RoochTransaction::mock();
+        let v2_data = tx.data.clone().with_expiration_timestamp(1_000);
+        tx.data = v2_data.clone().with_gas_price(2);
+        assert_ne!(tx.data.tx_hash(), v2_data.tx_hash());
+        test_serialize_deserialize_roundtrip(tx.clone());
+        tx.data.expiration_timestamp = None;
+        test_serialize_deserialize_roundtrip(tx)
+    }
+
+    #[test]
+    fn test_serialize_deserialize_multi_call() {
+        let mut tx = RoochTransaction::mock();
+        let call = match tx.data.action.clone() {
+            MoveAction::Function(call) => call,
+            _ => unreachable!(),
+        };
+        tx.data.action = MoveAction::new_multi_call(vec![ChainedCall::new(
+            call.function_id,
+            call.ty_args,
+            vec![],
+        )]);
+        assert!(tx.data.is_extended());
+        test_serialize_deserialize_roundtrip(tx.clone());
+        // The multi-call is encoded as a `MoveAction` with or without the extension fields
+        let action_bytes = bcs::to_bytes(&tx.data.action).unwrap();
+        let contains_action =
+            |bytes: Vec<u8>| bytes.windows(action_bytes.len()).any(|w| w == action_bytes);
+        assert!(contains_action(tx.data.encode()));
+        tx.data = tx.data.with_expiration_timestamp(1_000);
+        assert!(contains_action(tx.data.encode()));
+        test_serialize_deserialize_roundtrip(tx)
+    }
+
+    #[test]
+    fn test_deserialize_non_canonical_extended_data() {
+        #[derive(Serialize)]
+        enum Payload {
+            _Script,
+            _Function,
+            _ModuleBundle,
+            Extended {
+                action: MoveAction,
+                extension: Extension,
+            },
+        }
+        #[derive(Serialize)]
+        enum Extension {
+            V1 {
+                expiration_timestamp: Option<u64>,
+                gas_price: Option<u64>,
+            },
+        }
+        #[derive(Serialize)]
+        struct RoochTransactionDataExtended {
+            sender: RoochAddress,
+            sequence_number: u64,
+            chain_id: u64,
+            max_gas_amount: u64,
+            payload: Payload,
+        }
+        let tx = RoochTransaction::mock();
+        let mut extended = RoochTransactionDataExtended {
+            sender: tx.data.sender,
+            sequence_number: tx.data.sequence_number,
+            chain_id: tx.data.chain_id,
+            max_gas_amount: tx.data.max_gas_amount,
+            payload: Payload::Extended {
+                action: tx.data.action.clone(),
+                extension: Extension::V1 {
+                    expiration_timestamp: Some(1_000),
+                    gas_price: None,
+                },
+            },
+        };
+        let data = RoochTransactionData::decode(&bcs::to_bytes(&extended).unwrap()).unwrap();
+        assert_eq!(data.expiration_timestamp, Some(1_000));
+        assert_eq!(data.encode(), bcs::to_bytes(&extended).unwrap());
+
+        // The transaction data without extension fields must be encoded as the legacy data
+        extended.payload = Payload::Extended {
+            action: tx.data.action.clone(),
+            extension: Extension::V1 {
+                expiration_timestamp: None,
+                gas_price: None,
+            },
+        };
+        assert!(RoochTransactionData::decode(&bcs::to_bytes(&extended).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_serialize_deserialize_sponsored() {
+        let mut tx = RoochTransaction::mock();
+        let unsponsored_bytes = tx.encode();
+        let unsponsored_hash = tx.data.tx_hash();
+        let sponsor = RoochAddress::random();
+        tx.data = tx.data.with_sponsor(sponsor);
+        assert_ne!(tx.data.tx_hash(), unsponsored_hash);
+        assert_ne!(tx.data.sponsor_hash(), tx.data.tx_hash());
+        let sponsor_authenticator = RoochTransaction::mock().authenticator;
+        let tx = tx.with_sponsor_authenticator(sponsor_authenticator.clone());
+        assert_ne!(tx.encode(), unsponsored_bytes);
+        let tx2 = RoochTransaction::decode(&tx.encode()).unwrap();
+        assert_eq!(tx2.data.sponsor, Some(sponsor));
+        assert_eq!(tx2.sponsor_authenticator(), Some(&sponsor_authenticator));
+        test_serialize_deserialize_roundtrip(tx)
+    }
+
+    #[test]
+    fn test_v1_transaction_data_layout() {
+        #[derive(Serialize)]
+        struct RoochTransactionDataV1 {
+            sender: RoochAddress,
+            sequence_number: u64,
+            chain_id: u64,
+            max_gas_amount: u64,
+            action: MoveAction,
+        }
+        let tx = RoochTransaction::mock();
+        let data_v1 = RoochTransactionDataV1 {
+            sender: tx.data.sender,
+            sequence_number: tx.data.sequence_number,
+            chain_id: tx.data.chain_id,
+            max_gas_amount: tx.data.max_gas_amount,
+            action: tx.data.action.clone(),
+        };
+        assert_eq!(bcs::to_bytes(&data_v1).unwrap(), tx.data.encode());
+    }
+
     #[test]
     fn test_serialize_deserialize_transaction_sequence_info() {
         let tx_order_signature = random_bytes();
@@ -1,9 +1,11 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use super::authenticator::{BitcoinAuthenticator, BitcoinMultisignAuthenticator};
-use super::RawTransaction;
+use super::authenticator::{
+    BitcoinAuthenticator, BitcoinMultisignAuthenticator, SponsoredAuthenticator,
+};
 use super::{authenticator::Authenticator, AuthenticatorInfo};
+use super::{RawTransaction, TransactionExpiration, TransactionGasPrice};
 use crate::address::RoochAddress;
 use crate::crypto::RoochKeyPair;
 use crate::rooch_network::BuiltinChainID;
@@ -12,13 +14,17 @@ use moveos_types::h256::H256;
 use moveos_types::moveos_std::gas_schedule::GasScheduleConfig;
 use moveos_types::moveos_std::object::ObjectMeta;
 use moveos_types::{
-    moveos_std::tx_context::TxContext,
-    transaction::{MoveAction, MoveOSTransaction},
+    moveos_std::tx_context::{GasPaymentAccount, TxContext},
+    transaction::{FunctionCall, MoveAction, MoveOSTransaction, MultiCall, ScriptCall},
 };
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
 use std::fmt::{Display, Formatter};
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+/// The domain of the `RoochTransactionData::sponsor_hash`.
+pub const SPONSOR_SIGN_DOMAIN: &[u8] = b"ROOCH_SPONSOR::";
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct RoochTransactionData {
     /// Sender's address.
     pub sender: RoochAddress,
@@ -30,6 +36,141 @@ pub struct RoochTransactionData {
     pub max_gas_amount: u64,
     // The MoveAction to execute.
     pub action: MoveAction,
+    // The timestamp in milliseconds from which the transaction is expired.
+    // It is serialized in the transaction data extension.
+    pub expiration_timestamp: Option<u64>,
+    // The gas price the sender bids, it must not be lower than the base fee.
+    // It is serialized in the transaction data extension, the transaction without gas price pays the base fee.
+    pub gas_price: Option<u64>,
+    // The sponsor who pays the gas, the transaction must be signed by the sponsor too.
+    // It is serialized in the transaction data extension.
+    pub sponsor: Option<RoochAddress>,
+}
+
+/// The serialized form of the `RoochTransactionData`.
+/// The transaction data without extension fields serializes the action as a `MoveAction`, so the
+/// transactions before the extension was introduced keep the same bytes and tx hash.
+/// Otherwise the action and the versioned `TransactionExtension` are serialized in the `Extended` variant,
+/// the new fields of the transaction data are added to the extension.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "RoochTransactionData")]
+struct RoochTransactionDataWire<'a> {
+    sender: RoochAddress,
+    sequence_number: u64,
+    chain_id: u64,
+    max_gas_amount: u64,
+    payload: TransactionPayload<'a>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum TransactionPayload<'a> {
+    // The legacy variants must be the same as the first `MoveAction` variants
+    Script(Cow<'a, ScriptCall>),
+    Function(Cow<'a, FunctionCall>),
+    ModuleBundle(Cow<'a, Vec<Vec<u8>>>),
+    Extended {
+        action: Cow<'a, MoveAction>,
+        extension: TransactionExtension,
+    },
+}
+
+/// The extension fields of the transaction data.
+/// A new version is added when the transaction data has new fields, the earlier versions are kept for decoding.
+#[derive(Serialize, Deserialize)]
+enum TransactionExtension {
+    V1 {
+        expiration_timestamp: Option<u64>,
+        gas_price: Option<u64>,
+        sponsor: Option<RoochAddress>,
+    },
+}
+
+impl Serialize for RoochTransactionData {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let payload = match &self.action {
+            MoveAction::Script(call) if !self.is_extended() => {
+                TransactionPayload::Script(Cow::Borrowed(call))
+            }
+            MoveAction::Function(call) if !self.is_extended() => {
+                TransactionPayload::Function(Cow::Borrowed(call))
+            }
+            MoveAction::ModuleBundle(modules) if !self.is_extended() => {
+                TransactionPayload::ModuleBundle(Cow::Borrowed(modules))
+            }
+            action => TransactionPayload::Extended {
+                action: Cow::Borrowed(action),
+                extension: TransactionExtension::V1 {
+                    expiration_timestamp: self.expiration_timestamp,
+                    gas_price: self.gas_price,
+                    sponsor: self.sponsor,
+                },
+            },
+        };
+        RoochTransactionDataWire {
+            sender: self.sender,
+            sequence_number: self.sequence_number,
+            chain_id: self.chain_id,
+            max_gas_amount: self.max_gas_amount,
+            payload,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RoochTransactionData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let wire = RoochTransactionDataWire::deserialize(deserializer)?;
+        let extended = matches!(wire.payload, TransactionPayload::Extended { .. });
+        let (action, expiration_timestamp, gas_price, sponsor) = match wire.payload {
+            TransactionPayload::Script(call) => {
+                (MoveAction::Script(call.into_owned()), None, None, None)
+            }
+            TransactionPayload::Function(call) => {
+                (MoveAction::Function(call.into_owned()), None, None, None)
+            }
+            TransactionPayload::ModuleBundle(modules) => (
+                MoveAction::ModuleBundle(modules.into_owned()),
+                None,
+                None,
+                None,
+            ),
+            TransactionPayload::Extended { action, extension } => match extension {
+                TransactionExtension::V1 {
+                    expiration_timestamp,
+                    gas_price,
+                    sponsor,
+                } => (
+                    action.into_owned(),
+                    expiration_timestamp,
+                    gas_price,
+                    sponsor,
+                ),
+            },
+        };
+        let data = Self {
+            sender: wire.sender,
+            sequence_number: wire.sequence_number,
+            chain_id: wire.chain_id,
+            max_gas_amount: wire.max_gas_amount,
+            action,
+            expiration_timestamp,
+            gas_price,
+            sponsor,
+        };
+        // The transaction data has only one encoding, so the tx hash is unique
+        if extended != data.is_extended() {
+            return Err(serde::de::Error::custom(
+                "The transaction data without extension fields should not be extended",
+            ));
+        }
+        Ok(data)
+    }
 }
 
 impl RoochTransactionData {
@@ -46,9 +187,39 @@ impl RoochTransactionData {
             chain_id,
             max_gas_amount,
             action,
+            expiration_timestamp: None,
+            gas_price: None,
+            sponsor: None,
         }
     }
 
+    /// The transaction is expired from the `expiration_timestamp` in milliseconds.
+    pub fn with_expiration_timestamp(mut self, expiration_timestamp: u64) -> Self {
+        self.expiration_timestamp = Some(expiration_timestamp);
+        self
+    }
+
+    /// The transaction bids the `gas_price` per gas unit.
+    pub fn with_gas_price(mut self, gas_price: u64) -> Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    /// The `sponsor` pays the gas of the transaction, it must sign the `sponsor_hash` of the transaction.
+    pub fn with_sponsor(mut self, sponsor: RoochAddress) -> Self {
+        self.sponsor = Some(sponsor);
+        self
+    }
+
+    /// Whether the transaction data is serialized with the extension.
+    /// The `MoveAction::MultiCall` is introduced after the extension, so it is always extended.
+    pub fn is_extended(&self) -> bool {
+        self.expiration_timestamp.is_some()
+            || self.gas_price.is_some()
+            || self.sponsor.is_some()
+            || matches!(self.action, MoveAction::MultiCall(_))
+    }
+
     pub fn new_for_test(sender: RoochAddress, sequence_number: u64, action: MoveAction) -> Self {
         Self {
             sender,
@@ -56,6 +227,9 @@ impl RoochTransactionData {
             chain_id: BuiltinChainID::Local.chain_id().id(),
             max_gas_amount: GasScheduleConfig::INITIAL_MAX_GAS_AMOUNT,
             action,
+            expiration_timestamp: None,
+            gas_price: None,
+            sponsor: None,
         }
     }
 
@@ -74,6 +248,14 @@ impl RoochTransactionData {
         moveos_types::h256::sha3_256_of(self.encode().as_slice())
     }
 
+    /// The hash signed by the sponsor, it is separated from the tx hash signed by the sender,
+    /// so a signature of the sender can not be used as the signature of the sponsor.
+    pub fn sponsor_hash(&self) -> H256 {
+        let mut data = SPONSOR_SIGN_DOMAIN.to_vec();
+        data.extend_from_slice(self.tx_hash().as_bytes());
+        moveos_types::h256::sha3_256_of(data.as_slice())
+    }
+
     pub fn tx_size(&self) -> u64 {
         bcs::serialized_size(self).expect("serialize transaction size should success") as u64
     }
@@ -88,8 +270,15 @@ impl Display for RoochTransactionData {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{{ sender: {}, sequence_number {}, chain_id: {}, max_gas_amount: {}, action: {} }}",
-            self.sender, self.sequence_number, self.chain_id, self.max_gas_amount, self.action
+            "{{ sender: {}, sequence_number {}, chain_id: {}, max_gas_amount: {}, action: {}, expiration_timestamp: {:?}, gas_price: {:?}, sponsor: {:?} }}",
+            self.sender,
+            self.sequence_number,
+            self.chain_id,
+            self.max_gas_amount,
+            self.action,
+            self.expiration_timestamp,
+            self.gas_price,
+            self.sponsor
         )
     }
 }
@@ -165,24 +354,88 @@ impl PartiallySignedRoochTransaction {
     }
 }
 
-#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct RoochTransaction {
     pub data: RoochTransactionData,
+    /// The sender's authenticator.
     pub authenticator: Authenticator,
+    /// The authenticator of the `sponsor` in the transaction data, if the transaction is sponsored.
+    pub sponsor_authenticator: Option<Authenticator>,
 
-    #[serde(skip_serializing, skip_deserializing)]
     data_hash: Option<H256>,
 }
 
+/// The serialized form of the `RoochTransaction`.
+/// The authenticators of the sponsored transaction are serialized as a `SponsoredAuthenticator`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "RoochTransaction")]
+struct RoochTransactionWire<'a> {
+    data: Cow<'a, RoochTransactionData>,
+    authenticator: Cow<'a, Authenticator>,
+}
+
+impl Serialize for RoochTransaction {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let authenticator = match &self.sponsor_authenticator {
+            Some(sponsor_authenticator) => {
+                Cow::Owned(Authenticator::from(SponsoredAuthenticator::new(
+                    self.authenticator.clone(),
+                    sponsor_authenticator.clone(),
+                )))
+            }
+            None => Cow::Borrowed(&self.authenticator),
+        };
+        RoochTransactionWire {
+            data: Cow::Borrowed(&self.data),
+            authenticator,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for RoochTransaction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let wire = RoochTransactionWire::deserialize(deserializer)?;
+        let authenticator = wire.authenticator.into_owned();
+        let (authenticator, sponsor_authenticator) =
+            if SponsoredAuthenticator::is_sponsored(&authenticator) {
+                let sponsored = SponsoredAuthenticator::from_authenticator(&authenticator)
+                    .map_err(serde::de::Error::custom)?;
+                (sponsored.sender, Some(sponsored.sponsor))
+            } else {
+                (authenticator, None)
+            };
+        Ok(Self {
+            data: wire.data.into_owned(),
+            authenticator,
+            sponsor_authenticator,
+            data_hash: None,
+        })
+    }
+}
+
 impl RoochTransaction {
     pub fn new(data: RoochTransactionData, authenticator: Authenticator) -> Self {
         Self {
             data,
             authenticator,
+            sponsor_authenticator: None,
             data_hash: None,
         }
     }
 
+    /// Add the authenticator of the `sponsor` in the transaction data.
+    pub fn with_sponsor_authenticator(mut self, sponsor_authenticator: Authenticator) -> Self {
+        self.sponsor_authenticator = Some(sponsor_authenticator);
+        self
+    }
+
     pub fn new_genesis_tx(
         genesis_address: RoochAddress,
         chain_id: u64,
@@ -191,6 +444,7 @@ impl RoochTransaction {
         Self {
             data: RoochTransactionData::new(genesis_address, 0, chain_id, u64::MAX, action),
             authenticator: Authenticator::genesis(),
+            sponsor_authenticator: None,
             data_hash: None,
         }
     }
@@ -215,6 +469,14 @@ impl RoochTransaction {
         &self.data.action
     }
 
+    pub fn expiration_timestamp(&self) -> Option<u64> {
+        self.data.expiration_timestamp
+    }
+
+    pub fn gas_price(&self) -> Option<u64> {
+        self.data.gas_price
+    }
+
     pub fn decode(bytes: &[u8]) -> Result<Self>
     where
         Self: std::marker::Sized,
@@ -244,6 +506,10 @@ impl RoochTransaction {
         &self.authenticator
     }
 
+    pub fn sponsor_authenticator(&self) -> Option<&Authenticator> {
+        self.sponsor_authenticator.as_ref()
+    }
+
     pub fn tx_size(&self) -> u64 {
         bcs::serialized_size(self).expect("serialize transaction size should success") as u64
     }
@@ -278,13 +544,28 @@ impl RoochTransaction {
     pub fn into_moveos_transaction(mut self, root: ObjectMeta) -> MoveOSTransaction {
         let tx_hash = self.tx_hash();
         let tx_size = self.tx_size();
-        let tx_ctx = TxContext::new(
+        let mut tx_ctx = TxContext::new(
             self.data.sender.into(),
             self.data.sequence_number,
             self.data.max_gas_amount,
             tx_hash,
             tx_size,
         );
+        if let Some(expiration_timestamp) = self.data.expiration_timestamp {
+            tx_ctx
+                .add(TransactionExpiration::new(expiration_timestamp))
+                .expect("add transaction expiration to tx context should success");
+        }
+        if let Some(gas_price) = self.data.gas_price {
+            tx_ctx
+                .add(TransactionGasPrice::new(gas_price))
+                .expect("add transaction gas price to tx context should success");
+        }
+        if let Some(sponsor) = self.data.sponsor {
+            tx_ctx
+                .add(GasPaymentAccount::new(sponsor.into()))
+                .expect("add gas payment account to tx context should success");
+        }
         MoveOSTransaction::new(root, tx_ctx, self.data.action)
     }
 }
@@ -302,8 +583,8 @@ impl Display for RoochTransaction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "RoochTransaction {{ data: {}, authenticator: {}, data_hash {:?} }}",
-            self.data, self.authenticator, self.data_hash
+            "RoochTransaction {{ data: {}, authenticator: {}, sponsor_authenticator: {:?}, data_hash {:?} }}",
+            self.data, self.authenticator, self.sponsor_authenticator, self.data_hash
         )
     }
 }
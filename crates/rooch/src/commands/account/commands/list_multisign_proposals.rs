@@ -0,0 +1,115 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use moveos_types::module_binding::MoveFunctionCaller;
+use rooch_rpc_api::jsonrpc_types::BytesView;
+use rooch_types::{
+    address::{ParsedAddress, RoochAddress},
+    bitcoin::multisign_account::{self, MultisignAccountModule},
+    error::RoochResult,
+};
+use serde::{Deserialize, Serialize};
+
+/// List the participants proposals and the migration records of a multisign account.
+#[derive(Debug, Parser)]
+pub struct ListMultisignProposalsCommand {
+    /// The multisign account address
+    #[clap(long = "multisign-address", short = 'm', value_parser=ParsedAddress::parse)]
+    pub multisign_address: ParsedAddress,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipantsProposalView {
+    pub proposal_id: u64,
+    pub proposer: RoochAddress,
+    pub participant_public_keys: Vec<BytesView>,
+    pub threshold: u64,
+    pub new_bitcoin_address: String,
+    pub approvals: Vec<RoochAddress>,
+    pub rejections: Vec<RoochAddress>,
+    pub status: String,
+    pub created_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationRecordView {
+    pub proposal_id: u64,
+    pub from_bitcoin_address: String,
+    pub to_bitcoin_address: String,
+    pub migrated_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultisignProposalsOutput {
+    pub proposals: Vec<ParticipantsProposalView>,
+    pub migration_records: Vec<MigrationRecordView>,
+}
+
+#[async_trait]
+impl CommandAction<MultisignProposalsOutput> for ListMultisignProposalsCommand {
+    async fn execute(self) -> RoochResult<MultisignProposalsOutput> {
+        let context = self.context_options.build()?;
+        let mapping = context.address_mapping();
+        let multisign_address = self.multisign_address.into_account_address(&mapping)?;
+        let bitcoin_network = context.get_bitcoin_network().await?;
+
+        let client = context.get_client().await?;
+        let multisign_account_module = client.as_module_binding::<MultisignAccountModule>();
+
+        let proposals = multisign_account_module
+            .proposals(multisign_address)?
+            .into_iter()
+            .map(|p| ParticipantsProposalView {
+                proposal_id: p.proposal_id,
+                proposer: p.proposer.into(),
+                participant_public_keys: p
+                    .participant_public_keys
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                threshold: p.threshold,
+                new_bitcoin_address: p
+                    .new_bitcoin_address
+                    .format(bitcoin_network)
+                    .expect("format multisign address should success"),
+                approvals: p.approvals.into_iter().map(Into::into).collect(),
+                rejections: p.rejections.into_iter().map(Into::into).collect(),
+                status: match p.status {
+                    multisign_account::PROPOSAL_STATUS_PENDING => "pending",
+                    multisign_account::PROPOSAL_STATUS_APPROVED => "approved",
+                    _ => "rejected",
+                }
+                .to_owned(),
+                created_at: p.created_at,
+            })
+            .collect();
+
+        let migration_records = multisign_account_module
+            .migration_records(multisign_address)?
+            .into_iter()
+            .map(|r| MigrationRecordView {
+                proposal_id: r.proposal_id,
+                from_bitcoin_address: r
+                    .from_bitcoin_address
+                    .format(bitcoin_network)
+                    .expect("format multisign address should success"),
+                to_bitcoin_address: r
+                    .to_bitcoin_address
+                    .format(bitcoin_network)
+                    .expect("format multisign address should success"),
+                migrated_at: r.migrated_at,
+            })
+            .collect();
+
+        Ok(MultisignProposalsOutput {
+            proposals,
+            migration_records,
+        })
+    }
+}
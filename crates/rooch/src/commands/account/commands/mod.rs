@@ -7,8 +7,11 @@ pub mod create_multisign;
 pub mod export;
 pub mod import;
 pub mod list;
+pub mod list_multisign_proposals;
 pub mod nullify;
+pub mod propose_multisign_update;
 pub mod sign;
 pub mod switch;
 pub mod transfer;
 pub mod verify;
+pub mod vote_multisign_update;
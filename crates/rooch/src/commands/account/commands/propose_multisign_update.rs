@@ -0,0 +1,68 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
+use anyhow::Result;
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    address::{ParsedAddress, RoochAddress},
+    bitcoin::multisign_account::MultisignAccountModule,
+    error::RoochResult,
+};
+
+/// Propose to change the participants and the threshold of a multisign account.
+/// The proposal is executed when the approvals of the participants reach the current threshold.
+#[derive(Debug, Parser)]
+pub struct ProposeMultisignUpdateCommand {
+    /// The multisign account address
+    #[clap(long = "multisign-address", short = 'm', value_parser=ParsedAddress::parse)]
+    pub multisign_address: ParsedAddress,
+
+    /// Public keys of the participants to add
+    #[clap(long = "add-public-keys")]
+    pub add_public_keys: Vec<String>,
+
+    /// Public keys of the participants to remove
+    #[clap(long = "remove-public-keys")]
+    pub remove_public_keys: Vec<String>,
+
+    /// The new threshold of the multisign account
+    #[clap(long = "threshold", short = 't')]
+    pub threshold: u64,
+
+    #[clap(flatten)]
+    pub tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+fn decode_public_keys(public_keys: &[String]) -> Result<Vec<Vec<u8>>> {
+    public_keys
+        .iter()
+        .map(|s| Ok(hex::decode(s.strip_prefix("0x").unwrap_or(s))?))
+        .collect()
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for ProposeMultisignUpdateCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build_require_password()?;
+        let mapping = context.address_mapping();
+        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+        let multisign_address = self.multisign_address.into_account_address(&mapping)?;
+
+        let action = MultisignAccountModule::propose_participants_update_action(
+            multisign_address,
+            decode_public_keys(&self.add_public_keys)?,
+            decode_public_keys(&self.remove_public_keys)?,
+            self.threshold,
+        );
+        let tx_data = context
+            .build_tx_data(sender, action, self.tx_options.max_gas_amount)
+            .await?;
+        context.sign_and_execute(sender, tx_data).await
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use rooch_rpc_api::jsonrpc_types::ExecuteTransactionResponseView;
+use rooch_types::{
+    address::{ParsedAddress, RoochAddress},
+    bitcoin::multisign_account::MultisignAccountModule,
+    error::RoochResult,
+};
+
+/// Approve or reject a proposal to change the participants of a multisign account.
+#[derive(Debug, Parser)]
+pub struct VoteMultisignUpdateCommand {
+    /// The multisign account address
+    #[clap(long = "multisign-address", short = 'm', value_parser=ParsedAddress::parse)]
+    pub multisign_address: ParsedAddress,
+
+    /// The id of the proposal
+    #[clap(long = "proposal-id", short = 'i')]
+    pub proposal_id: u64,
+
+    /// Reject the proposal instead of approving it
+    #[clap(long, default_value = "false")]
+    pub reject: bool,
+
+    #[clap(flatten)]
+    pub tx_options: TransactionOptions,
+
+    #[clap(flatten)]
+    pub context_options: WalletContextOptions,
+}
+
+#[async_trait]
+impl CommandAction<ExecuteTransactionResponseView> for VoteMultisignUpdateCommand {
+    async fn execute(self) -> RoochResult<ExecuteTransactionResponseView> {
+        let context = self.context_options.build_require_password()?;
+        let mapping = context.address_mapping();
+        let sender: RoochAddress = context.resolve_address(self.tx_options.sender)?.into();
+        let multisign_address = self.multisign_address.into_account_address(&mapping)?;
+
+        let action = if self.reject {
+            MultisignAccountModule::reject_participants_update_action(
+                multisign_address,
+                self.proposal_id,
+            )
+        } else {
+            MultisignAccountModule::approve_participants_update_action(
+                multisign_address,
+                self.proposal_id,
+            )
+        };
+        let tx_data = context
+            .build_tx_data(sender, action, self.tx_options.max_gas_amount)
+            .await?;
+        context.sign_and_execute(sender, tx_data).await
+    }
+}
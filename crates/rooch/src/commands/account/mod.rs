@@ -5,8 +5,11 @@ use crate::cli_types::CommandAction;
 use async_trait::async_trait;
 use commands::{
     balance::BalanceCommand, create::CreateCommand, create_multisign::CreateMultisignCommand,
-    export::ExportCommand, import::ImportCommand, list::ListCommand, nullify::NullifyCommand,
-    sign::SignCommand, switch::SwitchCommand, transfer::TransferCommand, verify::VerifyCommand,
+    export::ExportCommand, import::ImportCommand, list::ListCommand,
+    list_multisign_proposals::ListMultisignProposalsCommand, nullify::NullifyCommand,
+    propose_multisign_update::ProposeMultisignUpdateCommand, sign::SignCommand,
+    switch::SwitchCommand, transfer::TransferCommand, verify::VerifyCommand,
+    vote_multisign_update::VoteMultisignUpdateCommand,
 };
 use rooch_rpc_api::jsonrpc_types::json_to_table_display::json_to_table;
 use rooch_types::error::RoochResult;
@@ -32,6 +35,9 @@ impl CommandAction<String> for Account {
             AccountCommand::CreateMultisign(create_multisign) => {
                 create_multisign.execute_serialized().await
             }
+            AccountCommand::ProposeMultisignUpdate(propose) => propose.execute_serialized().await,
+            AccountCommand::VoteMultisignUpdate(vote) => vote.execute_serialized().await,
+            AccountCommand::ListMultisignProposals(list) => list.execute_serialized().await,
             AccountCommand::List(list) => list.execute_serialized().await,
             AccountCommand::Switch(switch) => switch.execute_serialized().await,
             AccountCommand::Nullify(nullify) => nullify.execute_serialized().await,
@@ -61,6 +67,9 @@ impl CommandAction<String> for Account {
 pub enum AccountCommand {
     Create(CreateCommand),
     CreateMultisign(CreateMultisignCommand),
+    ProposeMultisignUpdate(ProposeMultisignUpdateCommand),
+    VoteMultisignUpdate(VoteMultisignUpdateCommand),
+    ListMultisignProposals(ListMultisignProposalsCommand),
     List(ListCommand),
     Switch(SwitchCommand),
     Nullify(NullifyCommand),
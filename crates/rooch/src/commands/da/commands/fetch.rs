@@ -0,0 +1,274 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use rooch_config::da_config::DAConfig;
+use rooch_da::backend::{DABackend, DABackends};
+use rooch_types::da::segment::{segment_from_bytes, Segment, SegmentID};
+use rooch_types::error::{RoochError, RoochResult};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Fetch segments from DA backends to segments directory.
+/// Segments are stored in files named by segment_id, same as the layout of OpenDA fs backend.
+#[derive(Debug, Parser)]
+pub struct FetchCommand {
+    #[clap(long = "segment-dir")]
+    pub segment_dir: PathBuf,
+    #[clap(
+        long = "da",
+        help = "DA config in JSON format, same as `rooch server start --da`"
+    )]
+    pub da: DAConfig,
+    #[clap(
+        long = "namespace",
+        help = "DA namespace, could be derived by `rooch da namespace`"
+    )]
+    pub namespace: String,
+    #[clap(long = "start-chunk", help = "Fetch from the specified chunk_id")]
+    pub start_chunk: Option<u128>,
+    #[clap(
+        long = "end-chunk",
+        help = "Fetch until the specified chunk_id(inclusive)"
+    )]
+    pub end_chunk: Option<u128>,
+}
+
+impl FetchCommand {
+    pub async fn execute(self) -> RoochResult<()> {
+        let backends = DABackends::initialize(self.da.da_backend, self.namespace).await?;
+        if backends.backends.is_empty() {
+            return Err(RoochError::from(anyhow::anyhow!(
+                "No DA backend found in config"
+            )));
+        }
+        if !self.segment_dir.exists() {
+            fs::create_dir_all(&self.segment_dir)?;
+        }
+
+        let start_chunk = self.start_chunk.unwrap_or(0);
+        let end_chunk = self.end_chunk.unwrap_or(u128::MAX);
+
+        let chunks = list_chunks(&backends.backends).await?;
+        let mut fetched_chunks = 0;
+        let mut incomplete_chunks = vec![];
+        for (chunk_id, segment_numbers) in chunks.range(start_chunk..=end_chunk) {
+            if fetch_chunk(
+                &backends.backends,
+                &self.segment_dir,
+                *chunk_id,
+                segment_numbers,
+            )
+            .await?
+            {
+                fetched_chunks += 1;
+            } else {
+                incomplete_chunks.push(*chunk_id);
+            }
+        }
+
+        info!(
+            "Fetched {} chunks to {:?}, incomplete chunks: {:?}",
+            fetched_chunks, self.segment_dir, incomplete_chunks
+        );
+        Ok(())
+    }
+}
+
+// merges the chunks listed by all backends, the backends failed to list are skipped
+async fn list_chunks(backends: &[Arc<dyn DABackend>]) -> anyhow::Result<BTreeMap<u128, Vec<u64>>> {
+    let mut merged_chunks: BTreeMap<u128, Vec<u64>> = BTreeMap::new();
+    let mut listed = false;
+    for backend in backends {
+        match backend.list_chunks().await {
+            Ok(chunks) => {
+                listed = true;
+                for (chunk_id, segment_numbers) in chunks {
+                    merged_chunks
+                        .entry(chunk_id)
+                        .or_default()
+                        .extend(segment_numbers);
+                }
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to list chunks from {}: {:?}",
+                    backend.get_identifier(),
+                    e
+                );
+            }
+        }
+    }
+    if !listed {
+        return Err(anyhow::anyhow!(
+            "Failed to list chunks from all DA backends"
+        ));
+    }
+    for segment_numbers in merged_chunks.values_mut() {
+        segment_numbers.sort();
+        segment_numbers.dedup();
+    }
+    Ok(merged_chunks)
+}
+
+// gets the segment from backends by priority, falls back to the next backend if it's missing or broken
+async fn get_segment(
+    backends: &[Arc<dyn DABackend>],
+    segment_id: SegmentID,
+) -> Option<Box<dyn Segment>> {
+    for backend in backends {
+        match backend.get_segment(segment_id).await {
+            Ok(Some(segment)) => return Some(segment),
+            Ok(None) => {}
+            Err(e) => {
+                warn!(
+                    "Failed to get segment: {} from {}: {:?}",
+                    segment_id,
+                    backend.get_identifier(),
+                    e
+                );
+            }
+        }
+    }
+    None
+}
+
+/// Fetches the listed segments of the chunk which don't exist in segment dir,
+/// returns true if the chunk is complete: segment numbers are continuous from 0 and the last segment is found.
+pub(crate) async fn fetch_chunk(
+    backends: &[Arc<dyn DABackend>],
+    segment_dir: &Path,
+    chunk_id: u128,
+    segment_numbers: &[u64],
+) -> anyhow::Result<bool> {
+    let mut complete = true;
+    let mut has_last = false;
+    for (i, segment_number) in segment_numbers.iter().enumerate() {
+        if *segment_number != i as u64 {
+            complete = false;
+        }
+        let segment_id = SegmentID {
+            chunk_id,
+            segment_number: *segment_number,
+        };
+        let segment_path = segment_dir.join(segment_id.to_string());
+        let segment = if segment_path.exists() {
+            segment_from_bytes(&fs::read(&segment_path)?)?
+        } else {
+            match get_segment(backends, segment_id).await {
+                Some(segment) => {
+                    fs::write(&segment_path, segment.to_bytes())?;
+                    segment
+                }
+                None => {
+                    warn!(
+                        "Segment: {} listed but not found in DA backends",
+                        segment_id
+                    );
+                    complete = false;
+                    continue;
+                }
+            }
+        };
+        if i == segment_numbers.len() - 1 {
+            has_last = segment.is_last();
+        }
+    }
+    Ok(complete && has_last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rooch_config::da_config::{DABackendOpenDAConfig, OpenDAScheme};
+    use rooch_da::backend::openda::OpenDABackendManager;
+    use rooch_types::crypto::RoochKeyPair;
+    use rooch_types::da::batch::DABatch;
+    use rooch_types::test_utils::random_ledger_transaction_with_order;
+    use std::collections::HashMap;
+
+    const TEST_NAMESPACE: &str = "test_namespace";
+
+    async fn new_fs_backend(root: &Path) -> Arc<dyn DABackend> {
+        fs::create_dir_all(root.join(TEST_NAMESPACE)).unwrap();
+        let open_da_config = DABackendOpenDAConfig {
+            scheme: OpenDAScheme::Fs,
+            config: HashMap::from([("root".to_string(), root.to_string_lossy().to_string())]),
+            namespace: Some(TEST_NAMESPACE.to_string()),
+            max_segment_size: Some(512),
+            max_retries: None,
+        };
+        Arc::new(OpenDABackendManager::new(&open_da_config).await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_fetch_chunk_with_fallback() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_a = tmp_dir.path().join("a");
+        let root_b = tmp_dir.path().join("b");
+        let segment_dir = tmp_dir.path().join("segments");
+        fs::create_dir_all(&segment_dir).unwrap();
+        let backends = vec![new_fs_backend(&root_a).await, new_fs_backend(&root_b).await];
+
+        let keypair = RoochKeyPair::generate_secp256k1();
+        let tx_list = (1..=16)
+            .map(|tx_order| random_ledger_transaction_with_order(tx_order, &keypair))
+            .collect::<Vec<_>>();
+        let batch = Arc::new(DABatch::new(0, 1, 16, &tx_list, &keypair).unwrap());
+        for backend in &backends {
+            backend.submit_batch(batch.clone()).await.unwrap();
+        }
+
+        // the first segment is missing and the second one is broken in the first backend
+        let chunks = list_chunks(&backends).await.unwrap();
+        let segment_numbers = chunks[&0].clone();
+        assert!(segment_numbers.len() > 2);
+        let segment_path_a = |segment_number: u64| {
+            root_a.join(TEST_NAMESPACE).join(
+                SegmentID {
+                    chunk_id: 0,
+                    segment_number,
+                }
+                .to_string(),
+            )
+        };
+        fs::remove_file(segment_path_a(0)).unwrap();
+        fs::write(segment_path_a(1), b"broken segment").unwrap();
+
+        assert!(fetch_chunk(&backends, &segment_dir, 0, &segment_numbers)
+            .await
+            .unwrap());
+        assert_eq!(
+            fs::read_dir(&segment_dir).unwrap().count(),
+            segment_numbers.len()
+        );
+
+        // the segment numbers must be continuous from 0
+        assert!(
+            !fetch_chunk(&backends, &segment_dir, 0, &segment_numbers[1..])
+                .await
+                .unwrap()
+        );
+
+        // the chunk is incomplete if the last segment is missing in all backends
+        let last_segment_number = *segment_numbers.last().unwrap();
+        let last_segment_id = SegmentID {
+            chunk_id: 0,
+            segment_number: last_segment_number,
+        };
+        fs::remove_file(segment_dir.join(last_segment_id.to_string())).unwrap();
+        fs::remove_file(segment_path_a(last_segment_number)).unwrap();
+        fs::remove_file(
+            root_b
+                .join(TEST_NAMESPACE)
+                .join(last_segment_id.to_string()),
+        )
+        .unwrap();
+        assert!(!fetch_chunk(&backends, &segment_dir, 0, &segment_numbers)
+            .await
+            .unwrap());
+    }
+}
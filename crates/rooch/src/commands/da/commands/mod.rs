@@ -39,6 +39,7 @@ use tokio::time;
 use tracing::{error, info, warn};
 
 pub mod exec;
+pub mod fetch;
 pub mod index;
 pub mod namespace;
 pub mod pack;
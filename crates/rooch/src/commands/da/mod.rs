@@ -5,6 +5,7 @@ pub mod commands;
 
 use crate::cli_types::CommandAction;
 use crate::commands::da::commands::exec::ExecCommand;
+use crate::commands::da::commands::fetch::FetchCommand;
 use crate::commands::da::commands::index::IndexCommand;
 use crate::commands::da::commands::namespace::NamespaceCommand;
 use crate::commands::da::commands::pack::PackCommand;
@@ -42,6 +43,10 @@ impl CommandAction<String> for DA {
                 repair.execute().await?;
                 Ok("".to_owned())
             }
+            DACommand::Fetch(fetch) => {
+                fetch.execute().await?;
+                Ok("".to_owned())
+            }
         }
     }
 }
@@ -56,4 +61,5 @@ pub enum DACommand {
     Index(IndexCommand),
     Verify(VerifyCommand),
     Repair(RepairCommand),
+    Fetch(FetchCommand),
 }
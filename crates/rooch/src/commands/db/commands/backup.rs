@@ -0,0 +1,30 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::WalletContextOptions;
+use clap::Parser;
+use rooch_rpc_api::jsonrpc_types::backup_view::BackupManifestView;
+use rooch_types::error::RoochResult;
+
+/// Backup the database of the running node online.
+/// The backup is written into the `backup/<name>` dir under the rooch db dir of the node.
+/// The node only serves the backup to the clients on its local host, so the command should be
+/// run on the node host with an active env whose rpc url is a loopback address, e.g. `http://127.0.0.1:6767`.
+#[derive(Debug, Parser)]
+pub struct BackupCommand {
+    /// The backup name, only alphanumeric, '-' and '_' are allowed, default is the current timestamp in milliseconds
+    #[clap(long)]
+    pub name: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) context_options: WalletContextOptions,
+}
+
+impl BackupCommand {
+    pub async fn execute(self) -> RoochResult<BackupManifestView> {
+        let context = self.context_options.build()?;
+        let client = context.get_client().await?;
+        let manifest = client.rooch.backup_db(self.name).await?;
+        Ok(manifest)
+    }
+}
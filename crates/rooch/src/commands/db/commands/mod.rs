@@ -7,6 +7,7 @@ use rooch_types::rooch_network::RoochChainID;
 use std::collections::HashSet;
 use std::path::PathBuf;
 
+pub mod backup;
 pub mod best_rollback;
 pub mod changeset;
 pub mod cp_cf;
@@ -15,7 +16,9 @@ pub mod get_changeset_by_order;
 pub mod get_execution_info_by_hash;
 pub mod get_tx_by_order;
 pub mod list_anomaly;
+pub mod prune;
 pub mod repair;
+pub mod restore;
 pub mod revert;
 pub mod rollback;
 
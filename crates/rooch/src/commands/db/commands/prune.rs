@@ -0,0 +1,85 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::utils::open_rooch_db;
+use clap::Parser;
+use rooch_config::pruner_config::PrunerConfig;
+use rooch_config::R_OPT_NET_HELP;
+use rooch_pruner::pruner::StatePruner;
+use rooch_types::error::RoochResult;
+use rooch_types::rooch_network::RoochChainID;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PruneOutput {
+    /// The existing nodes pinned when the state pruning is initialized on a store
+    /// without the node logs since genesis
+    pub pinned_nodes: Option<u64>,
+    pub indexed_versions: u64,
+    pub pruned_versions: u64,
+    pub deleted_nodes: u64,
+    pub retained_nodes: u64,
+}
+
+/// Prune the historical state nodes offline.
+/// The state node logs are indexed first, then the stale nodes out of the retention are deleted.
+/// If the store has no node logs since genesis, the existing nodes are kept,
+/// and only the states written after the last executed tx are pruned.
+#[derive(Debug, Parser)]
+pub struct PruneCommand {
+    #[clap(
+        long,
+        help = "The number of recent tx states to retain, default is 100000"
+    )]
+    pub retain_recent: Option<u64>,
+    #[clap(
+        long,
+        help = "Retain the states at the DA checkpoints, and only prune the states before the last submitted block, default is true"
+    )]
+    pub retain_checkpoints: Option<bool>,
+    #[clap(
+        long,
+        help = "The max number of tx states to index or prune in a batch, default is 1000"
+    )]
+    pub batch_size: Option<u64>,
+
+    #[clap(long = "data-dir", short = 'd')]
+    pub base_data_dir: Option<PathBuf>,
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl PruneCommand {
+    pub async fn execute(self) -> RoochResult<PruneOutput> {
+        let (_root, rooch_db, _start_time) = open_rooch_db(self.base_data_dir, self.chain_id);
+        let config = PrunerConfig {
+            enable: Some(true),
+            retain_recent: self.retain_recent,
+            retain_checkpoints: self.retain_checkpoints,
+            batch_size: self.batch_size,
+            interval: None,
+        };
+        let pruner = StatePruner::new(rooch_db.moveos_store, rooch_db.rooch_store, &config)?;
+
+        let mut output = PruneOutput {
+            pinned_nodes: pruner.init_baseline_if_needed()?,
+            ..Default::default()
+        };
+        loop {
+            let stats = pruner.run_once()?;
+            if stats.is_idle() {
+                break;
+            }
+            output.indexed_versions += stats.indexed_versions;
+            output.pruned_versions += stats.prune_stats.pruned_versions;
+            output.deleted_nodes += stats.prune_stats.deleted_nodes;
+            output.retained_nodes += stats.prune_stats.retained_nodes;
+            println!(
+                "indexed versions: {}, pruned versions: {}, deleted nodes: {}",
+                output.indexed_versions, output.pruned_versions, output.deleted_nodes
+            );
+        }
+        Ok(output)
+    }
+}
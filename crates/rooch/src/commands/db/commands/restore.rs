@@ -0,0 +1,35 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use rooch_config::{RoochOpt, R_OPT_NET_HELP};
+use rooch_db::backup::restore_backup;
+use rooch_types::backup::BackupManifest;
+use rooch_types::error::RoochResult;
+use rooch_types::rooch_network::RoochChainID;
+use std::path::PathBuf;
+
+/// Restore the database from a backup offline.
+/// The backup is validated with its manifest before restoring, the node must be stopped
+/// and the store and indexer dirs of the target must be empty.
+#[derive(Debug, Parser)]
+pub struct RestoreCommand {
+    /// The backup dir created by `rooch db backup`
+    #[clap(long = "backup-dir")]
+    pub backup_dir: PathBuf,
+
+    #[clap(long = "data-dir", short = 'd')]
+    pub base_data_dir: Option<PathBuf>,
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl RestoreCommand {
+    pub async fn execute(self) -> RoochResult<BackupManifest> {
+        let opt = RoochOpt::new_with_default(self.base_data_dir, self.chain_id, None)?;
+        let chain_id = opt.chain_id().chain_id().id();
+        let rooch_db_dir = opt.store_config().get_rooch_db_dir();
+        let manifest = restore_backup(&self.backup_dir, &rooch_db_dir, chain_id)?;
+        Ok(manifest)
+    }
+}
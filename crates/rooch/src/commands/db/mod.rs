@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::cli_types::CommandAction;
+use crate::commands::db::commands::backup::BackupCommand;
 use crate::commands::db::commands::best_rollback::BestRollbackCommand;
 use crate::commands::db::commands::changeset::ChangesetCommand;
 use crate::commands::db::commands::cp_cf::CpCfCommand;
@@ -10,7 +11,9 @@ use crate::commands::db::commands::get_changeset_by_order::GetChangesetByOrderCo
 use crate::commands::db::commands::get_execution_info_by_hash::GetExecutionInfoByHashCommand;
 use crate::commands::db::commands::get_tx_by_order::GetTxByOrderCommand;
 use crate::commands::db::commands::list_anomaly::ListAnomaly;
+use crate::commands::db::commands::prune::PruneCommand;
 use crate::commands::db::commands::repair::RepairCommand;
+use crate::commands::db::commands::restore::RestoreCommand;
 use crate::commands::db::commands::revert::RevertCommand;
 use async_trait::async_trait;
 use clap::Parser;
@@ -66,6 +69,15 @@ impl CommandAction<String> for DB {
             DBCommand::Changeset(changeset) => changeset.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
+            DBCommand::Prune(prune) => prune.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
+            DBCommand::Backup(backup) => backup.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
+            DBCommand::Restore(restore) => restore.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
         }
     }
 }
@@ -84,4 +96,7 @@ pub enum DBCommand {
     ListAnomaly(ListAnomaly),
     CpCf(CpCfCommand),
     Changeset(ChangesetCommand),
+    Prune(PruneCommand),
+    Backup(BackupCommand),
+    Restore(RestoreCommand),
 }
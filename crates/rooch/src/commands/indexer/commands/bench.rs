@@ -34,6 +34,10 @@ pub struct BenchCommand {
     /// All data would be deleted when the service is stopped.
     #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
     pub chain_id: Option<RoochChainID>,
+
+    /// The PostgreSQL database url of the indexer, if not set, the indexer uses SQLite in the data dir
+    #[clap(long)]
+    pub indexer_db_url: Option<String>,
 }
 
 impl BenchCommand {
@@ -50,8 +54,12 @@ impl BenchCommand {
 
     fn init(&self) -> (IndexerStore, IndexerReader, Instant) {
         let start_time = Instant::now();
-        let (indexer_store, indexer_reader) =
-            init_indexer(self.base_data_dir.clone(), self.chain_id.clone()).unwrap();
+        let (indexer_store, indexer_reader) = init_indexer(
+            self.base_data_dir.clone(),
+            self.chain_id.clone(),
+            self.indexer_db_url.clone(),
+        )
+        .unwrap();
         tracing::info!("indexer bench started");
         (indexer_store, indexer_reader, start_time)
     }
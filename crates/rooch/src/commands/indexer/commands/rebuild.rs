@@ -45,6 +45,10 @@ pub struct RebuildCommand {
 
     #[clap(long, short = 'b', default_value = "20971")]
     pub batch_size: Option<usize>,
+
+    /// The PostgreSQL database url of the indexer, if not set, the indexer uses SQLite in the data dir
+    #[clap(long)]
+    pub indexer_db_url: Option<String>,
 }
 
 impl RebuildCommand {
@@ -71,8 +75,12 @@ impl RebuildCommand {
 
     fn init(self) -> (IndexerStore, IndexerReader, Instant) {
         let start_time = Instant::now();
-        let (indexer_store, indexer_reader) =
-            init_indexer(self.base_data_dir.clone(), self.chain_id.clone()).unwrap();
+        let (indexer_store, indexer_reader) = init_indexer(
+            self.base_data_dir.clone(),
+            self.chain_id.clone(),
+            self.indexer_db_url.clone(),
+        )
+        .unwrap();
         tracing::info!("indexer rebuild started");
         (indexer_store, indexer_reader, start_time)
     }
@@ -101,10 +109,14 @@ fn produce_updates(
     let inscription_state_index_start = indexer_reader
         .query_last_state_index_by_tx_order(tx_order, ObjectStateType::Inscription)?
         .map_or(0, |x| x + 1);
+    let rune_balances_state_index_start = indexer_reader
+        .query_last_state_index_by_tx_order(tx_order, ObjectStateType::RuneBalances)?
+        .map_or(0, |x| x + 1);
     let mut state_index_generator = IndexerObjectStatesIndexGenerator {
         object_states_index_generator: state_index_start,
         object_state_utxos_index_generator: utxo_state_index_start,
         object_state_inscriptions_generator: inscription_state_index_start,
+        object_state_rune_balances_generator: rune_balances_state_index_start,
     };
 
     println!(
@@ -161,6 +173,11 @@ fn produce_updates(
                 .object_state_inscriptions
                 .new_object_states
                 .is_empty()
+            && updates
+                .object_state_change_set
+                .object_state_rune_balances
+                .new_object_states
+                .is_empty()
         {
             break;
         }
@@ -194,7 +211,12 @@ fn apply_updates(
             .object_state_inscriptions
             .new_object_states
             .len();
-        let count = object_states_len + utxos_len + inscriptions_len;
+        let rune_balances_len = batch
+            .object_state_change_set
+            .object_state_rune_balances
+            .new_object_states
+            .len();
+        let count = object_states_len + utxos_len + inscriptions_len + rune_balances_len;
         indexer_store.persist_or_update_object_states(
             batch
                 .object_state_change_set
@@ -213,13 +235,20 @@ fn apply_updates(
                 .object_state_inscriptions
                 .new_object_states,
         )?;
+        indexer_store.persist_or_update_object_state_rune_balances(
+            batch
+                .object_state_change_set
+                .object_state_rune_balances
+                .new_object_states,
+        )?;
         ok_count += count;
         println!(
-            "Total {} updates applied. this batch process object states count {}, utxo count {}, inscription count {}. this batch cost: {:?}",
+            "Total {} updates applied. this batch process object states count {}, utxo count {}, inscription count {}, rune balances count {}. this batch cost: {:?}",
             ok_count,
             object_states_len,
             utxos_len,
             inscriptions_len,
+            rune_balances_len,
             loop_start_time.elapsed()
         );
     }
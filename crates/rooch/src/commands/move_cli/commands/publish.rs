@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use super::verify_source::{package_source_digest, PackageBuildMetadata};
 use crate::cli_types::{CommandAction, TransactionOptions, WalletContextOptions};
 use crate::tx_runner::dry_run_tx_locally;
 use async_trait::async_trait;
@@ -145,6 +146,11 @@ pub struct Publish {
     #[clap(long)]
     pub by_move_action: bool,
 
+    /// Attach the source digest and build metadata of the package on chain,
+    /// so the package source can be verified by `rooch move verify-source`.
+    #[clap(long)]
+    pub attach_source: bool,
+
     /// Return command outputs in json format
     #[clap(long, default_value = "false")]
     json: bool,
@@ -164,12 +170,24 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
             .resolve_address(self.tx_options.sender.clone())?
             .into();
 
+        if self.attach_source && self.by_move_action {
+            return Err(RoochError::CommandArgumentError(
+                "The --attach-source option can not be used with --by-move-action".to_owned(),
+            ));
+        }
+
         if let Some(ref package_file) = self.package_file {
+            if self.attach_source {
+                return Err(RoochError::CommandArgumentError(
+                    "The --attach-source option requires building the package from source"
+                        .to_owned(),
+                ));
+            }
             let file = std::fs::File::open(package_file)?;
             let pkg_data: PackageData = bcs::from_reader(file)?;
             eprintln!("Publish modules to address: {:?}", pkg_data.package_id);
             return self
-                .publish_package(&context, sender, pkg_data, max_gas_amount)
+                .publish_package(&context, sender, pkg_data, None, max_gas_amount)
                 .await;
         }
 
@@ -186,6 +204,7 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
         config.additional_named_addresses =
             context.parse_and_resolve_addresses(self.named_addresses.clone())?;
         let config_cloned = config.clone();
+        let build_metadata = PackageBuildMetadata::new(&config);
 
         // Compile the package and run the verifier
         let mut package = config.compile_package_no_exit(&package_path, &mut stderr())?;
@@ -240,12 +259,18 @@ impl CommandAction<ExecuteTransactionResponseView> for Publish {
 
         // Prepare and execute the transaction based on the action type
         let tx_result = if !self.by_move_action {
+            let source = if self.attach_source {
+                let build_metadata = serde_json::to_string(&build_metadata)?;
+                Some((package_source_digest(&package)?, build_metadata))
+            } else {
+                None
+            };
             let pkg_data = PackageData::new(
                 MoveString::from(package.compiled_package_info.package_name.as_str()),
                 pkg_address,
                 bundles,
             );
-            self.publish_package(&context, sender, pkg_data, max_gas_amount)
+            self.publish_package(&context, sender, pkg_data, source, max_gas_amount)
                 .await?
         } else {
             // Handle MoveAction.ModuleBundle case
@@ -378,20 +403,30 @@ impl Publish {
         context: &WalletContext,
         sender: RoochAddress,
         pkg_data: PackageData,
+        source: Option<(Vec<u8>, String)>,
         max_gas_amount: Option<u64>,
     ) -> RoochResult<ExecuteTransactionResponseView> {
         let pkg_bytes = bcs::to_bytes(&pkg_data).unwrap();
-        let args = bcs::to_bytes(&pkg_bytes).unwrap();
+        let mut args = vec![bcs::to_bytes(&pkg_bytes).unwrap()];
+        // Publish with the source digest and build metadata if the source is attached
+        let function_name = match source {
+            Some((source_digest, build_metadata)) => {
+                args.push(bcs::to_bytes(&source_digest).unwrap());
+                args.push(bcs::to_bytes(&MoveString::from(build_metadata)).unwrap());
+                "publish_package_with_source_entry"
+            }
+            None => "publish_package_entry",
+        };
         let action = MoveAction::new_function_call(
             FunctionId::new(
                 ModuleId::new(
                     MOVEOS_STD_ADDRESS,
                     Identifier::new("module_store".to_owned()).unwrap(),
                 ),
-                Identifier::new("publish_package_entry".to_owned()).unwrap(),
+                Identifier::new(function_name.to_owned()).unwrap(),
             ),
             vec![],
-            vec![args],
+            args,
         );
 
         if self.dry_run {
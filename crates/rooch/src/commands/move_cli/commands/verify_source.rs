@@ -0,0 +1,437 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_types::{CommandAction, WalletContextOptions};
+use async_trait::async_trait;
+use clap::Parser;
+use move_cli::{base::reroot_path, Move};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_package::compilation::compiled_package::CompiledPackage;
+use move_package::BuildConfig;
+use moveos_types::access_path::AccessPath;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::moveos_std::module_store::{Package, PackageSource};
+use moveos_types::moveos_std::move_module::MoveModule;
+use moveos_types::state::ObjectState;
+use moveos_verifier::build::run_verifier;
+use rooch_rpc_client::Client;
+use rooch_types::error::{RoochError, RoochResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::stderr;
+
+/// The build metadata attached to the on-chain package, it records the compiler settings
+/// which affect the bytecode, so the package can be rebuilt with the same settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PackageBuildMetadata {
+    /// The version of the rooch CLI which built the package
+    pub rooch_version: String,
+    pub bytecode_version: Option<u32>,
+    pub dev_mode: bool,
+    pub named_addresses: BTreeMap<String, AccountAddress>,
+}
+
+impl PackageBuildMetadata {
+    pub fn new(config: &BuildConfig) -> Self {
+        Self {
+            rooch_version: env!("CARGO_PKG_VERSION").to_owned(),
+            bytecode_version: config.bytecode_version,
+            dev_mode: config.dev_mode,
+            named_addresses: config.additional_named_addresses.clone(),
+        }
+    }
+
+    /// Apply the build settings to the config, the settings given by the user take precedence.
+    pub fn apply_to(&self, config: &mut BuildConfig) {
+        if config.bytecode_version.is_none() {
+            config.bytecode_version = self.bytecode_version;
+        }
+        config.dev_mode |= self.dev_mode;
+        for (name, address) in &self.named_addresses {
+            config
+                .additional_named_addresses
+                .entry(name.clone())
+                .or_insert(*address);
+        }
+    }
+}
+
+/// Get the source digest of the root package computed by the Move package system.
+pub fn package_source_digest(package: &CompiledPackage) -> RoochResult<Vec<u8>> {
+    let digest = package.compiled_package_info.source_digest.ok_or_else(|| {
+        RoochError::MoveCompilationError("Package source digest is missing".to_owned())
+    })?;
+    hex::decode(digest.as_str()).map_err(|e| {
+        RoochError::MoveCompilationError(format!("Invalid package source digest: {}", e))
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ModuleVerifyStatus {
+    /// The local bytecode is the same as the on-chain module
+    Matched,
+    /// The local bytecode is different from the on-chain module
+    Mismatched,
+    /// The module is not found on chain
+    NotPublished,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleVerifyResult {
+    pub module_name: String,
+    pub status: ModuleVerifyStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageSourceView {
+    pub version: u64,
+    pub source_digest: String,
+    pub build_metadata: String,
+    /// Whether the source is attached to the current version of the package
+    pub up_to_date: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifySourceOutput {
+    pub package_id: AccountAddress,
+    pub package_version: u64,
+    /// The source digest of the local package
+    pub source_digest: String,
+    /// The source attached to the on-chain package
+    pub onchain_source: Option<PackageSourceView>,
+    pub modules: Vec<ModuleVerifyResult>,
+    /// All the local modules are the same as the on-chain modules
+    pub bytecode_verified: bool,
+    /// The local source digest is the same as the up to date on-chain source digest
+    pub source_verified: bool,
+}
+
+/// Verify the source of a published package.
+/// Rebuild the local package with the same compiler settings as the on-chain build metadata,
+/// and compare the bytecode with the on-chain modules.
+#[derive(Parser)]
+#[clap(name = "verify-source")]
+pub struct VerifySourceCommand {
+    /// Named addresses for the move binary
+    ///
+    /// Example: alice=0x1234, bob=default, alice2=alice
+    ///
+    /// Note: This will fail if there are duplicates in the Move.toml file remove those first.
+    #[clap(long, value_parser = crate::utils::parse_map::<String, String>, default_value = "")]
+    pub(crate) named_addresses: BTreeMap<String, String>,
+
+    #[clap(flatten)]
+    context_options: WalletContextOptions,
+
+    #[clap(flatten)]
+    move_args: Move,
+}
+
+#[async_trait]
+impl CommandAction<VerifySourceOutput> for VerifySourceCommand {
+    async fn execute(self) -> RoochResult<VerifySourceOutput> {
+        let context = self.context_options.build()?;
+        let client = context.get_client().await?;
+
+        let package_path = reroot_path(self.move_args.package_path)?;
+        let mut config = self.move_args.build_config;
+        config
+            .additional_named_addresses
+            .extend(context.parse_and_resolve_addresses(self.named_addresses)?);
+
+        // Resolve the package id by the first build, then rebuild with the on-chain build metadata
+        let package = compile_package(&package_path, config.clone())?;
+        let package_id = root_package_address(&package)?;
+        let onchain_source = get_package_source(&client, package_id).await?;
+        let package = match &onchain_source {
+            Some(source) => {
+                let build_metadata: PackageBuildMetadata =
+                    serde_json::from_str(source.build_metadata.as_str()).map_err(|e| {
+                        RoochError::CommandArgumentError(format!(
+                            "Invalid build metadata of package {}: {}",
+                            package_id.to_hex_literal(),
+                            e
+                        ))
+                    })?;
+                let mut config = config;
+                build_metadata.apply_to(&mut config);
+                compile_package(&package_path, config)?
+            }
+            None => package,
+        };
+        let source_digest = package_source_digest(&package)?;
+
+        let package_version = get_package_version(&client, package_id)
+            .await?
+            .ok_or_else(|| {
+                RoochError::CommandArgumentError(format!(
+                    "Package {} is not published",
+                    package_id.to_hex_literal()
+                ))
+            })?;
+
+        let local_modules = package
+            .root_modules_map()
+            .iter_modules_owned()
+            .into_iter()
+            .map(|module| {
+                let mut binary = vec![];
+                module.serialize(&mut binary)?;
+                Ok((module.self_id().name().to_owned(), binary))
+            })
+            .collect::<RoochResult<BTreeMap<Identifier, Vec<u8>>>>()?;
+        let onchain_modules =
+            get_modules(&client, package_id, local_modules.keys().cloned().collect()).await?;
+        let modules = verify_modules(&local_modules, &onchain_modules);
+        let bytecode_verified = modules
+            .iter()
+            .all(|m| m.status == ModuleVerifyStatus::Matched);
+
+        let onchain_source = onchain_source.map(|source| PackageSourceView {
+            version: source.version,
+            source_digest: hex::encode(&source.source_digest),
+            build_metadata: source.build_metadata.to_string(),
+            up_to_date: source.version == package_version,
+        });
+        let source_verified =
+            bytecode_verified && is_source_verified(onchain_source.as_ref(), &source_digest);
+
+        Ok(VerifySourceOutput {
+            package_id,
+            package_version,
+            source_digest: hex::encode(&source_digest),
+            onchain_source,
+            modules,
+            bytecode_verified,
+            source_verified,
+        })
+    }
+}
+
+/// Compare the local modules with the on-chain modules by bytecode.
+fn verify_modules(
+    local_modules: &BTreeMap<Identifier, Vec<u8>>,
+    onchain_modules: &BTreeMap<String, Vec<u8>>,
+) -> Vec<ModuleVerifyResult> {
+    local_modules
+        .iter()
+        .map(|(name, binary)| {
+            let status = match onchain_modules.get(name.as_str()) {
+                Some(onchain_binary) if onchain_binary == binary => ModuleVerifyStatus::Matched,
+                Some(_) => ModuleVerifyStatus::Mismatched,
+                None => ModuleVerifyStatus::NotPublished,
+            };
+            ModuleVerifyResult {
+                module_name: name.to_string(),
+                status,
+            }
+        })
+        .collect()
+}
+
+/// The source is verified only if it is attached to the current version of the package
+/// and has the same digest as the local source.
+fn is_source_verified(onchain_source: Option<&PackageSourceView>, source_digest: &[u8]) -> bool {
+    onchain_source.is_some_and(|source| {
+        source.up_to_date && source.source_digest == hex::encode(source_digest)
+    })
+}
+
+fn compile_package(
+    package_path: &std::path::Path,
+    config: BuildConfig,
+) -> RoochResult<CompiledPackage> {
+    let mut package = config
+        .clone()
+        .compile_package_no_exit(package_path, &mut stderr())?;
+    run_verifier(package_path.to_path_buf(), config, &mut package)?;
+    Ok(package)
+}
+
+fn root_package_address(package: &CompiledPackage) -> RoochResult<AccountAddress> {
+    let modules = package.root_modules_map();
+    match modules.iter_modules_owned().first() {
+        Some(module) => Ok(*module.self_id().address()),
+        None => Err(RoochError::MoveCompilationError(
+            "compiling move modules error! The project or module is empty".to_owned(),
+        )),
+    }
+}
+
+async fn get_package_version(
+    client: &Client,
+    package_id: AccountAddress,
+) -> RoochResult<Option<u64>> {
+    let access_path = AccessPath::object(Package::package_id(&package_id));
+    let state = client
+        .rooch
+        .get_states(access_path, None)
+        .await?
+        .pop()
+        .flatten();
+    state
+        .map(|state| {
+            let package = ObjectState::from(state).value_as::<Package>()?;
+            Ok(package.version())
+        })
+        .transpose()
+}
+
+async fn get_package_source(
+    client: &Client,
+    package_id: AccountAddress,
+) -> RoochResult<Option<PackageSource>> {
+    let access_path = AccessPath::object(PackageSource::object_id(package_id));
+    let state = client
+        .rooch
+        .get_states(access_path, None)
+        .await?
+        .pop()
+        .flatten();
+    state
+        .map(|state| Ok(ObjectState::from(state).value_as::<PackageSource>()?))
+        .transpose()
+}
+
+async fn get_modules(
+    client: &Client,
+    package_id: AccountAddress,
+    module_names: Vec<Identifier>,
+) -> RoochResult<BTreeMap<String, Vec<u8>>> {
+    let access_path = AccessPath::modules(package_id, module_names);
+    let states = client.rooch.get_states(access_path, None).await?;
+    let mut modules = BTreeMap::new();
+    for state in states.into_iter().flatten() {
+        let module = ObjectState::from(state).value_as_df::<MoveString, MoveModule>()?;
+        modules.insert(module.name.to_string(), module.value.byte_codes);
+    }
+    Ok(modules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_build_metadata() -> PackageBuildMetadata {
+        PackageBuildMetadata {
+            rooch_version: "0.1.0".to_owned(),
+            bytecode_version: Some(6),
+            dev_mode: true,
+            named_addresses: BTreeMap::from([
+                (
+                    "alice".to_owned(),
+                    AccountAddress::from_hex_literal("0x1").unwrap(),
+                ),
+                (
+                    "bob".to_owned(),
+                    AccountAddress::from_hex_literal("0x2").unwrap(),
+                ),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_build_metadata_apply_to_empty_config() {
+        let build_metadata = new_build_metadata();
+        let mut config = BuildConfig::default();
+        build_metadata.apply_to(&mut config);
+        assert_eq!(config.bytecode_version, Some(6));
+        assert!(config.dev_mode);
+        assert_eq!(
+            config.additional_named_addresses,
+            build_metadata.named_addresses
+        );
+    }
+
+    #[test]
+    fn test_build_metadata_apply_to_user_config() {
+        let build_metadata = new_build_metadata();
+        let mut config = BuildConfig {
+            bytecode_version: Some(7),
+            additional_named_addresses: BTreeMap::from([(
+                "alice".to_owned(),
+                AccountAddress::from_hex_literal("0x3").unwrap(),
+            )]),
+            ..Default::default()
+        };
+        build_metadata.apply_to(&mut config);
+        // the settings given by the user take precedence
+        assert_eq!(config.bytecode_version, Some(7));
+        assert!(config.dev_mode);
+        assert_eq!(
+            config.additional_named_addresses,
+            BTreeMap::from([
+                (
+                    "alice".to_owned(),
+                    AccountAddress::from_hex_literal("0x3").unwrap()
+                ),
+                (
+                    "bob".to_owned(),
+                    AccountAddress::from_hex_literal("0x2").unwrap()
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_build_metadata_json_round_trip() {
+        let build_metadata = new_build_metadata();
+        let json = serde_json::to_string(&build_metadata).unwrap();
+        let decoded: PackageBuildMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.bytecode_version, build_metadata.bytecode_version);
+        assert_eq!(decoded.named_addresses, build_metadata.named_addresses);
+        assert!(serde_json::from_str::<PackageBuildMetadata>("invalid").is_err());
+    }
+
+    #[test]
+    fn test_verify_modules() {
+        let local_modules = BTreeMap::from([
+            (Identifier::new("a").unwrap(), vec![1, 2, 3]),
+            (Identifier::new("b").unwrap(), vec![4, 5, 6]),
+            (Identifier::new("c").unwrap(), vec![7, 8, 9]),
+        ]);
+        let onchain_modules = BTreeMap::from([
+            ("a".to_owned(), vec![1, 2, 3]),
+            ("b".to_owned(), vec![4, 5, 0]),
+        ]);
+        let modules = verify_modules(&local_modules, &onchain_modules);
+        let statuses = modules
+            .iter()
+            .map(|m| (m.module_name.as_str(), m.status))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            vec![
+                ("a", ModuleVerifyStatus::Matched),
+                ("b", ModuleVerifyStatus::Mismatched),
+                ("c", ModuleVerifyStatus::NotPublished),
+            ]
+        );
+
+        let not_published = verify_modules(&local_modules, &BTreeMap::new());
+        assert!(not_published
+            .iter()
+            .all(|m| m.status == ModuleVerifyStatus::NotPublished));
+    }
+
+    #[test]
+    fn test_is_source_verified() {
+        let source_digest = vec![1u8; 32];
+        let source = PackageSourceView {
+            version: 1,
+            source_digest: hex::encode(&source_digest),
+            build_metadata: "{}".to_owned(),
+            up_to_date: true,
+        };
+        assert!(is_source_verified(Some(&source), &source_digest));
+        assert!(!is_source_verified(Some(&source), &[2u8; 32]));
+        assert!(!is_source_verified(None, &source_digest));
+
+        let outdated = PackageSourceView {
+            up_to_date: false,
+            ..source
+        };
+        assert!(!is_source_verified(Some(&outdated), &source_digest));
+    }
+}
@@ -8,7 +8,7 @@ use commands::{
     docgen::DocgenCommand, errmap::ErrmapCommand, info::InfoCommand,
     integration_test::IntegrationTestCommand, new::NewCommand, prove::ProveCommand,
     publish::Publish, run_function::RunFunction, run_view_function::RunViewFunction,
-    unit_test::TestCommand,
+    unit_test::TestCommand, verify_source::VerifySourceCommand,
 };
 use rooch_types::error::RoochResult;
 use serde_json::{json, Value};
@@ -41,6 +41,7 @@ pub enum MoveCommand {
     View(RunViewFunction),
     IntegrationTest(IntegrationTestCommand),
     Explain(ExplainCommand),
+    VerifySource(VerifySourceCommand),
 }
 
 #[async_trait]
@@ -61,6 +62,7 @@ impl CommandAction<String> for MoveCli {
             MoveCommand::View(c) => c.execute_serialized().await,
             MoveCommand::IntegrationTest(c) => c.execute_serialized().await,
             MoveCommand::Explain(c) => c.execute_serialized().await,
+            MoveCommand::VerifySource(c) => c.execute_serialized().await,
         }
     }
 }
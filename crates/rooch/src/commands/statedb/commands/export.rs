@@ -507,7 +507,7 @@ impl ExportCommand {
             }
 
             let mut utxo_raw = UTXORawData::from_str(&line);
-            let (key, state) = utxo_raw.gen_utxo_update(outpoint_inscriptions_map.clone());
+            let (key, state) = utxo_raw.gen_utxo_update(outpoint_inscriptions_map.clone(), None);
             writer.write_record(&key, &state)?;
             if utxo_raw.height > max_height {
                 max_height = utxo_raw.height;
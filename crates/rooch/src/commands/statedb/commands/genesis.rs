@@ -20,18 +20,20 @@ use serde::{Deserialize, Serialize};
 use crate::commands::statedb::commands::genesis_ord::{
     apply_inscription_updates, produce_inscription_updates,
 };
+use crate::commands::statedb::commands::genesis_rune::{apply_rune_updates, produce_rune_updates};
 use crate::commands::statedb::commands::genesis_utxo::{
     apply_address_updates, apply_utxo_updates, produce_address_map_updates, produce_utxo_updates,
 };
 use crate::commands::statedb::commands::inscription::InscriptionStats;
+use crate::commands::statedb::commands::rune::RuneOutpoints;
 use crate::commands::statedb::commands::{init_rooch_db, OutpointInscriptionsMap};
 
-/// Import BTC Inscription & UTXO & rooch_address:BTC_address mapping for genesis
+/// Import BTC Inscription & Rune & UTXO & rooch_address:BTC_address mapping for genesis
 #[derive(Debug, Parser)]
 pub struct GenesisCommand {
     #[clap(
         long,
-        help = "source data dir. ord: <source_data_dir>/ord, utxo: <source_data_dir>/utxo, ord_stats: <source_data_dir>/ord_stats, outpoint_inscriptions_map: <source_data_dir>/outpoint_inscriptions_map, checksum: <source_data_dir>/checksum"
+        help = "source data dir. ord: <source_data_dir>/ord, utxo: <source_data_dir>/utxo, ord_stats: <source_data_dir>/ord_stats, outpoint_inscriptions_map: <source_data_dir>/outpoint_inscriptions_map, rune_entry: <source_data_dir>/rune_entry, rune_balances: <source_data_dir>/rune_balances, checksum: <source_data_dir>/checksum"
     )]
     pub source_data_dir: PathBuf,
     #[clap(
@@ -49,7 +51,7 @@ pub struct GenesisCommand {
     #[clap(
         long,
         default_value = "all",
-        help = "genesis job: inscription, utxo, all"
+        help = "genesis job: inscription, utxo, rune, all"
     )]
     pub job: Option<GenesisJob>,
 
@@ -66,10 +68,11 @@ pub struct GenesisCommand {
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Default)]
 #[serde(rename_all = "kebab-case")]
 pub enum GenesisJob {
-    Inscription = 0b01,
-    Utxo = 0b10,
+    Inscription = 0b001,
+    Utxo = 0b010,
+    Rune = 0b100,
     #[default]
-    All = 0b11,
+    All = 0b111,
 }
 
 impl GenesisJob {
@@ -83,6 +86,7 @@ impl Display for GenesisJob {
         match self {
             GenesisJob::Inscription => write!(f, "inscription"),
             GenesisJob::Utxo => write!(f, "utxo"),
+            GenesisJob::Rune => write!(f, "rune"),
             GenesisJob::All => write!(f, "all"),
         }
     }
@@ -95,6 +99,7 @@ impl FromStr for GenesisJob {
         match s.to_lowercase().as_str() {
             "inscription" => Ok(GenesisJob::Inscription),
             "utxo" => Ok(GenesisJob::Utxo),
+            "rune" => Ok(GenesisJob::Rune),
             "all" => Ok(GenesisJob::All),
             _ => Ok(GenesisJob::All),
         }
@@ -109,6 +114,8 @@ impl GenesisCommand {
         let ord_exp_stats_path = self.source_data_dir.join("ord_stats");
         let utxo_source_path = self.source_data_dir.join("utxo");
         let outpoint_inscriptions_map_path = self.source_data_dir.join("outpoint_inscriptions_map");
+        let rune_entry_path = self.source_data_dir.join("rune_entry");
+        let rune_balances_path = self.source_data_dir.join("rune_balances");
 
         let outpoint_inscriptions_map = Arc::new(OutpointInscriptionsMap::load_or_index(
             outpoint_inscriptions_map_path.clone(),
@@ -123,7 +130,31 @@ impl GenesisCommand {
         let moveos_store_arc = Arc::new(moveos_store);
         let mut handles = vec![];
 
-        let job = self.job.unwrap();
+        let job = self.job.clone().unwrap();
+        // import runes
+        if job.clone().contains(GenesisJob::Rune) {
+            let moveos_store_arc = Arc::clone(&moveos_store_arc);
+            let rune_balances_path = rune_balances_path.clone();
+            let rune_batch_size = self.utxo_batch_size.unwrap();
+            let handle = thread::spawn(move || {
+                let (rune_tx, rune_rx) = mpsc::sync_channel(2);
+                let produce_rune_updates_thread = thread::spawn(move || {
+                    produce_rune_updates(
+                        rune_tx,
+                        rune_entry_path,
+                        rune_balances_path,
+                        rune_batch_size,
+                    )
+                });
+                let apply_rune_updates_thread = thread::spawn(move || {
+                    apply_rune_updates(rune_rx, moveos_store_arc);
+                });
+                produce_rune_updates_thread.join().unwrap();
+                apply_rune_updates_thread.join().unwrap();
+            });
+            handles.push(handle);
+        }
+
         // import inscriptions
         if job.clone().contains(GenesisJob::Inscription) {
             let moveos_store_arc = Arc::clone(&moveos_store_arc);
@@ -149,7 +180,18 @@ impl GenesisCommand {
             handles.push(handle);
         }
 
-        if job.contains(GenesisJob::Utxo) {
+        if job.clone().contains(GenesisJob::Utxo) {
+            // the rune balances are sealed to the UTXOs
+            let rune_outpoints = if job.contains(GenesisJob::Rune) {
+                let rune_outpoints = RuneOutpoints::load(rune_balances_path);
+                println!(
+                    "{} outpoints with rune balances loaded",
+                    rune_outpoints.len()
+                );
+                Some(Arc::new(rune_outpoints))
+            } else {
+                None
+            };
             // import utxo
             let utxo_source_path_arc = Arc::new(utxo_source_path.clone());
             let utxo_batch_size = self.utxo_batch_size.unwrap();
@@ -172,6 +214,7 @@ impl GenesisCommand {
                         utxo_source_path_arc1,
                         utxo_batch_size,
                         Some(outpoint_inscriptions_map),
+                        rune_outpoints,
                     )
                 });
                 let apply_addr_updates_thread = thread::spawn(move || {
@@ -193,7 +236,7 @@ impl GenesisCommand {
             handle.join().unwrap();
         }
         println!(
-            "genesis inscriptions and utxo imported, cost: {:?}",
+            "genesis inscriptions, runes and utxo imported, cost: {:?}",
             start_time.elapsed()
         );
 
@@ -204,7 +247,15 @@ impl GenesisCommand {
     // For file content protected by xxh3, enough strong for file integrity check
     fn validate_checksum(&self) -> RoochResult<()> {
         let checksums = self.load_checksum();
-        let filenames = vec!["ord", "ord_stats", "utxo", "outpoint_inscriptions_map"];
+        let mut filenames = vec!["ord", "ord_stats", "utxo", "outpoint_inscriptions_map"];
+        if self
+            .job
+            .clone()
+            .unwrap_or_default()
+            .contains(GenesisJob::Rune)
+        {
+            filenames.extend(["rune_entry", "rune_balances"]);
+        }
         for filename in filenames {
             self.validate_file_checksum(filename, checksums.clone())?;
         }
@@ -0,0 +1,211 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::commands::statedb::commands::rune::{RuneBalancesSource, RuneEntrySource};
+use crate::commands::statedb::commands::{apply_fields, apply_nodes, init_rooch_db};
+use clap::Parser;
+use moveos_store::MoveOSStore;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::GENESIS_STATE_ROOT;
+use moveos_types::state::{FieldKey, ObjectState};
+use rooch_config::R_OPT_NET_HELP;
+use rooch_types::error::RoochResult;
+use rooch_types::rooch_network::RoochChainID;
+use smt::UpdateSet;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::sync::mpsc::{Receiver, SyncSender};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Instant, SystemTime};
+
+/// Import the runes etched before the genesis, the runes are active from the block 840000,
+/// but the genesis block of the mainnet is 859001.
+/// The UTXOs of the rune balances should be imported with the same rune balances source, see `genesis-utxo`.
+#[derive(Debug, Parser)]
+pub struct GenesisRuneCommand {
+    #[clap(long)]
+    /// rune entry source file, one rune entry json per line, must be sorted by the rune number
+    pub rune_entry_source: PathBuf,
+    #[clap(long)]
+    /// rune balances source file, one json of the rune balances of an unspent outpoint per line
+    pub rune_balances_source: PathBuf,
+    #[clap(
+        long,
+        default_value = "1048576",
+        help = "batch size submitted to state db. Set it smaller if memory is limited."
+    )]
+    pub rune_batch_size: Option<usize>,
+
+    #[clap(long = "data-dir", short = 'd')]
+    /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
+    pub base_data_dir: Option<PathBuf>,
+
+    /// If local chainid, start the service with a temporary data store.
+    /// All data will be deleted when the service is stopped.
+    #[clap(long, short = 'n', help = R_OPT_NET_HELP)]
+    pub chain_id: Option<RoochChainID>,
+}
+
+impl GenesisRuneCommand {
+    pub async fn execute(self) -> RoochResult<()> {
+        let rooch_db = init_rooch_db(self.base_data_dir.clone(), self.chain_id.clone());
+        let moveos_store = Arc::new(rooch_db.moveos_store);
+
+        let entry_path = self.rune_entry_source.clone();
+        let balances_path = self.rune_balances_source.clone();
+        let batch_size = self.rune_batch_size.unwrap();
+        let (rune_tx, rune_rx) = mpsc::sync_channel(2);
+        let produce_rune_updates_thread = thread::spawn(move || {
+            produce_rune_updates(rune_tx, entry_path, balances_path, batch_size)
+        });
+        let moveos_store_clone = Arc::clone(&moveos_store);
+        let apply_rune_updates_thread = thread::spawn(move || {
+            apply_rune_updates(rune_rx, moveos_store_clone);
+        });
+
+        produce_rune_updates_thread.join().unwrap();
+        apply_rune_updates_thread.join().unwrap();
+
+        Ok(())
+    }
+}
+
+pub(crate) struct RuneUpdates {
+    update_set: UpdateSet<FieldKey, ObjectState>,
+    rune_count: u64,
+    balances_count: u64,
+}
+
+pub(crate) fn produce_rune_updates(
+    tx: SyncSender<RuneUpdates>,
+    entry_path: PathBuf,
+    balances_path: PathBuf,
+    batch_size: usize,
+) {
+    // the RuneEntry number is the index of the rune in the RuneStore, so the entries must be complete and sorted
+    let mut next_number: u64 = 0;
+    let mut entry_reader =
+        BufReader::with_capacity(8 * 1024 * 1024, File::open(entry_path).unwrap());
+    loop {
+        let loop_start_time = SystemTime::now();
+        let mut updates = RuneUpdates {
+            update_set: UpdateSet::new(),
+            rune_count: 0,
+            balances_count: 0,
+        };
+        for line in entry_reader.by_ref().lines().take(batch_size) {
+            let source = RuneEntrySource::from_str(&line.unwrap());
+            assert_eq!(
+                source.number, next_number,
+                "rune entries must be sorted by number"
+            );
+            next_number += 1;
+            let ((entry_key, entry_state), (rune_key, rune_state)) = source.gen_update();
+            updates.update_set.put(entry_key, entry_state);
+            updates.update_set.put(rune_key, rune_state);
+            updates.rune_count += 1;
+        }
+        if updates.update_set.is_empty() {
+            break;
+        }
+        println!(
+            "{} rune entry updates produced, cost: {:?}",
+            updates.rune_count,
+            loop_start_time.elapsed().unwrap()
+        );
+        tx.send(updates).expect("failed to send updates");
+    }
+
+    let mut balances_reader =
+        BufReader::with_capacity(8 * 1024 * 1024, File::open(balances_path).unwrap());
+    loop {
+        let loop_start_time = SystemTime::now();
+        let mut updates = RuneUpdates {
+            update_set: UpdateSet::new(),
+            rune_count: 0,
+            balances_count: 0,
+        };
+        let mut lines_read = 0;
+        for line in balances_reader.by_ref().lines().take(batch_size) {
+            lines_read += 1;
+            let source = RuneBalancesSource::from_str(&line.unwrap());
+            // keep the same as the UTXO seals, the outpoint without balances is not sealed
+            if source.balances.is_empty() {
+                continue;
+            }
+            let (key, state) = source.gen_update();
+            updates.update_set.put(key, state);
+            updates.balances_count += 1;
+        }
+        if lines_read == 0 {
+            break;
+        }
+        if updates.update_set.is_empty() {
+            continue;
+        }
+        println!(
+            "{} rune balances updates produced, cost: {:?}",
+            updates.balances_count,
+            loop_start_time.elapsed().unwrap()
+        );
+        tx.send(updates).expect("failed to send updates");
+    }
+
+    drop(tx);
+}
+
+pub(crate) fn apply_rune_updates(rx: Receiver<RuneUpdates>, moveos_store_arc: Arc<MoveOSStore>) {
+    let mut rune_store_state_root = *GENESIS_STATE_ROOT;
+    let mut rune_store_field_count = 0u64;
+    let mut rune_count = 0u64;
+    let mut balances_count = 0u64;
+    let moveos_store = moveos_store_arc.as_ref();
+    while let Ok(batch) = rx.recv() {
+        let loop_start_time = SystemTime::now();
+
+        let gen_nodes_start = Instant::now();
+        let mut nodes: BTreeMap<H256, Vec<u8>> = BTreeMap::new();
+        let cnt = batch.update_set.len();
+        let mut tree_change_set =
+            apply_fields(moveos_store, rune_store_state_root, batch.update_set).unwrap();
+        nodes.append(&mut tree_change_set.nodes);
+        let gen_nodes_cost = gen_nodes_start.elapsed();
+
+        let last_rune_store_state_root = rune_store_state_root;
+        rune_store_state_root = tree_change_set.state_root;
+        rune_store_field_count += cnt as u64;
+        rune_count += batch.rune_count;
+        balances_count += batch.balances_count;
+
+        let apply_nodes_start = Instant::now();
+        apply_nodes(moveos_store, nodes).expect("failed to apply rune nodes");
+        let apply_nodes_cost = apply_nodes_start.elapsed();
+
+        println!(
+            "{} runes and {} rune balances applied. this batch: {}, cost: {:?}(gen_nodes: {:?}, apply_nodes: {:?})",
+            rune_count,
+            balances_count,
+            cnt,
+            loop_start_time.elapsed().unwrap(),
+            gen_nodes_cost,
+            apply_nodes_cost,
+        );
+
+        tracing::debug!(
+            "last rune_store_state_root: {:?}, new rune_store_state_root: {:?}",
+            last_rune_store_state_root,
+            rune_store_state_root,
+        );
+    }
+
+    drop(rx);
+
+    // RuneStore::genesis_with_state_root(state_root, size, RuneStore { runes }) for the genesis config
+    println!(
+        "genesis RuneStore object updated, state_root: {:?}, size: {}, runes: {}, rune balances: {}",
+        rune_store_state_root, rune_store_field_count, rune_count, balances_count
+    );
+}
@@ -24,6 +24,7 @@ use rooch_types::error::RoochResult;
 use rooch_types::rooch_network::RoochChainID;
 use smt::UpdateSet;
 
+use crate::commands::statedb::commands::rune::RuneOutpoints;
 use crate::commands::statedb::commands::utxo::UTXORawData;
 use crate::commands::statedb::commands::{
     apply_fields, apply_nodes, init_rooch_db, OutpointInscriptionsMap,
@@ -40,6 +41,9 @@ pub struct GenesisUTXOCommand {
     pub utxo_source: PathBuf,
     #[clap(long, short = 'b', default_value = "1048576")]
     pub batch_size: Option<usize>,
+    #[clap(long)]
+    /// rune balances source file, the rune balances are sealed to the UTXOs, see `genesis-rune`
+    pub rune_balances_source: Option<PathBuf>,
 
     #[clap(long = "data-dir", short = 'd')]
     /// Path to data dir, this dir is base dir, the final data_dir is base_dir/chain_network_name
@@ -63,6 +67,10 @@ impl GenesisUTXOCommand {
         let produce_addr_updates_thread = thread::spawn(move || {
             produce_address_map_updates(addr_tx, utxo_input_path_clone1, self.batch_size.unwrap())
         });
+        let rune_outpoints = self
+            .rune_balances_source
+            .clone()
+            .map(|path| Arc::new(RuneOutpoints::load(path)));
         let (utxo_tx, utxo_rx) = mpsc::sync_channel(2);
         let produce_utxo_updates_thread = thread::spawn(move || {
             produce_utxo_updates(
@@ -70,6 +78,7 @@ impl GenesisUTXOCommand {
                 utxo_input_path_clone2,
                 self.batch_size.unwrap(),
                 None,
+                rune_outpoints,
             )
         });
         let moveos_store_clone = Arc::clone(&moveos_store_arc);
@@ -144,6 +153,7 @@ pub(crate) fn produce_utxo_updates(
     input: Arc<PathBuf>,
     batch_size: usize,
     outpoint_inscriptions_map: Option<Arc<OutpointInscriptionsMap>>,
+    rune_outpoints: Option<Arc<RuneOutpoints>>,
 ) {
     let input = input.as_ref();
     // produce utxo updates is slower than produce address map updates, so we put cache manager to drop cache here
@@ -170,7 +180,8 @@ pub(crate) fn produce_utxo_updates(
             }
 
             let mut utxo_raw = UTXORawData::from_str(&line);
-            let (key, state) = utxo_raw.gen_utxo_update(outpoint_inscriptions_map.clone());
+            let (key, state) =
+                utxo_raw.gen_utxo_update(outpoint_inscriptions_map.clone(), rune_outpoints.clone());
             utxo_updates.put(key, state);
             if utxo_raw.height > max_height {
                 max_height = utxo_raw.height;
@@ -220,7 +220,7 @@ impl UTXOFilter {
             }
 
             let mut utxo_raw = UTXORawData::from_str(&line);
-            let (utxo_key, _) = utxo_raw.gen_utxo_update(outpoint_inscriptions_map.clone());
+            let (utxo_key, _) = utxo_raw.gen_utxo_update(outpoint_inscriptions_map.clone(), None);
             utxo_keys.push(xxh3_64(&utxo_key.0));
 
             let (_, exp_addr_map) = utxo_raw.gen_address_mapping_data();
@@ -441,7 +441,7 @@ fn verify_utxo(
         // check utxo
         utxo_checked_count += 1;
         let (exp_utxo_key, exp_utxo_state) =
-            utxo_raw.gen_utxo_update(outpoint_inscriptions_map.clone());
+            utxo_raw.gen_utxo_update(outpoint_inscriptions_map.clone(), None);
         let act_utxo_state = resolver
             .get_field_at(utxo_store_state_root, &exp_utxo_key)
             .unwrap();
@@ -34,11 +34,13 @@ use xxhash_rust::xxh3::xxh3_64;
 pub mod export;
 pub mod genesis;
 pub mod genesis_ord;
+pub mod genesis_rune;
 pub mod genesis_utxo;
 pub mod genesis_verify;
 pub mod re_genesis;
 
 mod inscription;
+mod rune;
 mod utxo;
 
 pub const GLOBAL_STATE_TYPE_PREFIX: &str = "states";
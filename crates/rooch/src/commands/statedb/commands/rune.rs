@@ -0,0 +1,292 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use bitcoin::{OutPoint, Txid};
+use framework_types::addresses::BITCOIN_MOVE_ADDRESS;
+use move_core_types::account_address::AccountAddress;
+use moveos_types::move_std::string::MoveString;
+use moveos_types::moveos_std::object::{DynamicField, ObjectEntity, ObjectID};
+use moveos_types::moveos_std::simple_multimap::Element;
+use moveos_types::state::{FieldKey, MoveType, ObjectState};
+use rooch_types::address::BitcoinAddress;
+use rooch_types::bitcoin::runes::{
+    derive_rune_balances_id, derive_rune_entry_id, RuneBalance, RuneBalances, RuneEntry, RuneId,
+    RuneStore, SpacedRune, Terms,
+};
+use rooch_types::bitcoin::types;
+use rooch_types::into_address::IntoAddress;
+use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use serde_with::DisplayFromStr;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+const ADDRESS_UNBOUND: &str = "unbound";
+const ADDRESS_NON_STANDARD: &str = "non-standard";
+
+lazy_static::lazy_static! {
+    static ref UTXO_SEAL_RUNE_PROTOCOL: String = {
+        RuneBalances::type_tag().to_canonical_string()
+    };
+}
+
+/// The rune entry exported from ord, with the RuneId
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RuneEntrySource {
+    pub id: RuneId,
+    #[serde_as(as = "DisplayFromStr")]
+    pub spaced_rune: SpacedRune,
+    pub divisibility: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<char>,
+    pub premine: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms: Option<TermsSource>,
+    pub mints: u128,
+    pub burned: u128,
+    pub number: u64,
+    pub etching: Txid,
+    pub turbo: bool,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct TermsSource {
+    pub amount: Option<u128>,
+    pub cap: Option<u128>,
+    pub height: (Option<u64>, Option<u64>),
+    pub offset: (Option<u64>, Option<u64>),
+}
+
+impl RuneEntrySource {
+    pub fn from_str(line: &str) -> Self {
+        serde_json::from_str(line).unwrap()
+    }
+
+    pub fn to_rune_entry(&self) -> RuneEntry {
+        let terms = self.terms.clone().map(|terms| Terms {
+            amount: terms.amount.into(),
+            cap: terms.cap.into(),
+            height_start: terms.height.0.into(),
+            height_end: terms.height.1.into(),
+            offset_start: terms.offset.0.into(),
+            offset_end: terms.offset.1.into(),
+        });
+        RuneEntry {
+            id: self.id,
+            rune: self.spaced_rune.rune.0,
+            spacers: self.spaced_rune.spacers,
+            divisibility: self.divisibility,
+            symbol: self.symbol.map(u32::from).into(),
+            premine: self.premine,
+            terms: terms.into(),
+            mints: self.mints,
+            burned: self.burned,
+            number: self.number,
+            etching: self.etching.into_address(),
+            turbo: self.turbo,
+            timestamp: self.timestamp,
+        }
+    }
+
+    /// The RuneEntry object and the rune -> RuneId field of the RuneStore
+    pub(crate) fn gen_update(&self) -> ((FieldKey, ObjectState), (FieldKey, ObjectState)) {
+        let entry = self.to_rune_entry();
+        let rune = entry.rune;
+        let entry_obj = ObjectEntity::new(
+            derive_rune_entry_id(&self.id),
+            BITCOIN_MOVE_ADDRESS,
+            0u8,
+            None,
+            0,
+            0,
+            0,
+            entry,
+        );
+        let rune_field: ObjectEntity<DynamicField<u128, RuneId>> =
+            ObjectEntity::new_dynamic_field(RuneStore::object_id(), rune, self.id);
+        let rune_state = rune_field.into_state();
+        (
+            (entry_obj.id.field_key(), entry_obj.into_state()),
+            (rune_state.id().field_key(), rune_state),
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RuneBalanceSource {
+    pub id: RuneId,
+    pub amount: u128,
+}
+
+/// The rune balances of an unspent outpoint exported from ord
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RuneBalancesSource {
+    #[serde_as(as = "DisplayFromStr")]
+    pub outpoint: OutPoint,
+    pub address: String, // <address>, "unbound", "non-standard"
+    pub balances: Vec<RuneBalanceSource>,
+}
+
+impl RuneBalancesSource {
+    pub fn from_str(line: &str) -> Self {
+        serde_json::from_str(line).unwrap()
+    }
+
+    // derive account address from the source address(unbound/non-standard/valid_address), same as the UTXO owner
+    pub fn derive_account_address(&self) -> AccountAddress {
+        if self.address.is_empty()
+            || self.address == ADDRESS_UNBOUND
+            || self.address == ADDRESS_NON_STANDARD
+        {
+            return BITCOIN_MOVE_ADDRESS;
+        }
+        let bitcoin_address = BitcoinAddress::from_str(self.address.as_str()).unwrap();
+        AccountAddress::from(bitcoin_address.to_rooch_address())
+    }
+
+    pub fn to_rune_balances(&self) -> RuneBalances {
+        let mut balances = self
+            .balances
+            .iter()
+            .map(|balance| RuneBalance {
+                id: balance.id,
+                amount: balance.amount,
+            })
+            .collect::<Vec<_>>();
+        // keep the same order as the balances created by the runes module
+        balances.sort_by_key(|balance| balance.id);
+        RuneBalances {
+            outpoint: self.outpoint.into(),
+            balances,
+        }
+    }
+
+    pub(crate) fn gen_update(&self) -> (FieldKey, ObjectState) {
+        let rune_balances = self.to_rune_balances();
+        let balances_obj = ObjectEntity::new(
+            rune_balances.object_id(),
+            self.derive_account_address(),
+            0u8,
+            None,
+            0,
+            0,
+            0,
+            rune_balances,
+        );
+        (balances_obj.id.field_key(), balances_obj.into_state())
+    }
+}
+
+/// The outpoints with rune balances, the rune balances are sealed to the UTXOs of the outpoints
+pub(crate) struct RuneOutpoints {
+    outpoints: FxHashSet<OutPoint>,
+}
+
+impl RuneOutpoints {
+    pub(crate) fn load(rune_balances_path: PathBuf) -> Self {
+        let reader = BufReader::new(File::open(rune_balances_path).unwrap());
+        let mut outpoints = FxHashSet::default();
+        for line in reader.lines() {
+            let source = RuneBalancesSource::from_str(&line.unwrap());
+            if !source.balances.is_empty() {
+                outpoints.insert(source.outpoint);
+            }
+        }
+        RuneOutpoints { outpoints }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.outpoints.len()
+    }
+
+    pub(crate) fn derive_utxo_rune_seal(
+        &self,
+        outpoint: &OutPoint,
+    ) -> Option<Element<MoveString, ObjectID>> {
+        if !self.outpoints.contains(outpoint) {
+            return None;
+        }
+        let outpoint: types::OutPoint = (*outpoint).into();
+        Some(Element {
+            key: MoveString::from_str(&UTXO_SEAL_RUNE_PROTOCOL).unwrap(),
+            value: vec![derive_rune_balances_id(&outpoint)],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rune_entry_source() {
+        let line = r#"{"id":"840000:3","spaced_rune":"UNCOMMON•GOODS","divisibility":0,"symbol":"⧉","premine":0,"terms":{"amount":1,"cap":340282366920938463463374607431768211455,"height":[840000,1050000],"offset":[null,null]},"mints":100,"burned":5,"number":0,"etching":"0000000000000000000000000000000000000000000000000000000000000000","turbo":true,"timestamp":1713571767}"#;
+        let source = RuneEntrySource::from_str(line);
+        let entry = source.to_rune_entry();
+        assert_eq!(entry.id, RuneId::new(840000, 3));
+        assert_eq!(entry.spaced_rune().to_string(), "UNCOMMON•GOODS");
+        assert_eq!(entry.symbol(), Some('⧉'));
+        let terms = entry.terms.as_ref().unwrap();
+        assert_eq!(terms.cap.as_ref(), Some(&u128::MAX));
+        assert_eq!(terms.height_end.as_ref(), Some(&1050000));
+        assert_eq!(terms.offset_start.as_ref(), None);
+
+        let ((entry_key, entry_state), (rune_key, rune_state)) = source.gen_update();
+        assert_eq!(entry_key, derive_rune_entry_id(&entry.id).field_key());
+        assert_eq!(entry_state.value_as::<RuneEntry>().unwrap(), entry);
+        assert_eq!(
+            rune_state.value_as_df::<u128, RuneId>().unwrap().value,
+            entry.id
+        );
+        assert_ne!(entry_key, rune_key);
+    }
+
+    #[test]
+    fn test_rune_balances_source() {
+        let outpoint = OutPoint::new(
+            Txid::from_str("5bd7e4ea4ac9e86bb8e4c4ed9cd5d3f5a5b1cd5fa2bf1d3a8bd6ee7d7d16b82d")
+                .unwrap(),
+            1,
+        );
+        let line = format!(
+            r#"{{"outpoint":"{}","address":"unbound","balances":[{{"id":"840001:1","amount":7}},{{"id":"840000:3","amount":340282366920938463463374607431768211455}}]}}"#,
+            outpoint
+        );
+        let source = RuneBalancesSource::from_str(&line);
+        assert_eq!(source.derive_account_address(), BITCOIN_MOVE_ADDRESS);
+        let rune_balances = source.to_rune_balances();
+        // sorted by the RuneId
+        assert_eq!(
+            rune_balances.balances,
+            vec![
+                RuneBalance {
+                    id: RuneId::new(840000, 3),
+                    amount: u128::MAX
+                },
+                RuneBalance {
+                    id: RuneId::new(840001, 1),
+                    amount: 7
+                },
+            ]
+        );
+        let (key, state) = source.gen_update();
+        let rooch_outpoint: types::OutPoint = outpoint.into();
+        assert_eq!(key, derive_rune_balances_id(&rooch_outpoint).field_key());
+        assert_eq!(state.metadata.owner, BITCOIN_MOVE_ADDRESS);
+
+        let rune_outpoints = RuneOutpoints {
+            outpoints: vec![outpoint].into_iter().collect(),
+        };
+        let seal = rune_outpoints.derive_utxo_rune_seal(&outpoint).unwrap();
+        assert_eq!(seal.value, vec![derive_rune_balances_id(&rooch_outpoint)]);
+        assert!(rune_outpoints
+            .derive_utxo_rune_seal(&OutPoint::new(outpoint.txid, 0))
+            .is_none());
+    }
+}
@@ -18,6 +18,7 @@ use rooch_types::bitcoin::{types, utxo};
 use rooch_types::framework::address_mapping::RoochToBitcoinAddressMapping;
 use rooch_types::into_address::IntoAddress;
 
+use crate::commands::statedb::commands::rune::RuneOutpoints;
 use crate::commands::statedb::commands::{derive_utxo_inscription_seal, OutpointInscriptionsMap};
 
 const SCRIPT_TYPE_P2MS: &str = "p2ms";
@@ -73,10 +74,11 @@ impl UTXORawData {
     pub fn gen_utxo_update(
         &mut self,
         outpoint_inscriptions_map: Option<Arc<OutpointInscriptionsMap>>,
+        rune_outpoints: Option<Arc<RuneOutpoints>>,
     ) -> (FieldKey, ObjectState) {
         let (address, _address_mapping_data) = self.gen_address_mapping_data();
 
-        let seals = match outpoint_inscriptions_map {
+        let mut seals = match outpoint_inscriptions_map {
             Some(outpoint_inscriptions_map) => {
                 let inscriptions =
                     outpoint_inscriptions_map.search(&OutPoint::new(self.txid, self.vout));
@@ -84,6 +86,13 @@ impl UTXORawData {
             }
             None => SimpleMultiMap::create(),
         };
+        if let Some(rune_outpoints) = rune_outpoints {
+            if let Some(rune_seal) =
+                rune_outpoints.derive_utxo_rune_seal(&OutPoint::new(self.txid, self.vout))
+            {
+                seals.data.push(rune_seal);
+            }
+        }
 
         let txid = self.txid.into_address();
         let outpoint = types::OutPoint::new(txid, self.vout);
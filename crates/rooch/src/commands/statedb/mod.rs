@@ -10,6 +10,7 @@ use rooch_types::error::RoochResult;
 use crate::cli_types::CommandAction;
 use crate::commands::statedb::commands::genesis::GenesisCommand;
 use crate::commands::statedb::commands::genesis_ord::GenesisOrdCommand;
+use crate::commands::statedb::commands::genesis_rune::GenesisRuneCommand;
 use crate::commands::statedb::commands::genesis_utxo::GenesisUTXOCommand;
 use crate::commands::statedb::commands::genesis_verify::GenesisVerifyCommand;
 use crate::commands::statedb::commands::re_genesis::ReGenesisCommand;
@@ -36,6 +37,9 @@ impl CommandAction<String> for Statedb {
             StatedbCommand::GenesisOrd(genesis_ord) => genesis_ord.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
+            StatedbCommand::GenesisRune(genesis_rune) => genesis_rune.execute().await.map(|resp| {
+                serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
+            }),
             StatedbCommand::Genesis(genesis) => genesis.execute().await.map(|resp| {
                 serde_json::to_string_pretty(&resp).expect("Failed to serialize response")
             }),
@@ -58,6 +62,7 @@ pub enum StatedbCommand {
     Genesis(GenesisCommand),
     GenesisUTXO(GenesisUTXOCommand),
     GenesisOrd(GenesisOrdCommand),
+    GenesisRune(GenesisRuneCommand),
     GenesisVerify(GenesisVerifyCommand),
     ReGenesis(ReGenesisCommand),
 }
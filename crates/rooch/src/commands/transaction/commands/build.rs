@@ -8,6 +8,7 @@ use move_command_line_common::types::ParsedStructType;
 use move_core_types::language_storage::TypeTag;
 use moveos_types::transaction::MoveAction;
 use rooch_types::{
+    address::ParsedAddress,
     error::RoochResult,
     function_arg::{parse_function_arg, FunctionArg, ParsedFunctionId},
 };
@@ -40,6 +41,21 @@ pub struct BuildCommand {
     #[clap(long = "args", value_parser=parse_function_arg)]
     pub args: Vec<FunctionArg>,
 
+    /// The transaction is expired from this timestamp, unit: millisecond.
+    /// If specified, the expiration timestamp is added to the transaction data extension.
+    #[clap(long)]
+    pub expiration_timestamp: Option<u64>,
+
+    /// The gas price per gas unit the transaction bids, it must not be lower than the base fee.
+    /// If specified, the gas price is added to the transaction data extension.
+    #[clap(long)]
+    pub gas_price: Option<u64>,
+
+    /// The sponsor who pays the gas, the sponsor address is added to the transaction data extension.
+    /// The sponsor must co-sign the transaction with `rooch tx sign --as-sponsor`.
+    #[clap(long, value_parser=ParsedAddress::parse)]
+    pub sponsor: Option<ParsedAddress>,
+
     #[clap(flatten)]
     tx_options: TransactionOptions,
 
@@ -82,9 +98,18 @@ impl CommandAction<Option<FileOutput>> for BuildCommand {
             .collect::<Result<Vec<_>>>()?;
         let action = MoveAction::new_function_call(function_id, type_args, args);
 
-        let tx_data = context
+        let mut tx_data = context
             .build_tx_data_with_sequence_number(sender, action, max_gas_amount, sequenc_number)
             .await?;
+        if let Some(expiration_timestamp) = self.expiration_timestamp {
+            tx_data = tx_data.with_expiration_timestamp(expiration_timestamp);
+        }
+        if let Some(gas_price) = self.gas_price {
+            tx_data = tx_data.with_gas_price(gas_price);
+        }
+        if let Some(sponsor) = self.sponsor {
+            tx_data = tx_data.with_sponsor(context.resolve_rooch_address(sponsor)?);
+        }
 
         let output =
             FileOutput::write_to_file(FileOutputData::RoochTransactionData(tx_data), self.output)?;
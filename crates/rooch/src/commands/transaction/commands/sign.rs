@@ -21,6 +21,8 @@ use rooch_types::{
 pub enum SignInput {
     RoochTransactionData(RoochTransactionData),
     PartiallySignedRoochTransaction(PartiallySignedRoochTransaction),
+    /// The transaction signed by the sender, to be co-signed by the sponsor
+    RoochTransaction(RoochTransaction),
 }
 
 impl TryFrom<FileOrHexInput> for SignInput {
@@ -44,10 +46,18 @@ impl TryFrom<FileOrHexInput> for SignInput {
 }
 
 impl SignInput {
+    /// Parse the input as a transaction signed by the sender
+    pub fn try_from_signed_transaction(value: FileOrHexInput) -> anyhow::Result<Self> {
+        let tx = bcs::from_bytes::<RoochTransaction>(&value.data)
+            .map_err(|_| anyhow::anyhow!("Invalid signed transaction data"))?;
+        Ok(SignInput::RoochTransaction(tx))
+    }
+
     pub fn sender(&self) -> RoochAddress {
         match self {
             SignInput::RoochTransactionData(tx_data) => tx_data.sender,
             SignInput::PartiallySignedRoochTransaction(psrt) => psrt.sender(),
+            SignInput::RoochTransaction(tx) => tx.sender(),
         }
     }
 }
@@ -83,9 +93,15 @@ pub struct SignCommand {
 
     /// The address of the signer when the transaction is a multisign account transaction
     /// If not specified, we will auto find the existing participants in the multisign account from the keystore
+    /// When signing as a sponsor, the default address is used if not specified
     #[clap(short = 's', long, value_parser=ParsedAddress::parse)]
     signer: Option<ParsedAddress>,
 
+    /// Co-sign a transaction signed by the sender as the sponsor who pays the gas
+    /// The input must be a signed transaction
+    #[clap(long)]
+    as_sponsor: bool,
+
     /// The output file path for the signed transaction
     /// If not specified, the signed output will write to temp directory.
     #[clap(long, short = 'o')]
@@ -104,11 +120,41 @@ pub struct SignCommand {
 }
 
 impl SignCommand {
+    fn sign_input(&self) -> anyhow::Result<SignInput> {
+        if self.as_sponsor {
+            SignInput::try_from_signed_transaction(self.input.clone())
+        } else {
+            SignInput::try_from(self.input.clone())
+        }
+    }
+
     async fn sign(self) -> anyhow::Result<SignOutput> {
         let context = self.context.build_require_password()?;
+        let sign_input = self.sign_input()?;
+        let sign_input = match sign_input {
+            SignInput::RoochTransaction(tx) => {
+                if tx.sponsor_authenticator().is_some() {
+                    return Err(anyhow::anyhow!(
+                        "The transaction has already been signed by a sponsor"
+                    ));
+                }
+                let sponsor = context.resolve_rooch_address(self.signer.unwrap_or_default())?;
+                if tx.data.sponsor != Some(sponsor) {
+                    return Err(anyhow::anyhow!(
+                        "The transaction data does not specify {} as the sponsor, build it with `--sponsor`",
+                        sponsor
+                    ));
+                }
+                let kp = context.get_key_pair(&sponsor)?;
+                let sponsor_authenticator = BitcoinAuthenticator::sign_sponsor(&kp, &tx.data);
+                return Ok(SignOutput::SignedRoochTransaction(
+                    tx.with_sponsor_authenticator(sponsor_authenticator.into()),
+                ));
+            }
+            sign_input => sign_input,
+        };
         let client = context.get_client().await?;
         let multisign_account_module = client.as_module_binding::<MultisignAccountModule>();
-        let sign_input = SignInput::try_from(self.input)?;
         let sender = sign_input.sender();
         let output = if multisign_account_module.is_multisign_account(sender.into())? {
             let threshold = multisign_account_module.threshold(sender.into())?;
@@ -118,6 +164,7 @@ impl SignCommand {
                     PartiallySignedRoochTransaction::new(tx_data, threshold)
                 }
                 SignInput::PartiallySignedRoochTransaction(psrt) => psrt,
+                SignInput::RoochTransaction(_) => unreachable!(),
             };
             match self.signer {
                 Some(signer) => {
@@ -174,6 +221,7 @@ impl SignCommand {
                         "Cannot sign a partially signed transaction with a single signer"
                     ))
                 }
+                SignInput::RoochTransaction(_) => unreachable!(),
             };
             SignOutput::SignedRoochTransaction(context.sign_transaction(sender, tx_data)?)
         };
@@ -208,6 +256,9 @@ impl SignCommand {
                     pstx.threshold
                 );
             }
+            SignInput::RoochTransaction(tx) => {
+                println!("Transaction signed by the sender:\n{}", tx_data(&tx.data));
+            }
         }
     }
 }
@@ -215,7 +266,7 @@ impl SignCommand {
 #[async_trait]
 impl CommandAction<Option<FileOutput>> for SignCommand {
     async fn execute(self) -> RoochResult<Option<FileOutput>> {
-        let sign_input = SignInput::try_from(self.input.clone())?;
+        let sign_input = self.sign_input()?;
         SignCommand::print_tx_details(&sign_input);
         if !self.answer_yes && !prompt_yes_no("Do you want to sign this transaction?") {
             return Ok(None);
@@ -271,6 +271,7 @@ fn convert_to_verified_tx(
             module_bundle,
             init_function_modules: vec![],
         },
+        MoveAction::MultiCall(call) => VerifiedMoveAction::MultiCall { call },
     };
 
     Ok(VerifiedMoveOSTransaction::new(
@@ -0,0 +1,22 @@
+Feature: Rooch follower node integration tests
+
+    @serial
+    Scenario: follower
+      Given a server for follower
+      Given a follower server for follower
+
+      Then cmd: "account create"
+      Then cmd: "move run --function rooch_framework::gas_coin::faucet_entry --args u256:10000000000 --json"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then cmd: "move publish -p ../../examples/event  --named-addresses rooch_examples=default --json"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then cmd: "move run --function default::event_test::emit_event  --args 10u64 --json"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+      Then cmd: "move run --function default::event_test::emit_event  --args 11u64 --json"
+      Then assert: "{{$.move[-1].execution_info.status.type}} == executed"
+
+      # The follower applies the store changes of the leader without re-executing the transactions
+      Then the follower server is synced with the leader
+
+      Then stop the follower server
+      Then stop the server
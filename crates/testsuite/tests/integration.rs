@@ -13,6 +13,7 @@ use jpst::TemplateContext;
 use rooch::RoochCli;
 use rooch_config::{RoochOpt, ServerOpt, ROOCH_CONFIR_DIR};
 use rooch_rpc_client::wallet_context::WalletContext;
+use rooch_rpc_client::ClientBuilder;
 use rooch_rpc_server::Service;
 use rooch_types::crypto::RoochKeyPair;
 use serde_json::Value;
@@ -37,6 +38,8 @@ struct World {
     docker: Cli,
     container_network: String,
     service: Option<Service>,
+    sequencer_kp: Option<RoochKeyPair>,
+    follower: Option<(RoochOpt, Service)>,
     bitcoind: Option<Container<BitcoinD>>,
     ord: Option<Container<Ord>>,
     tpl_ctx: Option<TemplateContext>,
@@ -51,6 +54,8 @@ impl Default for World {
             docker: Cli::default(),
             container_network: format!("test_network_{}", network_uuid),
             service: None,
+            sequencer_kp: None,
+            follower: None,
             bitcoind: None,
             ord: None,
             tpl_ctx: None,
@@ -94,6 +99,113 @@ async fn start_server(w: &mut World, _scenario: String) {
     service.start(w.opt.clone(), server_opt).await.unwrap();
 
     w.service = Some(service);
+    w.sequencer_kp = Some(kp);
+}
+
+#[given(expr = "a follower server for {word}")] // Cucumber Expression
+async fn start_follower_server(w: &mut World, _scenario: String) {
+    // The follower must be built from the same genesis as the leader
+    let kp = w
+        .sequencer_kp
+        .as_ref()
+        .expect("The leader server should be started before the follower server")
+        .copy();
+    let mut follower_opt = RoochOpt::new_with_temp_store().expect("new rooch opt should be ok");
+    follower_opt.port = Some(w.opt.port() + 1);
+    follower_opt.follower.leader_url = Some(format!("http://127.0.0.1:{}", w.opt.port()));
+    follower_opt.follower.interval = Some(1u64);
+    wait_port_available(follower_opt.port()).await;
+
+    let mut server_opt = ServerOpt::new();
+    server_opt.sequencer_keypair = Some(kp.copy());
+    server_opt.proposer_keypair = Some(kp);
+
+    let mut service = Service::new();
+    service
+        .start(follower_opt.clone(), server_opt)
+        .await
+        .unwrap();
+
+    w.follower = Some((follower_opt, service));
+}
+
+#[then(expr = "stop the follower server")] // Cucumber Expression
+async fn stop_follower_server(w: &mut World) {
+    match w.follower.take() {
+        Some((_, service)) => {
+            service.stop().unwrap();
+            info!("Shutdown follower server");
+        }
+        None => {
+            info!("follower service is none");
+        }
+    }
+}
+
+#[then(expr = "the follower server is synced with the leader")] // Cucumber Expression
+async fn assert_follower_synced(w: &mut World) {
+    let (follower_opt, _) = w
+        .follower
+        .as_ref()
+        .expect("The follower server should be started");
+    let leader = ClientBuilder::default()
+        .build(format!("http://127.0.0.1:{}", w.opt.port()))
+        .await
+        .unwrap();
+    let follower = ClientBuilder::default()
+        .build(format!("http://127.0.0.1:{}", follower_opt.port()))
+        .await
+        .unwrap();
+
+    let leader_status = leader.rooch.status().await.unwrap();
+    let last_order = leader_status.rooch_status.sequencer_info.last_order.0;
+    let mut count = 0;
+    loop {
+        let follower_status = follower.rooch.status().await.unwrap();
+        if follower_status.rooch_status.sequencer_info.last_order.0 >= last_order {
+            assert_eq!(
+                follower_status.rooch_status.genesis_hash.0,
+                leader_status.rooch_status.genesis_hash.0
+            );
+            break;
+        }
+        count += 1;
+        if count > 60 {
+            panic!(
+                "The follower is not synced to the tx_order {} after 60 seconds",
+                last_order
+            );
+        }
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+
+    // The follower serves the same committed transactions as the leader,
+    // the state roots and the events of every transaction must be the same.
+    let leader_txs = leader
+        .rooch
+        .get_committed_transactions(None, Some(last_order + 1))
+        .await
+        .unwrap();
+    let follower_txs = follower
+        .rooch
+        .get_committed_transactions(None, Some(last_order + 1))
+        .await
+        .unwrap();
+    assert_eq!(leader_txs.len() as u64, last_order + 1);
+    assert_eq!(follower_txs.len(), leader_txs.len());
+    for (leader_tx, follower_tx) in leader_txs.iter().zip(follower_txs.iter()) {
+        assert_eq!(
+            follower_tx.transaction.sequence_info,
+            leader_tx.transaction.sequence_info
+        );
+        assert_eq!(follower_tx.execution_info, leader_tx.execution_info);
+        assert_eq!(follower_tx.events, leader_tx.events);
+        assert_eq!(
+            follower_tx.state_change_set.state_change_set,
+            leader_tx.state_change_set.state_change_set
+        );
+    }
+    info!("follower is synced to tx_order {}", last_order);
 }
 
 #[then(expr = "stop the server")] // Cucumber Expression
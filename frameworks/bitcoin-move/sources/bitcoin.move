@@ -20,10 +20,11 @@ module bitcoin_move::bitcoin{
     
     use bitcoin_move::network;
     use bitcoin_move::types::{Self, Block, Header, Transaction, BlockHeightHash, OutPoint};
-    use bitcoin_move::utxo::{Self, UTXOSeal};
+    use bitcoin_move::utxo::{Self, UTXO, UTXOSeal, SealOut};
     use bitcoin_move::pending_block::{Self, PendingBlock};
     use bitcoin_move::script_buf;
     use bitcoin_move::bbn;
+    use bitcoin_move::runes;
 
     friend bitcoin_move::genesis;
 
@@ -110,7 +111,7 @@ module bitcoin_move::bitcoin{
     fun process_tx(btc_block_store: &mut BitcoinBlockStore, pblock: &mut Object<PendingBlock>, tx: &Transaction, is_coinbase: bool){
         let block_height = pending_block::block_height(pblock);
         let txid = types::tx_id(tx);
-        let repeat_txid = process_utxo(block_height, pblock, tx, is_coinbase);
+        let repeat_txid = process_utxo(btc_block_store, block_height, pblock, tx, is_coinbase);
         
         if (repeat_txid) {
             table::upsert(&mut btc_block_store.txs, txid, *tx);
@@ -127,7 +128,7 @@ module bitcoin_move::bitcoin{
         };
     }
 
-    fun process_utxo(block_height: u64, pending_block: &mut Object<PendingBlock>, tx: &Transaction, is_coinbase: bool) : bool{
+    fun process_utxo(btc_block_store: &BitcoinBlockStore, block_height: u64, pending_block: &mut Object<PendingBlock>, tx: &Transaction, is_coinbase: bool) : bool{
         let txinput = types::tx_input(tx);
         let input_utxos = vector::empty();
 
@@ -172,16 +173,11 @@ module bitcoin_move::bitcoin{
         let skip_ordinals = block_height >= ORDINALS_PAUSE_HEIGHT && network::is_mainnet() && rooch_framework::chain_id::is_main();
         if(!skip_ordinals){
             let seal_outs = bitcoin_move::inscription_updater::process_tx(pending_block, tx, &mut input_utxos);
-            let seal_outs_len = vector::length(&seal_outs);
-            if (seal_outs_len > 0) {
-                let seal_out_idx = 0;
-                while (seal_out_idx < seal_outs_len) {
-                    let seal_out = vector::pop_back(&mut seal_outs);
-                    let (output_index, utxo_seal) = utxo::unpack_seal_out(seal_out);
-                    simple_multimap::add(&mut output_seals, output_index, utxo_seal);
-                    seal_out_idx = seal_out_idx + 1;
-                };
-            };
+            add_seal_outs(&mut output_seals, seal_outs);
+        };
+        if(runes::is_active(block_height)){
+            let seal_outs = process_runes(btc_block_store, block_height, pending_block, tx, &mut input_utxos);
+            add_seal_outs(&mut output_seals, seal_outs);
         };
     
         // create new utxo
@@ -198,6 +194,60 @@ module bitcoin_move::bitcoin{
         repeat_txid
     }
 
+    fun add_seal_outs(output_seals: &mut SimpleMultiMap<u32, UTXOSeal>, seal_outs: vector<SealOut>){
+        let seal_outs_len = vector::length(&seal_outs);
+        let seal_out_idx = 0;
+        while (seal_out_idx < seal_outs_len) {
+            let seal_out = vector::pop_back(&mut seal_outs);
+            let (output_index, utxo_seal) = utxo::unpack_seal_out(seal_out);
+            simple_multimap::add(output_seals, output_index, utxo_seal);
+            seal_out_idx = seal_out_idx + 1;
+        };
+    }
+
+    fun process_runes(btc_block_store: &BitcoinBlockStore, block_height: u64, pending_block: &Object<PendingBlock>, tx: &Transaction, input_utxos: &mut vector<UTXO>): vector<SealOut>{
+        let runestone = runes::decipher_runestone(tx);
+        //The tx index and the commitment inputs are only needed when the runestone etches a rune
+        let tx_index = if (runes::need_tx_index(&runestone)) {
+            pending_block::tx_index(pending_block, types::tx_id(tx))
+        } else {
+            0
+        };
+        let commitment_inputs = if (runes::need_commitment(&runestone)) {
+            rune_commitment_inputs(btc_block_store, block_height, tx)
+        } else {
+            vector::empty()
+        };
+        runes::process_tx(block_height, tx_index, tx, input_utxos, runestone, commitment_inputs)
+    }
+
+    /// Check which inputs can carry the rune commitment,
+    /// the previous output should be a taproot output and the commit tx should have enough confirmations
+    fun rune_commitment_inputs(btc_block_store: &BitcoinBlockStore, block_height: u64, tx: &Transaction): vector<bool>{
+        let commitment_inputs = vector::empty();
+        let txinput = types::tx_input(tx);
+        let idx = 0;
+        let input_len = vector::length(txinput);
+        while (idx < input_len) {
+            let outpoint = types::txin_previous_output(vector::borrow(txinput, idx));
+            let prev_txid = types::outpoint_txid(outpoint);
+            let prev_vout = (types::outpoint_vout(outpoint) as u64);
+            let committed = if (table::contains(&btc_block_store.txs, prev_txid)) {
+                let prev_tx = table::borrow(&btc_block_store.txs, prev_txid);
+                let prev_height = *table::borrow(&btc_block_store.tx_to_height, prev_txid);
+                let prev_output = types::tx_output(prev_tx);
+                prev_vout < vector::length(prev_output)
+                    && script_buf::is_p2tr(types::txout_script_pubkey(vector::borrow(prev_output, prev_vout)))
+                    && runes::is_commitment_confirmed(block_height, prev_height)
+            } else {
+                false
+            };
+            vector::push_back(&mut commitment_inputs, committed);
+            idx = idx + 1;
+        };
+        commitment_inputs
+    }
+
     fun handle_new_utxo(tx: &Transaction, is_coinbase: bool, output_seals: &mut SimpleMultiMap<u32, UTXOSeal>, block_height: u64, sender: Option<address>) :bool {
         let txid = types::tx_id(tx);
         let txoutput = types::tx_output(tx);
@@ -9,11 +9,13 @@ module bitcoin_move::genesis{
     use rooch_framework::bitcoin_address::{Self, BitcoinAddress};
     use bitcoin_move::bitcoin;
     use bitcoin_move::ord;
+    use bitcoin_move::runes;
     use bitcoin_move::utxo;
     use bitcoin_move::network;
     use bitcoin_move::pending_block;
     use bitcoin_move::bitcoin_multisign_validator;
     use bitcoin_move::multisign_account;
+    use rooch_framework::webauthn_validator;
 
     const ErrorGenesisInit: u64 = 1;
     const ErrorInvalidRoochDaoAddress: u64 = 2;
@@ -41,10 +43,12 @@ module bitcoin_move::genesis{
         network::genesis_init(genesis_context.network);
         utxo::genesis_init();
         ord::genesis_init();
+        runes::genesis_init();
         bbn::genesis_init();
         bitcoin::genesis_init(&genesis_account, genesis_context.genesis_block_height, genesis_context.genesis_block_hash);
         pending_block::genesis_init(genesis_context.reorg_block_count);
         bitcoin_multisign_validator::genesis_init();
+        webauthn_validator::init_for_upgrade();
         let rooch_dao_address = multisign_account::initialize_multisig_account(genesis_context.rooch_dao.threshold, genesis_context.rooch_dao.participant_public_keys);
         assert!(rooch_dao_address == bitcoin_address::to_rooch_address(&genesis_context.rooch_dao.multisign_bitcoin_address), ErrorInvalidRoochDaoAddress);
     }
@@ -13,6 +13,8 @@ module bitcoin_move::multisign_account{
     use moveos_std::sort;
     use moveos_std::simple_map::{Self, SimpleMap};
     use moveos_std::result;
+    use moveos_std::event;
+    use moveos_std::timestamp;
     
     use rooch_framework::ecdsa_k1;
     use rooch_framework::bitcoin_address::{Self, BitcoinAddress};
@@ -39,6 +41,10 @@ module bitcoin_move::multisign_account{
     const ErrorProposalAlreadySigned: u64 = 8;
     const ErrorInvalidProposalStatus: u64 = 9;
     const ErrorInvalidSignature: u64 = 10; 
+    const ErrorProposalNotFound: u64 = 11;
+    const ErrorParticipantNotFound: u64 = 12;
+    const ErrorProposalOutdated: u64 = 13;
+    const ErrorMultisignAddressConflict: u64 = 14;
 
     struct MultisignAccountInfo has key, store {
         /// The multisign account rooch address
@@ -60,6 +66,62 @@ module bitcoin_move::multisign_account{
         public_key: vector<u8>,
     }
 
+    /// A proposal to change the participants and the threshold of a multisign account.
+    /// The proposal is executed when the approvals reach the current threshold.
+    struct ParticipantsProposal has store, copy, drop {
+        proposal_id: u64,
+        proposer: address,
+        /// The multisign BitcoinAddress when the proposal is created,
+        /// the proposal is outdated if the participants are changed by another proposal.
+        base_bitcoin_address: BitcoinAddress,
+        /// The public keys of the participants after the proposal is executed
+        participant_public_keys: vector<vector<u8>>,
+        /// The threshold after the proposal is executed
+        threshold: u64,
+        /// The BitcoinAddress derived from the new participants and threshold
+        new_bitcoin_address: BitcoinAddress,
+        approvals: vector<address>,
+        rejections: vector<address>,
+        status: u8,
+        created_at: u64,
+    }
+
+    /// The pending proposals of a multisign account, the executed and rejected proposals are pruned,
+    /// their results are emitted in the `ParticipantsProposalEvent`.
+    struct ParticipantsProposals has key {
+        next_proposal_id: u64,
+        proposals: SimpleMap<u64, ParticipantsProposal>,
+    }
+
+    /// The bookkeeping of a participants change.
+    /// The Rooch account and its assets are kept, but the Bitcoin UTXOs of the previous multisign
+    /// BitcoinAddress are still locked by the previous participants, they should be transferred to the new address on Bitcoin.
+    struct MigrationRecord has store, copy, drop {
+        proposal_id: u64,
+        from_bitcoin_address: BitcoinAddress,
+        from_threshold: u64,
+        from_participant_public_keys: vector<vector<u8>>,
+        to_bitcoin_address: BitcoinAddress,
+        migrated_at: u64,
+    }
+
+    struct MigrationRecords has key {
+        records: vector<MigrationRecord>,
+    }
+
+    struct ParticipantsProposalEvent has copy, drop {
+        multisign_address: address,
+        proposal_id: u64,
+        status: u8,
+    }
+
+    struct MultisignAccountMigratedEvent has copy, drop {
+        multisign_address: address,
+        proposal_id: u64,
+        from_bitcoin_address: BitcoinAddress,
+        to_bitcoin_address: BitcoinAddress,
+    }
+
     /// Initialize a taproot multisign account
     /// If the multisign account already exists, we will init the MultisignAccountInfo into the account
     public entry fun initialize_multisig_account_entry(
@@ -76,6 +138,8 @@ module bitcoin_move::multisign_account{
         assert!(vector::length(&participant_public_keys) >= threshold, ErrorInvalidThreshold);
         check_public_keys(&participant_public_keys);
         let multisign_bitcoin_address = generate_multisign_address(threshold, participant_public_keys);
+        // The BitcoinAddress is taken by the multisign account migrated to it
+        assert!(!address_mapping::is_rebound_bitcoin_address(&multisign_bitcoin_address), ErrorMultisignAddressConflict);
         let multisign_address = bitcoin_address::to_rooch_address(&multisign_bitcoin_address);
         let participants = simple_map::new();
         let idx = 0;
@@ -235,6 +299,268 @@ module bitcoin_move::multisign_account{
         participant.participant_address
     }
 
+    // ======== Participants Proposal functions ========
+
+    /// Propose to add or remove participants and change the threshold of the multisign account
+    public entry fun propose_participants_update_entry(
+        sender: &signer,
+        multisign_address: address,
+        add_public_keys: vector<vector<u8>>,
+        remove_public_keys: vector<vector<u8>>,
+        threshold: u64,
+    ){
+        propose_participants_update(sender, multisign_address, add_public_keys, remove_public_keys, threshold);
+    }
+
+    public fun propose_participants_update(
+        sender: &signer,
+        multisign_address: address,
+        add_public_keys: vector<vector<u8>>,
+        remove_public_keys: vector<vector<u8>>,
+        threshold: u64,
+    ): u64 {
+        let sender_addr = signer::address_of(sender);
+        assert!(is_multisign_account(multisign_address), ErrorMultisignAccountNotFound);
+        assert!(is_participant(multisign_address, sender_addr), ErrorInvalidParticipant);
+        check_public_keys(&add_public_keys);
+
+        let participant_public_keys = vector::empty();
+        let current_participants = participants(multisign_address);
+        let idx = 0;
+        let len = vector::length(&current_participants);
+        while(idx < len){
+            let public_key = vector::borrow(&current_participants, idx).public_key;
+            if(!vector::contains(&remove_public_keys, &public_key)){
+                vector::push_back(&mut participant_public_keys, public_key);
+            };
+            idx = idx + 1;
+        };
+        assert!(
+            vector::length(&participant_public_keys) + vector::length(&remove_public_keys) == len,
+            ErrorParticipantNotFound
+        );
+        let idx = 0;
+        let len = vector::length(&add_public_keys);
+        while(idx < len){
+            let public_key = *vector::borrow(&add_public_keys, idx);
+            assert!(!vector::contains(&participant_public_keys, &public_key), ErrorParticipantAlreadyJoined);
+            vector::push_back(&mut participant_public_keys, public_key);
+            idx = idx + 1;
+        };
+        assert!(threshold > 0 && vector::length(&participant_public_keys) >= threshold, ErrorInvalidThreshold);
+
+        let new_bitcoin_address = generate_multisign_address(threshold, participant_public_keys);
+        check_migration_target(multisign_address, &new_bitcoin_address);
+        let account = borrow_mut_account(multisign_address);
+        if(!account::account_exists_resource<ParticipantsProposals>(account)){
+            account::account_move_resource_to(account, ParticipantsProposals{
+                next_proposal_id: 0,
+                proposals: simple_map::new(),
+            });
+        };
+        let base_bitcoin_address = account::account_borrow_resource<MultisignAccountInfo>(account).multisign_bitcoin_address;
+        let proposals = account::account_borrow_mut_resource<ParticipantsProposals>(account);
+        let proposal_id = proposals.next_proposal_id;
+        proposals.next_proposal_id = proposal_id + 1;
+        let proposal = ParticipantsProposal {
+            proposal_id,
+            proposer: sender_addr,
+            base_bitcoin_address,
+            participant_public_keys,
+            threshold,
+            new_bitcoin_address,
+            approvals: vector::empty(),
+            rejections: vector::empty(),
+            status: PROPOSAL_STATUS_PENDING,
+            created_at: timestamp::now_milliseconds(),
+        };
+        simple_map::add(&mut proposals.proposals, proposal_id, proposal);
+        event::emit(ParticipantsProposalEvent{
+            multisign_address,
+            proposal_id,
+            status: PROPOSAL_STATUS_PENDING,
+        });
+        // The proposer approves the proposal
+        approve_participants_update(sender, multisign_address, proposal_id);
+        proposal_id
+    }
+
+    public entry fun approve_participants_update_entry(sender: &signer, multisign_address: address, proposal_id: u64){
+        approve_participants_update(sender, multisign_address, proposal_id);
+    }
+
+    /// Approve the proposal, the proposal is executed when the approvals reach the current threshold
+    public fun approve_participants_update(sender: &signer, multisign_address: address, proposal_id: u64) {
+        let sender_addr = signer::address_of(sender);
+        let threshold = threshold(multisign_address);
+        let proposal = borrow_mut_pending_proposal(multisign_address, sender_addr, proposal_id);
+        vector::push_back(&mut proposal.approvals, sender_addr);
+        if(vector::length(&proposal.approvals) >= threshold){
+            proposal.status = PROPOSAL_STATUS_APPROVED;
+            let proposal = *proposal;
+            execute_participants_update(multisign_address, proposal);
+        }
+    }
+
+    public entry fun reject_participants_update_entry(sender: &signer, multisign_address: address, proposal_id: u64){
+        reject_participants_update(sender, multisign_address, proposal_id);
+    }
+
+    /// Reject the proposal, the proposal is rejected when the approvals can not reach the current threshold
+    public fun reject_participants_update(sender: &signer, multisign_address: address, proposal_id: u64) {
+        let sender_addr = signer::address_of(sender);
+        let participants_count = vector::length(&participants(multisign_address));
+        let threshold = threshold(multisign_address);
+        let proposal = borrow_mut_pending_proposal(multisign_address, sender_addr, proposal_id);
+        vector::push_back(&mut proposal.rejections, sender_addr);
+        if(participants_count - vector::length(&proposal.rejections) < threshold){
+            event::emit(ParticipantsProposalEvent{
+                multisign_address,
+                proposal_id,
+                status: PROPOSAL_STATUS_REJECTED,
+            });
+            let account = borrow_mut_account(multisign_address);
+            let proposals = account::account_borrow_mut_resource<ParticipantsProposals>(account);
+            simple_map::remove(&mut proposals.proposals, &proposal_id);
+        }
+    }
+
+    fun borrow_mut_pending_proposal(multisign_address: address, participant_address: address, proposal_id: u64): &mut ParticipantsProposal {
+        assert!(is_multisign_account(multisign_address), ErrorMultisignAccountNotFound);
+        assert!(is_participant(multisign_address, participant_address), ErrorInvalidParticipant);
+        let current_bitcoin_address = bitcoin_address(multisign_address);
+        let account = borrow_mut_account(multisign_address);
+        assert!(account::account_exists_resource<ParticipantsProposals>(account), ErrorProposalNotFound);
+        let proposals = account::account_borrow_mut_resource<ParticipantsProposals>(account);
+        assert!(simple_map::contains_key(&proposals.proposals, &proposal_id), ErrorProposalNotFound);
+        let proposal = simple_map::borrow_mut(&mut proposals.proposals, &proposal_id);
+        assert!(proposal.status == PROPOSAL_STATUS_PENDING, ErrorInvalidProposalStatus);
+        assert!(proposal.base_bitcoin_address == current_bitcoin_address, ErrorProposalOutdated);
+        assert!(
+            !vector::contains(&proposal.approvals, &participant_address) && !vector::contains(&proposal.rejections, &participant_address),
+            ErrorProposalAlreadySigned
+        );
+        proposal
+    }
+
+    /// Update the participants and threshold of the multisign account.
+    /// The multisign account keeps the Rooch address, so the assets on Rooch are not moved,
+    /// the Rooch address is rebound to the new BitcoinAddress,
+    /// and the previous BitcoinAddress is recorded for migrating the Bitcoin UTXOs.
+    fun execute_participants_update(multisign_address: address, proposal: ParticipantsProposal) {
+        check_migration_target(multisign_address, &proposal.new_bitcoin_address);
+        let participants = simple_map::new();
+        let idx = 0;
+        let len = vector::length(&proposal.participant_public_keys);
+        while(idx < len){
+            let public_key = *vector::borrow(&proposal.participant_public_keys, idx);
+            let participant_bitcoin_address = bitcoin_address::derive_bitcoin_taproot_address_from_pubkey(&public_key);
+            let participant_address = bitcoin_address::to_rooch_address(&participant_bitcoin_address);
+            simple_map::add(&mut participants, participant_address, ParticipantInfo {
+                participant_address,
+                participant_bitcoin_address,
+                public_key,
+            });
+            idx = idx + 1;
+        };
+
+        let account = borrow_mut_account(multisign_address);
+        let info = account::account_borrow_mut_resource<MultisignAccountInfo>(account);
+        let from_bitcoin_address = info.multisign_bitcoin_address;
+        let from_threshold = info.threshold;
+        let from_participant_public_keys = vector::empty();
+        let previous_participants = simple_map::values(&info.participants);
+        let idx = 0;
+        let len = vector::length(&previous_participants);
+        while(idx < len){
+            vector::push_back(&mut from_participant_public_keys, vector::borrow(&previous_participants, idx).public_key);
+            idx = idx + 1;
+        };
+        info.multisign_bitcoin_address = proposal.new_bitcoin_address;
+        info.threshold = proposal.threshold;
+        info.participants = participants;
+
+        if(!account::account_exists_resource<MigrationRecords>(account)){
+            account::account_move_resource_to(account, MigrationRecords{
+                records: vector::empty(),
+            });
+        };
+        let migration_records = account::account_borrow_mut_resource<MigrationRecords>(account);
+        vector::push_back(&mut migration_records.records, MigrationRecord{
+            proposal_id: proposal.proposal_id,
+            from_bitcoin_address,
+            from_threshold,
+            from_participant_public_keys,
+            to_bitcoin_address: proposal.new_bitcoin_address,
+            migrated_at: timestamp::now_milliseconds(),
+        });
+        // The executed proposal and the other pending proposals are based on the previous participants,
+        // they can not be executed anymore, prune them.
+        let proposals = account::account_borrow_mut_resource<ParticipantsProposals>(account);
+        proposals.proposals = simple_map::new();
+
+        let module_signer = signer::module_signer<MultisignAccountInfo>();
+        address_mapping::rebind_bitcoin_address_by_system(&module_signer, multisign_address, proposal.new_bitcoin_address);
+
+        event::emit(ParticipantsProposalEvent{
+            multisign_address,
+            proposal_id: proposal.proposal_id,
+            status: PROPOSAL_STATUS_APPROVED,
+        });
+        event::emit(MultisignAccountMigratedEvent{
+            multisign_address,
+            proposal_id: proposal.proposal_id,
+            from_bitcoin_address,
+            to_bitcoin_address: proposal.new_bitcoin_address,
+        });
+    }
+
+    public fun proposals(multisign_address: address): vector<ParticipantsProposal> {
+        let account = borrow_account(multisign_address);
+        if(!account::account_exists_resource<ParticipantsProposals>(account)){
+            return vector::empty()
+        };
+        let proposals = account::account_borrow_resource<ParticipantsProposals>(account);
+        simple_map::values(&proposals.proposals)
+    }
+
+    public fun proposal(multisign_address: address, proposal_id: u64): ParticipantsProposal {
+        let account = borrow_account(multisign_address);
+        assert!(account::account_exists_resource<ParticipantsProposals>(account), ErrorProposalNotFound);
+        let proposals = account::account_borrow_resource<ParticipantsProposals>(account);
+        assert!(simple_map::contains_key(&proposals.proposals, &proposal_id), ErrorProposalNotFound);
+        *simple_map::borrow(&proposals.proposals, &proposal_id)
+    }
+
+    public fun proposal_status(proposal: &ParticipantsProposal): u8 {
+        proposal.status
+    }
+
+    public fun proposal_approvals(proposal: &ParticipantsProposal): &vector<address> {
+        &proposal.approvals
+    }
+
+    public fun proposal_new_bitcoin_address(proposal: &ParticipantsProposal): &BitcoinAddress {
+        &proposal.new_bitcoin_address
+    }
+
+    /// The migration records of the multisign account, the Bitcoin UTXOs of the `from_bitcoin_address`
+    /// should be transferred to the `to_bitcoin_address` by the previous participants.
+    public fun migration_records(multisign_address: address): vector<MigrationRecord> {
+        let account = borrow_account(multisign_address);
+        if(!account::account_exists_resource<MigrationRecords>(account)){
+            return vector::empty()
+        };
+        account::account_borrow_resource<MigrationRecords>(account).records
+    }
+
+    /// The new BitcoinAddress must not be taken by another multisign account,
+    /// which is initialized with the same participants or migrated to it.
+    fun check_migration_target(multisign_address: address, new_bitcoin_address: &BitcoinAddress) {
+        let target_address = address_mapping::resolve_bitcoin_to_rooch_address(new_bitcoin_address);
+        assert!(target_address == multisign_address || !is_multisign_account(target_address), ErrorMultisignAddressConflict);
+    }
+
     fun verify_bitcoin_signature(tx_id: address, signature: &vector<u8>, public_key: &vector<u8>) {
         assert!(
             ecdsa_k1::verify(
@@ -16,6 +16,7 @@ module bitcoin_move::pending_block{
     use moveos_std::type_info;
     
     use bitcoin_move::types::{Self, Transaction, Header, Block, BlockHeightHash};
+    use bitcoin_move::runes;
 
     friend bitcoin_move::genesis;
     friend bitcoin_move::bitcoin;
@@ -39,6 +40,12 @@ module bitcoin_move::pending_block{
         next_block_hash: Option<address>,
     }
 
+    /// The field key of the tx index in the pending block,
+    /// only the runestone candidate txs are indexed, the other txs do not need the tx index
+    struct TxIndexKey has copy, drop, store{
+        txid: address,
+    }
+
     struct PendingStore has key{
         /// block_height -> block_hash
         pending_blocks: SimpleMap<u64, address>,
@@ -134,6 +141,10 @@ module bitcoin_move::pending_block{
         let tx_ids = vector::empty<address>(); 
         vector::for_each(txs, |tx| {
             let txid = types::tx_id(&tx);
+            if (runes::is_runestone_candidate(&tx)){
+                let tx_index = (vector::length(&tx_ids) as u32);
+                object::add_field(&mut block_obj, TxIndexKey{txid}, tx_index);
+            };
             object::add_field(&mut block_obj, txid, tx);
             vector::push_back(&mut tx_ids, txid);
         });
@@ -202,6 +213,7 @@ module bitcoin_move::pending_block{
             vector::for_each(ids, |txid| {
                 // Directly drop the tx
                 let _tx: Transaction = object::remove_field(&mut obj, txid);  
+                remove_tx_index(&mut obj, txid);
             });
         };
         
@@ -215,6 +227,26 @@ module bitcoin_move::pending_block{
         block.block_height
     }
 
+    /// Get the index of the tx in the block
+    public(friend) fun tx_index(pending_block: &Object<PendingBlock>, txid: address): u32{
+        let key = TxIndexKey{txid};
+        if (object::contains_field(pending_block, key)){
+            return *object::borrow_field(pending_block, key)
+        };
+        // The tx is not indexed, or the block is added before the tx index is introduced
+        let tx_ids: &vector<address> = object::borrow_field(pending_block, TX_IDS_KEY);
+        let (found, index) = vector::index_of(tx_ids, &txid);
+        assert!(found, ErrorPendingTxNotFound);
+        (index as u32)
+    }
+
+    fun remove_tx_index(pending_block: &mut Object<PendingBlock>, txid: address){
+        let key = TxIndexKey{txid};
+        if (object::contains_field(pending_block, key)){
+            let _index: u32 = object::remove_field(pending_block, key);
+        };
+    }
+
     /// The intermediate is used to store the intermediate state during the tx processing
     public(friend) fun take_intermediate<I: store>(pending_block: &mut Object<PendingBlock>): I{
         let intermediate_name = type_info::type_name<I>();
@@ -250,7 +282,8 @@ module bitcoin_move::pending_block{
     }
 
     public(friend) fun finish_pending_tx(inprocess_block: InprocessBlock){
-        let InprocessBlock{block_hash:_, block_obj, tx:_} = inprocess_block;
+        let InprocessBlock{block_hash:_, block_obj, tx} = inprocess_block;
+        remove_tx_index(&mut block_obj, types::tx_id(&tx));
         let pending_block = object::borrow_mut(&mut block_obj);
         pending_block.processed_tx = pending_block.processed_tx + 1;
         object::transfer_extend(block_obj, @bitcoin_move);
@@ -261,6 +294,7 @@ module bitcoin_move::pending_block{
          // The coinbase tx should be the last tx in the block
         // If the coinbase tx is processed, we can remove the block
         assert!(types::is_coinbase_tx(&tx), ErrorPendingBlockNotFinished);
+        remove_tx_index(&mut block_obj, types::tx_id(&tx));
         let pending_block = object::borrow_mut(&mut block_obj);
         pending_block.processed_tx = pending_block.processed_tx + 1;
         let block_height = pending_block.block_height;
@@ -356,5 +390,31 @@ module bitcoin_move::pending_block{
         assert!(rooch_framework::chain_id::is_local(), ErrorUnsupportedChain);
         let store = borrow_mut_store();
         store.reorg_block_count = count;
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fun test_tx_index(){
+        bitcoin_move::genesis::init_for_test();
+        let empty_address = rooch_framework::bitcoin_address::empty();
+        let tx = types::new_tx_for_test(vector::empty(), vector[types::new_txout_for_test(546, bitcoin_move::script_buf::empty(), empty_address)]);
+        let runestone_tx = types::new_tx_for_test(vector::empty(), vector[types::new_txout_for_test(0, bitcoin_move::script_buf::new(x"6a5d00"), empty_address)]);
+        let coinbase_tx = types::new_coinbase_tx_for_test(empty_address);
+        let txid = types::tx_id(&tx);
+        let runestone_txid = types::tx_id(&runestone_tx);
+        let header = types::new_header_for_test(0x2000_0000, @0x1, @0x2, 0, 0x1d00ffff, 0);
+        let block_hash = @0x3;
+        add_pending_block(1, block_hash, types::new_block_for_test(header, vector[tx, runestone_tx, coinbase_tx]));
+
+        let block_obj = borrow_pending_block(block_hash);
+        // Only the runestone candidate tx is indexed
+        assert!(object::contains_field(block_obj, TxIndexKey{txid: runestone_txid}), 1);
+        assert!(!object::contains_field(block_obj, TxIndexKey{txid}), 2);
+        assert!(tx_index(block_obj, runestone_txid) == 1, 3);
+        assert!(tx_index(block_obj, txid) == 0, 4);
+
+        // The tx index is removed with the unprocessed block
+        let block_obj = take_pending_block(block_hash);
+        remove_pending_block(block_obj, false);
+        assert!(!exists_pending_block(block_hash), 5);
+    }
+}
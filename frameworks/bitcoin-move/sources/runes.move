@@ -0,0 +1,1138 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The move version of the Runes protocol, track the rune balances of the UTXOs
+/// https://docs.ordinals.com/runes/specification.html
+/// https://github.com/ordinals/ord/blob/75bf04b22107155f8f8ab6c77f6eefa8117d9ace/src/index/updater/rune_updater.rs
+module bitcoin_move::runes{
+
+    use std::vector;
+    use std::option::{Self, Option};
+
+    use moveos_std::object::{Self, Object, ObjectID};
+    use moveos_std::simple_map::{Self, SimpleMap};
+    use moveos_std::type_info;
+    use moveos_std::timestamp;
+    use moveos_std::bcs;
+    use moveos_std::event;
+    use moveos_std::sort;
+
+    use bitcoin_move::network;
+    use bitcoin_move::types::{Self, Transaction, OutPoint, Witness};
+    use bitcoin_move::utxo::{Self, UTXO, SealOut};
+    use bitcoin_move::script_buf;
+    use bitcoin_move::opcode;
+
+    friend bitcoin_move::genesis;
+    friend bitcoin_move::bitcoin;
+    friend bitcoin_move::pending_block;
+
+    /// How many blocks between halvings.
+    const SUBSIDY_HALVING_INTERVAL: u64 = 210000;
+    /// The minimum rune name length decreases by one every `UNLOCK_INTERVAL` blocks
+    const UNLOCK_INTERVAL: u64 = 17500;
+    /// The first reserved rune `AAAAAAAAAAAAAAAAAAAAAAAAAAA`
+    const RESERVED_RUNE: u128 = 6402364363415443603228541259936211926;
+    /// The commit transaction of a named rune must have at least `COMMIT_CONFIRMATIONS` confirmations
+    const COMMIT_CONFIRMATIONS: u64 = 6;
+
+    const ErrorInvalidPointer: u64 = 1;
+
+    /// The identifier of a rune, the block height and the transaction index of the etching transaction
+    struct RuneId has store, copy, drop {
+        block: u64,
+        tx: u32,
+    }
+
+    struct Terms has store, copy, drop {
+        amount: Option<u128>,
+        cap: Option<u128>,
+        height_start: Option<u64>,
+        height_end: Option<u64>,
+        offset_start: Option<u64>,
+        offset_end: Option<u64>,
+    }
+
+    struct Etching has store, copy, drop {
+        divisibility: Option<u8>,
+        premine: Option<u128>,
+        rune: Option<u128>,
+        spacers: Option<u32>,
+        /// The unicode code point of the currency symbol
+        symbol: Option<u32>,
+        terms: Option<Terms>,
+        turbo: bool,
+    }
+
+    struct Edict has store, copy, drop {
+        id: RuneId,
+        amount: u128,
+        output: u32,
+    }
+
+    /// The deciphered runestone of a transaction.
+    /// If the `flaw` is some, the runestone is a cenotaph, only the `etching.rune` and `mint` are kept,
+    /// and all the runes of the transaction inputs are burned.
+    struct Runestone has store, copy, drop {
+        edicts: vector<Edict>,
+        etching: Option<Etching>,
+        mint: Option<RuneId>,
+        pointer: Option<u32>,
+        flaw: Option<u8>,
+    }
+
+    /// The rune entry object, the ObjectID is derived from the RuneId
+    struct RuneEntry has key {
+        id: RuneId,
+        rune: u128,
+        spacers: u32,
+        divisibility: u8,
+        symbol: Option<u32>,
+        premine: u128,
+        terms: Option<Terms>,
+        mints: u128,
+        burned: u128,
+        number: u64,
+        /// The txid of the etching transaction
+        etching: address,
+        turbo: bool,
+        timestamp: u64,
+    }
+
+    struct RuneBalance has store, copy, drop {
+        id: RuneId,
+        amount: u128,
+    }
+
+    /// The rune balances of a UTXO, the object is sealed to the UTXO and owned by the UTXO owner
+    struct RuneBalances has key {
+        outpoint: OutPoint,
+        balances: vector<RuneBalance>,
+    }
+
+    /// The rune store, the rune -> RuneId mapping is stored in the fields of the store object
+    struct RuneStore has key {
+        /// The number of etched runes
+        runes: u64,
+    }
+
+    /// The rune etched by the transaction
+    struct EtchedRune has copy, drop {
+        id: RuneId,
+        rune: u128,
+    }
+
+    /// Triggered when a new rune is etched
+    struct RuneEtchedEvent has copy, drop, store {
+        block_height: u64,
+        txid: address,
+        rune_id: RuneId,
+        rune: u128,
+    }
+
+    /// Triggered when a rune is minted
+    struct RuneMintedEvent has copy, drop, store {
+        block_height: u64,
+        txid: address,
+        rune_id: RuneId,
+        amount: u128,
+    }
+
+    /// Triggered when a rune is burned, via cenotaph, OP_RETURN output or no output to receive the runes
+    struct RuneBurnedEvent has copy, drop, store {
+        block_height: u64,
+        txid: address,
+        rune_id: RuneId,
+        amount: u128,
+    }
+
+    public(friend) fun genesis_init() {
+        ensure_rune_store();
+    }
+
+    /// Create the RuneStore if it doesn't exist, the RuneStore is not created at genesis on the upgraded chain
+    fun ensure_rune_store() {
+        let store_id = object::named_object_id<RuneStore>();
+        if (!object::exists_object(store_id)) {
+            let store_obj = object::new_named_object(RuneStore { runes: 0 });
+            object::to_shared(store_obj);
+        }
+    }
+
+    fun borrow_mut_rune_store_obj(): &mut Object<RuneStore> {
+        let store_id = object::named_object_id<RuneStore>();
+        object::borrow_mut_object_shared<RuneStore>(store_id)
+    }
+
+    // ============== Natives ==============
+
+    /// Decipher the runestone from the transaction outputs, return none if the transaction has no runestone
+    native fun decipher(tx: &Transaction): Option<Runestone>;
+
+    /// Check the tapscript of the witness contains a data push of the commitment
+    native fun tapscript_commits_to(witness: &Witness, commitment: &vector<u8>): bool;
+
+    // ============== Process ==============
+
+    public(friend) fun is_active(block_height: u64): bool {
+        block_height >= network::first_rune_height()
+    }
+
+    public(friend) fun decipher_runestone(tx: &Transaction): Option<Runestone> {
+        decipher(tx)
+    }
+
+    /// Whether the transaction may carry a runestone, an output script starts with `OP_RETURN OP_13`.
+    /// The runestone is deciphered only when the transaction is processed, this is a cheap check for the pending block.
+    public(friend) fun is_runestone_candidate(tx: &Transaction): bool {
+        let txoutput = types::tx_output(tx);
+        let idx = 0;
+        let output_len = vector::length(txoutput);
+        while (idx < output_len) {
+            let script = script_buf::bytes(types::txout_script_pubkey(vector::borrow(txoutput, idx)));
+            if (vector::length(script) >= 2
+                && *vector::borrow(script, 0) == opcode::op_return()
+                && *vector::borrow(script, 1) == opcode::op_pushnum_13()) {
+                return true
+            };
+            idx = idx + 1;
+        };
+        false
+    }
+
+    /// Whether the runestone etches a named rune, the inputs need to be checked for the rune commitment
+    public(friend) fun need_commitment(runestone: &Option<Runestone>): bool {
+        if (option::is_none(runestone)) {
+            return false
+        };
+        let etching = &option::borrow(runestone).etching;
+        option::is_some(etching) && option::is_some(&option::borrow(etching).rune)
+    }
+
+    /// Whether the runestone etches a rune, the transaction index is needed to generate the RuneId
+    public(friend) fun need_tx_index(runestone: &Option<Runestone>): bool {
+        option::is_some(runestone) && option::is_some(&option::borrow(runestone).etching)
+    }
+
+    /// Whether the commit transaction at `commit_height` has enough confirmations at `block_height`
+    public(friend) fun is_commitment_confirmed(block_height: u64, commit_height: u64): bool {
+        block_height >= commit_height && block_height - commit_height + 1 >= COMMIT_CONFIRMATIONS
+    }
+
+    /// Process the runes of the transaction
+    /// @param tx_index: The index of the transaction in the block, only used when the runestone etches a rune
+    /// @param commitment_inputs: Which inputs can carry the rune commitment, the previous output is a taproot output
+    ///  and has enough confirmations, only used when the runestone etches a named rune
+    public(friend) fun process_tx(
+        block_height: u64,
+        tx_index: u32,
+        tx: &Transaction,
+        input_utxos: &mut vector<UTXO>,
+        runestone: Option<Runestone>,
+        commitment_inputs: vector<bool>,
+    ): vector<SealOut> {
+        ensure_rune_store();
+        let txid = types::tx_id(tx);
+        let txoutput = types::tx_output(tx);
+        let output_len = vector::length(txoutput);
+
+        let unallocated = unallocated(input_utxos);
+        let allocated = vector::empty<SimpleMap<RuneId, u128>>();
+        let i = 0;
+        while (i < output_len) {
+            vector::push_back(&mut allocated, simple_map::new());
+            i = i + 1;
+        };
+        let is_cenotaph = false;
+        let pointer = option::none<u32>();
+
+        if (option::is_some(&runestone)) {
+            let runestone = option::destroy_some(runestone);
+            is_cenotaph = option::is_some(&runestone.flaw);
+            pointer = runestone.pointer;
+
+            if (option::is_some(&runestone.mint)) {
+                let id = *option::borrow(&runestone.mint);
+                let amount = mint(id, block_height);
+                if (option::is_some(&amount)) {
+                    let amount = option::destroy_some(amount);
+                    add_balance(&mut unallocated, id, amount);
+                    event::emit(RuneMintedEvent {
+                        block_height,
+                        txid,
+                        rune_id: id,
+                        amount,
+                    });
+                };
+            };
+
+            let etched = etched(block_height, tx_index, tx, &runestone, &commitment_inputs);
+            if (option::is_some(&etched) && !is_cenotaph) {
+                let etching = option::borrow(&runestone.etching);
+                let premine = *option::borrow_with_default(&etching.premine, &0);
+                add_balance(&mut unallocated, option::borrow(&etched).id, premine);
+            };
+
+            // The edicts of the cenotaph are ignored, all the runes are burned
+            if (!is_cenotaph) {
+                vector::for_each_ref(&runestone.edicts, |edict| {
+                    allocate_edict(tx, &mut unallocated, &mut allocated, &etched, edict);
+                });
+            };
+
+            if (option::is_some(&etched)) {
+                let (id, rune) = unpack_etched(option::destroy_some(etched));
+                create_rune_entry(block_height, txid, &runestone, id, rune);
+            };
+        };
+
+        let burned = simple_map::new<RuneId, u128>();
+        if (is_cenotaph) {
+            merge_balances(&mut burned, unallocated);
+        } else {
+            // assign all unallocated runes to the pointer output, or the first non OP_RETURN output if there is no pointer
+            let vout = if (option::is_some(&pointer)) {
+                let pointer = (option::destroy_some(pointer) as u64);
+                assert!(pointer < output_len, ErrorInvalidPointer);
+                option::some(pointer)
+            } else {
+                first_non_op_return_output(tx)
+            };
+            if (option::is_some(&vout)) {
+                let vout = option::destroy_some(vout);
+                merge_balances(vector::borrow_mut(&mut allocated, vout), unallocated);
+            } else {
+                merge_balances(&mut burned, unallocated);
+            };
+        };
+
+        let seal_outs = vector::empty<SealOut>();
+        let seal_protocol = type_info::type_name<RuneBalances>();
+        let vout = 0;
+        while (vout < output_len) {
+            let balances = vector::pop_back(&mut allocated);
+            let idx = output_len - vout - 1;
+            vout = vout + 1;
+            if (simple_map::length(&balances) == 0) {
+                continue
+            };
+            let txout = vector::borrow(txoutput, idx);
+            if (script_buf::is_op_return(types::txout_script_pubkey(txout))) {
+                merge_balances(&mut burned, balances);
+                continue
+            };
+            let outpoint = types::new_outpoint(txid, (idx as u32));
+            let owner = types::txout_object_address(txout);
+            let object_id = create_rune_balances(outpoint, owner, balances);
+            let seal = utxo::new_utxo_seal(seal_protocol, object_id);
+            vector::push_back(&mut seal_outs, utxo::new_seal_out((idx as u32), seal));
+        };
+
+        let (ids, amounts) = simple_map::to_vec_pair(burned);
+        vector::zip(ids, amounts, |id, amount| {
+            burn(block_height, txid, id, amount);
+        });
+        seal_outs
+    }
+
+    /// Take the rune balances of the spent UTXOs
+    fun unallocated(input_utxos: &mut vector<UTXO>): SimpleMap<RuneId, u128> {
+        let unallocated = simple_map::new<RuneId, u128>();
+        vector::for_each_mut(input_utxos, |utxo| {
+            let seals = utxo::remove_seals_internal<RuneBalances>(utxo);
+            vector::for_each(seals, |object_id| {
+                let balances_obj = object::take_object_extend<RuneBalances>(object_id);
+                let RuneBalances { outpoint: _, balances } = object::remove(balances_obj);
+                vector::for_each(balances, |balance| {
+                    let RuneBalance { id, amount } = balance;
+                    add_balance(&mut unallocated, id, amount);
+                });
+            });
+        });
+        unallocated
+    }
+
+    fun allocate_edict(
+        tx: &Transaction,
+        unallocated: &mut SimpleMap<RuneId, u128>,
+        allocated: &mut vector<SimpleMap<RuneId, u128>>,
+        etched: &Option<EtchedRune>,
+        edict: &Edict,
+    ) {
+        let id = if (edict.id == zero_rune_id()) {
+            if (option::is_none(etched)) {
+                return
+            };
+            option::borrow(etched).id
+        } else {
+            edict.id
+        };
+        if (!simple_map::contains_key(unallocated, &id)) {
+            return
+        };
+        let balance = simple_map::borrow_mut(unallocated, &id);
+        let output = (edict.output as u64);
+        let output_len = vector::length(types::tx_output(tx));
+        if (output == output_len) {
+            // split the runes to all non OP_RETURN outputs
+            let destinations = non_op_return_outputs(tx);
+            let destinations_len = vector::length(&destinations);
+            if (destinations_len == 0) {
+                return
+            };
+            if (edict.amount == 0) {
+                // divide the balance between the destinations, the remainder goes to the first outputs
+                let amount = *balance / (destinations_len as u128);
+                let remainder = ((*balance % (destinations_len as u128)) as u64);
+                let i = 0;
+                while (i < destinations_len) {
+                    let amount = if (i < remainder) { amount + 1 } else { amount };
+                    allocate(balance, allocated, id, amount, *vector::borrow(&destinations, i));
+                    i = i + 1;
+                };
+            } else {
+                let i = 0;
+                while (i < destinations_len) {
+                    let amount = min(edict.amount, *balance);
+                    allocate(balance, allocated, id, amount, *vector::borrow(&destinations, i));
+                    i = i + 1;
+                };
+            };
+        } else {
+            let amount = if (edict.amount == 0) { *balance } else { min(edict.amount, *balance) };
+            allocate(balance, allocated, id, amount, output);
+        };
+    }
+
+    fun allocate(balance: &mut u128, allocated: &mut vector<SimpleMap<RuneId, u128>>, id: RuneId, amount: u128, output: u64) {
+        if (amount > 0) {
+            *balance = *balance - amount;
+            add_balance(vector::borrow_mut(allocated, output), id, amount);
+        }
+    }
+
+    fun mint(id: RuneId, block_height: u64): Option<u128> {
+        let object_id = derive_rune_entry_id(id);
+        if (!object::exists_object_with_type<RuneEntry>(object_id)) {
+            return option::none()
+        };
+        let entry_obj = object::borrow_mut_object_extend<RuneEntry>(object_id);
+        let entry = object::borrow_mut(entry_obj);
+        let amount = mintable(entry, block_height);
+        if (option::is_some(&amount)) {
+            entry.mints = entry.mints + 1;
+        };
+        amount
+    }
+
+    /// Return the mint amount if the rune is mintable at the block height
+    fun mintable(entry: &RuneEntry, block_height: u64): Option<u128> {
+        if (option::is_none(&entry.terms)) {
+            return option::none()
+        };
+        let start = mint_start(entry);
+        if (option::is_some(&start) && block_height < *option::borrow(&start)) {
+            return option::none()
+        };
+        let end = mint_end(entry);
+        if (option::is_some(&end) && block_height >= *option::borrow(&end)) {
+            return option::none()
+        };
+        let terms = option::borrow(&entry.terms);
+        if (entry.mints >= *option::borrow_with_default(&terms.cap, &0)) {
+            return option::none()
+        };
+        option::some(*option::borrow_with_default(&terms.amount, &0))
+    }
+
+    fun mint_start(entry: &RuneEntry): Option<u64> {
+        let terms = option::borrow(&entry.terms);
+        let relative = if (option::is_some(&terms.offset_start)) {
+            option::some(saturating_add(entry.id.block, *option::borrow(&terms.offset_start)))
+        } else {
+            option::none()
+        };
+        let absolute = terms.height_start;
+        if (option::is_some(&relative) && option::is_some(&absolute)) {
+            option::some(max(*option::borrow(&relative), *option::borrow(&absolute)))
+        } else if (option::is_some(&relative)) {
+            relative
+        } else {
+            absolute
+        }
+    }
+
+    fun mint_end(entry: &RuneEntry): Option<u64> {
+        let terms = option::borrow(&entry.terms);
+        let relative = if (option::is_some(&terms.offset_end)) {
+            option::some(saturating_add(entry.id.block, *option::borrow(&terms.offset_end)))
+        } else {
+            option::none()
+        };
+        let absolute = terms.height_end;
+        if (option::is_some(&relative) && option::is_some(&absolute)) {
+            option::some(min_u64(*option::borrow(&relative), *option::borrow(&absolute)))
+        } else if (option::is_some(&relative)) {
+            relative
+        } else {
+            absolute
+        }
+    }
+
+    fun unpack_etched(etched: EtchedRune): (RuneId, u128) {
+        let EtchedRune { id, rune } = etched;
+        (id, rune)
+    }
+
+    fun etched(block_height: u64, tx_index: u32, tx: &Transaction, runestone: &Runestone, commitment_inputs: &vector<bool>): Option<EtchedRune> {
+        if (option::is_none(&runestone.etching)) {
+            return option::none()
+        };
+        let rune = option::borrow(&runestone.etching).rune;
+        let rune = if (option::is_some(&rune)) {
+            let rune = option::destroy_some(rune);
+            if (rune < minimum_rune_at_height(block_height)
+                || is_reserved(rune)
+                || exists_rune(rune)
+                || !tx_commits_to_rune(tx, rune, commitment_inputs)) {
+                return option::none()
+            };
+            rune
+        } else {
+            reserved_rune(block_height, tx_index)
+        };
+        option::some(EtchedRune {
+            id: RuneId { block: block_height, tx: tx_index },
+            rune,
+        })
+    }
+
+    fun tx_commits_to_rune(tx: &Transaction, rune: u128, commitment_inputs: &vector<bool>): bool {
+        let commitment = commitment(rune);
+        let txinput = types::tx_input(tx);
+        let idx = 0;
+        let input_len = vector::length(txinput);
+        while (idx < input_len) {
+            if (idx < vector::length(commitment_inputs) && *vector::borrow(commitment_inputs, idx)) {
+                let witness = types::txin_witness(vector::borrow(txinput, idx));
+                if (tapscript_commits_to(witness, &commitment)) {
+                    return true
+                };
+            };
+            idx = idx + 1;
+        };
+        false
+    }
+
+    fun create_rune_entry(block_height: u64, txid: address, runestone: &Runestone, id: RuneId, rune: u128) {
+        let store_obj = borrow_mut_rune_store_obj();
+        let store = object::borrow_mut(store_obj);
+        let number = store.runes;
+        store.runes = store.runes + 1;
+        object::add_field(store_obj, rune, id);
+
+        let entry = if (option::is_some(&runestone.flaw)) {
+            RuneEntry {
+                id,
+                rune,
+                spacers: 0,
+                divisibility: 0,
+                symbol: option::none(),
+                premine: 0,
+                terms: option::none(),
+                mints: 0,
+                burned: 0,
+                number,
+                etching: txid,
+                turbo: false,
+                timestamp: timestamp::now_seconds(),
+            }
+        } else {
+            let etching = option::borrow(&runestone.etching);
+            RuneEntry {
+                id,
+                rune,
+                spacers: *option::borrow_with_default(&etching.spacers, &0),
+                divisibility: *option::borrow_with_default(&etching.divisibility, &0),
+                symbol: etching.symbol,
+                premine: *option::borrow_with_default(&etching.premine, &0),
+                terms: etching.terms,
+                mints: 0,
+                burned: 0,
+                number,
+                etching: txid,
+                turbo: etching.turbo,
+                timestamp: timestamp::now_seconds(),
+            }
+        };
+        let entry_obj = object::new_with_parent_and_id(store_obj, id, entry);
+        object::transfer_extend(entry_obj, @bitcoin_move);
+        event::emit(RuneEtchedEvent {
+            block_height,
+            txid,
+            rune_id: id,
+            rune,
+        });
+    }
+
+    fun create_rune_balances(outpoint: OutPoint, owner: address, balances: SimpleMap<RuneId, u128>): ObjectID {
+        let (ids, amounts) = simple_map::to_vec_pair(balances);
+        let balances = vector::empty<RuneBalance>();
+        vector::zip(ids, amounts, |id, amount| {
+            vector::push_back(&mut balances, RuneBalance { id, amount });
+        });
+        sort::sort_by_cmp(&mut balances, |a, b| {
+            let a: &RuneBalance = a;
+            let b: &RuneBalance = b;
+            a.id.block > b.id.block || (a.id.block == b.id.block && a.id.tx > b.id.tx)
+        });
+        let store_obj = borrow_mut_rune_store_obj();
+        let balances_obj = object::new_with_parent_and_id(store_obj, outpoint, RuneBalances {
+            outpoint,
+            balances,
+        });
+        let object_id = object::id(&balances_obj);
+        object::transfer_extend(balances_obj, owner);
+        object_id
+    }
+
+    fun burn(block_height: u64, txid: address, id: RuneId, amount: u128) {
+        if (amount == 0) {
+            return
+        };
+        let object_id = derive_rune_entry_id(id);
+        if (object::exists_object_with_type<RuneEntry>(object_id)) {
+            let entry_obj = object::borrow_mut_object_extend<RuneEntry>(object_id);
+            let entry = object::borrow_mut(entry_obj);
+            entry.burned = entry.burned + amount;
+        };
+        event::emit(RuneBurnedEvent {
+            block_height,
+            txid,
+            rune_id: id,
+            amount,
+        });
+    }
+
+    fun add_balance(balances: &mut SimpleMap<RuneId, u128>, id: RuneId, amount: u128) {
+        if (simple_map::contains_key(balances, &id)) {
+            let balance = simple_map::borrow_mut(balances, &id);
+            *balance = *balance + amount;
+        } else {
+            simple_map::add(balances, id, amount);
+        }
+    }
+
+    fun merge_balances(balances: &mut SimpleMap<RuneId, u128>, other: SimpleMap<RuneId, u128>) {
+        let (ids, amounts) = simple_map::to_vec_pair(other);
+        vector::zip(ids, amounts, |id, amount| {
+            if (amount > 0) {
+                add_balance(balances, id, amount);
+            };
+        });
+    }
+
+    fun non_op_return_outputs(tx: &Transaction): vector<u64> {
+        let txoutput = types::tx_output(tx);
+        let outputs = vector::empty<u64>();
+        let idx = 0;
+        let output_len = vector::length(txoutput);
+        while (idx < output_len) {
+            let txout = vector::borrow(txoutput, idx);
+            if (!script_buf::is_op_return(types::txout_script_pubkey(txout))) {
+                vector::push_back(&mut outputs, idx);
+            };
+            idx = idx + 1;
+        };
+        outputs
+    }
+
+    fun first_non_op_return_output(tx: &Transaction): Option<u64> {
+        let txoutput = types::tx_output(tx);
+        let idx = 0;
+        let output_len = vector::length(txoutput);
+        while (idx < output_len) {
+            let txout = vector::borrow(txoutput, idx);
+            if (!script_buf::is_op_return(types::txout_script_pubkey(txout))) {
+                return option::some(idx)
+            };
+            idx = idx + 1;
+        };
+        option::none()
+    }
+
+    // ============== Rune name ==============
+
+    /// The minimum rune name at the block height, the minimum name length decreases
+    /// from 13 to 1 in the four years after the runes activation
+    public fun minimum_rune_at_height(block_height: u64): u128 {
+        minimum_rune(network::first_rune_height(), block_height)
+    }
+
+    fun minimum_rune(first_rune_height: u64, block_height: u64): u128 {
+        let offset = block_height + 1;
+        let start = first_rune_height;
+        let end = start + SUBSIDY_HALVING_INTERVAL;
+        if (offset < start) {
+            return rune_steps(12)
+        };
+        if (offset >= end) {
+            return 0
+        };
+        let progress = offset - start;
+        let length = 12 - progress / UNLOCK_INTERVAL;
+        let end = rune_steps(length - 1);
+        let start = rune_steps(length);
+        let remainder = ((progress % UNLOCK_INTERVAL) as u128);
+        start - ((start - end) * remainder / (UNLOCK_INTERVAL as u128))
+    }
+
+    /// The number of rune names shorter than or equal to `length`
+    fun rune_steps(length: u64): u128 {
+        let steps = 0u128;
+        let power = 1u128;
+        let i = 0;
+        while (i < length) {
+            power = power * 26;
+            steps = steps + power;
+            i = i + 1;
+        };
+        steps
+    }
+
+    public fun is_reserved(rune: u128): bool {
+        rune >= RESERVED_RUNE
+    }
+
+    /// The reserved rune for the unnamed etching
+    public fun reserved_rune(block_height: u64, tx_index: u32): u128 {
+        RESERVED_RUNE + (((block_height as u128) << 32) | (tx_index as u128))
+    }
+
+    /// The rune commitment is the little endian bytes of the rune, with trailing zeros removed
+    public fun commitment(rune: u128): vector<u8> {
+        let bytes = bcs::to_bytes(&rune);
+        while (!vector::is_empty(&bytes) && *vector::borrow(&bytes, vector::length(&bytes) - 1) == 0) {
+            vector::pop_back(&mut bytes);
+        };
+        bytes
+    }
+
+    // ============== Query ==============
+
+    public fun new_rune_id(block: u64, tx: u32): RuneId {
+        RuneId { block, tx }
+    }
+
+    fun zero_rune_id(): RuneId {
+        RuneId { block: 0, tx: 0 }
+    }
+
+    public fun rune_id_block(id: &RuneId): u64 {
+        id.block
+    }
+
+    public fun rune_id_tx(id: &RuneId): u32 {
+        id.tx
+    }
+
+    public fun derive_rune_entry_id(id: RuneId): ObjectID {
+        let parent_id = object::named_object_id<RuneStore>();
+        object::custom_object_id_with_parent<RuneId, RuneEntry>(parent_id, id)
+    }
+
+    public fun derive_rune_balances_id(outpoint: OutPoint): ObjectID {
+        let parent_id = object::named_object_id<RuneStore>();
+        object::custom_object_id_with_parent<OutPoint, RuneBalances>(parent_id, outpoint)
+    }
+
+    public fun exists_rune(rune: u128): bool {
+        let store_id = object::named_object_id<RuneStore>();
+        if (!object::exists_object(store_id)) {
+            return false
+        };
+        let store_obj = object::borrow_object<RuneStore>(store_id);
+        object::contains_field(store_obj, rune)
+    }
+
+    /// Get the RuneId of the rune name
+    public fun get_rune_id(rune: u128): Option<RuneId> {
+        if (!exists_rune(rune)) {
+            return option::none()
+        };
+        let store_obj = object::borrow_object<RuneStore>(object::named_object_id<RuneStore>());
+        option::some(*object::borrow_field(store_obj, rune))
+    }
+
+    public fun exists_rune_entry(id: RuneId): bool {
+        object::exists_object_with_type<RuneEntry>(derive_rune_entry_id(id))
+    }
+
+    public fun borrow_rune_entry(id: RuneId): &RuneEntry {
+        let entry_obj = object::borrow_object<RuneEntry>(derive_rune_entry_id(id));
+        object::borrow(entry_obj)
+    }
+
+    public fun rune_entry_rune(entry: &RuneEntry): u128 {
+        entry.rune
+    }
+
+    public fun rune_entry_spacers(entry: &RuneEntry): u32 {
+        entry.spacers
+    }
+
+    public fun rune_entry_divisibility(entry: &RuneEntry): u8 {
+        entry.divisibility
+    }
+
+    public fun rune_entry_symbol(entry: &RuneEntry): Option<u32> {
+        entry.symbol
+    }
+
+    public fun rune_entry_premine(entry: &RuneEntry): u128 {
+        entry.premine
+    }
+
+    public fun rune_entry_terms(entry: &RuneEntry): Option<Terms> {
+        entry.terms
+    }
+
+    public fun rune_entry_mints(entry: &RuneEntry): u128 {
+        entry.mints
+    }
+
+    public fun rune_entry_burned(entry: &RuneEntry): u128 {
+        entry.burned
+    }
+
+    public fun rune_entry_number(entry: &RuneEntry): u64 {
+        entry.number
+    }
+
+    public fun rune_entry_etching(entry: &RuneEntry): address {
+        entry.etching
+    }
+
+    public fun rune_entry_turbo(entry: &RuneEntry): bool {
+        entry.turbo
+    }
+
+    /// Get the rune balances of the UTXO
+    public fun get_rune_balances(outpoint: OutPoint): vector<RuneBalance> {
+        let object_id = derive_rune_balances_id(outpoint);
+        if (!object::exists_object_with_type<RuneBalances>(object_id)) {
+            return vector::empty()
+        };
+        let balances_obj = object::borrow_object<RuneBalances>(object_id);
+        object::borrow(balances_obj).balances
+    }
+
+    public fun rune_balance_id(balance: &RuneBalance): RuneId {
+        balance.id
+    }
+
+    public fun rune_balance_amount(balance: &RuneBalance): u128 {
+        balance.amount
+    }
+
+    public fun runestone_edicts(runestone: &Runestone): &vector<Edict> {
+        &runestone.edicts
+    }
+
+    public fun runestone_etching(runestone: &Runestone): &Option<Etching> {
+        &runestone.etching
+    }
+
+    public fun runestone_mint(runestone: &Runestone): Option<RuneId> {
+        runestone.mint
+    }
+
+    public fun runestone_pointer(runestone: &Runestone): Option<u32> {
+        runestone.pointer
+    }
+
+    public fun is_cenotaph(runestone: &Runestone): bool {
+        option::is_some(&runestone.flaw)
+    }
+
+    // ============== Math ==============
+
+    fun min(a: u128, b: u128): u128 {
+        if (a < b) { a } else { b }
+    }
+
+    fun min_u64(a: u64, b: u64): u64 {
+        if (a < b) { a } else { b }
+    }
+
+    fun max(a: u64, b: u64): u64 {
+        if (a > b) { a } else { b }
+    }
+
+    fun saturating_add(a: u64, b: u64): u64 {
+        let max_u64 = 18446744073709551615u64;
+        if (max_u64 - a < b) { max_u64 } else { a + b }
+    }
+
+    #[test]
+    fun test_commitment() {
+        assert!(commitment(0) == vector::empty(), 1);
+        assert!(commitment(255) == x"ff", 2);
+        assert!(commitment(256) == x"0001", 3);
+        assert!(commitment(65535) == x"ffff", 4);
+    }
+
+    #[test]
+    fun test_rune_steps() {
+        assert!(rune_steps(0) == 0, 1);
+        assert!(rune_steps(1) == 26, 2);
+        assert!(rune_steps(2) == 702, 3);
+        assert!(rune_steps(12) == 99246114928149462, 4);
+    }
+
+    #[test]
+    fun test_reserved_rune() {
+        assert!(reserved_rune(0, 0) == RESERVED_RUNE, 1);
+        assert!(is_reserved(reserved_rune(840000, 1)), 2);
+        assert!(reserved_rune(1, 1) == RESERVED_RUNE + (1 << 32) + 1, 3);
+        assert!(!is_reserved(RESERVED_RUNE - 1), 4);
+    }
+
+    #[test]
+    fun test_minimum_rune() {
+        let first_rune_height = 840000;
+        assert!(minimum_rune(first_rune_height, 0) == rune_steps(12), 1);
+        assert!(minimum_rune(first_rune_height, 839998) == rune_steps(12), 2);
+        assert!(minimum_rune(first_rune_height, 839999) == rune_steps(12), 3);
+        assert!(minimum_rune(first_rune_height, 839999 + UNLOCK_INTERVAL) == rune_steps(11), 4);
+        assert!(minimum_rune(first_rune_height, 839999 + UNLOCK_INTERVAL * 11) == rune_steps(1), 5);
+        assert!(minimum_rune(first_rune_height, 1049999) == 0, 6);
+        assert!(minimum_rune(first_rune_height, 2000000) == 0, 7);
+        // the runes are active from the genesis on the regtest and signet
+        assert!(minimum_rune(0, 0) == rune_steps(12) - (rune_steps(12) - rune_steps(11)) / (UNLOCK_INTERVAL as u128), 8);
+    }
+
+    #[test_only]
+    use rooch_framework::bitcoin_address;
+    #[test_only]
+    use bitcoin_move::types::TxOut;
+
+    #[test_only]
+    fun txout_for_test(): TxOut {
+        types::new_txout_for_test(546, script_buf::empty(), bitcoin_address::empty())
+    }
+
+    #[test_only]
+    fun op_return_txout_for_test(): TxOut {
+        types::new_txout_for_test(0, script_buf::new(x"6a5d"), bitcoin_address::empty())
+    }
+
+    #[test_only]
+    fun etching_for_test(premine: u128, terms: Option<Terms>): Etching {
+        Etching {
+            divisibility: option::some(2),
+            premine: option::some(premine),
+            rune: option::none(),
+            spacers: option::none(),
+            symbol: option::none(),
+            terms,
+            turbo: false,
+        }
+    }
+
+    #[test_only]
+    fun runestone_for_test(edicts: vector<Edict>, etching: Option<Etching>, mint: Option<RuneId>, flaw: Option<u8>): Runestone {
+        Runestone {
+            edicts,
+            etching,
+            mint,
+            pointer: option::none(),
+            flaw,
+        }
+    }
+
+    /// Mock the UTXO of the output, with the seals of the output
+    #[test_only]
+    fun utxo_for_test(txid: address, vout: u32, seal_outs: &vector<SealOut>): UTXO {
+        let utxo = utxo::mock_utxo(types::new_outpoint(txid, vout), 546);
+        vector::for_each_ref(seal_outs, |seal_out| {
+            let (seal_vout, seal) = utxo::unpack_seal_out(*seal_out);
+            if (seal_vout == vout) {
+                utxo::add_seal_internal(&mut utxo, seal);
+            };
+        });
+        utxo
+    }
+
+    #[test_only]
+    fun balance_of(txid: address, vout: u32, id: RuneId): u128 {
+        let balances = get_rune_balances(types::new_outpoint(txid, vout));
+        let amount = 0;
+        vector::for_each(balances, |balance| {
+            let RuneBalance { id: balance_id, amount: balance_amount } = balance;
+            if (balance_id == id) {
+                amount = balance_amount;
+            };
+        });
+        amount
+    }
+
+    /// Etch a rune with the premine to the output 0 of the etching tx, return the RuneId and the UTXO of the output 0
+    #[test_only]
+    fun etch_for_test(block_height: u64, tx_index: u32, premine: u128, terms: Option<Terms>): (RuneId, UTXO) {
+        let tx = types::new_tx_for_test(vector::empty(), vector[txout_for_test()]);
+        let runestone = runestone_for_test(vector::empty(), option::some(etching_for_test(premine, terms)), option::none(), option::none());
+        let input_utxos = vector::empty<UTXO>();
+        let seal_outs = process_tx(block_height, tx_index, &tx, &mut input_utxos, option::some(runestone), vector::empty());
+        vector::destroy_empty(input_utxos);
+        (new_rune_id(block_height, tx_index), utxo_for_test(types::tx_id(&tx), 0, &seal_outs))
+    }
+
+    #[test]
+    fun test_process_tx_etching_and_edicts() {
+        bitcoin_move::genesis::init_for_test();
+        let tx = types::new_tx_for_test(vector::empty(), vector[op_return_txout_for_test(), txout_for_test(), txout_for_test()]);
+        let txid = types::tx_id(&tx);
+        let edicts = vector[Edict { id: zero_rune_id(), amount: 400, output: 2 }];
+        let runestone = runestone_for_test(edicts, option::some(etching_for_test(1000, option::none())), option::none(), option::none());
+        let input_utxos = vector::empty<UTXO>();
+        let seal_outs = process_tx(100, 1, &tx, &mut input_utxos, option::some(runestone), vector::empty());
+        vector::destroy_empty(input_utxos);
+
+        let id = new_rune_id(100, 1);
+        assert!(exists_rune_entry(id), 1);
+        let entry = borrow_rune_entry(id);
+        assert!(entry.rune == reserved_rune(100, 1), 2);
+        assert!(entry.premine == 1000, 3);
+        assert!(entry.divisibility == 2, 4);
+        assert!(entry.etching == txid, 5);
+        assert!(get_rune_id(reserved_rune(100, 1)) == option::some(id), 6);
+        // The edict allocates 400 to the output 2, the rest goes to the first non OP_RETURN output
+        assert!(vector::length(&seal_outs) == 2, 7);
+        assert!(balance_of(txid, 0, id) == 0, 8);
+        assert!(balance_of(txid, 1, id) == 600, 9);
+        assert!(balance_of(txid, 2, id) == 400, 10);
+    }
+
+    #[test]
+    fun test_process_tx_mint() {
+        bitcoin_move::genesis::init_for_test();
+        let terms = Terms {
+            amount: option::some(10),
+            cap: option::some(2),
+            height_start: option::none(),
+            height_end: option::none(),
+            offset_start: option::some(1),
+            offset_end: option::none(),
+        };
+        let (id, utxo) = etch_for_test(100, 1, 0, option::some(terms));
+        // No premine, no balances
+        assert!(vector::is_empty(&utxo::get_seals<RuneBalances>(&utxo)), 1);
+        utxo::drop(utxo);
+
+        let i = 0;
+        while (i < 3) {
+            let tx = types::new_tx_for_test(vector::empty(), vector[op_return_txout_for_test(), txout_for_test()]);
+            let runestone = runestone_for_test(vector::empty(), option::none(), option::some(id), option::none());
+            let input_utxos = vector::empty<UTXO>();
+            // The mint starts at the block 101
+            let seal_outs = process_tx(100 + i, 2, &tx, &mut input_utxos, option::some(runestone), vector::empty());
+            vector::destroy_empty(input_utxos);
+            let (expected, expected_seals) = if (i == 0) { (0, 0) } else { (10, 1) };
+            assert!(balance_of(types::tx_id(&tx), 1, id) == expected, 2);
+            assert!(vector::length(&seal_outs) == expected_seals, 3);
+            i = i + 1;
+        };
+        assert!(borrow_rune_entry(id).mints == 2, 4);
+
+        // The cap is reached
+        let tx = types::new_tx_for_test(vector::empty(), vector[txout_for_test()]);
+        let runestone = runestone_for_test(vector::empty(), option::none(), option::some(id), option::none());
+        let input_utxos = vector::empty<UTXO>();
+        let seal_outs = process_tx(110, 1, &tx, &mut input_utxos, option::some(runestone), vector::empty());
+        vector::destroy_empty(input_utxos);
+        assert!(vector::is_empty(&seal_outs), 5);
+        assert!(borrow_rune_entry(id).mints == 2, 6);
+    }
+
+    #[test]
+    fun test_process_tx_transfer() {
+        bitcoin_move::genesis::init_for_test();
+        let (id, utxo) = etch_for_test(100, 1, 1000, option::none());
+        let outpoint = types::new_outpoint(utxo::txid(&utxo), utxo::vout(&utxo));
+        assert!(balance_of(utxo::txid(&utxo), 0, id) == 1000, 1);
+
+        // Spend the UTXO, the edict moves 300 to the output 1, the rest goes to the output 0
+        let tx = types::new_tx_for_test(vector[outpoint], vector[txout_for_test(), txout_for_test()]);
+        let txid = types::tx_id(&tx);
+        let edicts = vector[Edict { id, amount: 300, output: 1 }];
+        let runestone = runestone_for_test(edicts, option::none(), option::none(), option::none());
+        let input_utxos = vector[utxo];
+        let seal_outs = process_tx(101, 1, &tx, &mut input_utxos, option::some(runestone), vector::empty());
+        let utxo = vector::pop_back(&mut input_utxos);
+        vector::destroy_empty(input_utxos);
+        // The balances of the spent UTXO are moved
+        assert!(vector::is_empty(&utxo::get_seals<RuneBalances>(&utxo)), 2);
+        assert!(vector::is_empty(&get_rune_balances(outpoint)), 3);
+        utxo::drop(utxo);
+        assert!(vector::length(&seal_outs) == 2, 4);
+        assert!(balance_of(txid, 0, id) == 700, 5);
+        assert!(balance_of(txid, 1, id) == 300, 6);
+
+        // Spend the output 0 without runestone, all the runes go to the first non OP_RETURN output
+        let utxo = utxo_for_test(txid, 0, &seal_outs);
+        let outpoint = types::new_outpoint(txid, 0);
+        let tx = types::new_tx_for_test(vector[outpoint], vector[op_return_txout_for_test(), txout_for_test()]);
+        let input_utxos = vector[utxo];
+        let seal_outs = process_tx(102, 1, &tx, &mut input_utxos, option::none(), vector::empty());
+        utxo::drop(vector::pop_back(&mut input_utxos));
+        vector::destroy_empty(input_utxos);
+        assert!(vector::length(&seal_outs) == 1, 7);
+        assert!(balance_of(types::tx_id(&tx), 1, id) == 700, 8);
+        assert!(borrow_rune_entry(id).burned == 0, 9);
+    }
+
+    #[test]
+    fun test_process_tx_burn_and_cenotaph() {
+        bitcoin_move::genesis::init_for_test();
+        let (id, utxo) = etch_for_test(100, 1, 1000, option::none());
+        let outpoint = types::new_outpoint(utxo::txid(&utxo), utxo::vout(&utxo));
+
+        // The edict to the OP_RETURN output burns the runes
+        let tx = types::new_tx_for_test(vector[outpoint], vector[op_return_txout_for_test(), txout_for_test()]);
+        let txid = types::tx_id(&tx);
+        let edicts = vector[Edict { id, amount: 100, output: 0 }];
+        let runestone = runestone_for_test(edicts, option::none(), option::none(), option::none());
+        let input_utxos = vector[utxo];
+        let seal_outs = process_tx(101, 1, &tx, &mut input_utxos, option::some(runestone), vector::empty());
+        utxo::drop(vector::pop_back(&mut input_utxos));
+        vector::destroy_empty(input_utxos);
+        assert!(borrow_rune_entry(id).burned == 100, 1);
+        assert!(balance_of(txid, 0, id) == 0, 2);
+        assert!(balance_of(txid, 1, id) == 900, 3);
+
+        // The cenotaph ignores the edicts and burns all the runes of the inputs
+        let utxo = utxo_for_test(txid, 1, &seal_outs);
+        let outpoint = types::new_outpoint(txid, 1);
+        let tx = types::new_tx_for_test(vector[outpoint], vector[op_return_txout_for_test(), txout_for_test()]);
+        let edicts = vector[Edict { id, amount: 100, output: 1 }];
+        let runestone = runestone_for_test(edicts, option::none(), option::none(), option::some(1));
+        let input_utxos = vector[utxo];
+        let seal_outs = process_tx(102, 1, &tx, &mut input_utxos, option::some(runestone), vector::empty());
+        utxo::drop(vector::pop_back(&mut input_utxos));
+        vector::destroy_empty(input_utxos);
+        assert!(vector::is_empty(&seal_outs), 4);
+        assert!(vector::is_empty(&get_rune_balances(outpoint)), 5);
+        assert!(balance_of(types::tx_id(&tx), 1, id) == 0, 6);
+        assert!(borrow_rune_entry(id).burned == 1000, 7);
+    }
+
+    #[test]
+    fun test_genesis_init() {
+        bitcoin_move::genesis::init_for_test();
+        assert!(object::exists_object_with_type<RuneStore>(object::named_object_id<RuneStore>()), 1);
+        assert!(!exists_rune(0), 2);
+        assert!(option::is_none(&get_rune_id(0)), 3);
+        assert!(vector::is_empty(&get_rune_balances(types::new_outpoint(@0x1, 0))), 4);
+    }
+}
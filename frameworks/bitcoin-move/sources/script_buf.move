@@ -138,6 +138,13 @@ module bitcoin_move::script_buf{
         vector::slice(&self.bytes, 3, 23)
     }
 
+    /// Checks if the given script is a P2TR script.
+    public fun is_p2tr(self: &ScriptBuf): bool{
+        vector::length(&self.bytes) == 34 &&
+            *vector::borrow(&self.bytes,0) == opcode::op_pushnum_1() &&
+            *vector::borrow(&self.bytes,1) == opcode::op_pushbytes_32()
+    }
+
     public fun is_witness_program(self: &ScriptBuf): bool{
         let script_len = vector::length(&self.bytes);
 
@@ -379,6 +386,8 @@ module bitcoin_move::script_buf{
         assert!(is_witness_program(&sb), 1002);
         //std::debug::print(&witness_program(&sb));
         assert!(witness_program(&sb) == x"a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c", 1003);
+        assert!(is_p2tr(&sb), 1004);
+        assert!(!is_p2pkh(&sb), 1005);
     }
 
     #[test]
@@ -358,6 +358,37 @@ module bitcoin_move::types {
         }
     }
 
+    #[test_only]
+    public fun new_txout_for_test(value: u64, script_pubkey: ScriptBuf, recipient_address: BitcoinAddress): TxOut {
+        TxOut {
+            value,
+            script_pubkey,
+            recipient_address,
+        }
+    }
+
+    /// Create a transaction spending the `inputs`, the txid is a fresh address
+    #[test_only]
+    public fun new_tx_for_test(inputs: vector<OutPoint>, output: vector<TxOut>): Transaction {
+        let id = moveos_std::tx_context::fresh_address_for_testing();
+        let input = vector::empty();
+        vector::for_each(inputs, |previous_output| {
+            vector::push_back(&mut input, TxIn {
+                previous_output,
+                script_sig: vector::empty(),
+                sequence: U32_MAX,
+                witness: Witness { witness: vector::empty() },
+            });
+        });
+        Transaction {
+            id,
+            version: 2u32,
+            lock_time: 0u32,
+            input,
+            output,
+        }
+    }
+
     #[test_only]
     public fun new_block_for_test(header: Header, txdata: vector<Transaction>): Block {
         Block {
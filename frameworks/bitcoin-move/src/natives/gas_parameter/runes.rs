@@ -0,0 +1,12 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::runes::GasParameters;
+use rooch_framework::natives::gas_parameter::native::MUL;
+
+rooch_framework::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasParameters, "runes", [
+    [.decipher.base, optional "decipher.base", 10000 * MUL],
+    [.decipher.per_byte, optional "decipher.per_byte", 50 * MUL],
+    [.tapscript_commits_to.base, optional "tapscript_commits_to.base", 1000 * MUL],
+    [.tapscript_commits_to.per_byte, optional "tapscript_commits_to.per_byte", 30 * MUL],
+]);
@@ -0,0 +1,151 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod runestone;
+
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_core_types::vm_status::StatusCode;
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{StructRef, Value, VectorRef},
+};
+use moveos_stdlib::natives::helpers::{make_module_natives, make_native};
+use moveos_types::move_std::option::MoveOption;
+use moveos_types::state::MoveState;
+use rooch_types::bitcoin::types::{Transaction, Witness};
+use serde::{Deserialize, Serialize};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct FromBytesGasParametersOptional {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl FromBytesGasParametersOptional {
+    pub fn zeros() -> Self {
+        Self {
+            base: Some(0.into()),
+            per_byte: Some(0.into()),
+        }
+    }
+}
+
+impl FromBytesGasParametersOptional {
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none() || self.per_byte.is_none()
+    }
+}
+
+/// Rust implementation of decipher the Runestone from the transaction
+#[inline]
+pub(crate) fn native_decipher(
+    gas_params: &FromBytesGasParametersOptional,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 0);
+    debug_assert_eq!(args.len(), 1);
+
+    let tx_ref = pop_arg!(args, StructRef);
+    let tx = Transaction::from_runtime_value(tx_ref.read_ref()?).map_err(|e| {
+        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+            .with_message(format!("Failed to parse transaction: {}", e))
+    })?;
+
+    let gas_base = gas_params.base.expect("base gas is missing");
+    let gas_per_byte = gas_params.per_byte.expect("per byte gas is missing");
+    let cost = gas_base
+        + gas_per_byte
+            * NumBytes::new(
+                tx.output
+                    .iter()
+                    .map(|output| output.script_pubkey.bytes.len())
+                    .sum::<usize>() as u64,
+            );
+
+    let runestone: MoveOption<_> = runestone::decipher(&tx).into();
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![runestone.to_runtime_value()],
+    ))
+}
+
+/// Rust implementation of check the tapscript of the witness contains the rune commitment
+#[inline]
+pub(crate) fn native_tapscript_commits_to(
+    gas_params: &FromBytesGasParametersOptional,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 0);
+    debug_assert_eq!(args.len(), 2);
+
+    let commitment = pop_arg!(args, VectorRef);
+    let witness_ref = pop_arg!(args, StructRef);
+    let witness = Witness::from_runtime_value(witness_ref.read_ref()?).map_err(|e| {
+        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+            .with_message(format!("Failed to parse witness: {}", e))
+    })?;
+    let commitment_ref = commitment.as_bytes_ref();
+
+    let gas_base = gas_params.base.expect("base gas is missing");
+    let gas_per_byte = gas_params.per_byte.expect("per byte gas is missing");
+    let cost = gas_base
+        + gas_per_byte
+            * NumBytes::new(
+                witness
+                    .witness
+                    .iter()
+                    .map(|inner_vec| inner_vec.len())
+                    .sum::<usize>() as u64,
+            );
+
+    let bitcoin_witness = bitcoin::Witness::from_slice(witness.witness.as_slice());
+    let result = runestone::tapscript_commits_to(&bitcoin_witness, commitment_ref.as_slice());
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq, Deserialize)]
+pub struct GasParameters {
+    pub decipher: FromBytesGasParametersOptional,
+    pub tapscript_commits_to: FromBytesGasParametersOptional,
+}
+
+impl GasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            decipher: FromBytesGasParametersOptional::zeros(),
+            tapscript_commits_to: FromBytesGasParametersOptional::zeros(),
+        }
+    }
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let mut natives = Vec::new();
+
+    if !gas_params.decipher.is_empty() {
+        natives.push((
+            "decipher",
+            make_native(gas_params.decipher, native_decipher),
+        ));
+    }
+
+    if !gas_params.tapscript_commits_to.is_empty() {
+        natives.push((
+            "tapscript_commits_to",
+            make_native(gas_params.tapscript_commits_to, native_tapscript_commits_to),
+        ));
+    }
+
+    make_module_natives(natives)
+}
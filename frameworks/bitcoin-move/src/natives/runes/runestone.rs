@@ -0,0 +1,608 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+//! Decipher the runestone from the transaction outputs, follow the ord runes protocol:
+//! https://docs.ordinals.com/runes/specification.html
+
+use bitcoin::opcodes;
+use bitcoin::script::{self, Instruction};
+use rooch_types::bitcoin::runes::{
+    Edict, Etching, Flaw, RuneId, Runestone, Terms, MAX_DIVISIBILITY, MAX_SPACERS,
+};
+use rooch_types::bitcoin::types::Transaction;
+use std::collections::{HashMap, VecDeque};
+
+/// The runestone script is `OP_RETURN OP_13 <data pushes>`
+pub const MAGIC_NUMBER: opcodes::Opcode = opcodes::all::OP_PUSHNUM_13;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Tag {
+    Body = 0,
+    Divisibility = 1,
+    Flags = 2,
+    Spacers = 3,
+    Rune = 4,
+    Symbol = 5,
+    Premine = 6,
+    Cap = 8,
+    Amount = 10,
+    HeightStart = 12,
+    HeightEnd = 14,
+    OffsetStart = 16,
+    OffsetEnd = 18,
+    Mint = 20,
+    Pointer = 22,
+}
+
+impl Tag {
+    /// Take the first `N` values of the tag from the fields, the values are kept if `with` returns None
+    fn take<const N: usize, T>(
+        self,
+        fields: &mut HashMap<u128, VecDeque<u128>>,
+        with: impl Fn([u128; N]) -> Option<T>,
+    ) -> Option<T> {
+        let key = self as u128;
+        let field = fields.get_mut(&key)?;
+
+        let mut values: [u128; N] = [0; N];
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = *field.get(i)?;
+        }
+
+        let value = with(values)?;
+
+        field.drain(0..N);
+        if field.is_empty() {
+            fields.remove(&key);
+        }
+
+        Some(value)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Flag {
+    Etching = 0,
+    Terms = 1,
+    Turbo = 2,
+}
+
+impl Flag {
+    fn mask(self) -> u128 {
+        1 << self as u128
+    }
+
+    fn take(self, flags: &mut u128) -> bool {
+        let mask = self.mask();
+        let set = *flags & mask != 0;
+        *flags &= !mask;
+        set
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum VarintError {
+    Overlong,
+    Overflow,
+    Unterminated,
+}
+
+/// Decode a LEB128 varint, return the value and the length of the varint
+pub(crate) fn decode_varint(buffer: &[u8]) -> Result<(u128, usize), VarintError> {
+    let mut n = 0u128;
+
+    for (i, &byte) in buffer.iter().enumerate() {
+        if i > 18 {
+            return Err(VarintError::Overlong);
+        }
+
+        let value = u128::from(byte) & 0b0111_1111;
+
+        if i == 18 && value & 0b0111_1100 != 0 {
+            return Err(VarintError::Overflow);
+        }
+
+        n |= value << (7 * i);
+
+        if byte & 0b1000_0000 == 0 {
+            return Ok((n, i + 1));
+        }
+    }
+
+    Err(VarintError::Unterminated)
+}
+
+#[cfg(test)]
+pub(crate) fn encode_varint(mut n: u128, v: &mut Vec<u8>) {
+    while n >> 7 > 0 {
+        v.push(n.to_le_bytes()[0] | 0b1000_0000);
+        n >>= 7;
+    }
+    v.push(n.to_le_bytes()[0]);
+}
+
+enum Payload {
+    Valid(Vec<u8>),
+    Invalid(Flaw),
+}
+
+struct Message {
+    flaw: Option<Flaw>,
+    edicts: Vec<Edict>,
+    fields: HashMap<u128, VecDeque<u128>>,
+}
+
+impl Message {
+    fn from_integers(output_count: usize, payload: &[u128]) -> Self {
+        let mut edicts = Vec::new();
+        let mut fields = HashMap::<u128, VecDeque<u128>>::new();
+        let mut flaw = None;
+
+        for i in (0..payload.len()).step_by(2) {
+            let tag = payload[i];
+
+            if tag == Tag::Body as u128 {
+                let mut id = RuneId::default();
+                for chunk in payload[i + 1..].chunks(4) {
+                    if chunk.len() != 4 {
+                        flaw.get_or_insert(Flaw::TrailingIntegers);
+                        break;
+                    }
+
+                    let Some(next) = id.next(chunk[0], chunk[1]) else {
+                        flaw.get_or_insert(Flaw::EdictRuneId);
+                        break;
+                    };
+
+                    // The output equals to the output count means split the runes to all non-OP_RETURN outputs
+                    let output = match u32::try_from(chunk[3]) {
+                        Ok(output) if output as usize <= output_count => output,
+                        _ => {
+                            flaw.get_or_insert(Flaw::EdictOutput);
+                            break;
+                        }
+                    };
+
+                    id = next;
+                    edicts.push(Edict {
+                        id,
+                        amount: chunk[2],
+                        output,
+                    });
+                }
+                break;
+            }
+
+            let Some(&value) = payload.get(i + 1) else {
+                flaw.get_or_insert(Flaw::TruncatedField);
+                break;
+            };
+
+            fields.entry(tag).or_default().push_back(value);
+        }
+
+        Self {
+            flaw,
+            edicts,
+            fields,
+        }
+    }
+}
+
+fn payload(tx: &Transaction) -> Option<Payload> {
+    for output in &tx.output {
+        let script = script::Script::from_bytes(&output.script_pubkey.bytes);
+        let mut instructions = script.instructions();
+
+        if instructions.next() != Some(Ok(Instruction::Op(opcodes::all::OP_RETURN))) {
+            continue;
+        }
+
+        if instructions.next() != Some(Ok(Instruction::Op(MAGIC_NUMBER))) {
+            continue;
+        }
+
+        let mut payload = Vec::new();
+        for result in instructions {
+            match result {
+                Ok(Instruction::PushBytes(push)) => payload.extend_from_slice(push.as_bytes()),
+                Ok(Instruction::Op(_)) => return Some(Payload::Invalid(Flaw::Opcode)),
+                Err(_) => return Some(Payload::Invalid(Flaw::InvalidScript)),
+            }
+        }
+
+        return Some(Payload::Valid(payload));
+    }
+
+    None
+}
+
+fn integers(payload: &[u8]) -> Result<Vec<u128>, VarintError> {
+    let mut integers = Vec::new();
+    let mut i = 0;
+
+    while i < payload.len() {
+        let (integer, length) = decode_varint(&payload[i..])?;
+        integers.push(integer);
+        i += length;
+    }
+
+    Ok(integers)
+}
+
+/// Decipher the runestone of the transaction, return None if the transaction has no runestone
+pub fn decipher(tx: &Transaction) -> Option<Runestone> {
+    let payload = match payload(tx)? {
+        Payload::Valid(payload) => payload,
+        Payload::Invalid(flaw) => return Some(Runestone::cenotaph(flaw, None, None)),
+    };
+
+    let Ok(integers) = integers(&payload) else {
+        return Some(Runestone::cenotaph(Flaw::Varint, None, None));
+    };
+
+    let Message {
+        mut flaw,
+        edicts,
+        mut fields,
+    } = Message::from_integers(tx.output.len(), &integers);
+
+    let mut flags = Tag::Flags
+        .take(&mut fields, |[flags]| Some(flags))
+        .unwrap_or_default();
+
+    let etching = Flag::Etching.take(&mut flags).then(|| Etching {
+        divisibility: Tag::Divisibility
+            .take(&mut fields, |[divisibility]| {
+                let divisibility = u8::try_from(divisibility).ok()?;
+                (divisibility <= MAX_DIVISIBILITY).then_some(divisibility)
+            })
+            .into(),
+        premine: Tag::Premine
+            .take(&mut fields, |[premine]| Some(premine))
+            .into(),
+        rune: Tag::Rune.take(&mut fields, |[rune]| Some(rune)).into(),
+        spacers: Tag::Spacers
+            .take(&mut fields, |[spacers]| {
+                let spacers = u32::try_from(spacers).ok()?;
+                (spacers <= MAX_SPACERS).then_some(spacers)
+            })
+            .into(),
+        symbol: Tag::Symbol
+            .take(&mut fields, |[symbol]| {
+                let symbol = u32::try_from(symbol).ok()?;
+                char::from_u32(symbol).map(|_| symbol)
+            })
+            .into(),
+        terms: Flag::Terms
+            .take(&mut flags)
+            .then(|| Terms {
+                cap: Tag::Cap.take(&mut fields, |[cap]| Some(cap)).into(),
+                height_start: Tag::HeightStart
+                    .take(&mut fields, |[start_height]| {
+                        u64::try_from(start_height).ok()
+                    })
+                    .into(),
+                height_end: Tag::HeightEnd
+                    .take(&mut fields, |[end_height]| u64::try_from(end_height).ok())
+                    .into(),
+                amount: Tag::Amount
+                    .take(&mut fields, |[amount]| Some(amount))
+                    .into(),
+                offset_start: Tag::OffsetStart
+                    .take(&mut fields, |[start_offset]| {
+                        u64::try_from(start_offset).ok()
+                    })
+                    .into(),
+                offset_end: Tag::OffsetEnd
+                    .take(&mut fields, |[end_offset]| u64::try_from(end_offset).ok())
+                    .into(),
+            })
+            .into(),
+        turbo: Flag::Turbo.take(&mut flags),
+    });
+
+    let mint = Tag::Mint.take(&mut fields, |[block, tx]| {
+        Some(RuneId::new(block.try_into().ok()?, tx.try_into().ok()?))
+    });
+
+    let pointer = Tag::Pointer.take(&mut fields, |[pointer]| {
+        let pointer = u32::try_from(pointer).ok()?;
+        ((pointer as usize) < tx.output.len()).then_some(pointer)
+    });
+
+    if etching
+        .as_ref()
+        .map(|etching| etching.supply().is_none())
+        .unwrap_or_default()
+    {
+        flaw.get_or_insert(Flaw::SupplyOverflow);
+    }
+
+    if flags != 0 {
+        flaw.get_or_insert(Flaw::UnrecognizedFlag);
+    }
+
+    if fields.keys().any(|tag| tag % 2 == 0) {
+        flaw.get_or_insert(Flaw::UnrecognizedEvenTag);
+    }
+
+    if let Some(flaw) = flaw {
+        return Some(Runestone::cenotaph(
+            flaw,
+            etching.and_then(|etching| etching.rune.into()),
+            mint,
+        ));
+    }
+
+    Some(Runestone {
+        edicts,
+        etching: etching.into(),
+        mint: mint.into(),
+        pointer: pointer.into(),
+        flaw: None.into(),
+    })
+}
+
+/// Check the tapscript of the witness contains the rune commitment as a data push
+pub fn tapscript_commits_to(witness: &bitcoin::Witness, commitment: &[u8]) -> bool {
+    // extracting a tapscript does not indicate that the input being spent was actually a taproot output,
+    // the caller should check the previous output is a taproot output.
+    let Some(tapscript) = witness.tapscript() else {
+        return false;
+    };
+
+    for instruction in tapscript.instructions() {
+        // ignore errors, since the extracted script may not be valid
+        let Ok(instruction) = instruction else {
+            break;
+        };
+
+        let Some(pushbytes) = instruction.push_bytes() else {
+            continue;
+        };
+
+        if pushbytes.as_bytes() == commitment {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::script::{Builder, PushBytesBuf};
+    use move_core_types::account_address::AccountAddress;
+    use rooch_types::bitcoin::types::{ScriptBuf, TxOut};
+
+    fn payload_script(payload: &[u128]) -> ScriptBuf {
+        let mut data = Vec::new();
+        for n in payload {
+            encode_varint(*n, &mut data);
+        }
+        let script = Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_opcode(MAGIC_NUMBER)
+            .push_slice(PushBytesBuf::try_from(data).unwrap())
+            .into_script();
+        script.into()
+    }
+
+    fn tx(scripts: Vec<ScriptBuf>) -> Transaction {
+        Transaction {
+            id: AccountAddress::ZERO,
+            version: 2,
+            lock_time: 0,
+            input: vec![],
+            output: scripts
+                .into_iter()
+                .map(|script_pubkey| TxOut {
+                    value: 0,
+                    script_pubkey,
+                    recipient_address: Default::default(),
+                })
+                .collect(),
+        }
+    }
+
+    fn decipher_integers(payload: &[u128]) -> Option<Runestone> {
+        decipher(&tx(vec![
+            payload_script(payload),
+            ScriptBuf {
+                bytes: vec![opcodes::all::OP_PUSHNUM_1.to_u8()],
+            },
+        ]))
+    }
+
+    #[test]
+    fn test_varint() {
+        for n in [0, 1, 127, 128, 255, 300, u64::MAX as u128, u128::MAX] {
+            let mut buffer = Vec::new();
+            encode_varint(n, &mut buffer);
+            assert_eq!(decode_varint(&buffer), Ok((n, buffer.len())));
+        }
+        assert_eq!(decode_varint(&[0x80]), Err(VarintError::Unterminated));
+        assert_eq!(decode_varint(&[0xFF; 19]), Err(VarintError::Overflow));
+        assert_eq!(decode_varint(&[0x80; 20]), Err(VarintError::Overlong));
+    }
+
+    #[test]
+    fn test_no_runestone() {
+        assert_eq!(decipher(&tx(vec![])), None);
+        let script = Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .into_script();
+        assert_eq!(decipher(&tx(vec![script.into()])), None);
+    }
+
+    #[test]
+    fn test_etching() {
+        let runestone = decipher_integers(&[
+            Tag::Flags as u128,
+            Flag::Etching.mask() | Flag::Terms.mask(),
+            Tag::Rune as u128,
+            4,
+            Tag::Divisibility as u128,
+            2,
+            Tag::Premine as u128,
+            1000,
+            Tag::Amount as u128,
+            100,
+            Tag::Cap as u128,
+            10,
+            Tag::Symbol as u128,
+            'R' as u128,
+            Tag::Body as u128,
+            0,
+            0,
+            500,
+            1,
+        ])
+        .unwrap();
+        assert!(!runestone.is_cenotaph());
+        let etching = runestone.etching.as_ref().unwrap();
+        assert_eq!(etching.rune, Some(4).into());
+        assert_eq!(etching.divisibility, Some(2).into());
+        assert_eq!(etching.premine, Some(1000).into());
+        assert_eq!(etching.symbol, Some('R' as u32).into());
+        let terms = etching.terms.as_ref().unwrap();
+        assert_eq!(terms.amount, Some(100).into());
+        assert_eq!(terms.cap, Some(10).into());
+        assert_eq!(
+            runestone.edicts,
+            vec![Edict {
+                id: RuneId::default(),
+                amount: 500,
+                output: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_mint_and_edicts() {
+        let runestone = decipher_integers(&[
+            Tag::Mint as u128,
+            840000,
+            Tag::Mint as u128,
+            1,
+            Tag::Pointer as u128,
+            1,
+            Tag::Body as u128,
+            840000,
+            1,
+            10,
+            0,
+            0,
+            2,
+            20,
+            1,
+        ])
+        .unwrap();
+        assert!(!runestone.is_cenotaph());
+        assert_eq!(runestone.mint, Some(RuneId::new(840000, 1)).into());
+        assert_eq!(runestone.pointer, Some(1).into());
+        assert_eq!(
+            runestone.edicts,
+            vec![
+                Edict {
+                    id: RuneId::new(840000, 1),
+                    amount: 10,
+                    output: 0,
+                },
+                Edict {
+                    id: RuneId::new(840000, 3),
+                    amount: 20,
+                    output: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cenotaph() {
+        // unrecognized even tag
+        let runestone = decipher_integers(&[
+            Tag::Flags as u128,
+            Flag::Etching.mask(),
+            Tag::Rune as u128,
+            4,
+            Tag::Mint as u128,
+            1,
+            Tag::Mint as u128,
+            1,
+            24,
+            1,
+        ])
+        .unwrap();
+        assert_eq!(runestone.flaw, Some(Flaw::UnrecognizedEvenTag as u8).into());
+        assert_eq!(runestone.etching.as_ref().unwrap().rune, Some(4).into());
+        assert_eq!(runestone.mint, Some(RuneId::new(1, 1)).into());
+
+        // edict output out of range
+        let runestone = decipher_integers(&[Tag::Body as u128, 1, 1, 10, 3]).unwrap();
+        assert_eq!(runestone.flaw, Some(Flaw::EdictOutput as u8).into());
+        assert!(runestone.edicts.is_empty());
+
+        // trailing integers
+        let runestone = decipher_integers(&[Tag::Body as u128, 1, 1, 10]).unwrap();
+        assert_eq!(runestone.flaw, Some(Flaw::TrailingIntegers as u8).into());
+
+        // truncated field
+        let runestone = decipher_integers(&[Tag::Flags as u128]).unwrap();
+        assert_eq!(runestone.flaw, Some(Flaw::TruncatedField as u8).into());
+
+        // unrecognized flag
+        let runestone = decipher_integers(&[Tag::Flags as u128, 1 << 3]).unwrap();
+        assert_eq!(runestone.flaw, Some(Flaw::UnrecognizedFlag as u8).into());
+
+        // supply overflow
+        let runestone = decipher_integers(&[
+            Tag::Flags as u128,
+            Flag::Etching.mask() | Flag::Terms.mask(),
+            Tag::Cap as u128,
+            2,
+            Tag::Amount as u128,
+            u128::MAX,
+        ])
+        .unwrap();
+        assert_eq!(runestone.flaw, Some(Flaw::SupplyOverflow as u8).into());
+
+        // opcode in the payload
+        let script = Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_opcode(MAGIC_NUMBER)
+            .push_opcode(opcodes::all::OP_VERIFY)
+            .into_script();
+        let runestone = decipher(&tx(vec![script.into()])).unwrap();
+        assert_eq!(runestone.flaw, Some(Flaw::Opcode as u8).into());
+
+        // unterminated varint
+        let script = Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_opcode(MAGIC_NUMBER)
+            .push_slice([0x80])
+            .into_script();
+        let runestone = decipher(&tx(vec![script.into()])).unwrap();
+        assert_eq!(runestone.flaw, Some(Flaw::Varint as u8).into());
+    }
+
+    #[test]
+    fn test_odd_tag_is_ignored() {
+        let runestone = decipher_integers(&[127, 100]).unwrap();
+        assert!(!runestone.is_cenotaph());
+    }
+
+    #[test]
+    fn test_tapscript_commits_to() {
+        let commitment = vec![1, 2, 3];
+        let tapscript = Builder::new()
+            .push_slice(PushBytesBuf::try_from(commitment.clone()).unwrap())
+            .push_opcode(opcodes::all::OP_DROP)
+            .into_script();
+        let witness = bitcoin::Witness::from_slice(&[tapscript.into_bytes(), vec![0xc0; 33]]);
+        assert!(tapscript_commits_to(&witness, &commitment));
+        assert!(!tapscript_commits_to(&witness, &[1, 2]));
+    }
+}
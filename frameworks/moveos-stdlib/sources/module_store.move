@@ -2,9 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 /// `module_store` provide object to manage packages and modules.
+///
+/// Upgrade Policy:
+/// The owner of the UpgradeCap can restrict the upgrade policy of the package, the policy can only be more restrictive:
+/// 1. Compatible: the existing modules can be upgraded with the compatibility check, and new modules can be added.
+/// 2. Additive: only new modules, structs and functions can be added, the existing definitions can not be changed.
+/// 3. Dependency-only: the modules can only be republished, no modules or definitions can be added or changed.
+/// 4. Immutable: the package can not be upgraded anymore.
+///
+/// The owner can also require a timelock for upgrades. In timelock mode, an upgrade is announced with the
+/// sha3_256 hash of the package bytes first, and can only be committed by `publish_package_entry` after the delay.
+/// The delay can only be increased, so the users of the package can rely on it.
 module moveos_std::module_store {
     use std::vector;
+    use std::option::{Self, Option};
     use std::string::String;
+    use std::hash;
     use moveos_std::core_addresses;
     use moveos_std::object::{Self, ObjectID, Object};
     use moveos_std::tx_context;
@@ -13,6 +26,7 @@ module moveos_std::module_store {
     use moveos_std::features;
     use moveos_std::bcs;
     use moveos_std::event;
+    use moveos_std::timestamp;
 
     friend moveos_std::genesis;
     
@@ -22,6 +36,33 @@ module moveos_std::module_store {
     const ErrorNoUpgradePermission: u64 = 2;
     /// Upgrade cap issued already
     const ErrorUpgradeCapIssued: u64 = 3;
+    /// The upgrade policy is invalid, or less restrictive than the current one
+    const ErrorInvalidUpgradePolicy: u64 = 4;
+    /// The package is immutable, it can not be upgraded
+    const ErrorPackageImmutable: u64 = 5;
+    /// The upgrade delay can only be increased
+    const ErrorInvalidUpgradeDelay: u64 = 6;
+    /// The package requires the timelock, but the upgrade is not announced
+    const ErrorUpgradeNotAnnounced: u64 = 7;
+    /// The published package does not match the announced upgrade
+    const ErrorUpgradeHashMismatch: u64 = 8;
+    /// The delay of the announced upgrade is not passed
+    const ErrorUpgradeTimelocked: u64 = 9;
+    /// The upgrade policy does not allow adding new modules
+    const ErrorNewModuleNotAllowed: u64 = 10;
+    /// The package does not require the timelock
+    const ErrorTimelockNotRequired: u64 = 11;
+    /// The package source is not attached
+    const ErrorPackageSourceNotFound: u64 = 12;
+
+    /// The existing modules can be upgraded with the compatibility check, and new modules can be added.
+    const UPGRADE_POLICY_COMPATIBLE: u8 = 0;
+    /// Only new modules, structs and functions can be added, the existing definitions can not be changed.
+    const UPGRADE_POLICY_ADDITIVE: u8 = 1;
+    /// The modules can only be republished, no modules or definitions can be added or changed.
+    const UPGRADE_POLICY_DEP_ONLY: u8 = 2;
+    /// The package can not be upgraded anymore.
+    const UPGRADE_POLICY_IMMUTABLE: u8 = 3;
 
     /// Allowlist for module function invocation
     struct Allowlist has key, store {
@@ -66,6 +107,56 @@ module moveos_std::module_store {
         version: u64,
     }
 
+    /// The upgrade policy of a package, it is an account named object under the package id.
+    /// The package without the UpgradePolicy uses the compatible policy without timelock.
+    struct UpgradePolicy has key {
+        policy: u8,
+        /// The delay in milliseconds between announcing and committing an upgrade, 0 means no timelock.
+        upgrade_delay: u64,
+        /// The announced upgrade waiting to be committed.
+        pending_upgrade: Option<PendingUpgrade>,
+    }
+
+    /// An announced upgrade in timelock mode.
+    struct PendingUpgrade has store, copy, drop {
+        /// The sha3_256 hash of the package bytes passed to `publish_package_entry`.
+        package_hash: vector<u8>,
+        /// The upgrade can be committed after this timestamp in milliseconds.
+        commit_after: u64,
+    }
+
+    /// Event for upgrade policy changes.
+    struct UpgradePolicyEvent has drop, store, copy {
+        package_id: address,
+        policy: u8,
+        upgrade_delay: u64,
+    }
+
+    /// Event for announced upgrades in timelock mode.
+    struct UpgradeAnnouncedEvent has drop, store, copy {
+        package_id: address,
+        package_hash: vector<u8>,
+        commit_after: u64,
+    }
+
+    /// The source information of a package, it is an account named object under the package id.
+    /// Explorers and auditors can rebuild the package from the source, and compare it with the on-chain modules.
+    struct PackageSource has key {
+        /// The package version the source is attached to, the source is outdated if the package is upgraded later.
+        version: u64,
+        /// The digest of the package source files and manifest, computed by the Move package system.
+        source_digest: vector<u8>,
+        /// The build metadata, eg. the compiler version and settings, in JSON format.
+        build_metadata: String,
+    }
+
+    /// Event for attaching the package source.
+    struct PackageSourceEvent has drop, store, copy {
+        package_id: address,
+        version: u64,
+        source_digest: vector<u8>,
+    }
+
     public fun module_store_id(): ObjectID {
         object::named_object_id<ModuleStore>()
     }
@@ -140,7 +231,21 @@ module moveos_std::module_store {
     /// Entry function to publish package
     /// The order of modules must be sorted by dependency order.
     public entry fun publish_package_entry(account: &signer, package_bytes: vector<u8>) {
+        publish_package(account, package_bytes);
+    }
+
+    /// Entry function to publish package, and attach the source digest and build metadata to the package.
+    public entry fun publish_package_with_source_entry(
+        account: &signer, package_bytes: vector<u8>, source_digest: vector<u8>, build_metadata: String
+    ) {
+        let package_id = publish_package(account, package_bytes);
+        attach_package_source_internal(package_id, source_digest, build_metadata);
+    }
+
+    /// Publish package and return the package id
+    fun publish_package(account: &signer, package_bytes: vector<u8>): address {
         let sender_address = signer::address_of(account);
+        let package_hash = hash::sha3_256(package_bytes);
         let package_data = bcs::from_bytes<PackageData>(package_bytes);
 
         // check if the package id is in allowlist
@@ -161,7 +266,9 @@ module moveos_std::module_store {
             }
         } else {
             assert!(has_upgrade_permission(package_data.package_id, sender_address), ErrorNoUpgradePermission);
+            commit_upgrade(package_data.package_id, package_hash);
         };
+        let policy = upgrade_policy(package_data.package_id);
 
 
         // convert module bytes to MoveModule
@@ -177,10 +284,11 @@ module moveos_std::module_store {
 
 
         let module_store = borrow_mut_module_store(); 
-        let upgrade_flag = publish_modules_internal(module_store, package_data.package_id, module_vec);
+        let upgrade_flag = publish_modules_with_policy(module_store, package_data.package_id, module_vec, policy);
         // Store ModuleUpgradeFlag in tx_context which will be fetched in VM in Rust, 
         // and then announce to the VM that the code loading cache should be considered outdated. 
         tx_context::set_module_upgrade_flag(upgrade_flag);
+        package_data.package_id
     }
 
     public fun package_version(package_id: address): u64 {
@@ -194,6 +302,12 @@ module moveos_std::module_store {
     /// Return true if the modules are upgraded
     public(friend) fun publish_modules_internal(
         module_store_object: &mut Object<ModuleStore>, package_id: address, modules: vector<MoveModule>
+    ) : bool {
+        publish_modules_with_policy(module_store_object, package_id, modules, UPGRADE_POLICY_COMPATIBLE)
+    }
+
+    fun publish_modules_with_policy(
+        module_store_object: &mut Object<ModuleStore>, package_id: address, modules: vector<MoveModule>, policy: u8
     ) : bool {
         let i = 0;
         let len = vector::length(&modules);
@@ -218,8 +332,13 @@ module moveos_std::module_store {
             // The module already exists, which means we are upgrading the module
             if (object::contains_field(package, module_name)) {
                 let old_m = remove_module(package, module_name);
-                move_module::check_comatibility(m, &old_m);
+                if (policy == UPGRADE_POLICY_COMPATIBLE) {
+                    move_module::check_comatibility(m, &old_m);
+                } else {
+                    move_module::check_upgrade_policy(m, &old_m, policy);
+                };
             } else {
+                assert!(!is_upgrade || policy < UPGRADE_POLICY_DEP_ONLY, ErrorNewModuleNotAllowed);
                 // request init function invoking
                 if (vector::contains(&module_names_with_init_fn, &module_name)) {
                     let module_id = move_module::module_id_from_name(package_id, module_name);
@@ -283,6 +402,224 @@ module moveos_std::module_store {
         object::to_frozen(package);
     }
 
+    /************************ upgrade policy functions *************************/
+
+    public fun upgrade_policy_compatible(): u8 {
+        UPGRADE_POLICY_COMPATIBLE
+    }
+
+    public fun upgrade_policy_additive(): u8 {
+        UPGRADE_POLICY_ADDITIVE
+    }
+
+    public fun upgrade_policy_dep_only(): u8 {
+        UPGRADE_POLICY_DEP_ONLY
+    }
+
+    public fun upgrade_policy_immutable(): u8 {
+        UPGRADE_POLICY_IMMUTABLE
+    }
+
+    fun upgrade_policy_id(package_id: address): ObjectID {
+        object::account_named_object_id<UpgradePolicy>(package_id)
+    }
+
+    fun borrow_mut_or_init_upgrade_policy(package_id: address): &mut UpgradePolicy {
+        let id = upgrade_policy_id(package_id);
+        if (!object::exists_object(id)) {
+            let policy_obj = object::new_account_named_object<UpgradePolicy>(package_id, UpgradePolicy {
+                policy: UPGRADE_POLICY_COMPATIBLE,
+                upgrade_delay: 0,
+                pending_upgrade: option::none(),
+            });
+            object::transfer_extend(policy_obj, package_id);
+        };
+        let policy_obj = object::borrow_mut_object_extend<UpgradePolicy>(id);
+        object::borrow_mut(policy_obj)
+    }
+
+    /// Get the upgrade policy of the package
+    public fun upgrade_policy(package_id: address): u8 {
+        let id = upgrade_policy_id(package_id);
+        if (!object::exists_object(id)) {
+            return UPGRADE_POLICY_COMPATIBLE
+        };
+        object::borrow(object::borrow_object<UpgradePolicy>(id)).policy
+    }
+
+    /// Get the upgrade delay in milliseconds of the package, 0 means no timelock.
+    public fun upgrade_delay(package_id: address): u64 {
+        let id = upgrade_policy_id(package_id);
+        if (!object::exists_object(id)) {
+            return 0
+        };
+        object::borrow(object::borrow_object<UpgradePolicy>(id)).upgrade_delay
+    }
+
+    /// Get the announced upgrade of the package in timelock mode.
+    public fun pending_upgrade(package_id: address): Option<PendingUpgrade> {
+        let id = upgrade_policy_id(package_id);
+        if (!object::exists_object(id)) {
+            return option::none()
+        };
+        object::borrow(object::borrow_object<UpgradePolicy>(id)).pending_upgrade
+    }
+
+    public fun pending_upgrade_hash(self: &PendingUpgrade): vector<u8> {
+        self.package_hash
+    }
+
+    public fun pending_upgrade_commit_after(self: &PendingUpgrade): u64 {
+        self.commit_after
+    }
+
+    /// Restrict the upgrade policy of the package, the policy can only be more restrictive.
+    public fun restrict_upgrade_policy(account: &signer, package_id: address, policy: u8) {
+        ensure_upgrade_permission(package_id, account);
+        assert!(policy <= UPGRADE_POLICY_IMMUTABLE, ErrorInvalidUpgradePolicy);
+        let upgrade_policy = borrow_mut_or_init_upgrade_policy(package_id);
+        assert!(policy >= upgrade_policy.policy, ErrorInvalidUpgradePolicy);
+        upgrade_policy.policy = policy;
+        emit_upgrade_policy_event(package_id, upgrade_policy);
+    }
+
+    public entry fun restrict_upgrade_policy_entry(account: &signer, package_id: address, policy: u8) {
+        restrict_upgrade_policy(account, package_id, policy);
+    }
+
+    /// Require the timelock for the package upgrades, the delay in milliseconds can only be increased.
+    public fun set_upgrade_delay(account: &signer, package_id: address, upgrade_delay: u64) {
+        ensure_upgrade_permission(package_id, account);
+        let upgrade_policy = borrow_mut_or_init_upgrade_policy(package_id);
+        assert!(upgrade_delay >= upgrade_policy.upgrade_delay, ErrorInvalidUpgradeDelay);
+        upgrade_policy.upgrade_delay = upgrade_delay;
+        emit_upgrade_policy_event(package_id, upgrade_policy);
+    }
+
+    public entry fun set_upgrade_delay_entry(account: &signer, package_id: address, upgrade_delay: u64) {
+        set_upgrade_delay(account, package_id, upgrade_delay);
+    }
+
+    /// Announce an upgrade in timelock mode, the `package_hash` is the sha3_256 hash of the package bytes
+    /// which will be passed to `publish_package_entry`. A new announcement replaces the pending one.
+    public fun announce_upgrade(account: &signer, package_id: address, package_hash: vector<u8>) {
+        ensure_upgrade_permission(package_id, account);
+        let upgrade_policy = borrow_mut_or_init_upgrade_policy(package_id);
+        assert!(upgrade_policy.policy != UPGRADE_POLICY_IMMUTABLE, ErrorPackageImmutable);
+        assert!(upgrade_policy.upgrade_delay > 0, ErrorTimelockNotRequired);
+        let commit_after = timestamp::now_milliseconds() + upgrade_policy.upgrade_delay;
+        upgrade_policy.pending_upgrade = option::some(PendingUpgrade { package_hash, commit_after });
+        event::emit(UpgradeAnnouncedEvent { package_id, package_hash, commit_after });
+    }
+
+    public entry fun announce_upgrade_entry(account: &signer, package_id: address, package_hash: vector<u8>) {
+        announce_upgrade(account, package_id, package_hash);
+    }
+
+    /// Cancel the announced upgrade.
+    public fun cancel_upgrade(account: &signer, package_id: address) {
+        ensure_upgrade_permission(package_id, account);
+        let upgrade_policy = borrow_mut_or_init_upgrade_policy(package_id);
+        upgrade_policy.pending_upgrade = option::none();
+    }
+
+    public entry fun cancel_upgrade_entry(account: &signer, package_id: address) {
+        cancel_upgrade(account, package_id);
+    }
+
+    /// Check the upgrade is allowed by the policy, and consume the announced upgrade in timelock mode.
+    fun commit_upgrade(package_id: address, package_hash: vector<u8>) {
+        let id = upgrade_policy_id(package_id);
+        if (!object::exists_object(id)) {
+            return
+        };
+        let policy_obj = object::borrow_mut_object_extend<UpgradePolicy>(id);
+        let upgrade_policy = object::borrow_mut(policy_obj);
+        assert!(upgrade_policy.policy != UPGRADE_POLICY_IMMUTABLE, ErrorPackageImmutable);
+        if (upgrade_policy.upgrade_delay == 0) {
+            return
+        };
+        assert!(option::is_some(&upgrade_policy.pending_upgrade), ErrorUpgradeNotAnnounced);
+        let PendingUpgrade { package_hash: announced_hash, commit_after } = option::extract(&mut upgrade_policy.pending_upgrade);
+        assert!(announced_hash == package_hash, ErrorUpgradeHashMismatch);
+        assert!(timestamp::now_milliseconds() >= commit_after, ErrorUpgradeTimelocked);
+    }
+
+    fun emit_upgrade_policy_event(package_id: address, upgrade_policy: &UpgradePolicy) {
+        event::emit(UpgradePolicyEvent {
+            package_id,
+            policy: upgrade_policy.policy,
+            upgrade_delay: upgrade_policy.upgrade_delay,
+        });
+    }
+
+    /************************ package source functions *************************/
+
+    fun package_source_id(package_id: address): ObjectID {
+        object::account_named_object_id<PackageSource>(package_id)
+    }
+
+    /// Attach the source digest and build metadata to the current version of the package.
+    public fun attach_package_source(
+        account: &signer, package_id: address, source_digest: vector<u8>, build_metadata: String
+    ) {
+        ensure_upgrade_permission(package_id, account);
+        attach_package_source_internal(package_id, source_digest, build_metadata);
+    }
+
+    public entry fun attach_package_source_entry(
+        account: &signer, package_id: address, source_digest: vector<u8>, build_metadata: String
+    ) {
+        attach_package_source(account, package_id, source_digest, build_metadata);
+    }
+
+    fun attach_package_source_internal(package_id: address, source_digest: vector<u8>, build_metadata: String) {
+        let version = package_version(package_id);
+        let id = package_source_id(package_id);
+        if (object::exists_object(id)) {
+            let source_obj = object::borrow_mut_object_extend<PackageSource>(id);
+            let source = object::borrow_mut(source_obj);
+            source.version = version;
+            source.source_digest = source_digest;
+            source.build_metadata = build_metadata;
+        } else {
+            let source_obj = object::new_account_named_object<PackageSource>(package_id, PackageSource {
+                version,
+                source_digest,
+                build_metadata,
+            });
+            object::transfer_extend(source_obj, package_id);
+        };
+        event::emit(PackageSourceEvent { package_id, version, source_digest });
+    }
+
+    public fun exists_package_source(package_id: address): bool {
+        object::exists_object(package_source_id(package_id))
+    }
+
+    public fun borrow_package_source(package_id: address): &PackageSource {
+        let id = package_source_id(package_id);
+        assert!(object::exists_object(id), ErrorPackageSourceNotFound);
+        object::borrow(object::borrow_object<PackageSource>(id))
+    }
+
+    public fun source_version(self: &PackageSource): u64 {
+        self.version
+    }
+
+    public fun source_digest(self: &PackageSource): vector<u8> {
+        self.source_digest
+    }
+
+    public fun source_build_metadata(self: &PackageSource): String {
+        self.build_metadata
+    }
+
+    /// Check the attached source is for the current version of the package.
+    public fun is_package_source_up_to_date(package_id: address): bool {
+        exists_package_source(package_id) && borrow_package_source(package_id).version == package_version(package_id)
+    }
+
     /************************ allowlist functions *************************/
 
     /// Add a package id to the allowlist. Only package id in allowlist can publish modules.
@@ -436,5 +773,136 @@ module moveos_std::module_store {
         freeze_package(package);
         Self::publish_package_entry(account, args);
     }
-}
 
+    #[test_only]
+    fun publish_counter_for_test(account: &signer): vector<u8> {
+        init_module_store();
+        features::init_feature_store_for_test();
+        let pkg_data = PackageData {
+            package_name: std::string::utf8(b"counter"),
+            package_id: @0x42,
+            modules: vector::singleton(COUNTER_MV_BYTES),
+        };
+        let args = bcs::to_bytes(&pkg_data);
+        Self::publish_package_entry(account, args);
+        args
+    }
+
+    #[test(account=@0x42)]
+    #[expected_failure(abort_code = ErrorPackageImmutable, location = Self)]
+    fun test_restrict_upgrade_policy(account: &signer) {
+        let args = publish_counter_for_test(account);
+        assert!(upgrade_policy(@0x42) == UPGRADE_POLICY_COMPATIBLE, 1);
+
+        restrict_upgrade_policy(account, @0x42, UPGRADE_POLICY_ADDITIVE);
+        assert!(upgrade_policy(@0x42) == UPGRADE_POLICY_ADDITIVE, 2);
+        // The unchanged modules can be republished
+        Self::publish_package_entry(account, args);
+
+        restrict_upgrade_policy(account, @0x42, UPGRADE_POLICY_DEP_ONLY);
+        Self::publish_package_entry(account, args);
+
+        restrict_upgrade_policy(account, @0x42, UPGRADE_POLICY_IMMUTABLE);
+        Self::publish_package_entry(account, args);
+    }
+
+    #[test(account=@0x42)]
+    #[expected_failure(abort_code = ErrorInvalidUpgradePolicy, location = Self)]
+    fun test_relax_upgrade_policy(account: &signer) {
+        publish_counter_for_test(account);
+        restrict_upgrade_policy(account, @0x42, UPGRADE_POLICY_DEP_ONLY);
+        restrict_upgrade_policy(account, @0x42, UPGRADE_POLICY_ADDITIVE);
+    }
+
+    #[test(account=@0x42, other=@0x43)]
+    #[expected_failure(abort_code = ErrorNoUpgradePermission, location = Self)]
+    fun test_restrict_upgrade_policy_without_permission(account: &signer, other: &signer) {
+        publish_counter_for_test(account);
+        restrict_upgrade_policy(other, @0x42, UPGRADE_POLICY_IMMUTABLE);
+    }
+
+    #[test(account=@0x42)]
+    fun test_timelock_upgrade(account: &signer) {
+        let args = publish_counter_for_test(account);
+        set_upgrade_delay(account, @0x42, 1000);
+        assert!(upgrade_delay(@0x42) == 1000, 1);
+
+        announce_upgrade(account, @0x42, hash::sha3_256(args));
+        let pending = option::destroy_some(pending_upgrade(@0x42));
+        assert!(pending_upgrade_commit_after(&pending) == timestamp::now_milliseconds() + 1000, 2);
+
+        timestamp::fast_forward_milliseconds_for_test(1000);
+        Self::publish_package_entry(account, args);
+        assert!(package_version(@0x42) == 2, 3);
+        assert!(option::is_none(&pending_upgrade(@0x42)), 4);
+    }
+
+    #[test(account=@0x42)]
+    #[expected_failure(abort_code = ErrorUpgradeTimelocked, location = Self)]
+    fun test_timelock_upgrade_before_delay(account: &signer) {
+        let args = publish_counter_for_test(account);
+        set_upgrade_delay(account, @0x42, 1000);
+        announce_upgrade(account, @0x42, hash::sha3_256(args));
+        timestamp::fast_forward_milliseconds_for_test(999);
+        Self::publish_package_entry(account, args);
+    }
+
+    #[test(account=@0x42)]
+    #[expected_failure(abort_code = ErrorUpgradeNotAnnounced, location = Self)]
+    fun test_timelock_upgrade_without_announcement(account: &signer) {
+        let args = publish_counter_for_test(account);
+        set_upgrade_delay(account, @0x42, 1000);
+        Self::publish_package_entry(account, args);
+    }
+
+    #[test(account=@0x42)]
+    #[expected_failure(abort_code = ErrorUpgradeHashMismatch, location = Self)]
+    fun test_timelock_upgrade_with_other_package(account: &signer) {
+        let args = publish_counter_for_test(account);
+        set_upgrade_delay(account, @0x42, 1000);
+        announce_upgrade(account, @0x42, hash::sha3_256(b"other package"));
+        timestamp::fast_forward_milliseconds_for_test(1000);
+        Self::publish_package_entry(account, args);
+    }
+
+    #[test(account=@0x42)]
+    #[expected_failure(abort_code = ErrorInvalidUpgradeDelay, location = Self)]
+    fun test_decrease_upgrade_delay(account: &signer) {
+        publish_counter_for_test(account);
+        set_upgrade_delay(account, @0x42, 1000);
+        set_upgrade_delay(account, @0x42, 999);
+    }
+
+    #[test(account=@0x42)]
+    fun test_publish_package_with_source(account: &signer) {
+        init_module_store();
+        features::init_feature_store_for_test();
+        let pkg_data = PackageData {
+            package_name: std::string::utf8(b"counter"),
+            package_id: @0x42,
+            modules: vector::singleton(COUNTER_MV_BYTES),
+        };
+        let args = bcs::to_bytes(&pkg_data);
+        let build_metadata = std::string::utf8(b"{}");
+        Self::publish_package_with_source_entry(account, args, x"1234", build_metadata);
+        assert!(is_package_source_up_to_date(@0x42), 1);
+        let source = borrow_package_source(@0x42);
+        assert!(source_version(source) == 1, 2);
+        assert!(source_digest(source) == x"1234", 3);
+
+        // The source is outdated after upgrading without source
+        Self::publish_package_entry(account, args);
+        assert!(!is_package_source_up_to_date(@0x42), 4);
+
+        attach_package_source(account, @0x42, x"5678", build_metadata);
+        assert!(is_package_source_up_to_date(@0x42), 5);
+        assert!(source_digest(borrow_package_source(@0x42)) == x"5678", 6);
+    }
+
+    #[test(account=@0x42, other=@0x43)]
+    #[expected_failure(abort_code = ErrorNoUpgradePermission, location = Self)]
+    fun test_attach_package_source_without_permission(account: &signer, other: &signer) {
+        publish_counter_for_test(account);
+        attach_package_source(other, @0x42, x"1234", std::string::utf8(b"{}"));
+    }
+}
@@ -87,6 +87,12 @@ module moveos_std::move_module {
         check_compatibililty_inner(new_module.byte_codes, old_module.byte_codes);
     }
 
+    /// Check module upgrade with the package upgrade policy, see `moveos_std::module_store` for the policies.
+    /// Abort if the new module is not compatible with the old module, or breaks the policy.
+    public fun check_upgrade_policy(new_module: &MoveModule, old_module: &MoveModule, policy: u8) {
+        check_upgrade_policy_inner(new_module.byte_codes, old_module.byte_codes, policy);
+    }
+
     // TODO: add more tests
     /// Binding given module's address to the new address
     public fun binding_module_address(
@@ -249,6 +255,10 @@ module moveos_std::move_module {
 
     native fun check_compatibililty_inner(new_bytecodes: vector<u8>, old_bytecodes: vector<u8>);
 
+    /// Check the module compatibility, and the definitions of the old module are unchanged
+    /// for the additive and dependency-only policy.
+    native fun check_upgrade_policy_inner(new_bytecodes: vector<u8>, old_bytecodes: vector<u8>, policy: u8);
+
     /// Native function to replace addresses identifier in module binary where the length of
     /// `old_addresses` must equal to that of `new_addresses`.  
     native public(friend) fun replace_address_identifiers(
@@ -0,0 +1,38 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// The storage deposit of each object is kept in a `StorageDepositRecord` object, outside of the `ObjectMeta`.
+/// The records are created, updated and removed by the VM when it settles the storage deposit of a transaction.
+/// Objects created before the storage deposit was introduced have no record, they are exempt from the deposit.
+module moveos_std::storage_deposit {
+
+    use moveos_std::object::{Self, ObjectID};
+
+    /// The storage deposit record of an object.
+    /// The record is a root object, its id is `object::custom_object_id<ObjectID, StorageDepositRecord>(object_id)`.
+    struct StorageDepositRecord has key {
+        /// The deposit paid for the value bytes of the object.
+        deposit: u64,
+    }
+
+    /// Get the id of the storage deposit record of the object.
+    public fun record_id(object_id: ObjectID): ObjectID {
+        object::custom_object_id<ObjectID, StorageDepositRecord>(object_id)
+    }
+
+    /// Check if the object has a storage deposit record.
+    public fun has_record(object_id: ObjectID): bool {
+        object::exists_object_with_type<StorageDepositRecord>(record_id(object_id))
+    }
+
+    /// Get the storage deposit paid for the object, return 0 if the object has no record.
+    public fun deposit_of(object_id: ObjectID): u64 {
+        let record_id = record_id(object_id);
+        if (object::exists_object_with_type<StorageDepositRecord>(record_id)) {
+            let record = object::borrow(object::borrow_object<StorageDepositRecord>(record_id));
+            record.deposit
+        } else {
+            0
+        }
+    }
+}
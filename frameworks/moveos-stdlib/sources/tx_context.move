@@ -17,7 +17,7 @@ module moveos_std::tx_context {
     use moveos_std::copyable_any::{Self, Any};
     use moveos_std::type_info;
     use moveos_std::tx_meta::{TxMeta};
-    use moveos_std::tx_result::{TxResult};
+    use moveos_std::tx_result::{TxResult, StorageDeposit};
 
     friend moveos_std::object;
     friend moveos_std::account;
@@ -52,6 +52,13 @@ module moveos_std::tx_context {
         is_upgrade: bool,
     }
 
+    /// The account which pays the gas of the transaction if it is not the sender.
+    /// It is added to the context by the VM, eg. the sponsor of the sponsored transaction.
+    struct GasPaymentAccount has copy, drop, store {
+        account: address,
+        pay_gas_by_module_account: bool,
+    }
+
     /// Return the address of the user that signed the current transaction
     public fun sender(): address {
         borrow().sender
@@ -147,11 +154,15 @@ module moveos_std::tx_context {
     }
 
     /// Get the gas payment account of the transaction
-    /// Currently, the gas payment account is the sender of the transaction.
-    /// In the future, the gas payment account may be different from the sender.
+    /// The gas payment account is the sender of the transaction, unless the `GasPaymentAccount` is in the context.
     public fun tx_gas_payment_account(): address {
         let ctx = borrow();
-        ctx.sender
+        let gas_payment_account = get<GasPaymentAccount>(ctx);
+        if (option::is_some(&gas_payment_account)) {
+            option::destroy_some(gas_payment_account).account
+        } else {
+            ctx.sender
+        }
     }
 
     /// The result is only available in the `post_execute` function.
@@ -162,6 +173,13 @@ module moveos_std::tx_context {
         option::extract(&mut result)
     }
 
+    /// The storage deposit settled in the transaction, it is only available in the `post_execute` function.
+    /// Returns none if the storage deposit is not settled, eg. the execution failed.
+    public fun tx_storage_deposit(): Option<StorageDeposit> {
+        let ctx = borrow();
+        get<StorageDeposit>(ctx)
+    }
+
     /// Check if the current transaction is a system call
     /// The system call is a special transaction initiated by the system.
     public fun is_system_call(): bool {
@@ -20,4 +20,21 @@ module moveos_std::tx_result {
     public fun gas_used(self: &TxResult) : u64 {
         self.gas_used
     }
+
+    /// The storage deposit settled for the objects changed by the transaction.
+    /// The VM will put this struct in the TxContext after the action execution, it is absent if the execution failed.
+    struct StorageDeposit has copy, store, drop {
+        /// The deposit charged for the new or grown objects, it is included in the gas used.
+        deposit: u64,
+        /// The deposit refunded for the shrunk or removed objects.
+        rebate: u64,
+    }
+
+    public fun deposit(self: &StorageDeposit) : u64 {
+        self.deposit
+    }
+
+    public fun rebate(self: &StorageDeposit) : u64 {
+        self.rebate
+    }
 }
\ No newline at end of file
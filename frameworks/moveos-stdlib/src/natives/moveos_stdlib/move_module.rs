@@ -5,8 +5,13 @@ use crate::natives::helpers::{make_module_natives, make_native};
 use better_any::{Tid, TidAble};
 use itertools::zip_eq;
 use move_binary_format::{
+    access::ModuleAccess,
     compatibility::Compatibility,
     errors::{PartialVMError, PartialVMResult},
+    file_format::{
+        Bytecode, FunctionDefinition, FunctionHandleIndex, SignatureIndex, StructDefinitionIndex,
+        StructFieldInformation,
+    },
     normalized, CompiledModule,
 };
 use move_core_types::u256::U256;
@@ -41,6 +46,11 @@ const E_MODULE_VERIFICATION_ERROR: u64 = 2;
 const E_MODULE_INCOMPATIBLE: u64 = 3;
 const E_LENTH_NOT_MATCH: u64 = 4;
 
+/// The upgrade policies, keep the same with `moveos_std::module_store`
+const UPGRADE_POLICY_COMPATIBLE: u8 = 0;
+const UPGRADE_POLICY_ADDITIVE: u8 = 1;
+const UPGRADE_POLICY_DEP_ONLY: u8 = 2;
+
 /// The native module context.
 #[derive(Tid)]
 pub struct NativeModuleContext<'a> {
@@ -297,32 +307,306 @@ fn check_compatibililty_inner(
     mut args: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
     let mut cost = gas_params.base;
+    let old_bytecodes = pop_arg!(args, Vec<u8>);
+    let new_bytecodes = pop_arg!(args, Vec<u8>);
+    cost += gas_params.per_byte * NumBytes::new(new_bytecodes.len() as u64);
+    cost += gas_params.per_byte * NumBytes::new(old_bytecodes.len() as u64);
+    let new_module = CompiledModule::deserialize(&new_bytecodes)?;
+    let old_module = CompiledModule::deserialize(&old_bytecodes)?;
+
+    match check_module_compatibility(&old_module, &new_module) {
+        Ok(_) => Ok(NativeResult::ok(cost, smallvec![])),
+        Err(code) => Ok(NativeResult::err(cost, code)),
+    }
+}
+
+fn check_module_compatibility(
+    old_module: &CompiledModule,
+    new_module: &CompiledModule,
+) -> Result<(), u64> {
     // TODO: config compatibility through global configuration
     // We allow `friend` function to be broken
     let compat = Compatibility::new(true, true, false);
     if compat.need_check_compat() {
-        let old_bytecodes = pop_arg!(args, Vec<u8>);
-        let new_bytecodes = pop_arg!(args, Vec<u8>);
-        cost += gas_params.per_byte * NumBytes::new(new_bytecodes.len() as u64);
-        cost += gas_params.per_byte * NumBytes::new(old_bytecodes.len() as u64);
-        let new_module = CompiledModule::deserialize(&new_bytecodes)?;
-        let old_module = CompiledModule::deserialize(&old_bytecodes)?;
-        let new_m = normalized::Module::new(&new_module);
-        let old_m = normalized::Module::new(&old_module);
-
-        match compat.check(&old_m, &new_m) {
-            Ok(_) => {}
-            Err(_) => return Ok(NativeResult::err(cost, E_MODULE_INCOMPATIBLE)),
+        let new_m = normalized::Module::new(new_module);
+        let old_m = normalized::Module::new(old_module);
+
+        if compat.check(&old_m, &new_m).is_err() {
+            return Err(E_MODULE_INCOMPATIBLE);
         }
 
-        match check_metadata_compatibility(&old_module, &new_module) {
-            Ok(_) => {}
-            Err(e) => return Ok(NativeResult::err(cost, e.sub_status().unwrap_or(0))),
+        if let Err(e) = check_metadata_compatibility(old_module, new_module) {
+            return Err(e.sub_status().unwrap_or(0));
+        }
+    }
+    Ok(())
+}
+
+/***************************************************************************************************
+ * native fun check_upgrade_policy_inner(
+ *      new_bytecodes: vector<u8>,
+ *      old_bytecodes: vector<u8>,
+ *      policy: u8,
+ * );
+ * Check the module upgrade with the package upgrade policy,
+ * Abort if the new module breaks the policy.
+ **************************************************************************************************/
+
+#[derive(Debug, Clone)]
+pub struct CheckUpgradePolicyInnerGasParametersOption {
+    pub base: Option<InternalGas>,
+    pub per_byte: Option<InternalGasPerByte>,
+}
+
+impl CheckUpgradePolicyInnerGasParametersOption {
+    pub fn zeros() -> Self {
+        Self {
+            base: Some(0.into()),
+            per_byte: Some(0.into()),
         }
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none() || self.per_byte.is_none()
+    }
+}
+
+fn check_upgrade_policy_inner(
+    gas_params: &CheckUpgradePolicyInnerGasParametersOption,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let gas_base = gas_params.base.expect("base gas is missing");
+    let per_byte = gas_params.per_byte.expect("per byte gas is missing");
+
+    let policy = pop_arg!(args, u8);
+    let old_bytecodes = pop_arg!(args, Vec<u8>);
+    let new_bytecodes = pop_arg!(args, Vec<u8>);
+    let cost = gas_base
+        + per_byte * NumBytes::new(new_bytecodes.len() as u64)
+        + per_byte * NumBytes::new(old_bytecodes.len() as u64);
+    let new_module = CompiledModule::deserialize(&new_bytecodes)?;
+    let old_module = CompiledModule::deserialize(&old_bytecodes)?;
+
+    if let Err(code) = check_module_compatibility(&old_module, &new_module) {
+        return Ok(NativeResult::err(cost, code));
+    }
+    let allow_new_definitions = match policy {
+        UPGRADE_POLICY_COMPATIBLE => return Ok(NativeResult::ok(cost, smallvec![])),
+        UPGRADE_POLICY_ADDITIVE => true,
+        UPGRADE_POLICY_DEP_ONLY => false,
+        // The immutable package can not be upgraded at all
+        _ => return Ok(NativeResult::err(cost, E_MODULE_INCOMPATIBLE)),
+    };
+    if !check_definitions_unchanged(&old_module, &new_module, allow_new_definitions) {
+        return Ok(NativeResult::err(cost, E_MODULE_INCOMPATIBLE));
+    }
     Ok(NativeResult::ok(cost, smallvec![]))
 }
 
+/// Check all the friends, structs and functions of the old module are kept unchanged in the new module,
+/// including the function bodies.
+/// If `allow_new_definitions` is false, the new module can not add friends, structs or functions either.
+fn check_definitions_unchanged(
+    old_module: &CompiledModule,
+    new_module: &CompiledModule,
+    allow_new_definitions: bool,
+) -> bool {
+    let old_friends = old_module.immediate_friends();
+    let new_friends = new_module.immediate_friends();
+    if !old_friends
+        .iter()
+        .all(|friend| new_friends.contains(friend))
+    {
+        return false;
+    }
+
+    let old_structs = struct_definitions(old_module);
+    let new_structs = struct_definitions(new_module);
+    if !old_structs
+        .iter()
+        .all(|(name, def)| new_structs.get(name) == Some(def))
+    {
+        return false;
+    }
+
+    let old_functions = function_definitions(old_module);
+    let new_functions = function_definitions(new_module);
+    if !old_functions
+        .iter()
+        .all(|(name, def)| new_functions.get(name) == Some(def))
+    {
+        return false;
+    }
+
+    allow_new_definitions
+        || (old_friends.len() == new_friends.len()
+            && old_structs.len() == new_structs.len()
+            && old_functions.len() == new_functions.len())
+}
+
+fn struct_definitions(module: &CompiledModule) -> HashMap<String, String> {
+    module
+        .struct_defs()
+        .iter()
+        .map(|def| {
+            let handle = module.struct_handle_at(def.struct_handle);
+            let fields = match &def.field_information {
+                StructFieldInformation::Native => None,
+                StructFieldInformation::Declared(fields) => Some(
+                    fields
+                        .iter()
+                        .map(|field| {
+                            (
+                                module.identifier_at(field.name).to_string(),
+                                normalized::Type::new(module, &field.signature.0),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+            };
+            (
+                module.identifier_at(handle.name).to_string(),
+                format!(
+                    "{:?} {:?} {:?}",
+                    handle.abilities, handle.type_parameters, fields
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Describe the functions with the names instead of the indices of the module pools,
+/// so the same function compiled into the old and new modules gets the same description.
+fn function_definitions(module: &CompiledModule) -> HashMap<String, String> {
+    module
+        .function_defs()
+        .iter()
+        .map(|def| {
+            let (name, function) = normalized::Function::new(module, def);
+            let acquires = def
+                .acquires_global_resources
+                .iter()
+                .map(|idx| struct_name(module, *idx))
+                .collect::<Vec<_>>();
+            (
+                name.to_string(),
+                format!(
+                    "{:?} {:?} {:?}",
+                    function,
+                    acquires,
+                    function_body(module, def)
+                ),
+            )
+        })
+        .collect()
+}
+
+fn function_body(
+    module: &CompiledModule,
+    def: &FunctionDefinition,
+) -> Option<(Vec<normalized::Type>, Vec<String>)> {
+    def.code.as_ref().map(|code| {
+        let locals = signature_types(module, code.locals);
+        let instructions = code
+            .code
+            .iter()
+            .map(|instruction| describe_bytecode(module, instruction))
+            .collect();
+        (locals, instructions)
+    })
+}
+
+fn describe_bytecode(module: &CompiledModule, instruction: &Bytecode) -> String {
+    use Bytecode::*;
+    // The opcode name without the operands, eg. `Pack` of `Pack(StructDefinitionIndex(0))`
+    let debug = format!("{:?}", instruction);
+    let opcode = debug.split('(').next().unwrap_or_default();
+    match instruction {
+        LdConst(idx) => format!("{}({:?})", opcode, module.constant_at(*idx)),
+        Call(idx) => format!("{}({})", opcode, function_name(module, *idx)),
+        CallGeneric(idx) => {
+            let inst = module.function_instantiation_at(*idx);
+            format!(
+                "{}({}, {:?})",
+                opcode,
+                function_name(module, inst.handle),
+                signature_types(module, inst.type_parameters)
+            )
+        }
+        Pack(idx) | Unpack(idx) | MutBorrowGlobal(idx) | ImmBorrowGlobal(idx) | Exists(idx)
+        | MoveFrom(idx) | MoveTo(idx) => format!("{}({})", opcode, struct_name(module, *idx)),
+        PackGeneric(idx)
+        | UnpackGeneric(idx)
+        | MutBorrowGlobalGeneric(idx)
+        | ImmBorrowGlobalGeneric(idx)
+        | ExistsGeneric(idx)
+        | MoveFromGeneric(idx)
+        | MoveToGeneric(idx) => {
+            let inst = module.struct_instantiation_at(*idx);
+            format!(
+                "{}({}, {:?})",
+                opcode,
+                struct_name(module, inst.def),
+                signature_types(module, inst.type_parameters)
+            )
+        }
+        MutBorrowField(idx) | ImmBorrowField(idx) => {
+            let handle = module.field_handle_at(*idx);
+            format!(
+                "{}({}, {})",
+                opcode,
+                struct_name(module, handle.owner),
+                handle.field
+            )
+        }
+        MutBorrowFieldGeneric(idx) | ImmBorrowFieldGeneric(idx) => {
+            let inst = module.field_instantiation_at(*idx);
+            let handle = module.field_handle_at(inst.handle);
+            format!(
+                "{}({}, {}, {:?})",
+                opcode,
+                struct_name(module, handle.owner),
+                handle.field,
+                signature_types(module, inst.type_parameters)
+            )
+        }
+        VecPack(idx, num) | VecUnpack(idx, num) => {
+            format!("{}({:?}, {})", opcode, signature_types(module, *idx), num)
+        }
+        VecLen(idx) | VecImmBorrow(idx) | VecMutBorrow(idx) | VecPushBack(idx)
+        | VecPopBack(idx) | VecSwap(idx) => {
+            format!("{}({:?})", opcode, signature_types(module, *idx))
+        }
+        _ => debug,
+    }
+}
+
+fn function_name(module: &CompiledModule, idx: FunctionHandleIndex) -> String {
+    let handle = module.function_handle_at(idx);
+    let module_id = module.module_id_for_handle(module.module_handle_at(handle.module));
+    format!(
+        "{}::{}",
+        module_id.short_str_lossless(),
+        module.identifier_at(handle.name)
+    )
+}
+
+fn struct_name(module: &CompiledModule, idx: StructDefinitionIndex) -> String {
+    let handle = module.struct_handle_at(module.struct_def_at(idx).struct_handle);
+    module.identifier_at(handle.name).to_string()
+}
+
+fn signature_types(module: &CompiledModule, idx: SignatureIndex) -> Vec<normalized::Type> {
+    module
+        .signature_at(idx)
+        .0
+        .iter()
+        .map(|token| normalized::Type::new(module, token))
+        .collect()
+}
+
 ////////////////////// Native functions for replace identifiers //////////////////////
 
 #[derive(Debug, Clone)]
@@ -769,6 +1053,7 @@ pub struct GasParameters {
     pub sort_and_verify_modules_inner: VerifyModulesGasParameters,
     pub request_init_functions: RequestInitFunctionsGasParameters,
     pub check_compatibililty_inner: CheckCompatibilityInnerGasParameters,
+    pub check_upgrade_policy_inner: CheckUpgradePolicyInnerGasParametersOption,
     pub replace_address_identifiers: ModuleModifyGasParameters,
     pub replace_addresses_constant: ModuleModifyGasParameters,
     pub replace_identifiers: ModuleModifyGasParameters,
@@ -802,6 +1087,7 @@ impl GasParameters {
                 base: 0.into(),
                 per_byte: 0.into(),
             },
+            check_upgrade_policy_inner: CheckUpgradePolicyInnerGasParametersOption::zeros(),
             replace_address_identifiers: ModuleModifyGasParameters {
                 base: 0.into(),
                 per_byte: 0.into(),
@@ -835,7 +1121,7 @@ impl GasParameters {
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [
+    let mut natives = [
         (
             "module_id_inner",
             make_native(gas_params.module_id_inner, native_module_id_inner),
@@ -896,6 +1182,18 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
             "replace_u256_constant",
             make_native(gas_params.replace_u256_constant, replace_u256_constant),
         ),
-    ];
+    ]
+    .to_vec();
+
+    if !gas_params.check_upgrade_policy_inner.is_empty() {
+        natives.push((
+            "check_upgrade_policy_inner",
+            make_native(
+                gas_params.check_upgrade_policy_inner,
+                check_upgrade_policy_inner,
+            ),
+        ));
+    }
+
     make_module_natives(natives)
 }
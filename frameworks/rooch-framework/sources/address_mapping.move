@@ -22,7 +22,8 @@ module rooch_framework::address_mapping{
     const NAMED_REVERSE_MAPPING_INDEX: u64 = 1;
 
     /// Mapping from multi-chain address to rooch address
-    /// Not including Bitcoin address, because Bitcoin address can directly hash to rooch address
+    /// Not including Bitcoin address, because Bitcoin address can directly hash to rooch address,
+    /// except the Bitcoin address rebound to another rooch address, see `rebind_bitcoin_address_by_system`
     /// The mapping record is the object field, key is the multi-chain address, value is the rooch address
     struct MultiChainAddressMapping has key{
         _placeholder: bool,
@@ -76,6 +77,9 @@ module rooch_framework::address_mapping{
             return option::some(multichain_address::into_rooch_address(maddress))
         };
         if (multichain_address::is_bitcoin_address(&maddress)) {
+            if(object::contains_field(obj, maddress)){
+                return option::some(*object::borrow_field(obj, maddress))
+            };
             return option::some(bitcoin_address::to_rooch_address(&multichain_address::into_bitcoin_address(maddress)))
         };
 
@@ -109,6 +113,24 @@ module rooch_framework::address_mapping{
         Self::resolve_address(am, maddress)
     }
 
+    /// Resolve a bitcoin address to a rooch address, the rebound bitcoin address resolves to
+    /// the rooch address it is rebound to, others resolve to the rooch address derived from it
+    public fun resolve_bitcoin_to_rooch_address(btc_address: &BitcoinAddress): address {
+        let am = Self::borrow_multichain();
+        let maddress = multichain_address::from_bitcoin(*btc_address);
+        if(object::contains_field(am, maddress)){
+            *object::borrow_field(am, maddress)
+        }else{
+            bitcoin_address::to_rooch_address(btc_address)
+        }
+    }
+
+    /// Check if a bitcoin address is rebound to a rooch address which is not derived from it
+    public fun is_rebound_bitcoin_address(btc_address: &BitcoinAddress): bool {
+        let am = Self::borrow_multichain();
+        object::contains_field(am, multichain_address::from_bitcoin(*btc_address))
+    }
+
     /// Resolve a rooch address to a bitcoin address
     public fun resolve_bitcoin(rooch_address: address): Option<BitcoinAddress> {
         let am = Self::borrow_rooch_to_bitcoin();
@@ -147,6 +169,31 @@ module rooch_framework::address_mapping{
         Self::bind_bitcoin_address_internal(rooch_address, btc_address);
     }
 
+    /// Rebind the rooch address to a new bitcoin address, eg. the participants of a multisign account are changed.
+    /// The rooch address is kept, so it is not derived from the new bitcoin address anymore,
+    /// the new bitcoin address is recorded in the multi-chain address mapping to resolve to the rooch address.
+    public fun rebind_bitcoin_address_by_system(system: &signer, rooch_address: address, btc_address: BitcoinAddress) {
+        core_addresses::assert_system_reserved(system);
+        let obj = Self::borrow_rooch_to_bitcoin_mut();
+        if(object::contains_field(obj, rooch_address)){
+            *object::borrow_mut_field(obj, rooch_address) = btc_address;
+        }else{
+            object::add_field(obj, rooch_address, btc_address);
+        };
+        let maddress = multichain_address::from_bitcoin(btc_address);
+        let multichain_obj = Self::borrow_multichain_mut();
+        if(bitcoin_address::to_rooch_address(&btc_address) == rooch_address){
+            // rebound back to the derived rooch address
+            if(object::contains_field(multichain_obj, maddress)){
+                let _: address = object::remove_field(multichain_obj, maddress);
+            };
+        }else if(object::contains_field(multichain_obj, maddress)){
+            *object::borrow_mut_field(multichain_obj, maddress) = rooch_address;
+        }else{
+            object::add_field(multichain_obj, maddress, rooch_address);
+        }
+    }
+
 
     /// Bind a bitcoin address to a rooch address
     /// We can calculate the rooch address from bitcoin address
@@ -184,5 +231,30 @@ module rooch_framework::address_mapping{
         assert!(bitcoin_address::is_empty(vector::borrow(&resolved_addrs, 1)), 1);
     }
 
+    #[test]
+    fun test_rebind_bitcoin_address(){
+        let genesis_account = moveos_std::signer::module_signer<RoochToBitcoinAddressMapping>();
+        genesis_init(&genesis_account);
+        let btc_addr = bitcoin_address::from_string(&string::utf8(b"bc1p8xpjpkc9uzj2dexcxjg9sw8lxje85xa4070zpcys589e3rf6k20qm6gjrt"));
+        let new_btc_addr = bitcoin_address::from_string(&string::utf8(b"bc1pw5tlklftdmjhxklcsawzz9d96dqs4wje2wkx9zesfhjjh6yqxwzqcdzu3r"));
+        let rooch_addr = bitcoin_address::to_rooch_address(&btc_addr);
+        bind_bitcoin_address(btc_addr);
+        assert!(!is_rebound_bitcoin_address(&new_btc_addr), 1);
+
+        let system = moveos_std::signer::module_signer<MultiChainAddressMapping>();
+        rebind_bitcoin_address_by_system(&system, rooch_addr, new_btc_addr);
+        assert!(is_rebound_bitcoin_address(&new_btc_addr), 2);
+        assert!(resolve_bitcoin(rooch_addr) == option::some(new_btc_addr), 3);
+        assert!(resolve_bitcoin_to_rooch_address(&new_btc_addr) == rooch_addr, 4);
+        assert!(resolve(multichain_address::from_bitcoin(new_btc_addr)) == option::some(rooch_addr), 5);
+        // the previous bitcoin address is still derived to the rooch address
+        assert!(resolve_bitcoin_to_rooch_address(&btc_addr) == rooch_addr, 6);
+
+        // rebind back to the derived bitcoin address
+        rebind_bitcoin_address_by_system(&system, rooch_addr, btc_addr);
+        assert!(!is_rebound_bitcoin_address(&btc_addr), 7);
+        assert!(resolve_bitcoin(rooch_addr) == option::some(btc_addr), 8);
+    }
+
 
 }
@@ -40,6 +40,18 @@ module rooch_framework::auth_payload {
         public_keys: vector<vector<u8>>,
     }
 
+    #[data_struct]
+    struct WebauthnAuthPayload has copy, store, drop {
+        // The secp256r1 signature of the authenticator, 64-bytes (r, s)
+        signature: vector<u8>,
+        // The compressed secp256r1 public key of the passkey
+        public_key: vector<u8>,
+        // The authenticator data returned by the authenticator
+        authenticator_data: vector<u8>,
+        // The client data json, the challenge is the base64url encoded tx_hash
+        client_data_json: vector<u8>,
+    }
+
     #[data_struct]
     struct SignData has copy, drop {
         message_prefix: vector<u8>,
@@ -174,6 +186,42 @@ module rooch_framework::auth_payload {
         conseneus_encode_sign_data(&sign_data)
     }
 
+    // ======= WebauthnAuthPayload =======
+
+    public fun new_webauthn_auth_payload(
+        signature: vector<u8>,
+        public_key: vector<u8>,
+        authenticator_data: vector<u8>,
+        client_data_json: vector<u8>
+    ): WebauthnAuthPayload {
+        WebauthnAuthPayload {
+            signature,
+            public_key,
+            authenticator_data,
+            client_data_json,
+        }
+    }
+
+    public fun webauthn_from_bytes(bytes: vector<u8>): WebauthnAuthPayload {
+        bcs::from_bytes<WebauthnAuthPayload>(bytes)
+    }
+
+    public fun webauthn_signature(payload: &WebauthnAuthPayload): &vector<u8> {
+        &payload.signature
+    }
+
+    public fun webauthn_public_key(payload: &WebauthnAuthPayload): &vector<u8> {
+        &payload.public_key
+    }
+
+    public fun webauthn_authenticator_data(payload: &WebauthnAuthPayload): &vector<u8> {
+        &payload.authenticator_data
+    }
+
+    public fun webauthn_client_data_json(payload: &WebauthnAuthPayload): &vector<u8> {
+        &payload.client_data_json
+    }
+
     fun conseneus_encode_sign_data(sign_data: &SignData): vector<u8> {
         let encoder = consensus_codec::encoder();
         consensus_codec::emit_var_slice(&mut encoder, sign_data.message_prefix);
@@ -38,6 +38,8 @@ module rooch_framework::auth_validator {
     const ErrorValidateSessionIsExpired: u64 = 1012;
     /// The function call is beyond the session's scope
     const ErrorValidateFunctionCallBeyondSessionScope: u64 = 1013;
+    /// The gas price of the transaction is lower than the base fee
+    const ErrorValidateGasPriceTooLow: u64 = 1014;
 
     public fun error_validate_sequence_number_too_old(): u64 {
         ErrorValidateSequenceNuberTooOld
@@ -91,6 +93,10 @@ module rooch_framework::auth_validator {
         ErrorValidateFunctionCallBeyondSessionScope
     }
 
+    public fun error_validate_gas_price_too_low(): u64 {
+        ErrorValidateGasPriceTooLow
+    }
+
     /// The Authentication Validator
     struct AuthValidator has store, copy, drop {
         id: u64,
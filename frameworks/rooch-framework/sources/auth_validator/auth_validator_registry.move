@@ -16,6 +16,7 @@ module rooch_framework::auth_validator_registry {
 
     const ErrorValidatorUnregistered: u64 = 1;
     const ErrorValidatorAlreadyRegistered: u64 = 2;
+    const ErrorValidatorIdAlreadyAllocated: u64 = 3;
 
     struct AuthValidatorWithType<phantom ValidatorType: store> has key,store {
         id: u64,
@@ -51,6 +52,16 @@ module rooch_framework::auth_validator_registry {
         register_internal<ValidatorType>()
     }
 
+    /// Register a new validator with a reserved id by system, the unallocated ids before the reserved id are skipped.
+    /// It is used when the id of a builtin validator is reserved by another validator which is not registered on chain.
+    public fun register_by_system_with_id<ValidatorType: store>(system: &signer, id: u64) : u64{
+        core_addresses::assert_system_reserved(system);
+        let registry = account::borrow_mut_resource<ValidatorRegistry>(@rooch_framework);
+        assert!(id >= registry.validator_num, ErrorValidatorIdAlreadyAllocated);
+        registry.validator_num = id;
+        register_internal<ValidatorType>()
+    }
+
     public(friend) fun register_internal<ValidatorType: store>() : u64{
         let type_info = type_info::type_of<ValidatorType>();
         let module_address = type_info::account_address(&type_info);
@@ -111,4 +122,33 @@ module rooch_framework::auth_validator_registry {
         let validator2_id = auth_validator::validator_id(validator2);
         assert!(validator_id == validator2_id, 1000);
     }
+
+    #[test_only]
+    struct TestReservedAuthValidator has store{
+    }
+    #[test(sender=@rooch_framework)]
+    fun test_register_with_id(sender: signer){
+        features::init_and_enable_all_features_for_test();
+        genesis_init(&sender);
+        let id = register<TestAuthValidator>();
+        let system = moveos_std::signer::module_signer<TestReservedAuthValidator>();
+        let reserved_id = register_by_system_with_id<TestReservedAuthValidator>(&system, id + 2);
+        assert!(reserved_id == id + 2, 1000);
+        let validator = borrow_validator_by_type<TestReservedAuthValidator>();
+        assert!(auth_validator::validator_id(validator) == reserved_id, 1001);
+        assert!(!table::contains(&account::borrow_resource<ValidatorRegistry>(@rooch_framework).validators, id + 1), 1002);
+    }
+
+    #[test_only]
+    struct TestOtherAuthValidator has store{
+    }
+    #[test(sender=@rooch_framework)]
+    #[expected_failure(abort_code = ErrorValidatorIdAlreadyAllocated, location = Self)]
+    fun test_register_with_allocated_id(sender: signer){
+        features::init_and_enable_all_features_for_test();
+        genesis_init(&sender);
+        let id = register<TestAuthValidator>();
+        let system = moveos_std::signer::module_signer<TestOtherAuthValidator>();
+        register_by_system_with_id<TestOtherAuthValidator>(&system, id);
+    }
 }
@@ -44,10 +44,24 @@ module rooch_framework::bitcoin_validator {
     public(friend) fun validate(authenticator_payload: vector<u8>) :BitcoinAddress{
 
         let sender = tx_context::sender();
-        let tx_hash = tx_context::tx_hash();
+        let bitcoin_addr = validate_signer(authenticator_payload, tx_context::tx_hash());
+
+        let rooch_addr = bitcoin_address::to_rooch_address(&bitcoin_addr);
+
+        // Check if the sender is related to the Rooch address
+        assert!(
+            sender == rooch_addr,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        bitcoin_addr
+    }
+
+    /// Validate the authenticator signs the `message_hash`, return the Bitcoin address of the signer.
+    /// The signer is not required to be the sender, eg. the sponsor signs the sponsor hash of the transaction.
+    public(friend) fun validate_signer(authenticator_payload: vector<u8>, message_hash: vector<u8>) :BitcoinAddress{
         let payload = auth_payload::from_bytes(authenticator_payload);
 
-        validate_signature(&payload, tx_hash);
+        validate_signature(&payload, message_hash);
 
         let from_address_in_payload = auth_payload::from_address(&payload);
         let bitcoin_addr = bitcoin_address::from_string(&from_address_in_payload);
@@ -57,14 +71,6 @@ module rooch_framework::bitcoin_validator {
             bitcoin_address::verify_with_public_key(&from_address_in_payload, &auth_payload::public_key(&payload)),
             auth_validator::error_validate_invalid_authenticator()
         );
-
-        let rooch_addr = bitcoin_address::to_rooch_address(&bitcoin_addr);
-
-        // Check if the sender is related to the Rooch address
-        assert!(
-            sender == rooch_addr,
-            auth_validator::error_validate_invalid_authenticator()
-        );
         bitcoin_addr
     }
 
@@ -15,6 +15,9 @@ module rooch_framework::builtin_validators{
     const BITCOIN_VALIDATOR_ID: u64 = 1;
     /// Bitcoin multisign validator is defined in bitcoin_move framework.
     const BITCOIN_MULTISIGN_VALIDATOR_ID: u64 = 2;
+    /// The id 3 is reserved for the Ethereum validator.
+    /// WebAuthn validator is registered after the bitcoin multisign validator in the bitcoin_move genesis.
+    const WEBAUTHN_VALIDATOR_ID: u64 = 4;
 
     public(friend) fun genesis_init(_genesis_account: &signer) {
         // NATIVE_AUTH_VALIDATOR_ID: u64 = 0;
@@ -29,6 +32,7 @@ module rooch_framework::builtin_validators{
     public fun is_builtin_auth_validator(auth_validator_id: u64): bool {
         auth_validator_id == SESSION_VALIDATOR_ID || 
         auth_validator_id == BITCOIN_VALIDATOR_ID || 
-        auth_validator_id == BITCOIN_MULTISIGN_VALIDATOR_ID
+        auth_validator_id == BITCOIN_MULTISIGN_VALIDATOR_ID ||
+        auth_validator_id == WEBAUTHN_VALIDATOR_ID
     }
 }
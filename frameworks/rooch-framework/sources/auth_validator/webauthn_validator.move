@@ -0,0 +1,429 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// This module implements the WebAuthn (passkey) validator with the ECDSA signature over Secp256r1.
+/// A passkey controls the account derived from it, so a user can onboard with a passkey only,
+/// and an account can add more passkeys by itself. The account sends transactions with a WebAuthn
+/// assertion whose challenge is the transaction hash.
+module rooch_framework::webauthn_validator {
+
+    use std::option::{Self, Option};
+    use std::vector;
+    use std::string::{Self, String};
+    use moveos_std::tx_context;
+    use moveos_std::account;
+    use moveos_std::bcs;
+    use moveos_std::hash;
+    use moveos_std::json;
+    use moveos_std::base64;
+    use moveos_std::signer;
+    use moveos_std::simple_map;
+    use rooch_framework::ecdsa_r1;
+    use rooch_framework::auth_payload::{Self, WebauthnAuthPayload};
+    use rooch_framework::auth_validator;
+    use rooch_framework::auth_validator_registry;
+    use rooch_framework::address_mapping;
+    use rooch_framework::bitcoin_address::{Self, BitcoinAddress};
+
+    /// there defines auth validator id for each auth validator
+    /// The id 3 is reserved for the Ethereum validator, so the WebAuthn validator id is 4.
+    const WEBAUTHN_AUTH_VALIDATOR_ID: u64 = 4;
+
+    /// The authenticator data contains the 32-bytes rpIdHash, 1-byte flags and 4-bytes signCount at least.
+    const AUTHENTICATOR_DATA_MIN_LENGTH: u64 = 37;
+    const RP_ID_HASH_LENGTH: u64 = 32;
+    const AUTHENTICATOR_DATA_FLAGS_INDEX: u64 = 32;
+    /// The User Present flag of the authenticator data.
+    const FLAG_USER_PRESENT: u8 = 0x01;
+    const CLIENT_DATA_TYPE_GET: vector<u8> = b"webauthn.get";
+    const HTTPS_SCHEME: vector<u8> = b"https://";
+    /// The http origin is only allowed for the localhost, the browsers allow it for the development.
+    const HTTP_SCHEME: vector<u8> = b"http://";
+    const LOCALHOST: vector<u8> = b"localhost";
+    /// The BIP341 NUMS point, the key path of the passkey account Bitcoin address is unspendable.
+    const NUMS_INTERNAL_PUBKEY: vector<u8> = x"50929b74c1a04954b78b4b6035e97a5e078a5a0f28ec96d547bfee9ace803ac0";
+    /// The domain of the passkey account script, it must be the same as the `PASSKEY_ACCOUNT_DOMAIN` in Rust.
+    const PASSKEY_ACCOUNT_DOMAIN: vector<u8> = b"ROOCH_PASSKEY::";
+
+    const ErrorGenesisInitError: u64 = 1;
+    const ErrorInvalidPublicKey: u64 = 2;
+    const ErrorPasskeyAlreadyExists: u64 = 3;
+    const ErrorPasskeyNotFound: u64 = 4;
+    const ErrorInvalidRpId: u64 = 5;
+
+    struct WebauthnValidator has store, drop {}
+
+    struct Passkey has store, copy, drop {
+        /// The 33-bytes compressed secp256r1 public key
+        public_key: vector<u8>,
+        /// The relying party id the passkey is created for, e.g. `rooch.network`
+        rp_id: String,
+    }
+
+    /// The passkeys added to an account.
+    struct Passkeys has key {
+        passkeys: vector<Passkey>,
+    }
+
+    public fun auth_validator_id(): u64 {
+        WEBAUTHN_AUTH_VALIDATOR_ID
+    }
+
+    /// Register the validator. It is called by the bitcoin_move genesis after the bitcoin multisign validator,
+    /// the reserved Ethereum validator id is skipped.
+    /// The transactions are validated by the transaction validator directly, so the registration is only for lookups.
+    public fun init_for_upgrade(){
+        if(!auth_validator_registry::is_registered<WebauthnValidator>()){
+            let system = signer::module_signer<WebauthnValidator>();
+            let id = auth_validator_registry::register_by_system_with_id<WebauthnValidator>(&system, WEBAUTHN_AUTH_VALIDATOR_ID);
+            assert!(id == WEBAUTHN_AUTH_VALIDATOR_ID, ErrorGenesisInitError);
+        }
+    }
+
+    /// Register the validator on an existing chain after the framework upgrade, anyone can call it, and it only takes effect once.
+    public entry fun init_for_upgrade_entry(){
+        init_for_upgrade();
+    }
+
+    /// The Bitcoin address of the account controlled by the passkey, it is a taproot address whose internal key is
+    /// the unspendable NUMS point and whose merkle root commits to the rpIdHash and the passkey public key.
+    public fun passkey_bitcoin_address(rp_id_hash: &vector<u8>, public_key: &vector<u8>): BitcoinAddress {
+        let data = PASSKEY_ACCOUNT_DOMAIN;
+        vector::append(&mut data, *rp_id_hash);
+        vector::append(&mut data, *public_key);
+        let merkle_root = bcs::to_address(hash::sha2_256(data));
+        bitcoin_address::p2tr(&NUMS_INTERNAL_PUBKEY, option::some(merkle_root))
+    }
+
+    /// The Rooch address of the account controlled by the passkey of the `rp_id`.
+    public fun passkey_address(rp_id: String, public_key: vector<u8>): address {
+        let rp_id_hash = hash::sha2_256(*string::bytes(&rp_id));
+        bitcoin_address::to_rooch_address(&passkey_bitcoin_address(&rp_id_hash, &public_key))
+    }
+
+    fun find_passkey(account_address: address, public_key: &vector<u8>): Option<Passkey> {
+        if(!account::exists_resource<Passkeys>(account_address)){
+            return option::none()
+        };
+        let passkeys = &account::borrow_resource<Passkeys>(account_address).passkeys;
+        let i = 0;
+        let len = vector::length(passkeys);
+        while (i < len) {
+            let passkey = vector::borrow(passkeys, i);
+            if (&passkey.public_key == public_key) {
+                return option::some(*passkey)
+            };
+            i = i + 1;
+        };
+        option::none()
+    }
+
+    public fun has_passkey(account_address: address, public_key: vector<u8>): bool {
+        option::is_some(&find_passkey(account_address, &public_key))
+    }
+
+    /// Add a 33-bytes compressed secp256r1 passkey public key of the relying party `rp_id` to the sender account.
+    public fun add_passkey(sender: &signer, public_key: vector<u8>, rp_id: String) {
+        assert!(vector::length(&public_key) == ecdsa_r1::public_key_length(), ErrorInvalidPublicKey);
+        assert!(!string::is_empty(&rp_id), ErrorInvalidRpId);
+        let sender_addr = signer::address_of(sender);
+        assert!(!has_passkey(sender_addr, public_key), ErrorPasskeyAlreadyExists);
+        if(!account::exists_resource<Passkeys>(sender_addr)){
+            account::move_resource_to(sender, Passkeys{ passkeys: vector::empty() });
+        };
+        let passkeys = account::borrow_mut_resource<Passkeys>(sender_addr);
+        vector::push_back(&mut passkeys.passkeys, Passkey{ public_key, rp_id });
+    }
+
+    public entry fun add_passkey_entry(sender: &signer, public_key: vector<u8>, rp_id: String) {
+        add_passkey(sender, public_key, rp_id);
+    }
+
+    /// Remove the passkey added to the sender account, the passkey the account is derived from can not be removed.
+    public fun remove_passkey(sender: &signer, public_key: vector<u8>) {
+        let sender_addr = signer::address_of(sender);
+        assert!(account::exists_resource<Passkeys>(sender_addr), ErrorPasskeyNotFound);
+        let passkeys = &mut account::borrow_mut_resource<Passkeys>(sender_addr).passkeys;
+        let i = 0;
+        let len = vector::length(passkeys);
+        while (i < len) {
+            if (vector::borrow(passkeys, i).public_key == public_key) {
+                vector::remove(passkeys, i);
+                return
+            };
+            i = i + 1;
+        };
+        abort ErrorPasskeyNotFound
+    }
+
+    public entry fun remove_passkey_entry(sender: &signer, public_key: vector<u8>) {
+        remove_passkey(sender, public_key);
+    }
+
+    /// Encode the bytes with the base64url alphabet without padding, the WebAuthn challenge is encoded in this way.
+    fun base64url_encode(input: &vector<u8>): vector<u8> {
+        let encoded = base64::encode(input);
+        let result = vector::empty<u8>();
+        let i = 0;
+        let len = vector::length(&encoded);
+        while (i < len) {
+            let c = *vector::borrow(&encoded, i);
+            // '+' => '-', '/' => '_', and drop the '=' padding
+            if (c == 43) {
+                vector::push_back(&mut result, 45);
+            } else if (c == 47) {
+                vector::push_back(&mut result, 95);
+            } else if (c != 61) {
+                vector::push_back(&mut result, c);
+            };
+            i = i + 1;
+        };
+        result
+    }
+
+    fun starts_with(bytes: &vector<u8>, prefix: &vector<u8>): bool {
+        let prefix_len = vector::length(prefix);
+        vector::length(bytes) >= prefix_len && vector::slice(bytes, 0, prefix_len) == *prefix
+    }
+
+    /// Parse the host of the origin `https://<host>[:port]`, or `http://localhost[:port]`.
+    fun origin_host(origin: &vector<u8>): vector<u8> {
+        let (is_https, start) = if (starts_with(origin, &HTTPS_SCHEME)) {
+            (true, vector::length(&HTTPS_SCHEME))
+        } else {
+            assert!(starts_with(origin, &HTTP_SCHEME), auth_validator::error_validate_invalid_authenticator());
+            (false, vector::length(&HTTP_SCHEME))
+        };
+        let len = vector::length(origin);
+        let end = start;
+        // ':'
+        while (end < len && *vector::borrow(origin, end) != 58) {
+            end = end + 1;
+        };
+        let host = vector::slice(origin, start, end);
+        assert!(!vector::is_empty(&host), auth_validator::error_validate_invalid_authenticator());
+        assert!(is_https || host == LOCALHOST, auth_validator::error_validate_invalid_authenticator());
+        host
+    }
+
+    /// Check the rp id of the `rp_id_hash` is the host of the origin or a domain suffix of it,
+    /// the browsers only allow the origin to use the passkeys of these rp ids.
+    fun is_origin_of_rp_id(origin: &vector<u8>, rp_id_hash: &vector<u8>): bool {
+        let host = origin_host(origin);
+        let len = vector::length(&host);
+        let i = 0;
+        while (i < len) {
+            // '.'
+            if ((i == 0 || *vector::borrow(&host, i - 1) == 46) && hash::sha2_256(vector::slice(&host, i, len)) == *rp_id_hash) {
+                return true
+            };
+            i = i + 1;
+        };
+        false
+    }
+
+    /// Check the client data is a `webauthn.get` assertion of the tx_hash from an origin of the rp id.
+    fun validate_client_data(client_data_json: &vector<u8>, tx_hash: &vector<u8>, rp_id_hash: &vector<u8>) {
+        let client_data = json::to_map(*client_data_json);
+
+        let type_key = string::utf8(b"type");
+        assert!(simple_map::contains_key(&client_data, &type_key), auth_validator::error_validate_invalid_authenticator());
+        assert!(
+            *string::bytes(simple_map::borrow(&client_data, &type_key)) == CLIENT_DATA_TYPE_GET,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+
+        let challenge_key = string::utf8(b"challenge");
+        assert!(simple_map::contains_key(&client_data, &challenge_key), auth_validator::error_validate_invalid_authenticator());
+        assert!(
+            *string::bytes(simple_map::borrow(&client_data, &challenge_key)) == base64url_encode(tx_hash),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+
+        let origin_key = string::utf8(b"origin");
+        assert!(simple_map::contains_key(&client_data, &origin_key), auth_validator::error_validate_invalid_authenticator());
+        assert!(
+            is_origin_of_rp_id(string::bytes(simple_map::borrow(&client_data, &origin_key)), rp_id_hash),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+
+        // The assertion requested by an iframe of another origin is rejected
+        let cross_origin_key = string::utf8(b"crossOrigin");
+        assert!(
+            !simple_map::contains_key(&client_data, &cross_origin_key) ||
+            *string::bytes(simple_map::borrow(&client_data, &cross_origin_key)) != b"true",
+            auth_validator::error_validate_invalid_authenticator()
+        );
+    }
+
+    /// Only validate the authenticator's signature, return the rpIdHash of the assertion.
+    fun validate_signature(payload: &WebauthnAuthPayload, tx_hash: vector<u8>): vector<u8> {
+        let signature = auth_payload::webauthn_signature(payload);
+        let public_key = auth_payload::webauthn_public_key(payload);
+        assert!(
+            vector::length(signature) == ecdsa_r1::signature_length() &&
+            vector::length(public_key) == ecdsa_r1::public_key_length(),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+
+        let authenticator_data = auth_payload::webauthn_authenticator_data(payload);
+        assert!(
+            vector::length(authenticator_data) >= AUTHENTICATOR_DATA_MIN_LENGTH,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        let flags = *vector::borrow(authenticator_data, AUTHENTICATOR_DATA_FLAGS_INDEX);
+        assert!(
+            (flags & FLAG_USER_PRESENT) == FLAG_USER_PRESENT,
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        let rp_id_hash = vector::slice(authenticator_data, 0, RP_ID_HASH_LENGTH);
+
+        let client_data_json = auth_payload::webauthn_client_data_json(payload);
+        validate_client_data(client_data_json, &tx_hash, &rp_id_hash);
+
+        // The authenticator signs `authenticator_data || sha256(client_data_json)`,
+        // the `ecdsa_r1::verify` function does sha2_256 on the message.
+        let message = *authenticator_data;
+        vector::append(&mut message, hash::sha2_256(*client_data_json));
+        assert!(
+            ecdsa_r1::verify(signature, public_key, &message),
+            auth_validator::error_validate_invalid_authenticator()
+        );
+        rp_id_hash
+    }
+
+    /// Validate the WebAuthn assertion and return the Bitcoin address of the sender.
+    /// The passkey must be added to the sender account for the same rp id, or the sender is the account derived from the passkey.
+    public fun validate(authenticator_payload: vector<u8>): BitcoinAddress {
+        let sender = tx_context::sender();
+        let tx_hash = tx_context::tx_hash();
+        let payload = auth_payload::webauthn_from_bytes(authenticator_payload);
+        let rp_id_hash = validate_signature(&payload, tx_hash);
+        let public_key = auth_payload::webauthn_public_key(&payload);
+
+        let passkey = find_passkey(sender, public_key);
+        if (option::is_some(&passkey)) {
+            let passkey = option::destroy_some(passkey);
+            assert!(
+                hash::sha2_256(*string::bytes(&passkey.rp_id)) == rp_id_hash,
+                auth_validator::error_validate_invalid_authenticator()
+            );
+            let bitcoin_address = address_mapping::resolve_bitcoin(sender);
+            assert!(option::is_some(&bitcoin_address), auth_validator::error_validate_account_does_not_exist());
+            option::destroy_some(bitcoin_address)
+        } else {
+            let bitcoin_address = passkey_bitcoin_address(&rp_id_hash, public_key);
+            assert!(
+                bitcoin_address::to_rooch_address(&bitcoin_address) == sender,
+                auth_validator::error_validate_invalid_authenticator()
+            );
+            bitcoin_address
+        }
+    }
+
+    #[test_only]
+    fun test_payload(): WebauthnAuthPayload {
+        // The assertion is signed with `authenticator_data || sha256(client_data_json)`, the challenge is the base64url of the tx_hash
+        auth_payload::new_webauthn_auth_payload(
+            x"ea60a5985a24d25394299d0c5d67935db290b4fe200ae6fa3c7a98b7bc5210353afb26a4fe33873ed570508c498fe007ebbaeca254a691d13a567b35416d6b66",
+            x"032c417230cb6a9e3ad4e735e88341e2ecc4cbac08daf16a3380f26228408a73b1",
+            x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0500000001",
+            b"{\"type\":\"webauthn.get\",\"challenge\":\"y82dn21YjgviXLBYbqILvvTSvp5oFsaPRbjR1qA6GRY\",\"origin\":\"https://rooch.network\",\"crossOrigin\":false}",
+        )
+    }
+
+    #[test]
+    fun test_base64url_encode() {
+        assert!(base64url_encode(&x"fbff") == b"-_8", 0);
+        assert!(base64url_encode(&b"rooch") == b"cm9vY2g", 1);
+    }
+
+    #[test]
+    fun test_validate_signature_success() {
+        let tx_hash = x"cbcd9d9f6d588e0be25cb0586ea20bbef4d2be9e6816c68f45b8d1d6a03a1916";
+        validate_signature(&test_payload(), tx_hash);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 1010)]
+    fun test_validate_signature_fail_with_other_tx_hash() {
+        let tx_hash = x"cccd9d9f6d588e0be25cb0586ea20bbef4d2be9e6816c68f45b8d1d6a03a1916";
+        validate_signature(&test_payload(), tx_hash);
+    }
+
+    #[test_only]
+    /// The assertion signed for the subdomain origin `https://app.rooch.network` of the rp id `rooch.network`
+    fun test_subdomain_payload(): WebauthnAuthPayload {
+        auth_payload::new_webauthn_auth_payload(
+            x"209cae79c8d96a766a81d2f3f9413b8025bc33b2964baeee5eb097b51999f9e34a7a737b5d167fb5c8b1b9681102833adc44abc40a773f03b5ea68038e87cb46",
+            x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c",
+            x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0500000001",
+            b"{\"type\":\"webauthn.get\",\"challenge\":\"y82dn21YjgviXLBYbqILvvTSvp5oFsaPRbjR1qA6GRY\",\"origin\":\"https://app.rooch.network\",\"crossOrigin\":false}",
+        )
+    }
+
+    #[test_only]
+    /// The assertion of the rp id `rooch.network` signed for the other origin `https://evil.network`
+    fun test_other_origin_payload(): WebauthnAuthPayload {
+        auth_payload::new_webauthn_auth_payload(
+            x"3443569f7feae33efd7c340816275b1ad435f200c885410c660181a7e5ec976e3a84da638d477c098bc983dd68d03bf3bf12e075ece54690c468a87b2b7ad9a2",
+            x"02471c3e758c4904285bba7e53118ed0f524adeb0757d25bd2f8e7b0d76dfa714c",
+            x"701e5ca13e155a366732dbcbbbc694edc8c7fc06b03cfebc4c7361057a4f148d0500000001",
+            b"{\"type\":\"webauthn.get\",\"challenge\":\"y82dn21YjgviXLBYbqILvvTSvp5oFsaPRbjR1qA6GRY\",\"origin\":\"https://evil.network\",\"crossOrigin\":false}",
+        )
+    }
+
+    #[test]
+    fun test_validate_signature_with_subdomain_origin() {
+        let tx_hash = x"cbcd9d9f6d588e0be25cb0586ea20bbef4d2be9e6816c68f45b8d1d6a03a1916";
+        let rp_id_hash = validate_signature(&test_subdomain_payload(), tx_hash);
+        assert!(rp_id_hash == hash::sha2_256(b"rooch.network"), 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 1010)]
+    fun test_validate_signature_fail_with_other_origin() {
+        let tx_hash = x"cbcd9d9f6d588e0be25cb0586ea20bbef4d2be9e6816c68f45b8d1d6a03a1916";
+        validate_signature(&test_other_origin_payload(), tx_hash);
+    }
+
+    #[test]
+    fun test_is_origin_of_rp_id() {
+        let rp_id_hash = hash::sha2_256(b"rooch.network");
+        assert!(is_origin_of_rp_id(&b"https://rooch.network", &rp_id_hash), 0);
+        assert!(is_origin_of_rp_id(&b"https://app.rooch.network:8443", &rp_id_hash), 1);
+        assert!(!is_origin_of_rp_id(&b"https://evilrooch.network", &rp_id_hash), 2);
+        assert!(!is_origin_of_rp_id(&b"https://rooch.network.evil", &rp_id_hash), 3);
+        assert!(is_origin_of_rp_id(&b"http://localhost:3000", &hash::sha2_256(b"localhost")), 4);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = 1010)]
+    fun test_http_origin_of_other_host() {
+        is_origin_of_rp_id(&b"http://rooch.network", &hash::sha2_256(b"rooch.network"));
+    }
+
+    #[test(sender=@0x42)]
+    fun test_add_and_remove_passkey(sender: signer) {
+        rooch_framework::genesis::init_for_test();
+        let public_key = x"032c417230cb6a9e3ad4e735e88341e2ecc4cbac08daf16a3380f26228408a73b1";
+        let sender_addr = signer::address_of(&sender);
+        assert!(!has_passkey(sender_addr, public_key), 0);
+        add_passkey(&sender, public_key, string::utf8(b"rooch.network"));
+        assert!(has_passkey(sender_addr, public_key), 1);
+        let passkey = option::destroy_some(find_passkey(sender_addr, &public_key));
+        assert!(passkey.rp_id == string::utf8(b"rooch.network"), 2);
+        remove_passkey(&sender, public_key);
+        assert!(!has_passkey(sender_addr, public_key), 3);
+    }
+
+    #[test]
+    fun test_passkey_address() {
+        let public_key = x"032c417230cb6a9e3ad4e735e88341e2ecc4cbac08daf16a3380f26228408a73b1";
+        let rp_id_hash = hash::sha2_256(b"rooch.network");
+        let bitcoin_address = passkey_bitcoin_address(&rp_id_hash, &public_key);
+        assert!(bitcoin_address::is_witness_program(&bitcoin_address), 0);
+        assert!(passkey_address(string::utf8(b"rooch.network"), public_key) == bitcoin_address::to_rooch_address(&bitcoin_address), 1);
+        assert!(passkey_address(string::utf8(b"app.rooch.network"), public_key) != bitcoin_address::to_rooch_address(&bitcoin_address), 2);
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+module rooch_framework::ecdsa_r1 {
+
+    /// constant codes
+    const ECDSA_R1_COMPRESSED_PUBKEY_LENGTH: u64 = 33;
+    const ECDSA_R1_SIG_LENGTH: u64 = 64;
+
+    /// Error if the signature is invalid.
+    const ErrorInvalidSignature: u64 = 1;
+
+    /// Error if the public key is invalid.
+    const ErrorInvalidPubKey: u64 = 2;
+
+    /// built-in functions
+    public fun public_key_length(): u64 {
+        ECDSA_R1_COMPRESSED_PUBKEY_LENGTH
+    }
+
+    public fun signature_length(): u64 {
+        ECDSA_R1_SIG_LENGTH
+    }
+
+    /// @param signature: A 64-bytes signature in form (r, s) that is signed using
+    /// Ecdsa over the secp256r1 (P-256) curve. The s value must be in the lower half of the curve order.
+    /// @param public_key: A 33-bytes compressed public key that is used to sign messages.
+    /// @param msg: The message that the signature is signed against, it is hashed with sha256 before verification.
+    ///
+    /// If the signature is valid to the pubkey and hashed message, return true. Else false.
+    native public fun verify(
+        signature: &vector<u8>,
+        public_key: &vector<u8>,
+        msg: &vector<u8>
+    ): bool;
+
+    #[test]
+    fun test_verify_success() {
+        let msg = x"00010203";
+        let pubkey = x"032c417230cb6a9e3ad4e735e88341e2ecc4cbac08daf16a3380f26228408a73b1";
+        let sig = x"d85f0a15b7f340f2d029d8bd7a3f477e0562370aedae46b77b9d4e5f32dcde8139c21e2a353e30bd01a29591aa9b971991e7c5ff12cf22c6f554b49986559691";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(result, 0);
+    }
+
+    #[test]
+    fun test_verify_fails_wrong_msg() {
+        let msg = x"00010204";
+        let pubkey = x"032c417230cb6a9e3ad4e735e88341e2ecc4cbac08daf16a3380f26228408a73b1";
+        let sig = x"d85f0a15b7f340f2d029d8bd7a3f477e0562370aedae46b77b9d4e5f32dcde8139c21e2a353e30bd01a29591aa9b971991e7c5ff12cf22c6f554b49986559691";
+        let result = verify(&sig, &pubkey, &msg);
+        assert!(!result, 0);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidSignature)]
+    fun test_verify_fails_invalid_sig() {
+        let msg = x"00010203";
+        let pubkey = x"032c417230cb6a9e3ad4e735e88341e2ecc4cbac08daf16a3380f26228408a73b1";
+        let sig = x"";
+        verify(&sig, &pubkey, &msg);
+    }
+
+    #[test]
+    #[expected_failure(location=Self, abort_code = ErrorInvalidPubKey)]
+    fun test_verify_fails_invalid_pubkey() {
+        let msg = x"00010203";
+        let pubkey = x"";
+        let sig = x"d85f0a15b7f340f2d029d8bd7a3f477e0562370aedae46b77b9d4e5f32dcde8139c21e2a353e30bd01a29591aa9b971991e7c5ff12cf22c6f554b49986559691";
+        verify(&sig, &pubkey, &msg);
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+/// State Commitment Chain(SCC) stores the roots of blocks proposed by the proposer,
+/// so light clients can read the finalized roots on chain.
+module rooch_framework::state_commitment_chain {
+
+    use std::option::{Self, Option};
+    use std::vector;
+    use moveos_std::object;
+    use moveos_std::signer;
+    use moveos_std::table::{Self, Table};
+    use moveos_std::event;
+    use moveos_std::timestamp;
+    use rooch_framework::onchain_config;
+
+    const ErrorNotProposer: u64 = 1;
+    const ErrorInvalidBlockNumber: u64 = 2;
+    const ErrorPrevAccumulatorRootMismatch: u64 = 3;
+    const ErrorInvalidRootLength: u64 = 4;
+
+    const ROOT_LENGTH: u64 = 32;
+
+    /// The block committed to the SCC, a mirror of the Rust `rooch_types::block::Block`
+    #[data_struct]
+    struct Block has store, copy, drop {
+        /// The index if the block
+        block_number: u128,
+        /// How many transactions in the block
+        batch_size: u64,
+        /// The hash of the batch, made by DA
+        batch_hash: vector<u8>,
+        /// The previous tx accumulator root of the block
+        prev_tx_accumulator_root: vector<u8>,
+        /// The tx accumulator root after the last transaction append to the accumulator
+        tx_accumulator_root: vector<u8>,
+        /// The last transaction's state root
+        state_root: vector<u8>,
+        /// The timestamp when the block is committed, in millisecond
+        commit_timestamp: u64,
+    }
+
+    struct StateCommitmentChain has key {
+        /// The account allowed to commit blocks
+        proposer: address,
+        /// The last committed block number, None if no block has been committed
+        last_block_number: Option<u128>,
+        blocks: Table<u128, Block>,
+    }
+
+    struct BlockCommittedEvent has copy, drop {
+        block_number: u128,
+        batch_size: u64,
+        tx_accumulator_root: vector<u8>,
+        state_root: vector<u8>,
+    }
+
+    /// Commit a block proposed by the proposer.
+    /// Blocks must be committed one by one, except the first one, the proposer may start from any block number.
+    public entry fun commit_block(
+        account: &signer,
+        block_number: u128,
+        batch_size: u64,
+        batch_hash: vector<u8>,
+        prev_tx_accumulator_root: vector<u8>,
+        tx_accumulator_root: vector<u8>,
+        state_root: vector<u8>,
+    ) {
+        let scc = borrow_mut_scc();
+        assert!(signer::address_of(account) == scc.proposer, ErrorNotProposer);
+        assert!(vector::length(&batch_hash) == ROOT_LENGTH, ErrorInvalidRootLength);
+        assert!(vector::length(&prev_tx_accumulator_root) == ROOT_LENGTH, ErrorInvalidRootLength);
+        assert!(vector::length(&tx_accumulator_root) == ROOT_LENGTH, ErrorInvalidRootLength);
+        assert!(vector::length(&state_root) == ROOT_LENGTH, ErrorInvalidRootLength);
+
+        if (option::is_some(&scc.last_block_number)) {
+            let last_block_number = *option::borrow(&scc.last_block_number);
+            assert!(block_number == last_block_number + 1, ErrorInvalidBlockNumber);
+            let last_block = table::borrow(&scc.blocks, last_block_number);
+            assert!(last_block.tx_accumulator_root == prev_tx_accumulator_root, ErrorPrevAccumulatorRootMismatch);
+        };
+
+        table::add(&mut scc.blocks, block_number, Block {
+            block_number,
+            batch_size,
+            batch_hash,
+            prev_tx_accumulator_root,
+            tx_accumulator_root,
+            state_root,
+            commit_timestamp: timestamp::now_milliseconds(),
+        });
+        scc.last_block_number = option::some(block_number);
+
+        event::emit(BlockCommittedEvent {
+            block_number,
+            batch_size,
+            tx_accumulator_root,
+            state_root,
+        });
+    }
+
+    /// Update the proposer, only the admin can call this function
+    public entry fun set_proposer(account: &signer, proposer: address) {
+        onchain_config::ensure_admin(account);
+        let scc = borrow_mut_scc();
+        scc.proposer = proposer;
+    }
+
+    /// The account allowed to commit blocks, it's the sequencer before set by the admin
+    public fun proposer(): address {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        if (!object::exists_object(object_id)) {
+            return onchain_config::sequencer()
+        };
+        borrow_scc().proposer
+    }
+
+    public fun last_block_number(): Option<u128> {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        if (!object::exists_object(object_id)) {
+            return option::none()
+        };
+        borrow_scc().last_block_number
+    }
+
+    public fun get_block(block_number: u128): Option<Block> {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        if (!object::exists_object(object_id)) {
+            return option::none()
+        };
+        let scc = borrow_scc();
+        if (!table::contains(&scc.blocks, block_number)) {
+            return option::none()
+        };
+        option::some(*table::borrow(&scc.blocks, block_number))
+    }
+
+    public fun block_number(self: &Block): u128 {
+        self.block_number
+    }
+
+    public fun batch_size(self: &Block): u64 {
+        self.batch_size
+    }
+
+    public fun batch_hash(self: &Block): vector<u8> {
+        self.batch_hash
+    }
+
+    public fun prev_tx_accumulator_root(self: &Block): vector<u8> {
+        self.prev_tx_accumulator_root
+    }
+
+    public fun tx_accumulator_root(self: &Block): vector<u8> {
+        self.tx_accumulator_root
+    }
+
+    public fun state_root(self: &Block): vector<u8> {
+        self.state_root
+    }
+
+    public fun commit_timestamp(self: &Block): u64 {
+        self.commit_timestamp
+    }
+
+    fun borrow_scc(): &StateCommitmentChain {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        let obj = object::borrow_object<StateCommitmentChain>(object_id);
+        object::borrow(obj)
+    }
+
+    fun borrow_mut_scc(): &mut StateCommitmentChain {
+        let object_id = object::named_object_id<StateCommitmentChain>();
+        if (!object::exists_object(object_id)) {
+            let scc = StateCommitmentChain {
+                proposer: onchain_config::sequencer(),
+                last_block_number: option::none(),
+                blocks: table::new(),
+            };
+            let obj = object::new_named_object(scc);
+            object::transfer_extend(obj, @rooch_framework);
+        };
+        let obj = object::borrow_mut_object_extend<StateCommitmentChain>(object_id);
+        object::borrow_mut(obj)
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+#[test_only]
+module rooch_framework::state_commitment_chain_test{
+    use std::option;
+    use std::vector;
+    use moveos_std::account;
+    use rooch_framework::onchain_config;
+    use rooch_framework::state_commitment_chain;
+
+    fun root(v: u8): vector<u8> {
+        let root = vector::empty<u8>();
+        let i = 0;
+        while (i < 32) {
+            vector::push_back(&mut root, v);
+            i = i + 1;
+        };
+        root
+    }
+
+    #[test]
+    fun test_commit_block(){
+        rooch_framework::genesis::init_for_test();
+        let proposer = onchain_config::sequencer();
+        assert!(state_commitment_chain::proposer() == proposer, 1000);
+        assert!(option::is_none(&state_commitment_chain::last_block_number()), 1001);
+
+        let proposer_signer = account::create_signer_for_testing(proposer);
+        state_commitment_chain::commit_block(&proposer_signer, 10, 2, root(1), root(0), root(2), root(3));
+        state_commitment_chain::commit_block(&proposer_signer, 11, 3, root(4), root(2), root(5), root(6));
+        assert!(state_commitment_chain::last_block_number() == option::some(11), 1002);
+
+        let block = option::destroy_some(state_commitment_chain::get_block(10));
+        assert!(state_commitment_chain::block_number(&block) == 10, 1003);
+        assert!(state_commitment_chain::batch_size(&block) == 2, 1004);
+        assert!(state_commitment_chain::tx_accumulator_root(&block) == root(2), 1005);
+        assert!(state_commitment_chain::state_root(&block) == root(3), 1006);
+        assert!(option::is_none(&state_commitment_chain::get_block(12)), 1007);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1, location = rooch_framework::state_commitment_chain)]
+    fun test_commit_block_not_proposer(){
+        rooch_framework::genesis::init_for_test();
+        let other_signer = account::create_signer_for_testing(@0x42);
+        state_commitment_chain::commit_block(&other_signer, 0, 1, root(1), root(0), root(2), root(3));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 3, location = rooch_framework::state_commitment_chain)]
+    fun test_commit_block_prev_root_mismatch(){
+        rooch_framework::genesis::init_for_test();
+        let proposer_signer = account::create_signer_for_testing(onchain_config::sequencer());
+        state_commitment_chain::commit_block(&proposer_signer, 0, 1, root(1), root(0), root(2), root(3));
+        state_commitment_chain::commit_block(&proposer_signer, 1, 1, root(4), root(9), root(5), root(6));
+    }
+}
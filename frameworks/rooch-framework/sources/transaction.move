@@ -22,6 +22,20 @@ module rooch_framework::transaction {
         tx_accumulator_num_nodes: u64,
     }
 
+    /// The expiration of the transaction, it is in the transaction data extension.
+    #[data_struct]
+    struct TransactionExpiration has copy, drop, store{
+        /// The transaction is expired from this timestamp, in millisecond.
+        expiration_timestamp: u64,
+    }
+
+    /// The gas price bid by the transaction, it is in the transaction data extension.
+    #[data_struct]
+    struct TransactionGasPrice has copy, drop, store{
+        /// The price per gas unit, in the smallest unit of RGas.
+        gas_price: u64,
+    }
+
     public fun tx_order(self: &TransactionSequenceInfo): u64 {
         self.tx_order
     }
@@ -37,4 +51,12 @@ module rooch_framework::transaction {
     public fun tx_timestamp(self: &TransactionSequenceInfo): u64 {
         self.tx_timestamp
     }
-}
\ No newline at end of file
+
+    public fun expiration_timestamp(self: &TransactionExpiration): u64 {
+        self.expiration_timestamp
+    }
+
+    public fun gas_price(self: &TransactionGasPrice): u64 {
+        self.gas_price
+    }
+}
@@ -11,17 +11,39 @@
 /// 3. Application Developers 30%
 ///     * Goes to the developer of the entry function contract called by the transaction
 ///     * If the entry contract is a system Framework contract, this portion goes to the Rooch network
+///
+/// The fee above is calculated by the base fee, the priority fee (the gas price above the base fee) goes to the Sequencer.
+///
+/// Base Fee:
+/// The base fee per gas unit is adjusted by the gas used in the fee block like EIP-1559,
+/// the fee block is a time window of `FEE_BLOCK_INTERVAL` milliseconds.
+/// When a new fee block begins, the base fee increases at most 1/8 if the gas used in the last fee block
+/// is above the target, and decreases at most 1/8 if it is below the target.
+/// The gas used in the fee block is summed by the executor and provided in the tx context as `FeeBlockGasUsed`,
+/// so the `BaseFee` is only written by the first transaction of every fee block.
+///
+/// Storage Deposit:
+/// The VM charges a storage deposit for the value bytes of the new or grown objects as gas, and records it
+/// in the `moveos_std::storage_deposit` record of the object. The deposit is not distributed as fee, it is kept in the `StorageDepositPool`,
+/// and refunded to the gas payment account when the object shrinks or is removed.
+/// The deposit is not scaled by the gas price, so the rebate always equals the deposit.
 
 module rooch_framework::transaction_fee {
 
+    use std::option;
+
     use moveos_std::object::{Self, Object};
     use moveos_std::core_addresses;
+    use moveos_std::event;
     use moveos_std::signer;
+    use moveos_std::timestamp;
+    use moveos_std::tx_context;
 
     use rooch_framework::coin_store::{Self, CoinStore};
     use rooch_framework::coin::{Self,Coin};
     use rooch_framework::gas_coin::{RGas};
     use rooch_framework::account_coin_store;
+    use rooch_framework::transaction::{Self, TransactionGasPrice, TransactionSequenceInfo};
 
     friend rooch_framework::genesis;
     friend rooch_framework::transaction_validator;
@@ -31,10 +53,54 @@ module rooch_framework::transaction_fee {
     ///Error code for invalid gas used in transaction
     const ErrorInvalidGasUsed: u64 = 1;
 
+    const MAX_U64: u64 = 18446744073709551615;
+
+    /// The initial base fee per gas unit, it is the same as the fixed gas price before the base fee is introduced.
+    const INITIAL_BASE_FEE: u64 = 1;
+    /// The minimum base fee per gas unit.
+    const MIN_BASE_FEE: u64 = 1;
+    /// The base fee changes at most 1/BASE_FEE_MAX_CHANGE_DENOMINATOR per fee block.
+    const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+    /// The fee block interval in milliseconds.
+    const FEE_BLOCK_INTERVAL: u64 = 10000;
+    /// The target gas used per fee block.
+    const TARGET_GAS_PER_FEE_BLOCK: u64 = 10_000_000_000;
+
     struct TransactionFeePool has key {
         fee: Object<CoinStore<RGas>>,
     }
 
+    /// The storage deposit pool, it is created when the first transaction settles the storage deposit.
+    struct StorageDepositPool has key {
+        deposit: Object<CoinStore<RGas>>,
+    }
+
+    /// Event emitted when the storage deposit of a transaction is settled.
+    struct StorageDepositEvent has drop, store, copy {
+        /// The gas payment account which pays the deposit and receives the rebate.
+        account: address,
+        /// The deposit charged for the new or grown objects.
+        deposit: u256,
+        /// The deposit refunded for the shrunk or removed objects.
+        rebate: u256,
+    }
+
+    /// The base fee market, it is created when the first sequenced transaction updates the base fee.
+    struct BaseFee has key {
+        /// The base fee per gas unit.
+        base_fee: u64,
+        /// The start timestamp of the current fee block, in milliseconds.
+        fee_block_start_timestamp: u64,
+        /// The order of the first transaction in the current fee block.
+        fee_block_start_tx_order: u64,
+    }
+
+    /// The gas used by the L2 transactions in the current fee block before the current transaction.
+    /// It is a tx context attribute provided by the executor.
+    struct FeeBlockGasUsed has copy, drop, store {
+        gas_used: u64,
+    }
+
     public(friend) fun genesis_init(_genesis_account: &signer)  {
         let fee_store = coin_store::create_coin_store<RGas>();
         let obj = object::new_named_object(TransactionFeePool{
@@ -57,13 +123,165 @@ module rooch_framework::transaction_fee {
 
     /// Returns the gas factor of gas.
     public fun get_gas_factor(): u64 {
-        //TODO we should provide a algorithm to cordanate the gas factor based on the network throughput
-        //https://github.com/rooch-network/rooch/issues/1733
         return 1
     }
 
+    /// Returns the current base fee per gas unit.
+    public fun base_fee(): u64 {
+        let base_fee_id = object::named_object_id<BaseFee>();
+        if (object::exists_object(base_fee_id)) {
+            object::borrow(object::borrow_object<BaseFee>(base_fee_id)).base_fee
+        } else {
+            INITIAL_BASE_FEE
+        }
+    }
+
+    /// Returns the gas price of the current transaction.
+    /// The transaction without gas price pays the base fee.
+    public fun tx_gas_price(): u64 {
+        let tx_gas_price = tx_context::get_attribute<TransactionGasPrice>();
+        if (option::is_some(&tx_gas_price)) {
+            transaction::gas_price(option::borrow(&tx_gas_price))
+        } else {
+            base_fee()
+        }
+    }
+
+    /// Calculate the gas fee of the current transaction by its gas price.
     public fun calculate_gas(gas_amount: u64): u256{
-        (gas_amount as u256) * (get_gas_factor() as u256)
+        (gas_amount as u256) * (tx_gas_price() as u256) * (get_gas_factor() as u256)
+    }
+
+    /// Calculate the gas fee by the base fee, the rest of the gas fee of the transaction is the priority fee.
+    public fun calculate_base_fee_gas(gas_amount: u64): u256{
+        (gas_amount as u256) * (base_fee() as u256) * (get_gas_factor() as u256)
+    }
+
+    /// Calculate the storage deposit, it is not scaled by the gas price of the transaction.
+    public fun calculate_storage_deposit(deposit_amount: u64): u256{
+        (deposit_amount as u256) * (get_gas_factor() as u256)
+    }
+
+    /// Returns the total storage deposit kept in the pool.
+    public fun storage_deposit_pool_balance(): u256 {
+        let pool_id = object::named_object_id<StorageDepositPool>();
+        if (object::exists_object(pool_id)) {
+            let pool = object::borrow(object::borrow_object<StorageDepositPool>(pool_id));
+            coin_store::balance(&pool.deposit)
+        } else {
+            0u256
+        }
+    }
+
+    fun borrow_mut_or_init_storage_deposit_pool(): &mut StorageDepositPool {
+        let pool_id = object::named_object_id<StorageDepositPool>();
+        if (!object::exists_object(pool_id)) {
+            let obj = object::new_named_object(StorageDepositPool{
+                deposit: coin_store::create_coin_store<RGas>(),
+            });
+            object::transfer_extend(obj, @rooch_framework);
+        };
+        object::borrow_mut(object::borrow_mut_object_extend<StorageDepositPool>(pool_id))
+    }
+
+    /// Settle the storage deposit of the transaction, move the `deposit` from the remaining gas coin into the
+    /// storage deposit pool, and refund the `rebate` from the pool to the remaining gas coin.
+    public(friend) fun settle_storage_deposit(remaining_gas_coin: &mut Coin<RGas>, account: address, deposit: u64, rebate: u64) {
+        if (deposit == 0 && rebate == 0) {
+            return
+        };
+        let pool = borrow_mut_or_init_storage_deposit_pool();
+        let deposit_amount = calculate_storage_deposit(deposit);
+        // The remaining gas coin always covers the deposit, because the deposit is charged as gas and the gas price is at least 1.
+        // We still cap it, the post_execute function should not fail.
+        let remaining = coin::value(remaining_gas_coin);
+        if (deposit_amount > remaining) {
+            deposit_amount = remaining;
+        };
+        if (deposit_amount > 0) {
+            coin_store::deposit(&mut pool.deposit, coin::extract(remaining_gas_coin, deposit_amount));
+        };
+        let rebate_amount = calculate_storage_deposit(rebate);
+        let pool_balance = coin_store::balance(&pool.deposit);
+        if (rebate_amount > pool_balance) {
+            rebate_amount = pool_balance;
+        };
+        if (rebate_amount > 0) {
+            coin::merge(remaining_gas_coin, coin_store::withdraw(&mut pool.deposit, rebate_amount));
+        };
+        event::emit(StorageDepositEvent {
+            account,
+            deposit: deposit_amount,
+            rebate: rebate_amount,
+        });
+    }
+
+    /// Calculate the base fee of the next fee block by the gas used in the last fee block.
+    public fun next_base_fee(base_fee: u64, gas_used: u64): u64 {
+        let target = (TARGET_GAS_PER_FEE_BLOCK as u128);
+        let gas_used = (gas_used as u128);
+        let base_fee = (base_fee as u128);
+        let next_base_fee = if (gas_used > target) {
+            let delta = base_fee * (gas_used - target) / target / (BASE_FEE_MAX_CHANGE_DENOMINATOR as u128);
+            // the base fee increases at least 1 if the gas used is above the target
+            if (delta == 0) {
+                delta = 1;
+            };
+            base_fee + delta
+        } else {
+            let delta = base_fee * (target - gas_used) / target / (BASE_FEE_MAX_CHANGE_DENOMINATOR as u128);
+            if (base_fee > delta) {
+                base_fee - delta
+            } else {
+                0
+            }
+        };
+        if (next_base_fee < (MIN_BASE_FEE as u128)) {
+            MIN_BASE_FEE
+        } else if (next_base_fee > (MAX_U64 as u128)) {
+            MAX_U64
+        } else {
+            (next_base_fee as u64)
+        }
+    }
+
+    /// Adjust the base fee when a new fee block begins.
+    /// The `BaseFee` is only borrowed mutably by the first transaction of the fee block,
+    /// the other transactions only read it, so they do not conflict with each other.
+    public(friend) fun update_base_fee() {
+        let tx_sequence_info = tx_context::get_attribute<TransactionSequenceInfo>();
+        // The transaction is not sequenced, such as the dry run transaction
+        if (option::is_none(&tx_sequence_info)) {
+            return
+        };
+        let tx_order = transaction::tx_order(option::borrow(&tx_sequence_info));
+        let now = timestamp::now_milliseconds();
+        let base_fee_id = object::named_object_id<BaseFee>();
+        if (!object::exists_object(base_fee_id)) {
+            let obj = object::new_named_object(BaseFee{
+                base_fee: INITIAL_BASE_FEE,
+                fee_block_start_timestamp: now,
+                fee_block_start_tx_order: tx_order,
+            });
+            object::transfer_extend(obj, @rooch_framework);
+            return
+        };
+        let fee_block_start_timestamp = object::borrow(object::borrow_object<BaseFee>(base_fee_id)).fee_block_start_timestamp;
+        if (now < fee_block_start_timestamp + FEE_BLOCK_INTERVAL) {
+            return
+        };
+        let fee_block_gas_used = tx_context::get_attribute<FeeBlockGasUsed>();
+        let gas_used = if (option::is_some(&fee_block_gas_used)) {
+            let FeeBlockGasUsed { gas_used } = option::destroy_some(fee_block_gas_used);
+            gas_used
+        } else {
+            0
+        };
+        let base_fee_obj = object::borrow_mut_object_extend<BaseFee>(base_fee_id);
+        let base_fee = object::borrow_mut(base_fee_obj);
+        base_fee.base_fee = next_base_fee(base_fee.base_fee, gas_used);
+        base_fee.fee_block_start_timestamp = now;
+        base_fee.fee_block_start_tx_order = tx_order;
     }
 
     public(friend) fun withdraw_fee(amount: u256) : Coin<RGas> {
@@ -80,13 +298,17 @@ module rooch_framework::transaction_fee {
         coin_store::deposit<RGas>(&mut pool.fee, gas_coin);
     }
 
-    public(friend) fun distribute_fee(total_paid_gas: u256, gas_used: u256, contract_address: address, sequencer_address: address) : Coin<RGas> {
+    /// Distribute the fee of the used gas, the `priority_fee` is the part of `gas_used` above the base fee,
+    /// it goes to the sequencer.
+    public(friend) fun distribute_fee(total_paid_gas: u256, gas_used: u256, priority_fee: u256, contract_address: address, sequencer_address: address) : Coin<RGas> {
         assert!(total_paid_gas >= gas_used, ErrorInvalidGasUsed);
+        assert!(gas_used >= priority_fee, ErrorInvalidGasUsed);
         let total_paid_gas_coin = withdraw_fee(total_paid_gas);
         let used_gas_coin = coin::extract(&mut total_paid_gas_coin, gas_used);
-        
-        let sequencer_fee = gas_used * 30 / 100;
-        let developer_fee = gas_used * 30 / 100;
+        let base_fee = gas_used - priority_fee;
+
+        let sequencer_fee = base_fee * 30 / 100 + priority_fee;
+        let developer_fee = base_fee * 30 / 100;
         
         let sequencer_fee_coin = coin::extract(&mut used_gas_coin, sequencer_fee);
         let sequencer_fee_coin_store = borrow_mut_or_init_gas_revenue_store(sequencer_address);
@@ -144,7 +366,7 @@ module rooch_framework::transaction_fee {
         let gas_used = 100;
         let contract_address = @0x42;
         let sequencer_address = @0x43;
-        let remaining_gas_coin = distribute_fee(total_paid_gas, gas_used, contract_address, sequencer_address);
+        let remaining_gas_coin = distribute_fee(total_paid_gas, gas_used, 0, contract_address, sequencer_address);
         assert!(coin::value(&remaining_gas_coin) == 20, 1);
         
         let contract_signer = moveos_std::account::create_signer_for_testing(contract_address);
@@ -164,4 +386,34 @@ module rooch_framework::transaction_fee {
         coin::destroy_for_testing(sequencer_gas_revenue);
         coin::destroy_for_testing(system_gas_revenue);
     }
+
+    #[test]
+    fun test_distribute_fee_with_priority_fee(){
+        let system_signer = moveos_std::account::create_signer_for_testing(SystemFeeAddress);
+        rooch_framework::coin::init_for_testing();
+        rooch_framework::gas_coin::genesis_init(&system_signer);
+        genesis_init(&system_signer);
+
+        let gas_coin = rooch_framework::gas_coin::mint_for_test(200);
+        deposit_fee(gas_coin);
+        let contract_address = @0x42;
+        let sequencer_address = @0x43;
+        // the base fee part is 100, the priority fee is 100
+        let remaining_gas_coin = distribute_fee(200, 200, 100, contract_address, sequencer_address);
+        assert!(coin::value(&remaining_gas_coin) == 0, 1);
+        assert!(gas_revenue_balance(sequencer_address) == 130, 2);
+        assert!(gas_revenue_balance(contract_address) == 30, 3);
+        assert!(gas_revenue_balance(SystemFeeAddress) == 40, 4);
+        coin::destroy_zero(remaining_gas_coin);
+    }
+
+    #[test]
+    fun test_next_base_fee(){
+        assert!(next_base_fee(1000, TARGET_GAS_PER_FEE_BLOCK) == 1000, 1);
+        assert!(next_base_fee(1000, TARGET_GAS_PER_FEE_BLOCK * 2) == 1125, 2);
+        assert!(next_base_fee(1000, 0) == 875, 3);
+        // the base fee increases at least 1 if the gas used is above the target
+        assert!(next_base_fee(1, TARGET_GAS_PER_FEE_BLOCK + 1) == 2, 4);
+        assert!(next_base_fee(MIN_BASE_FEE, 0) == MIN_BASE_FEE, 5);
+    }
 }
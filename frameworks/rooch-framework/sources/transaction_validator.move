@@ -3,6 +3,8 @@
 
 module rooch_framework::transaction_validator {
     use std::option;
+    use std::vector;
+    use moveos_std::hash;
     use moveos_std::timestamp;
     use moveos_std::signer::module_signer;
     use moveos_std::tx_context;
@@ -18,10 +20,12 @@ module rooch_framework::transaction_validator {
     use rooch_framework::chain_id;
     use rooch_framework::transaction_fee;
     use rooch_framework::gas_coin;
-    use rooch_framework::transaction::{Self, TransactionSequenceInfo};
+    use rooch_framework::transaction::{Self, TransactionSequenceInfo, TransactionExpiration};
     use rooch_framework::session_validator;
     use rooch_framework::bitcoin_validator;
+    use rooch_framework::webauthn_validator;
     use rooch_framework::address_mapping;
+    use rooch_framework::bitcoin_address;
     use rooch_framework::account_coin_store;
     use rooch_framework::builtin_validators;
     use rooch_framework::onchain_config;
@@ -29,6 +33,9 @@ module rooch_framework::transaction_validator {
 
     const MAX_U64: u128 = 18446744073709551615;
 
+    /// The domain of the sponsor hash, it must be the same as the `SPONSOR_SIGN_DOMAIN` in Rust.
+    const SPONSOR_SIGN_DOMAIN: vector<u8> = b"ROOCH_SPONSOR::";
+
 
     /// Just using to get module signer
     struct TransactionValidatorPlaceholder {}
@@ -67,7 +74,22 @@ module rooch_framework::transaction_validator {
             auth_validator::error_validate_sequence_number_too_new(),
         );
 
+        // === validate the expiration ===
+        let tx_expiration = tx_context::get_attribute<TransactionExpiration>();
+        if (option::is_some(&tx_expiration)) {
+            let expiration_timestamp = transaction::expiration_timestamp(option::borrow(&tx_expiration));
+            assert!(
+                timestamp::now_milliseconds() < expiration_timestamp,
+                auth_validator::error_validate_transaction_expired(),
+            );
+        };
+
         // === validate gas ===
+        // The transaction without gas price pays the base fee
+        assert!(
+            transaction_fee::tx_gas_price() >= transaction_fee::base_fee(),
+            auth_validator::error_validate_gas_price_too_low(),
+        );
         let max_gas_amount = tx_context::max_gas_amount();
         let gas = transaction_fee::calculate_gas(max_gas_amount);
 
@@ -77,7 +99,9 @@ module rooch_framework::transaction_validator {
             auth_validator::error_validate_max_gas_amount_exceeded(),
         );
 
-        let gas_balance = gas_coin::balance(sender);
+        // The gas is paid by the sponsor if the transaction is sponsored
+        let gas_payment_account = tx_context::tx_gas_payment_account();
+        let gas_balance = gas_coin::balance(gas_payment_account);
 
         // we do not need to check the gas balance in local or dev chain
         if(!chain_id::is_local_or_dev()){
@@ -97,6 +121,10 @@ module rooch_framework::transaction_validator {
         }else if (auth_validator_id == bitcoin_validator::auth_validator_id()){
             let bitcoin_address = bitcoin_validator::validate(authenticator_payload);
             (option::some(bitcoin_address), option::none(), option::none())
+        }else if (auth_validator_id == webauthn_validator::auth_validator_id()){
+            // The account derived from the passkey may not exist yet, its Bitcoin address is bound in pre_execute
+            let bitcoin_address = webauthn_validator::validate(authenticator_payload);
+            (option::some(bitcoin_address), option::none(), option::none())
         }else{
             let auth_validator = auth_validator_registry::borrow_validator(auth_validator_id);
             let validator_id = auth_validator::validator_id(auth_validator);
@@ -112,6 +140,34 @@ module rooch_framework::transaction_validator {
         auth_validator::new_tx_validate_result(auth_validator_id, auth_validator, session_key, bitcoin_address)
     }
 
+    /// Validate the sponsor's authenticator of the sponsored transaction, return the sponsor address who pays the gas.
+    /// Only the Bitcoin authenticator is supported for the sponsor.
+    /// The sponsor in the transaction data is added to the tx context as the gas payment account,
+    /// the authenticator must be signed by it over the sponsor hash of the transaction.
+    public(friend) fun validate_sponsor(
+        auth_validator_id: u64,
+        authenticator_payload: vector<u8>
+    ): address {
+        assert!(
+            auth_validator_id == bitcoin_validator::auth_validator_id(),
+            auth_validator::error_validate_invalid_authenticator(),
+        );
+        let bitcoin_address = bitcoin_validator::validate_signer(authenticator_payload, sponsor_hash(tx_context::tx_hash()));
+        let sponsor = bitcoin_address::to_rooch_address(&bitcoin_address);
+        assert!(
+            sponsor == tx_context::tx_gas_payment_account(),
+            auth_validator::error_validate_invalid_authenticator(),
+        );
+        sponsor
+    }
+
+    /// The hash signed by the sponsor, it is separated from the tx hash signed by the sender.
+    public fun sponsor_hash(tx_hash: vector<u8>): vector<u8> {
+        let data = SPONSOR_SIGN_DOMAIN;
+        vector::append(&mut data, tx_hash);
+        hash::sha3_256(data)
+    }
+
     /// Transaction pre_execute function.
     /// Execute before the transaction is executed, automatically called by the MoveOS VM.
     /// This function is for Rooch to auto create account and address maping.
@@ -166,7 +222,17 @@ module rooch_framework::transaction_validator {
         let tx_result = tx_context::tx_result();
         let gas_payment_account = tx_context::tx_gas_payment_account();
         let gas_used = tx_result::gas_used(&tx_result);
-        let gas_used_after_scale = transaction_fee::calculate_gas(gas_used);
+        let storage_deposit_opt = tx_context::tx_storage_deposit();
+        let (storage_deposit, storage_rebate) = if (option::is_some(&storage_deposit_opt)) {
+            let storage_deposit = option::destroy_some(storage_deposit_opt);
+            (tx_result::deposit(&storage_deposit), tx_result::rebate(&storage_deposit))
+        } else {
+            (0, 0)
+        };
+        // The storage deposit is charged as gas, but it is kept in the storage deposit pool rather than distributed as fee
+        let fee_gas_used = gas_used - storage_deposit;
+        let gas_used_after_scale = transaction_fee::calculate_gas(fee_gas_used);
+        let priority_fee = gas_used_after_scale - transaction_fee::calculate_base_fee_gas(fee_gas_used);
 
         let max_gas_amount = tx_context::max_gas_amount();
         let paid_gas = transaction_fee::calculate_gas(max_gas_amount);
@@ -181,11 +247,13 @@ module rooch_framework::transaction_validator {
             @rooch_framework
         };
         let sequencer_address = onchain_config::sequencer();
-        let remaining_gas_coin = transaction_fee::distribute_fee(paid_gas, gas_used_after_scale, contract_address, sequencer_address);
+        let remaining_gas_coin = transaction_fee::distribute_fee(paid_gas, gas_used_after_scale, priority_fee, contract_address, sequencer_address);
+        transaction_fee::settle_storage_deposit(&mut remaining_gas_coin, gas_payment_account, storage_deposit, storage_rebate);
         if (coin::value(&remaining_gas_coin) > 0) {
             account_coin_store::deposit(gas_payment_account, remaining_gas_coin);
         }else{
             coin::destroy_zero(remaining_gas_coin);
-        }
+        };
+        transaction_fee::update_base_fee();
     }
 }
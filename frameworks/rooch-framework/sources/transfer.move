@@ -10,7 +10,7 @@ module rooch_framework::transfer {
     use rooch_framework::account_coin_store;
     use rooch_framework::multichain_address;
     use rooch_framework::address_mapping;
-    use rooch_framework::bitcoin_address;
+    use rooch_framework::bitcoin_address::{Self, BitcoinAddress};
 
     const ErrorAddressMappingNotExists: u64 = 1;
 
@@ -31,8 +31,7 @@ module rooch_framework::transfer {
         amount: u256,
     ) {
         let btc_address = bitcoin_address::from_string(&to);
-        let rooch_address = bitcoin_address::to_rooch_address(&btc_address);
-        address_mapping::bind_bitcoin_address_internal(rooch_address, btc_address);
+        let rooch_address = resolve_bitcoin_address(btc_address);
         account_coin_store::transfer<CoinType>(from, rooch_address, amount)
     }
 
@@ -52,6 +51,17 @@ module rooch_framework::transfer {
         account_coin_store::transfer<CoinType>(from, to, amount)
     }
 
+    /// Resolve the Bitcoin address to the Rooch address, the Bitcoin address may be rebound to a
+    /// multisign account whose participants are changed, otherwise the mapping is recorded.
+    fun resolve_bitcoin_address(btc_address: BitcoinAddress): address {
+        if(address_mapping::is_rebound_bitcoin_address(&btc_address)){
+            return address_mapping::resolve_bitcoin_to_rooch_address(&btc_address)
+        };
+        let rooch_address = bitcoin_address::to_rooch_address(&btc_address);
+        address_mapping::bind_bitcoin_address_internal(rooch_address, btc_address);
+        rooch_address
+    }
+
     /// Transfer `from` owned `Object<T>` to `to` account.
     public entry fun transfer_object<T: key + store>(to: address, obj: Object<T>) {
         object::transfer(obj, to);
@@ -62,8 +72,7 @@ module rooch_framework::transfer {
         to: String, 
         obj: Object<T>) {
         let btc_address = bitcoin_address::from_string(&to);
-        let rooch_address = bitcoin_address::to_rooch_address(&btc_address);
-        address_mapping::bind_bitcoin_address_internal(rooch_address, btc_address);
+        let rooch_address = resolve_bitcoin_address(btc_address);
         object::transfer(obj, rooch_address);
     }
 }
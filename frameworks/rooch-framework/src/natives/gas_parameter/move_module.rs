@@ -14,6 +14,8 @@ crate::natives::gas_parameter::native::define_gas_parameters_for_natives!(GasPar
     [.request_init_functions.per_function, "request_init_functions.per_function", 1000 * MUL],
     [.check_compatibililty_inner.base, "check_compatibililty_inner.base", 100_000 * MUL],
     [.check_compatibililty_inner.per_byte, "check_compatibililty_inner.per_byte", 20 * MUL],
+    [.check_upgrade_policy_inner.base, optional "check_upgrade_policy_inner.base", 100_000 * MUL],
+    [.check_upgrade_policy_inner.per_byte, optional "check_upgrade_policy_inner.per_byte", 20 * MUL],
     [.replace_address_identifiers.base, "replace_address_identifiers.base", 5000 * MUL],
     [.replace_address_identifiers.per_byte, "replace_address_identifiers.per_byte", 20 * MUL],
     [.replace_addresses_constant.base, "replace_addresses_constant.base", 5000 * MUL],
@@ -15,6 +15,9 @@ pub trait ClassifiedGasMeter {
     fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()>;
     // fn charge_io_read(&mut self);
     fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()>;
+    /// The storage deposit per byte of the object value
+    fn storage_deposit_per_byte(&self) -> u64;
+    fn charge_storage_deposit(&mut self, deposit: u64) -> PartialVMResult<()>;
     //fn charge_event(&mut self, events: &[TransactionEvent]) -> PartialVMResult<()>;
     //fn charge_change_set(&mut self, change_set: &StateChangeSet) -> PartialVMResult<()>;
     fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()>;
@@ -30,6 +33,14 @@ impl ClassifiedGasMeter for UnmeteredGasMeter {
         Ok(())
     }
 
+    fn storage_deposit_per_byte(&self) -> u64 {
+        0
+    }
+
+    fn charge_storage_deposit(&mut self, _deposit: u64) -> PartialVMResult<()> {
+        Ok(())
+    }
+
     fn check_constrains(&self, _max_gas_amount: u64) -> PartialVMResult<()> {
         Ok(())
     }
@@ -56,6 +56,8 @@ impl CallFrame {
     fn to_erased(&self) -> Node<InternalGas> {
         let name = match &self.name {
             FrameName::Script => "script".to_string(),
+            FrameName::ModuleBundle => "module_bundle".to_string(),
+            FrameName::MultiCall => "multi_call".to_string(),
             FrameName::Function {
                 module_id,
                 name,
@@ -115,6 +117,7 @@ impl ExecutionGasEvent {
                 fn_name,
                 ty_args,
                 cost,
+                ..
             } => Node::new(
                 format!(
                     "{}",
@@ -26,6 +26,8 @@ pub enum ExecutionGasEvent {
         module_id: ModuleId,
         fn_name: Identifier,
         ty_args: Vec<TypeTag>,
+        args: Vec<String>,
+        return_values: Vec<String>,
         cost: InternalGas,
     },
     LoadResource {
@@ -39,10 +41,12 @@ pub enum ExecutionGasEvent {
 }
 
 /// An enum representing the name of a call frame.
-/// Could be either a script or a function.
+/// Could be either a script, a module bundle or a function.
 #[derive(Debug, Clone)]
 pub enum FrameName {
     Script,
+    ModuleBundle,
+    MultiCall,
     Function {
         module_id: ModuleId,
         name: Identifier,
@@ -51,10 +55,11 @@ pub enum FrameName {
 }
 
 /// A struct containing information about a function call, including the name of the
-/// function and all gas events that happened during the call.
+/// function, the rendered arguments and all gas events that happened during the call.
 #[derive(Debug, Clone)]
 pub struct CallFrame {
     pub name: FrameName,
+    pub args: Vec<String>,
     pub events: Vec<ExecutionGasEvent>,
 }
 
@@ -66,6 +71,7 @@ impl CallFrame {
                 name,
                 ty_args,
             },
+            args: vec![],
             events: vec![],
         }
     }
@@ -73,9 +79,51 @@ impl CallFrame {
     pub fn new_script() -> Self {
         Self {
             name: FrameName::Script,
+            args: vec![],
             events: vec![],
         }
     }
+
+    pub fn new_module_bundle() -> Self {
+        Self {
+            name: FrameName::ModuleBundle,
+            args: vec![],
+            events: vec![],
+        }
+    }
+
+    pub fn new_multi_call() -> Self {
+        Self {
+            name: FrameName::MultiCall,
+            args: vec![],
+            events: vec![],
+        }
+    }
+
+    pub fn with_args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// The total gas cost of the frame, including the cost of the nested calls.
+    pub fn total_cost(&self) -> InternalGas {
+        self.events
+            .iter()
+            .fold(InternalGas::zero(), |total, event| total + event.cost())
+    }
+}
+
+impl ExecutionGasEvent {
+    pub fn cost(&self) -> InternalGas {
+        match self {
+            ExecutionGasEvent::Loc(_) => InternalGas::zero(),
+            ExecutionGasEvent::Bytecode { cost, .. }
+            | ExecutionGasEvent::CallNative { cost, .. }
+            | ExecutionGasEvent::LoadResource { cost, .. }
+            | ExecutionGasEvent::CreateTy { cost } => *cost,
+            ExecutionGasEvent::Call(frame) => frame.total_cost(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::log::{CallFrame, ExecutionAndIOCosts, ExecutionGasEvent, FrameName, TransactionGasLog};
+use crate::render::render_value;
 use move_binary_format::file_format::CodeOffset;
 use move_binary_format::file_format_common::Opcodes;
 use move_core_types::account_address::AccountAddress;
@@ -12,7 +13,7 @@ use move_vm_types::gas::{GasMeter, SimpleInstruction};
 use move_vm_types::natives::function::PartialVMResult;
 use move_vm_types::views::{TypeView, ValueView};
 use moveos_common::types::{ClassifiedGasMeter, GasStatement, SwitchableGasMeter};
-use moveos_types::transaction::MoveAction;
+use moveos_types::transaction::{MoveAction, VerifiedMoveAction};
 use std::sync::{Arc, RwLock};
 
 #[derive(Debug, Clone)]
@@ -68,6 +69,30 @@ impl<G> GasProfiler<G> {
             metering: true,
         }
     }
+
+    pub fn new_script(base: G) -> Self {
+        Self {
+            base,
+            frames: Arc::new(RwLock::new(vec![CallFrame::new_script()])),
+            metering: true,
+        }
+    }
+
+    pub fn new_module_bundle(base: G) -> Self {
+        Self {
+            base,
+            frames: Arc::new(RwLock::new(vec![CallFrame::new_module_bundle()])),
+            metering: true,
+        }
+    }
+
+    pub fn new_multi_call(base: G) -> Self {
+        Self {
+            base,
+            frames: Arc::new(RwLock::new(vec![CallFrame::new_multi_call()])),
+            metering: true,
+        }
+    }
 }
 
 impl<G: GasMeter> GasProfiler<G> {
@@ -95,6 +120,21 @@ impl<G: GasMeter> GasProfiler<G> {
         }
     }
 
+    /// Push a new call frame, the frames are only tracked when metering.
+    fn push_frame(&mut self, frame: CallFrame) {
+        if self.metering {
+            self.frames.write().unwrap().push(frame);
+        }
+    }
+
+    fn render_args(&self, args: impl Iterator<Item = impl ValueView>) -> Vec<String> {
+        if self.metering {
+            args.map(|arg| render_value(&arg)).collect()
+        } else {
+            vec![]
+        }
+    }
+
     /// Delegate the charging call to the base gas meter and measure variation in balance.
     fn delegate_charge<F, R>(&mut self, charge: F) -> (InternalGas, R)
     where
@@ -273,7 +313,10 @@ impl<G: GasMeter> GasMeter for GasProfiler<G> {
         // and we need to convert the current CallFrame into a GasEvent.
         // [call_frame_1, call_frame_2, call_frame_3]
         // [call_frame_1, call_frame_2(events: [Bytecode::Op, Call(call_frame_3)])]
-        if matches!(instr, SimpleInstruction::Ret) && self.frames.read().unwrap().len() > 1 {
+        if self.metering
+            && matches!(instr, SimpleInstruction::Ret)
+            && self.frames.read().unwrap().len() > 1
+        {
             let cur_frame = self
                 .frames
                 .write()
@@ -326,17 +369,21 @@ impl<G: GasMeter> GasMeter for GasProfiler<G> {
         args: impl ExactSizeIterator<Item = impl ValueView> + Clone,
         num_locals: NumArgs,
     ) -> PartialVMResult<()> {
+        let rendered_args = self.render_args(args.clone());
         let (cost, res) =
             self.delegate_charge(|base| base.charge_call(module_id, func_name, args, num_locals));
 
         //println!("charge_call {:?}::{:?}", module_id, func_name);
 
         self.record_bytecode(Opcodes::CALL, cost);
-        self.frames.write().unwrap().push(CallFrame::new_function(
-            module_id.clone(),
-            Identifier::new(func_name).unwrap(),
-            vec![],
-        ));
+        self.push_frame(
+            CallFrame::new_function(
+                module_id.clone(),
+                Identifier::new(func_name).unwrap(),
+                vec![],
+            )
+            .with_args(rendered_args),
+        );
 
         res
     }
@@ -354,16 +401,21 @@ impl<G: GasMeter> GasMeter for GasProfiler<G> {
             .map(|ty| ty.to_type_tag())
             .collect::<Vec<_>>();
 
+        let rendered_args = self.render_args(args.clone());
+
         let (cost, res) = self.delegate_charge(|base| {
             base.charge_call_generic(module_id, func_name, ty_args, args, num_locals)
         });
 
         self.record_bytecode(Opcodes::CALL_GENERIC, cost);
-        self.frames.write().unwrap().push(CallFrame::new_function(
-            module_id.clone(),
-            Identifier::new(func_name).unwrap(),
-            ty_tags,
-        ));
+        self.push_frame(
+            CallFrame::new_function(
+                module_id.clone(),
+                Identifier::new(func_name).unwrap(),
+                ty_tags,
+            )
+            .with_args(rendered_args),
+        );
 
         res
     }
@@ -394,9 +446,18 @@ impl<G: GasMeter> GasMeter for GasProfiler<G> {
         amount: InternalGas,
         ret_vals: Option<impl ExactSizeIterator<Item = impl ValueView> + Clone>,
     ) -> PartialVMResult<()> {
+        let return_values = match &ret_vals {
+            Some(ret_vals) => self.render_args(ret_vals.clone()),
+            None => vec![],
+        };
         let (cost, res) =
             self.delegate_charge(|base| base.charge_native_function(amount, ret_vals));
 
+        // The frames are not created when the metering is stopped.
+        if !self.metering {
+            return res;
+        }
+
         // Whenever a function gets called, the VM will notify the gas profiler
         // via `charge_call/charge_call_generic`.
         //
@@ -417,7 +478,7 @@ impl<G: GasMeter> GasMeter for GasProfiler<G> {
                 name,
                 ty_args,
             } => (module_id, name, ty_args),
-            FrameName::Script => unreachable!(),
+            FrameName::Script | FrameName::ModuleBundle | FrameName::MultiCall => unreachable!(),
         };
         // The following line of code is needed for correctness.
         //
@@ -435,6 +496,8 @@ impl<G: GasMeter> GasMeter for GasProfiler<G> {
             module_id,
             fn_name: name,
             ty_args,
+            args: cur.args,
+            return_values,
             cost,
         });
 
@@ -470,7 +533,7 @@ impl<G: GasMeter> ProfileGasMeter for GasProfiler<G> {
                 .expect("frame must exist"),
         };
 
-        self.stop_metering();
+        self.metering = false;
 
         TransactionGasLog {
             exec_io,
@@ -479,34 +542,41 @@ impl<G: GasMeter> ProfileGasMeter for GasProfiler<G> {
     }
 }
 
-impl<G: GasMeter> ClassifiedGasMeter for GasProfiler<G> {
-    fn charge_execution(&mut self, _gas_cost: u64) -> PartialVMResult<()> {
-        Ok(())
+impl<G: GasMeter + ClassifiedGasMeter> ClassifiedGasMeter for GasProfiler<G> {
+    fn charge_execution(&mut self, gas_cost: u64) -> PartialVMResult<()> {
+        self.base.charge_execution(gas_cost)
     }
 
-    fn charge_io_write(&mut self, _data_size: u64) -> PartialVMResult<()> {
-        Ok(())
+    fn charge_io_write(&mut self, data_size: u64) -> PartialVMResult<()> {
+        self.base.charge_io_write(data_size)
     }
 
-    fn check_constrains(&self, _max_gas_amount: u64) -> PartialVMResult<()> {
-        Ok(())
+    fn storage_deposit_per_byte(&self) -> u64 {
+        self.base.storage_deposit_per_byte()
+    }
+
+    fn charge_storage_deposit(&mut self, deposit: u64) -> PartialVMResult<()> {
+        self.base.charge_storage_deposit(deposit)
+    }
+
+    fn check_constrains(&self, max_gas_amount: u64) -> PartialVMResult<()> {
+        self.base.check_constrains(max_gas_amount)
     }
 
     fn gas_statement(&self) -> GasStatement {
-        GasStatement {
-            execution_gas_used: InternalGas::zero(),
-            storage_gas_used: InternalGas::zero(),
-        }
+        self.base.gas_statement()
     }
 }
 
-impl<G: GasMeter> SwitchableGasMeter for GasProfiler<G> {
+impl<G: GasMeter + SwitchableGasMeter> SwitchableGasMeter for GasProfiler<G> {
     fn stop_metering(&mut self) {
         self.metering = false;
+        self.base.stop_metering();
     }
 
     fn start_metering(&mut self) {
         self.metering = true;
+        self.base.start_metering();
     }
 
     fn is_metering(&self) -> bool {
@@ -516,13 +586,31 @@ impl<G: GasMeter> SwitchableGasMeter for GasProfiler<G> {
 
 pub fn new_gas_profiler<G>(action: MoveAction, base_gas_meter: G) -> GasProfiler<G> {
     match action {
-        MoveAction::Script(_) => unreachable!("Script payload is not supported yet"),
+        MoveAction::Script(_) => GasProfiler::new_script(base_gas_meter),
         MoveAction::Function(call) => GasProfiler::new_function(
             base_gas_meter,
             call.function_id.module_id,
             call.function_id.function_name,
             call.ty_args,
         ),
-        MoveAction::ModuleBundle(_) => unreachable!("ModuleBundle payload is not supported yet"),
+        MoveAction::ModuleBundle(_) => GasProfiler::new_module_bundle(base_gas_meter),
+        MoveAction::MultiCall(_) => GasProfiler::new_multi_call(base_gas_meter),
+    }
+}
+
+pub fn new_verified_action_gas_profiler<G>(
+    action: &VerifiedMoveAction,
+    base_gas_meter: G,
+) -> GasProfiler<G> {
+    match action {
+        VerifiedMoveAction::Script { .. } => GasProfiler::new_script(base_gas_meter),
+        VerifiedMoveAction::Function { call, .. } => GasProfiler::new_function(
+            base_gas_meter,
+            call.function_id.module_id.clone(),
+            call.function_id.function_name.clone(),
+            call.ty_args.clone(),
+        ),
+        VerifiedMoveAction::ModuleBundle { .. } => GasProfiler::new_module_bundle(base_gas_meter),
+        VerifiedMoveAction::MultiCall { .. } => GasProfiler::new_multi_call(base_gas_meter),
     }
 }
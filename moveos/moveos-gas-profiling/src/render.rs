@@ -5,6 +5,8 @@ use crate::log::FrameName;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::IdentStr;
 use move_core_types::language_storage::{ModuleId, TypeTag};
+use move_core_types::u256::U256;
+use move_vm_types::views::{ValueView, ValueVisitor};
 use std::fmt;
 use std::fmt::Display;
 
@@ -53,6 +55,7 @@ impl Display for FrameName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Script => write!(f, "<script>"),
+            Self::ModuleBundle => write!(f, "<module_bundle>"),
             Self::Function {
                 module_id,
                 name: fn_name,
@@ -65,3 +68,112 @@ impl Display for FrameName {
         }
     }
 }
+
+/// Render a runtime value in a compact human readable format, structs are rendered as `{..}`,
+/// vectors as `[..]`, byte vectors as hex strings and references as `&..`.
+pub(crate) fn render_value(val: &impl ValueView) -> String {
+    let mut renderer = ValueRenderer::default();
+    val.visit(&mut renderer);
+    renderer.finish()
+}
+
+#[derive(Default)]
+struct ValueRenderer {
+    out: String,
+    /// The open containers, with their depth and closing delimiter.
+    open: Vec<(usize, Option<char>)>,
+    need_separator: bool,
+}
+
+impl ValueRenderer {
+    fn close_containers(&mut self, depth: usize) {
+        while let Some((open_depth, closing)) = self.open.last() {
+            if *open_depth < depth {
+                break;
+            }
+            if let Some(closing) = closing {
+                self.out.push(*closing);
+            }
+            self.open.pop();
+            self.need_separator = true;
+        }
+    }
+
+    fn begin_value(&mut self, depth: usize) {
+        self.close_containers(depth);
+        if self.need_separator {
+            self.out.push_str(", ");
+        }
+    }
+
+    fn write_value(&mut self, depth: usize, val: impl Display) {
+        self.begin_value(depth);
+        self.out.push_str(&val.to_string());
+        self.need_separator = true;
+    }
+
+    fn open_container(&mut self, depth: usize, opening: &str, closing: Option<char>) {
+        self.begin_value(depth);
+        self.out.push_str(opening);
+        self.open.push((depth, closing));
+        self.need_separator = false;
+    }
+
+    fn finish(mut self) -> String {
+        self.close_containers(0);
+        self.out
+    }
+}
+
+impl ValueVisitor for ValueRenderer {
+    fn visit_u8(&mut self, depth: usize, val: u8) {
+        self.write_value(depth, val)
+    }
+
+    fn visit_u16(&mut self, depth: usize, val: u16) {
+        self.write_value(depth, val)
+    }
+
+    fn visit_u32(&mut self, depth: usize, val: u32) {
+        self.write_value(depth, val)
+    }
+
+    fn visit_u64(&mut self, depth: usize, val: u64) {
+        self.write_value(depth, val)
+    }
+
+    fn visit_u128(&mut self, depth: usize, val: u128) {
+        self.write_value(depth, val)
+    }
+
+    fn visit_u256(&mut self, depth: usize, val: U256) {
+        self.write_value(depth, val)
+    }
+
+    fn visit_bool(&mut self, depth: usize, val: bool) {
+        self.write_value(depth, val)
+    }
+
+    fn visit_address(&mut self, depth: usize, val: AccountAddress) {
+        self.write_value(depth, val.to_hex_literal())
+    }
+
+    fn visit_struct(&mut self, depth: usize, _len: usize) -> bool {
+        self.open_container(depth, "{", Some('}'));
+        true
+    }
+
+    fn visit_vec(&mut self, depth: usize, _len: usize) -> bool {
+        self.open_container(depth, "[", Some(']'));
+        true
+    }
+
+    fn visit_ref(&mut self, depth: usize, _is_global: bool) -> bool {
+        self.open_container(depth, "&", None);
+        true
+    }
+
+    fn visit_vec_u8(&mut self, depth: usize, vals: &[u8]) {
+        self.write_value(depth, format!("0x{}", hex::encode(vals)))
+    }
+}
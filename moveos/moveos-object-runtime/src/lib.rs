@@ -7,6 +7,7 @@ use move_vm_runtime::native_functions::NativeContext;
 use move_vm_types::loaded_data::runtime_types::Type;
 use runtime::partial_extension_error;
 
+pub mod read_write_set;
 pub mod resolved_arg;
 pub mod runtime;
 pub mod runtime_object;
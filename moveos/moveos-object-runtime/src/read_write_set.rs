@@ -0,0 +1,187 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
+use moveos_types::state::{FieldKey, ObjectChange, ObjectState, StateChangeSet};
+use moveos_types::state_resolver::{StateKV, StatelessResolver};
+use parking_lot::Mutex;
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The objects read by a transaction from the state, the root object is not included.
+///
+/// Every object is loaded through its parent by the `ObjectRuntime`, so a read of a field
+/// implies the read of all its ancestors, and the absent fields are only recorded for the root.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReadSet {
+    objects: BTreeSet<ObjectID>,
+    /// The objects first loaded while executing the transaction action,
+    /// the others are only loaded by the system pre_execute and post_execute functions.
+    action_objects: BTreeSet<ObjectID>,
+    /// The transaction listed the fields of the root object, it depends on every object.
+    root_listed: bool,
+}
+
+impl ReadSet {
+    pub fn objects(&self) -> &BTreeSet<ObjectID> {
+        &self.objects
+    }
+
+    pub fn is_root_listed(&self) -> bool {
+        self.root_listed
+    }
+
+    /// Check if the object is loaded by the transaction action.
+    /// An object loaded by the system functions before the action is cached in the `ObjectRuntime`,
+    /// so it is not recorded again if the action reads it.
+    pub fn is_read_by_action(&self, object_id: &ObjectID) -> bool {
+        self.action_objects.contains(object_id)
+    }
+
+    /// Check if the read objects are changed by the `write_set`
+    pub fn conflicts_with(&self, write_set: &WriteSet) -> bool {
+        if self.root_listed {
+            return !write_set.is_empty();
+        }
+        !self.objects.is_disjoint(&write_set.objects)
+    }
+
+    /// Returns the read objects changed by the `write_set`,
+    /// returns None if the transaction listed the fields of the root object and the `write_set` is not empty.
+    pub fn conflicting_objects(&self, write_set: &WriteSet) -> Option<BTreeSet<ObjectID>> {
+        if self.root_listed && !write_set.is_empty() {
+            return None;
+        }
+        Some(
+            self.objects
+                .intersection(&write_set.objects)
+                .cloned()
+                .collect(),
+        )
+    }
+}
+
+/// The objects changed by a transaction, the root object is not included.
+///
+/// A changed field also changes the metadata(state_root and size) of its parent,
+/// so all the ancestors of a changed object are in the write set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WriteSet {
+    objects: BTreeSet<ObjectID>,
+}
+
+impl WriteSet {
+    pub fn from_change_set(change_set: &StateChangeSet) -> Self {
+        let mut objects = BTreeSet::new();
+        for change in change_set.changes.values() {
+            Self::collect_object_change(&mut objects, change);
+        }
+        Self { objects }
+    }
+
+    fn collect_object_change(objects: &mut BTreeSet<ObjectID>, change: &ObjectChange) {
+        objects.insert(change.metadata.id.clone());
+        for field_change in change.fields.values() {
+            Self::collect_object_change(objects, field_change);
+        }
+    }
+
+    pub fn objects(&self) -> &BTreeSet<ObjectID> {
+        &self.objects
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    pub fn conflicts_with(&self, other: &WriteSet) -> bool {
+        !self.objects.is_disjoint(&other.objects)
+    }
+
+    /// Returns the objects changed by both write sets
+    pub fn conflicting_objects(&self, other: &WriteSet) -> BTreeSet<ObjectID> {
+        self.objects.intersection(&other.objects).cloned().collect()
+    }
+
+    pub fn merge(&mut self, other: WriteSet) {
+        self.objects.extend(other.objects);
+    }
+}
+
+/// A `StatelessResolver` wrapper which records the objects read by the `ObjectRuntime`
+/// on top of the given root object.
+pub struct ReadSetRecorder<'a, R> {
+    root_state_root: H256,
+    resolver: &'a R,
+    read_set: Mutex<ReadSet>,
+    in_action: AtomicBool,
+}
+
+impl<'a, R> ReadSetRecorder<'a, R>
+where
+    R: StatelessResolver,
+{
+    pub fn new(root: &ObjectMeta, resolver: &'a R) -> Self {
+        Self {
+            root_state_root: root.state_root(),
+            resolver,
+            read_set: Mutex::new(ReadSet::default()),
+            in_action: AtomicBool::new(false),
+        }
+    }
+
+    /// Mark the following reads are made by the transaction action or by the system functions
+    pub fn set_in_action(&self, in_action: bool) {
+        self.in_action.store(in_action, Ordering::SeqCst);
+    }
+
+    fn record(&self, read_set: &mut ReadSet, object_id: ObjectID) {
+        if self.in_action.load(Ordering::SeqCst) {
+            read_set.action_objects.insert(object_id.clone());
+        }
+        read_set.objects.insert(object_id);
+    }
+
+    pub fn into_read_set(self) -> ReadSet {
+        self.read_set.into_inner()
+    }
+}
+
+impl<R> StatelessResolver for ReadSetRecorder<'_, R>
+where
+    R: StatelessResolver,
+{
+    fn get_field_at(&self, state_root: H256, key: &FieldKey) -> Result<Option<ObjectState>> {
+        let state = self.resolver.get_field_at(state_root, key)?;
+        match &state {
+            Some(state) => {
+                self.record(&mut self.read_set.lock(), state.metadata.id.clone());
+            }
+            // The absent field of other objects is covered by the read of the parent object
+            None if state_root == self.root_state_root => {
+                self.record(&mut self.read_set.lock(), ObjectID::root().child_id(*key));
+            }
+            None => {}
+        }
+        Ok(state)
+    }
+
+    fn list_fields_at(
+        &self,
+        state_root: H256,
+        cursor: Option<FieldKey>,
+        limit: usize,
+    ) -> Result<Vec<StateKV>> {
+        let fields = self.resolver.list_fields_at(state_root, cursor, limit)?;
+        let mut read_set = self.read_set.lock();
+        if state_root == self.root_state_root {
+            read_set.root_listed = true;
+        }
+        for (_key, state) in fields.iter() {
+            self.record(&mut read_set, state.metadata.id.clone());
+        }
+        Ok(fields)
+    }
+}
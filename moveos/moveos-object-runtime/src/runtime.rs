@@ -12,12 +12,16 @@ use crate::{
 use better_any::{Tid, TidAble};
 use move_binary_format::errors::{Location, PartialVMError, PartialVMResult, VMResult};
 use move_core_types::{
-    account_address::AccountAddress, gas_algebra::NumBytes, language_storage::ModuleId,
-    value::MoveTypeLayout, vm_status::StatusCode,
+    account_address::AccountAddress,
+    gas_algebra::NumBytes,
+    language_storage::{ModuleId, TypeTag},
+    value::MoveTypeLayout,
+    vm_status::StatusCode,
 };
 use move_vm_types::loaded_data::runtime_types::Type;
-use move_vm_types::values::{StructRef, Value};
+use move_vm_types::values::{Reference, StructRef, Value};
 use moveos_types::moveos_std::onchain_features::FeatureStore;
+use moveos_types::moveos_std::{storage_deposit::StorageDepositRecord, tx_result::StorageDeposit};
 use moveos_types::{
     move_std::string::MoveString,
     moveos_std::timestamp::Timestamp,
@@ -41,6 +45,33 @@ use parking_lot::RwLock;
 use std::{collections::BTreeMap, rc::Rc};
 use tracing::debug;
 
+/// The storage deposit records are settled outside of the Move functions,
+/// so we use this loader to resolve the layout of the records loaded from the state.
+struct StorageDepositRecordLoader;
+
+impl TypeLayoutLoader for StorageDepositRecordLoader {
+    fn get_type_layout(&self, type_tag: &TypeTag) -> PartialVMResult<MoveTypeLayout> {
+        if StorageDepositRecord::type_tag_match(type_tag) {
+            Ok(StorageDepositRecord::type_layout())
+        } else {
+            Err(partial_extension_error(format!(
+                "unexpected storage deposit record type: {}",
+                type_tag
+            )))
+        }
+    }
+    fn type_to_type_layout(&self, _ty: &Type) -> PartialVMResult<MoveTypeLayout> {
+        Err(partial_extension_error(
+            "storage deposit record loader does not support runtime type",
+        ))
+    }
+    fn type_to_type_tag(&self, _ty: &Type) -> PartialVMResult<TypeTag> {
+        Err(partial_extension_error(
+            "storage deposit record loader does not support runtime type",
+        ))
+    }
+}
+
 /// Ensure the error codes in this file is consistent with the error code in object.move
 pub const ERROR_ALREADY_EXISTS: u64 = 1;
 pub const ERROR_NOT_FOUND: u64 = 2;
@@ -478,6 +509,73 @@ impl<'r> ObjectRuntime<'r> {
         Ok(())
     }
 
+    /// Settle the storage deposit of the objects changed in the transaction so far,
+    /// return the total deposit charged and rebate refunded.
+    /// The deposit of each object is kept in its `StorageDepositRecord` root object:
+    /// * The object created in the transaction is charged the full deposit, and a record is created.
+    /// * The object with a record is charged or refunded the difference with the recorded deposit,
+    ///   the record is removed with the object.
+    /// * The object created before the storage deposit was introduced has no record, it is exempt.
+    pub fn settle_storage_deposit(
+        &mut self,
+        deposit_per_byte: u64,
+    ) -> PartialVMResult<StorageDeposit> {
+        let mut storage_deposit = StorageDeposit::default();
+        if deposit_per_byte == 0 {
+            return Ok(storage_deposit);
+        }
+        let mut changes = vec![];
+        //The root object is not charged, only the objects in the global state
+        for field in self.root.fields.values() {
+            field.collect_storage_changes(&mut changes)?;
+        }
+        let resolver = self.resolver;
+        let record_type = StorageDepositRecord::type_tag();
+        for change in changes {
+            let required = change
+                .bytes_len
+                .map(|len| len.saturating_mul(deposit_per_byte))
+                .unwrap_or_default();
+            let record_key = StorageDepositRecord::record_id(&change.object_id).field_key();
+            let (record_obj, _) =
+                self.root
+                    .load_field(&StorageDepositRecordLoader, resolver, record_key)?;
+            if record_obj.exists_with_type(&record_type)? {
+                let record_ref = record_obj
+                    .borrow_value(Some(&record_type))?
+                    .value_as::<Reference>()?;
+                let previous = StorageDepositRecord::from_runtime_value(record_ref.read_ref()?)
+                    .map_err(|e| partial_extension_error(e.to_string()))?
+                    .deposit;
+                storage_deposit.settle(previous, required);
+                if change.bytes_len.is_some() {
+                    if previous != required {
+                        let record = StorageDepositRecord { deposit: required };
+                        record_obj
+                            .borrow_value(Some(&record_type))?
+                            .value_as::<Reference>()?
+                            .write_ref(record.to_runtime_value())?;
+                    }
+                } else {
+                    //The object is removed, remove the record with it
+                    record_obj.move_from(Some(&record_type))?;
+                    record_obj.pointer.value.move_from()?;
+                    self.root.rt_meta.decrease_size()?;
+                }
+            } else if change.is_fresh && change.bytes_len.is_some() {
+                storage_deposit.settle(0, required);
+                let record = StorageDepositRecord { deposit: required };
+                record_obj.move_to(
+                    record.to_runtime_value(),
+                    record_type.clone(),
+                    StorageDepositRecord::type_layout(),
+                )?;
+                self.root.rt_meta.increase_size()?;
+            }
+        }
+        Ok(storage_deposit)
+    }
+
     // into inner
     pub fn into_inner(self) -> (TxContext, RuntimeObject) {
         let ObjectRuntime {
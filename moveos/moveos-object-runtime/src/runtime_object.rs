@@ -25,6 +25,7 @@ use moveos_types::{
     moveos_std::{
         move_module::MoveModule,
         object::{DynamicField, ObjectID, ObjectMeta},
+        storage_deposit::StorageDepositRecord,
         timestamp::Timestamp,
     },
     state::{FieldKey, MoveState, MoveType, ObjectChange, ObjectState},
@@ -36,6 +37,15 @@ type ScanFieldList = Vec<(FieldKey, Value)>;
 type FieldList = Vec<(FieldKey, RuntimeObject, Option<Option<NumBytes>>)>;
 type FieldKeyList = Vec<(FieldKey, Option<Option<NumBytes>>)>;
 
+/// The value bytes of an object changed in the transaction.
+pub(crate) struct StorageChange {
+    pub object_id: ObjectID,
+    /// The length of the value bytes, `None` if the object is removed.
+    pub bytes_len: Option<u64>,
+    /// The object is created in the transaction.
+    pub is_fresh: bool,
+}
+
 /// A structure representing a single runtime object.
 pub struct RuntimeObject {
     pub(crate) rt_meta: RuntimeObjectMeta,
@@ -354,6 +364,43 @@ impl RuntimeObject {
             .filter(|rt_obj| !rt_obj.is_none())
     }
 
+    /// Collect the value bytes of the object and its loaded fields changed in the transaction,
+    /// the storage deposit records are settled according to them.
+    pub(crate) fn collect_storage_changes(
+        &self,
+        changes: &mut Vec<StorageChange>,
+    ) -> PartialVMResult<()> {
+        if self.is_none() {
+            //The object is not exists, or it is created and removed in the same transaction
+            return Ok(());
+        }
+        if StorageDepositRecord::type_tag() == *self.rt_meta.value_type()? {
+            //The storage deposit record itself is not charged
+            return Ok(());
+        }
+        if self.value.is_mutated() {
+            let bytes_len = if self.value.exists()? {
+                let value = self
+                    .value
+                    .borrow_global()?
+                    .value_as::<Reference>()?
+                    .read_ref()?;
+                Some(serialize(self.rt_meta.value_layout()?, &value)?.len() as u64)
+            } else {
+                None
+            };
+            changes.push(StorageChange {
+                object_id: self.id().clone(),
+                bytes_len,
+                is_fresh: self.is_fresh(),
+            });
+        }
+        for field in self.fields.values() {
+            field.collect_storage_changes(changes)?;
+        }
+        Ok(())
+    }
+
     pub fn into_change(self, timestamp: &Timestamp) -> PartialVMResult<Option<ObjectChange>> {
         let object_id = self.id().clone();
         let mut rt_meta = self.rt_meta;
@@ -1,8 +1,12 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{CONFIG_GENESIS_COLUMN_FAMILY_NAME, CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME};
+use crate::{
+    CONFIG_FORK_COLUMN_FAMILY_NAME, CONFIG_GENESIS_COLUMN_FAMILY_NAME,
+    CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME,
+};
 use anyhow::Result;
+use moveos_types::fork_info::ForkInfo;
 use moveos_types::genesis_info::GenesisInfo;
 use moveos_types::startup_info::StartupInfo;
 use raw_store::{derive_store, CodecKVStore, StoreInstance};
@@ -10,6 +14,7 @@ use std::string::ToString;
 
 pub const STARTUP_INFO_KEY: &str = "startup_info";
 pub const GENESIS_KEY: &str = "genesis";
+pub const FORK_INFO_KEY: &str = "fork_info";
 
 derive_store!(
     StartupInfoStore,
@@ -23,6 +28,12 @@ derive_store!(
     GenesisInfo,
     CONFIG_GENESIS_COLUMN_FAMILY_NAME
 );
+derive_store!(
+    ForkInfoStore,
+    String,
+    ForkInfo,
+    CONFIG_FORK_COLUMN_FAMILY_NAME
+);
 
 pub trait ConfigStore {
     fn get_startup_info(&self) -> Result<Option<StartupInfo>>;
@@ -32,19 +43,25 @@ pub trait ConfigStore {
     fn get_genesis(&self) -> Result<Option<GenesisInfo>>;
 
     fn save_genesis(&self, genesis_info: GenesisInfo) -> Result<()>;
+
+    fn get_fork_info(&self) -> Result<Option<ForkInfo>>;
+
+    fn save_fork_info(&self, fork_info: ForkInfo) -> Result<()>;
 }
 
 #[derive(Clone)]
 pub struct ConfigDBStore {
     startup_store: StartupInfoStore,
     genesis_store: GenesisStore,
+    fork_info_store: ForkInfoStore,
 }
 
 impl ConfigDBStore {
     pub fn new(instance: StoreInstance) -> Self {
         ConfigDBStore {
             startup_store: StartupInfoStore::new(instance.clone()),
-            genesis_store: GenesisStore::new(instance),
+            genesis_store: GenesisStore::new(instance.clone()),
+            fork_info_store: ForkInfoStore::new(instance),
         }
     }
 
@@ -73,4 +90,14 @@ impl ConfigDBStore {
     pub fn remove_genesis(&self) -> Result<()> {
         self.genesis_store.remove(GENESIS_KEY.to_string())
     }
+
+    /// The fork info is saved when the store is initialized by forking a remote node
+    pub fn get_fork_info(&self) -> Result<Option<ForkInfo>> {
+        self.fork_info_store.kv_get(FORK_INFO_KEY.to_string())
+    }
+
+    pub fn save_fork_info(&self, fork_info: ForkInfo) -> Result<()> {
+        self.fork_info_store
+            .put_sync(FORK_INFO_KEY.to_string(), fork_info)
+    }
 }
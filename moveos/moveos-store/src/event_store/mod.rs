@@ -123,6 +123,30 @@ impl EventDBStore {
         Ok(event_ids)
     }
 
+    /// The event ids the events would get if they are saved now, nothing is written.
+    pub fn next_event_ids(&self, tx_events: &[TransactionEvent]) -> Result<Vec<EventID>> {
+        let mut event_counts: HashMap<ObjectID, u64> = HashMap::new();
+        tx_events
+            .iter()
+            .map(|tx_event| {
+                let event_handle_id = EventHandle::derive_event_handle_id(&tx_event.event_type);
+                if !event_counts.contains_key(&event_handle_id) {
+                    let count = self
+                        .get_event_handle(event_handle_id.clone())?
+                        .map(|handle| handle.count)
+                        .unwrap_or_default();
+                    event_counts.insert(event_handle_id.clone(), count);
+                }
+                let count = event_counts
+                    .get_mut(&event_handle_id)
+                    .expect("Event count must exist");
+                let event_id = EventID::new(event_handle_id, *count);
+                *count += 1;
+                Ok(event_id)
+            })
+            .collect()
+    }
+
     pub fn get_event(&self, event_id: EventID) -> Result<Option<Event>> {
         let key = (event_id.event_handle_id, event_id.event_seq);
         self.event_store.kv_get(key)
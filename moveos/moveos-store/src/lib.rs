@@ -6,22 +6,24 @@
 
 use crate::config_store::{ConfigDBStore, ConfigStore, STARTUP_INFO_KEY};
 use crate::event_store::{EventDBStore, EventStore};
+use crate::prune_store::{PruneDBStore, StateNodeLog};
 use crate::state_store::statedb::StateDBStore;
-use crate::state_store::{nodes_to_write_batch, NodeDBStore};
+use crate::state_store::{nodes_with_log_to_cf_batches, NodeDBStore};
 use crate::transaction_store::{TransactionDBStore, TransactionStore};
 use accumulator::inmemory::InMemoryAccumulator;
-use anyhow::{Error, Result};
+use anyhow::{ensure, Error, Result};
 use bcs::to_bytes;
 use move_core_types::language_storage::StructTag;
 use moveos_config::store_config::{MoveOSStoreConfig, RocksdbConfig};
 use moveos_config::DataDirPath;
+use moveos_types::fork_info::ForkInfo;
 use moveos_types::genesis_info::GenesisInfo;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::{Event, EventID, TransactionEvent};
-use moveos_types::moveos_std::object::ObjectID;
+use moveos_types::moveos_std::object::{ObjectID, ObjectMeta};
 use moveos_types::moveos_std::onchain_features::FeatureStore;
 use moveos_types::startup_info::StartupInfo;
-use moveos_types::state::{FieldKey, ObjectState};
+use moveos_types::state::{FieldKey, ObjectState, StateChangeSet};
 use moveos_types::state_resolver::{StateKV, StateResolver, StatelessResolver};
 use moveos_types::transaction::{
     RawTransactionOutput, TransactionExecutionInfo, TransactionOutput,
@@ -34,12 +36,14 @@ use raw_store::rocks::RocksDB;
 use raw_store::traits::DBStore;
 use raw_store::{ColumnFamilyName, SchemaStore, StoreInstance, WriteOp};
 use smt::NodeReader;
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Display, Formatter};
 use std::path::Path;
 use std::sync::Arc;
 
 pub mod config_store;
 pub mod event_store;
+pub mod prune_store;
 pub mod state_store;
 #[cfg(test)]
 mod tests;
@@ -53,6 +57,10 @@ pub const EVENT_COLUMN_FAMILY_NAME: ColumnFamilyName = "event";
 pub const EVENT_HANDLE_COLUMN_FAMILY_NAME: ColumnFamilyName = "event_handle";
 pub const CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME: ColumnFamilyName = "config_startup_info";
 pub const CONFIG_GENESIS_COLUMN_FAMILY_NAME: ColumnFamilyName = "config_genesis";
+pub const CONFIG_FORK_COLUMN_FAMILY_NAME: ColumnFamilyName = "config_fork";
+pub const STATE_NODE_LOG_COLUMN_FAMILY_NAME: ColumnFamilyName = "state_node_log";
+pub const STATE_NODE_REFCOUNT_COLUMN_FAMILY_NAME: ColumnFamilyName = "state_node_refcount";
+pub const STATE_STALE_NODE_INDEX_COLUMN_FAMILY_NAME: ColumnFamilyName = "state_stale_node_index";
 
 /// db store use cf_name vec to init
 /// Please note that adding a column family needs to be added in vec simultaneously, remember！！
@@ -64,6 +72,10 @@ static VEC_COLUMN_FAMILY_NAME: Lazy<Vec<ColumnFamilyName>> = Lazy::new(|| {
         EVENT_HANDLE_COLUMN_FAMILY_NAME,
         CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME,
         CONFIG_GENESIS_COLUMN_FAMILY_NAME,
+        STATE_NODE_LOG_COLUMN_FAMILY_NAME,
+        STATE_NODE_REFCOUNT_COLUMN_FAMILY_NAME,
+        STATE_STALE_NODE_INDEX_COLUMN_FAMILY_NAME,
+        CONFIG_FORK_COLUMN_FAMILY_NAME,
     ]
 });
 
@@ -83,6 +95,7 @@ pub struct MoveOSStore {
     pub transaction_store: TransactionDBStore,
     pub config_store: ConfigDBStore,
     pub state_store: StateDBStore,
+    pub prune_store: PruneDBStore,
 }
 
 impl MoveOSStore {
@@ -109,8 +122,9 @@ impl MoveOSStore {
             node_store,
             event_store: EventDBStore::new(instance.clone()),
             transaction_store: TransactionDBStore::new(instance.clone()),
-            config_store: ConfigDBStore::new(instance),
+            config_store: ConfigDBStore::new(instance.clone()),
             state_store,
+            prune_store: PruneDBStore::new(instance),
         };
         Ok(store)
     }
@@ -143,6 +157,10 @@ impl MoveOSStore {
         &self.state_store
     }
 
+    pub fn get_prune_store(&self) -> &PruneDBStore {
+        &self.prune_store
+    }
+
     pub fn handle_tx_output(
         &self,
         tx_hash: H256,
@@ -158,7 +176,7 @@ impl MoveOSStore {
         } = output;
 
         // node_store updates
-        let changed_nodes = self.state_store.change_set_to_nodes(&mut changeset)?;
+        let (changed_nodes, node_log) = self.state_store.change_set_to_nodes(&mut changeset)?;
         // transaction_store updates
         let new_state_root = changeset.state_root;
         let size = changeset.global_size;
@@ -193,36 +211,96 @@ impl MoveOSStore {
             );
         }
 
-        // atomic save updates
+        // atomic save updates, use non-sync write here:
+        // 1. we could replay tx from rooch store(which has sync write after sequenced) at startup.
+        // 2. output write sequentially
+        self.write_tx_changes(changed_nodes, &node_log, new_startup_info, &execution_info)?;
+
+        let out = TransactionOutput::new(status, changeset, events, gas_used, is_upgrade);
+
+        Ok((out, execution_info))
+    }
+
+    /// Apply the change set and events of a transaction executed by another node without re-executing it,
+    /// such as the follower node applying the store changes of the leader node.
+    /// The change set is rebased on top of the given root, and the new state root, the event root
+    /// and the event ids must be the same as the execution info before anything is written,
+    /// so a mismatched transaction leaves the store untouched. Returns the applied change set.
+    pub fn apply_tx_change_set(
+        &self,
+        root: ObjectMeta,
+        execution_info: TransactionExecutionInfo,
+        mut changeset: StateChangeSet,
+        events: Vec<Event>,
+    ) -> Result<StateChangeSet> {
+        let event_hashes: Vec<_> = events.iter().map(|e| e.hash()).collect();
+        let event_root = InMemoryAccumulator::from_leaves(event_hashes.as_slice()).root_hash();
+        ensure!(
+            event_root == execution_info.event_root,
+            "Event root mismatch of tx {:?}, expect: {:?}, actual: {:?}",
+            execution_info.tx_hash,
+            execution_info.event_root,
+            event_root
+        );
+
+        self.state_store.rebase_change_set(root, &mut changeset)?;
+        let (changed_nodes, node_log) = self.state_store.change_set_to_nodes(&mut changeset)?;
+        ensure!(
+            changeset.state_root == execution_info.state_root
+                && changeset.global_size == execution_info.size,
+            "State root mismatch of tx {:?}, expect: ({:?}, {}), actual: ({:?}, {})",
+            execution_info.tx_hash,
+            execution_info.state_root,
+            execution_info.size,
+            changeset.state_root,
+            changeset.global_size
+        );
+
+        let event_ids: Vec<_> = events.iter().map(|e| e.event_id.clone()).collect();
+        let tx_events: Vec<_> = events
+            .into_iter()
+            .map(|e| TransactionEvent::new(e.event_type, e.event_data, e.event_index))
+            .collect();
+        ensure!(
+            self.event_store.next_event_ids(&tx_events)? == event_ids,
+            "Event ids mismatch of tx {:?}, the event store is diverged",
+            execution_info.tx_hash
+        );
+
+        self.event_store.save_events(tx_events)?;
+        let startup_info = StartupInfo::new(changeset.state_root, changeset.global_size);
+        self.write_tx_changes(changed_nodes, &node_log, startup_info, &execution_info)?;
+        Ok(changeset)
+    }
+
+    /// Write the new state nodes with the node log, the startup info and the execution info of a transaction atomically.
+    fn write_tx_changes(
+        &self,
+        changed_nodes: BTreeMap<H256, Vec<u8>>,
+        node_log: &StateNodeLog,
+        startup_info: StartupInfo,
+        execution_info: &TransactionExecutionInfo,
+    ) -> Result<()> {
         let inner_store = self.node_store.get_store().store();
-        let mut cf_batches: Vec<WriteBatchCF> = Vec::new();
-        let write_batch = nodes_to_write_batch(changed_nodes);
-        cf_batches.push(WriteBatchCF {
-            batch: write_batch,
-            cf_name: STATE_NODE_COLUMN_FAMILY_NAME.to_string(),
-        });
+        let mut cf_batches: Vec<WriteBatchCF> =
+            nodes_with_log_to_cf_batches(changed_nodes, execution_info.state_root, node_log)?;
         cf_batches.push(WriteBatchCF {
             batch: WriteBatch::new_with_rows(vec![(
                 to_bytes(STARTUP_INFO_KEY).unwrap(),
-                WriteOp::Value(to_bytes(&new_startup_info).unwrap()),
+                WriteOp::Value(to_bytes(&startup_info).unwrap()),
             )]),
             cf_name: CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME.to_string(),
         });
         cf_batches.push(WriteBatchCF {
             batch: WriteBatch::new_with_rows(vec![(
-                to_bytes(&tx_hash).unwrap(),
-                WriteOp::Value(to_bytes(&execution_info).unwrap()),
+                to_bytes(&execution_info.tx_hash).unwrap(),
+                WriteOp::Value(to_bytes(execution_info).unwrap()),
             )]),
             cf_name: TRANSACTION_EXECUTION_INFO_COLUMN_FAMILY_NAME.to_string(),
         });
-        // use non-sync write here:
-        // 1. we could replay tx from rooch store(which has sync write after sequenced) at startup.
-        // 2. output write sequentially
-        inner_store.write_cf_batch(cf_batches, false)?;
-
-        let out = TransactionOutput::new(status, changeset, events, gas_used, is_upgrade);
-
-        Ok((out, execution_info))
+        // the state pruner must not delete a node which is written again by this tx
+        let _guard = self.prune_store.lock_state_writes();
+        inner_store.write_cf_batch(cf_batches, false)
     }
 }
 
@@ -332,6 +410,14 @@ impl ConfigStore for MoveOSStore {
     fn save_genesis(&self, genesis_info: GenesisInfo) -> Result<()> {
         self.get_config_store().save_genesis(genesis_info)
     }
+
+    fn get_fork_info(&self) -> Result<Option<ForkInfo>> {
+        self.get_config_store().get_fork_info()
+    }
+
+    fn save_fork_info(&self, fork_info: ForkInfo) -> Result<()> {
+        self.get_config_store().save_fork_info(fork_info)
+    }
 }
 
 /// Moveos store define
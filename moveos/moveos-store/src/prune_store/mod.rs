@@ -0,0 +1,644 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    MoveOSStore, CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME, STATE_NODE_COLUMN_FAMILY_NAME,
+    STATE_NODE_LOG_COLUMN_FAMILY_NAME, STATE_NODE_REFCOUNT_COLUMN_FAMILY_NAME,
+    STATE_STALE_NODE_INDEX_COLUMN_FAMILY_NAME,
+};
+use anyhow::{ensure, format_err, Result};
+use bcs::to_bytes;
+use moveos_types::h256::H256;
+use raw_store::rocks::batch::{WriteBatch, WriteBatchCF};
+use raw_store::traits::DBStore;
+use raw_store::{derive_store, CodecKVStore, SchemaStore, StoreInstance, WriteOp};
+use serde::{Deserialize, Serialize};
+use smt::TreeChangeSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+pub const STATE_PRUNE_INFO_KEY: &str = "state_prune_info";
+
+const NODE_LOG_BATCH_SIZE: usize = 1000;
+
+/// The state nodes added and removed by a transaction in all the state trees.
+/// The node key is the node hash, a node may be referenced by more than one tree,
+/// so the same node may appear more than once in the log.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateNodeLog {
+    pub new_nodes: Vec<H256>,
+    pub stale_nodes: Vec<H256>,
+}
+
+impl StateNodeLog {
+    pub fn append(&mut self, tree_change_set: &TreeChangeSet) {
+        self.new_nodes.extend(tree_change_set.nodes.keys());
+        self.stale_nodes.extend(tree_change_set.stale_nodes.iter());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.new_nodes.is_empty() && self.stale_nodes.is_empty()
+    }
+}
+
+/// How many times a node is referenced by the state trees of the last indexed version.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeRefCount {
+    pub refs: u64,
+    /// The version since when the node is referenced continuously
+    pub alive_since: u64,
+    /// The version since when the node is not referenced, None if the node is referenced
+    pub stale_since: Option<u64>,
+    /// The node is kept for a retained version, it will never be pruned
+    pub pinned: bool,
+}
+
+/// The key of the stale node index, the version is encoded in big endian,
+/// so the index is ordered by the version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StaleNodeKey {
+    stale_since: [u8; 8],
+    node: H256,
+}
+
+impl StaleNodeKey {
+    pub fn new(stale_since: u64, node: H256) -> Self {
+        Self {
+            stale_since: stale_since.to_be_bytes(),
+            node,
+        }
+    }
+
+    pub fn stale_since(&self) -> u64 {
+        u64::from_be_bytes(self.stale_since)
+    }
+
+    pub fn node(&self) -> H256 {
+        self.node
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatePruneInfo {
+    /// The last version whose node log has been indexed
+    pub last_indexed_version: Option<u64>,
+    /// The last version whose stale nodes have been pruned
+    pub last_pruned_version: Option<u64>,
+}
+
+impl StatePruneInfo {
+    pub fn next_index_version(&self) -> u64 {
+        self.last_indexed_version.map(|v| v + 1).unwrap_or(0)
+    }
+
+    pub fn next_prune_version(&self) -> u64 {
+        self.last_pruned_version.map(|v| v + 1).unwrap_or(0)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatePruneStats {
+    pub pruned_versions: u64,
+    pub deleted_nodes: u64,
+    /// The stale nodes which are kept for the retained versions
+    pub retained_nodes: u64,
+}
+
+derive_store!(
+    StateNodeLogDBStore,
+    H256,
+    StateNodeLog,
+    STATE_NODE_LOG_COLUMN_FAMILY_NAME
+);
+derive_store!(
+    NodeRefCountDBStore,
+    H256,
+    NodeRefCount,
+    STATE_NODE_REFCOUNT_COLUMN_FAMILY_NAME
+);
+derive_store!(
+    StaleNodeIndexDBStore,
+    StaleNodeKey,
+    u64,
+    STATE_STALE_NODE_INDEX_COLUMN_FAMILY_NAME
+);
+derive_store!(
+    StatePruneInfoStore,
+    String,
+    StatePruneInfo,
+    CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME
+);
+
+/// The stale nodes to delete or to retain of the prune versions, it is planned without blocking
+/// the state writes, and committed with the state writes blocked.
+pub struct StatePrunePlan {
+    first_version: u64,
+    last_version: u64,
+    state_roots: Vec<H256>,
+    index_deletes: Vec<StaleNodeKey>,
+    retained: Vec<(H256, NodeRefCount)>,
+    deletes: Vec<H256>,
+    retained_nodes: u64,
+}
+
+#[derive(Clone)]
+pub struct PruneDBStore {
+    node_log_store: StateNodeLogDBStore,
+    refcount_store: NodeRefCountDBStore,
+    stale_index_store: StaleNodeIndexDBStore,
+    prune_info_store: StatePruneInfoStore,
+    state_write_lock: Arc<Mutex<()>>,
+    state_index_lock: Arc<Mutex<()>>,
+}
+
+impl PruneDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        PruneDBStore {
+            node_log_store: StateNodeLogDBStore::new(instance.clone()),
+            refcount_store: NodeRefCountDBStore::new(instance.clone()),
+            stale_index_store: StaleNodeIndexDBStore::new(instance.clone()),
+            prune_info_store: StatePruneInfoStore::new(instance),
+            state_write_lock: Arc::new(Mutex::new(())),
+            state_index_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Get the node log of the transaction whose output state root is `state_root`
+    pub fn get_node_log(&self, state_root: H256) -> Result<Option<StateNodeLog>> {
+        self.node_log_store.kv_get(state_root)
+    }
+
+    pub fn multi_get_node_logs(&self, state_roots: Vec<H256>) -> Result<Vec<Option<StateNodeLog>>> {
+        self.node_log_store.multiple_get(state_roots)
+    }
+
+    pub fn save_node_log(&self, state_root: H256, node_log: StateNodeLog) -> Result<()> {
+        self.node_log_store.kv_put(state_root, node_log)
+    }
+
+    pub fn get_node_refcount(&self, node: H256) -> Result<Option<NodeRefCount>> {
+        self.refcount_store.kv_get(node)
+    }
+
+    pub fn get_prune_info(&self) -> Result<Option<StatePruneInfo>> {
+        self.prune_info_store
+            .kv_get(STATE_PRUNE_INFO_KEY.to_string())
+    }
+
+    /// Hold the lock to block the state writes of the transaction outputs,
+    /// the nodes written by the versions not indexed yet must not be deleted.
+    pub fn lock_state_writes(&self) -> MutexGuard<'_, ()> {
+        self.state_write_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Hold the lock to update the node refcounts, the indexing, unindexing and pruning
+    /// must not run at the same time. It does not block the state writes.
+    pub fn lock_state_index(&self) -> MutexGuard<'_, ()> {
+        self.state_index_lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl MoveOSStore {
+    /// Initialize the state pruning of a store without the node logs since genesis,
+    /// all the existing nodes are pinned, and the versions until `base_version` are treated as pruned.
+    /// The nodes written after `base_version` are pruned as usual.
+    /// It scans all the state nodes, the caller must make sure no state is written meanwhile,
+    /// and `base_version` must be the last executed version.
+    pub fn init_state_prune_baseline(&self, base_version: u64) -> Result<u64> {
+        ensure!(
+            self.prune_store.get_prune_info()?.is_none(),
+            "The state pruning is already initialized"
+        );
+        let db = self
+            .node_store
+            .get_store()
+            .store()
+            .db()
+            .ok_or_else(|| format_err!("Only support scan on db store instance"))?;
+        let pinned = NodeRefCount {
+            refs: 0,
+            alive_since: 0,
+            stale_since: None,
+            pinned: true,
+        };
+        let mut pinned_nodes = 0u64;
+        let mut refcount_batch = WriteBatch::new();
+        let mut iter = db.raw_iter(STATE_NODE_COLUMN_FAMILY_NAME);
+        iter.seek_to_first();
+        while iter.valid() {
+            let node = H256::from_slice(iter.key().expect("Iterator must be valid."));
+            refcount_batch.put(to_bytes(&node)?, to_bytes(&pinned)?)?;
+            pinned_nodes += 1;
+            if refcount_batch.rows.len() >= NODE_LOG_BATCH_SIZE {
+                self.prune_store
+                    .refcount_store
+                    .write_batch_raw(std::mem::take(&mut refcount_batch))?;
+            }
+            iter.next();
+        }
+        iter.status()?;
+        self.prune_store
+            .refcount_store
+            .write_batch_raw(refcount_batch)?;
+
+        // The node logs before the baseline are never indexed
+        let mut node_log_batch = WriteBatch::new();
+        let mut iter = db.raw_iter(STATE_NODE_LOG_COLUMN_FAMILY_NAME);
+        iter.seek_to_first();
+        while iter.valid() {
+            node_log_batch.delete(iter.key().expect("Iterator must be valid.").to_vec())?;
+            iter.next();
+        }
+        iter.status()?;
+
+        let prune_info = StatePruneInfo {
+            last_indexed_version: Some(base_version),
+            last_pruned_version: Some(base_version),
+        };
+        self.write_prune_batches(
+            vec![(STATE_NODE_LOG_COLUMN_FAMILY_NAME, node_log_batch)],
+            &prune_info,
+        )?;
+        Ok(pinned_nodes)
+    }
+
+    /// Index the node logs of the `versions`, which must follow the last indexed version.
+    /// Each version is the (version, state_root) of a transaction, in ascending order.
+    /// The node refcounts are updated, and the unreferenced nodes are added to the stale node index.
+    /// The caller must hold the `lock_state_index`.
+    pub fn index_state_node_logs(&self, versions: &[(u64, H256)]) -> Result<u64> {
+        let (first_version, last_version) = match (versions.first(), versions.last()) {
+            (Some((first, _)), Some((last, _))) => (*first, *last),
+            _ => return Ok(0),
+        };
+        let mut prune_info = self.prune_store.get_prune_info()?.unwrap_or_default();
+        ensure!(
+            first_version == prune_info.next_index_version(),
+            "Index versions must follow the last indexed version, expected: {}, actual: {}",
+            prune_info.next_index_version(),
+            first_version
+        );
+        if first_version == 0 {
+            ensure!(
+                self.prune_store.get_node_log(versions[0].1)?.is_some(),
+                "The state node log of genesis is not found, initialize the state pruning baseline with `rooch db prune` offline"
+            );
+        }
+
+        let mut refcounts: HashMap<H256, NodeRefCount> = HashMap::new();
+        let mut index_puts: HashMap<StaleNodeKey, u64> = HashMap::new();
+        let mut index_deletes = vec![];
+        for chunk in versions.chunks(NODE_LOG_BATCH_SIZE) {
+            let state_roots = chunk.iter().map(|(_, root)| *root).collect::<Vec<_>>();
+            let node_logs = self.prune_store.multi_get_node_logs(state_roots)?;
+            for ((version, _state_root), node_log) in chunk.iter().zip(node_logs) {
+                // the tx has no state change
+                let node_log = match node_log {
+                    Some(node_log) => node_log,
+                    None => continue,
+                };
+                for node in node_log.new_nodes {
+                    let refcount = self.load_node_refcount(&mut refcounts, node)?;
+                    if refcount.refs == 0 {
+                        if let Some(stale_since) = refcount.stale_since.take() {
+                            let key = StaleNodeKey::new(stale_since, node);
+                            if index_puts.remove(&key).is_none() {
+                                index_deletes.push(key);
+                            }
+                        }
+                        refcount.alive_since = *version;
+                    }
+                    refcount.refs += 1;
+                }
+                for node in node_log.stale_nodes {
+                    let refcount = self.load_node_refcount(&mut refcounts, node)?;
+                    if refcount.refs == 0 {
+                        // The node is written before the node log is recorded, keep it
+                        if refcount.pinned {
+                            continue;
+                        }
+                        tracing::warn!(
+                            "State node {:?} becomes stale at version {} without reference",
+                            node,
+                            version
+                        );
+                        continue;
+                    }
+                    refcount.refs -= 1;
+                    if refcount.refs == 0 {
+                        refcount.stale_since = Some(*version);
+                        index_puts.insert(StaleNodeKey::new(*version, node), refcount.alive_since);
+                    }
+                }
+            }
+        }
+
+        let stale_nodes = index_puts.len() as u64;
+        let mut refcount_batch = WriteBatch::new();
+        for (node, refcount) in refcounts {
+            refcount_batch.put(to_bytes(&node)?, to_bytes(&refcount)?)?;
+        }
+        let mut index_batch = WriteBatch::new();
+        for key in index_deletes {
+            index_batch.delete(to_bytes(&key)?)?;
+        }
+        for (key, alive_since) in index_puts {
+            index_batch.put(to_bytes(&key)?, to_bytes(&alive_since)?)?;
+        }
+        prune_info.last_indexed_version = Some(last_version);
+        self.write_prune_batches(
+            vec![
+                (STATE_NODE_REFCOUNT_COLUMN_FAMILY_NAME, refcount_batch),
+                (STATE_STALE_NODE_INDEX_COLUMN_FAMILY_NAME, index_batch),
+            ],
+            &prune_info,
+        )?;
+        Ok(stale_nodes)
+    }
+
+    /// Prune the stale nodes of the `versions`, which must follow the last pruned version
+    /// and must have been indexed.
+    ///
+    /// A node which becomes stale in the `versions` is deleted unless
+    /// `retain(alive_since, stale_since)` returns true, it is used to keep the retained versions
+    /// in the range [alive_since, stale_since) readable. The node logs of the `versions` are deleted too.
+    /// The caller must hold the `lock_state_index` and make sure all the written versions are indexed.
+    pub fn prune_state_nodes<F>(
+        &self,
+        versions: &[(u64, H256)],
+        retain: F,
+    ) -> Result<StatePruneStats>
+    where
+        F: Fn(u64, u64) -> Result<bool>,
+    {
+        match self.plan_state_prune(versions, retain)? {
+            Some(plan) => self.commit_state_prune(plan, &[]),
+            None => Ok(StatePruneStats::default()),
+        }
+    }
+
+    /// Plan the pruning of the `versions` like `prune_state_nodes`, it scans the stale node index
+    /// without blocking the state writes. Returns None if the `versions` is empty.
+    /// The caller must hold the `lock_state_index` until the plan is committed.
+    pub fn plan_state_prune<F>(
+        &self,
+        versions: &[(u64, H256)],
+        retain: F,
+    ) -> Result<Option<StatePrunePlan>>
+    where
+        F: Fn(u64, u64) -> Result<bool>,
+    {
+        let (first_version, last_version) = match (versions.first(), versions.last()) {
+            (Some((first, _)), Some((last, _))) => (*first, *last),
+            _ => return Ok(None),
+        };
+        let prune_info = self.prune_store.get_prune_info()?.unwrap_or_default();
+        ensure!(
+            first_version == prune_info.next_prune_version(),
+            "Prune versions must follow the last pruned version, expected: {}, actual: {}",
+            prune_info.next_prune_version(),
+            first_version
+        );
+        ensure!(
+            prune_info
+                .last_indexed_version
+                .map(|v| v >= last_version)
+                .unwrap_or(false),
+            "Prune versions must be indexed, last indexed version: {:?}, last prune version: {}",
+            prune_info.last_indexed_version,
+            last_version
+        );
+
+        let mut plan = StatePrunePlan {
+            first_version,
+            last_version,
+            state_roots: versions.iter().map(|(_, state_root)| *state_root).collect(),
+            index_deletes: vec![],
+            retained: vec![],
+            deletes: vec![],
+            retained_nodes: 0,
+        };
+        let mut iter = self.prune_store.stale_index_store.iter()?;
+        iter.seek(to_bytes(&StaleNodeKey::new(first_version, H256::zero()))?)?;
+        for item in iter {
+            let (key, alive_since) = item?;
+            let stale_since = key.stale_since();
+            if stale_since > last_version {
+                break;
+            }
+            plan.index_deletes.push(key);
+            let node = key.node();
+            let mut refcount = self
+                .prune_store
+                .get_node_refcount(node)?
+                .ok_or_else(|| format_err!("The refcount of stale node {:?} not found", node))?;
+            if refcount.refs > 0 || refcount.stale_since != Some(stale_since) {
+                tracing::warn!(
+                    "Stale node index {:?} is outdated, refcount: {:?}",
+                    key,
+                    refcount
+                );
+                continue;
+            }
+            if refcount.pinned {
+                plan.retained_nodes += 1;
+            } else if retain(alive_since, stale_since)? {
+                refcount.pinned = true;
+                plan.retained.push((node, refcount));
+                plan.retained_nodes += 1;
+            } else {
+                plan.deletes.push(node);
+            }
+        }
+        Ok(Some(plan))
+    }
+
+    /// Commit the prune `plan`, the caller must block the state writes by `lock_state_writes`.
+    /// The nodes which are written again by the `unindexed_versions`, the written versions after
+    /// the last indexed version, are kept, they are referenced again once the versions are indexed.
+    pub fn commit_state_prune(
+        &self,
+        plan: StatePrunePlan,
+        unindexed_versions: &[(u64, H256)],
+    ) -> Result<StatePruneStats> {
+        let mut prune_info = self.prune_store.get_prune_info()?.unwrap_or_default();
+        ensure!(
+            plan.first_version == prune_info.next_prune_version(),
+            "The prune plan of versions [{}, {}] is outdated, last pruned version: {:?}",
+            plan.first_version,
+            plan.last_version,
+            prune_info.last_pruned_version
+        );
+        let mut rewritten_nodes = HashSet::new();
+        for node_log in self
+            .prune_store
+            .multi_get_node_logs(
+                unindexed_versions
+                    .iter()
+                    .map(|(_, state_root)| *state_root)
+                    .collect(),
+            )?
+            .into_iter()
+            .flatten()
+        {
+            rewritten_nodes.extend(node_log.new_nodes);
+        }
+
+        let mut stats = StatePruneStats {
+            pruned_versions: plan.last_version - plan.first_version + 1,
+            deleted_nodes: 0,
+            retained_nodes: plan.retained_nodes,
+        };
+        let mut node_batch = WriteBatch::new();
+        let mut refcount_batch = WriteBatch::new();
+        let mut index_batch = WriteBatch::new();
+        for key in plan.index_deletes {
+            index_batch.delete(to_bytes(&key)?)?;
+        }
+        for (node, refcount) in plan.retained {
+            refcount_batch.put(to_bytes(&node)?, to_bytes(&refcount)?)?;
+        }
+        for node in plan.deletes {
+            if rewritten_nodes.contains(&node) {
+                stats.retained_nodes += 1;
+                continue;
+            }
+            node_batch.delete(node.0.to_vec())?;
+            refcount_batch.delete(to_bytes(&node)?)?;
+            stats.deleted_nodes += 1;
+        }
+        let mut node_log_batch = WriteBatch::new();
+        for state_root in plan.state_roots {
+            node_log_batch.delete(to_bytes(&state_root)?)?;
+        }
+        prune_info.last_pruned_version = Some(plan.last_version);
+        self.write_prune_batches(
+            vec![
+                (STATE_NODE_COLUMN_FAMILY_NAME, node_batch),
+                (STATE_NODE_REFCOUNT_COLUMN_FAMILY_NAME, refcount_batch),
+                (STATE_STALE_NODE_INDEX_COLUMN_FAMILY_NAME, index_batch),
+                (STATE_NODE_LOG_COLUMN_FAMILY_NAME, node_log_batch),
+            ],
+            &prune_info,
+        )?;
+        Ok(stats)
+    }
+
+    /// Revert the index of the last indexed version, used when the transaction is reverted.
+    /// The caller must hold the `lock_state_index`.
+    pub fn unindex_state_node_log(&self, version: u64, state_root: H256) -> Result<()> {
+        let mut prune_info = match self.prune_store.get_prune_info()? {
+            Some(prune_info) => prune_info,
+            None => return Ok(()),
+        };
+        if prune_info.next_index_version() <= version {
+            return Ok(());
+        }
+        ensure!(
+            prune_info.last_indexed_version == Some(version),
+            "Only the last indexed version can be unindexed, last indexed version: {:?}, version: {}",
+            prune_info.last_indexed_version,
+            version
+        );
+        ensure!(
+            prune_info.next_prune_version() <= version,
+            "The version {} has been pruned, last pruned version: {:?}",
+            version,
+            prune_info.last_pruned_version
+        );
+
+        let mut refcounts: HashMap<H256, NodeRefCount> = HashMap::new();
+        let mut index_batch = WriteBatch::new();
+        if let Some(node_log) = self.prune_store.get_node_log(state_root)? {
+            for node in node_log.stale_nodes.into_iter().rev() {
+                let refcount = self.load_node_refcount(&mut refcounts, node)?;
+                if refcount.refs == 0 {
+                    if refcount.stale_since != Some(version) {
+                        // skipped when indexing
+                        continue;
+                    }
+                    index_batch.delete(to_bytes(&StaleNodeKey::new(version, node))?)?;
+                    refcount.stale_since = None;
+                }
+                refcount.refs += 1;
+            }
+            for node in node_log.new_nodes.into_iter().rev() {
+                let refcount = self.load_node_refcount(&mut refcounts, node)?;
+                ensure!(
+                    refcount.refs > 0,
+                    "The refcount of new node {:?} at version {} is zero",
+                    node,
+                    version
+                );
+                refcount.refs -= 1;
+                if refcount.refs == 0 {
+                    // The node may be referenced by an earlier version before, the history is lost,
+                    // pin it to keep the earlier versions readable.
+                    refcount.stale_since = Some(version);
+                    refcount.pinned = true;
+                    index_batch.put(
+                        to_bytes(&StaleNodeKey::new(version, node))?,
+                        to_bytes(&refcount.alive_since)?,
+                    )?;
+                }
+            }
+        }
+        let mut refcount_batch = WriteBatch::new();
+        for (node, refcount) in refcounts {
+            refcount_batch.put(to_bytes(&node)?, to_bytes(&refcount)?)?;
+        }
+        prune_info.last_indexed_version = version.checked_sub(1);
+        self.write_prune_batches(
+            vec![
+                (STATE_NODE_REFCOUNT_COLUMN_FAMILY_NAME, refcount_batch),
+                (STATE_STALE_NODE_INDEX_COLUMN_FAMILY_NAME, index_batch),
+            ],
+            &prune_info,
+        )
+    }
+
+    fn load_node_refcount<'a>(
+        &self,
+        refcounts: &'a mut HashMap<H256, NodeRefCount>,
+        node: H256,
+    ) -> Result<&'a mut NodeRefCount> {
+        if !refcounts.contains_key(&node) {
+            let refcount = self
+                .prune_store
+                .get_node_refcount(node)?
+                .unwrap_or_default();
+            refcounts.insert(node, refcount);
+        }
+        Ok(refcounts.get_mut(&node).expect("refcount must exist"))
+    }
+
+    // the prune info must be updated atomically with the prune batches
+    fn write_prune_batches(
+        &self,
+        batches: Vec<(&str, WriteBatch)>,
+        prune_info: &StatePruneInfo,
+    ) -> Result<()> {
+        let mut cf_batches = batches
+            .into_iter()
+            .map(|(cf_name, batch)| WriteBatchCF {
+                batch,
+                cf_name: cf_name.to_string(),
+            })
+            .collect::<Vec<_>>();
+        cf_batches.push(WriteBatchCF {
+            batch: WriteBatch::new_with_rows(vec![(
+                to_bytes(STATE_PRUNE_INFO_KEY)?,
+                WriteOp::Value(to_bytes(prune_info)?),
+            )]),
+            cf_name: CONFIG_STARTUP_INFO_COLUMN_FAMILY_NAME.to_string(),
+        });
+        self.node_store
+            .get_store()
+            .store()
+            .write_cf_batch(cf_batches, true)
+    }
+}
@@ -4,18 +4,73 @@
 pub mod metrics;
 pub mod statedb;
 
-use crate::STATE_NODE_COLUMN_FAMILY_NAME;
-use anyhow::Result;
+use crate::prune_store::StateNodeLog;
+use crate::{STATE_NODE_COLUMN_FAMILY_NAME, STATE_NODE_LOG_COLUMN_FAMILY_NAME};
+use anyhow::{ensure, Result};
+use bcs::to_bytes;
 use moveos_types::h256::H256;
-use raw_store::rocks::batch::WriteBatch;
-use raw_store::CodecKVStore;
-use raw_store::{derive_store, WriteOp};
+use once_cell::sync::OnceCell;
+use raw_store::rocks::batch::{WriteBatch, WriteBatchCF};
+use raw_store::traits::DBStore;
+use raw_store::{CodecKVStore, ColumnFamily, ColumnFamilyName, SchemaStore, WriteOp};
+use raw_store::{InnerStore, StoreInstance};
 use smt::{NodeReader, NodeWriter};
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
-derive_store!(NodeDBStore, H256, Vec<u8>, STATE_NODE_COLUMN_FAMILY_NAME);
+pub type RemoteNodeReader = Arc<dyn NodeReader + Send + Sync>;
+
+#[derive(Clone)]
+pub struct NodeDBStore {
+    store: InnerStore<Self>,
+    /// The remote node reader of the forked node, it is shared by all the clones of the store.
+    /// The nodes missing locally are loaded from it, and cached in the local store.
+    remote: Arc<OnceCell<RemoteNodeReader>>,
+}
+
+impl ColumnFamily for NodeDBStore {
+    type Key = H256;
+    type Value = Vec<u8>;
+
+    fn name() -> ColumnFamilyName {
+        STATE_NODE_COLUMN_FAMILY_NAME
+    }
+}
+
+impl SchemaStore for NodeDBStore {
+    fn get_store(&self) -> &InnerStore<Self> {
+        &self.store
+    }
+}
 
 impl NodeDBStore {
+    pub fn new(instance: StoreInstance) -> Self {
+        Self {
+            store: InnerStore::new(instance),
+            remote: Arc::new(OnceCell::new()),
+        }
+    }
+
+    /// Load the missing nodes from the remote node reader, it can only be set once.
+    pub fn set_remote_node_reader(&self, remote: RemoteNodeReader) -> Result<()> {
+        ensure!(
+            self.remote.set(remote).is_ok(),
+            "The remote node reader is already set"
+        );
+        Ok(())
+    }
+
+    pub fn is_forked(&self) -> bool {
+        self.remote.get().is_some()
+    }
+
+    pub fn get_nodes(&self, node_hashes: &[H256]) -> Result<Vec<Option<Vec<u8>>>> {
+        node_hashes
+            .iter()
+            .map(|node_hash| NodeReader::get(self, node_hash))
+            .collect()
+    }
+
     pub fn put(&self, key: H256, node: Vec<u8>) -> Result<()> {
         self.put_raw(key.as_bytes().to_vec(), node)
     }
@@ -29,6 +84,17 @@ impl NodeDBStore {
         );
         self.write_batch_raw(batch)
     }
+
+    /// Write the new nodes and the node log of a state change atomically.
+    pub fn write_nodes_with_log(
+        &self,
+        nodes: BTreeMap<H256, Vec<u8>>,
+        state_root: H256,
+        node_log: &StateNodeLog,
+    ) -> Result<()> {
+        let cf_batches = nodes_with_log_to_cf_batches(nodes, state_root, node_log)?;
+        self.get_store().store().write_cf_batch(cf_batches, false)
+    }
 }
 
 pub fn nodes_to_write_batch(nodes: BTreeMap<H256, Vec<u8>>) -> WriteBatch {
@@ -40,9 +106,44 @@ pub fn nodes_to_write_batch(nodes: BTreeMap<H256, Vec<u8>>) -> WriteBatch {
     )
 }
 
+/// The write batches of the new nodes and the node log of a state change,
+/// the node log is keyed by the new state root, for pruning the stale nodes later.
+/// They must be written atomically, otherwise the nodes without log can never be pruned.
+pub fn nodes_with_log_to_cf_batches(
+    nodes: BTreeMap<H256, Vec<u8>>,
+    state_root: H256,
+    node_log: &StateNodeLog,
+) -> Result<Vec<WriteBatchCF>> {
+    let mut cf_batches = vec![WriteBatchCF {
+        batch: nodes_to_write_batch(nodes),
+        cf_name: STATE_NODE_COLUMN_FAMILY_NAME.to_string(),
+    }];
+    if !node_log.is_empty() {
+        cf_batches.push(WriteBatchCF {
+            batch: WriteBatch::new_with_rows(vec![(
+                to_bytes(&state_root)?,
+                WriteOp::Value(to_bytes(node_log)?),
+            )]),
+            cf_name: STATE_NODE_LOG_COLUMN_FAMILY_NAME.to_string(),
+        });
+    }
+    Ok(cf_batches)
+}
+
 impl NodeReader for NodeDBStore {
     fn get(&self, hash: &H256) -> Result<Option<Vec<u8>>> {
-        self.get_raw(hash.as_bytes())
+        let node = self.get_raw(hash.as_bytes())?;
+        match (node, self.remote.get()) {
+            (None, Some(remote)) => {
+                let node = remote.get(hash)?;
+                // The node is addressed by its hash, so it can be cached safely
+                if let Some(node) = &node {
+                    self.put(*hash, node.clone())?;
+                }
+                Ok(node)
+            }
+            (node, _) => Ok(node),
+        }
     }
 }
 
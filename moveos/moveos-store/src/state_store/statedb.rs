@@ -1,6 +1,7 @@
 // Copyright (c) RoochNetwork
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::prune_store::StateNodeLog;
 use crate::state_store::metrics::StateDBMetrics;
 use crate::state_store::NodeDBStore;
 use anyhow::{Error, Ok, Result};
@@ -8,7 +9,7 @@ use function_name::named;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::effects::Op;
 use moveos_types::h256::H256;
-use moveos_types::moveos_std::object::GENESIS_STATE_ROOT;
+use moveos_types::moveos_std::object::{ObjectMeta, GENESIS_STATE_ROOT};
 use moveos_types::state::FieldKey;
 use moveos_types::state::ObjectChange;
 use moveos_types::state::ObjectState;
@@ -19,7 +20,7 @@ use moveos_types::state_resolver::StateResolver;
 use moveos_types::state_resolver::StatelessResolver;
 use prometheus::Registry;
 use quick_cache::sync::Cache;
-use smt::{SMTIterator, TreeChangeSet};
+use smt::{SMTIterator, SparseMerkleProof, TreeChangeSet};
 use smt::{SMTree, UpdateSet};
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -88,6 +89,7 @@ impl StateDBStore {
         &self,
         resolver: &dyn StateResolver,
         nodes: &mut BTreeMap<H256, Vec<u8>>,
+        node_log: &mut StateNodeLog,
         update_set: &mut UpdateSet<FieldKey, ObjectState>,
         field_key: FieldKey,
         obj_change: &mut ObjectChange,
@@ -121,12 +123,14 @@ impl StateDBStore {
             self.apply_object_change(
                 resolver,
                 nodes,
+                node_log,
                 &mut field_update_set,
                 *child_field_key,
                 child_change,
             )?;
         }
         let mut tree_change_set = self.update_fields(obj.state_root(), field_update_set)?;
+        node_log.append(&tree_change_set);
         nodes.append(&mut tree_change_set.nodes);
         let new_state_root = tree_change_set.state_root;
         obj.update_state_root(new_state_root);
@@ -137,11 +141,13 @@ impl StateDBStore {
         Ok(())
     }
 
+    /// Apply the state change set to the state trees, returns the new nodes to write,
+    /// and the node log of all the changed trees for state pruning.
     #[named]
     pub fn change_set_to_nodes(
         &self,
         state_change_set: &mut StateChangeSet,
-    ) -> Result<BTreeMap<H256, Vec<u8>>> {
+    ) -> Result<(BTreeMap<H256, Vec<u8>>, StateNodeLog)> {
         let fn_name = function_name!();
         let _timer = self
             .metrics
@@ -157,10 +163,12 @@ impl StateDBStore {
 
         let mut update_set = UpdateSet::new();
         let mut nodes = BTreeMap::new();
+        let mut node_log = StateNodeLog::default();
         for (field_key, obj_change) in &mut state_change_set.changes {
             self.apply_object_change(
                 &resolver,
                 &mut nodes,
+                &mut node_log,
                 &mut update_set,
                 *field_key,
                 obj_change,
@@ -178,6 +186,7 @@ impl StateDBStore {
             .sum::<usize>();
         let mut tree_change_set = self.update_fields(pre_state_root, update_set)?;
         let new_state_root = tree_change_set.state_root;
+        node_log.append(&tree_change_set);
         nodes.append(&mut tree_change_set.nodes);
         if tracing::enabled!(tracing::Level::DEBUG) {
             tracing::debug!(
@@ -193,12 +202,45 @@ impl StateDBStore {
             .state_change_set_to_nodes_bytes
             .with_label_values(&[fn_name])
             .observe(size as f64);
-        Ok(nodes)
+        Ok((nodes, node_log))
     }
 
+    /// Apply the state change set and write the new nodes with the node log in the same batch.
     pub fn apply_change_set(&self, state_change_set: &mut StateChangeSet) -> Result<()> {
-        let nodes = self.change_set_to_nodes(state_change_set)?;
-        self.node_store.write_nodes(nodes)?;
+        let (nodes, node_log) = self.change_set_to_nodes(state_change_set)?;
+        self.node_store
+            .write_nodes_with_log(nodes, state_change_set.state_root, &node_log)
+    }
+
+    /// Rebase a change set which is already applied by another node on top of the local root.
+    /// The state roots in the applied change set are the state roots after the changes,
+    /// they are reset to the local state roots before the changes, so the change set can be applied again.
+    pub fn rebase_change_set(
+        &self,
+        root: ObjectMeta,
+        state_change_set: &mut StateChangeSet,
+    ) -> Result<()> {
+        let pre_state_root = root.state_root();
+        let resolver = RootObjectResolver::new(root, self);
+        for obj_change in state_change_set.changes.values_mut() {
+            Self::rebase_object_change(&resolver, obj_change)?;
+        }
+        state_change_set.update_state_root(pre_state_root);
+        Ok(())
+    }
+
+    fn rebase_object_change(
+        resolver: &dyn StateResolver,
+        obj_change: &mut ObjectChange,
+    ) -> Result<()> {
+        let pre_state_root = resolver
+            .get_object(&obj_change.metadata.id)?
+            .map(|obj| obj.state_root())
+            .unwrap_or(*GENESIS_STATE_ROOT);
+        obj_change.update_state_root(pre_state_root);
+        for child_change in obj_change.fields.values_mut() {
+            Self::rebase_object_change(resolver, child_change)?;
+        }
         Ok(())
     }
 
@@ -216,6 +258,16 @@ impl StateDBStore {
             .start_timer();
         self.smt.iter(state_root, starting_key)
     }
+
+    /// Get the field with the key at the given state_root, and the proof of the field in the state tree.
+    /// If the field does not exist, return None and non-inclusion proof.
+    pub fn get_field_at_with_proof(
+        &self,
+        state_root: H256,
+        key: FieldKey,
+    ) -> Result<(Option<ObjectState>, SparseMerkleProof)> {
+        self.smt.get_with_proof(state_root, key)
+    }
 }
 
 impl StatelessResolver for StateDBStore {
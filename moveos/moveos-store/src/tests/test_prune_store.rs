@@ -0,0 +1,248 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::prune_store::StateNodeLog;
+use crate::MoveOSStore;
+use anyhow::Result;
+use moveos_types::h256::H256;
+use moveos_types::test_utils::random_state_change_set;
+use smt::NodeReader;
+
+#[tokio::test]
+async fn test_prune_state_nodes() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let node_store = moveos_store.get_state_node_store();
+    let prune_store = moveos_store.get_prune_store();
+
+    let (a, b, c, d) = (
+        H256::random(),
+        H256::random(),
+        H256::random(),
+        H256::random(),
+    );
+    for node in [a, b, c, d] {
+        node_store.put(node, node.0.to_vec())?;
+    }
+    let logs = vec![
+        StateNodeLog {
+            new_nodes: vec![a, b],
+            stale_nodes: vec![],
+        },
+        StateNodeLog {
+            new_nodes: vec![c, d],
+            stale_nodes: vec![a],
+        },
+        StateNodeLog {
+            new_nodes: vec![a],
+            stale_nodes: vec![c, d],
+        },
+    ];
+    let versions = logs
+        .into_iter()
+        .enumerate()
+        .map(|(version, log)| {
+            let state_root = H256::random();
+            prune_store.save_node_log(state_root, log)?;
+            Ok((version as u64, state_root))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // the versions must be indexed before pruning
+    assert!(moveos_store
+        .prune_state_nodes(&versions[0..1], |_, _| Ok(false))
+        .is_err());
+    assert_eq!(moveos_store.index_state_node_logs(&versions)?, 2);
+    // `a` becomes stale at version 1, but it is added again at version 2
+    assert_eq!(prune_store.get_node_refcount(a)?.unwrap().alive_since, 2);
+    assert_eq!(prune_store.get_node_refcount(b)?.unwrap().refs, 1);
+
+    let stats = moveos_store.prune_state_nodes(&versions[0..2], |_, _| Ok(false))?;
+    assert_eq!(stats.pruned_versions, 2);
+    assert_eq!(stats.deleted_nodes, 0);
+    assert!(node_store.get(&a)?.is_some());
+    assert!(prune_store.get_node_log(versions[0].1)?.is_none());
+
+    // the versions must follow the last pruned version
+    assert!(moveos_store
+        .prune_state_nodes(&versions[0..1], |_, _| Ok(false))
+        .is_err());
+
+    // `c` and `d` are alive in [1, 2), they are retained for version 1
+    let stats =
+        moveos_store.prune_state_nodes(&versions[2..3], |alive_since, _| Ok(alive_since == 1))?;
+    assert_eq!(stats.deleted_nodes, 0);
+    assert_eq!(stats.retained_nodes, 2);
+    assert!(node_store.get(&c)?.is_some());
+    assert!(prune_store.get_node_refcount(c)?.unwrap().pinned);
+    let prune_info = prune_store.get_prune_info()?.unwrap();
+    assert_eq!(prune_info.last_indexed_version, Some(2));
+    assert_eq!(prune_info.last_pruned_version, Some(2));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_stale_nodes() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let node_store = moveos_store.get_state_node_store();
+    let prune_store = moveos_store.get_prune_store();
+
+    let (a, b) = (H256::random(), H256::random());
+    for node in [a, b] {
+        node_store.put(node, node.0.to_vec())?;
+    }
+    let versions = vec![(0, H256::random()), (1, H256::random())];
+    prune_store.save_node_log(
+        versions[0].1,
+        StateNodeLog {
+            new_nodes: vec![a],
+            stale_nodes: vec![],
+        },
+    )?;
+    prune_store.save_node_log(
+        versions[1].1,
+        StateNodeLog {
+            new_nodes: vec![b],
+            stale_nodes: vec![a],
+        },
+    )?;
+    moveos_store.index_state_node_logs(&versions)?;
+
+    // revert the last version, `a` is referenced again
+    moveos_store.unindex_state_node_log(1, versions[1].1)?;
+    assert_eq!(prune_store.get_node_refcount(a)?.unwrap().refs, 1);
+    assert_eq!(
+        prune_store.get_prune_info()?.unwrap().last_indexed_version,
+        Some(0)
+    );
+
+    moveos_store.index_state_node_logs(&versions[1..2])?;
+    let stats = moveos_store.prune_state_nodes(&versions, |_, _| Ok(false))?;
+    assert_eq!(stats.deleted_nodes, 1);
+    assert!(node_store.get(&a)?.is_none());
+    assert!(prune_store.get_node_refcount(a)?.is_none());
+    assert!(node_store.get(&b)?.is_some());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_apply_change_set_saves_node_log() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let mut change_set = random_state_change_set();
+    moveos_store
+        .get_state_store()
+        .apply_change_set(&mut change_set)?;
+    let node_log = moveos_store
+        .get_prune_store()
+        .get_node_log(change_set.state_root)?
+        .expect("the node log should be saved with the nodes");
+    assert!(!node_log.new_nodes.is_empty());
+    for node in node_log.new_nodes {
+        assert!(moveos_store.get_state_node_store().get(&node)?.is_some());
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_prune_from_baseline() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let node_store = moveos_store.get_state_node_store();
+    let prune_store = moveos_store.get_prune_store();
+
+    // `a` and `b` are written before the node logs are recorded
+    let (a, b, c) = (H256::random(), H256::random(), H256::random());
+    for node in [a, b] {
+        node_store.put(node, node.0.to_vec())?;
+    }
+    let legacy_state_root = H256::random();
+    prune_store.save_node_log(legacy_state_root, StateNodeLog::default())?;
+    let genesis = [(0, H256::random())];
+    assert!(moveos_store.index_state_node_logs(&genesis).is_err());
+
+    assert_eq!(moveos_store.init_state_prune_baseline(5)?, 2);
+    assert!(moveos_store.init_state_prune_baseline(5).is_err());
+    assert!(prune_store.get_node_refcount(a)?.unwrap().pinned);
+    assert!(prune_store.get_node_log(legacy_state_root)?.is_none());
+
+    node_store.put(c, c.0.to_vec())?;
+    let versions = vec![(6, H256::random()), (7, H256::random())];
+    prune_store.save_node_log(
+        versions[0].1,
+        StateNodeLog {
+            new_nodes: vec![c, a],
+            stale_nodes: vec![b],
+        },
+    )?;
+    prune_store.save_node_log(
+        versions[1].1,
+        StateNodeLog {
+            new_nodes: vec![],
+            stale_nodes: vec![c, a],
+        },
+    )?;
+    moveos_store.index_state_node_logs(&versions)?;
+    let stats = moveos_store.prune_state_nodes(&versions, |_, _| Ok(false))?;
+    // the pinned nodes are never deleted, even if they are written again after the baseline
+    assert_eq!(stats.deleted_nodes, 1);
+    assert!(node_store.get(&a)?.is_some());
+    assert!(node_store.get(&b)?.is_some());
+    assert!(node_store.get(&c)?.is_none());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_keep_nodes_written_after_prune_plan() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let node_store = moveos_store.get_state_node_store();
+    let prune_store = moveos_store.get_prune_store();
+
+    let (a, b) = (H256::random(), H256::random());
+    for node in [a, b] {
+        node_store.put(node, node.0.to_vec())?;
+    }
+    let versions = vec![(0, H256::random()), (1, H256::random())];
+    prune_store.save_node_log(
+        versions[0].1,
+        StateNodeLog {
+            new_nodes: vec![a, b],
+            stale_nodes: vec![],
+        },
+    )?;
+    prune_store.save_node_log(
+        versions[1].1,
+        StateNodeLog {
+            new_nodes: vec![],
+            stale_nodes: vec![a, b],
+        },
+    )?;
+    moveos_store.index_state_node_logs(&versions)?;
+    let plan = moveos_store
+        .plan_state_prune(&versions, |_, _| Ok(false))?
+        .unwrap();
+
+    // `a` is written again by a tx executed after the plan, before it is indexed
+    let unindexed_versions = vec![(2, H256::random())];
+    prune_store.save_node_log(
+        unindexed_versions[0].1,
+        StateNodeLog {
+            new_nodes: vec![a],
+            stale_nodes: vec![],
+        },
+    )?;
+    let stats = moveos_store.commit_state_prune(plan, &unindexed_versions)?;
+    assert_eq!(stats.deleted_nodes, 1);
+    assert_eq!(stats.retained_nodes, 1);
+    assert!(node_store.get(&a)?.is_some());
+    assert!(node_store.get(&b)?.is_none());
+
+    // `a` is referenced again once the version is indexed
+    moveos_store.index_state_node_logs(&unindexed_versions)?;
+    let refcount = prune_store.get_node_refcount(a)?.unwrap();
+    assert_eq!(refcount.refs, 1);
+    assert_eq!(refcount.stale_since, None);
+    Ok(())
+}
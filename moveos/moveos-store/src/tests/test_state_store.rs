@@ -5,11 +5,14 @@ use crate::{MoveOSStore, StoreMeta};
 use anyhow::Result;
 use moveos_config::store_config::RocksdbConfig;
 use moveos_types::h256::H256;
+use moveos_types::moveos_std::object::GENESIS_STATE_ROOT;
+use moveos_types::state::{ObjectChange, StateChangeSet};
 use moveos_types::test_utils::random_state_change_set;
 use raw_store::metrics::DBMetrics;
 use raw_store::rocks::RocksDB;
 use raw_store::{StoreInstance, CF_METRICS_REPORT_PERIOD_MILLIS};
-use smt::NodeReader;
+use smt::{InMemoryNodeStore, NodeReader};
+use std::sync::Arc;
 use std::time::Duration;
 
 #[tokio::test]
@@ -71,6 +74,69 @@ async fn test_statedb_state_root() -> Result<()> {
     Ok(())
 }
 
+fn assert_state_roots(change: &ObjectChange, state_root: H256) {
+    assert_eq!(change.metadata.state_root(), state_root);
+    for field_change in change.fields.values() {
+        assert_state_roots(field_change, state_root);
+    }
+}
+
+#[tokio::test]
+async fn test_rebase_change_set() -> Result<()> {
+    let (leader_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let (follower_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+
+    let mut applied_change_set = random_state_change_set();
+    leader_store
+        .get_state_store()
+        .apply_change_set(&mut applied_change_set)?;
+    assert_ne!(applied_change_set.state_root, *GENESIS_STATE_ROOT);
+
+    // The objects are new, their state roots before the changes are the genesis state root
+    let mut rebased_change_set = applied_change_set.clone();
+    follower_store.get_state_store().rebase_change_set(
+        StateChangeSet::default().root_metadata(),
+        &mut rebased_change_set,
+    )?;
+    assert_eq!(rebased_change_set.state_root, *GENESIS_STATE_ROOT);
+    for change in rebased_change_set.changes.values() {
+        assert_state_roots(change, *GENESIS_STATE_ROOT);
+    }
+
+    follower_store
+        .get_state_store()
+        .apply_change_set(&mut rebased_change_set)?;
+    assert_eq!(rebased_change_set, applied_change_set);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_load_node_from_remote() -> Result<()> {
+    let (moveos_store, _) =
+        MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
+    let remote = InMemoryNodeStore::default();
+    let key = H256::random();
+    let node = b"remotenode".to_vec();
+    remote.put(key, node.clone())?;
+
+    let node_store = moveos_store.get_state_node_store();
+    assert_eq!(node_store.get(&key)?, None);
+    node_store.set_remote_node_reader(Arc::new(remote.clone()))?;
+    assert!(node_store.is_forked());
+    assert!(node_store.set_remote_node_reader(Arc::new(remote)).is_err());
+
+    assert_eq!(node_store.get(&key)?, Some(node.clone()));
+    assert_eq!(node_store.get(&H256::random())?, None);
+    // The remote node is cached in the local store
+    assert_eq!(
+        raw_store::CodecKVStore::get_raw(node_store, key.as_bytes())?,
+        Some(node)
+    );
+    Ok(())
+}
+
 // #[tokio::test]
 // async fn test_child_state_db_dump_and_apply() -> Result<()> {
 //     let mut moveos_store = MoveOSStore::mock_moveos_store().expect("moveos store mock should succ");
@@ -3,16 +3,24 @@
 
 extern crate chrono;
 
+use crate::config_store::ConfigStore;
 use crate::event_store::EventStore;
+use crate::transaction_store::TransactionStore;
 use crate::MoveOSStore;
 use move_core_types::account_address::AccountAddress;
+use move_core_types::effects::Op;
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::{StructTag, TypeTag};
 use move_core_types::vm_status::KeptVMStatus;
 use moveos_config::store_config::RocksdbConfig;
 use moveos_types::h256::H256;
 use moveos_types::moveos_std::event::TransactionEvent;
-use moveos_types::transaction::TransactionExecutionInfo;
+use moveos_types::startup_info::StartupInfo;
+use moveos_types::state::StateChangeSet;
+use moveos_types::test_utils::{random_field_change, random_state_change_set};
+use moveos_types::transaction::{
+    RawTransactionOutput, TransactionExecutionInfo, TransactionOutput,
+};
 use raw_store::rocks::{RocksDB, DEFAULT_COLUMN_FAMILY_NAME};
 use raw_store::traits::DBStore;
 use raw_store::CodecKVStore;
@@ -165,3 +173,196 @@ async fn test_iter() {
     assert_eq!(transaction_info1, transaction_info2);
     assert!(iter.next().is_none());
 }
+
+fn random_tx_events() -> Vec<TransactionEvent> {
+    let event_type = StructTag {
+        address: AccountAddress::random(),
+        module: Identifier::new("Module").unwrap(),
+        name: Identifier::new("Name").unwrap(),
+        type_params: vec![],
+    };
+    vec![
+        TransactionEvent::new(event_type.clone(), b"data0".to_vec(), 0),
+        TransactionEvent::new(event_type, b"data1".to_vec(), 1),
+    ]
+}
+
+/// Modify the objects of the applied change set with new fields,
+/// the state roots in the applied change set are the state roots before the new changes.
+fn modify_applied_change_set(applied: &StateChangeSet) -> StateChangeSet {
+    let mut changeset = applied.clone();
+    for change in changeset.changes.values_mut() {
+        if let Some(Op::New(value)) = change.value.take() {
+            change.value = Some(Op::Modify(value));
+        }
+        change.fields.clear();
+        let (key, field_change) = random_field_change(1);
+        change.fields.insert(key, field_change);
+    }
+    changeset
+}
+
+fn handle_random_tx_output(
+    store: &MoveOSStore,
+    changeset: StateChangeSet,
+) -> (TransactionOutput, TransactionExecutionInfo) {
+    let output = RawTransactionOutput {
+        status: KeptVMStatus::Executed,
+        changeset,
+        events: random_tx_events(),
+        gas_used: 0,
+        is_upgrade: false,
+        is_gas_upgrade: false,
+    };
+    store.handle_tx_output(H256::random(), output).unwrap()
+}
+
+#[tokio::test]
+async fn test_apply_tx_change_set() {
+    let (leader, _) = MoveOSStore::mock_moveos_store().unwrap();
+    let (follower, _) = MoveOSStore::mock_moveos_store().unwrap();
+
+    let mut follower_root = StateChangeSet::default().root_metadata();
+    let mut applied: Option<StateChangeSet> = None;
+    for _ in 0..3 {
+        // The first tx creates new objects, the following txs modify them,
+        // so the state roots of the objects in the applied change sets must be rebased.
+        let changeset = match &applied {
+            Some(applied) => modify_applied_change_set(applied),
+            None => random_state_change_set(),
+        };
+        let (output, execution_info) = handle_random_tx_output(&leader, changeset);
+
+        let follower_changeset = follower
+            .apply_tx_change_set(
+                follower_root.clone(),
+                execution_info.clone(),
+                output.changeset.clone(),
+                output.events.clone(),
+            )
+            .unwrap();
+        assert_eq!(follower_changeset, output.changeset);
+        follower_root = follower_changeset.root_metadata();
+
+        for event in &output.events {
+            assert_eq!(
+                follower.get_event(event.event_id.clone()).unwrap(),
+                Some(event.clone())
+            );
+        }
+        assert_eq!(
+            follower
+                .get_tx_execution_info(execution_info.tx_hash)
+                .unwrap(),
+            Some(execution_info.clone())
+        );
+        assert_eq!(
+            follower.get_startup_info().unwrap(),
+            Some(StartupInfo::new(
+                execution_info.state_root,
+                execution_info.size
+            ))
+        );
+        applied = Some(output.changeset);
+    }
+}
+
+#[tokio::test]
+async fn test_apply_tx_change_set_mismatch() {
+    let (leader, _) = MoveOSStore::mock_moveos_store().unwrap();
+    let (follower, _) = MoveOSStore::mock_moveos_store().unwrap();
+    let root = StateChangeSet::default().root_metadata();
+    let (output, execution_info) = handle_random_tx_output(&leader, random_state_change_set());
+
+    let assert_nothing_written = || {
+        for event in &output.events {
+            assert!(follower
+                .get_event(event.event_id.clone())
+                .unwrap()
+                .is_none());
+        }
+        assert!(follower
+            .get_tx_execution_info(execution_info.tx_hash)
+            .unwrap()
+            .is_none());
+        assert!(follower.get_startup_info().unwrap().is_none());
+    };
+
+    // The state root is not the same as the leader's
+    let mut tampered_execution_info = execution_info.clone();
+    tampered_execution_info.state_root = H256::random();
+    assert!(follower
+        .apply_tx_change_set(
+            root.clone(),
+            tampered_execution_info,
+            output.changeset.clone(),
+            output.events.clone(),
+        )
+        .is_err());
+    assert_nothing_written();
+
+    // The change set is tampered
+    let mut tampered_changeset = output.changeset.clone();
+    tampered_changeset.changes.pop_first();
+    assert!(follower
+        .apply_tx_change_set(
+            root.clone(),
+            execution_info.clone(),
+            tampered_changeset,
+            output.events.clone(),
+        )
+        .is_err());
+    assert_nothing_written();
+
+    // The events are tampered
+    let mut tampered_events = output.events.clone();
+    tampered_events[0].event_data = b"tampered".to_vec();
+    assert!(follower
+        .apply_tx_change_set(
+            root.clone(),
+            execution_info.clone(),
+            output.changeset.clone(),
+            tampered_events,
+        )
+        .is_err());
+    assert_nothing_written();
+
+    follower
+        .apply_tx_change_set(
+            root,
+            execution_info.clone(),
+            output.changeset.clone(),
+            output.events.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        follower.get_startup_info().unwrap(),
+        Some(StartupInfo::new(
+            execution_info.state_root,
+            execution_info.size
+        ))
+    );
+
+    // The event ids are diverged from the local event store
+    let (diverged_follower, _) = MoveOSStore::mock_moveos_store().unwrap();
+    let event = &output.events[0];
+    diverged_follower
+        .save_events(vec![TransactionEvent::new(
+            event.event_type.clone(),
+            event.event_data.clone(),
+            event.event_index,
+        )])
+        .unwrap();
+    assert!(diverged_follower
+        .apply_tx_change_set(
+            StateChangeSet::default().root_metadata(),
+            execution_info.clone(),
+            output.changeset.clone(),
+            output.events.clone(),
+        )
+        .is_err());
+    assert!(diverged_follower
+        .get_tx_execution_info(execution_info.tx_hash)
+        .unwrap()
+        .is_none());
+}
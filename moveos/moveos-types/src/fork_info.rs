@@ -0,0 +1,42 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::moveos_std::object::ObjectMeta;
+use primitive_types::H256;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The fork point of a local node which forks the state of a remote node.
+#[derive(Eq, PartialEq, Hash, Deserialize, Serialize, Clone, Debug)]
+pub struct ForkInfo {
+    /// The RPC url of the remote node, the state nodes missing locally are loaded from it
+    pub fork_url: String,
+    /// The global state root of the remote node at the fork point
+    pub state_root: H256,
+    /// The global state tree size at the fork point
+    pub size: u64,
+}
+
+impl fmt::Display for ForkInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ForkInfo {{ fork_url: {}, state_root: {:?}, size: {} }}",
+            self.fork_url, self.state_root, self.size
+        )
+    }
+}
+
+impl ForkInfo {
+    pub fn new(fork_url: String, state_root: H256, size: u64) -> Self {
+        Self {
+            fork_url,
+            state_root,
+            size,
+        }
+    }
+
+    pub fn root_metadata(&self) -> ObjectMeta {
+        ObjectMeta::root_metadata(self.state_root, self.size)
+    }
+}
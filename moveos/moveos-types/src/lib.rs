@@ -3,6 +3,7 @@
 
 pub mod access_path;
 pub mod addresses;
+pub mod fork_info;
 pub mod function_return_value;
 pub mod genesis_info;
 pub mod h256;
@@ -18,6 +18,7 @@ pub mod object;
 pub mod onchain_features;
 pub mod simple_map;
 pub mod simple_multimap;
+pub mod storage_deposit;
 pub mod table;
 pub mod timestamp;
 pub mod tx_context;
@@ -66,6 +66,10 @@ pub struct Package {
 }
 
 impl Package {
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
     pub fn package_id(module_address: &AccountAddress) -> ObjectID {
         let module_store_id = ModuleStore::object_id();
         module_store_id.child_id(Self::package_field_key(module_address))
@@ -156,3 +160,45 @@ impl MoveStructState for PackageData {
         ])
     }
 }
+
+///////////// PackageSource ////////////////
+#[derive(Eq, PartialEq, Debug, Clone, Deserialize, Serialize)]
+pub struct PackageSource {
+    /// The package version the source is attached to
+    pub version: u64,
+    /// The digest of the package source files and manifest
+    pub source_digest: Vec<u8>,
+    /// The build metadata in JSON format
+    pub build_metadata: MoveString,
+}
+
+impl PackageSource {
+    pub fn object_id(package_id: AccountAddress) -> ObjectID {
+        object::account_named_object_id(package_id, &Self::struct_tag())
+    }
+}
+
+impl MoveStructType for PackageSource {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("PackageSource");
+
+    fn struct_tag() -> StructTag {
+        StructTag {
+            address: Self::ADDRESS,
+            module: Self::MODULE_NAME.to_owned(),
+            name: Self::STRUCT_NAME.to_owned(),
+            type_params: vec![],
+        }
+    }
+}
+
+impl MoveStructState for PackageSource {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![
+            MoveTypeLayout::U64,
+            MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8)),
+            MoveTypeLayout::Struct(MoveString::struct_layout()),
+        ])
+    }
+}
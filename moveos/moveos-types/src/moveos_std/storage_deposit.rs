@@ -0,0 +1,38 @@
+// Copyright (c) RoochNetwork
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::addresses::MOVEOS_STD_ADDRESS;
+use crate::moveos_std::object::{self, ObjectID};
+use crate::state::{MoveStructState, MoveStructType};
+use move_core_types::{account_address::AccountAddress, ident_str, identifier::IdentStr};
+use serde::{Deserialize, Serialize};
+
+pub const MODULE_NAME: &IdentStr = ident_str!("storage_deposit");
+
+/// The storage deposit paid for the value bytes of an object.
+/// It is kept in a root object beside the object, so the `ObjectMeta` encoding is not changed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Default)]
+pub struct StorageDepositRecord {
+    pub deposit: u64,
+}
+
+impl StorageDepositRecord {
+    /// The id of the storage deposit record of the object
+    pub fn record_id(object_id: &ObjectID) -> ObjectID {
+        object::custom_object_id(object_id, &Self::struct_tag())
+    }
+}
+
+impl MoveStructType for StorageDepositRecord {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("StorageDepositRecord");
+}
+
+impl MoveStructState for StorageDepositRecord {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U64,
+        ])
+    }
+}
@@ -194,6 +194,35 @@ impl MoveStructState for TxContext {
     }
 }
 
+/// The account which pays the gas of the transaction if it is not the sender,
+/// eg. the sponsor of the sponsored transaction.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct GasPaymentAccount {
+    pub account: AccountAddress,
+    pub pay_gas_by_module_account: bool,
+}
+
+impl GasPaymentAccount {
+    pub fn new(account: AccountAddress) -> Self {
+        Self {
+            account,
+            pay_gas_by_module_account: false,
+        }
+    }
+}
+
+impl MoveStructType for GasPaymentAccount {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = TX_CONTEXT_MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("GasPaymentAccount");
+}
+
+impl MoveStructState for GasPaymentAccount {
+    fn struct_layout() -> MoveStructLayout {
+        MoveStructLayout::new(vec![MoveTypeLayout::Address, MoveTypeLayout::Bool])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use move_core_types::value::MoveValue;
@@ -42,3 +42,40 @@ impl TxResult {
         }
     }
 }
+
+/// The storage deposit settled for the objects changed by the transaction.
+/// The VM puts it in the TxContext after the action execution, so the `post_execute` function can
+/// move the deposit into the storage deposit pool and refund the rebate to the gas payment account.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageDeposit {
+    /// The deposit charged for the new or grown objects, it is included in the gas used
+    pub deposit: u64,
+    /// The deposit refunded for the shrunk or removed objects
+    pub rebate: u64,
+}
+
+impl MoveStructType for StorageDeposit {
+    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
+    const MODULE_NAME: &'static IdentStr = MODULE_NAME;
+    const STRUCT_NAME: &'static IdentStr = ident_str!("StorageDeposit");
+}
+
+impl MoveStructState for StorageDeposit {
+    fn struct_layout() -> move_core_types::value::MoveStructLayout {
+        move_core_types::value::MoveStructLayout::new(vec![
+            move_core_types::value::MoveTypeLayout::U64,
+            move_core_types::value::MoveTypeLayout::U64,
+        ])
+    }
+}
+
+impl StorageDeposit {
+    /// Record the deposit of an object changed from `previous` to `required`
+    pub fn settle(&mut self, previous: u64, required: u64) {
+        if required > previous {
+            self.deposit = self.deposit.saturating_add(required - previous);
+        } else {
+            self.rebate = self.rebate.saturating_add(previous - required);
+        }
+    }
+}
@@ -111,6 +111,99 @@ impl Arbitrary for FunctionCall {
     }
 }
 
+/// The argument of a call in a `MultiCall`
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
+pub enum CallArgument {
+    /// The BCS serialized argument, it is resolved the same as the args of `FunctionCall`
+    Pure(Vec<u8>),
+    /// The `result_index` return value of the `call_index` call before the current call
+    Result { call_index: u16, result_index: u16 },
+}
+
+impl CallArgument {
+    pub fn pure(arg: Vec<u8>) -> Self {
+        Self::Pure(arg)
+    }
+
+    pub fn result(call_index: u16, result_index: u16) -> Self {
+        Self::Result {
+            call_index,
+            result_index,
+        }
+    }
+}
+
+/// Call a Move function in a `MultiCall`, the args can refer to the return values of the previous calls
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChainedCall {
+    pub function_id: FunctionId,
+    pub ty_args: Vec<TypeTag>,
+    pub args: Vec<CallArgument>,
+}
+
+impl ChainedCall {
+    pub fn new(function_id: FunctionId, ty_args: Vec<TypeTag>, args: Vec<CallArgument>) -> Self {
+        Self {
+            function_id,
+            ty_args,
+            args,
+        }
+    }
+}
+
+impl From<FunctionCall> for ChainedCall {
+    fn from(call: FunctionCall) -> Self {
+        Self {
+            function_id: call.function_id,
+            ty_args: call.ty_args,
+            args: call.args.into_iter().map(CallArgument::Pure).collect(),
+        }
+    }
+}
+
+// Generates random ChainedCall
+#[cfg(any(test, feature = "fuzzing"))]
+impl Arbitrary for ChainedCall {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        let function_id_strategy = (any::<ModuleId>(), any::<Identifier>())
+            .prop_map(|(module_id, identifier)| FunctionId::new(module_id, identifier));
+        let ty_args_strategy = prop::collection::vec(type_tag_prop_strategy(), 0..10);
+
+        (
+            function_id_strategy,
+            ty_args_strategy,
+            any::<Vec<CallArgument>>(),
+        )
+            .prop_map(|(function_id, ty_args, args)| ChainedCall {
+                function_id,
+                ty_args,
+                args,
+            })
+            .boxed()
+    }
+}
+
+/// Call a sequence of Move functions in one transaction, the calls are executed in order and atomically,
+/// the return values of a call can be passed to the following calls as arguments.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
+pub struct MultiCall {
+    pub calls: Vec<ChainedCall>,
+}
+
+impl MultiCall {
+    /// The max number of calls in a `MultiCall`
+    pub const MAX_CALLS: usize = 32;
+
+    pub fn new(calls: Vec<ChainedCall>) -> Self {
+        Self { calls }
+    }
+}
+
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(any(test, feature = "fuzzing"), derive(Arbitrary))]
 pub enum MoveAction {
@@ -120,6 +213,8 @@ pub enum MoveAction {
     Function(FunctionCall),
     //Publish Move modules
     ModuleBundle(Vec<Vec<u8>>),
+    //Execute a sequence of Move functions with result chaining
+    MultiCall(MultiCall),
 }
 
 impl MoveAction {
@@ -128,6 +223,7 @@ impl MoveAction {
             MoveAction::Script(_) => 0,
             MoveAction::Function(_) => 1,
             MoveAction::ModuleBundle(_) => 2,
+            MoveAction::MultiCall(_) => 3,
         }
     }
 
@@ -136,6 +232,7 @@ impl MoveAction {
             MoveAction::Script(_) => "Script".to_string(),
             MoveAction::Function(_) => "Function".to_string(),
             MoveAction::ModuleBundle(_) => "ModuleBundle".to_string(),
+            MoveAction::MultiCall(_) => "MultiCall".to_string(),
         }
     }
 
@@ -153,6 +250,9 @@ impl MoveAction {
             args,
         })
     }
+    pub fn new_multi_call(calls: Vec<ChainedCall>) -> Self {
+        Self::MultiCall(MultiCall::new(calls))
+    }
     pub fn new_script_call(code: Vec<u8>, ty_args: Vec<TypeTag>, args: Vec<Vec<u8>>) -> Self {
         Self::Script(ScriptCall {
             code,
@@ -180,6 +280,7 @@ impl From<VerifiedMoveAction> for MoveAction {
                 module_bundle,
                 init_function_modules: _init_function_modules,
             } => MoveAction::ModuleBundle(module_bundle),
+            VerifiedMoveAction::MultiCall { call } => MoveAction::MultiCall(call),
         }
     }
 }
@@ -229,6 +330,27 @@ impl Display for MoveAction {
                 }
                 write!(f, "MoveAction::ModuleBundle( {:?} )", module_list)
             }
+            MoveAction::MultiCall(multi_call) => {
+                let mut call_list = vec![];
+                for call in multi_call.calls.iter() {
+                    let arg_list = call
+                        .args
+                        .iter()
+                        .map(|arg| match arg {
+                            CallArgument::Pure(arg) => format!("0x{:}", hex::encode(arg)),
+                            CallArgument::Result {
+                                call_index,
+                                result_index,
+                            } => format!("Result({}, {})", call_index, result_index),
+                        })
+                        .collect::<Vec<_>>();
+                    call_list.push(format!(
+                        "FunctionCall( function_id: {},  type_args: {:?}, args: {:?})",
+                        call.function_id, call.ty_args, arg_list
+                    ));
+                }
+                write!(f, "MoveAction::MultiCall( {:?} )", call_list)
+            }
         }
     }
 }
@@ -247,6 +369,9 @@ pub enum VerifiedMoveAction {
         module_bundle: Vec<Vec<u8>>,
         init_function_modules: Vec<ModuleId>,
     },
+    MultiCall {
+        call: MultiCall,
+    },
 }
 
 impl Display for VerifiedMoveAction {
@@ -276,6 +401,9 @@ impl Display for VerifiedMoveAction {
                     init_function_modules.len()
                 )
             }
+            VerifiedMoveAction::MultiCall { call } => {
+                write!(f, "MultiCall(calls: {})", call.calls.len())
+            }
         }
     }
 }
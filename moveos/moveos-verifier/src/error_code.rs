@@ -71,6 +71,8 @@ pub enum ErrorCode {
     INVALID_PARAM_TYPE_ENTRY_FUNCTION = 11001,
     INVALID_PARAM_SINGER_COUNT = 11002,
     INVALID_FIRST_ARGUMENT_IS_NOT_SIGNER = 11003,
+    INVALID_MULTI_CALL_PRIVATE_GENERICS_FUNCTION = 11004,
+    MULTI_CALL_RESULT_ALREADY_USED = 11005,
 
     INVALID_PUBLIC_INIT_FUNC = 12000,
     INVALID_INIT_FUNC_WITH_ENTRY = 12001,
@@ -13,7 +13,7 @@ use move_binary_format::file_format::{
     StructFieldInformation, StructHandleIndex, Visibility,
 };
 use move_binary_format::{access::ModuleAccess, CompiledModule};
-use move_core_types::identifier::Identifier;
+use move_core_types::identifier::{IdentStr, Identifier};
 use move_core_types::language_storage::ModuleId;
 use move_core_types::resolver::ModuleResolver;
 use move_core_types::vm_status::StatusCode;
@@ -302,7 +302,80 @@ fn struct_full_name_from_sid(
     format!("0x{}::{}::{}", module_address, module_name, struct_name)
 }
 
-fn check_transaction_input_type<S>(ety: &Type, session: &Session<S>) -> bool
+/// Verify the function called in a `MultiCall`.
+/// The function must be an entry function or a public function without `private_generics`, the return values
+/// are passed to the following calls by value, so the function can not return references or signer.
+/// The parameters are checked when resolving the arguments, because a parameter can receive a return value
+/// of the previous call which is not allowed as a transaction input.
+pub fn verify_multi_call_function(
+    module: &CompiledModule,
+    function_name: &IdentStr,
+    func: &LoadedFunctionInstantiation,
+) -> PartialVMResult<()> {
+    let fdef = module
+        .function_defs
+        .iter()
+        .find(|fdef| {
+            module.identifier_at(module.function_handle_at(fdef.function).name) == function_name
+        })
+        .ok_or_else(|| {
+            PartialVMError::new(StatusCode::FUNCTION_RESOLUTION_FAILURE).with_message(format!(
+                "Function {}::{} not found",
+                module.self_id(),
+                function_name
+            ))
+        })?;
+    if !fdef.is_entry && fdef.visibility != Visibility::Public {
+        return Err(PartialVMError::new(
+            StatusCode::EXECUTE_ENTRY_FUNCTION_CALLED_ON_NON_ENTRY_FUNCTION,
+        )
+        .with_message(format!(
+            "Function {}::{} should be an entry function or a public function",
+            module.self_id(),
+            function_name
+        )));
+    }
+
+    // The calls are executed with visibility bypassed, the type arguments of a `private_generics`
+    // function would not be checked, so it can not be called in a `MultiCall`.
+    let full_func_name = format!(
+        "{}::{}::{}",
+        module.self_id().address().to_hex_literal(),
+        module.self_id().name(),
+        function_name
+    );
+    if let Some(metadata) = get_metadata_from_compiled_module(module) {
+        if metadata
+            .private_generics_indices
+            .contains_key(full_func_name.as_str())
+        {
+            return Err(PartialVMError::new(StatusCode::ABORTED)
+                .with_sub_status(ErrorCode::INVALID_MULTI_CALL_PRIVATE_GENERICS_FUNCTION.into())
+                .with_message(format!(
+                    "Function {} with private_generics can not be called in MultiCall",
+                    full_func_name
+                )));
+        }
+    }
+
+    for (idx, ty) in func.return_.iter().enumerate() {
+        if matches!(
+            ty,
+            Type::Reference(_) | Type::MutableReference(_) | Type::Signer
+        ) {
+            return Err(PartialVMError::new(StatusCode::ABORTED)
+                .with_sub_status(ErrorCode::INVALID_ENTRY_FUNC_SIGNATURE.into())
+                .with_message(format!(
+                    "The type of the {} return value is not allowed",
+                    idx
+                )));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn check_transaction_input_type<S>(ety: &Type, session: &Session<S>) -> bool
 where
     S: TransactionCache,
 {
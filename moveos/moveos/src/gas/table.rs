@@ -48,6 +48,7 @@ pub struct StorageGasParameter {
     pub io_read_price: InternalGas,
     pub storage_fee_per_transaction_byte: InternalGas,
     pub storage_fee_per_event_byte: InternalGas,
+    /// The storage deposit per byte of the object value, it is refunded when the object shrinks or is removed
     pub storage_fee_per_op_new_byte: InternalGas,
     pub storage_fee_per_op_modify_byte: InternalGas,
     pub storage_fee_per_op_delete: InternalGas,
@@ -795,6 +796,30 @@ impl ClassifiedGasMeter for MoveOSGasMeter {
 
         self.deduct_gas(InternalGas::from(io_writes_gas))
     }
+
+    fn storage_deposit_per_byte(&self) -> u64 {
+        if !self.charge {
+            return 0;
+        }
+
+        self.cost_table
+            .storage_gas_parameter
+            .storage_fee_per_op_new_byte
+            .into()
+    }
+
+    fn charge_storage_deposit(&mut self, deposit: u64) -> PartialVMResult<()> {
+        if !self.charge {
+            return Ok(());
+        }
+
+        let new_value = self
+            .storage_gas_used
+            .borrow()
+            .add(InternalGas::from(deposit));
+        *self.storage_gas_used.borrow_mut() = new_value;
+        self.deduct_gas(InternalGas::from(deposit))
+    }
     //TODO cleanup
     // fn charge_event(&mut self, events: &[TransactionEvent]) -> PartialVMResult<()> {
     //     if !self.charge {
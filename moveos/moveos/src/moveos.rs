@@ -12,36 +12,37 @@ use move_binary_format::errors::VMError;
 use move_binary_format::errors::{vm_status_of_result, Location, PartialVMError, VMResult};
 use move_binary_format::file_format::FunctionDefinitionIndex;
 use move_binary_format::CompiledModule;
-use move_core_types::identifier::IdentStr;
 use move_core_types::language_storage::ModuleId;
 use move_core_types::value::MoveTypeLayout;
 use move_core_types::vm_status::{KeptVMStatus, VMStatus};
 use move_core_types::{
-    account_address::AccountAddress, ident_str, identifier::Identifier, vm_status::StatusCode,
+    account_address::AccountAddress, identifier::Identifier, vm_status::StatusCode,
 };
 use move_vm_runtime::config::VMConfig;
 use move_vm_runtime::data_cache::TransactionCache;
 use move_vm_runtime::native_functions::NativeFunction;
-use moveos_common::types::ClassifiedGasMeter;
+use moveos_common::types::{ClassifiedGasMeter, SwitchableGasMeter};
+use moveos_gas_profiling::log::TransactionGasLog;
+use moveos_gas_profiling::profiler::{new_verified_action_gas_profiler, ProfileGasMeter};
+use moveos_object_runtime::read_write_set::{ReadSet, ReadSetRecorder};
 use moveos_store::config_store::ConfigDBStore;
 use moveos_store::event_store::EventDBStore;
 use moveos_store::state_store::statedb::StateDBStore;
 use moveos_store::transaction_store::TransactionDBStore;
 use moveos_store::{load_feature_store_object, MoveOSStore};
-use moveos_types::addresses::MOVEOS_STD_ADDRESS;
 use moveos_types::function_return_value::FunctionResult;
 use moveos_types::moveos_std::gas_schedule::{GasScheduleConfig, GasScheduleUpdated};
 use moveos_types::moveos_std::object::ObjectMeta;
+pub use moveos_types::moveos_std::tx_context::GasPaymentAccount;
 use moveos_types::moveos_std::tx_context::TxContext;
 use moveos_types::moveos_std::tx_result::TxResult;
-use moveos_types::state::{MoveStructState, MoveStructType, ObjectState};
-use moveos_types::state_resolver::{GenesisResolver, RootObjectResolver};
+use moveos_types::state::ObjectState;
+use moveos_types::state_resolver::{GenesisResolver, RootObjectResolver, StatelessResolver};
 use moveos_types::transaction::{FunctionCall, VMErrorInfo};
 use moveos_types::transaction::{
     MoveOSTransaction, RawTransactionOutput, VerifiedMoveAction, VerifiedMoveOSTransaction,
 };
 use parking_lot::RwLock;
-use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 #[derive(thiserror::Error, Debug)]
@@ -52,27 +53,6 @@ pub enum VMPanicError {
     SystemCallPanicError(Error),
 }
 
-#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
-pub struct GasPaymentAccount {
-    pub account: AccountAddress,
-    pub pay_gas_by_module_account: bool,
-}
-
-impl MoveStructType for GasPaymentAccount {
-    const ADDRESS: AccountAddress = MOVEOS_STD_ADDRESS;
-    const MODULE_NAME: &'static IdentStr = ident_str!("tx_context");
-    const STRUCT_NAME: &'static IdentStr = ident_str!("GasPaymentAccount");
-}
-
-impl MoveStructState for GasPaymentAccount {
-    fn struct_layout() -> move_core_types::value::MoveStructLayout {
-        move_core_types::value::MoveStructLayout::new(vec![
-            move_core_types::value::MoveTypeLayout::Address,
-            move_core_types::value::MoveTypeLayout::Bool,
-        ])
-    }
-}
-
 #[derive(Default)]
 pub struct MoveOSConfig {
     pub vm_config: VMConfig,
@@ -263,6 +243,79 @@ impl MoveOS {
         &self,
         tx: VerifiedMoveOSTransaction,
     ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)> {
+        let gas_meter = self.new_gas_meter(&tx.root, tx.ctx.max_gas_amount)?;
+        self.execute_with_gas_meter(tx, gas_meter, &self.db, |_| {})
+    }
+
+    /// Execute the transaction and record the objects read from the state,
+    /// the read set is used to detect the conflicts when the transactions are executed in parallel.
+    pub fn execute_with_read_set(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>, ReadSet)> {
+        let recorder = ReadSetRecorder::new(&tx.root, &self.db);
+        let gas_meter =
+            self.new_gas_meter_with_resolver(&tx.root, tx.ctx.max_gas_amount, &recorder)?;
+        let (output, vm_error_info) =
+            self.execute_with_gas_meter(tx, gas_meter, &recorder, |in_action| {
+                recorder.set_in_action(in_action)
+            })?;
+        Ok((output, vm_error_info, recorder.into_read_set()))
+    }
+
+    /// Execute the transaction with the gas profiler, the returned gas log contains the call graph
+    /// of the execution, and the gas cost of every frame.
+    pub fn execute_with_gas_profiler(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+    ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>, TransactionGasLog)> {
+        let gas_meter = self.new_gas_meter(&tx.root, tx.ctx.max_gas_amount)?;
+        let mut gas_profiler = new_verified_action_gas_profiler(&tx.action, gas_meter);
+        // The profiler clones share the call frames, so we keep one to collect the gas log.
+        let (output, vm_error_info) =
+            self.execute_with_gas_meter(tx, gas_profiler.clone(), &self.db, |_| {})?;
+        Ok((output, vm_error_info, gas_profiler.finish()))
+    }
+
+    fn new_gas_meter(&self, root: &ObjectMeta, max_gas_amount: u64) -> VMResult<MoveOSGasMeter> {
+        self.new_gas_meter_with_resolver(root, max_gas_amount, &self.db)
+    }
+
+    fn new_gas_meter_with_resolver<R: StatelessResolver>(
+        &self,
+        root: &ObjectMeta,
+        max_gas_amount: u64,
+        db: &R,
+    ) -> VMResult<MoveOSGasMeter> {
+        let feature_resolver = RootObjectResolver::new(root.clone(), db);
+        let feature_store_opt = load_feature_store_object(&feature_resolver);
+        let has_io_tired_write_feature = match feature_store_opt {
+            None => false,
+            Some(feature_store) => feature_store.has_value_size_gas_feature(),
+        };
+
+        let cost_table = self.load_cost_table(root)?;
+        Ok(MoveOSGasMeter::new(
+            cost_table,
+            max_gas_amount,
+            has_io_tired_write_feature,
+        ))
+    }
+
+    /// The `set_in_action` is called before and after the transaction action is executed,
+    /// so the caller can tell the state reads of the action from the reads of the system functions.
+    fn execute_with_gas_meter<G, R, F>(
+        &self,
+        tx: VerifiedMoveOSTransaction,
+        gas_meter: G,
+        db: &R,
+        set_in_action: F,
+    ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)>
+    where
+        G: SwitchableGasMeter + ClassifiedGasMeter + Clone,
+        R: StatelessResolver,
+        F: Fn(bool),
+    {
         let VerifiedMoveOSTransaction { root, ctx, action } = tx;
         let tx_hash = ctx.tx_hash();
         if tracing::enabled!(tracing::Level::DEBUG) {
@@ -279,20 +332,9 @@ impl MoveOS {
         // The variables in TxContext kv store before this executions should not be cleaned,
         // So we keep a backup here, and then insert to the TxContext kv store when session respawed.
         let system_env = ctx.map.clone();
-
-        let feature_resolver = RootObjectResolver::new(root.clone(), &self.db);
-        let feature_store_opt = load_feature_store_object(&feature_resolver);
-        let has_io_tired_write_feature = match feature_store_opt {
-            None => false,
-            Some(feature_store) => feature_store.has_value_size_gas_feature(),
-        };
-
-        let cost_table = self.load_cost_table(&root)?;
-        let gas_meter =
-            MoveOSGasMeter::new(cost_table, ctx.max_gas_amount, has_io_tired_write_feature);
         let tx_size = ctx.tx_size;
 
-        let resolver = RootObjectResolver::new(root, &self.db);
+        let resolver = RootObjectResolver::new(root, db);
         let mut session = self.vm.new_session(&resolver, ctx, gas_meter);
 
         //We do not execute pre_execute and post_execute functions for system call
@@ -310,7 +352,11 @@ impl MoveOS {
             }
         }
 
-        match self.execute_action(&mut session, action.clone(), tx_size) {
+        set_in_action(true);
+        let action_result =
+            self.execute_action(&mut session, action.clone(), tx_size, is_system_call);
+        set_in_action(false);
+        match action_result {
             Ok(_) => {
                 let status = VMStatus::Executed;
                 if tracing::enabled!(tracing::Level::DEBUG) {
@@ -440,28 +486,42 @@ impl MoveOS {
     // Execute action with pre_execute and post_execute.
     // Return the action execution status if success,
     // else return VMError and a bool which indicate if we should respawn the session.
-    fn execute_action(
+    fn execute_action<G, R>(
         &self,
-        session: &mut MoveOSSession<'_, '_, RootObjectResolver<MoveOSStore>, MoveOSGasMeter>,
+        session: &mut MoveOSSession<'_, '_, RootObjectResolver<R>, G>,
         action: VerifiedMoveAction,
         tx_size: u64,
-    ) -> Result<(), VMError> {
+        is_system_call: bool,
+    ) -> Result<(), VMError>
+    where
+        G: SwitchableGasMeter + ClassifiedGasMeter,
+        R: StatelessResolver,
+    {
         match session.gas_meter.charge_io_write(tx_size) {
             Ok(_) => {}
             Err(e) => {
                 return Err(e.finish(Location::Undefined));
             }
         }
-        session.execute_move_action(action)
+        session.execute_move_action(action)?;
+        // The storage deposit is settled in the post_execute function, so we do not settle it for system call
+        if !is_system_call {
+            session.settle_storage_deposit()?;
+        }
+        Ok(())
     }
 
-    fn execution_cleanup(
+    fn execution_cleanup<G, R>(
         &self,
         is_system_call: bool,
-        mut session: MoveOSSession<'_, '_, RootObjectResolver<MoveOSStore>, MoveOSGasMeter>,
+        mut session: MoveOSSession<'_, '_, RootObjectResolver<R>, G>,
         status: VMStatus,
         vm_error_info: Option<VMErrorInfo>,
-    ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)> {
+    ) -> Result<(RawTransactionOutput, Option<VMErrorInfo>)>
+    where
+        G: SwitchableGasMeter + ClassifiedGasMeter,
+        R: StatelessResolver,
+    {
         let kept_status = match status.keep_or_discard() {
             Ok(kept_status) => {
                 if is_system_call && kept_status != KeptVMStatus::Executed {
@@ -530,9 +590,9 @@ impl MoveOS {
     }
 }
 
-fn extract_execution_state(
+fn extract_execution_state<R: StatelessResolver>(
     vm_err: VMError,
-    data_cache: &MoveosDataCache<RootObjectResolver<MoveOSStore>>,
+    data_cache: &MoveosDataCache<RootObjectResolver<R>>,
 ) -> Result<Vec<String>> {
     let mut execution_stack_trace = Vec::new();
     if let Some(exec_state) = vm_err.exec_state() {
@@ -557,10 +617,10 @@ fn extract_execution_state(
     Ok(execution_stack_trace)
 }
 
-fn func_name_from_db(
+fn func_name_from_db<R: StatelessResolver>(
     module_id: &ModuleId,
     func_idx: &FunctionDefinitionIndex,
-    data_cache: &MoveosDataCache<RootObjectResolver<MoveOSStore>>,
+    data_cache: &MoveosDataCache<RootObjectResolver<R>>,
 ) -> Result<String> {
     let module_bytes = data_cache.load_module(module_id)?;
     let compiled_module = CompiledModule::deserialize(module_bytes.as_slice())?;
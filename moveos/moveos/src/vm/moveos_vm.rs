@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::data_cache::{into_change_set, MoveosDataCache};
+use super::tx_argument_resolver::MultiCallResults;
 use move_binary_format::compatibility::Compatibility;
 use move_binary_format::file_format::CompiledScript;
 use move_binary_format::normalized;
@@ -46,7 +47,7 @@ use moveos_types::{
     moveos_std::tx_context::TxContext,
     moveos_std::{event::TransactionEvent, module_upgrade_flag::ModuleUpgradeFlag},
     state_resolver::MoveOSResolver,
-    transaction::{FunctionCall, MoveAction, VerifiedMoveAction},
+    transaction::{ChainedCall, FunctionCall, MoveAction, MultiCall, VerifiedMoveAction},
 };
 use moveos_verifier::verifier::INIT_FN_NAME_IDENTIFIER;
 use parking_lot::RwLock;
@@ -301,7 +302,94 @@ where
                     init_function_modules,
                 })
             }
+            MoveAction::MultiCall(call) => {
+                self.verify_multi_call(&call)?;
+                Ok(VerifiedMoveAction::MultiCall { call })
+            }
+        }
+    }
+
+    /// Verify the calls of a `MultiCall` and the result arguments without execution.
+    fn verify_multi_call(&self, call: &MultiCall) -> VMResult<()> {
+        if call.calls.is_empty() || call.calls.len() > MultiCall::MAX_CALLS {
+            return Err(PartialVMError::new(StatusCode::MALFORMED)
+                .with_message(format!(
+                    "The number of calls in MultiCall should be in [1, {}], got:{}",
+                    MultiCall::MAX_CALLS,
+                    call.calls.len()
+                ))
+                .finish(Location::Undefined));
+        }
+        let mut results = MultiCallResults::default();
+        for call in call.calls.iter() {
+            let loaded_function = self.load_multi_call_function(call)?;
+            let location = Location::Module(call.function_id.module_id.clone());
+            let _resolved_args = self.resolve_multi_call_argument(
+                &loaded_function,
+                call.args.clone(),
+                &mut results,
+                location.clone(),
+                false,
+            )?;
+            self.push_multi_call_results(&loaded_function, None, &mut results, location)?;
         }
+        results.check_consumed()
+    }
+
+    fn load_multi_call_function(
+        &self,
+        call: &ChainedCall,
+    ) -> VMResult<LoadedFunctionInstantiation> {
+        let loaded_function = self.session.load_function(
+            &call.function_id.module_id,
+            &call.function_id.function_name,
+            call.ty_args.as_slice(),
+        )?;
+        let module = self
+            .vm
+            .load_module(&call.function_id.module_id, &self.remote)?;
+        moveos_verifier::verifier::verify_multi_call_function(
+            module.as_ref(),
+            &call.function_id.function_name,
+            &loaded_function,
+        )
+        .map_err(|e| e.finish(Location::Module(call.function_id.module_id.clone())))?;
+        Ok(loaded_function)
+    }
+
+    /// Execute the calls of a `MultiCall` in order, the return values are passed to the following calls.
+    fn execute_multi_call(&mut self, call: MultiCall) -> VMResult<()> {
+        let mut results = MultiCallResults::default();
+        for call in call.calls {
+            let loaded_function = self.load_multi_call_function(&call)?;
+            let location = Location::Module(call.function_id.module_id.clone());
+            let serialized_args = self.resolve_multi_call_argument(
+                &loaded_function,
+                call.args,
+                &mut results,
+                location.clone(),
+                true,
+            )?;
+            let return_values = self.session.execute_function_bypass_visibility(
+                &call.function_id.module_id,
+                &call.function_id.function_name,
+                call.ty_args,
+                serialized_args,
+                &mut self.gas_meter,
+            )?;
+            let values = return_values
+                .return_values
+                .into_iter()
+                .map(|(value, _layout)| value)
+                .collect();
+            self.push_multi_call_results(&loaded_function, Some(values), &mut results, location)?;
+            // Release the object arguments, so the objects can be used by the following calls
+            self.object_runtime
+                .write()
+                .release_arguments()
+                .map_err(|e| e.finish(Location::Undefined))?;
+        }
+        results.check_consumed()
     }
 
     /// Execute a move action.
@@ -470,6 +558,7 @@ where
 
                 self.execute_init_modules(modules_with_init)
             }
+            VerifiedMoveAction::MultiCall { call } => self.execute_multi_call(call),
         };
 
         if action_result.is_ok() {
@@ -489,6 +578,23 @@ where
         action_result
     }
 
+    /// Settle the storage deposit of the objects changed by the action.
+    /// The deposit is charged as storage gas, and the settled `StorageDeposit` is put in the TxContext,
+    /// so the `post_execute` function can move the deposit into the pool and refund the rebate.
+    pub fn settle_storage_deposit(&mut self) -> VMResult<()> {
+        let deposit_per_byte = self.gas_meter.storage_deposit_per_byte();
+        let mut object_runtime = self.object_runtime.write();
+        let storage_deposit = object_runtime
+            .settle_storage_deposit(deposit_per_byte)
+            .map_err(|e| e.finish(Location::Undefined))?;
+        self.gas_meter
+            .charge_storage_deposit(storage_deposit.deposit)
+            .map_err(|e| e.finish(Location::Undefined))?;
+        object_runtime
+            .add_to_tx_context(storage_deposit)
+            .map_err(|e| e.finish(Location::Undefined))
+    }
+
     /// Resolve pending init functions request registered via the NativeModuleContext.
     fn resolve_pending_init_functions(&mut self) -> VMResult<()> {
         let ctx = self
@@ -4,6 +4,7 @@
 use super::moveos_vm::MoveOSSession;
 use anyhow::bail;
 use move_binary_format::errors::{Location, PartialVMError, PartialVMResult, VMResult};
+use move_binary_format::file_format::AbilitySet;
 use move_core_types::value::MoveValue;
 use move_core_types::vm_status::VMStatus;
 use move_core_types::{language_storage::TypeTag, vm_status::StatusCode};
@@ -14,6 +15,7 @@ use moveos_common::types::{ClassifiedGasMeter, SwitchableGasMeter};
 use moveos_object_runtime::resolved_arg::ResolvedArg;
 use moveos_object_runtime::TypeLayoutLoader;
 use moveos_types::state::ObjectState;
+use moveos_types::transaction::CallArgument;
 use moveos_types::{
     move_std::{ascii::MoveAsciiString, string::MoveString},
     moveos_std::object::{is_object_struct, ObjectID},
@@ -24,16 +26,202 @@ use moveos_types::{
     state::{MoveStructType, PlaceholderStruct},
     state_resolver::MoveOSResolver,
 };
+use moveos_verifier::error_code::ErrorCode;
+use moveos_verifier::verifier::check_transaction_input_type;
 use std::io::{Cursor, Read};
 use std::ops::Deref;
 use std::sync::Arc;
 use std::vec::IntoIter;
 
+/// A return value of a call in a `MultiCall`
+struct CallResult {
+    ty: Type,
+    /// The serialized value, it is `None` when the `MultiCall` is verified without execution
+    value: Option<Vec<u8>>,
+    abilities: AbilitySet,
+    consumed: bool,
+}
+
+/// The return values of the executed calls in a `MultiCall`.
+/// A return value can be passed to the following calls by value only, the value without `copy` ability
+/// can be used once, and the value without `drop` ability must be used before the end of the `MultiCall`.
+#[derive(Default)]
+pub struct MultiCallResults {
+    results: Vec<Vec<CallResult>>,
+}
+
+impl MultiCallResults {
+    fn take(
+        &mut self,
+        call_index: u16,
+        result_index: u16,
+        param_ty: &Type,
+        location: Location,
+    ) -> VMResult<Option<Vec<u8>>> {
+        let result = self
+            .results
+            .get_mut(call_index as usize)
+            .and_then(|results| results.get_mut(result_index as usize))
+            .ok_or_else(|| {
+                PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)
+                    .with_message(format!(
+                        "Invalid call result argument, call index:{}, result index:{}",
+                        call_index, result_index
+                    ))
+                    .finish(location.clone())
+            })?;
+        if &result.ty != param_ty {
+            return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)
+                .with_message(format!(
+                    "The call result({}, {}) type mismatch with the parameter type",
+                    call_index, result_index
+                ))
+                .finish(location));
+        }
+        if result.consumed {
+            return Err(PartialVMError::new(StatusCode::ABORTED)
+                .with_sub_status(ErrorCode::MULTI_CALL_RESULT_ALREADY_USED.into())
+                .with_message(format!(
+                    "The call result({}, {}) is already used",
+                    call_index, result_index
+                ))
+                .finish(location));
+        }
+        if !result.abilities.has_copy() {
+            result.consumed = true;
+        }
+        Ok(result.value.clone())
+    }
+
+    /// Check all the return values without `drop` ability are used
+    pub fn check_consumed(&self) -> VMResult<()> {
+        for (call_index, results) in self.results.iter().enumerate() {
+            for (result_index, result) in results.iter().enumerate() {
+                if !result.consumed && !result.abilities.has_drop() {
+                    return Err(PartialVMError::new(
+                        StatusCode::UNSAFE_RET_UNUSED_VALUES_WITHOUT_DROP,
+                    )
+                    .with_message(format!(
+                        "The call result({}, {}) without drop ability is not used",
+                        call_index, result_index
+                    ))
+                    .finish(Location::Undefined));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<'r, 'l, S, G> MoveOSSession<'r, 'l, S, G>
 where
     S: MoveOSResolver,
     G: SwitchableGasMeter + ClassifiedGasMeter,
 {
+    /// Resolve the arguments of a call in a `MultiCall`.
+    /// The pure arguments are resolved the same as `resolve_argument`, the result arguments are taken from the
+    /// return values of the previous calls, when `load_object` is false, the result arguments are placeholders.
+    pub fn resolve_multi_call_argument(
+        &self,
+        func: &LoadedFunctionInstantiation,
+        args: Vec<CallArgument>,
+        results: &mut MultiCallResults,
+        location: Location,
+        load_object: bool,
+    ) -> VMResult<Vec<Vec<u8>>> {
+        let parameters = func
+            .parameters
+            .iter()
+            .map(|ty| ty.subst(&func.type_arguments))
+            .collect::<PartialVMResult<Vec<_>>>()
+            .map_err(|err| err.finish(location.clone()))?;
+
+        let mut args = args.into_iter();
+        let mut serialized_args = vec![];
+        for ty in parameters.iter() {
+            if is_signer(ty) {
+                serialized_args.push(self.construct_arg(
+                    ty,
+                    vec![],
+                    load_object,
+                    location.clone(),
+                )?);
+                continue;
+            }
+            let arg = args.next().ok_or_else(|| {
+                PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                    .with_message("argument length mismatch, too few args".to_string())
+                    .finish(location.clone())
+            })?;
+            let serialized_arg = match arg {
+                CallArgument::Pure(arg) => {
+                    if !check_transaction_input_type(ty, &self.session) {
+                        return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)
+                            .with_message(format!(
+                                "The type of the {} parameter is not allowed as a pure argument",
+                                serialized_args.len()
+                            ))
+                            .finish(location.clone()));
+                    }
+                    self.construct_arg(ty, arg, load_object, location.clone())?
+                }
+                CallArgument::Result {
+                    call_index,
+                    result_index,
+                } => results
+                    .take(call_index, result_index, ty, location.clone())?
+                    .unwrap_or_default(),
+            };
+            serialized_args.push(serialized_arg);
+        }
+
+        if args.next().is_some() {
+            return Err(
+                PartialVMError::new(StatusCode::NUMBER_OF_ARGUMENTS_MISMATCH)
+                    .with_message("argument length mismatch, too many args".to_string())
+                    .finish(location.clone()),
+            );
+        }
+
+        Ok(serialized_args)
+    }
+
+    /// Record the return values of a call in a `MultiCall`, the `values` are `None` when verifying
+    pub fn push_multi_call_results(
+        &self,
+        func: &LoadedFunctionInstantiation,
+        values: Option<Vec<Vec<u8>>>,
+        results: &mut MultiCallResults,
+        location: Location,
+    ) -> VMResult<()> {
+        let mut values = values.map(|values| values.into_iter());
+        let mut call_results = vec![];
+        for ty in func.return_.iter() {
+            let ty = ty
+                .subst(&func.type_arguments)
+                .map_err(|err| err.finish(location.clone()))?;
+            let abilities = self.session.get_type_abilities(&ty)?;
+            let value = match values.as_mut() {
+                Some(values) => Some(values.next().ok_or_else(|| {
+                    PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                        .with_message(
+                            "The return values mismatch with the return types".to_string(),
+                        )
+                        .finish(location.clone())
+                })?),
+                None => None,
+            };
+            call_results.push(CallResult {
+                ty,
+                value,
+                abilities,
+                consumed: false,
+            });
+        }
+        results.results.push(call_results);
+        Ok(())
+    }
+
     pub fn resolve_argument(
         &self,
         func: &LoadedFunctionInstantiation,
@@ -24,6 +24,7 @@ use std::convert::TryInto;
 use std::ffi::CStr;
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::oneshot;
@@ -123,6 +124,18 @@ impl StoreInstance {
         }
     }
 
+    /// Create a consistent checkpoint of the underlying db in `path`, it is safe to call while the
+    /// store is being written.
+    pub fn create_checkpoint(&self, path: impl AsRef<Path>) -> Result<()> {
+        match self {
+            StoreInstance::DB {
+                db,
+                db_metrics: _,
+                metrics_task_cancel_handle: _,
+            } => db.create_checkpoint(path),
+        }
+    }
+
     pub fn db_metrics(&self) -> Option<&DBMetrics> {
         match self {
             StoreInstance::DB {
@@ -12,9 +12,9 @@ use std::path::Path;
 
 use anyhow::{ensure, format_err, Error, Result};
 use rocksdb::{
-    statistics, AsColumnFamilyRef, BlockBasedIndexType, BlockBasedOptions, CStrLike, Cache,
-    ColumnFamily, ColumnFamilyDescriptor, DBCompressionType, DBRawIterator, DBRecoveryMode,
-    Options, ReadOptions, WriteBatch as DBWriteBatch, WriteOptions, DB,
+    checkpoint::Checkpoint, statistics, AsColumnFamilyRef, BlockBasedIndexType, BlockBasedOptions,
+    CStrLike, Cache, ColumnFamily, ColumnFamilyDescriptor, DBCompressionType, DBRawIterator,
+    DBRecoveryMode, Options, ReadOptions, WriteBatch as DBWriteBatch, WriteOptions, DB,
 };
 use serde::de::DeserializeOwned;
 use serde::Serialize;
@@ -221,6 +221,14 @@ impl RocksDB {
         Ok(())
     }
 
+    /// Create a consistent point-in-time checkpoint of the db in `path`, which must not exist.
+    /// The SST files are hard linked if `path` is on the same filesystem, otherwise copied.
+    pub fn create_checkpoint(&self, path: impl AsRef<Path>) -> Result<()> {
+        let checkpoint = Checkpoint::new(&self.db)?;
+        checkpoint.create_checkpoint(path)?;
+        Ok(())
+    }
+
     /// List cf
     pub fn list_cf(path: impl AsRef<Path>) -> Result<Vec<String>, Error> {
         Ok(DB::list_cf(&Options::default(), path)?)
@@ -299,6 +307,13 @@ impl RocksDB {
         self.iter_with_direction(cf_name, ScanDirection::Backward)
     }
 
+    /// Returns a raw iterator on a certain column family, the keys and values are not decoded.
+    pub fn raw_iter(&self, cf_name: &str) -> DBRawIterator {
+        let cf_handle = self.get_cf_handle(cf_name);
+        self.db
+            .raw_iterator_cf_opt(&cf_handle, ReadOptions::default())
+    }
+
     fn sync_write_options() -> WriteOptions {
         let mut opts = WriteOptions::new();
         opts.set_sync(true);
@@ -13,7 +13,7 @@ use parking_lot::RwLock;
 use primitive_types::H256;
 use prometheus::Registry;
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     marker::PhantomData,
     sync::Arc,
 };
@@ -100,11 +100,22 @@ impl NodeWriter for InMemoryNodeStore {
 pub struct TreeChangeSet {
     pub state_root: H256,
     pub nodes: BTreeMap<H256, Vec<u8>>,
+    /// The nodes which are no longer referenced by the new state_root in this tree.
+    /// The nodes may still be referenced by other trees, because the node key is the node hash.
+    pub stale_nodes: BTreeSet<H256>,
 }
 
 impl TreeChangeSet {
-    pub fn new(state_root: H256, nodes: BTreeMap<H256, Vec<u8>>) -> Self {
-        Self { state_root, nodes }
+    pub fn new(
+        state_root: H256,
+        nodes: BTreeMap<H256, Vec<u8>>,
+        stale_nodes: BTreeSet<H256>,
+    ) -> Self {
+        Self {
+            state_root,
+            nodes,
+            stale_nodes,
+        }
     }
 }
 
@@ -293,6 +304,7 @@ where
             return Ok(TreeChangeSet {
                 state_root,
                 nodes: BTreeMap::default(),
+                stale_nodes: BTreeSet::default(),
             });
         }
 
@@ -306,11 +318,20 @@ where
             node_map.insert(nk.into(), n.encode()?);
         }
 
+        // the placeholder node is never stored
+        let stale_nodes = change_set
+            .stale_node_index_batch
+            .into_iter()
+            .map(|index| index.node_key.into())
+            .filter(|node_hash: &H256| *node_hash != *SPARSE_MERKLE_PLACEHOLDER_HASH)
+            .collect();
+
         let new_state_root: H256 = new_state_root.into();
 
         Ok(TreeChangeSet {
             state_root: new_state_root,
             nodes: node_map,
+            stale_nodes,
         })
     }
 
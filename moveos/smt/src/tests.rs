@@ -57,3 +57,34 @@ fn test_smt() {
     let iter = smt.iter(changeset3.state_root, None).unwrap();
     assert_eq!(iter.count(), 2);
 }
+
+#[test]
+fn test_stale_nodes() {
+    let node_store = InMemoryNodeStore::default();
+    let registry = prometheus::Registry::new();
+    let smt = SMTree::new(node_store.clone(), &registry);
+    let genesis_root = *SPARSE_MERKLE_PLACEHOLDER_HASH;
+    let key = H256::random();
+    let changeset = smt.put(genesis_root, key, "value".to_string()).unwrap();
+    // the placeholder root is not a stored node
+    assert!(changeset.stale_nodes.is_empty());
+    node_store.write_nodes(changeset.nodes.clone()).unwrap();
+
+    let changeset2 = smt
+        .put(changeset.state_root, key, "value2".to_string())
+        .unwrap();
+    // the only leaf is the root, it is replaced by the new leaf
+    assert_eq!(
+        changeset2.stale_nodes,
+        BTreeSet::from([changeset.state_root])
+    );
+    assert!(changeset2.nodes.contains_key(&changeset2.state_root));
+    node_store.write_nodes(changeset2.nodes).unwrap();
+
+    let changeset3 = smt.remove(changeset2.state_root, key).unwrap();
+    assert_eq!(changeset3.state_root, genesis_root);
+    assert_eq!(
+        changeset3.stale_nodes,
+        BTreeSet::from([changeset2.state_root])
+    );
+}
@@ -146,6 +146,12 @@ export interface ExecuteTransactionResponseView {
   output?: TransactionOutputView | null
   sequence_info: TransactionSequenceInfoView
 }
+export interface FieldProofView {
+  field_key: string
+  proof: SparseMerkleProofView
+  /** None means the field does not exist, and the proof is a non-inclusion proof */
+  state?: ObjectStateView | null
+}
 export interface FunctionCallView {
   args: string[]
   function_id: string
@@ -519,6 +525,8 @@ export type RepairIndexerParamsView =
     }
 export interface RoochStatus {
   da_info: DAInfoView
+  /** The genesis hash of the chain, the nodes of the same chain must have the same genesis */
+  genesis_hash: string
   root_state: RootStateView
   sequencer_info: SequencerInfoView
 }
@@ -539,9 +547,23 @@ export interface SequencerInfoView {
   last_accumulator_info: AccumulatorInfoView
   last_order: string
 }
-export type ServiceStatus = 'active' | 'maintenance' | 'read-only-mode' | 'date-import-mode'
+export type ServiceStatus =
+  | 'active'
+  | 'maintenance'
+  | 'read-only-mode'
+  | 'date-import-mode'
+  | 'sync-mode'
 /** Some specific struct that we want to display in a special way for better readability */
 export type SpecificStructView = MoveString | MoveAsciiString | string
+export interface SparseMerkleLeafView {
+  key: string
+  value_hash: string
+}
+export interface SparseMerkleProofView {
+  /** The leaf in the proof, None means the key's subtree is empty */
+  leaf?: SparseMerkleLeafView | null
+  siblings: string[]
+}
 export interface StateChangeSetView {
   changes: ObjectChangeView[]
   global_size: string
@@ -563,6 +585,13 @@ export interface StateOptions {
   /** The state root of remote stateDB */
   stateRoot?: string | null
 }
+export interface StateProofView {
+  field_key: string
+  object_id: string
+  /** The field proofs from the global state tree to the field */
+  proofs: FieldProofView[]
+  state_root: string
+}
 export interface Status {
   /** The status of the Bitcoin chain */
   bitcoin_status: BitcoinStatus
@@ -615,6 +644,15 @@ export interface TransactionOutputView {
   is_upgrade: boolean
   status: KeptVMStatusView
 }
+export interface TransactionProofView {
+  /** The tx accumulator root the proof is generated with */
+  accumulator_root: string
+  leaf_index: string
+  /** The accumulator siblings, ordered from the bottom level to the root level */
+  siblings: string[]
+  tx_hash: string
+  tx_order: string
+}
 export interface TransactionSequenceInfoView {
   tx_accumulator_root: string
   tx_order: string
@@ -83,6 +83,15 @@ export interface GetObjectStatesParams {
   ids: string[]
   stateOption?: RpcTypes.StateOptions | null | undefined
 }
+/**
+ * Get the Merkle proofs of the states by access_path. If the state_root is None, the proofs are
+ * generated with the latest state root. The proofs can be verified with `StateProof::verify`, even
+ * if the state does not exist.
+ */
+export interface GetStateProofParams {
+  accessPath: string
+  stateRoot?: string | null | undefined
+}
 /**
  * Get the states by access_path If the StateOptions.decode is true, the state is decoded and the
  * decoded value is returned in the response.
@@ -91,6 +100,16 @@ export interface GetStatesParams {
   accessPath: string
   stateOption?: RpcTypes.StateOptions | null | undefined
 }
+/**
+ * Get the Merkle proof of the transaction inclusion in the tx accumulator. If the
+ * accumulator_tx_order is provided, the proof is generated with the tx accumulator root after the
+ * transaction at accumulator_tx_order, such as the last transaction of a proposed block. Otherwise,
+ * the proof is generated with the transaction's own tx accumulator root.
+ */
+export interface GetTransactionProofParams {
+  txOrder: string
+  accumulatorTxOrder?: string | null | undefined
+}
 export interface GetTransactionsByHashParams {
   txHashes: string[]
 }